@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! Demonstrate creating a simple flowchart with shapes and connectors using
+//! the `rust_xlsxwriter` library.
+
+use rust_xlsxwriter::{Shape, ShapeLine, ShapeLineArrowType, ShapeType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create some flowchart shapes.
+    let start = Shape::new(ShapeType::FlowchartTerminator)
+        .set_text("Start")
+        .set_width(120)
+        .set_height(40);
+
+    let process = Shape::new(ShapeType::FlowchartProcess)
+        .set_text("Process the data")
+        .set_width(120)
+        .set_height(60);
+
+    let decision = Shape::new(ShapeType::FlowchartDecision)
+        .set_text("Valid?")
+        .set_width(120)
+        .set_height(80);
+
+    // Insert the shapes into the worksheet.
+    worksheet.insert_shape(1, 1, &start)?;
+    worksheet.insert_shape(5, 1, &process)?;
+    worksheet.insert_shape(10, 1, &decision)?;
+
+    // Create a connector with an arrowhead at the end.
+    let connector = Shape::new(ShapeType::StraightConnector)
+        .set_format(&ShapeLine::new().set_end_arrow(ShapeLineArrowType::Triangle));
+
+    // Join the shapes with connectors.
+    worksheet.insert_connector(1, 1, 5, 1, &connector)?;
+    worksheet.insert_connector(5, 1, 10, 1, &connector)?;
+
+    // Save the file to disk.
+    workbook.save("shape_flowchart.xlsx")?;
+
+    Ok(())
+}
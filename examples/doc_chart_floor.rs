@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of formatting the floor and walls of a 3D chart.
+
+use rust_xlsxwriter::{Chart, ChartFormat, ChartSolidFill, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart.
+    worksheet.write_column(0, 0, [10, 40, 50, 20, 10])?;
+    worksheet.write_column(0, 1, [30, 60, 70, 50, 40])?;
+
+    // Create a new 3D column chart.
+    let mut chart = Chart::new(ChartType::Column3D);
+
+    // Add the data series.
+    chart.add_series().set_values("Sheet1!$A$1:$A$5");
+    chart.add_series().set_values("Sheet1!$B$1:$B$5");
+
+    // Format the floor and walls of the chart.
+    chart
+        .floor()
+        .set_format(ChartFormat::new().set_solid_fill(ChartSolidFill::new().set_color("#D9D9D9")));
+    chart
+        .back_wall()
+        .set_format(ChartFormat::new().set_solid_fill(ChartSolidFill::new().set_color("#F2F2F2")));
+    chart
+        .side_wall()
+        .set_format(ChartFormat::new().set_solid_fill(ChartSolidFill::new().set_color("#F2F2F2")));
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 3, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating several charts with the same
+//! formatting from a chart template.
+
+use rust_xlsxwriter::{
+    Chart, ChartFormat, ChartLegendPosition, ChartSolidFill, ChartTemplate, ChartType, Workbook,
+    XlsxError,
+};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the charts.
+    let data = [[10, 40, 50], [20, 30, 60], [30, 25, 15], [40, 50, 30]];
+    for (row, row_data) in data.iter().enumerate() {
+        worksheet.write_row(row as u32, 0, *row_data)?;
+    }
+
+    // Create and format a chart to use as a template.
+    let mut chart = Chart::new(ChartType::Column);
+    chart.title().set_name("Quarterly results");
+    chart.legend().set_position(ChartLegendPosition::Bottom);
+    chart.y_axis().set_major_gridlines(false);
+    chart
+        .add_series()
+        .set_format(ChartFormat::new().set_solid_fill(ChartSolidFill::new().set_color("#1F4E79")));
+
+    let template = ChartTemplate::new(&chart);
+
+    // Create charts with the same formatting for each column of data.
+    for col in 0..3 {
+        let mut chart = Chart::from_template(&template)?;
+        chart.add_series().set_values(("Sheet1", 0, col, 3, col));
+
+        worksheet.insert_chart(col as u32 * 15 + 5, 0, &chart)?;
+    }
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
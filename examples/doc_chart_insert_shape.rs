@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! This example demonstrates adding a callout shape to annotate a chart.
+
+use rust_xlsxwriter::{Chart, ChartType, Shape, ShapeType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart.
+    worksheet.write(0, 0, 50)?;
+    worksheet.write(1, 0, 30)?;
+    worksheet.write(2, 0, 40)?;
+
+    // Create a new chart.
+    let mut chart = Chart::new(ChartType::Column);
+
+    // Add a data series using Excel formula syntax to describe the range.
+    chart.add_series().set_values("Sheet1!$A$1:$A$3");
+
+    // Add a callout shape to annotate the chart.
+    let shape = Shape::new(ShapeType::RectangularCallout)
+        .set_text("Peak value")
+        .set_width(96)
+        .set_height(40);
+
+    chart.insert_shape(&shape, 100, 20);
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 2, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! A chart example demonstrating combining Column, Line and Area series in the
+//! same chart.
+
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add the worksheet data that the charts will refer to.
+    worksheet.write_column(0, 0, [10, 40, 50, 20, 10, 50])?;
+    worksheet.write_column(0, 1, [30, 30, 40, 40, 40, 50])?;
+    worksheet.write_column(0, 2, [20, 30, 35, 30, 25, 45])?;
+
+    // Create a new Column chart.
+    let mut chart = Chart::new(ChartType::Column);
+
+    // Add the "actual" values as a Column series.
+    chart
+        .add_series()
+        .set_values("Sheet1!$A$1:$A$6")
+        .set_name("Actual");
+
+    // Add the "target" values as a Line series.
+    chart
+        .add_series()
+        .set_values("Sheet1!$B$1:$B$6")
+        .set_name("Target")
+        .set_chart_type(ChartType::Line);
+
+    // Add the "forecast" values as an Area series.
+    chart
+        .add_series()
+        .set_values("Sheet1!$C$1:$C$6")
+        .set_name("Forecast")
+        .set_chart_type(ChartType::Area);
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart_with_offset(0, 3, &chart, 5, 5)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! An example of setting the 3D view properties of a 3D chart.
+
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart.
+    worksheet.write_column(0, 0, [10, 40, 50, 20, 10])?;
+    worksheet.write_column(0, 1, [30, 60, 70, 50, 40])?;
+    worksheet.write_column(0, 2, [20, 30, 40, 60, 50])?;
+
+    // Create a new 3D column chart with a series (depth) axis.
+    let mut chart = Chart::new(ChartType::Column3DStandard);
+
+    // Add the data series.
+    chart.add_series().set_values("Sheet1!$A$1:$A$5");
+    chart.add_series().set_values("Sheet1!$B$1:$B$5");
+    chart.add_series().set_values("Sheet1!$C$1:$C$5");
+
+    // Set the 3D view properties of the chart.
+    chart.set_elevation(25).set_rotation(40).set_perspective(20);
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 4, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
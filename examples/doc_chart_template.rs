@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating a chart template from a formatted
+//! chart and using it to create a new chart.
+
+use rust_xlsxwriter::{Chart, ChartTemplate, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart.
+    worksheet.write_column(0, 0, [10, 40, 50, 20, 10, 50])?;
+    worksheet.write_column(0, 1, [30, 60, 70, 50, 40, 30])?;
+
+    // Create and format a chart to use as a template.
+    let mut chart = Chart::new(ChartType::Line);
+    chart.set_style(37);
+    chart.x_axis().set_name("Month");
+    chart.y_axis().set_name("Sales");
+    chart.add_series().set_values("Sheet1!$A$1:$A$6");
+
+    let template = ChartTemplate::new(&chart);
+
+    // Create a new chart with the same formatting but different data.
+    let mut chart = Chart::from_template(&template)?;
+    chart.add_series().set_values("Sheet1!$B$1:$B$6");
+
+    worksheet.insert_chart(0, 3, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
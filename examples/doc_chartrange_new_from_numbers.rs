@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating a new chart range from an array
+//! of numbers.
+
+use rust_xlsxwriter::ChartRange;
+
+#[allow(unused_variables)]
+fn main() {
+    let range = ChartRange::new_from_numbers(&[10.0, 40.0, 50.0, 20.0, 10.0]);
+}
@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating a new chart range from an array
+//! of strings.
+
+use rust_xlsxwriter::ChartRange;
+
+#[allow(unused_variables)]
+fn main() {
+    let range = ChartRange::new_from_strings(&["Jan", "Feb", "Mar", "Apr", "May"]);
+}
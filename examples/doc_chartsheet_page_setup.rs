@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting up a chartsheet for printing in
+//! landscape mode with a footer, and protecting it.
+
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart.
+    worksheet.write(0, 0, 10)?;
+    worksheet.write(1, 0, 60)?;
+    worksheet.write(2, 0, 30)?;
+    worksheet.write(3, 0, 10)?;
+    worksheet.write(4, 0, 50)?;
+
+    // Create a new chart.
+    let mut chart = Chart::new(ChartType::Column);
+    chart.add_series().set_values("Sheet1!$A$1:$A$5");
+
+    // Create a new chartsheet and add the chart to it.
+    let chartsheet = workbook.add_chartsheet();
+    chartsheet.insert_chart(0, 0, &chart)?;
+
+    // Set the print and display options.
+    chartsheet
+        .set_name("Revenue")?
+        .set_landscape()
+        .set_paper_size(9)
+        .set_footer("&LCompany Confidential&RPage &P of &N")
+        .set_tab_color("#4472C4")
+        .set_zoom(90);
+
+    // Protect the chart from changes.
+    chartsheet.protect();
+
+    // Save the file.
+    workbook.save("chartsheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! Example of adding a Cell conditional format that compares the values in a
+//! range against a target value in another worksheet.
+
+use rust_xlsxwriter::{
+    ConditionalFormatCell, ConditionalFormatCellRule, Format, Formula, Workbook, XlsxError,
+};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a format. Green fill with dark green text.
+    let format = Format::new()
+        .set_font_color("006100")
+        .set_background_color("C6EFCE");
+
+    // Add some sample data.
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_column(0, 0, [10, 80, 50, 10, 20, 60, 40, 70, 30, 40])?;
+
+    // Compare the values against a target in another worksheet.
+    let conditional_format = ConditionalFormatCell::new()
+        .set_rule(ConditionalFormatCellRule::GreaterThan(Formula::new(
+            "Config!$B$1",
+        )))
+        .set_format(format);
+
+    worksheet.add_conditional_format(0, 0, 9, 0, &conditional_format)?;
+
+    // Add the target value to the other worksheet.
+    let config = workbook.add_worksheet().set_name("Config")?;
+    config.write(0, 0, "Target")?;
+    config.write(0, 1, 50)?;
+
+    // Save the file.
+    workbook.save("conditional_format.xlsx")?;
+
+    Ok(())
+}
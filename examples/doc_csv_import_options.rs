@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates importing CSV data into a worksheet table
+//! with some custom formatting.
+
+use rust_xlsxwriter::{CsvImportOptions, Format, Table, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Some sample CSV data. This could also be a file or other reader.
+    let data = "Region;Sales;Date\n\
+                North;1000;2024-01-01\n\
+                South;2300.5;2024-01-02\n\
+                East;1800;2024-01-03\n\
+                West;2750.25;2024-01-04\n";
+
+    // Set some options for the CSV import.
+    let options = CsvImportOptions::new()
+        .set_delimiter(';')
+        .set_table(Table::new())
+        .set_column_format("Sales", Format::new().set_num_format("#,##0.00"))
+        .set_date_format(Format::new().set_num_format("dd/mm/yyyy"))
+        .set_autofit(true);
+
+    // Import the CSV data into the worksheet.
+    worksheet.import_csv(data.as_bytes(), &options)?;
+
+    // Save the file to disk.
+    workbook.save("csv_import.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! Example of adding a custom table style to a workbook and a table.
+
+use rust_xlsxwriter::{Color, CustomTableStyle, Format, FormatBorder, Table, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Create a custom table style with some brand colors.
+    let custom_style = CustomTableStyle::new("BrandStyle")
+        .set_whole_table_format(
+            &Format::new()
+                .set_border(FormatBorder::Thin)
+                .set_border_color("#1F3864"),
+        )
+        .set_header_row_format(
+            &Format::new()
+                .set_bold()
+                .set_font_color(Color::White)
+                .set_background_color("#1F3864"),
+        )
+        .set_first_row_stripe_format(&Format::new().set_background_color("#D9E1F2"));
+
+    // Add the style to the workbook so it can be used by tables.
+    workbook.add_table_style(&custom_style)?;
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Some sample data for the table.
+    let items = ["Apples", "Pears", "Bananas", "Oranges"];
+    let data = [
+        [10000, 5000, 8000, 6000],
+        [2000, 3000, 4000, 5000],
+        [6000, 6000, 6500, 6000],
+        [500, 300, 200, 700],
+    ];
+
+    // Write the table data.
+    worksheet.write_column(3, 1, items)?;
+    worksheet.write_row_matrix(3, 2, data)?;
+
+    // Set the column widths for clarity.
+    worksheet.set_column_range_width(1, 6, 12)?;
+
+    // Create a new table and set the custom style.
+    let table = Table::new().set_custom_style(&custom_style);
+
+    // Add the table to the worksheet.
+    worksheet.add_table(2, 1, 6, 5, &table)?;
+
+    // Save the file to disk.
+    workbook.save("tables.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates a list that is longer than Excel's 255
+//! character limit. The strings are stored in a hidden worksheet when the file
+//! is saved.
+
+use rust_xlsxwriter::{DataValidation, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write(1, 0, "Select value in cell D2:")?;
+
+    // Create a list of 100 product codes.
+    let products: Vec<String> = (1..=100).map(|i| format!("Product-{i:04}")).collect();
+
+    let data_validation = DataValidation::new().allow_list_strings(&products)?;
+
+    worksheet.add_data_validation(1, 3, 1, 3, &data_validation)?;
+
+    // Save the file.
+    workbook.save("data_validation.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a Polars dataframe to a worksheet
+//! table with some custom formatting.
+
+use polars::prelude::*;
+use rust_xlsxwriter::{DataFrameOptions, Format, Table, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a sample dataframe.
+    let df: DataFrame = df!(
+        "Region" => &["North", "South", "East", "West"],
+        "Sales" => &[1000.0, 2300.5, 1800.0, 2750.25],
+    )?;
+
+    // Set some options for the dataframe.
+    let options = DataFrameOptions::new()
+        .set_table(Table::new())
+        .set_column_format("Sales", Format::new().set_num_format("#,##0.00"))
+        .set_autofit(true);
+
+    // Write the dataframe to the worksheet.
+    worksheet.write_dataframe_with_options(&df, 0, 0, &options)?;
+
+    // Save the file to disk.
+    workbook.save("dataframe.xlsx")?;
+
+    Ok(())
+}
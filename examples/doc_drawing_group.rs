@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! This example demonstrates grouping an image, a textbox and an arrow shape so
+//! that they stay together when the worksheet rows are resized.
+
+use rust_xlsxwriter::{DrawingGroup, Image, Shape, ShapeType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create the objects to group.
+    let image = Image::new("examples/rust_logo.png")?.set_scale_to_size(64, 64, true);
+    let textbox = Shape::textbox().set_text("Sales are up this quarter");
+    let arrow = Shape::new(ShapeType::LeftArrow)
+        .set_width(80)
+        .set_height(40);
+
+    // Create a group with the objects at offsets from the top left corner.
+    let group = DrawingGroup::new()
+        .add_image(&image, 0, 0)
+        .add_shape(&arrow, 80, 12)
+        .add_shape(&textbox, 176, 0);
+
+    // Insert the group into the worksheet.
+    worksheet.insert_drawing_group(1, 1, &group)?;
+
+    // Save the file to disk.
+    workbook.save("drawing_group.xlsx")?;
+
+    Ok(())
+}
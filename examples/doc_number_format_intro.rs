@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates displaying numbers and dates using Excel
+//! number formats.
+
+use rust_xlsxwriter::{Color, ExcelDateTime, Format, NumberFormat, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Display numbers with a currency format.
+    let number_format = NumberFormat::new("$#,##0.00;[Red]($#,##0.00)");
+
+    assert_eq!(number_format.format_number(1234.5), "$1,234.50");
+    assert_eq!(number_format.format_number(-1234.5), "($1,234.50)");
+    assert_eq!(number_format.font_color(-1234.5), Some(Color::Red));
+
+    // Display a date and time.
+    let number_format = NumberFormat::new("yyyy-mm-dd hh:mm");
+    let datetime = ExcelDateTime::from_ymd(2025, 1, 15)?.and_hms(14, 30, 0)?;
+
+    assert_eq!(number_format.format_datetime(&datetime), "2025-01-15 14:30");
+
+    // Display a number using the number format of a `Format`.
+    let format = Format::new().set_num_format("0.0%");
+    let number_format = NumberFormat::from_format(&format);
+
+    assert_eq!(number_format.format_number(0.256), "25.6%");
+
+    Ok(())
+}
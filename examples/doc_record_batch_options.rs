@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing an Arrow record batch to a
+//! worksheet table with some custom formatting.
+
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Float64Array, RecordBatch, StringArray};
+use rust_xlsxwriter::{Format, RecordBatchOptions, Table, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a sample record batch.
+    let regions: ArrayRef = Arc::new(StringArray::from(vec!["North", "South", "East", "West"]));
+    let sales: ArrayRef = Arc::new(Float64Array::from(vec![1000.0, 2300.5, 1800.0, 2750.25]));
+
+    let batch = RecordBatch::try_from_iter(vec![("Region", regions), ("Sales", sales)])?;
+
+    // Set some options for the record batch.
+    let options = RecordBatchOptions::new()
+        .set_table(Table::new())
+        .set_column_format("Sales", Format::new().set_num_format("#,##0.00"))
+        .set_autofit(true);
+
+    // Write the record batch to the worksheet.
+    worksheet.write_record_batch_with_options(&batch, 0, 0, &options)?;
+
+    // Save the file to disk.
+    workbook.save("record_batch.xlsx")?;
+
+    Ok(())
+}
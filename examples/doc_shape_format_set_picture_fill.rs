@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! This example demonstrates adding a shape with an image fill.
+
+use rust_xlsxwriter::{
+    Image, Shape, ShapeFormat, ShapePictureFill, ShapePictureFillType, ShapeType, Workbook,
+    XlsxError,
+};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a shape with a tiled image fill.
+    let image = Image::new("examples/rust_logo.png")?;
+
+    let shape = Shape::new(ShapeType::Ellipse).set_format(
+        &ShapeFormat::new()
+            .set_picture_fill(&ShapePictureFill::new(&image).set_type(ShapePictureFillType::Tile)),
+    );
+
+    // Insert the shape in a cell.
+    worksheet.insert_shape(1, 1, &shape)?;
+
+    // Save the file to disk.
+    workbook.save("shape.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! This example demonstrates joining two shapes with a connector that has an
+//! arrowhead at the end.
+
+use rust_xlsxwriter::{Shape, ShapeLine, ShapeLineArrowType, ShapeType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create two shapes to connect.
+    let shape1 = Shape::new(ShapeType::Rectangle).set_text("From");
+    let shape2 = Shape::new(ShapeType::Rectangle).set_text("To");
+
+    worksheet.insert_shape(1, 1, &shape1)?;
+    worksheet.insert_shape(1, 6, &shape2)?;
+
+    // Create a connector with an arrowhead.
+    let connector = Shape::new(ShapeType::StraightConnector).set_format(
+        &ShapeLine::new()
+            .set_color("#FF0000")
+            .set_end_arrow(ShapeLineArrowType::Triangle),
+    );
+
+    worksheet.insert_connector(1, 1, 1, 6, &connector)?;
+
+    // Save the file to disk.
+    workbook.save("shape.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! This example demonstrates adding some preset shapes to a worksheet.
+
+use rust_xlsxwriter::{Shape, ShapeType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create some preset shapes.
+    let rectangle = Shape::new(ShapeType::RoundedRectangle).set_text("Rectangle");
+    let ellipse = Shape::new(ShapeType::Ellipse).set_text("Ellipse");
+    let star = Shape::new(ShapeType::Star5).set_text("Star");
+
+    // Insert the shapes in the worksheet.
+    worksheet.insert_shape(1, 1, &rectangle)?;
+    worksheet.insert_shape(1, 5, &ellipse)?;
+    worksheet.insert_shape(1, 9, &star)?;
+
+    // Save the file to disk.
+    workbook.save("shape.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! This example demonstrates joining two shapes with an elbow connector using
+//! explicit connection sites.
+
+use rust_xlsxwriter::{Shape, ShapeConnectionSite, ShapeType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create two shapes to connect.
+    let shape1 = Shape::new(ShapeType::Rectangle).set_text("Start");
+    let shape2 = Shape::new(ShapeType::Ellipse).set_text("End");
+
+    worksheet.insert_shape(1, 1, &shape1)?;
+    worksheet.insert_shape(10, 5, &shape2)?;
+
+    // Connect the bottom of the first shape to the left of the second.
+    let connector = Shape::new(ShapeType::ElbowConnector)
+        .set_connection_sites(ShapeConnectionSite::Bottom, ShapeConnectionSite::Left);
+
+    worksheet.insert_connector(1, 1, 10, 5, &connector)?;
+
+    // Save the file to disk.
+    workbook.save("shape.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding named cell styles to a workbook.
+
+use rust_xlsxwriter::{Format, FormatBorder, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add some named cell styles to the workbook.
+    let heading = workbook.add_cell_style(
+        "Corp Heading",
+        &Format::new()
+            .set_bold()
+            .set_font_size(14)
+            .set_border_bottom(FormatBorder::Medium),
+    )?;
+
+    let currency =
+        workbook.add_cell_style("Corp Currency", &Format::new().set_num_format("$#,##0.00"))?;
+
+    // Create a format that is based on one of the styles.
+    let total = currency.clone().set_bold();
+
+    // Write some data with the style formats.
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(0, 20)?;
+
+    worksheet.write_with_format(0, 0, "Sales", &heading)?;
+    worksheet.write_with_format(1, 0, 1234.5, &currency)?;
+    worksheet.write_with_format(2, 0, 5432.1, &currency)?;
+    worksheet.write_formula_with_format(3, 0, "=SUM(A2:A3)", &total)?;
+
+    // Save the file to disk.
+    workbook.save("cell_styles.xlsx")?;
+
+    Ok(())
+}
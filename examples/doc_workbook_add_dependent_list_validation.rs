@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding dependent drop-down lists to a
+//! worksheet.
+
+use rust_xlsxwriter::{DataValidation, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet with headers for the drop-down lists.
+    let worksheet = workbook.add_worksheet().set_name("Orders")?;
+    worksheet.write_row(0, 0, ["Country", "Region", "City"])?;
+    worksheet.set_column_range_width(0, 2, 16)?;
+
+    // Add a standard list validation for the top level values.
+    let data_validation = DataValidation::new().allow_list_strings(&["France", "Japan"])?;
+    worksheet.add_data_validation(1, 0, 20, 0, &data_validation)?;
+
+    // Add a dependent list of regions for each country.
+    let regions = vec![
+        ("France", vec!["Brittany", "Normandy"]),
+        ("Japan", vec!["Hokkaido", "Kanto"]),
+    ];
+    workbook.add_dependent_list_validation("Orders", 1, 20, 0, 1, regions)?;
+
+    // Add a dependent list of cities for each region.
+    let cities = vec![
+        ("Brittany", vec!["Brest", "Rennes"]),
+        ("Normandy", vec!["Caen", "Rouen"]),
+        ("Hokkaido", vec!["Hakodate", "Sapporo"]),
+        ("Kanto", vec!["Tokyo", "Yokohama"]),
+    ];
+    workbook.add_dependent_list_validation("Orders", 1, 20, 1, 2, cities)?;
+
+    // Save the file to disk.
+    workbook.save("dependent_lists.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates saving a preview of a workbook as a PDF
+//! file.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write(0, 0, "Hello")?;
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write(0, 0, "World")?;
+
+    // Save the workbook and a preview of its worksheets.
+    workbook.save("workbook.xlsx")?;
+    workbook.save_to_pdf("workbook.pdf")?;
+
+    Ok(())
+}
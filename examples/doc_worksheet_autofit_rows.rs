@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates auto-fitting the worksheet row heights for
+//! cells with wrapped text.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    let wrap = Format::new().set_text_wrap();
+
+    // Add some data to the worksheet.
+    worksheet.set_column_width(1, 30)?;
+    worksheet.write_string(0, 0, "Item")?;
+    worksheet.write_string(0, 1, "Comment")?;
+    worksheet.write_string(1, 0, "Apples")?;
+    worksheet.write_string_with_format(
+        1,
+        1,
+        "The apples were delivered late and some of them were bruised.",
+        &wrap,
+    )?;
+    worksheet.write_string(2, 0, "Pears")?;
+    worksheet.write_string_with_format(2, 1, "Line 1\nLine 2\nLine 3", &wrap)?;
+
+    // Autofit the rows to show all of the wrapped text.
+    worksheet.autofit_rows();
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
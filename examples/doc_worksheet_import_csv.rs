@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates importing a CSV file into a worksheet.
+
+use std::fs::File;
+
+use rust_xlsxwriter::{CsvImportOptions, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Import a CSV file into the worksheet.
+    let file = File::open("examples/sales.csv")?;
+    worksheet.import_csv(file, &CsvImportOptions::new())?;
+
+    // Save the file to disk.
+    workbook.save("csv_import.xlsx")?;
+
+    Ok(())
+}
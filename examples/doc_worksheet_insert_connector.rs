@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! This example demonstrates joining two shapes in a worksheet with a connector.
+
+use rust_xlsxwriter::{Shape, ShapeType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create two shapes and insert them in the worksheet.
+    let shape1 = Shape::new(ShapeType::Rectangle).set_text("Shape 1");
+    let shape2 = Shape::new(ShapeType::Rectangle).set_text("Shape 2");
+
+    worksheet.insert_shape(1, 1, &shape1)?;
+    worksheet.insert_shape(10, 6, &shape2)?;
+
+    // Join the shapes with an elbow connector.
+    let connector = Shape::new(ShapeType::ElbowConnector);
+
+    worksheet.insert_connector(1, 1, 10, 6, &connector)?;
+
+    // Save the file to disk.
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
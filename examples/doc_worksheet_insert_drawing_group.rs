@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! This example demonstrates inserting a group of shapes into a worksheet.
+
+use rust_xlsxwriter::{DrawingGroup, Shape, ShapeType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a group with a callout and a textbox.
+    let group = DrawingGroup::new()
+        .add_shape(&Shape::new(ShapeType::OvalCallout).set_text("Note"), 0, 0)
+        .add_shape(&Shape::textbox().set_text("Some details"), 200, 0);
+
+    // Insert the group into the worksheet.
+    worksheet.insert_drawing_group(1, 1, &group)?;
+
+    // Save the file to disk.
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! Example of resizing a worksheet table after it has been added.
+
+use rust_xlsxwriter::{Table, TableColumn, TableFunction, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a table with a total row for the first chunk of data.
+    let columns = vec![
+        TableColumn::new()
+            .set_header("Product")
+            .set_total_label("Totals"),
+        TableColumn::new()
+            .set_header("Sales")
+            .set_total_function(TableFunction::Sum),
+    ];
+
+    let table = Table::new()
+        .set_name("Sales")
+        .set_columns(&columns)
+        .set_total_row(true);
+
+    worksheet.add_table(0, 0, 3, 1, &table)?;
+    worksheet.write_column(1, 0, ["Apples", "Pears"])?;
+    worksheet.write_column(1, 1, [1000, 2000])?;
+
+    // Extend the table for the next chunk of data. The total row moves
+    // from row 4 to row 6.
+    worksheet.resize_table("Sales", 5)?;
+    worksheet.write_column(3, 0, ["Bananas", "Oranges"])?;
+    worksheet.write_column(3, 1, [3000, 4000])?;
+
+    // Save the file to disk.
+    workbook.save("tables.xlsx")?;
+
+    Ok(())
+}
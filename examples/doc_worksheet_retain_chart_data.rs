@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates retaining the data of a "constant memory"
+//! worksheet so that it can be used in the cache of a chart in another
+//! worksheet.
+
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet in "constant memory" mode to store the data.
+    let worksheet = workbook.add_worksheet_with_constant_memory();
+
+    // Retain the chart data before it is written.
+    worksheet.retain_chart_data(0, 0, 4, 0)?;
+
+    worksheet.write_column(0, 0, [10, 40, 50, 20, 10])?;
+
+    // Add a chart that refers to the data in another worksheet.
+    let mut chart = Chart::new(ChartType::Column);
+    chart.add_series().set_values("Sheet1!$A$1:$A$5");
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.insert_chart(0, 0, &chart)?;
+
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates saving a preview of a worksheet as a PDF
+//! file.
+
+use rust_xlsxwriter::{Format, FormatBorder, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some formats.
+    let header_format = Format::new()
+        .set_bold()
+        .set_border_bottom(FormatBorder::Thin);
+    let currency_format = Format::new().set_num_format("$#,##0.00");
+
+    // Add some data to the worksheet.
+    worksheet.write_with_format(0, 0, "Region", &header_format)?;
+    worksheet.write_with_format(0, 1, "Sales", &header_format)?;
+    worksheet.write(1, 0, "North")?;
+    worksheet.write_with_format(1, 1, 12345.6, &currency_format)?;
+    worksheet.write(2, 0, "South")?;
+    worksheet.write_with_format(2, 1, 2345.75, &currency_format)?;
+
+    // Widen the columns to fit the data.
+    worksheet.set_column_range_width(0, 1, 12)?;
+
+    // Set up the printed page.
+    worksheet.set_paper_size(9);
+    worksheet.set_header("&CSales report");
+
+    // Save a preview of the worksheet.
+    worksheet.save_to_pdf("worksheet.pdf")?;
+
+    // Save the workbook.
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
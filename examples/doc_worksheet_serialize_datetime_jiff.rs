@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! Example of a serializable struct with a Jiff date value with a helper
+//! function.
+
+use jiff::civil::Date;
+use serde::Serialize;
+
+use rust_xlsxwriter::utility::serialize_jiff_to_excel;
+
+fn main() {
+    #[derive(Serialize)]
+    struct Student {
+        full_name: String,
+
+        #[serde(serialize_with = "serialize_jiff_to_excel")]
+        birth_date: Date,
+
+        id_number: u32,
+    }
+}
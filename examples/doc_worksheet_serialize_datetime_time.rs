@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! Example of a serializable struct with a `time` crate date value with a
+//! helper function.
+
+use serde::Serialize;
+use time::Date;
+
+use rust_xlsxwriter::utility::serialize_time_to_excel;
+
+fn main() {
+    #[derive(Serialize)]
+    struct Student {
+        full_name: String,
+
+        #[serde(serialize_with = "serialize_time_to_excel")]
+        birth_date: Date,
+
+        id_number: u32,
+    }
+}
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! Example of appending rows of data to a worksheet table.
+
+use rust_xlsxwriter::{Formula, Table, TableColumn, TableFunction, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a table with a formula column and a total row.
+    let columns = vec![
+        TableColumn::new()
+            .set_header("Q1")
+            .set_total_function(TableFunction::Sum),
+        TableColumn::new()
+            .set_header("Q2")
+            .set_total_function(TableFunction::Sum),
+        TableColumn::new()
+            .set_header("Half Year")
+            .set_formula(Formula::new("SUM(Sales[@[Q1]:[Q2]])"))
+            .set_total_function(TableFunction::Sum),
+    ];
+
+    let table = Table::new()
+        .set_name("Sales")
+        .set_columns(&columns)
+        .set_total_row(true);
+
+    // Add the table with the first row of data.
+    worksheet.add_table(0, 0, 2, 2, &table)?;
+    worksheet.write_row(1, 0, [10000, 5000])?;
+
+    // Append the remaining rows. The "Half Year" formula is added
+    // automatically.
+    worksheet.table_append_row("Sales", [2000, 3000])?;
+    worksheet.table_append_row("Sales", [6000, 6000])?;
+    worksheet.table_append_row("Sales", [500, 300])?;
+
+    // Set the column widths for clarity.
+    worksheet.set_column_range_width(0, 2, 12)?;
+
+    // Save the file to disk.
+    workbook.save("tables.xlsx")?;
+
+    Ok(())
+}
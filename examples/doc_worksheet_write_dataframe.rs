@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a Polars dataframe to a worksheet.
+
+use polars::prelude::*;
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a sample dataframe.
+    let df: DataFrame = df!(
+        "String" => &["North", "South", "East", "West"],
+        "Integer" => &[1, 2, 3, 4],
+        "Float" => &[4.0, 5.0, 6.0, 7.0],
+        "Boolean" => &[true, false, true, false],
+    )?;
+
+    // Write the dataframe to the worksheet.
+    worksheet.write_dataframe(&df, 0, 0)?;
+
+    // Save the file to disk.
+    workbook.save("dataframe.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a Polars dataframe with dates and
+//! null values to a worksheet table.
+
+use polars::prelude::*;
+use rust_xlsxwriter::{DataFrameOptions, Format, Table, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a sample dataframe with a date column and a null value.
+    let dates = Series::new("Date".into(), &[19_723, 19_724, 19_725]).cast(&DataType::Date)?;
+
+    let df: DataFrame = df!(
+        "Item" => &["Apples", "Pears", "Plums"],
+        "Quantity" => &[Some(10), None, Some(30)],
+        "Date" => dates,
+    )?;
+
+    // Set the dataframe options.
+    let options = DataFrameOptions::new()
+        .set_table(Table::new())
+        .set_header_format(Format::new().set_bold())
+        .set_dtype_format(DataType::Date, Format::new().set_num_format("dd/mm/yyyy"))
+        .set_null_value("N/A")
+        .set_autofit(true);
+
+    // Write the dataframe to the worksheet.
+    worksheet.write_dataframe_with_options(&df, 1, 1, &options)?;
+
+    // Save the file to disk.
+    workbook.save("dataframe.xlsx")?;
+
+    Ok(())
+}
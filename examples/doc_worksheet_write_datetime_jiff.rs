@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing formatted Jiff datetimes in an
+//! Excel worksheet.
+
+use jiff::civil;
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create some formats to use with the datetimes below.
+    let format1 = Format::new().set_num_format("dd/mm/yyyy hh:mm");
+    let format2 = Format::new().set_num_format("yyyy-mm-dd");
+    let format3 = Format::new().set_num_format("hh:mm:ss");
+
+    // Set the column width for clarity.
+    worksheet.set_column_width(0, 30)?;
+
+    // Create some date and time objects.
+    let date = civil::date(2023, 1, 25);
+    let time = civil::time(12, 30, 0, 0);
+    let datetime = date.to_datetime(time);
+
+    // Write the dates and times with different Excel formats.
+    worksheet.write_datetime_with_format(0, 0, datetime, &format1)?;
+    worksheet.write_datetime_with_format(1, 0, date, &format2)?;
+    worksheet.write_datetime_with_format(2, 0, time, &format3)?;
+
+    // Dates and times can also be written with the generic write() method.
+    worksheet.write_with_format(3, 0, datetime, &format1)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing an Arrow record batch to a
+//! worksheet.
+
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, BooleanArray, Date32Array, Int32Array, RecordBatch, StringArray};
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a sample record batch. The dates are days since 1970-01-01.
+    let strings: ArrayRef = Arc::new(StringArray::from(vec!["North", "South", "East"]));
+    let integers: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+    let booleans: ArrayRef = Arc::new(BooleanArray::from(vec![true, false, true]));
+    let dates: ArrayRef = Arc::new(Date32Array::from(vec![19_723, 19_724, 19_725]));
+
+    let batch = RecordBatch::try_from_iter(vec![
+        ("String", strings),
+        ("Integer", integers),
+        ("Boolean", booleans),
+        ("Date", dates),
+    ])?;
+
+    // Write the record batch to the worksheet.
+    worksheet.write_record_batch(&batch, 0, 0)?;
+
+    // Save the file to disk.
+    workbook.save("record_batch.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing several Arrow record batches to a
+//! worksheet.
+
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Float64Array, Int64Array, RecordBatch};
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // For large amounts of data you can use a worksheet created with
+    // `add_worksheet_with_constant_memory()` instead.
+    let worksheet = workbook.add_worksheet();
+
+    // Create some sample record batches.
+    let batches = (0..10).map(|batch_num| {
+        let ids: ArrayRef = Arc::new(Int64Array::from_iter_values(
+            (0..100).map(|id| batch_num * 100 + id),
+        ));
+        let values: ArrayRef = Arc::new(Float64Array::from_iter_values(
+            (0..100).map(|value| f64::from(value) / 10.0),
+        ));
+
+        RecordBatch::try_from_iter(vec![("Id", ids), ("Value", values)]).unwrap()
+    });
+
+    // Write the record batches to the worksheet.
+    worksheet.write_record_batches(batches, 0, 0)?;
+
+    // Save the file to disk.
+    workbook.save("record_batches.xlsx")?;
+
+    Ok(())
+}
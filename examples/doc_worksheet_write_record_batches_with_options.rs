@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing Arrow record batches with
+//! timestamps and null values to a worksheet table.
+
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray, TimestampSecondArray};
+use rust_xlsxwriter::{Format, RecordBatchOptions, Table, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create some sample record batches. The timestamps are in seconds
+    // since 1970-01-01 UTC and are converted to local New York time.
+    let mut batches = vec![];
+    for (items, quantities, timestamps) in [
+        (
+            ["Apples", "Pears"],
+            [Some(10), None],
+            [1_704_103_200, 1_704_189_600],
+        ),
+        (
+            ["Plums", "Kiwis"],
+            [Some(30), Some(40)],
+            [1_704_276_000, 1_704_362_400],
+        ),
+    ] {
+        let items: ArrayRef = Arc::new(StringArray::from(items.to_vec()));
+        let quantities: ArrayRef = Arc::new(Int32Array::from(quantities.to_vec()));
+        let timestamps: ArrayRef = Arc::new(
+            TimestampSecondArray::from(timestamps.to_vec()).with_timezone("America/New_York"),
+        );
+
+        batches.push(RecordBatch::try_from_iter(vec![
+            ("Item", items),
+            ("Quantity", quantities),
+            ("Delivery", timestamps),
+        ])?);
+    }
+
+    // Set the record batch options.
+    let options = RecordBatchOptions::new()
+        .set_table(Table::new())
+        .set_header_format(Format::new().set_bold())
+        .set_column_format("Delivery", Format::new().set_num_format("dd/mm/yyyy hh:mm"))
+        .set_null_value("N/A")
+        .set_autofit(true);
+
+    // Write the record batches to the worksheet.
+    worksheet.write_record_batches_with_options(&batches, 1, 1, &options)?;
+
+    // Save the file to disk.
+    workbook.save("record_batches.xlsx")?;
+
+    Ok(())
+}
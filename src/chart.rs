@@ -263,8 +263,10 @@
 //! - Radar
 //! - Stock
 //! - Scatter
+//! - Surface
 //!
-//! See [`ChartType`] for the full list and examples.
+//! Area, Bar, Column, Line and Pie charts also have 3D variants. See
+//! [`ChartType`] for the full list and examples.
 //!
 //! Support for newer Excel chart types such as Treemap, Sunburst, Box and
 //! Whisker, Statistical Histogram, Waterfall, Funnel, and Maps is not currently
@@ -721,6 +723,65 @@
 //!
 //! <img src="https://rustxlsxwriter.github.io/images/app_chart_pareto.png">
 //!
+//! More than two chart types can be combined by calling [`Chart::combine()`]
+//! for each additional chart. Alternatively, individual series can be assigned
+//! a different chart type via [`ChartSeries::set_chart_type()`] and they will
+//! be moved into a combined chart of that type when the chart is inserted into
+//! a worksheet:
+//!
+//! ```
+//! # // This code is available in examples/doc_chart_combine3.rs
+//! #
+//! # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+//! #
+//! # fn main() -> Result<(), XlsxError> {
+//! #     let mut workbook = Workbook::new();
+//! #     let worksheet = workbook.add_worksheet();
+//! #
+//! #     // Add the worksheet data that the charts will refer to.
+//! #     let data = [
+//! #         [2, 3, 4, 5, 6, 7],
+//! #         [10, 40, 50, 20, 10, 50],
+//! #         [30, 60, 70, 50, 40, 30],
+//! #         [20, 30, 40, 30, 20, 40],
+//! #     ];
+//! #     worksheet.write_column_matrix(0, 0, data)?;
+//! #
+//!     // Create a new Column chart. This will be the primary chart.
+//!     let mut column_chart = Chart::new(ChartType::Column);
+//!     column_chart
+//!         .add_series()
+//!         .set_categories("Sheet1!$A$1:$A$6")
+//!         .set_values("Sheet1!$B$1:$B$6");
+//!
+//!     // Create a Line chart on the secondary axis.
+//!     let mut line_chart = Chart::new(ChartType::Line);
+//!     line_chart
+//!         .add_series()
+//!         .set_categories("Sheet1!$A$1:$A$6")
+//!         .set_values("Sheet1!$C$1:$C$6")
+//!         .set_secondary_axis(true);
+//!
+//!     // Create an Area chart on the primary axis.
+//!     let mut area_chart = Chart::new(ChartType::Area);
+//!     area_chart
+//!         .add_series()
+//!         .set_categories("Sheet1!$A$1:$A$6")
+//!         .set_values("Sheet1!$D$1:$D$6");
+//!
+//!     // Combine the charts.
+//!     column_chart.combine(&line_chart).combine(&area_chart);
+//!
+//!     // Add the primary chart to the worksheet.
+//!     worksheet.insert_chart_with_offset(0, 4, &column_chart, 5, 5)?;
+//! #
+//! #     // Save the file to disk.
+//! #     workbook.save("chart.xlsx")?;
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
 //! The examples above use the concept of a *primary* and *secondary* chart. The
 //! primary chart is the chart that defines the primary X and Y axis. It is also
 //! used for setting all chart properties apart from the secondary data series.
@@ -730,7 +791,6 @@
 //! There are some limitations in the `rust_xlsxwriter` implementation of
 //! combined charts:
 //!
-//! - Only two charts can be combined.
 //! - Scatter charts cannot currently be used as a primary chart but they can be
 //!   used as a secondary chart.
 //! - Bar charts can only combine secondary charts on a secondary axis. This is
@@ -740,10 +800,14 @@
 
 mod tests;
 
+use std::collections::HashMap;
 use std::io::Cursor;
 use std::{fmt, mem, sync::OnceLock};
 
-use crate::drawing::{DrawingObject, DrawingType};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::drawing::{Drawing, DrawingCoordinates, DrawingInfo, DrawingObject, DrawingType};
 use crate::utility::{self, ToXmlBoolean};
 use crate::xmlwriter::{
     xml_data_element_only, xml_declaration, xml_empty_tag, xml_empty_tag_only, xml_end_tag,
@@ -751,7 +815,8 @@ use crate::xmlwriter::{
 };
 
 use crate::{
-    ColNum, Color, IntoExcelDateTime, ObjectMovement, RowNum, XlsxError, COL_MAX, ROW_MAX,
+    ColNum, Color, Image, IntoExcelDateTime, ObjectMovement, RowNum, Shape, XlsxError, COL_MAX,
+    ROW_MAX,
 };
 
 #[derive(Clone)]
@@ -833,11 +898,19 @@ pub struct Chart {
     pub(crate) y_axis: ChartAxis,
     pub(crate) x2_axis: ChartAxis,
     pub(crate) y2_axis: ChartAxis,
-    pub(crate) combined_chart: Option<Box<Chart>>,
+    pub(crate) z_axis: ChartAxis,
+    pub(crate) combined_charts: Vec<Chart>,
     pub(crate) chart_area: ChartArea,
     pub(crate) plot_area: ChartPlotArea,
+    pub(crate) floor: ChartWall,
+    pub(crate) side_wall: ChartWall,
+    pub(crate) back_wall: ChartWall,
     pub(crate) is_chartsheet: bool,
     pub(crate) protection_on: bool,
+    pub(crate) shapes: Vec<Shape>,
+    pub(crate) user_shapes_drawing_id: u32,
+    pub(crate) drawing_rel_ids: HashMap<String, u32>,
+    pub(crate) drawing_relationships: Vec<(String, String, String)>,
 
     legend: ChartLegend,
     grouping: ChartGrouping,
@@ -850,6 +923,10 @@ pub struct Chart {
     style: u8,
     hole_size: u8,
     rotation: u16,
+    elevation: i8,
+    perspective: Option<u8>,
+    depth_percent: u16,
+    right_angle_axes: bool,
     has_up_down_bars: bool,
     up_bar_format: ChartFormat,
     down_bar_format: ChartFormat,
@@ -861,6 +938,7 @@ pub struct Chart {
     series_index: usize,
     has_secondary_axis: bool,
     has_crosses: bool,
+    series_templates: Vec<ChartSeries>,
 }
 
 impl Chart {
@@ -953,9 +1031,13 @@ impl Chart {
             y_axis: ChartAxis::new(),
             x2_axis: ChartAxis::new(),
             y2_axis: ChartAxis::new(),
+            z_axis: ChartAxis::new(),
             legend: ChartLegend::new(),
             chart_area: ChartArea::default(),
             plot_area: ChartPlotArea::default(),
+            floor: ChartWall::default(),
+            side_wall: ChartWall::default(),
+            back_wall: ChartWall::default(),
             grouping: ChartGrouping::Standard,
             show_empty_cells_as: None,
             show_hidden_data: false,
@@ -966,6 +1048,10 @@ impl Chart {
             style: 2,
             hole_size: 50,
             rotation: 0,
+            elevation: 0,
+            perspective: None,
+            depth_percent: 100,
+            right_angle_axes: false,
             default_label_position: ChartDataLabelPosition::Default,
             has_up_down_bars: false,
             up_bar_format: ChartFormat::default(),
@@ -975,34 +1061,50 @@ impl Chart {
             has_drop_lines: false,
             drop_lines_format: ChartFormat::default(),
             table: None,
-            combined_chart: None,
+            combined_charts: vec![],
             series_index: 0,
             has_secondary_axis: false,
             has_crosses: true,
             is_chartsheet: false,
             protection_on: false,
+            shapes: vec![],
+            user_shapes_drawing_id: 0,
+            drawing_rel_ids: HashMap::new(),
+            drawing_relationships: vec![],
+            series_templates: vec![],
         };
 
-        match chart_type {
-            ChartType::Area | ChartType::AreaStacked | ChartType::AreaPercentStacked => {
-                Self::initialize_area_chart(chart)
-            }
-
-            ChartType::Bar | ChartType::BarStacked | ChartType::BarPercentStacked => {
-                Self::initialize_bar_chart(chart)
-            }
-
-            ChartType::Column | ChartType::ColumnStacked | ChartType::ColumnPercentStacked => {
-                Self::initialize_column_chart(chart)
-            }
+        let chart = match chart_type {
+            ChartType::Area
+            | ChartType::AreaStacked
+            | ChartType::AreaPercentStacked
+            | ChartType::Area3D
+            | ChartType::Area3DStacked
+            | ChartType::Area3DPercentStacked => Self::initialize_area_chart(chart),
+
+            ChartType::Bar
+            | ChartType::BarStacked
+            | ChartType::BarPercentStacked
+            | ChartType::Bar3D
+            | ChartType::Bar3DStacked
+            | ChartType::Bar3DPercentStacked => Self::initialize_bar_chart(chart),
+
+            ChartType::Column
+            | ChartType::ColumnStacked
+            | ChartType::ColumnPercentStacked
+            | ChartType::Column3D
+            | ChartType::Column3DStacked
+            | ChartType::Column3DPercentStacked
+            | ChartType::Column3DStandard => Self::initialize_column_chart(chart),
 
             ChartType::Doughnut => Self::initialize_doughnut_chart(chart),
 
-            ChartType::Line | ChartType::LineStacked | ChartType::LinePercentStacked => {
-                Self::initialize_line_chart(chart)
-            }
+            ChartType::Line
+            | ChartType::LineStacked
+            | ChartType::LinePercentStacked
+            | ChartType::Line3D => Self::initialize_line_chart(chart),
 
-            ChartType::Pie => Self::initialize_pie_chart(chart),
+            ChartType::Pie | ChartType::Pie3D => Self::initialize_pie_chart(chart),
 
             ChartType::Radar | ChartType::RadarWithMarkers | ChartType::RadarFilled => {
                 Self::initialize_radar_chart(chart)
@@ -1015,6 +1117,17 @@ impl Chart {
             | ChartType::ScatterSmoothWithMarkers => Self::initialize_scatter_chart(chart),
 
             ChartType::Stock => Self::initialize_stock_chart(chart),
+
+            ChartType::Surface3D
+            | ChartType::Surface3DWireframe
+            | ChartType::SurfaceContour
+            | ChartType::SurfaceContourWireframe => Self::initialize_surface_chart(chart),
+        };
+
+        if chart_type.is_3d() {
+            Self::initialize_3d_view(chart)
+        } else {
+            chart
         }
     }
 
@@ -1117,6 +1230,128 @@ impl Chart {
         Self::new(ChartType::Stock)
     }
 
+    /// Create a new `Chart` from a chart template.
+    ///
+    /// Create a new chart with the chart type, formatting and options stored
+    /// in a [`ChartTemplate`]. This is useful when a large number of charts
+    /// need to have the same appearance but display different data.
+    ///
+    /// The series formatting from the template is applied to the series
+    /// added via [`Chart::add_series()`], in the order that they were added
+    /// to the original chart. Only the data ranges, and the series names, need
+    /// to be added to the new chart. Any additional series that don't have a
+    /// template use the default formatting. Series added via
+    /// [`Chart::push_series()`] aren't affected by the template.
+    ///
+    /// # Parameters
+    ///
+    /// - `template`: A [`ChartTemplate`] created from a configured chart.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ChartError`] - The template was created by a version of
+    ///   `rust_xlsxwriter` with an unsupported template format.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates creating several charts with the same
+    /// formatting from a chart template.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_from_template.rs
+    /// #
+    /// # use rust_xlsxwriter::{
+    /// #     Chart, ChartFormat, ChartLegendPosition, ChartSolidFill, ChartTemplate, ChartType, Workbook,
+    /// #     XlsxError,
+    /// # };
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the charts.
+    /// #     let data = [[10, 40, 50], [20, 30, 60], [30, 25, 15], [40, 50, 30]];
+    /// #     for (row, row_data) in data.iter().enumerate() {
+    /// #         worksheet.write_row(row as u32, 0, *row_data)?;
+    /// #     }
+    /// #
+    ///     // Create and format a chart to use as a template.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///     chart.title().set_name("Quarterly results");
+    ///     chart.legend().set_position(ChartLegendPosition::Bottom);
+    ///     chart.y_axis().set_major_gridlines(false);
+    ///     chart
+    ///         .add_series()
+    ///         .set_format(ChartFormat::new().set_solid_fill(ChartSolidFill::new().set_color("#1F4E79")));
+    ///
+    ///     let template = ChartTemplate::new(&chart);
+    ///
+    ///     // Create charts with the same formatting for each column of data.
+    ///     for col in 0..3 {
+    ///         let mut chart = Chart::from_template(&template)?;
+    ///         chart.add_series().set_values(("Sheet1", 0, col, 3, col));
+    ///
+    ///         worksheet.insert_chart(col as u32 * 15 + 5, 0, &chart)?;
+    ///     }
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn from_template(template: &ChartTemplate) -> Result<Chart, XlsxError> {
+        if template.version != CHART_TEMPLATE_VERSION {
+            return Err(XlsxError::ChartError(format!(
+                "Unsupported chart template version: {}",
+                template.version
+            )));
+        }
+
+        let mut chart = Chart::new(template.chart_type);
+
+        chart.width = template.width;
+        chart.height = template.height;
+        chart.scale_width = template.scale_width;
+        chart.scale_height = template.scale_height;
+        chart.object_movement = template.object_movement;
+        chart.title = template.title.clone();
+        chart.legend = template.legend.clone();
+        chart.x_axis = template.x_axis.clone();
+        chart.y_axis = template.y_axis.clone();
+        chart.x2_axis = template.x2_axis.clone();
+        chart.y2_axis = template.y2_axis.clone();
+        chart.z_axis = template.z_axis.clone();
+        chart.chart_area = template.chart_area.clone();
+        chart.plot_area = template.plot_area.clone();
+        chart.floor = template.floor.clone();
+        chart.side_wall = template.side_wall.clone();
+        chart.back_wall = template.back_wall.clone();
+        chart.style = template.style;
+        chart.show_empty_cells_as = template.show_empty_cells_as;
+        chart.show_hidden_data = template.show_hidden_data;
+        chart.show_na_as_empty = template.show_na_as_empty;
+        chart.hole_size = template.hole_size;
+        chart.rotation = template.rotation;
+        chart.elevation = template.elevation;
+        chart.perspective = template.perspective;
+        chart.depth_percent = template.depth_percent;
+        chart.right_angle_axes = template.right_angle_axes;
+        chart.has_up_down_bars = template.has_up_down_bars;
+        chart.up_bar_format = template.up_bar_format.clone();
+        chart.down_bar_format = template.down_bar_format.clone();
+        chart.has_high_low_lines = template.has_high_low_lines;
+        chart.high_low_lines_format = template.high_low_lines_format.clone();
+        chart.has_drop_lines = template.has_drop_lines;
+        chart.drop_lines_format = template.drop_lines_format.clone();
+        chart.table = template.table.clone();
+        chart.shapes = template.shapes.clone();
+        chart.series_templates = template.series.clone();
+
+        Ok(chart)
+    }
+
     /// Create and add a new chart series to a chart.
     ///
     /// Create and add a new chart series to a chart. The chart series
@@ -1187,6 +1422,11 @@ impl Chart {
             series.marker = Some(ChartMarker::new().set_none().clone());
         }
 
+        // Use the series formatting from a chart template, if there is one.
+        if let Some(template_series) = self.series_templates.get(self.series.len()) {
+            series = template_series.clone();
+        }
+
         self.series.push(series);
 
         self.series.last_mut().unwrap()
@@ -1411,6 +1651,21 @@ impl Chart {
         &mut self.y2_axis
     }
 
+    /// Get the chart Z-Axis object in order to set its properties.
+    ///
+    /// Get a reference to the chart's Z-Axis [`ChartAxis`] object in order to
+    /// set its properties. The Z-Axis is the series (depth) axis of 3D charts
+    /// that display the series one behind the other such as
+    /// [`ChartType::Column3DStandard`], [`ChartType::Area3D`],
+    /// [`ChartType::Line3D`] and the Surface chart types. It is ignored for
+    /// other chart types.
+    ///
+    /// See the [`Chart::x_axis()`][Chart::x_axis] method above.
+    ///
+    pub fn z_axis(&mut self) -> &mut ChartAxis {
+        &mut self.z_axis
+    }
+
     /// Get the chart legend object in order to set its properties.
     ///
     /// Get a reference to the chart's [`ChartLegend`] object in order to set
@@ -1588,7 +1843,85 @@ impl Chart {
         &mut self.plot_area
     }
 
-    /// Create a combined chart from two different chart types.
+    /// Get the floor object of a 3D chart in order to set its properties.
+    ///
+    /// Get a reference to the floor [`ChartWall`] object of a 3D chart in order
+    /// to set its formatting. It is ignored for 2D charts and 3D Pie charts.
+    ///
+    /// # Examples
+    ///
+    /// An example of formatting the floor and walls of a 3D chart.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_floor.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartFormat, ChartSolidFill, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write_column(0, 0, [10, 40, 50, 20, 10])?;
+    /// #     worksheet.write_column(0, 1, [30, 60, 70, 50, 40])?;
+    /// #
+    ///     // Create a new 3D column chart.
+    ///     let mut chart = Chart::new(ChartType::Column3D);
+    ///
+    ///     // Add the data series.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$5");
+    ///     chart.add_series().set_values("Sheet1!$B$1:$B$5");
+    ///
+    ///     // Format the floor and walls of the chart.
+    ///     chart
+    ///         .floor()
+    ///         .set_format(ChartFormat::new().set_solid_fill(ChartSolidFill::new().set_color("#D9D9D9")));
+    ///     chart
+    ///         .back_wall()
+    ///         .set_format(ChartFormat::new().set_solid_fill(ChartSolidFill::new().set_color("#F2F2F2")));
+    ///     chart
+    ///         .side_wall()
+    ///         .set_format(ChartFormat::new().set_solid_fill(ChartSolidFill::new().set_color("#F2F2F2")));
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 3, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn floor(&mut self) -> &mut ChartWall {
+        &mut self.floor
+    }
+
+    /// Get the side wall object of a 3D chart in order to set its properties.
+    ///
+    /// Get a reference to the side wall [`ChartWall`] object of a 3D chart in
+    /// order to set its formatting. It is ignored for 2D charts and 3D Pie
+    /// charts.
+    ///
+    /// See the [`Chart::floor()`][Chart::floor] method above.
+    ///
+    pub fn side_wall(&mut self) -> &mut ChartWall {
+        &mut self.side_wall
+    }
+
+    /// Get the back wall object of a 3D chart in order to set its properties.
+    ///
+    /// Get a reference to the back wall [`ChartWall`] object of a 3D chart in
+    /// order to set its formatting. It is ignored for 2D charts and 3D Pie
+    /// charts.
+    ///
+    /// See the [`Chart::floor()`][Chart::floor] method above.
+    ///
+    pub fn back_wall(&mut self) -> &mut ChartWall {
+        &mut self.back_wall
+    }
+
+    /// Create a combined chart from two or more different chart types.
     ///
     /// In Excel is also possible to combine two different chart types, for
     /// example a column and line chart to create a Pareto chart. In
@@ -1597,8 +1930,13 @@ impl Chart {
     /// different type and combine it with the primary chart using the
     /// `Chart::combine()` method.
     ///
-    /// The combined secondary chart can share the same Y axis as the primary
-    /// chart or it can use a secondary Y2 axis. An example of each is shown
+    /// The method can be called more than once to combine several chart types,
+    /// for example a column, line and area chart. Individual series can also be
+    /// displayed as a different chart type via
+    /// [`ChartSeries::set_chart_type()`].
+    ///
+    /// The combined secondary charts can share the same Y axis as the primary
+    /// chart or they can use a secondary Y2 axis. An example of each is shown
     /// below.
     ///
     /// See [Combined Charts](crate::chart#combined-charts) for additional
@@ -1737,7 +2075,12 @@ impl Chart {
     ///
     ///
     pub fn combine(&mut self, chart: &Chart) -> &mut Chart {
-        self.combined_chart = Some(Box::new(chart.clone()));
+        let mut chart = chart.clone();
+
+        // Flatten any charts that are combined with the combined chart.
+        let nested_charts = mem::take(&mut chart.combined_charts);
+        self.combined_charts.push(chart);
+        self.combined_charts.extend(nested_charts);
 
         self
     }
@@ -1826,16 +2169,23 @@ impl Chart {
         self
     }
 
-    /// Set the Pie/Doughnut chart rotation.
+    /// Set the Pie/Doughnut or 3D chart rotation.
     ///
     /// The `set_rotation()` method is used to set the rotation of the first
     /// segment of a Pie/Doughnut chart. This has the effect of rotating the
     /// entire chart.
     ///
+    /// For 3D charts, such as [`ChartType::Column3D`] or
+    /// [`ChartType::Surface3D`], it sets the horizontal rotation of the 3D
+    /// view around the vertical axis. See also [`Chart::set_elevation()`] and
+    /// [`Chart::set_perspective()`].
+    ///
     /// # Parameters
     ///
-    /// - `rotation`: The rotation of the first segment of a Pie/Doughnut chart.
-    ///   The range is 0 <= rotation <= 360 and the default is 0.
+    /// - `rotation`: The rotation of the first segment of a Pie/Doughnut chart,
+    ///   or the rotation of a 3D chart. The range is 0 <= rotation <= 360. The
+    ///   default is 0 for Pie/Doughnut and 3D Pie charts and 20 for other 3D
+    ///   charts.
     ///
     ///
     /// # Examples
@@ -1887,6 +2237,130 @@ impl Chart {
         self
     }
 
+    /// Set the elevation of a 3D chart.
+    ///
+    /// The `set_elevation()` method is used to set the vertical rotation of
+    /// the 3D view of a chart, i.e., the angle that the chart is viewed from
+    /// above or below. It only applies to 3D chart types such as
+    /// [`ChartType::Column3D`] or [`ChartType::Surface3D`].
+    ///
+    /// # Parameters
+    ///
+    /// - `elevation`: The elevation angle in degrees. The range is -90 <=
+    ///   elevation <= 90. The default is 15 for most 3D charts, 30 for 3D Pie
+    ///   charts and 90 for Contour charts. 3D Pie charts only support values
+    ///   in the range 10 <= elevation <= 90.
+    ///
+    /// # Examples
+    ///
+    /// An example of setting the 3D view properties of a 3D chart.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_set_elevation.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write_column(0, 0, [10, 40, 50, 20, 10])?;
+    /// #     worksheet.write_column(0, 1, [30, 60, 70, 50, 40])?;
+    /// #     worksheet.write_column(0, 2, [20, 30, 40, 60, 50])?;
+    /// #
+    ///     // Create a new 3D column chart with a series (depth) axis.
+    ///     let mut chart = Chart::new(ChartType::Column3DStandard);
+    ///
+    ///     // Add the data series.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$5");
+    ///     chart.add_series().set_values("Sheet1!$B$1:$B$5");
+    ///     chart.add_series().set_values("Sheet1!$C$1:$C$5");
+    ///
+    ///     // Set the 3D view properties of the chart.
+    ///     chart.set_elevation(25).set_rotation(40).set_perspective(20);
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 4, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_elevation(&mut self, elevation: i8) -> &mut Chart {
+        if (-90..=90).contains(&elevation) {
+            self.elevation = elevation;
+        } else {
+            eprintln!("Elevation '{elevation}' outside Excel range: -90 <= elevation <= 90.");
+        }
+
+        self
+    }
+
+    /// Set the perspective of a 3D chart.
+    ///
+    /// The `set_perspective()` method is used to set the field of view of the
+    /// 3D view of a chart. Higher values increase the apparent depth of the
+    /// chart. It only applies to 3D chart types.
+    ///
+    /// Excel ignores the perspective when the chart has right angle axes. This
+    /// method turns them off, see [`Chart::set_right_angle_axes()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `perspective`: The field of view angle in degrees. The range is 0 <=
+    ///   perspective <= 120. The Excel default is 15.
+    ///
+    pub fn set_perspective(&mut self, perspective: u8) -> &mut Chart {
+        if perspective <= 120 {
+            self.perspective = Some(perspective * 2);
+            self.right_angle_axes = false;
+        } else {
+            eprintln!("Perspective '{perspective}' outside Excel range: 0 <= perspective <= 120.");
+        }
+
+        self
+    }
+
+    /// Set right angle axes for a 3D chart.
+    ///
+    /// The `set_right_angle_axes()` method is used to display the axes of a
+    /// 3D chart at right angles, independent of the chart rotation and
+    /// elevation. This is the default for clustered and stacked 3D Bar,
+    /// Column and Area charts.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off.
+    ///
+    pub fn set_right_angle_axes(&mut self, enable: bool) -> &mut Chart {
+        self.right_angle_axes = enable;
+        self
+    }
+
+    /// Set the depth of a 3D chart.
+    ///
+    /// The `set_depth_percent()` method is used to set the depth of a 3D chart
+    /// as a percentage of the chart width.
+    ///
+    /// # Parameters
+    ///
+    /// - `depth`: The chart depth as a percentage. The range is 20 <= depth <=
+    ///   2000 and the default is 100.
+    ///
+    pub fn set_depth_percent(&mut self, depth: u16) -> &mut Chart {
+        if (20..=2000).contains(&depth) {
+            self.depth_percent = depth;
+        } else {
+            eprintln!("Depth '{depth}' outside Excel range: 20 <= depth <= 2000.");
+        }
+
+        self
+    }
+
     /// Set the hole size for a Doughnut chart.
     ///
     /// Set the center hole size for a Doughnut chart.
@@ -2584,6 +3058,77 @@ impl Chart {
         self
     }
 
+    /// Insert a shape, such as a textbox or callout, into the chart.
+    ///
+    /// Shapes inserted into a chart are stored as part of the chart rather than
+    /// the worksheet so they move and resize with the chart. This is useful
+    /// for adding annotations or labels to a chart.
+    ///
+    /// The position of the shape is given in pixels relative to the top left
+    /// corner of the chart. Excel stores it as a fraction of the chart size so
+    /// it scales with the chart if the chart is resized.
+    ///
+    /// Note, hyperlinks, picture fills and connections between shapes aren't
+    /// supported within charts. Shapes with a hyperlink or a picture fill
+    /// return an error when the chart is validated, see [`Chart::validate()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `shape`: The [`Shape`] to insert into the chart.
+    /// - `x_offset`: The horizontal offset within the chart in pixels.
+    /// - `y_offset`: The vertical offset within the chart in pixels.
+    ///
+    /// # Examples
+    ///
+    /// This example demonstrates adding a callout shape to annotate a chart.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_insert_shape.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Shape, ShapeType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 50)?;
+    /// #     worksheet.write(1, 0, 30)?;
+    /// #     worksheet.write(2, 0, 40)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///
+    ///     // Add a data series using Excel formula syntax to describe the range.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$3");
+    ///
+    ///     // Add a callout shape to annotate the chart.
+    ///     let shape = Shape::new(ShapeType::RectangularCallout)
+    ///         .set_text("Peak value")
+    ///         .set_width(96)
+    ///         .set_height(40);
+    ///
+    ///     chart.insert_shape(&shape, 100, 20);
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn insert_shape(&mut self, shape: &Shape, x_offset: u32, y_offset: u32) -> &mut Chart {
+        let mut shape = shape.clone();
+        shape.x_offset = x_offset;
+        shape.y_offset = y_offset;
+
+        self.shapes.push(shape);
+        self
+    }
+
     /// Set a user defined name for a chart.
     ///
     /// By default Excel names charts as "Chart 1", "Chart 2", etc. This name
@@ -2738,6 +3283,46 @@ impl Chart {
                     ));
                 }
             }
+
+            // Check for 3D charts with secondary axes, which Excel doesn't support.
+            if self.chart_type.is_3d() && series.secondary_axis {
+                return Err(XlsxError::ChartError(
+                    "3D and Surface charts don't support secondary axes".to_string(),
+                ));
+            }
+        }
+
+        // Check for 3D charts in combined charts, which Excel doesn't support.
+        // This includes series that have been assigned a 3D chart type.
+        if (self.chart_type.is_3d() && !self.combined_charts.is_empty())
+            || self
+                .combined_charts
+                .iter()
+                .any(|chart| chart.chart_type.is_3d())
+            || self.series.iter().any(|series| {
+                series.chart_type.is_some_and(|chart_type| {
+                    chart_type != self.chart_type && (chart_type.is_3d() || self.chart_type.is_3d())
+                })
+            })
+        {
+            return Err(XlsxError::ChartError(
+                "3D and Surface charts cannot be combined with other charts".to_string(),
+            ));
+        }
+
+        // Check for shapes with properties that aren't supported in charts.
+        for shape in &self.shapes {
+            if shape.url.is_some() {
+                return Err(XlsxError::ChartError(
+                    "Shapes inserted into a chart don't support hyperlinks".to_string(),
+                ));
+            }
+
+            if shape.format.picture_fill.is_some() {
+                return Err(XlsxError::ChartError(
+                    "Shapes inserted into a chart don't support picture fills".to_string(),
+                ));
+            }
         }
 
         Ok(self)
@@ -2822,13 +3407,19 @@ impl Chart {
         let axis_id = (5000 + chart_id) * 10000 + 1;
         self.axis_ids = (axis_id, axis_id + 1);
 
-        if self.combined_chart.is_none() {
+        if self.combined_charts.is_empty() {
             self.axis2_ids = (axis_id + 2, axis_id + 3);
         } else {
             self.axis2_ids = (axis_id + 10_000_000, axis_id + 10_000_001);
         }
     }
 
+    // Get the axis id of the series axis of 3D charts. It is the next free id
+    // after the primary and secondary axis ids.
+    fn series_axis_id(&self) -> u32 {
+        self.axis_ids.0 + 4
+    }
+
     // Check for any legend entries that have been hidden/deleted via the
     // ChartSeries::delete_from_legend() and
     // ChartTrendline::delete_from_legend() methods. These can in turn be
@@ -2867,10 +3458,10 @@ impl Chart {
         deleted_entries
     }
 
-    // Check if the primary chart or optional combined chart have a secondary
+    // Check if the primary chart or optional combined charts have a secondary
     // data series.
     fn check_for_secondary_axis(&mut self) {
-        if let Some(combined_chart) = &self.combined_chart {
+        for combined_chart in &self.combined_charts {
             for series in &combined_chart.series {
                 if series.secondary_axis {
                     self.has_secondary_axis = true;
@@ -2886,6 +3477,54 @@ impl Chart {
         }
     }
 
+    // Move any series that have been assigned a different chart type via
+    // ChartSeries::set_chart_type() into a combined chart of that type. A new
+    // default combined chart is created if there isn't one of that type.
+    pub(crate) fn group_series_by_chart_type(&mut self) {
+        let mut moved_series = vec![];
+
+        let chart_type = self.chart_type;
+        let (series, moved): (Vec<ChartSeries>, Vec<ChartSeries>) = mem::take(&mut self.series)
+            .into_iter()
+            .partition(|series| series.chart_type.unwrap_or(chart_type) == chart_type);
+        self.series = series;
+        moved_series.extend(moved);
+
+        for combined_chart in &mut self.combined_charts {
+            let chart_type = combined_chart.chart_type;
+            let (series, moved): (Vec<ChartSeries>, Vec<ChartSeries>) =
+                mem::take(&mut combined_chart.series)
+                    .into_iter()
+                    .partition(|series| series.chart_type.unwrap_or(chart_type) == chart_type);
+            combined_chart.series = series;
+            moved_series.extend(moved);
+        }
+
+        for series in moved_series {
+            let Some(chart_type) = series.chart_type else {
+                continue;
+            };
+
+            if chart_type == self.chart_type {
+                self.series.push(series);
+                continue;
+            }
+
+            match self
+                .combined_charts
+                .iter_mut()
+                .find(|chart| chart.chart_type == chart_type)
+            {
+                Some(combined_chart) => combined_chart.series.push(series),
+                None => {
+                    let mut combined_chart = Chart::new(chart_type);
+                    combined_chart.series.push(series);
+                    self.combined_charts.push(combined_chart);
+                }
+            }
+        }
+    }
+
     // -----------------------------------------------------------------------
     // Chart specific methods.
     // -----------------------------------------------------------------------
@@ -2914,11 +3553,15 @@ impl Chart {
 
         self.chart_group_type = ChartType::Area;
 
-        if self.chart_type == ChartType::Area {
+        if self.chart_type == ChartType::Area || self.chart_type == ChartType::Area3D {
             self.grouping = ChartGrouping::Standard;
-        } else if self.chart_type == ChartType::AreaStacked {
+        } else if self.chart_type == ChartType::AreaStacked
+            || self.chart_type == ChartType::Area3DStacked
+        {
             self.grouping = ChartGrouping::Stacked;
-        } else if self.chart_type == ChartType::AreaPercentStacked {
+        } else if self.chart_type == ChartType::AreaPercentStacked
+            || self.chart_type == ChartType::Area3DPercentStacked
+        {
             self.grouping = ChartGrouping::PercentStacked;
             self.default_num_format = "0%".to_string();
         }
@@ -2953,12 +3596,16 @@ impl Chart {
 
         self.chart_group_type = ChartType::Bar;
 
-        if self.chart_type == ChartType::Bar {
+        if self.chart_type == ChartType::Bar || self.chart_type == ChartType::Bar3D {
             self.grouping = ChartGrouping::Clustered;
-        } else if self.chart_type == ChartType::BarStacked {
+        } else if self.chart_type == ChartType::BarStacked
+            || self.chart_type == ChartType::Bar3DStacked
+        {
             self.grouping = ChartGrouping::Stacked;
             self.overlap = Some(100);
-        } else if self.chart_type == ChartType::BarPercentStacked {
+        } else if self.chart_type == ChartType::BarPercentStacked
+            || self.chart_type == ChartType::Bar3DPercentStacked
+        {
             self.grouping = ChartGrouping::PercentStacked;
             self.default_num_format = "0%".to_string();
             self.overlap = Some(100);
@@ -2988,12 +3635,18 @@ impl Chart {
 
         self.chart_group_type = ChartType::Column;
 
-        if self.chart_type == ChartType::Column {
+        if self.chart_type == ChartType::Column || self.chart_type == ChartType::Column3D {
             self.grouping = ChartGrouping::Clustered;
-        } else if self.chart_type == ChartType::ColumnStacked {
+        } else if self.chart_type == ChartType::Column3DStandard {
+            self.grouping = ChartGrouping::Standard;
+        } else if self.chart_type == ChartType::ColumnStacked
+            || self.chart_type == ChartType::Column3DStacked
+        {
             self.grouping = ChartGrouping::Stacked;
             self.overlap = Some(100);
-        } else if self.chart_type == ChartType::ColumnPercentStacked {
+        } else if self.chart_type == ChartType::ColumnPercentStacked
+            || self.chart_type == ChartType::Column3DPercentStacked
+        {
             self.grouping = ChartGrouping::PercentStacked;
             self.default_num_format = "0%".to_string();
             self.overlap = Some(100);
@@ -3033,7 +3686,7 @@ impl Chart {
 
         self.chart_group_type = ChartType::Line;
 
-        if self.chart_type == ChartType::Line {
+        if self.chart_type == ChartType::Line || self.chart_type == ChartType::Line3D {
             self.grouping = ChartGrouping::Standard;
         } else if self.chart_type == ChartType::LineStacked {
             self.grouping = ChartGrouping::Stacked;
@@ -3131,6 +3784,62 @@ impl Chart {
         self
     }
 
+    // Initialize surface and contour charts.
+    fn initialize_surface_chart(mut self) -> Chart {
+        self.x_axis.axis_type = ChartAxisType::Category;
+        self.x_axis.axis_position = ChartAxisPosition::Bottom;
+        self.x_axis.position_between_ticks = false;
+
+        self.y_axis.axis_type = ChartAxisType::Value;
+        self.y_axis.axis_position = ChartAxisPosition::Left;
+        self.y_axis.title.is_horizontal = true;
+        self.y_axis.major_gridlines = true;
+
+        // The series axis isn't displayed for contour charts.
+        if self.chart_type == ChartType::SurfaceContour
+            || self.chart_type == ChartType::SurfaceContourWireframe
+        {
+            self.z_axis.is_hidden = true;
+        }
+
+        self.chart_group_type = ChartType::Surface3D;
+
+        self
+    }
+
+    // Initialize the 3D view properties that are common to all 3D charts.
+    fn initialize_3d_view(mut self) -> Chart {
+        self.z_axis.axis_type = ChartAxisType::Category;
+        self.z_axis.axis_position = ChartAxisPosition::Bottom;
+
+        match self.chart_type {
+            ChartType::Pie3D => {
+                self.elevation = 30;
+                self.rotation = 0;
+            }
+            ChartType::SurfaceContour | ChartType::SurfaceContourWireframe => {
+                self.elevation = 90;
+                self.rotation = 0;
+                self.perspective = Some(0);
+            }
+            ChartType::Area3D
+            | ChartType::Column3DStandard
+            | ChartType::Line3D
+            | ChartType::Surface3D
+            | ChartType::Surface3DWireframe => {
+                self.elevation = 15;
+                self.rotation = 20;
+            }
+            _ => {
+                self.elevation = 15;
+                self.rotation = 20;
+                self.right_angle_axes = true;
+            }
+        }
+
+        self
+    }
+
     // Write the <c:areaChart> element for Column charts.
     fn write_area_chart(&mut self, primary_axis: bool) {
         let series = self.get_series(primary_axis);
@@ -3139,7 +3848,13 @@ impl Chart {
             return;
         }
 
-        xml_start_tag_only(&mut self.writer, "c:areaChart");
+        let tag = if self.chart_type.is_3d() {
+            "c:area3DChart"
+        } else {
+            "c:areaChart"
+        };
+
+        xml_start_tag_only(&mut self.writer, tag);
 
         // Write the c:grouping element.
         self.write_grouping();
@@ -3155,7 +3870,7 @@ impl Chart {
         // Write the c:axId elements.
         self.write_ax_ids(primary_axis);
 
-        xml_end_tag(&mut self.writer, "c:areaChart");
+        xml_end_tag(&mut self.writer, tag);
     }
 
     // Write the <c:barChart> element for Bar charts.
@@ -3166,7 +3881,13 @@ impl Chart {
             return;
         }
 
-        xml_start_tag_only(&mut self.writer, "c:barChart");
+        let tag = if self.chart_type.is_3d() {
+            "c:bar3DChart"
+        } else {
+            "c:barChart"
+        };
+
+        xml_start_tag_only(&mut self.writer, tag);
 
         // Write the c:barDir element.
         self.write_bar_dir("bar");
@@ -3182,13 +3903,18 @@ impl Chart {
             self.write_gap_width(self.gap);
         }
 
-        // Write the c:overlap element.
-        self.write_overlap();
+        if self.chart_type.is_3d() {
+            // Write the c:shape element.
+            self.write_shape();
+        } else {
+            // Write the c:overlap element.
+            self.write_overlap();
+        }
 
         // Write the c:axId elements.
         self.write_ax_ids(primary_axis);
 
-        xml_end_tag(&mut self.writer, "c:barChart");
+        xml_end_tag(&mut self.writer, tag);
     }
 
     // Write the <c:barChart> element for Column charts.
@@ -3199,7 +3925,13 @@ impl Chart {
             return;
         }
 
-        xml_start_tag_only(&mut self.writer, "c:barChart");
+        let tag = if self.chart_type.is_3d() {
+            "c:bar3DChart"
+        } else {
+            "c:barChart"
+        };
+
+        xml_start_tag_only(&mut self.writer, tag);
 
         // Write the c:barDir element.
         self.write_bar_dir("col");
@@ -3215,13 +3947,18 @@ impl Chart {
             self.write_gap_width(self.gap);
         }
 
-        // Write the c:overlap element.
-        self.write_overlap();
+        if self.chart_type.is_3d() {
+            // Write the c:shape element.
+            self.write_shape();
+        } else {
+            // Write the c:overlap element.
+            self.write_overlap();
+        }
 
         // Write the c:axId elements.
         self.write_ax_ids(primary_axis);
 
-        xml_end_tag(&mut self.writer, "c:barChart");
+        xml_end_tag(&mut self.writer, tag);
     }
 
     // Write the <c:doughnutChart> element for Column charts.
@@ -3257,7 +3994,14 @@ impl Chart {
             return;
         }
 
-        xml_start_tag_only(&mut self.writer, "c:lineChart");
+        let is_3d = self.chart_type.is_3d();
+        let tag = if is_3d {
+            "c:line3DChart"
+        } else {
+            "c:lineChart"
+        };
+
+        xml_start_tag_only(&mut self.writer, tag);
 
         // Write the c:grouping element.
         self.write_grouping();
@@ -3270,23 +4014,26 @@ impl Chart {
             self.write_drop_lines();
         }
 
-        if self.has_high_low_lines {
-            // Write the c:hiLowLines element.
-            self.write_hi_low_lines();
-        }
+        // 3D line charts don't support high-low lines, up-down bars or markers.
+        if !is_3d {
+            if self.has_high_low_lines {
+                // Write the c:hiLowLines element.
+                self.write_hi_low_lines();
+            }
 
-        // Write the c:upDownBars element.
-        if self.has_up_down_bars {
-            self.write_up_down_bars();
-        }
+            // Write the c:upDownBars element.
+            if self.has_up_down_bars {
+                self.write_up_down_bars();
+            }
 
-        // Write the c:marker element.
-        self.write_marker_value();
+            // Write the c:marker element.
+            self.write_marker_value();
+        }
 
         // Write the c:axId elements.
         self.write_ax_ids(primary_axis);
 
-        xml_end_tag(&mut self.writer, "c:lineChart");
+        xml_end_tag(&mut self.writer, tag);
     }
 
     // Write the <c:pieChart> element for Column charts.
@@ -3297,7 +4044,10 @@ impl Chart {
             return;
         }
 
-        xml_start_tag_only(&mut self.writer, "c:pieChart");
+        let is_3d = self.chart_type.is_3d();
+        let tag = if is_3d { "c:pie3DChart" } else { "c:pieChart" };
+
+        xml_start_tag_only(&mut self.writer, tag);
 
         // Write the c:varyColors element.
         self.write_vary_colors();
@@ -3305,10 +4055,43 @@ impl Chart {
         // Write the c:ser elements.
         self.write_series(&series);
 
-        // Write the c:firstSliceAng element.
-        self.write_first_slice_ang();
+        // Write the c:firstSliceAng element. The rotation of 3D pie charts is
+        // set in the c:view3D element instead.
+        if !is_3d {
+            self.write_first_slice_ang();
+        }
+
+        xml_end_tag(&mut self.writer, tag);
+    }
+
+    // Write the <c:surfaceChart> or <c:surface3DChart> element.
+    fn write_surface_chart(&mut self, primary_axis: bool) {
+        let series = self.get_series(primary_axis);
+
+        if series.is_empty() {
+            return;
+        }
+
+        let tag = match self.chart_type {
+            ChartType::SurfaceContour | ChartType::SurfaceContourWireframe => "c:surfaceChart",
+            _ => "c:surface3DChart",
+        };
+
+        xml_start_tag_only(&mut self.writer, tag);
+
+        // Write the c:wireframe element.
+        self.write_wireframe();
+
+        // Write the c:ser elements.
+        self.write_series(&series);
 
-        xml_end_tag(&mut self.writer, "c:pieChart");
+        // Write the c:bandFmts element.
+        xml_empty_tag_only(&mut self.writer, "c:bandFmts");
+
+        // Write the c:axId elements.
+        self.write_ax_ids(primary_axis);
+
+        xml_end_tag(&mut self.writer, tag);
     }
 
     // Write the <c:radarChart>element.
@@ -3424,10 +4207,162 @@ impl Chart {
             self.write_print_settings();
         }
 
+        // Write the c:userShapes element.
+        if !self.shapes.is_empty() {
+            self.write_user_shapes();
+        }
+
         // Close the c:chartSpace tag.
         xml_end_tag(&mut self.writer, "c:chartSpace");
     }
 
+    // Get the images used in picture fills in the chart, in the order that
+    // they are written to the chart file.
+    pub(crate) fn picture_fill_images(&self) -> Vec<&Image> {
+        let mut formats = vec![
+            &self.chart_area.format,
+            &self.plot_area.format,
+            &self.title.format,
+            &self.legend.format,
+            &self.floor.format,
+            &self.side_wall.format,
+            &self.back_wall.format,
+            &self.up_bar_format,
+            &self.down_bar_format,
+        ];
+
+        for axis in [
+            &self.x_axis,
+            &self.y_axis,
+            &self.x2_axis,
+            &self.y2_axis,
+            &self.z_axis,
+        ] {
+            formats.push(&axis.format);
+            formats.push(&axis.title.format);
+        }
+
+        if let Some(table) = &self.table {
+            formats.push(&table.format);
+        }
+
+        for series in self
+            .series
+            .iter()
+            .chain(self.combined_charts.iter().flat_map(|chart| &chart.series))
+        {
+            formats.push(&series.format);
+            formats.push(&series.title.format);
+
+            if let Some(marker) = &series.marker {
+                formats.push(&marker.format);
+            }
+
+            if let Some(data_label) = &series.data_label {
+                formats.push(&data_label.format);
+            }
+
+            formats.extend(series.custom_data_labels.iter().map(|label| &label.format));
+            formats.extend(series.points.iter().map(|point| &point.format));
+        }
+
+        formats
+            .into_iter()
+            .filter_map(|format| format.picture_fill.as_ref())
+            .map(|fill| &fill.image)
+            .collect()
+    }
+
+    // Get the worksheet cell ranges referred to by the chart, including any
+    // combined charts. Literal data ranges aren't included.
+    pub(crate) fn cell_ranges(&self) -> Vec<&ChartRange> {
+        let mut ranges = vec![];
+
+        for chart in std::iter::once(self).chain(&self.combined_charts) {
+            ranges.push(&chart.title.range);
+            ranges.push(&chart.x_axis.title.range);
+            ranges.push(&chart.y_axis.title.range);
+        }
+
+        for series in self
+            .series
+            .iter()
+            .chain(self.combined_charts.iter().flat_map(|chart| &chart.series))
+        {
+            ranges.push(&series.title.range);
+            ranges.push(&series.value_range);
+            ranges.push(&series.category_range);
+
+            ranges.extend(
+                series
+                    .custom_data_labels
+                    .iter()
+                    .map(|label| &label.title.range),
+            );
+
+            for error_bars in [&series.y_error_bars, &series.x_error_bars]
+                .into_iter()
+                .flatten()
+            {
+                ranges.push(&error_bars.plus_range);
+                ranges.push(&error_bars.minus_range);
+            }
+        }
+
+        ranges
+            .into_iter()
+            .filter(|range| range.has_data() && !range.is_literal)
+            .collect()
+    }
+
+    // Create the chartDrawing part used to store the shapes inserted into the
+    // chart. The shape positions are stored as a fraction of the chart size.
+    pub(crate) fn user_shapes_drawing(&self) -> Drawing {
+        let mut drawing = Drawing::new();
+        drawing.is_chart_drawing = true;
+
+        let chart_width = self.width * self.scale_width;
+        let chart_height = self.height * self.scale_height;
+
+        for shape in &self.shapes {
+            let x = f64::from(shape.x_offset);
+            let y = f64::from(shape.y_offset);
+
+            let from = DrawingCoordinates {
+                col: 0,
+                row: 0,
+                col_offset: x / chart_width,
+                row_offset: y / chart_height,
+            };
+
+            let to = DrawingCoordinates {
+                col: 0,
+                row: 0,
+                col_offset: (x + shape.width) / chart_width,
+                row_offset: (y + shape.height) / chart_height,
+            };
+
+            let drawing_info = DrawingInfo {
+                from,
+                to,
+                col_absolute: (x * 9525.0).round() as u64,
+                row_absolute: (y * 9525.0).round() as u64,
+                width: (shape.width * 9525.0).round(),
+                height: (shape.height * 9525.0).round(),
+                name: shape.text.clone(),
+                description: shape.alt_text.clone(),
+                decorative: shape.decorative,
+                drawing_type: DrawingType::Shape,
+                ..Default::default()
+            };
+
+            drawing.drawings.push(drawing_info);
+            drawing.shapes.push(shape.clone());
+        }
+
+        drawing
+    }
+
     // Write the <c:chartSpace> element.
     fn write_chart_space(&mut self) {
         let attributes = [
@@ -3448,6 +4383,13 @@ impl Chart {
         xml_start_tag(&mut self.writer, "c:chartSpace", &attributes);
     }
 
+    // Write the <c:userShapes> element.
+    fn write_user_shapes(&mut self) {
+        let attributes = [("r:id", "rId1")];
+
+        xml_empty_tag(&mut self.writer, "c:userShapes", &attributes);
+    }
+
     // Write the <c:lang> element.
     fn write_lang(&mut self) {
         let attributes = [("val", "en-US")];
@@ -3466,6 +4408,23 @@ impl Chart {
             self.write_chart_title(&self.title.clone());
         }
 
+        if self.chart_type.is_3d() {
+            // Write the c:view3D element.
+            self.write_view_3d();
+
+            // 3D Pie charts don't have a floor or walls.
+            if self.chart_type != ChartType::Pie3D {
+                // Write the c:floor element.
+                self.write_wall("c:floor", &self.floor.format.clone());
+
+                // Write the c:sideWall element.
+                self.write_wall("c:sideWall", &self.side_wall.format.clone());
+
+                // Write the c:backWall element.
+                self.write_wall("c:backWall", &self.back_wall.format.clone());
+            }
+        }
+
         // Write the c:plotArea element.
         self.write_plot_area();
 
@@ -3519,11 +4478,17 @@ impl Chart {
         // Write the <c:xxxChart> element for each chart type.
         self.write_chart_type();
 
-        // Write the combined chart.
-        if let Some(combined_chart) = &mut self.combined_chart {
+        // Write the combined charts.
+        let mut series_index = self.series.len();
+        for combined_chart in &mut self.combined_charts {
             combined_chart.axis_ids = self.axis_ids;
             combined_chart.axis2_ids = self.axis2_ids;
-            combined_chart.series_index = self.series.len();
+            combined_chart.series_index = series_index;
+            series_index += combined_chart.series.len();
+
+            combined_chart
+                .drawing_rel_ids
+                .clone_from(&self.drawing_rel_ids);
 
             mem::swap(&mut combined_chart.writer, &mut self.writer);
             combined_chart.write_chart_type();
@@ -3559,6 +4524,11 @@ impl Chart {
 
                 // Write the c:valAx element.
                 self.write_val_ax(&x_axis, &y_axis, self.axis_ids);
+
+                // Write the c:serAx element.
+                if self.chart_type.has_series_axis() {
+                    self.write_ser_ax(&self.z_axis.clone());
+                }
             }
         }
 
@@ -3568,11 +4538,18 @@ impl Chart {
             let mut x_axis = self.x2_axis.clone();
             let mut y_axis = self.y2_axis.clone();
             let mut chart_group_type = self.chart_group_type;
-            let mut is_combined = false;
+            let is_combined = !self.combined_charts.is_empty();
 
-            if let Some(combined_chart) = &self.combined_chart {
+            // Use the type of the first combined chart with a secondary series
+            // for the secondary axes, or the first combined chart if none.
+            let combined_chart = self
+                .combined_charts
+                .iter()
+                .find(|chart| chart.series.iter().any(|series| series.secondary_axis))
+                .or(self.combined_charts.first());
+
+            if let Some(combined_chart) = combined_chart {
                 chart_group_type = combined_chart.chart_group_type;
-                is_combined = true;
             }
 
             // Reverse the X and Y axes for Bar charts.
@@ -3622,17 +4599,33 @@ impl Chart {
     // Write the <c:xxxChart> element.
     fn write_chart_type(&mut self) {
         match self.chart_type {
-            ChartType::Area | ChartType::AreaStacked | ChartType::AreaPercentStacked => {
+            ChartType::Area
+            | ChartType::AreaStacked
+            | ChartType::AreaPercentStacked
+            | ChartType::Area3D
+            | ChartType::Area3DStacked
+            | ChartType::Area3DPercentStacked => {
                 self.write_area_chart(true);
                 self.write_area_chart(false);
             }
 
-            ChartType::Bar | ChartType::BarStacked | ChartType::BarPercentStacked => {
+            ChartType::Bar
+            | ChartType::BarStacked
+            | ChartType::BarPercentStacked
+            | ChartType::Bar3D
+            | ChartType::Bar3DStacked
+            | ChartType::Bar3DPercentStacked => {
                 self.write_bar_chart(true);
                 self.write_bar_chart(false);
             }
 
-            ChartType::Column | ChartType::ColumnStacked | ChartType::ColumnPercentStacked => {
+            ChartType::Column
+            | ChartType::ColumnStacked
+            | ChartType::ColumnPercentStacked
+            | ChartType::Column3D
+            | ChartType::Column3DStacked
+            | ChartType::Column3DPercentStacked
+            | ChartType::Column3DStandard => {
                 self.write_column_chart(true);
                 self.write_column_chart(false);
             }
@@ -3642,12 +4635,15 @@ impl Chart {
                 self.write_doughnut_chart(false);
             }
 
-            ChartType::Line | ChartType::LineStacked | ChartType::LinePercentStacked => {
+            ChartType::Line
+            | ChartType::LineStacked
+            | ChartType::LinePercentStacked
+            | ChartType::Line3D => {
                 self.write_line_chart(true);
                 self.write_line_chart(false);
             }
 
-            ChartType::Pie => {
+            ChartType::Pie | ChartType::Pie3D => {
                 self.write_pie_chart(true);
                 self.write_pie_chart(false);
             }
@@ -3670,6 +4666,14 @@ impl Chart {
                 self.write_stock_chart(true);
                 self.write_stock_chart(false);
             }
+
+            ChartType::Surface3D
+            | ChartType::Surface3DWireframe
+            | ChartType::SurfaceContour
+            | ChartType::SurfaceContourWireframe => {
+                self.write_surface_chart(true);
+                self.write_surface_chart(false);
+            }
         }
     }
 
@@ -3767,6 +4771,9 @@ impl Chart {
 
     // Write the <c:ser> element.
     fn write_series(&mut self, series: &Vec<ChartSeries>) {
+        let is_3d = self.chart_type.is_3d();
+        let is_surface = self.chart_group_type == ChartType::Surface3D;
+
         for series in series {
             let max_points = series.value_range.number_of_points();
 
@@ -3793,46 +4800,10 @@ impl Chart {
             // Write the c:spPr element.
             self.write_sp_pr(&series.format);
 
-            if let Some(marker) = &series.marker {
-                if !marker.automatic {
-                    // Write the c:marker element.
-                    self.write_marker(marker);
-                }
-            }
-
-            // Write the c:invertIfNegative element.
-            if series.invert_if_negative {
-                self.write_invert_if_negative();
-            }
-
-            // Write the point formatting for the series.
-            if !series.points.is_empty() {
-                self.write_d_pt(&series.points, max_points);
-            }
-
-            if let Some(data_label) = &series.data_label {
-                // Write the c:dLbls element.
-                self.write_data_labels(data_label, &series.custom_data_labels, max_points);
-            }
-
-            if series.trendline.trend_type != ChartTrendlineType::None {
-                // Write the c:trendline element.
-                self.write_trendline(&series.trendline);
-            }
-
-            if self.chart_group_type == ChartType::Bar {
-                if let Some(error_bars) = &series.x_error_bars {
-                    // Write the c:errBars element.
-                    self.write_error_bar("", error_bars);
-                }
-            } else if self.chart_group_type == ChartType::Column {
-                if let Some(error_bars) = &series.y_error_bars {
-                    // Write the c:errBars element.
-                    self.write_error_bar("", error_bars);
-                }
-            } else if let Some(error_bars) = &series.y_error_bars {
-                // Write the c:errBars element.
-                self.write_error_bar("y", error_bars);
+            // Surface series only support the series formatting, categories
+            // and values.
+            if !is_surface {
+                self.write_series_options(series, max_points, is_3d);
             }
 
             // Write the c:cat element.
@@ -3846,7 +4817,7 @@ impl Chart {
             // Write the c:val element.
             self.write_val(&series.value_range);
 
-            if !series.inverted_color.is_auto_or_default() {
+            if !is_surface && !series.inverted_color.is_auto_or_default() {
                 // Write the c:extLst element for the inverted fill color.
                 self.write_extension_list(series.inverted_color);
             }
@@ -4067,7 +5038,15 @@ impl Chart {
     // Write the <c:numRef> or <c:strRef> elements. Value range must be written
     // as a numRef where strings are treated as zero.
     fn write_cache_ref(&mut self, range: &ChartRange, is_num_only: bool) {
-        if range.cache.cache_type == ChartRangeCacheDataType::String && !is_num_only {
+        let is_string = range.cache.cache_type == ChartRangeCacheDataType::String && !is_num_only;
+
+        if range.is_literal {
+            if is_string {
+                self.write_str_lit(&range.cache);
+            } else {
+                self.write_num_lit(&range.cache);
+            }
+        } else if is_string {
             self.write_str_ref(range);
         } else {
             self.write_num_ref(range);
@@ -4104,10 +5083,44 @@ impl Chart {
         xml_end_tag(&mut self.writer, "c:strRef");
     }
 
+    // Write the <c:numLit> element.
+    fn write_num_lit(&mut self, cache: &ChartRangeCacheData) {
+        xml_start_tag_only(&mut self.writer, "c:numLit");
+
+        self.write_num_data(cache);
+
+        xml_end_tag(&mut self.writer, "c:numLit");
+    }
+
+    // Write the <c:strLit> element.
+    fn write_str_lit(&mut self, cache: &ChartRangeCacheData) {
+        xml_start_tag_only(&mut self.writer, "c:strLit");
+
+        self.write_str_data(cache);
+
+        xml_end_tag(&mut self.writer, "c:strLit");
+    }
+
     // Write the <c:numCache> element.
     fn write_num_cache(&mut self, cache: &ChartRangeCacheData) {
         xml_start_tag_only(&mut self.writer, "c:numCache");
 
+        self.write_num_data(cache);
+
+        xml_end_tag(&mut self.writer, "c:numCache");
+    }
+
+    // Write the <c:strCache> element.
+    fn write_str_cache(&mut self, cache: &ChartRangeCacheData) {
+        xml_start_tag_only(&mut self.writer, "c:strCache");
+
+        self.write_str_data(cache);
+
+        xml_end_tag(&mut self.writer, "c:strCache");
+    }
+
+    // Write the numeric data points of a cache or literal element.
+    fn write_num_data(&mut self, cache: &ChartRangeCacheData) {
         // Write the c:formatCode element.
         if cache.cache_type == ChartRangeCacheDataType::Date {
             self.write_format_code("dd/mm/yyyy");
@@ -4130,14 +5143,10 @@ impl Chart {
                 }
             }
         }
-
-        xml_end_tag(&mut self.writer, "c:numCache");
     }
 
-    // Write the <c:strCache> element.
-    fn write_str_cache(&mut self, cache: &ChartRangeCacheData) {
-        xml_start_tag_only(&mut self.writer, "c:strCache");
-
+    // Write the string data points of a cache or literal element.
+    fn write_str_data(&mut self, cache: &ChartRangeCacheData) {
         // Write the c:ptCount element.
         self.write_pt_count(cache.data.len());
 
@@ -4145,8 +5154,6 @@ impl Chart {
         for (index, value) in cache.data.iter().enumerate() {
             self.write_pt(index, value);
         }
-
-        xml_end_tag(&mut self.writer, "c:strCache");
     }
 
     // Write the <c:f> element.
@@ -4175,11 +5182,80 @@ impl Chart {
         xml_end_tag(&mut self.writer, "c:pt");
     }
 
-    // Write both <c:axId> elements.
+    // Write the series marker, point, data label, trendline and error bar
+    // elements that are common to most chart types.
+    fn write_series_options(&mut self, series: &ChartSeries, max_points: usize, is_3d: bool) {
+        if let Some(marker) = &series.marker {
+            if !marker.automatic {
+                // Write the c:marker element.
+                self.write_marker(marker);
+            }
+        }
+
+        // Write the c:invertIfNegative element.
+        if series.invert_if_negative {
+            self.write_invert_if_negative();
+        }
+
+        // Write the c:pictureOptions element for stacked picture fills. These
+        // are only supported by bar, column and area charts.
+        if let Some(fill) = &series.format.picture_fill {
+            if matches!(
+                fill.fill_type,
+                ChartPictureFillType::Stack | ChartPictureFillType::StackAndScale(_)
+            ) && matches!(
+                self.chart_group_type,
+                ChartType::Area | ChartType::Bar | ChartType::Column
+            ) {
+                self.write_picture_options(fill);
+            }
+        }
+
+        // Write the point formatting for the series.
+        if !series.points.is_empty() {
+            self.write_d_pt(&series.points, max_points);
+        }
+
+        if let Some(data_label) = &series.data_label {
+            // Write the c:dLbls element.
+            self.write_data_labels(data_label, &series.custom_data_labels, max_points);
+        }
+
+        // Excel doesn't support trendlines or error bars for 3D charts.
+        if is_3d {
+            return;
+        }
+
+        if series.trendline.trend_type != ChartTrendlineType::None {
+            // Write the c:trendline element.
+            self.write_trendline(&series.trendline);
+        }
+
+        if self.chart_group_type == ChartType::Bar {
+            if let Some(error_bars) = &series.x_error_bars {
+                // Write the c:errBars element.
+                self.write_error_bar("", error_bars);
+            }
+        } else if self.chart_group_type == ChartType::Column {
+            if let Some(error_bars) = &series.y_error_bars {
+                // Write the c:errBars element.
+                self.write_error_bar("", error_bars);
+            }
+        } else if let Some(error_bars) = &series.y_error_bars {
+            // Write the c:errBars element.
+            self.write_error_bar("y", error_bars);
+        }
+    }
+
+    // Write the <c:axId> elements.
     fn write_ax_ids(&mut self, primary_axis: bool) {
         if primary_axis {
             self.write_ax_id(self.axis_ids.0);
             self.write_ax_id(self.axis_ids.1);
+
+            if self.chart_type.has_series_axis() {
+                self.write_ax_id(self.series_axis_id());
+            }
         } else {
             self.write_ax_id(self.axis2_ids.0);
             self.write_ax_id(self.axis2_ids.1);
@@ -4498,6 +5574,74 @@ impl Chart {
         xml_end_tag(&mut self.writer, "c:valAx");
     }
 
+    // -----------------------------------------------------------------------
+    // Series Axis.
+    // -----------------------------------------------------------------------
+
+    // Write the <c:serAx> element for the series (depth) axis of 3D charts.
+    fn write_ser_ax(&mut self, z_axis: &ChartAxis) {
+        xml_start_tag_only(&mut self.writer, "c:serAx");
+
+        self.write_ax_id(self.series_axis_id());
+
+        // Write the c:scaling element.
+        self.write_scaling(z_axis);
+
+        if z_axis.is_hidden {
+            self.write_delete();
+        }
+
+        // Write the c:axPos element.
+        self.write_ax_pos(z_axis.axis_position, false, ChartAxisCrossing::Automatic);
+
+        self.write_major_gridlines(z_axis);
+        self.write_minor_gridlines(z_axis);
+
+        // Write the c:title element.
+        self.write_chart_title(&z_axis.title);
+
+        // Write the c:majorTickMark element.
+        if let Some(tick_type) = z_axis.major_tick_type {
+            self.write_major_tick_mark(tick_type);
+        }
+
+        // Write the c:minorTickMark element.
+        if let Some(tick_type) = z_axis.minor_tick_type {
+            self.write_minor_tick_mark(tick_type);
+        }
+
+        // Write the c:tickLblPos element.
+        self.write_tick_label_position(z_axis.label_position);
+
+        if z_axis.format.has_formatting() {
+            // Write the c:spPr formatting element.
+            self.write_sp_pr(&z_axis.format);
+        }
+
+        // Write the axis font elements.
+        if let Some(font) = &z_axis.font {
+            self.write_axis_font(font);
+        }
+
+        // Write the c:crossAx element. The series axis crosses the value axis.
+        self.write_cross_ax(self.axis_ids.1);
+
+        // Write the c:crosses element.
+        self.write_crosses(&ChartAxisCrossing::Automatic.to_string());
+
+        // Write the c:tickLblSkip element.
+        if z_axis.label_interval > 1 {
+            self.write_tick_lbl_skip(z_axis.label_interval);
+        }
+
+        // Write the c:tickMarkSkip element.
+        if z_axis.tick_interval > 1 {
+            self.write_tick_mark_skip(z_axis.tick_interval);
+        }
+
+        xml_end_tag(&mut self.writer, "c:serAx");
+    }
+
     // -----------------------------------------------------------------------
     // Category Value Axis. Only for Scatter charts.
     // -----------------------------------------------------------------------
@@ -4881,7 +6025,7 @@ impl Chart {
         // Pie/Doughnut charts set the "rtl" flag to "0" in the legend font even
         // though "0" is implied. To match Excel output we set it if it hasn't
         // been set by the user.
-        if self.chart_type == ChartType::Pie || self.chart_type == ChartType::Doughnut {
+        if self.chart_group_type == ChartType::Pie || self.chart_group_type == ChartType::Doughnut {
             match &mut self.legend.font {
                 Some(font) => {
                     if font.right_to_left.is_none() {
@@ -5852,6 +6996,9 @@ impl Chart {
         } else if let Some(gradient_fill) = &format.gradient_fill {
             // Write the a:gradFill element.
             self.write_gradient_fill(gradient_fill);
+        } else if let Some(picture_fill) = &format.picture_fill {
+            // Write the a:blipFill element.
+            self.write_a_blip_fill(picture_fill);
         }
         if format.no_line {
             // Write a default line with no fill.
@@ -5864,6 +7011,71 @@ impl Chart {
         xml_end_tag(&mut self.writer, "c:spPr");
     }
 
+    // Write the <a:blipFill> element.
+    fn write_a_blip_fill(&mut self, fill: &ChartPictureFill) {
+        let Some(rel_id) = self.drawing_rel_ids.get(&fill.image.hash) else {
+            return;
+        };
+
+        xml_start_tag_only(&mut self.writer, "a:blipFill");
+
+        // Write the <a:blip> element.
+        let attributes = [("r:embed", format!("rId{rel_id}"))];
+
+        if fill.transparency > 0 {
+            xml_start_tag(&mut self.writer, "a:blip", &attributes);
+
+            let amount = (100 - u32::from(fill.transparency)) * 1000;
+            let attributes = [("amt", amount.to_string())];
+            xml_empty_tag(&mut self.writer, "a:alphaModFix", &attributes);
+
+            xml_end_tag(&mut self.writer, "a:blip");
+        } else {
+            xml_empty_tag(&mut self.writer, "a:blip", &attributes);
+        }
+
+        if fill.fill_type == ChartPictureFillType::Tile {
+            // Write the <a:tile> element.
+            let attributes = [
+                ("tx", "0"),
+                ("ty", "0"),
+                ("sx", "100000"),
+                ("sy", "100000"),
+                ("flip", "none"),
+                ("algn", "tl"),
+            ];
+            xml_empty_tag(&mut self.writer, "a:tile", &attributes);
+        } else {
+            xml_start_tag_only(&mut self.writer, "a:stretch");
+            xml_empty_tag_only(&mut self.writer, "a:fillRect");
+            xml_end_tag(&mut self.writer, "a:stretch");
+        }
+
+        xml_end_tag(&mut self.writer, "a:blipFill");
+    }
+
+    // Write the <c:pictureOptions> element.
+    fn write_picture_options(&mut self, fill: &ChartPictureFill) {
+        xml_start_tag_only(&mut self.writer, "c:pictureOptions");
+
+        match fill.fill_type {
+            ChartPictureFillType::Stack => {
+                let attributes = [("val", "stack")];
+                xml_empty_tag(&mut self.writer, "c:pictureFormat", &attributes);
+            }
+            ChartPictureFillType::StackAndScale(units) => {
+                let attributes = [("val", "stackScale")];
+                xml_empty_tag(&mut self.writer, "c:pictureFormat", &attributes);
+
+                let attributes = [("val", units.to_string())];
+                xml_empty_tag(&mut self.writer, "c:pictureStackUnit", &attributes);
+            }
+            ChartPictureFillType::Stretch | ChartPictureFillType::Tile => {}
+        }
+
+        xml_end_tag(&mut self.writer, "c:pictureOptions");
+    }
+
     // Write the <a:ln> element.
     fn write_a_ln(&mut self, line: &ChartLine) {
         let mut attributes = vec![];
@@ -6211,6 +7423,69 @@ impl Chart {
         xml_empty_tag(&mut self.writer, "c:style", &attributes);
     }
 
+    // Write the <c:view3D> element.
+    fn write_view_3d(&mut self) {
+        xml_start_tag_only(&mut self.writer, "c:view3D");
+
+        // Write the c:rotX element.
+        let attributes = [("val", self.elevation.to_string())];
+        xml_empty_tag(&mut self.writer, "c:rotX", &attributes);
+
+        // Write the c:rotY element.
+        let attributes = [("val", self.rotation.to_string())];
+        xml_empty_tag(&mut self.writer, "c:rotY", &attributes);
+
+        // Write the c:depthPercent element.
+        if self.depth_percent != 100 {
+            let attributes = [("val", self.depth_percent.to_string())];
+            xml_empty_tag(&mut self.writer, "c:depthPercent", &attributes);
+        }
+
+        // Write the c:rAngAx element.
+        let attributes = [("val", u8::from(self.right_angle_axes).to_string())];
+        xml_empty_tag(&mut self.writer, "c:rAngAx", &attributes);
+
+        // Write the c:perspective element.
+        if let Some(perspective) = self.perspective {
+            let attributes = [("val", perspective.to_string())];
+            xml_empty_tag(&mut self.writer, "c:perspective", &attributes);
+        }
+
+        xml_end_tag(&mut self.writer, "c:view3D");
+    }
+
+    // Write the <c:floor>, <c:sideWall> or <c:backWall> element.
+    fn write_wall(&mut self, tag: &str, format: &ChartFormat) {
+        xml_start_tag_only(&mut self.writer, tag);
+
+        // Write the c:thickness element.
+        let attributes = [("val", "0")];
+        xml_empty_tag(&mut self.writer, "c:thickness", &attributes);
+
+        // Write the c:spPr element.
+        self.write_sp_pr(format);
+
+        xml_end_tag(&mut self.writer, tag);
+    }
+
+    // Write the <c:shape> element.
+    fn write_shape(&mut self) {
+        let attributes = [("val", "box")];
+
+        xml_empty_tag(&mut self.writer, "c:shape", &attributes);
+    }
+
+    // Write the <c:wireframe> element.
+    fn write_wireframe(&mut self) {
+        let wireframe = matches!(
+            self.chart_type,
+            ChartType::Surface3DWireframe | ChartType::SurfaceContourWireframe
+        );
+        let attributes = [("val", u8::from(wireframe).to_string())];
+
+        xml_empty_tag(&mut self.writer, "c:wireframe", &attributes);
+    }
+
     // Write the <c:autoTitleDeleted> element.
     fn write_auto_title_deleted(&mut self) {
         let attributes = [("val", "1")];
@@ -6433,6 +7708,17 @@ impl Chart {
 ///   `("Sheet1", 0, 0)` (this gives the same range as the previous string
 ///   value).
 ///
+/// For chart series values and categories you can also use literal data that
+/// isn't stored in the worksheet:
+///
+/// - An array, slice or vector of numbers such as `&[1.0, 2.0, 3.0]` or
+///   `&[1, 2, 3]`. Any of the number types that convert losslessly to `f64`
+///   can be used.
+/// - An array, slice or vector of strings such as `&["Jan", "Feb", "Mar"]`.
+///
+/// See [`ChartRange::new_from_numbers()`] and
+/// [`ChartRange::new_from_strings()`] for more details.
+///
 pub trait IntoChartRange {
     /// Trait function to turn a type into [`ChartRange`].
     fn new_chart_range(&self) -> ChartRange;
@@ -6468,6 +7754,59 @@ impl IntoChartRange for &String {
     }
 }
 
+macro_rules! chart_range_from_numbers_impl {
+    ($($t:ty)*) => ($(
+        impl IntoChartRange for &[$t] {
+            fn new_chart_range(&self) -> ChartRange {
+                ChartRange::new_from_numbers(self)
+            }
+        }
+
+        impl<const N: usize> IntoChartRange for &[$t; N] {
+            fn new_chart_range(&self) -> ChartRange {
+                ChartRange::new_from_numbers(*self)
+            }
+        }
+
+        impl IntoChartRange for &Vec<$t> {
+            fn new_chart_range(&self) -> ChartRange {
+                ChartRange::new_from_numbers(self)
+            }
+        }
+    )*)
+}
+chart_range_from_numbers_impl!(u8 i8 u16 i16 u32 i32 f32 f64);
+
+impl IntoChartRange for &[&str] {
+    fn new_chart_range(&self) -> ChartRange {
+        ChartRange::new_from_strings(self)
+    }
+}
+
+impl<const N: usize> IntoChartRange for &[&str; N] {
+    fn new_chart_range(&self) -> ChartRange {
+        ChartRange::new_from_strings(*self)
+    }
+}
+
+impl IntoChartRange for &Vec<&str> {
+    fn new_chart_range(&self) -> ChartRange {
+        ChartRange::new_from_strings(self)
+    }
+}
+
+impl IntoChartRange for &[String] {
+    fn new_chart_range(&self) -> ChartRange {
+        ChartRange::new_from_strings(self)
+    }
+}
+
+impl IntoChartRange for &Vec<String> {
+    fn new_chart_range(&self) -> ChartRange {
+        ChartRange::new_from_strings(self)
+    }
+}
+
 /// Trait to map types into a `ChartFormat`.
 ///
 /// The `IntoChartFormat` trait provides a syntactic shortcut for the
@@ -6575,6 +7914,12 @@ impl IntoChartFormat for &mut ChartGradientFill {
     }
 }
 
+impl IntoChartFormat for &mut ChartPictureFill {
+    fn new_chart_format(&self) -> ChartFormat {
+        ChartFormat::new().set_picture_fill(self).clone()
+    }
+}
+
 // Trait for objects that have a component stored in the drawing.xml file.
 impl DrawingObject for Chart {
     fn x_offset(&self) -> u32 {
@@ -6669,6 +8014,7 @@ impl DrawingObject for Chart {
 /// <img src="https://rustxlsxwriter.github.io/images/chart_simple.png">
 ///
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartSeries {
     pub(crate) value_range: ChartRange,
     pub(crate) category_range: ChartRange,
@@ -6688,6 +8034,7 @@ pub struct ChartSeries {
     pub(crate) delete_from_legend: bool,
     pub(crate) smooth: Option<bool>,
     pub(crate) secondary_axis: bool,
+    pub(crate) chart_type: Option<ChartType>,
 }
 
 #[allow(clippy::new_without_default)]
@@ -6800,6 +8147,7 @@ impl ChartSeries {
             delete_from_legend: false,
             smooth: None,
             secondary_axis: false,
+            chart_type: None,
         }
     }
 
@@ -6817,13 +8165,17 @@ impl ChartSeries {
     ///
     /// # Parameters
     ///
-    /// - `range`: The range property which can be one of two generic types:
+    /// - `range`: The range property which can be one of the following generic
+    ///   types:
     ///    - A string with an Excel like range formula such as
     ///      `"Sheet1!$A$1:$A$3"`.
     ///    - A tuple that can be used to create the range programmatically using
     ///      a sheet name and zero indexed row and column values like:
     ///      `("Sheet1", 0, 0, 2, 0)` (this gives the same range as the previous
     ///      string value).
+    ///    - An array, slice or vector of literal numbers or strings such as
+    ///      `&[50.0, 30.0, 40.0]`. The data is stored in the chart and isn't
+    ///      linked to the worksheet. See [`ChartRange::new_from_numbers()`].
     ///
     /// # Examples
     ///
@@ -6893,13 +8245,17 @@ impl ChartSeries {
     ///
     /// # Parameters
     ///
-    /// - `range`: The range property which can be one of two generic types:
+    /// - `range`: The range property which can be one of the following generic
+    ///   types:
     ///    - A string with an Excel like range formula such as
     ///      `"Sheet1!$A$1:$A$3"`.
     ///    - A tuple that can be used to create the range programmatically using
     ///      a sheet name and zero indexed row and column values like:
     ///      `("Sheet1", 0, 0, 2, 0)` (this gives the same range as the previous
     ///      string value).
+    ///    - An array, slice or vector of literal numbers or strings such as
+    ///      `&[50.0, 30.0, 40.0]`. The data is stored in the chart and isn't
+    ///      linked to the worksheet. See [`ChartRange::new_from_numbers()`].
     ///
     /// # Examples
     ///
@@ -7032,6 +8388,81 @@ impl ChartSeries {
         self
     }
 
+    /// Set the chart type for a chart series.
+    ///
+    /// Display the series as a different chart type to the chart that it is
+    /// added to, for example as a Line series in a Column chart. This is a
+    /// shortcut for creating a combined chart via [`Chart::combine()`] when
+    /// the combined chart doesn't need any chart level properties.
+    ///
+    /// When the chart is inserted into a worksheet the series is moved into a
+    /// combined chart of the same type. If there is already a combined chart of
+    /// that type then the series is added to it, otherwise a new default chart
+    /// of that type is created. See [Combined
+    /// Charts](crate::chart#combined-charts) for more information.
+    ///
+    /// # Parameters
+    ///
+    /// - `chart_type`: The [`ChartType`] of the series. 3D chart types cannot
+    ///   be combined with other chart types.
+    ///
+    /// # Examples
+    ///
+    /// A chart example demonstrating combining Column, Line and Area series in
+    /// the same chart.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_series_set_chart_type.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add the worksheet data that the charts will refer to.
+    /// #     worksheet.write_column(0, 0, [10, 40, 50, 20, 10, 50])?;
+    /// #     worksheet.write_column(0, 1, [30, 30, 40, 40, 40, 50])?;
+    /// #     worksheet.write_column(0, 2, [20, 30, 35, 30, 25, 45])?;
+    /// #
+    ///     // Create a new Column chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///
+    ///     // Add the "actual" values as a Column series.
+    ///     chart
+    ///         .add_series()
+    ///         .set_values("Sheet1!$A$1:$A$6")
+    ///         .set_name("Actual");
+    ///
+    ///     // Add the "target" values as a Line series.
+    ///     chart
+    ///         .add_series()
+    ///         .set_values("Sheet1!$B$1:$B$6")
+    ///         .set_name("Target")
+    ///         .set_chart_type(ChartType::Line);
+    ///
+    ///     // Add the "forecast" values as an Area series.
+    ///     chart
+    ///         .add_series()
+    ///         .set_values("Sheet1!$C$1:$C$6")
+    ///         .set_name("Forecast")
+    ///         .set_chart_type(ChartType::Area);
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart_with_offset(0, 3, &chart, 5, 5)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_chart_type(&mut self, chart_type: ChartType) -> &mut ChartSeries {
+        self.chart_type = Some(chart_type);
+        self
+    }
+
     /// Add a name for a chart series.
     ///
     /// Set the name for the series. The name is displayed in the formula bar.
@@ -8272,6 +9703,7 @@ impl ChartSeries {
 // -----------------------------------------------------------------------
 
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// The `ChartRange` struct represents a chart range.
 ///
 /// A struct to represent a chart range like `"Sheet1!$A$1:$A$4"`. The struct is
@@ -8289,6 +9721,7 @@ pub struct ChartRange {
     last_col: ColNum,
     range_string: String,
     pub(crate) cache: ChartRangeCacheData,
+    pub(crate) is_literal: bool,
 }
 
 impl Default for ChartRange {
@@ -8339,6 +9772,7 @@ impl ChartRange {
             last_col,
             range_string: String::new(),
             cache: ChartRangeCacheData::new(),
+            is_literal: false,
         }
     }
 
@@ -8432,6 +9866,109 @@ impl ChartRange {
             last_col,
             range_string: range_string.to_string(),
             cache: ChartRangeCacheData::new(),
+            is_literal: false,
+        }
+    }
+
+    /// Create a new `ChartRange` from an array of literal numbers.
+    ///
+    /// Chart series data is usually taken from a range of worksheet cells.
+    /// However, Excel also allows series values, categories and custom error
+    /// bars to be stored as literal values in the chart itself. This is useful
+    /// when the source data isn't required in the worksheet.
+    ///
+    /// The data is written to the chart as a `c:numLit` element and isn't
+    /// linked to any worksheet cells. Literal ranges are only supported for
+    /// series values, categories and custom error bars. They are ignored if
+    /// used for chart titles or sparklines.
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: An array of numbers to use as the chart data. Any number type
+    ///   that converts to `f64`, such as `i32` or `u16`, can be used. NaN and
+    ///   infinite values are stored as empty points and are displayed as gaps.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates creating a new chart range from an
+    /// array of numbers.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chartrange_new_from_numbers.rs
+    /// #
+    /// # use rust_xlsxwriter::ChartRange;
+    /// #
+    /// # #[allow(unused_variables)]
+    /// # fn main() {
+    ///     let range = ChartRange::new_from_numbers(&[10.0, 40.0, 50.0, 20.0, 10.0]);
+    /// # }
+    /// ```
+    ///
+    pub fn new_from_numbers<T>(data: &[T]) -> ChartRange
+    where
+        T: Into<f64> + Copy,
+    {
+        ChartRange {
+            cache: ChartRangeCacheData {
+                cache_type: ChartRangeCacheDataType::Number,
+                data: data
+                    .iter()
+                    .map(|&value| {
+                        let value = value.into();
+
+                        // Non-finite values are stored as empty points, which
+                        // Excel displays as gaps.
+                        if value.is_finite() {
+                            value.to_string()
+                        } else {
+                            String::new()
+                        }
+                    })
+                    .collect(),
+            },
+            is_literal: true,
+            ..ChartRange::default()
+        }
+    }
+
+    /// Create a new `ChartRange` from an array of literal strings.
+    ///
+    /// This is the string equivalent of [`ChartRange::new_from_numbers()`].
+    /// It is mainly used for chart categories. The data is written to the
+    /// chart as a `c:strLit` element. If string data is used for series values
+    /// Excel will treat the values as zero.
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: An array of strings to use as the chart data.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates creating a new chart range from an
+    /// array of strings.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chartrange_new_from_strings.rs
+    /// #
+    /// # use rust_xlsxwriter::ChartRange;
+    /// #
+    /// # #[allow(unused_variables)]
+    /// # fn main() {
+    ///     let range = ChartRange::new_from_strings(&["Jan", "Feb", "Mar", "Apr", "May"]);
+    /// # }
+    /// ```
+    ///
+    pub fn new_from_strings<T: AsRef<str>>(data: &[T]) -> ChartRange {
+        ChartRange {
+            cache: ChartRangeCacheData {
+                cache_type: ChartRangeCacheDataType::String,
+                data: data
+                    .iter()
+                    .map(|value| value.as_ref().to_string())
+                    .collect(),
+            },
+            is_literal: true,
+            ..ChartRange::default()
         }
     }
 
@@ -8481,11 +10018,19 @@ impl ChartRange {
 
     // Check that the range has data.
     pub(crate) fn has_data(&self) -> bool {
-        !self.sheet_name.is_empty()
+        if self.is_literal {
+            self.cache.has_data()
+        } else {
+            !self.sheet_name.is_empty()
+        }
     }
 
     // Get the number of X or Y data points in the range.
     pub(crate) fn number_of_points(&self) -> usize {
+        if self.is_literal {
+            return self.cache.data.len();
+        }
+
         let row_range = (self.last_row - self.first_row + 1) as usize;
         let col_range = (self.last_col - self.first_col + 1) as usize;
 
@@ -8524,6 +10069,11 @@ impl ChartRange {
 
     // Check that the row/column values in the range are valid.
     pub(crate) fn validate(&self) -> Result<(), XlsxError> {
+        // Literal ranges don't refer to worksheet cells.
+        if self.is_literal {
+            return Ok(());
+        }
+
         let range = self.error_range();
 
         let error_message = format!("Sheet name error for range: '{range}'");
@@ -8590,6 +10140,7 @@ impl ChartRange {
 }
 
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub(crate) struct ChartRangeCacheData {
     pub(crate) cache_type: ChartRangeCacheDataType,
     pub(crate) data: Vec<String>,
@@ -8609,6 +10160,7 @@ impl ChartRangeCacheData {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub(crate) enum ChartRangeCacheDataType {
     None,
     String,
@@ -8621,6 +10173,7 @@ pub(crate) enum ChartRangeCacheDataType {
 // -----------------------------------------------------------------------
 
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// The `ChartType` enum define the type of a [`Chart`] object.
 ///
 /// The main original chart types are supported, see below.
@@ -8646,6 +10199,16 @@ pub enum ChartType {
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_area_percent_stacked.png">
     AreaPercentStacked,
 
+    /// A 3D Area chart type. The series are displayed one behind the other
+    /// along a series (depth) axis.
+    Area3D,
+
+    /// A 3D stacked Area chart type.
+    Area3DStacked,
+
+    /// A 3D percent stacked Area chart type.
+    Area3DPercentStacked,
+
     /// A Bar (horizontal histogram) chart type.
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_bar.png">
@@ -8661,6 +10224,15 @@ pub enum ChartType {
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_bar_percent_stacked.png">
     BarPercentStacked,
 
+    /// A 3D clustered Bar chart type.
+    Bar3D,
+
+    /// A 3D stacked Bar chart type.
+    Bar3DStacked,
+
+    /// A 3D percent stacked Bar chart type.
+    Bar3DPercentStacked,
+
     /// A Column (vertical histogram) chart type.
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_column.png">
@@ -8676,6 +10248,20 @@ pub enum ChartType {
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_column_percent_stacked.png">
     ColumnPercentStacked,
 
+    /// A 3D clustered Column chart type.
+    Column3D,
+
+    /// A 3D stacked Column chart type.
+    Column3DStacked,
+
+    /// A 3D percent stacked Column chart type.
+    Column3DPercentStacked,
+
+    /// A 3D Column chart type where the series are displayed one behind the
+    /// other along a series (depth) axis. This is the "3-D Column" chart in
+    /// Excel.
+    Column3DStandard,
+
     /// A Doughnut chart type.
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_doughnut.png">
@@ -8696,11 +10282,18 @@ pub enum ChartType {
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_line_percent_stacked.png">
     LinePercentStacked,
 
+    /// A 3D Line chart type. The series are displayed as ribbons one behind
+    /// the other along a series (depth) axis.
+    Line3D,
+
     /// A Pie chart type.
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_pie.png">
     Pie,
 
+    /// A 3D Pie chart type.
+    Pie3D,
+
     /// A Radar chart type.
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_radar.png">
@@ -8754,6 +10347,58 @@ pub enum ChartType {
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_stock.png">
     Stock,
+
+    /// A 3D Surface chart type. Each series is a row of the surface and the
+    /// surface is colored in bands according to the value axis.
+    Surface3D,
+
+    /// A 3D Surface chart type displayed as a wireframe without color bands.
+    Surface3DWireframe,
+
+    /// A Contour chart type. This is a Surface chart viewed from above.
+    SurfaceContour,
+
+    /// A Contour chart type displayed as a wireframe without color bands.
+    SurfaceContourWireframe,
+}
+
+impl ChartType {
+    // Check if the chart type is a 3D type, or is displayed with a 3D view.
+    pub(crate) fn is_3d(self) -> bool {
+        matches!(
+            self,
+            ChartType::Area3D
+                | ChartType::Area3DStacked
+                | ChartType::Area3DPercentStacked
+                | ChartType::Bar3D
+                | ChartType::Bar3DStacked
+                | ChartType::Bar3DPercentStacked
+                | ChartType::Column3D
+                | ChartType::Column3DStacked
+                | ChartType::Column3DPercentStacked
+                | ChartType::Column3DStandard
+                | ChartType::Line3D
+                | ChartType::Pie3D
+                | ChartType::Surface3D
+                | ChartType::Surface3DWireframe
+                | ChartType::SurfaceContour
+                | ChartType::SurfaceContourWireframe
+        )
+    }
+
+    // Check if the chart type has a series (depth) axis.
+    pub(crate) fn has_series_axis(self) -> bool {
+        matches!(
+            self,
+            ChartType::Area3D
+                | ChartType::Column3DStandard
+                | ChartType::Line3D
+                | ChartType::Surface3D
+                | ChartType::Surface3DWireframe
+                | ChartType::SurfaceContour
+                | ChartType::SurfaceContourWireframe
+        )
+    }
 }
 
 // -----------------------------------------------------------------------
@@ -8765,6 +10410,7 @@ pub enum ChartType {
 /// It is used in conjunction with the [`Chart`] struct.
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartTitle {
     pub(crate) range: ChartRange,
     pub(crate) format: ChartFormat,
@@ -8858,6 +10504,15 @@ impl ChartTitle {
     {
         self.range = name.new_chart_range();
 
+        // Literal data ranges aren't supported for titles.
+        if self.range.is_literal {
+            eprintln!(
+                "Literal data ranges aren't supported for chart titles. The range is ignored."
+            );
+            self.range = ChartRange::default();
+            return self;
+        }
+
         // If the name didn't convert to a populated range then it is probably
         // just a simple string title.
         if !self.range.has_data() {
@@ -9134,6 +10789,7 @@ impl ChartTitle {
 /// <img src="https://rustxlsxwriter.github.io/images/chart_marker.png">
 ///
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartMarker {
     pub(crate) automatic: bool,
     pub(crate) none: bool,
@@ -9331,6 +10987,7 @@ impl ChartMarker {
 
 /// The `ChartMarkerType` enum defines the [`Chart`] marker types.
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ChartMarkerType {
     /// Square marker type.
     ///
@@ -9457,6 +11114,7 @@ impl fmt::Display for ChartMarkerType {
 /// <img src="https://rustxlsxwriter.github.io/images/chart_data_labels.png">
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartDataLabel {
     pub(crate) format: ChartFormat,
     pub(crate) show_value: bool,
@@ -10306,6 +11964,7 @@ impl ChartDataLabel {
 /// | `BestFit`     |               |               | Yes (default) |               |
 ///
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ChartDataLabelPosition {
     /// Series data label position: Default position.
     Default,
@@ -10434,6 +12093,7 @@ impl fmt::Display for ChartDataLabelPosition {
 /// <img src="https://rustxlsxwriter.github.io/images/chart_set_points.png">
 ///
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartPoint {
     pub(crate) format: ChartFormat,
 }
@@ -10542,6 +12202,7 @@ impl ChartPoint {
 /// <img src="https://rustxlsxwriter.github.io/images/chart_axis_set_name.png">
 ///
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartAxis {
     axis_type: ChartAxisType,
     axis_position: ChartAxisPosition,
@@ -12357,6 +14018,7 @@ impl ChartAxis {
 }
 
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub(crate) enum ChartAxisType {
     Category,
     Value,
@@ -12364,6 +14026,7 @@ pub(crate) enum ChartAxisType {
 }
 
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub(crate) enum ChartAxisPosition {
     Top,
     Bottom,
@@ -12449,6 +14112,7 @@ impl fmt::Display for ChartAxisPosition {
 /// src="https://rustxlsxwriter.github.io/images/chart_axis_set_label_position.png">
 ///
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ChartAxisLabelPosition {
     /// Position the axis labels next to the axis. The default.
     NextTo,
@@ -12542,6 +14206,7 @@ impl fmt::Display for ChartAxisLabelPosition {
 /// src="https://rustxlsxwriter.github.io/images/chart_axis_set_major_tick_type.png">
 ///
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ChartAxisTickType {
     /// No tick mark for the axis.
     None,
@@ -12572,6 +14237,7 @@ impl fmt::Display for ChartAxisTickType {
 /// Define the unit type for the major or minor unit in a Chart Date axis.
 ///
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ChartAxisDateUnitType {
     /// The major or minor unit is expressed in days.
     Days,
@@ -12594,6 +14260,7 @@ impl fmt::Display for ChartAxisDateUnitType {
 }
 
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub(crate) enum ChartGrouping {
     Stacked,
     Standard,
@@ -12619,6 +14286,7 @@ impl fmt::Display for ChartGrouping {
 /// "Millions".
 ///
 #[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ChartAxisDisplayUnitType {
     /// Don't display any units for the axis values, the default.
     None,
@@ -12730,6 +14398,7 @@ impl fmt::Display for ChartAxisDisplayUnitType {
 /// <img src="https://rustxlsxwriter.github.io/images/chart_legend.png">
 ///
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartLegend {
     position: ChartLegendPosition,
     hidden: bool,
@@ -13149,6 +14818,7 @@ impl ChartLegend {
 /// and the `ChartLegendPosition` enum values.
 ///
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ChartLegendPosition {
     /// Chart legend positioned at the right side. The default.
     Right,
@@ -13186,6 +14856,7 @@ impl fmt::Display for ChartLegendPosition {
 /// These options can be set using the [`Chart::show_empty_cells_as()`] method.
 ///
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ChartEmptyCells {
     /// Show empty cells in the chart as gaps. The default.
     Gaps,
@@ -13212,6 +14883,7 @@ impl fmt::Display for ChartEmptyCells {
 // -----------------------------------------------------------------------
 
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// The `ChartFormat` struct represents formatting for various chart objects.
 ///
 /// Excel uses a standard formatting dialog for the elements of a chart such as
@@ -13234,6 +14906,8 @@ impl fmt::Display for ChartEmptyCells {
 ///   properties.
 /// - [`ChartFormat::set_gradient_fill()`]: Set the [`ChartGradientFill`]
 ///   properties.
+/// - [`ChartFormat::set_picture_fill()`]: Set the [`ChartPictureFill`]
+///   properties.
 /// - [`ChartFormat::set_no_fill()`]: Turn off the fill for the chart object.
 /// - [`ChartFormat::set_line()`]: Set the [`ChartLine`] properties.
 /// - [`ChartFormat::set_border()`]: Set the [`ChartBorder`] properties. A
@@ -13328,6 +15002,15 @@ pub struct ChartFormat {
     solid_fill: Option<ChartSolidFill>,
     pattern_fill: Option<ChartPatternFill>,
     gradient_fill: Option<ChartGradientFill>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            skip_deserializing,
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "serialize_picture_fill"
+        )
+    )]
+    picture_fill: Option<ChartPictureFill>,
 }
 
 impl Default for ChartFormat {
@@ -13347,6 +15030,7 @@ impl ChartFormat {
             solid_fill: None,
             pattern_fill: None,
             gradient_fill: None,
+            picture_fill: None,
         }
     }
 
@@ -13734,29 +15418,94 @@ impl ChartFormat {
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/chart_pattern_fill.png">
     ///
-    pub fn set_pattern_fill(&mut self, fill: &ChartPatternFill) -> &mut ChartFormat {
-        self.pattern_fill = Some(fill.clone());
+    pub fn set_pattern_fill(&mut self, fill: &ChartPatternFill) -> &mut ChartFormat {
+        self.pattern_fill = Some(fill.clone());
+        self
+    }
+
+    /// Set the gradient fill formatting for a chart element.
+    ///
+    /// See the [`ChartGradientFill`] struct for details on the gradient fill
+    /// properties that can be set.
+    ///
+    /// # Parameters
+    ///
+    /// - `fill`: A [`ChartGradientFill`] struct reference.
+    ///
+    /// # Examples
+    ///
+    /// An example of setting a gradient fill for a chart element.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_format_set_gradient_fill.rs
+    /// #
+    /// # use rust_xlsxwriter::{
+    /// #     Chart, ChartFormat, ChartGradientFill, ChartGradientStop, ChartType, Workbook, XlsxError,
+    /// # };
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 10)?;
+    /// #     worksheet.write(1, 0, 40)?;
+    /// #     worksheet.write(2, 0, 50)?;
+    /// #     worksheet.write(3, 0, 20)?;
+    /// #     worksheet.write(4, 0, 10)?;
+    /// #     worksheet.write(5, 0, 50)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///
+    ///     // Add a data series with formatting.
+    ///     chart
+    ///         .add_series()
+    ///         .set_values("Sheet1!$A$1:$A$6")
+    ///         .set_format(ChartFormat::new().set_gradient_fill(
+    ///             ChartGradientFill::new().set_gradient_stops(&[
+    ///                 ChartGradientStop::new("#963735", 0),
+    ///                 ChartGradientStop::new("#F1DCDB", 100),
+    ///             ]),
+    ///         ));
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/chart_gradient_fill.png">
+    ///
+    pub fn set_gradient_fill(&mut self, fill: &ChartGradientFill) -> &mut ChartFormat {
+        self.gradient_fill = Some(fill.clone());
         self
     }
 
-    /// Set the gradient fill formatting for a chart element.
+    /// Set the picture fill formatting for a chart element.
     ///
-    /// See the [`ChartGradientFill`] struct for details on the gradient fill
+    /// See the [`ChartPictureFill`] struct for details on the picture fill
     /// properties that can be set.
     ///
     /// # Parameters
     ///
-    /// - `fill`: A [`ChartGradientFill`] struct reference.
+    /// - `fill`: A [`ChartPictureFill`] struct reference.
     ///
     /// # Examples
     ///
-    /// An example of setting a gradient fill for a chart element.
+    /// An example of setting a picture fill for a chart element.
     ///
     /// ```
-    /// # // This code is available in examples/doc_chart_format_set_gradient_fill.rs
+    /// # // This code is available in examples/doc_chart_format_set_picture_fill.rs
     /// #
     /// # use rust_xlsxwriter::{
-    /// #     Chart, ChartFormat, ChartGradientFill, ChartGradientStop, ChartType, Workbook, XlsxError,
+    /// #     Chart, ChartFormat, ChartPictureFill, ChartType, Image, Workbook, XlsxError,
     /// # };
     /// #
     /// # fn main() -> Result<(), XlsxError> {
@@ -13774,16 +15523,15 @@ impl ChartFormat {
     /// #     // Create a new chart.
     ///     let mut chart = Chart::new(ChartType::Column);
     ///
-    ///     // Add a data series with formatting.
-    ///     chart
-    ///         .add_series()
-    ///         .set_values("Sheet1!$A$1:$A$6")
-    ///         .set_format(ChartFormat::new().set_gradient_fill(
-    ///             ChartGradientFill::new().set_gradient_stops(&[
-    ///                 ChartGradientStop::new("#963735", 0),
-    ///                 ChartGradientStop::new("#F1DCDB", 100),
-    ///             ]),
-    ///         ));
+    ///     // Add a data series.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$6");
+    ///
+    ///     // Add a background image to the chart area.
+    ///     let image = Image::new("examples/rust_logo.png")?;
+    ///
+    ///     chart.chart_area().set_format(
+    ///         ChartFormat::new().set_picture_fill(ChartPictureFill::new(&image).set_transparency(80)),
+    ///     );
     ///
     ///     // Add the chart to the worksheet.
     ///     worksheet.insert_chart(0, 2, &chart)?;
@@ -13795,12 +15543,8 @@ impl ChartFormat {
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img src="https://rustxlsxwriter.github.io/images/chart_gradient_fill.png">
-    ///
-    pub fn set_gradient_fill(&mut self, fill: &ChartGradientFill) -> &mut ChartFormat {
-        self.gradient_fill = Some(fill.clone());
+    pub fn set_picture_fill(&mut self, fill: &ChartPictureFill) -> &mut ChartFormat {
+        self.picture_fill = Some(fill.clone());
         self
     }
 
@@ -13810,6 +15554,7 @@ impl ChartFormat {
             || self.solid_fill.is_some()
             || self.pattern_fill.is_some()
             || self.gradient_fill.is_some()
+            || self.picture_fill.is_some()
             || self.no_fill
             || self.no_line
     }
@@ -13888,6 +15633,7 @@ impl ChartFormat {
 /// src="https://rustxlsxwriter.github.io/images/chart_line_formatting.png">
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartLine {
     color: Color,
     width: Option<f64>,
@@ -14311,6 +16057,7 @@ pub type ChartBorder = ChartLine;
 /// <img src="https://rustxlsxwriter.github.io/images/chart_solid_fill.png">
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartSolidFill {
     color: Color,
     transparency: u8,
@@ -14522,6 +16269,7 @@ impl ChartSolidFill {
 /// <img src="https://rustxlsxwriter.github.io/images/chart_pattern_fill.png">
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartPatternFill {
     background_color: Color,
     foreground_color: Color,
@@ -14695,6 +16443,7 @@ impl ChartPatternFill {
 
 /// The `ChartLineDashType` enum defines the [`Chart`] line dash types.
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ChartLineDashType {
     /// Solid - chart line/border dash type.
     ///
@@ -14754,6 +16503,7 @@ impl fmt::Display for ChartLineDashType {
 
 /// The `ChartPatternFillType` enum defines the [`Chart`] pattern fill types.
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ChartPatternFillType {
     /// Dotted 5 percent - chart fill pattern.
     ///
@@ -15051,11 +16801,173 @@ impl fmt::Display for ChartPatternFillType {
     }
 }
 
+// -----------------------------------------------------------------------
+// ChartPictureFill
+// -----------------------------------------------------------------------
+
+/// The `ChartPictureFill` struct represents a picture fill for a chart element.
+///
+/// The [`ChartPictureFill`] struct represents the formatting properties for an
+/// image used to fill a Chart element, such as a branded background image in
+/// the chart area or icons in the columns of a column chart.
+///
+/// `ChartPictureFill` is a sub property of the [`ChartFormat`] struct and is
+/// used with the [`ChartFormat::set_picture_fill()`] method.
+///
+/// It is used in conjunction with the [`Chart`] struct.
+///
+/// # Examples
+///
+/// An example of a pictograph column chart where the columns are filled with
+/// stacked images.
+///
+/// ```
+/// # // This code is available in examples/doc_chart_picture_fill.rs
+/// #
+/// # use rust_xlsxwriter::{
+/// #     Chart, ChartFormat, ChartPictureFill, ChartPictureFillType, ChartType, Image, Workbook,
+/// #     XlsxError,
+/// # };
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+/// #     // Add some data for the chart.
+/// #     worksheet.write(0, 0, 10)?;
+/// #     worksheet.write(1, 0, 40)?;
+/// #     worksheet.write(2, 0, 50)?;
+/// #     worksheet.write(3, 0, 20)?;
+/// #     worksheet.write(4, 0, 10)?;
+/// #     worksheet.write(5, 0, 50)?;
+/// #
+/// #     // Create a new chart.
+///     let mut chart = Chart::new(ChartType::Column);
+///
+///     // Add a data series with an image stacked in units of 10.
+///     let image = Image::new("examples/rust_logo.png")?;
+///
+///     chart
+///         .add_series()
+///         .set_values("Sheet1!$A$1:$A$6")
+///         .set_format(ChartFormat::new().set_picture_fill(
+///             ChartPictureFill::new(&image).set_type(ChartPictureFillType::StackAndScale(10.0)),
+///         ));
+///
+///     // Add the chart to the worksheet.
+///     worksheet.insert_chart(0, 2, &chart)?;
+/// #
+/// #     // Save the file.
+/// #     workbook.save("chart.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone)]
+pub struct ChartPictureFill {
+    pub(crate) image: Image,
+    pub(crate) fill_type: ChartPictureFillType,
+    pub(crate) transparency: u8,
+}
+
+impl ChartPictureFill {
+    /// Create a new `ChartPictureFill` object to represent a Chart picture
+    /// fill.
+    ///
+    /// # Parameters
+    ///
+    /// - `image`: The [`Image`] to use for the fill.
+    ///
+    pub fn new(image: &Image) -> ChartPictureFill {
+        ChartPictureFill {
+            image: image.clone(),
+            fill_type: ChartPictureFillType::Stretch,
+            transparency: 0,
+        }
+    }
+
+    /// Set the way the image is used to fill the chart element.
+    ///
+    /// The default is to stretch the image to fill the element. See the
+    /// [`ChartPictureFillType`] enum for the other options.
+    ///
+    /// # Parameters
+    ///
+    /// - `fill_type`: The picture fill type defined by a
+    ///   [`ChartPictureFillType`] enum value. The units of
+    ///   [`ChartPictureFillType::StackAndScale`] must be greater than 0.
+    ///
+    pub fn set_type(&mut self, fill_type: ChartPictureFillType) -> &mut ChartPictureFill {
+        if let ChartPictureFillType::StackAndScale(units) = fill_type {
+            if !units.is_finite() || units <= 0.0 {
+                eprintln!("Picture fill stack units '{units}' must be greater than 0.");
+                return self;
+            }
+        }
+
+        self.fill_type = fill_type;
+        self
+    }
+
+    /// Set the transparency of the picture fill.
+    ///
+    /// Set the transparency of the image used as a fill. This is useful for
+    /// background images that shouldn't obscure the chart data.
+    ///
+    /// # Parameters
+    ///
+    /// - `transparency`: The color transparency in the range 0 <= transparency
+    ///   <= 100. The default value is 0.
+    ///
+    pub fn set_transparency(&mut self, transparency: u8) -> &mut ChartPictureFill {
+        if transparency <= 100 {
+            self.transparency = transparency;
+        }
+
+        self
+    }
+}
+
+impl PartialEq for ChartPictureFill {
+    fn eq(&self, other: &Self) -> bool {
+        self.image.hash == other.image.hash
+            && self.fill_type == other.fill_type
+            && self.transparency == other.transparency
+    }
+}
+
+/// The `ChartPictureFillType` enum defines how an image fills a chart
+/// element.
+///
+/// It is used with [`ChartPictureFill::set_type()`].
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChartPictureFillType {
+    /// Stretch the image to fill the chart element. This is the default.
+    Stretch,
+
+    /// Tile the image, at its original size, to fill the chart element.
+    Tile,
+
+    /// Stack copies of the image, at its original size, to fill the chart
+    /// element. This only applies to the series of bar, column and area
+    /// charts. For other elements it is the same as `Stretch`.
+    Stack,
+
+    /// Stack copies of the image, where each image represents the given number
+    /// of units of the value axis. The number of units must be greater than 0.
+    /// This is sometimes referred to as a pictograph. This only applies to the series of bar, column and area
+    /// charts. For other elements it is the same as `Stretch`.
+    StackAndScale(f64),
+}
+
 // -----------------------------------------------------------------------
 // ChartFont
 // -----------------------------------------------------------------------
 
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// The `ChartFont` struct represents the font format for various chart objects.
 ///
 /// Excel uses a standard font dialog for text elements of a chart such as the
@@ -15691,6 +17603,7 @@ impl ChartFont {
 /// src="https://rustxlsxwriter.github.io/images/chart_trendline_intro.png">
 ///
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartTrendline {
     name: String,
     trend_type: ChartTrendlineType,
@@ -16222,6 +18135,7 @@ impl ChartTrendline {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// The `ChartTrendlineType` enum defines the trendline types of a
 /// [`ChartSeries`].
 ///
@@ -16337,6 +18251,7 @@ impl fmt::Display for ChartTrendlineType {
 /// <img src="https://rustxlsxwriter.github.io/images/chart_gradient_fill.png">
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartGradientFill {
     gradient_type: ChartGradientFillType,
     gradient_stops: Vec<ChartGradientStop>,
@@ -16661,6 +18576,7 @@ impl ChartGradientFill {
 ///
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartGradientStop {
     color: Color,
     position: u8,
@@ -16715,6 +18631,7 @@ impl ChartGradientStop {
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// The `ChartGradientFillType` enum defines the gradient types of a
 /// [`ChartGradientFill`].
 ///
@@ -16809,6 +18726,7 @@ pub enum ChartGradientFillType {
 /// src="https://rustxlsxwriter.github.io/images/chart_error_bars_intro.png">
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartErrorBars {
     has_end_cap: bool,
     error_type: ChartErrorBarsType,
@@ -16933,6 +18851,7 @@ impl ChartErrorBars {
 }
 
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// The `ChartErrorBarsType` enum defines the type of a chart series
 /// [`ChartErrorBars`].
 ///
@@ -16980,6 +18899,7 @@ impl fmt::Display for ChartErrorBarsType {
 }
 
 #[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 /// The `ChartErrorBarsDirection` enum defines the error bar direction for a
 /// chart series [`ChartErrorBars`].
 ///
@@ -17072,6 +18992,7 @@ impl fmt::Display for ChartErrorBarsDirection {
 /// <img src="https://rustxlsxwriter.github.io/images/chart_set_data_table.png">
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartDataTable {
     show_horizontal_borders: bool,
     show_vertical_borders: bool,
@@ -17281,6 +19202,7 @@ impl ChartDataTable {
 ///
 ///
 #[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ChartAxisCrossing {
     /// The axis crossing is at the default value which is generally zero. This
     /// is the default.
@@ -17336,6 +19258,7 @@ impl fmt::Display for ChartAxisCrossing {
 /// the opposite axis.
 ///
 #[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ChartAxisLabelAlignment {
     /// Center the axis label with the tick mark. This is the default.
     Center,
@@ -17422,6 +19345,7 @@ impl fmt::Display for ChartAxisLabelAlignment {
 /// src="https://rustxlsxwriter.github.io/images/chart_set_chart_area_format.png">
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartArea {
     pub(crate) format: ChartFormat,
 }
@@ -17592,6 +19516,7 @@ impl ChartArea {
 ///
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartPlotArea {
     pub(crate) format: ChartFormat,
     pub(crate) layout: ChartLayout,
@@ -17777,6 +19702,79 @@ impl ChartPlotArea {
     }
 }
 
+// -----------------------------------------------------------------------
+// ChartWall
+// -----------------------------------------------------------------------
+
+/// The `ChartWall` struct is a representation of the floor and walls of a 3D
+/// Excel chart.
+///
+/// The `ChartWall` struct can be used to configure the formatting of the floor,
+/// side wall and back wall of 3D charts and is usually obtained via the
+/// [`Chart::floor()`][Chart::floor], [`Chart::side_wall()`][Chart::side_wall]
+/// or [`Chart::back_wall()`][Chart::back_wall] methods.
+///
+/// It is used in conjunction with the [`Chart`] struct.
+///
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartWall {
+    pub(crate) format: ChartFormat,
+}
+
+impl Default for ChartWall {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChartWall {
+    /// Create a new `ChartWall` object to represent the floor or a wall of a
+    /// 3D Excel chart.
+    ///
+    pub fn new() -> ChartWall {
+        ChartWall {
+            format: ChartFormat::default(),
+        }
+    }
+
+    /// Set the formatting properties for the chart floor or wall.
+    ///
+    /// Set the formatting properties for a chart floor or wall via a
+    /// [`ChartFormat`] object or a sub struct that implements
+    /// [`IntoChartFormat`].
+    ///
+    /// The formatting that can be applied via a [`ChartFormat`] object are:
+    ///
+    /// - [`ChartFormat::set_solid_fill()`]: Set the [`ChartSolidFill`] properties.
+    /// - [`ChartFormat::set_pattern_fill()`]: Set the [`ChartPatternFill`] properties.
+    /// - [`ChartFormat::set_gradient_fill()`]: Set the [`ChartGradientFill`] properties.
+    /// - [`ChartFormat::set_no_fill()`]: Turn off the fill for the chart object.
+    /// - [`ChartFormat::set_line()`]: Set the [`ChartLine`] properties.
+    /// - [`ChartFormat::set_border()`]: Set the [`ChartBorder`] properties.
+    ///   A synonym for [`ChartLine`] depending on context.
+    /// - [`ChartFormat::set_no_line()`]: Turn off the line for the chart object.
+    /// - [`ChartFormat::set_no_border()`]: Turn off the border for the chart object.
+    ///
+    /// # Parameters
+    ///
+    /// `format`: A [`ChartFormat`] struct reference or a sub struct that will
+    /// convert into a `ChartFormat` instance. See the docs for
+    /// [`IntoChartFormat`] for details.
+    ///
+    /// # Examples
+    ///
+    /// See the [`Chart::floor()`][Chart::floor] example.
+    ///
+    pub fn set_format<T>(&mut self, format: T) -> &mut ChartWall
+    where
+        T: IntoChartFormat,
+    {
+        self.format = format.new_chart_format();
+        self
+    }
+}
+
 // -----------------------------------------------------------------------
 // ChartLayout
 // -----------------------------------------------------------------------
@@ -17896,6 +19894,7 @@ impl ChartPlotArea {
 /// src="https://rustxlsxwriter.github.io/images/chart_plot_area_set_layout.png">
 ///
 #[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ChartLayout {
     pub(crate) x_offset: Option<f64>,
     pub(crate) y_offset: Option<f64>,
@@ -18005,3 +20004,219 @@ impl ChartLayout {
             || self.height.is_some()
     }
 }
+
+// -----------------------------------------------------------------------
+// ChartTemplate
+// -----------------------------------------------------------------------
+
+// The version of the `ChartTemplate` format. This should be increased if the
+// template fields change in a way that isn't compatible with saved templates.
+const CHART_TEMPLATE_VERSION: u16 = 1;
+
+/// The `ChartTemplate` struct represents a reusable chart style.
+///
+/// A `ChartTemplate` stores the type, formatting and options of a configured
+/// [`Chart`], such as the axes, fonts, fills, legend and series formats,
+/// without the worksheet data ranges. New charts with the same appearance can
+/// then be created from the template via [`Chart::from_template()`].
+///
+/// When the `serde` feature is enabled `ChartTemplate` implements the Serde
+/// `Serialize` and `Deserialize` traits so that it can be saved and reloaded
+/// using any Serde data format, such as JSON. The serialized template only
+/// contains the chart style properties and a format version number, which is
+/// checked by [`Chart::from_template()`]. Templates with picture fills, see
+/// [`ChartPictureFill`], or with shapes inserted via [`Chart::insert_shape()`]
+/// can be used in memory but return an error if they are serialized.
+///
+/// It is used in conjunction with the [`Chart`] struct.
+///
+/// # Examples
+///
+/// The following example demonstrates creating a chart template from a
+/// formatted chart and using it to create a new chart.
+///
+/// ```
+/// # // This code is available in examples/doc_chart_template.rs
+/// #
+/// # use rust_xlsxwriter::{Chart, ChartTemplate, ChartType, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+/// #     // Add some data for the chart.
+/// #     worksheet.write_column(0, 0, [10, 40, 50, 20, 10, 50])?;
+/// #     worksheet.write_column(0, 1, [30, 60, 70, 50, 40, 30])?;
+/// #
+///     // Create and format a chart to use as a template.
+///     let mut chart = Chart::new(ChartType::Line);
+///     chart.set_style(37);
+///     chart.x_axis().set_name("Month");
+///     chart.y_axis().set_name("Sales");
+///     chart.add_series().set_values("Sheet1!$A$1:$A$6");
+///
+///     let template = ChartTemplate::new(&chart);
+///
+///     // Create a new chart with the same formatting but different data.
+///     let mut chart = Chart::from_template(&template)?;
+///     chart.add_series().set_values("Sheet1!$B$1:$B$6");
+///
+///     worksheet.insert_chart(0, 3, &chart)?;
+/// #
+/// #     // Save the file.
+/// #     workbook.save("chart.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartTemplate {
+    version: u16,
+    chart_type: ChartType,
+    width: f64,
+    height: f64,
+    scale_width: f64,
+    scale_height: f64,
+    object_movement: ObjectMovement,
+    title: ChartTitle,
+    legend: ChartLegend,
+    x_axis: ChartAxis,
+    y_axis: ChartAxis,
+    x2_axis: ChartAxis,
+    y2_axis: ChartAxis,
+    z_axis: ChartAxis,
+    chart_area: ChartArea,
+    plot_area: ChartPlotArea,
+    floor: ChartWall,
+    side_wall: ChartWall,
+    back_wall: ChartWall,
+    style: u8,
+    show_empty_cells_as: Option<ChartEmptyCells>,
+    show_hidden_data: bool,
+    show_na_as_empty: bool,
+    hole_size: u8,
+    rotation: u16,
+    elevation: i8,
+    perspective: Option<u8>,
+    depth_percent: u16,
+    right_angle_axes: bool,
+    has_up_down_bars: bool,
+    up_bar_format: ChartFormat,
+    down_bar_format: ChartFormat,
+    has_high_low_lines: bool,
+    high_low_lines_format: ChartFormat,
+    has_drop_lines: bool,
+    drop_lines_format: ChartFormat,
+    table: Option<ChartDataTable>,
+    series: Vec<ChartSeries>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            skip_deserializing,
+            skip_serializing_if = "Vec::is_empty",
+            serialize_with = "serialize_shapes"
+        )
+    )]
+    shapes: Vec<Shape>,
+}
+
+impl ChartTemplate {
+    /// Create a new `ChartTemplate` from a chart.
+    ///
+    /// Create a chart template from a configured [`Chart`]. The template keeps
+    /// the chart type, formatting and options, and the formatting of each
+    /// series. The series data ranges and names aren't stored in the
+    /// template.
+    ///
+    /// Charts combined with the chart via [`Chart::combine()`] aren't stored
+    /// in the template since their series can't be changed. Create a separate
+    /// template for the secondary chart and combine the charts created from
+    /// the templates instead.
+    ///
+    /// # Parameters
+    ///
+    /// - `chart`: The [`Chart`] to use as a template.
+    ///
+    pub fn new(chart: &Chart) -> ChartTemplate {
+        // Use the chart series, or the series templates of a chart created
+        // from a template, as the series formatting.
+        let mut series = if chart.series.is_empty() {
+            chart.series_templates.clone()
+        } else {
+            chart.series.clone()
+        };
+
+        for series in &mut series {
+            series.value_range = ChartRange::default();
+            series.category_range = ChartRange::default();
+            series.title.range = ChartRange::default();
+            series.title.name = String::new();
+        }
+
+        ChartTemplate {
+            version: CHART_TEMPLATE_VERSION,
+            chart_type: chart.chart_type,
+            width: chart.width,
+            height: chart.height,
+            scale_width: chart.scale_width,
+            scale_height: chart.scale_height,
+            object_movement: chart.object_movement,
+            title: chart.title.clone(),
+            legend: chart.legend.clone(),
+            x_axis: chart.x_axis.clone(),
+            y_axis: chart.y_axis.clone(),
+            x2_axis: chart.x2_axis.clone(),
+            y2_axis: chart.y2_axis.clone(),
+            z_axis: chart.z_axis.clone(),
+            chart_area: chart.chart_area.clone(),
+            plot_area: chart.plot_area.clone(),
+            floor: chart.floor.clone(),
+            side_wall: chart.side_wall.clone(),
+            back_wall: chart.back_wall.clone(),
+            style: chart.style,
+            show_empty_cells_as: chart.show_empty_cells_as,
+            show_hidden_data: chart.show_hidden_data,
+            show_na_as_empty: chart.show_na_as_empty,
+            hole_size: chart.hole_size,
+            rotation: chart.rotation,
+            elevation: chart.elevation,
+            perspective: chart.perspective,
+            depth_percent: chart.depth_percent,
+            right_angle_axes: chart.right_angle_axes,
+            has_up_down_bars: chart.has_up_down_bars,
+            up_bar_format: chart.up_bar_format.clone(),
+            down_bar_format: chart.down_bar_format.clone(),
+            has_high_low_lines: chart.has_high_low_lines,
+            high_low_lines_format: chart.high_low_lines_format.clone(),
+            has_drop_lines: chart.has_drop_lines,
+            drop_lines_format: chart.drop_lines_format.clone(),
+            table: chart.table.clone(),
+            series,
+            shapes: chart.shapes.clone(),
+        }
+    }
+}
+
+// Picture fills and shapes contain image data and aren't stored in serialized
+// chart templates. Raise an error instead of silently dropping them.
+#[cfg(feature = "serde")]
+fn serialize_picture_fill<S: serde::Serializer>(
+    _picture_fill: &Option<ChartPictureFill>,
+    _serializer: S,
+) -> Result<S::Ok, S::Error> {
+    Err(serde::ser::Error::custom(
+        "chart picture fills can't be serialized in a chart template",
+    ))
+}
+
+#[cfg(feature = "serde")]
+fn serialize_shapes<S: serde::Serializer>(
+    _shapes: &[Shape],
+    _serializer: S,
+) -> Result<S::Ok, S::Error> {
+    Err(serde::ser::Error::custom(
+        "chart shapes can't be serialized in a chart template",
+    ))
+}
@@ -7,9 +7,12 @@
 #[cfg(test)]
 mod chart_tests {
 
-    use crate::chart::{Chart, ChartRange, ChartSeries, ChartType, XlsxError};
+    use crate::chart::{
+        Chart, ChartFormat, ChartPictureFill, ChartPictureFillType, ChartRange, ChartSeries,
+        ChartSolidFill, ChartTemplate, ChartType, XlsxError,
+    };
     use crate::test_functions::xml_to_vec;
-    use crate::{xmlwriter, ChartRangeCacheDataType};
+    use crate::{xmlwriter, ChartRangeCacheDataType, Image, Shape, ShapeFormat, ShapePictureFill};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -277,6 +280,393 @@ mod chart_tests {
         assert_eq!(expected, got);
     }
 
+    #[test]
+    fn test_chart_template() -> Result<(), XlsxError> {
+        // Create a formatted chart to use as a template.
+        let mut chart = Chart::new(ChartType::Column);
+        chart.title().set_name("Results");
+        chart.set_style(11);
+        chart
+            .add_series()
+            .set_values("Sheet1!$A$1:$A$5")
+            .set_name("Old")
+            .set_format(
+                ChartFormat::new().set_solid_fill(ChartSolidFill::new().set_color("#FF0000")),
+            );
+
+        let template = ChartTemplate::new(&chart);
+
+        // Create a chart from the template with new data.
+        let mut got_chart = Chart::from_template(&template)?;
+        got_chart.add_series().set_values("Sheet1!$B$1:$B$5");
+        got_chart.add_axis_ids(1);
+
+        // Create the equivalent chart directly.
+        let mut expected_chart = Chart::new(ChartType::Column);
+        expected_chart.title().set_name("Results");
+        expected_chart.set_style(11);
+        expected_chart
+            .add_series()
+            .set_values("Sheet1!$B$1:$B$5")
+            .set_format(
+                ChartFormat::new().set_solid_fill(ChartSolidFill::new().set_color("#FF0000")),
+            );
+        expected_chart.add_axis_ids(1);
+
+        got_chart.assemble_xml_file();
+        expected_chart.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&got_chart.writer);
+        let expected = xmlwriter::cursor_to_str(&expected_chart.writer);
+
+        assert_eq!(xml_to_vec(expected), xml_to_vec(got));
+
+        // Additional series use the default formatting.
+        let mut chart = Chart::from_template(&template)?;
+        chart.add_series();
+        chart.add_series();
+
+        assert!(chart.series[0].format.has_formatting());
+        assert!(!chart.series[1].format.has_formatting());
+
+        // A template created from a templated chart keeps the series formats.
+        let template = ChartTemplate::new(&Chart::from_template(&template)?);
+        assert_eq!(template.series.len(), 1);
+        assert!(template.series[0].format.has_formatting());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chart_template_version() {
+        let chart = Chart::new(ChartType::Column);
+        let mut template = ChartTemplate::new(&chart);
+        template.version = 0;
+
+        let result = Chart::from_template(&template);
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+    }
+
+    #[test]
+    fn test_assemble_surface_3d() {
+        let mut range1 = ChartRange::new_from_string("Sheet1!$A$1:$A$3");
+        range1.set_cache(&["1", "2", "3"], ChartRangeCacheDataType::Number);
+
+        let mut range2 = ChartRange::new_from_string("Sheet1!$B$1:$B$3");
+        range2.set_cache(&["2", "4", "6"], ChartRangeCacheDataType::Number);
+
+        let mut chart = Chart::new(ChartType::Surface3D);
+        chart.add_series().set_values(&range1);
+        chart.add_series().set_values(&range2);
+        chart.set_axis_ids(50010001, 50010002);
+        chart.legend().set_hidden();
+
+        chart.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&chart.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                    <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                    <c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+                      <c:lang val="en-US"/>
+                      <c:chart>
+                        <c:view3D>
+                          <c:rotX val="15"/>
+                          <c:rotY val="20"/>
+                          <c:rAngAx val="0"/>
+                        </c:view3D>
+                        <c:floor>
+                          <c:thickness val="0"/>
+                        </c:floor>
+                        <c:sideWall>
+                          <c:thickness val="0"/>
+                        </c:sideWall>
+                        <c:backWall>
+                          <c:thickness val="0"/>
+                        </c:backWall>
+                        <c:plotArea>
+                          <c:layout/>
+                          <c:surface3DChart>
+                            <c:wireframe val="0"/>
+                            <c:ser>
+                              <c:idx val="0"/>
+                              <c:order val="0"/>
+                              <c:val>
+                                <c:numRef>
+                                  <c:f>Sheet1!$A$1:$A$3</c:f>
+                                  <c:numCache>
+                                    <c:formatCode>General</c:formatCode>
+                                    <c:ptCount val="3"/>
+                                    <c:pt idx="0">
+                                      <c:v>1</c:v>
+                                    </c:pt>
+                                    <c:pt idx="1">
+                                      <c:v>2</c:v>
+                                    </c:pt>
+                                    <c:pt idx="2">
+                                      <c:v>3</c:v>
+                                    </c:pt>
+                                  </c:numCache>
+                                </c:numRef>
+                              </c:val>
+                            </c:ser>
+                            <c:ser>
+                              <c:idx val="1"/>
+                              <c:order val="1"/>
+                              <c:val>
+                                <c:numRef>
+                                  <c:f>Sheet1!$B$1:$B$3</c:f>
+                                  <c:numCache>
+                                    <c:formatCode>General</c:formatCode>
+                                    <c:ptCount val="3"/>
+                                    <c:pt idx="0">
+                                      <c:v>2</c:v>
+                                    </c:pt>
+                                    <c:pt idx="1">
+                                      <c:v>4</c:v>
+                                    </c:pt>
+                                    <c:pt idx="2">
+                                      <c:v>6</c:v>
+                                    </c:pt>
+                                  </c:numCache>
+                                </c:numRef>
+                              </c:val>
+                            </c:ser>
+                            <c:bandFmts/>
+                            <c:axId val="50010001"/>
+                            <c:axId val="50010002"/>
+                            <c:axId val="50010005"/>
+                          </c:surface3DChart>
+                          <c:catAx>
+                            <c:axId val="50010001"/>
+                            <c:scaling>
+                              <c:orientation val="minMax"/>
+                            </c:scaling>
+                            <c:axPos val="b"/>
+                            <c:tickLblPos val="nextTo"/>
+                            <c:crossAx val="50010002"/>
+                            <c:crosses val="autoZero"/>
+                            <c:auto val="1"/>
+                            <c:lblAlgn val="ctr"/>
+                            <c:lblOffset val="100"/>
+                          </c:catAx>
+                          <c:valAx>
+                            <c:axId val="50010002"/>
+                            <c:scaling>
+                              <c:orientation val="minMax"/>
+                            </c:scaling>
+                            <c:axPos val="l"/>
+                            <c:majorGridlines/>
+                            <c:numFmt formatCode="General" sourceLinked="1"/>
+                            <c:tickLblPos val="nextTo"/>
+                            <c:crossAx val="50010001"/>
+                            <c:crosses val="autoZero"/>
+                            <c:crossBetween val="midCat"/>
+                          </c:valAx>
+                          <c:serAx>
+                            <c:axId val="50010005"/>
+                            <c:scaling>
+                              <c:orientation val="minMax"/>
+                            </c:scaling>
+                            <c:axPos val="b"/>
+                            <c:tickLblPos val="nextTo"/>
+                            <c:crossAx val="50010002"/>
+                            <c:crosses val="autoZero"/>
+                          </c:serAx>
+                        </c:plotArea>
+                        <c:plotVisOnly val="1"/>
+                      </c:chart>
+                      <c:printSettings>
+                        <c:headerFooter/>
+                        <c:pageMargins b="0.75" l="0.7" r="0.7" t="0.75" header="0.3" footer="0.3"/>
+                        <c:pageSetup/>
+                      </c:printSettings>
+                    </c:chartSpace>
+
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_3d_validation() {
+        // Check for 3D chart with a secondary axis series.
+        let mut chart = Chart::new(ChartType::Column3D);
+        chart
+            .add_series()
+            .set_values("Sheet1!$A$1:$A$3")
+            .set_secondary_axis(true);
+        let result = chart.validate();
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+
+        // Check for 3D chart combined with another chart.
+        let mut chart = Chart::new(ChartType::Column3D);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        let mut line_chart = Chart::new(ChartType::Line);
+        line_chart.add_series().set_values("Sheet1!$B$1:$B$3");
+        chart.combine(&line_chart);
+        let result = chart.validate();
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+    }
+
+    #[test]
+    fn test_user_shapes_validation() {
+        // Check for a chart shape with a hyperlink.
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        let shape = Shape::textbox()
+            .set_url("https://www.rust-lang.org")
+            .unwrap();
+        chart.insert_shape(&shape, 10, 10);
+        let result = chart.validate();
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+
+        // Check for a chart shape with a picture fill.
+        let image = Image::new("tests/input/images/red.png").unwrap();
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        let shape = Shape::textbox()
+            .set_format(&ShapeFormat::new().set_picture_fill(&ShapePictureFill::new(&image)));
+        chart.insert_shape(&shape, 10, 10);
+        let result = chart.validate();
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+    }
+
+    #[test]
+    fn test_group_series_by_chart_type() {
+        let mut line_chart = Chart::new(ChartType::Line);
+        line_chart.add_series().set_values("Sheet1!$D$1:$D$5");
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+        chart
+            .add_series()
+            .set_values("Sheet1!$B$1:$B$5")
+            .set_chart_type(ChartType::Line);
+        chart
+            .add_series()
+            .set_values("Sheet1!$C$1:$C$5")
+            .set_chart_type(ChartType::Area)
+            .set_secondary_axis(true);
+        chart.combine(&line_chart);
+
+        chart.group_series_by_chart_type();
+
+        // The Line series is added to the existing Line chart and a new Area
+        // chart is created for the Area series.
+        assert_eq!(chart.series.len(), 1);
+        assert_eq!(chart.combined_charts.len(), 2);
+        assert!(chart.combined_charts[0].chart_type == ChartType::Line);
+        assert_eq!(chart.combined_charts[0].series.len(), 2);
+        assert!(chart.combined_charts[1].chart_type == ChartType::Area);
+        assert_eq!(chart.combined_charts[1].series.len(), 1);
+
+        // Grouping is idempotent.
+        chart.group_series_by_chart_type();
+        assert_eq!(chart.series.len(), 1);
+        assert_eq!(chart.combined_charts.len(), 2);
+
+        // Check that the series indices continue across the chart groups and
+        // that the secondary axes are written.
+        chart.set_axis_ids(50010001, 50010002);
+        chart.set_axis2_ids(60010001, 60010002);
+        chart.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&chart.writer);
+        let got = xml_to_vec(got);
+
+        for idx in 0..4 {
+            assert!(got.contains(&format!(r#"<c:idx val="{idx}"/>"#)));
+        }
+        assert!(got.contains(&"<c:areaChart>".to_string()));
+        assert!(got.contains(&r#"<c:axId val="60010002"/>"#.to_string()));
+    }
+
+    #[test]
+    fn test_picture_fill() {
+        let logo = Image::new("tests/input/images/red.png").unwrap();
+        let background = Image::new("tests/input/images/blue.png").unwrap();
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart
+            .add_series()
+            .set_values("Sheet1!$A$1:$A$5")
+            .set_format(ChartFormat::new().set_picture_fill(
+                ChartPictureFill::new(&logo).set_type(ChartPictureFillType::StackAndScale(2.5)),
+            ));
+        chart.chart_area().set_format(
+            ChartFormat::new().set_picture_fill(
+                ChartPictureFill::new(&background)
+                    .set_type(ChartPictureFillType::Tile)
+                    .set_transparency(40),
+            ),
+        );
+
+        // The images are listed in the order they are written.
+        let images = chart.picture_fill_images();
+        assert_eq!(images.len(), 2);
+        assert_eq!(images[0].hash, background.hash);
+        assert_eq!(images[1].hash, logo.hash);
+
+        chart.drawing_rel_ids.insert(background.hash.clone(), 1);
+        chart.drawing_rel_ids.insert(logo.hash.clone(), 2);
+        chart.set_axis_ids(50010001, 50010002);
+        chart.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&chart.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                    <c:spPr>
+                      <a:blipFill>
+                        <a:blip r:embed="rId2"/>
+                        <a:stretch>
+                          <a:fillRect/>
+                        </a:stretch>
+                      </a:blipFill>
+                    </c:spPr>
+                    <c:pictureOptions>
+                      <c:pictureFormat val="stackScale"/>
+                      <c:pictureStackUnit val="2.5"/>
+                    </c:pictureOptions>
+                "#,
+        );
+        assert!(got.windows(expected.len()).any(|window| window == expected));
+
+        let expected = xml_to_vec(
+            r#"
+                    <c:spPr>
+                      <a:blipFill>
+                        <a:blip r:embed="rId1">
+                          <a:alphaModFix amt="60000"/>
+                        </a:blip>
+                        <a:tile tx="0" ty="0" sx="100000" sy="100000" flip="none" algn="tl"/>
+                      </a:blipFill>
+                    </c:spPr>
+                "#,
+        );
+        assert!(got.windows(expected.len()).any(|window| window == expected));
+    }
+
+    #[test]
+    fn test_picture_fill_stack_units() {
+        let logo = Image::new("tests/input/images/red.png").unwrap();
+        let mut fill = ChartPictureFill::new(&logo);
+
+        // Invalid stack units are ignored.
+        for units in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            fill.set_type(ChartPictureFillType::StackAndScale(units));
+            assert_eq!(fill.fill_type, ChartPictureFillType::Stretch);
+        }
+
+        fill.set_type(ChartPictureFillType::StackAndScale(0.5));
+        assert_eq!(fill.fill_type, ChartPictureFillType::StackAndScale(0.5));
+    }
+
     #[test]
     fn test_range_from_string() {
         let range_string = "=Sheet1!$A$1:$A$5";
@@ -294,4 +684,108 @@ mod chart_tests {
         assert_eq!("'Sheet 1'!$A$1:$A$5", range.formula_abs());
         assert_eq!("Sheet 1", range.sheet_name);
     }
+
+    #[test]
+    fn test_literal_ranges() {
+        let mut chart = Chart::new(ChartType::Column);
+        chart
+            .add_series()
+            .set_categories(&["Jan", "Feb", "Mar"])
+            .set_values(&[50.0, 30.5, 40.0]);
+
+        // Literal ranges are ignored for titles.
+        chart.title().set_name(&["Title"]);
+        assert!(!chart.title.range.has_data());
+
+        chart.validate().unwrap();
+        chart.set_axis_ids(50010001, 50010002);
+        chart.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&chart.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                    <c:cat>
+                      <c:strLit>
+                        <c:ptCount val="3"/>
+                        <c:pt idx="0">
+                          <c:v>Jan</c:v>
+                        </c:pt>
+                        <c:pt idx="1">
+                          <c:v>Feb</c:v>
+                        </c:pt>
+                        <c:pt idx="2">
+                          <c:v>Mar</c:v>
+                        </c:pt>
+                      </c:strLit>
+                    </c:cat>
+                    <c:val>
+                      <c:numLit>
+                        <c:formatCode>General</c:formatCode>
+                        <c:ptCount val="3"/>
+                        <c:pt idx="0">
+                          <c:v>50</c:v>
+                        </c:pt>
+                        <c:pt idx="1">
+                          <c:v>30.5</c:v>
+                        </c:pt>
+                        <c:pt idx="2">
+                          <c:v>40</c:v>
+                        </c:pt>
+                      </c:numLit>
+                    </c:val>
+                "#,
+        );
+        assert!(got.windows(expected.len()).any(|window| window == expected));
+
+        // Empty literal ranges don't count as series values.
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values(&Vec::<f64>::new());
+        assert!(chart.validate().is_err());
+
+        // Integer literal data is converted to the same values as f64 data.
+        let range = ChartRange::new_from_numbers(&[50, 30, 40]);
+        assert_eq!(range.cache.data, ["50", "30", "40"]);
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values(&vec![50_u32, 30, 40]);
+        assert_eq!(chart.series[0].value_range.cache.data, ["50", "30", "40"]);
+    }
+
+    #[test]
+    fn test_literal_ranges_non_finite() {
+        let range =
+            ChartRange::new_from_numbers(&[1.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY]);
+        assert_eq!(range.cache.data, ["1", "", "", ""]);
+
+        let mut chart = Chart::new(ChartType::Line);
+        chart.add_series().set_values(&[1.0, f64::INFINITY, 3.0]);
+
+        chart.validate().unwrap();
+        chart.set_axis_ids(50010001, 50010002);
+        chart.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&chart.writer);
+        let got = xml_to_vec(got);
+
+        // Non-finite values are written as gaps in the literal data.
+        let expected = xml_to_vec(
+            r#"
+                    <c:val>
+                      <c:numLit>
+                        <c:formatCode>General</c:formatCode>
+                        <c:ptCount val="3"/>
+                        <c:pt idx="0">
+                          <c:v>1</c:v>
+                        </c:pt>
+                        <c:pt idx="2">
+                          <c:v>3</c:v>
+                        </c:pt>
+                      </c:numLit>
+                    </c:val>
+                "#,
+        );
+        assert!(got.windows(expected.len()).any(|window| window == expected));
+    }
 }
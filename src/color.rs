@@ -8,6 +8,9 @@
 
 mod tests;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 /// The `Color` enum defines Excel colors that can be used throughout the
 /// `rust_xlsxwriter` APIs.
 ///
@@ -136,6 +139,7 @@ mod tests;
 /// <img src="https://rustxlsxwriter.github.io/images/into_color.png">
 ///
 #[derive(Debug, Clone, Copy, Hash, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Color {
     /// A user-defined RGB color in the range 0x000000 (black) to 0xFFFFFF
     /// (white). Any values outside this range will be ignored with a warning.
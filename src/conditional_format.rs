@@ -404,6 +404,60 @@
 //! src="https://rustxlsxwriter.github.io/images/conditional_format_anchor4.png">
 //!
 //!
+//! # Referencing other worksheets
+//!
+//! The rules in [`ConditionalFormatCell`] and [`ConditionalFormatFormula`]
+//! conditional formats can refer to cells in other worksheets, for example to
+//! compare values against targets stored in a configuration sheet. Excel
+//! doesn't allow these rules in the standard conditional format section of the
+//! worksheet so `rust_xlsxwriter` writes them as Excel 2010 extensions. This
+//! is handled automatically when the rule contains a sheet reference like
+//! `Config!$B$1` or `'Sales Targets'!$B$1`.
+//!
+//! For [`ConditionalFormatCell`] the value should be a [`Formula`] to
+//! distinguish the range from a string.
+//!
+//! ```
+//! # // This code is available in examples/doc_conditional_format_other_sheet.rs
+//! #
+//! # use rust_xlsxwriter::{
+//! #     ConditionalFormatCell, ConditionalFormatCellRule, Format, Formula, Workbook, XlsxError,
+//! # };
+//! #
+//! # fn main() -> Result<(), XlsxError> {
+//! #     // Create a new Excel file object.
+//! #     let mut workbook = Workbook::new();
+//! #
+//! #     // Add a format. Green fill with dark green text.
+//! #     let format = Format::new()
+//! #         .set_font_color("006100")
+//! #         .set_background_color("C6EFCE");
+//! #
+//! #     // Add some sample data.
+//! #     let worksheet = workbook.add_worksheet();
+//! #     worksheet.write_column(0, 0, [10, 80, 50, 10, 20, 60, 40, 70, 30, 40])?;
+//! #
+//!     // Compare the values against a target in another worksheet.
+//!     let conditional_format = ConditionalFormatCell::new()
+//!         .set_rule(ConditionalFormatCellRule::GreaterThan(Formula::new(
+//!             "Config!$B$1",
+//!         )))
+//!         .set_format(format);
+//!
+//!     worksheet.add_conditional_format(0, 0, 9, 0, &conditional_format)?;
+//!
+//!     // Add the target value to the other worksheet.
+//!     let config = workbook.add_worksheet().set_name("Config")?;
+//!     config.write(0, 0, "Target")?;
+//!     config.write(0, 1, 50)?;
+//! #
+//! #     // Save the file.
+//! #     workbook.save("conditional_format.xlsx")?;
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
 //!
 //! # Examples
 //!
@@ -921,9 +975,10 @@ use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 
 use std::{borrow::Cow, fmt, io::Cursor};
 
+use crate::styles::Styles;
 use crate::xmlwriter::{
-    cursor_to_string, xml_data_element_only, xml_empty_tag, xml_end_tag, xml_start_tag,
-    xml_start_tag_only,
+    cursor_to_string, xml_data_element_only, xml_empty_tag, xml_end_tag, xml_raw_string,
+    xml_start_tag, xml_start_tag_only,
 };
 use crate::{Color, ExcelDateTime, Format, Formula, XlsxError};
 
@@ -955,6 +1010,14 @@ pub trait ConditionalFormat {
     /// Get the index of the format object in the conditional format.
     fn format_index(&self) -> Option<u32>;
 
+    /// Get the user defined priority of the conditional format, if set.
+    ///
+    /// The default implementation returns `None` so that the conditional
+    /// format is prioritized in the order it was added to the worksheet.
+    fn priority(&self) -> Option<u32> {
+        None
+    }
+
     /// Get the multi-cell range for the conditional format, if present.
     fn multi_range(&self) -> String;
 
@@ -992,6 +1055,10 @@ macro_rules! generate_conditional_format_impls {
                 self.format_index()
             }
 
+            fn priority(&self) -> Option<u32> {
+                self.priority()
+            }
+
             fn multi_range(&self) -> String {
                 self.multi_range()
             }
@@ -1199,6 +1266,7 @@ pub struct ConditionalFormatCell {
     rule: Option<ConditionalFormatCellRule<ConditionalFormatValue>>,
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -1212,6 +1280,7 @@ impl ConditionalFormatCell {
             rule: None,
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -1371,6 +1440,24 @@ impl ConditionalFormatCell {
             }
         }
 
+        // Rules that refer to ranges in other worksheets can only be stored
+        // in the Excel 2010 extension section of the worksheet.
+        let has_sheet_reference = match &rule {
+            ConditionalFormatCellRule::EqualTo(value)
+            | ConditionalFormatCellRule::NotEqualTo(value)
+            | ConditionalFormatCellRule::LessThan(value)
+            | ConditionalFormatCellRule::LessThanOrEqualTo(value)
+            | ConditionalFormatCellRule::GreaterThan(value)
+            | ConditionalFormatCellRule::GreaterThanOrEqualTo(value) => value.has_sheet_reference(),
+            ConditionalFormatCellRule::Between(min, max)
+            | ConditionalFormatCellRule::NotBetween(min, max) => {
+                min.has_sheet_reference() || max.has_sheet_reference()
+            }
+        };
+
+        self.has_x14_extensions = has_sheet_reference;
+        self.has_x14_only = has_sheet_reference;
+
         self.rule = Some(rule);
         self
     }
@@ -1459,10 +1546,57 @@ impl ConditionalFormatCell {
         cursor_to_string(&writer)
     }
 
-    // Return an extended x14 rule for conditional formats that support it.
-    #[allow(clippy::unused_self)]
-    pub(crate) fn x14_rule(&self, _priority: u32, _guid: &str) -> String {
-        String::new()
+    // Return an extended x14 rule for rules that refer to other worksheets.
+    pub(crate) fn x14_rule(&self, priority: u32, guid: &str) -> String {
+        let Some(rule) = &self.rule else {
+            return String::new();
+        };
+
+        if !self.has_x14_only {
+            return String::new();
+        }
+
+        let mut writer = Cursor::new(Vec::with_capacity(2048));
+        let mut attributes = vec![
+            ("type", "cellIs".to_string()),
+            ("priority", priority.to_string()),
+        ];
+
+        // Set the "Stop if True" property.
+        if self.stop_if_true {
+            attributes.push(("stopIfTrue", "1".to_string()));
+        }
+
+        attributes.push(("operator", rule.to_string()));
+        attributes.push(("id", guid.to_string()));
+
+        // Write the rule.
+        xml_start_tag(&mut writer, "x14:cfRule", &attributes);
+
+        match rule {
+            ConditionalFormatCellRule::EqualTo(value)
+            | ConditionalFormatCellRule::NotEqualTo(value)
+            | ConditionalFormatCellRule::LessThan(value)
+            | ConditionalFormatCellRule::LessThanOrEqualTo(value)
+            | ConditionalFormatCellRule::GreaterThan(value)
+            | ConditionalFormatCellRule::GreaterThanOrEqualTo(value) => {
+                xml_data_element_only(&mut writer, "xm:f", &value.value);
+            }
+            ConditionalFormatCellRule::Between(min, max)
+            | ConditionalFormatCellRule::NotBetween(min, max) => {
+                xml_data_element_only(&mut writer, "xm:f", &min.value);
+                xml_data_element_only(&mut writer, "xm:f", &max.value);
+            }
+        }
+
+        // Write the <x14:dxf> element.
+        if let Some(format) = &self.format {
+            xml_raw_string(&mut writer, &x14_dxf_to_string(format));
+        }
+
+        xml_end_tag(&mut writer, "x14:cfRule");
+
+        cursor_to_string(&writer)
     }
 }
 
@@ -1550,6 +1684,7 @@ pub struct ConditionalFormatBlank {
     is_inverted: bool,
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -1563,6 +1698,7 @@ impl ConditionalFormatBlank {
             is_inverted: false,
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -1751,6 +1887,7 @@ pub struct ConditionalFormatError {
     is_inverted: bool,
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -1764,6 +1901,7 @@ impl ConditionalFormatError {
             is_inverted: false,
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -1953,6 +2091,7 @@ pub struct ConditionalFormatDuplicate {
     is_inverted: bool,
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -1966,6 +2105,7 @@ impl ConditionalFormatDuplicate {
             is_inverted: false,
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -2148,6 +2288,7 @@ pub struct ConditionalFormatFormula {
     formula: Formula,
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -2161,6 +2302,7 @@ impl ConditionalFormatFormula {
             formula: Formula::new(""),
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -2259,6 +2401,13 @@ impl ConditionalFormatFormula {
     ///
     pub fn set_rule(mut self, rule: impl Into<Formula>) -> ConditionalFormatFormula {
         self.formula = rule.into();
+
+        // Rules that refer to ranges in other worksheets can only be stored
+        // in the Excel 2010 extension section of the worksheet.
+        let has_sheet_reference = has_sheet_reference(&self.formula.formula_string);
+        self.has_x14_extensions = has_sheet_reference;
+        self.has_x14_only = has_sheet_reference;
+
         self
     }
 
@@ -2326,10 +2475,37 @@ impl ConditionalFormatFormula {
         cursor_to_string(&writer)
     }
 
-    // Return an extended x14 rule for conditional formats that support it.
-    #[allow(clippy::unused_self)]
-    pub(crate) fn x14_rule(&self, _priority: u32, _guid: &str) -> String {
-        String::new()
+    // Return an extended x14 rule for rules that refer to other worksheets.
+    pub(crate) fn x14_rule(&self, priority: u32, guid: &str) -> String {
+        if !self.has_x14_only {
+            return String::new();
+        }
+
+        let mut writer = Cursor::new(Vec::with_capacity(2048));
+        let mut attributes = vec![
+            ("type", "expression".to_string()),
+            ("priority", priority.to_string()),
+        ];
+
+        // Set the "Stop if True" property.
+        if self.stop_if_true {
+            attributes.push(("stopIfTrue", "1".to_string()));
+        }
+
+        attributes.push(("id", guid.to_string()));
+
+        // Write the rule.
+        xml_start_tag(&mut writer, "x14:cfRule", &attributes);
+        xml_data_element_only(&mut writer, "xm:f", &self.formula.formula_string);
+
+        // Write the <x14:dxf> element.
+        if let Some(format) = &self.format {
+            xml_raw_string(&mut writer, &x14_dxf_to_string(format));
+        }
+
+        xml_end_tag(&mut writer, "x14:cfRule");
+
+        cursor_to_string(&writer)
     }
 }
 
@@ -2427,6 +2603,7 @@ pub struct ConditionalFormatAverage {
     criteria: ConditionalFormatAverageRule,
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -2440,6 +2617,7 @@ impl ConditionalFormatAverage {
             criteria: ConditionalFormatAverageRule::AboveAverage,
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -2669,6 +2847,7 @@ pub struct ConditionalFormatTop {
 
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -2683,6 +2862,7 @@ impl ConditionalFormatTop {
 
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -2922,6 +3102,7 @@ pub struct ConditionalFormatText {
 
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -2936,6 +3117,7 @@ impl ConditionalFormatText {
 
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -3189,6 +3371,7 @@ pub struct ConditionalFormatDate {
 
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -3203,6 +3386,7 @@ impl ConditionalFormatDate {
 
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -3440,6 +3624,7 @@ pub struct ConditionalFormat2ColorScale {
 
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -3459,6 +3644,7 @@ impl ConditionalFormat2ColorScale {
             max_color: Color::RGB(0x63BE7B),
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -3903,6 +4089,7 @@ pub struct ConditionalFormat3ColorScale {
 
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -3925,6 +4112,7 @@ impl ConditionalFormat3ColorScale {
             max_color: Color::RGB(0x63BE7B),
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -4435,6 +4623,7 @@ pub struct ConditionalFormatDataBar {
 
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -4463,6 +4652,7 @@ impl ConditionalFormatDataBar {
 
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: true,
             has_x14_only: false,
             format: None,
@@ -5711,6 +5901,7 @@ pub struct ConditionalFormatIconSet {
 
     multi_range: String,
     stop_if_true: bool,
+    priority: Option<u32>,
     has_x14_extensions: bool,
     has_x14_only: bool,
     pub(crate) format: Option<Format>,
@@ -5729,6 +5920,7 @@ impl ConditionalFormatIconSet {
 
             multi_range: String::new(),
             stop_if_true: false,
+            priority: None,
             has_x14_extensions: false,
             has_x14_only: false,
             format: None,
@@ -6629,6 +6821,11 @@ impl ConditionalFormatValue {
         // Double quote the remaining string.
         self.value = format!("\"{}\"", self.value);
     }
+
+    // Check if a non-string value refers to a range in another worksheet.
+    pub(crate) fn has_sheet_reference(&self) -> bool {
+        !self.is_string && has_sheet_reference(&self.value)
+    }
 }
 
 // From/Into traits for ConditionalFormatValue.
@@ -7421,11 +7618,41 @@ macro_rules! generate_conditional_common_methods {
             self
         }
 
+        /// Set the priority of the conditional format rule.
+        ///
+        /// Excel applies conditional format rules in order of priority, with 1
+        /// being the highest priority. By default `rust_xlsxwriter` assigns
+        /// priorities in the order that the rules are added to the worksheet.
+        /// The `set_priority()` method can be used to override this for
+        /// individual rules. Rules without an explicit priority are numbered
+        /// around the user defined values.
+        ///
+        /// Priorities should be unique within a worksheet.
+        ///
+        /// # Parameters
+        ///
+        /// - `priority`: The priority of the rule. Must be 1 or greater.
+        ///
+        pub fn set_priority(mut self, priority: u32) -> $t {
+            if priority == 0 {
+                eprintln!("Conditional format priority must be 1 or greater.");
+                return self;
+            }
+
+            self.priority = Some(priority);
+            self
+        }
+
         // Get the index of the format object in the conditional format.
         pub(crate) fn format_index(&self) -> Option<u32> {
             self.format.as_ref().map(|format| format.dxf_index)
         }
 
+        // Get the user defined priority of the conditional format, if set.
+        pub(crate) fn priority(&self) -> Option<u32> {
+            self.priority
+        }
+
         // Get a reference to the format object in the conditional format.
         pub(crate) fn format_as_mut(&mut self) -> Option<&mut Format> {
             self.format.as_mut()
@@ -7508,11 +7735,41 @@ impl ConditionalFormatCell {
         self
     }
 
+    /// Set the priority of the conditional format rule.
+    ///
+    /// Excel applies conditional format rules in order of priority, with 1
+    /// being the highest priority. By default `rust_xlsxwriter` assigns
+    /// priorities in the order that the rules are added to the worksheet. The
+    /// `set_priority()` method can be used to override this for individual
+    /// rules. Rules without an explicit priority are numbered around the user
+    /// defined values.
+    ///
+    /// Priorities should be unique within a worksheet.
+    ///
+    /// # Parameters
+    ///
+    /// - `priority`: The priority of the rule. Must be 1 or greater.
+    ///
+    pub fn set_priority(mut self, priority: u32) -> ConditionalFormatCell {
+        if priority == 0 {
+            eprintln!("Conditional format priority must be 1 or greater.");
+            return self;
+        }
+
+        self.priority = Some(priority);
+        self
+    }
+
     // Get the index of the format object in the conditional format.
     pub(crate) fn format_index(&self) -> Option<u32> {
         self.format.as_ref().map(|format| format.dxf_index)
     }
 
+    // Get the user defined priority of the conditional format, if set.
+    pub(crate) fn priority(&self) -> Option<u32> {
+        self.priority
+    }
+
     // Get a reference to the format object in the conditional format.
     pub(crate) fn format_as_mut(&mut self) -> Option<&mut Format> {
         self.format.as_mut()
@@ -7538,6 +7795,44 @@ impl ConditionalFormatCell {
 // Common methods.
 // -----------------------------------------------------------------------
 
+// Check if a formula refers to a range in another worksheet, such as
+// `Sheet2!$A$1`. Sheet separators in string literals are ignored.
+fn has_sheet_reference(formula: &str) -> bool {
+    let mut in_string = false;
+
+    for char in formula.chars() {
+        match char {
+            '"' => in_string = !in_string,
+            '!' if !in_string => return true,
+            _ => {}
+        }
+    }
+
+    false
+}
+
+// Convert a conditional format into an inline <x14:dxf> element. This is used
+// for Excel 2010 rules which don't refer to the workbook dxf formats by index.
+fn x14_dxf_to_string(dxf_format: &Format) -> String {
+    let xf_formats: Vec<Format> = vec![];
+    let dxf_formats: Vec<Format> = vec![];
+    let mut styler = Styles::new(
+        &xf_formats,
+        &dxf_formats,
+        0,
+        0,
+        0,
+        vec![],
+        false,
+        false,
+        false,
+    );
+
+    styler.write_dxf(dxf_format, "x14:dxf");
+
+    cursor_to_string(&styler.writer)
+}
+
 // Extract the first cell from a range (potentially a multi range).
 fn range_to_anchor(range: &str) -> &str {
     let mut anchor = range;
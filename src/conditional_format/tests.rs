@@ -34,6 +34,7 @@ mod conditional_format_tests {
     use crate::ConditionalFormatTopRule;
     use crate::ConditionalFormatType;
     use crate::ExcelDateTime;
+    use crate::Format;
     use crate::Formula;
     use crate::XlsxError;
     use pretty_assertions::assert_eq;
@@ -5228,4 +5229,146 @@ mod conditional_format_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn conditional_format_25() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        let conditional_format = ConditionalFormatCell::new()
+            .set_rule(ConditionalFormatCellRule::GreaterThan(5))
+            .set_priority(2);
+
+        worksheet.add_conditional_format(0, 0, 0, 0, &conditional_format)?;
+
+        let conditional_format =
+            ConditionalFormatCell::new().set_rule(ConditionalFormatCellRule::LessThan(5));
+
+        worksheet.add_conditional_format(0, 0, 0, 0, &conditional_format)?;
+
+        let conditional_format =
+            ConditionalFormatCell::new().set_rule(ConditionalFormatCellRule::EqualTo(5));
+
+        worksheet.add_conditional_format(1, 0, 1, 0, &conditional_format)?;
+
+        worksheet.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&worksheet.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <conditionalFormatting sqref="A1">
+                <cfRule type="cellIs" priority="2" operator="greaterThan">
+                  <formula>5</formula>
+                </cfRule>
+                <cfRule type="cellIs" priority="1" operator="lessThan">
+                  <formula>5</formula>
+                </cfRule>
+              </conditionalFormatting>
+              <conditionalFormatting sqref="A2">
+                <cfRule type="cellIs" priority="3" operator="equal">
+                  <formula>5</formula>
+                </cfRule>
+              </conditionalFormatting>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        Ok(())
+    }
+
+    #[test]
+    fn conditional_format_26() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        let format = Format::new().set_bold();
+
+        let conditional_format = ConditionalFormatCell::new()
+            .set_rule(ConditionalFormatCellRule::GreaterThan(Formula::new(
+                "Config!$B$1",
+            )))
+            .set_format(&format);
+
+        worksheet.add_conditional_format(0, 0, 9, 0, &conditional_format)?;
+
+        let conditional_format = ConditionalFormatFormula::new()
+            .set_rule("=B1>'Sales Targets'!$B$2")
+            .set_stop_if_true(true);
+
+        worksheet.add_conditional_format(0, 1, 9, 1, &conditional_format)?;
+
+        let conditional_format =
+            ConditionalFormatCell::new().set_rule(ConditionalFormatCellRule::EqualTo("Config!A1"));
+
+        worksheet.add_conditional_format(0, 2, 9, 2, &conditional_format)?;
+
+        // Simulate the workbook mapping of the dxf formats.
+        worksheet.set_global_dxf_indices(&[0]);
+        worksheet.set_global_dxf_formats(&[format]);
+
+        worksheet.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&worksheet.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006" xmlns:x14ac="http://schemas.microsoft.com/office/spreadsheetml/2009/9/ac" mc:Ignorable="x14ac">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15" x14ac:dyDescent="0.25"/>
+              <sheetData/>
+              <conditionalFormatting sqref="C1:C10">
+                <cfRule type="cellIs" priority="3" operator="equal">
+                  <formula>"Config!A1"</formula>
+                </cfRule>
+              </conditionalFormatting>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+              <extLst>
+                <ext xmlns:x14="http://schemas.microsoft.com/office/spreadsheetml/2009/9/main" uri="{78C0D931-6437-407d-A8EE-F0AAD7539E65}">
+                  <x14:conditionalFormattings>
+                    <x14:conditionalFormatting xmlns:xm="http://schemas.microsoft.com/office/excel/2006/main">
+                      <x14:cfRule type="cellIs" priority="1" operator="greaterThan" id="{DA7ABA51-AAAA-BBBB-0001-000000000001}">
+                        <xm:f>Config!$B$1</xm:f>
+                        <x14:dxf>
+                          <font>
+                            <b/>
+                          </font>
+                        </x14:dxf>
+                      </x14:cfRule>
+                      <xm:sqref>A1:A10</xm:sqref>
+                    </x14:conditionalFormatting>
+                    <x14:conditionalFormatting xmlns:xm="http://schemas.microsoft.com/office/excel/2006/main">
+                      <x14:cfRule type="expression" priority="2" stopIfTrue="1" id="{DA7ABA51-AAAA-BBBB-0001-000000000002}">
+                        <xm:f>B1&gt;'Sales Targets'!$B$2</xm:f>
+                      </x14:cfRule>
+                      <xm:sqref>B1:B10</xm:sqref>
+                    </x14:conditionalFormatting>
+                  </x14:conditionalFormattings>
+                </ext>
+              </extLst>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        Ok(())
+    }
 }
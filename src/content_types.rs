@@ -96,6 +96,15 @@ impl ContentTypes {
         self.add_override(&part_name, content_type);
     }
 
+    // Add the name of a chart drawing to the ContentTypes overrides.
+    pub(crate) fn add_chart_drawing_name(&mut self, index: u16) {
+        let content_type =
+            "application/vnd.openxmlformats-officedocument.drawingml.chartshapes+xml";
+        let part_name = format!("/xl/drawings/drawing{index}.xml");
+
+        self.add_override(&part_name, content_type);
+    }
+
     // Add the name of a chart to the ContentTypes overrides.
     pub(crate) fn add_chart_name(&mut self, index: u16) {
         let content_type = "application/vnd.openxmlformats-officedocument.drawingml.chart+xml";
@@ -0,0 +1,611 @@
+// csv_import - A module for importing CSV data into worksheets.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use crate::{ColNum, ExcelDateTime, Format, RowNum, Table, XlsxError};
+
+/// The `CsvImportOptions` struct is used to set options for importing CSV
+/// data.
+///
+/// `CsvImportOptions` is used in conjunction with
+/// [`Worksheet::import_csv()`](crate::Worksheet::import_csv) to control how
+/// CSV, TSV or other delimited data is parsed and written to a worksheet. It
+/// can be used to set the delimiter and quote characters, the position of the
+/// data in the worksheet, whether the first record is a header row, wrap the
+/// data in a worksheet [`Table`] and set the formats for each column.
+///
+/// By default the type of each CSV field is inferred from its content and it
+/// is written to the worksheet as the closest Excel type:
+///
+/// | CSV field                                  | Excel type | Default number format |
+/// | :----------------------------------------- | :--------- | :-------------------- |
+/// | `123`, `-1.5`, `1e6`                       | Number     | None                  |
+/// | `true`, `FALSE`                            | Boolean    | None                  |
+/// | `2024-01-31`                               | Date       | `yyyy-mm-dd;@`        |
+/// | `2024-01-31 12:30:00`, `2024-01-31T12:30Z` | Datetime   | `yyyy-mm-dd hh:mm:ss` |
+/// | `12:30`, `12:30:45.5`                      | Time       | `hh:mm:ss;@`          |
+/// | Anything else                              | String     | None                  |
+///
+/// Numbers with leading zeros, such as `00123`, are written as strings to
+/// preserve data like zip codes or product ids. Integers with more than 15
+/// digits, such as order or account numbers, are also written as strings
+/// since they would lose precision as Excel numbers. Quoted fields, such as
+/// `"123"`, are always written as strings. Empty fields are ignored and
+/// the corresponding cell is left blank, or written as a formatted blank cell
+/// if the column has a format. Dates and times are parsed using
+/// [`ExcelDateTime::parse_from_str()`](crate::ExcelDateTime::parse_from_str)
+/// and are subject to the same limitations.
+///
+/// Type inference can be turned off with
+/// [`CsvImportOptions::set_type_inference()`] to write all of the fields as
+/// strings.
+///
+/// # Examples
+///
+/// The following example demonstrates importing CSV data into a worksheet
+/// table with some custom formatting.
+///
+/// ```
+/// # // This code is available in examples/doc_csv_import_options.rs
+/// #
+/// use rust_xlsxwriter::{CsvImportOptions, Format, Table, Workbook, XlsxError};
+///
+/// fn main() -> Result<(), XlsxError> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.add_worksheet();
+///
+///     // Some sample CSV data. This could also be a file or other reader.
+///     let data = "Region;Sales;Date\n\
+///                 North;1000;2024-01-01\n\
+///                 South;2300.5;2024-01-02\n\
+///                 East;1800;2024-01-03\n\
+///                 West;2750.25;2024-01-04\n";
+///
+///     // Set some options for the CSV import.
+///     let options = CsvImportOptions::new()
+///         .set_delimiter(';')
+///         .set_table(Table::new())
+///         .set_column_format("Sales", Format::new().set_num_format("#,##0.00"))
+///         .set_date_format(Format::new().set_num_format("dd/mm/yyyy"))
+///         .set_autofit(true);
+///
+///     // Import the CSV data into the worksheet.
+///     worksheet.import_csv(data.as_bytes(), &options)?;
+///
+///     // Save the file to disk.
+///     workbook.save("csv_import.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone)]
+pub struct CsvImportOptions {
+    pub(crate) delimiter: char,
+    pub(crate) quote_char: char,
+    pub(crate) first_row: RowNum,
+    pub(crate) first_col: ColNum,
+    pub(crate) has_headers: bool,
+    pub(crate) header_format: Option<Format>,
+    pub(crate) table: Option<Table>,
+    pub(crate) autofit: bool,
+    pub(crate) type_inference: bool,
+    pub(crate) column_formats: HashMap<String, Format>,
+    pub(crate) date_format: Format,
+    pub(crate) datetime_format: Format,
+    pub(crate) time_format: Format,
+}
+
+impl Default for CsvImportOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CsvImportOptions {
+    /// Create a new `CsvImportOptions` object.
+    ///
+    /// Create a `CsvImportOptions` struct to be used with the
+    /// [`Worksheet::import_csv()`](crate::Worksheet::import_csv) method.
+    ///
+    pub fn new() -> CsvImportOptions {
+        CsvImportOptions {
+            delimiter: ',',
+            quote_char: '"',
+            first_row: 0,
+            first_col: 0,
+            has_headers: true,
+            header_format: None,
+            table: None,
+            autofit: false,
+            type_inference: true,
+            column_formats: HashMap::new(),
+            date_format: Format::new().set_num_format("yyyy\\-mm\\-dd;@"),
+            datetime_format: Format::new().set_num_format("yyyy\\-mm\\-dd\\ hh:mm:ss"),
+            time_format: Format::new().set_num_format("hh:mm:ss;@"),
+        }
+    }
+
+    /// Set the field delimiter character.
+    ///
+    /// The default delimiter is a comma. Use `'\t'` for TSV data or `';'` for
+    /// CSV data from locales that use a comma as the decimal separator.
+    ///
+    /// # Parameters
+    ///
+    /// - `delimiter`: The field delimiter character.
+    ///
+    pub fn set_delimiter(mut self, delimiter: char) -> CsvImportOptions {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set the field quote character.
+    ///
+    /// The default quote character is a double quote. Quoted fields can
+    /// contain delimiters and newlines. A quote character within a quoted
+    /// field is escaped by doubling it.
+    ///
+    /// # Parameters
+    ///
+    /// - `quote_char`: The field quote character.
+    ///
+    pub fn set_quote_char(mut self, quote_char: char) -> CsvImportOptions {
+        self.quote_char = quote_char;
+        self
+    }
+
+    /// Set the worksheet cell where the CSV data starts.
+    ///
+    /// By default the data is written starting at cell `A1`.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number of the first record.
+    /// - `col`: The zero indexed column number of the first field.
+    ///
+    pub fn set_first_cell(mut self, row: RowNum, col: ColNum) -> CsvImportOptions {
+        self.first_row = row;
+        self.first_col = col;
+        self
+    }
+
+    /// Turn on/off the CSV header row.
+    ///
+    /// By default the first CSV record is treated as a header row. It is
+    /// written as strings, without type inference, and the header names can
+    /// be used with [`CsvImportOptions::set_column_format()`]. If it is turned
+    /// off then all records are treated as data and the columns are named
+    /// `Column1`, `Column2`, etc.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_header_row(mut self, enable: bool) -> CsvImportOptions {
+        self.has_headers = enable;
+        self
+    }
+
+    /// Set the format for the CSV header row.
+    ///
+    /// See [`Format`] for more information on formatting.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the headers.
+    ///
+    pub fn set_header_format(mut self, format: impl Into<Format>) -> CsvImportOptions {
+        self.header_format = Some(format.into());
+        self
+    }
+
+    /// Write the CSV data as a worksheet table.
+    ///
+    /// Add a worksheet [`Table`] around the CSV data. The table range is
+    /// calculated from the number of records and the table headers are taken
+    /// from the CSV header row. If the CSV data doesn't have a header row then
+    /// the table header row is turned off.
+    ///
+    /// If the table has a total row it is added below the data.
+    ///
+    /// # Parameters
+    ///
+    /// - `table`: A [`Table`] object with the required properties set.
+    ///
+    pub fn set_table(mut self, table: impl Into<Table>) -> CsvImportOptions {
+        self.table = Some(table.into());
+        self
+    }
+
+    /// Autofit the worksheet columns after the CSV data is written.
+    ///
+    /// This is equivalent to calling
+    /// [`Worksheet::autofit()`](crate::Worksheet::autofit) after the data has
+    /// been written. Note, this applies to all of the data in the worksheet
+    /// and not just the CSV data.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_autofit(mut self, enable: bool) -> CsvImportOptions {
+        self.autofit = enable;
+        self
+    }
+
+    /// Turn on/off the type inference of the CSV fields.
+    ///
+    /// By default the CSV fields are written as numbers, booleans, dates or
+    /// strings based on their content, see the table in the
+    /// [`CsvImportOptions`] docs. If type inference is turned off all of the
+    /// fields are written as strings.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_type_inference(mut self, enable: bool) -> CsvImportOptions {
+        self.type_inference = enable;
+        self
+    }
+
+    /// Set the format for a named CSV column.
+    ///
+    /// This format overrides the default date and time formats for the data
+    /// cells in the column. The header cell isn't affected.
+    ///
+    /// # Parameters
+    ///
+    /// - `column_name`: The name of the column in the CSV header row, or
+    ///   `Column1`, `Column2`, etc., if there isn't a header row.
+    /// - `format`: The [`Format`] property for the column data.
+    ///
+    pub fn set_column_format(
+        mut self,
+        column_name: impl Into<String>,
+        format: impl Into<Format>,
+    ) -> CsvImportOptions {
+        self.column_formats
+            .insert(column_name.into(), format.into());
+        self
+    }
+
+    /// Set the format for fields that are inferred to be dates.
+    ///
+    /// The default format is `yyyy-mm-dd;@`.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for dates.
+    ///
+    pub fn set_date_format(mut self, format: impl Into<Format>) -> CsvImportOptions {
+        self.date_format = format.into();
+        self
+    }
+
+    /// Set the format for fields that are inferred to be datetimes.
+    ///
+    /// The default format is `yyyy-mm-dd hh:mm:ss`.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for datetimes.
+    ///
+    pub fn set_datetime_format(mut self, format: impl Into<Format>) -> CsvImportOptions {
+        self.datetime_format = format.into();
+        self
+    }
+
+    /// Set the format for fields that are inferred to be times.
+    ///
+    /// The default format is `hh:mm:ss;@`.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for times.
+    ///
+    pub fn set_time_format(mut self, format: impl Into<Format>) -> CsvImportOptions {
+        self.time_format = format.into();
+        self
+    }
+
+    // -----------------------------------------------------------------------
+    // Crate level helper methods.
+    // -----------------------------------------------------------------------
+
+    // Get the format for a CSV field, in order of precedence: user column
+    // format, date/time format for the inferred value type.
+    pub(crate) fn field_format<'a>(
+        &'a self,
+        column_format: Option<&'a Format>,
+        value: &CsvValue,
+    ) -> Option<&'a Format> {
+        if column_format.is_some() {
+            return column_format;
+        }
+
+        match value {
+            CsvValue::Date(_) => Some(&self.date_format),
+            CsvValue::DateTime(_) => Some(&self.datetime_format),
+            CsvValue::Time(_) => Some(&self.time_format),
+            _ => None,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------
+// CsvValue.
+// -----------------------------------------------------------------------
+
+// The inferred type and value of a CSV field.
+#[derive(Debug, PartialEq)]
+pub(crate) enum CsvValue {
+    Empty,
+    Number(f64),
+    Boolean(bool),
+    Date(f64),
+    DateTime(f64),
+    Time(f64),
+    String,
+}
+
+impl CsvValue {
+    // Infer the Excel type of a CSV field from its content.
+    pub(crate) fn infer(field: &str) -> CsvValue {
+        let field = field.trim();
+
+        if field.is_empty() {
+            return CsvValue::Empty;
+        }
+
+        if is_number(field) && !is_long_integer(field) {
+            if let Ok(number) = field.parse::<f64>() {
+                if number.is_finite() {
+                    return CsvValue::Number(number);
+                }
+            }
+        }
+
+        if field.eq_ignore_ascii_case("true") {
+            return CsvValue::Boolean(true);
+        }
+
+        if field.eq_ignore_ascii_case("false") {
+            return CsvValue::Boolean(false);
+        }
+
+        let bytes = field.as_bytes();
+
+        if is_date(bytes) {
+            let time = &bytes[10..];
+            let time = time.strip_suffix(b"Z").unwrap_or(time);
+
+            if time.is_empty() {
+                if let Ok(datetime) = ExcelDateTime::parse_from_str(field) {
+                    return CsvValue::Date(datetime.to_excel());
+                }
+            } else if (time[0] == b'T' || time[0] == b' ') && is_time(&time[1..]) {
+                if let Ok(datetime) = ExcelDateTime::parse_from_str(field) {
+                    return CsvValue::DateTime(datetime.to_excel());
+                }
+            }
+        } else if is_time(bytes) {
+            if let Ok(datetime) = ExcelDateTime::parse_from_str(field) {
+                return CsvValue::Time(datetime.to_excel());
+            }
+        }
+
+        CsvValue::String
+    }
+}
+
+// Check if a string is a number in a simple decimal or scientific format.
+// Numbers with leading zeros, such as zip codes, aren't matched.
+fn is_number(field: &str) -> bool {
+    let bytes = field.as_bytes();
+    let bytes = bytes.strip_prefix(b"-").unwrap_or(bytes);
+
+    let int_len = bytes.iter().take_while(|b| b.is_ascii_digit()).count();
+    if int_len == 0 || (int_len > 1 && bytes[0] == b'0') {
+        return false;
+    }
+
+    let mut rest = &bytes[int_len..];
+
+    if let Some(fraction) = rest.strip_prefix(b".") {
+        let frac_len = fraction.iter().take_while(|b| b.is_ascii_digit()).count();
+        if frac_len == 0 {
+            return false;
+        }
+        rest = &fraction[frac_len..];
+    }
+
+    if let Some(exponent) = rest.strip_prefix(b"e").or(rest.strip_prefix(b"E")) {
+        let exponent = exponent
+            .strip_prefix(b"+")
+            .or(exponent.strip_prefix(b"-"))
+            .unwrap_or(exponent);
+
+        return !exponent.is_empty() && exponent.iter().all(u8::is_ascii_digit);
+    }
+
+    rest.is_empty()
+}
+
+// Check if a string is an integer with more digits than can be stored in an
+// Excel number, which has 15 significant digits, without losing precision.
+fn is_long_integer(field: &str) -> bool {
+    let digits = field.strip_prefix('-').unwrap_or(field);
+
+    digits.len() > 15 && digits.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+// Check if a string starts with an ISO 8601 date like "yyyy-mm-dd".
+fn is_date(bytes: &[u8]) -> bool {
+    bytes.len() >= 10
+        && bytes[0..4].iter().all(u8::is_ascii_digit)
+        && bytes[4] == b'-'
+        && bytes[5..7].iter().all(u8::is_ascii_digit)
+        && bytes[7] == b'-'
+        && bytes[8..10].iter().all(u8::is_ascii_digit)
+}
+
+// Check if a string is a time like "hh:mm", "hh:mm:ss" or "hh:mm:ss.sss".
+fn is_time(bytes: &[u8]) -> bool {
+    let is_two_digits = |bytes: &[u8]| bytes.len() == 2 && bytes.iter().all(u8::is_ascii_digit);
+
+    match bytes.len() {
+        5 => is_two_digits(&bytes[0..2]) && bytes[2] == b':' && is_two_digits(&bytes[3..5]),
+        len if len >= 8 => {
+            is_time(&bytes[0..5])
+                && bytes[5] == b':'
+                && is_two_digits(&bytes[6..8])
+                && match bytes.get(8..) {
+                    Some([]) | None => true,
+                    Some([b'.', fraction @ ..]) => {
+                        !fraction.is_empty() && fraction.iter().all(u8::is_ascii_digit)
+                    }
+                    Some(_) => false,
+                }
+        }
+        _ => false,
+    }
+}
+
+// -----------------------------------------------------------------------
+// CsvReader.
+// -----------------------------------------------------------------------
+
+// A CSV field and whether it was quoted. Quoted fields are written as strings
+// without type inference.
+#[derive(Debug, Default, PartialEq)]
+pub(crate) struct CsvField {
+    pub(crate) text: String,
+    pub(crate) is_quoted: bool,
+}
+
+// A simple CSV reader that returns one record at a time from a buffered
+// reader. It handles quoted fields with embedded delimiters, newlines and
+// doubled quote characters, as described in RFC 4180.
+pub(crate) struct CsvReader<R: BufRead> {
+    reader: R,
+    delimiter: char,
+    quote_char: char,
+    line: String,
+    line_number: usize,
+}
+
+impl<R: BufRead> CsvReader<R> {
+    pub(crate) fn new(reader: R, delimiter: char, quote_char: char) -> CsvReader<R> {
+        CsvReader {
+            reader,
+            delimiter,
+            quote_char,
+            line: String::new(),
+            line_number: 0,
+        }
+    }
+
+    // Read the next non-empty record. Returns `None` at the end of the data.
+    pub(crate) fn read_record(&mut self) -> Result<Option<Vec<CsvField>>, XlsxError> {
+        loop {
+            if !self.read_line()? {
+                return Ok(None);
+            }
+
+            // Skip empty lines.
+            if self.line.is_empty() {
+                continue;
+            }
+
+            return self.parse_record().map(Some);
+        }
+    }
+
+    // Read a line into the line buffer without the line ending. Returns false
+    // at the end of the data.
+    fn read_line(&mut self) -> Result<bool, XlsxError> {
+        self.line.clear();
+
+        if self.reader.read_line(&mut self.line)? == 0 {
+            return Ok(false);
+        }
+
+        // Remove the UTF-8 BOM from the start of the data.
+        if self.line_number == 0 && self.line.starts_with('\u{feff}') {
+            self.line.drain(..'\u{feff}'.len_utf8());
+        }
+
+        self.line_number += 1;
+
+        let len = self.line.trim_end_matches(['\n', '\r']).len();
+        self.line.truncate(len);
+
+        Ok(true)
+    }
+
+    // Parse the current line, and any continuation lines for quoted fields
+    // with embedded newlines, into a record.
+    fn parse_record(&mut self) -> Result<Vec<CsvField>, XlsxError> {
+        let first_line_number = self.line_number;
+        let mut record = vec![];
+        let mut field = CsvField::default();
+        let mut in_quotes = false;
+        let mut line = std::mem::take(&mut self.line);
+
+        loop {
+            let mut chars = line.chars().peekable();
+
+            while let Some(char) = chars.next() {
+                if in_quotes {
+                    if char == self.quote_char {
+                        if chars.peek() == Some(&self.quote_char) {
+                            field.text.push(char);
+                            chars.next();
+                        } else {
+                            in_quotes = false;
+                        }
+                    } else {
+                        field.text.push(char);
+                    }
+                } else if char == self.delimiter {
+                    record.push(std::mem::take(&mut field));
+                } else if char == self.quote_char && field.text.is_empty() {
+                    in_quotes = true;
+                    field.is_quoted = true;
+                } else {
+                    field.text.push(char);
+                }
+            }
+
+            if !in_quotes {
+                break;
+            }
+
+            // Quoted fields can span lines.
+            self.line = line;
+            if !self.read_line()? {
+                return Err(XlsxError::ParameterError(format!(
+                    "Unterminated quoted field in CSV record starting at line {first_line_number}"
+                )));
+            }
+            line = std::mem::take(&mut self.line);
+            field.text.push('\n');
+        }
+
+        self.line = line;
+        record.push(field);
+
+        Ok(record)
+    }
+}
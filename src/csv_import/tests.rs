@@ -0,0 +1,200 @@
+// CSV import unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod csv_import_tests {
+
+    use crate::csv_import::{CsvReader, CsvValue};
+    use crate::{CsvImportOptions, Format, Table, Worksheet, XlsxError};
+    use pretty_assertions::assert_eq;
+
+    // Read all of the records from a CSV string.
+    fn read_records(data: &str, delimiter: char, quote_char: char) -> Vec<Vec<String>> {
+        let mut reader = CsvReader::new(data.as_bytes(), delimiter, quote_char);
+        let mut records = vec![];
+
+        while let Some(record) = reader.read_record().unwrap() {
+            records.push(record.into_iter().map(|field| field.text).collect());
+        }
+
+        records
+    }
+
+    #[test]
+    fn test_csv_reader() {
+        let tests = [
+            ("a,b,c", vec![vec!["a", "b", "c"]]),
+            (
+                "a,b,c\n1,2,3\n",
+                vec![vec!["a", "b", "c"], vec!["1", "2", "3"]],
+            ),
+            (
+                "a,b,c\r\n1,2,3\r\n",
+                vec![vec!["a", "b", "c"], vec!["1", "2", "3"]],
+            ),
+            ("a,,c", vec![vec!["a", "", "c"]]),
+            ("a,b,", vec![vec!["a", "b", ""]]),
+            ("a\n\n\nb", vec![vec!["a"], vec!["b"]]),
+            ("\u{feff}a,b", vec![vec!["a", "b"]]),
+            ("\"a,b\",c", vec![vec!["a,b", "c"]]),
+            ("\"a \"\"b\"\"\",c", vec![vec!["a \"b\"", "c"]]),
+            ("\"a\nb\",c\nd,e", vec![vec!["a\nb", "c"], vec!["d", "e"]]),
+            ("\"a\r\n\r\nb\",c", vec![vec!["a\n\nb", "c"]]),
+            ("a\"b,c", vec![vec!["a\"b", "c"]]),
+            ("\"\",c", vec![vec!["", "c"]]),
+        ];
+
+        for (data, expected) in tests {
+            let got = read_records(data, ',', '"');
+            assert_eq!(expected, got, "{data:?}");
+        }
+    }
+
+    #[test]
+    fn test_csv_reader_quoted_fields() {
+        let mut reader = CsvReader::new("123,\"123\",\"\",a\"b\"".as_bytes(), ',', '"');
+        let got: Vec<bool> = reader
+            .read_record()
+            .unwrap()
+            .unwrap()
+            .iter()
+            .map(|field| field.is_quoted)
+            .collect();
+
+        assert_eq!(vec![false, true, true, false], got);
+    }
+
+    #[test]
+    fn test_csv_reader_delimiter_and_quote() {
+        let got = read_records("a\tb\t'c\td'\n1\t2\t3", '\t', '\'');
+        let expected = vec![vec!["a", "b", "c\td"], vec!["1", "2", "3"]];
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_csv_reader_unterminated_quote() {
+        let mut reader = CsvReader::new("a,b\n\"c,d\ne,f".as_bytes(), ',', '"');
+
+        assert!(reader.read_record().is_ok());
+        let result = reader.read_record();
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn test_csv_value_infer() {
+        let tests = [
+            ("", CsvValue::Empty),
+            ("  ", CsvValue::Empty),
+            ("0", CsvValue::Number(0.0)),
+            ("123", CsvValue::Number(123.0)),
+            (" 123 ", CsvValue::Number(123.0)),
+            ("-1.5", CsvValue::Number(-1.5)),
+            ("0.25", CsvValue::Number(0.25)),
+            ("1e6", CsvValue::Number(1_000_000.0)),
+            ("1.5E-3", CsvValue::Number(0.0015)),
+            ("00123", CsvValue::String),
+            ("+123", CsvValue::String),
+            ("1.", CsvValue::String),
+            (".5", CsvValue::String),
+            ("1e", CsvValue::String),
+            ("1,000", CsvValue::String),
+            ("123456789012345", CsvValue::Number(123_456_789_012_345.0)),
+            ("-123456789012345", CsvValue::Number(-123_456_789_012_345.0)),
+            ("1234567890123456", CsvValue::String),
+            ("-1234567890123456", CsvValue::String),
+            (
+                "1234567890123456.5",
+                CsvValue::Number(1_234_567_890_123_456.5),
+            ),
+            ("inf", CsvValue::String),
+            ("NaN", CsvValue::String),
+            ("true", CsvValue::Boolean(true)),
+            ("FALSE", CsvValue::Boolean(false)),
+            ("yes", CsvValue::String),
+            ("2024-01-01", CsvValue::Date(45292.0)),
+            ("2024-01-01 12:00:00", CsvValue::DateTime(45292.5)),
+            ("2024-01-01T12:00", CsvValue::DateTime(45292.5)),
+            ("2024-01-01T12:00:00Z", CsvValue::DateTime(45292.5)),
+            ("12:00", CsvValue::Time(0.5)),
+            ("18:00:00.0", CsvValue::Time(0.75)),
+            ("2024-13-01", CsvValue::String),
+            ("2024-01-01 noon", CsvValue::String),
+            ("2024-01-01T12:00+05:00", CsvValue::String),
+            ("12:00 PM", CsvValue::String),
+            ("1:30", CsvValue::String),
+            ("abc", CsvValue::String),
+        ];
+
+        for (field, expected) in tests {
+            assert_eq!(expected, CsvValue::infer(field), "{field:?}");
+        }
+    }
+
+    #[test]
+    fn test_field_format_precedence() {
+        let column_format = Format::new().set_num_format("0.000");
+        let options = CsvImportOptions::new();
+
+        let got = options.field_format(Some(&column_format), &CsvValue::Date(1.0));
+        assert_eq!(Some(&column_format), got);
+
+        let got = options.field_format(None, &CsvValue::Date(1.0));
+        assert_eq!(Some(&options.date_format), got);
+
+        let got = options.field_format(None, &CsvValue::DateTime(1.0));
+        assert_eq!(Some(&options.datetime_format), got);
+
+        let got = options.field_format(None, &CsvValue::Time(1.0));
+        assert_eq!(Some(&options.time_format), got);
+
+        let got = options.field_format(None, &CsvValue::Number(1.0));
+        assert_eq!(None, got);
+    }
+
+    #[test]
+    fn test_import_csv_limits() {
+        let mut worksheet = Worksheet::new();
+        let data = "col1\n1\n2\n3\n";
+
+        let options = CsvImportOptions::new().set_first_cell(1_048_574, 0);
+        let result = worksheet.import_csv(data.as_bytes(), &options);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let options = CsvImportOptions::new().set_first_cell(0, 16_384);
+        let result = worksheet.import_csv(data.as_bytes(), &options);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let options = CsvImportOptions::new().set_first_cell(1_048_572, 16_383);
+        let result = worksheet.import_csv(data.as_bytes(), &options);
+        assert!(result.is_ok());
+
+        // Empty data is ignored.
+        let options = CsvImportOptions::new().set_first_cell(1_048_576, 0);
+        let result = worksheet.import_csv("".as_bytes(), &options);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_import_csv_table_width() {
+        let mut worksheet = Worksheet::new();
+        let data = "col1,col2\n1,2\n3,4,5\n";
+
+        // Data that is wider than the table header row is an error.
+        let options = CsvImportOptions::new().set_table(Table::new());
+        let result = worksheet.import_csv(data.as_bytes(), &options);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        // The table range is widened to the data if there isn't a header row.
+        let options = CsvImportOptions::new()
+            .set_header_row(false)
+            .set_first_cell(10, 0)
+            .set_table(Table::new());
+        let result = worksheet.import_csv(data.as_bytes(), &options);
+        assert!(result.is_ok());
+        assert_eq!("A11:C13", worksheet.tables[0].cell_range.to_range_string());
+    }
+}
@@ -0,0 +1,103 @@
+// data_options - A module for the options shared by the Polars dataframe and
+// Arrow record batch writers.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use std::collections::HashMap;
+use std::mem;
+
+use crate::{Format, Table};
+
+// The default Excel number formats for date and time data types.
+pub(crate) const DATE_NUM_FORMAT: &str = "yyyy\\-mm\\-dd;@";
+pub(crate) const DATETIME_NUM_FORMAT: &str = "yyyy\\-mm\\-dd\\ hh:mm:ss";
+pub(crate) const TIME_NUM_FORMAT: &str = "hh:mm:ss;@";
+pub(crate) const DURATION_NUM_FORMAT: &str = "[h]:mm:ss";
+
+// The options that are common to `DataFrameOptions` and `RecordBatchOptions`.
+// The data type `T` is the Polars or Arrow `DataType`.
+#[derive(Clone)]
+pub(crate) struct DataOptions<T> {
+    pub(crate) has_headers: bool,
+    pub(crate) header_format: Option<Format>,
+    pub(crate) table: Option<Table>,
+    pub(crate) autofit: bool,
+    pub(crate) null_value: Option<String>,
+    pub(crate) column_formats: HashMap<String, Format>,
+    pub(crate) dtype_formats: Vec<(T, Format)>,
+}
+
+impl<T> DataOptions<T> {
+    pub(crate) fn new() -> DataOptions<T> {
+        DataOptions {
+            has_headers: true,
+            header_format: None,
+            table: None,
+            autofit: false,
+            null_value: None,
+            column_formats: HashMap::new(),
+            dtype_formats: vec![],
+        }
+    }
+
+    // Check if the headers should be written. They can be turned off in the
+    // options or by a table without a header row.
+    pub(crate) fn show_headers(&self) -> bool {
+        self.has_headers && self.table.as_ref().map_or(true, Table::has_header_row)
+    }
+
+    // Set the format for a data type. The data type is matched on its kind
+    // only so the format replaces any format for the same kind of data type.
+    pub(crate) fn set_dtype_format(&mut self, dtype: T, format: Format) {
+        match self
+            .dtype_formats
+            .iter_mut()
+            .find(|(existing, _)| mem::discriminant(existing) == mem::discriminant(&dtype))
+        {
+            Some(entry) => entry.1 = format,
+            None => self.dtype_formats.push((dtype, format)),
+        }
+    }
+
+    // Get the format for the data cells in a column, in order of precedence:
+    // user column format, user data type format, default data type format.
+    pub(crate) fn column_format(
+        &self,
+        column_name: &str,
+        dtype: &T,
+        default_format: Option<Format>,
+    ) -> Option<Format> {
+        if let Some(format) = self.column_formats.get(column_name) {
+            return Some(format.clone());
+        }
+
+        if let Some((_, format)) = self
+            .dtype_formats
+            .iter()
+            .find(|(existing, _)| mem::discriminant(existing) == mem::discriminant(dtype))
+        {
+            return Some(format.clone());
+        }
+
+        default_format
+    }
+}
+
+// The properties used when writing the data cells of a dataframe or record
+// batch column. The time zone `Z` is the Polars or Arrow time zone type.
+pub(crate) struct ColumnProperties<Z> {
+    pub(crate) format: Option<Format>,
+    pub(crate) timezone: Option<Z>,
+}
+
+// Get the default Excel number format for a decimal type with a scale.
+pub(crate) fn decimal_num_format(scale: usize) -> Option<Format> {
+    if scale > 0 {
+        let num_format = format!("0.{}", "0".repeat(scale));
+        Some(Format::new().set_num_format(num_format))
+    } else {
+        None
+    }
+}
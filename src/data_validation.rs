@@ -190,6 +190,7 @@ pub struct DataValidation {
     pub(crate) input_message: String,
     pub(crate) error_message: String,
     pub(crate) error_style: DataValidationErrorStyle,
+    pub(crate) long_list: Vec<String>,
 }
 
 impl DataValidation {
@@ -214,6 +215,7 @@ impl DataValidation {
             input_message: String::new(),
             error_message: String::new(),
             error_style: DataValidationErrorStyle::Stop,
+            long_list: vec![],
         }
     }
 
@@ -465,11 +467,14 @@ impl DataValidation {
     /// via a dropdown menu similar to online forms.
     ///
     /// Excel has a 255 character limit to the string used to store the
-    /// comma-separated list of strings, including the commas. This limit makes
-    /// it unsuitable for long lists such as a list of provinces or states. For
-    /// longer lists, it is better to place the string values somewhere in the
-    /// Excel workbook and refer to them using a range formula via the
-    /// [`DataValidation::allow_list_formula()`] method shown below.
+    /// comma-separated list of strings, including the commas. Lists that
+    /// exceed this limit, such as a list of provinces or states, are handled
+    /// automatically by `rust_xlsxwriter`. When the workbook is saved the
+    /// strings are written to a column in a hidden worksheet called `_Lists`,
+    /// or a unique variant like `_Lists1` if that name is already in use, and
+    /// the data validation refers to them via a range, like the
+    /// [`DataValidation::allow_list_formula()`] method shown below. Data
+    /// validations that use identical long lists share the same range.
     ///
     /// # Parameters
     ///
@@ -477,9 +482,8 @@ impl DataValidation {
     ///
     /// # Errors
     ///
-    /// - [`XlsxError::DataValidationError`] - The length of the combined
-    ///   comma-separated list of strings, including commas, exceeds Excel's
-    ///   limit of 255 characters, see the explanation above.
+    /// This method no longer returns an error. The `Result` return type is
+    /// retained for backward compatibility.
     ///
     /// # Examples
     ///
@@ -551,6 +555,37 @@ impl DataValidation {
     /// #     Ok(())
     /// # }
     /// ```
+    ///
+    /// The following example demonstrates a list that is longer than Excel's
+    /// 255 character limit. The strings are stored in a hidden worksheet when
+    /// the file is saved.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_data_validation_allow_list_strings3.rs
+    /// #
+    /// # use rust_xlsxwriter::{DataValidation, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     worksheet.write(1, 0, "Select value in cell D2:")?;
+    /// #
+    ///     // Create a list of 100 product codes.
+    ///     let products: Vec<String> = (1..=100).map(|i| format!("Product-{i:04}")).collect();
+    ///
+    ///     let data_validation = DataValidation::new().allow_list_strings(&products)?;
+    ///
+    ///     worksheet.add_data_validation(1, 3, 1, 3, &data_validation)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("data_validation.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::unnecessary_wraps)]
     pub fn allow_list_strings(
         mut self,
         list: &[impl AsRef<str>],
@@ -561,16 +596,17 @@ impl DataValidation {
             .collect::<Vec<String>>()
             .join(",");
 
+        // Lists that exceed Excel's limit are stored in a hidden worksheet,
+        // and referred to by range, when the workbook is saved.
         let length = joined_list.chars().count();
         if length > 255 {
-            return Err(XlsxError::DataValidationError(
-                format!("Validation list length '{length}' including commas is greater than Excel's limit of 255 characters: {joined_list}")
-            ));
+            self.long_list = list.iter().map(|s| s.as_ref().to_string()).collect();
+            self.rule = DataValidationRuleInternal::ListSource(String::new());
+        } else {
+            self.long_list.clear();
+            self.rule = DataValidationRuleInternal::ListSource(format!("\"{joined_list}\""));
         }
 
-        let joined_list = format!("\"{joined_list}\"");
-
-        self.rule = DataValidationRuleInternal::ListSource(joined_list);
         self.validation_type = DataValidationType::List;
         Ok(self)
     }
@@ -583,6 +619,9 @@ impl DataValidation {
     /// represented by a [`Formula`], see [Using cell references in Data
     /// Validations] and the example below.
     ///
+    /// For dropdown lists that depend on the value selected in another cell
+    /// see [`Workbook::add_dependent_list_validation()`](crate::Workbook::add_dependent_list_validation).
+    ///
     /// # Parameters
     /// - `list`: A cell range reference such as `=B1:B9`, `=$B$1:$B$9` or
     ///   `=Sheet2!B1:B9` using a [`Formula`]. See [Using cell references in
@@ -635,6 +674,7 @@ impl DataValidation {
     /// src="https://rustxlsxwriter.github.io/images/data_validation_allow_list_formula_dialog.png">
     ///
     pub fn allow_list_formula(mut self, list: Formula) -> DataValidation {
+        self.long_list.clear();
         let formula = list.formula_string.clone();
         self.rule = DataValidationRuleInternal::ListSource(formula);
         self.validation_type = DataValidationType::List;
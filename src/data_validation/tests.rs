@@ -1363,9 +1363,12 @@ mod data_validation_tests {
         let result = DataValidation::new().set_error_message(&invalid_message);
         assert!(matches!(result, Err(XlsxError::DataValidationError(_))));
 
-        // Check for invalid string list.
-        let result = DataValidation::new().allow_list_strings(&list_values);
-        assert!(matches!(result, Err(XlsxError::DataValidationError(_))));
+        // Check that long string lists are stored for the hidden worksheet.
+        let data_validation = DataValidation::new().allow_list_strings(&list_values)?;
+        assert_eq!(data_validation.long_list.len(), list_values.len());
+
+        let data_validation = data_validation.allow_list_formula("A1:A3".into());
+        assert!(data_validation.long_list.is_empty());
 
         worksheet.assemble_xml_file();
 
@@ -0,0 +1,326 @@
+// dataframe - A module for writing Polars dataframes to worksheets.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use chrono_tz::Tz;
+use polars::prelude::DataType;
+
+use crate::data_options::{
+    decimal_num_format, ColumnProperties, DataOptions, DATETIME_NUM_FORMAT, DATE_NUM_FORMAT,
+    DURATION_NUM_FORMAT, TIME_NUM_FORMAT,
+};
+use crate::{Format, Table, XlsxError};
+
+/// The `DataFrameOptions` struct is used to set options for writing Polars
+/// dataframes.
+///
+/// `DataFrameOptions` is used in conjunction with
+/// [`Worksheet::write_dataframe_with_options()`](crate::Worksheet::write_dataframe_with_options)
+/// to control how a Polars [`DataFrame`] is written to a worksheet. It can be
+/// used to set or hide the headers, wrap the data in a worksheet [`Table`],
+/// override the default number formats for each data type or column, and
+/// handle null values.
+///
+/// The default number formats used for Polars data types are:
+///
+/// | Polars type   | Excel number format       |
+/// | :------------ | :------------------------ |
+/// | `Date`        | `yyyy-mm-dd;@`            |
+/// | `Datetime`    | `yyyy-mm-dd hh:mm:ss`     |
+/// | `Time`        | `hh:mm:ss;@`              |
+/// | `Duration`    | `[h]:mm:ss`               |
+/// | `Decimal`     | `0.00`, based on scale    |
+///
+/// Other numeric types are written without a number format. `String` and
+/// `Categorical` data is written as Excel strings, `Boolean` data is written
+/// as Excel booleans and `List` data is written as a comma separated string of
+/// the list elements.
+///
+/// Excel doesn't have a time zone aware datetime type so Polars `Datetime`
+/// values with a time zone are converted to the local time in that zone. For
+/// example a value of `2024-01-01T12:00:00Z` with a time zone of
+/// `Europe/Berlin` is written as `2024-01-01 13:00:00`.
+///
+/// Float `NaN` and `Inf` values are handled in the same way as they are for
+/// [`Worksheet::write_number()`](crate::Worksheet::write_number()). See
+/// [`Worksheet::set_nan_value()`](crate::Worksheet::set_nan_value()) to
+/// change the default strings that are used.
+///
+/// [`DataFrame`]: https://docs.rs/polars/latest/polars/frame/struct.DataFrame.html
+///
+/// # Examples
+///
+/// The following example demonstrates writing a Polars dataframe to a
+/// worksheet table with some custom formatting.
+///
+/// ```
+/// # // This code is available in examples/doc_dataframe_options.rs
+/// #
+/// use polars::prelude::*;
+/// use rust_xlsxwriter::{DataFrameOptions, Format, Table, Workbook, XlsxError};
+///
+/// fn main() -> Result<(), XlsxError> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.add_worksheet();
+///
+///     // Create a sample dataframe.
+///     let df: DataFrame = df!(
+///         "Region" => &["North", "South", "East", "West"],
+///         "Sales" => &[1000.0, 2300.5, 1800.0, 2750.25],
+///     )?;
+///
+///     // Set some options for the dataframe.
+///     let options = DataFrameOptions::new()
+///         .set_table(Table::new())
+///         .set_column_format("Sales", Format::new().set_num_format("#,##0.00"))
+///         .set_autofit(true);
+///
+///     // Write the dataframe to the worksheet.
+///     worksheet.write_dataframe_with_options(&df, 0, 0, &options)?;
+///
+///     // Save the file to disk.
+///     workbook.save("dataframe.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
+pub struct DataFrameOptions {
+    pub(crate) core: DataOptions<DataType>,
+}
+
+impl Default for DataFrameOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DataFrameOptions {
+    /// Create a new `DataFrameOptions` object.
+    ///
+    /// Create a `DataFrameOptions` struct to be used with the
+    /// [`Worksheet::write_dataframe_with_options()`](crate::Worksheet::write_dataframe_with_options)
+    /// method.
+    ///
+    pub fn new() -> DataFrameOptions {
+        DataFrameOptions {
+            core: DataOptions::new(),
+        }
+    }
+
+    /// Hide the dataframe column headers.
+    ///
+    /// By default the dataframe column names are written as headers above the
+    /// data. This option can be used to write the data only.
+    ///
+    /// If the data is also written as a [`Table`] then the table header row is
+    /// also turned off.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn hide_headers(mut self, enable: bool) -> DataFrameOptions {
+        self.core.has_headers = !enable;
+        self
+    }
+
+    /// Set the format for the dataframe column headers.
+    ///
+    /// See [`Format`] for more information on formatting.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the headers.
+    ///
+    pub fn set_header_format(mut self, format: impl Into<Format>) -> DataFrameOptions {
+        self.core.header_format = Some(format.into());
+        self
+    }
+
+    /// Write the dataframe as a worksheet table.
+    ///
+    /// Add a worksheet [`Table`] around the dataframe data. The table range is
+    /// calculated from the dimensions of the dataframe and the table headers
+    /// are taken from the dataframe column names.
+    ///
+    /// If the table has a total row it is added below the dataframe data.
+    ///
+    /// # Parameters
+    ///
+    /// - `table`: A [`Table`] object with the required properties set.
+    ///
+    pub fn set_table(mut self, table: impl Into<Table>) -> DataFrameOptions {
+        self.core.table = Some(table.into());
+        self
+    }
+
+    /// Autofit the worksheet columns after the dataframe is written.
+    ///
+    /// This is equivalent to calling
+    /// [`Worksheet::autofit()`](crate::Worksheet::autofit) after the data has
+    /// been written. Note, this applies to all of the data in the worksheet
+    /// and not just the dataframe data.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_autofit(mut self, enable: bool) -> DataFrameOptions {
+        self.core.autofit = enable;
+        self
+    }
+
+    /// Set the string value used to represent null values.
+    ///
+    /// By default Polars null values are ignored and the corresponding cell is
+    /// left blank, or written as a formatted blank cell if the column has a
+    /// format. This option can be used to write a string such as `"NULL"` or
+    /// `"N/A"` instead.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The string to use for null values.
+    ///
+    pub fn set_null_value(mut self, value: impl Into<String>) -> DataFrameOptions {
+        self.core.null_value = Some(value.into());
+        self
+    }
+
+    /// Set the format for a named dataframe column.
+    ///
+    /// This format overrides any default or data type format for the data
+    /// cells in the column. The header cell isn't affected.
+    ///
+    /// # Parameters
+    ///
+    /// - `column_name`: The name of the dataframe column.
+    /// - `format`: The [`Format`] property for the column data.
+    ///
+    pub fn set_column_format(
+        mut self,
+        column_name: impl Into<String>,
+        format: impl Into<Format>,
+    ) -> DataFrameOptions {
+        self.core
+            .column_formats
+            .insert(column_name.into(), format.into());
+        self
+    }
+
+    /// Set the format for a Polars data type.
+    ///
+    /// This format overrides the default format for columns of the
+    /// corresponding data type. The data type is matched on its kind only, so
+    /// for example `DataType::Datetime(TimeUnit::Milliseconds, None)` also
+    /// matches datetime columns with other time units or time zones.
+    ///
+    /// # Parameters
+    ///
+    /// - `dtype`: A Polars [`DataType`].
+    /// - `format`: The [`Format`] property for the data type.
+    ///
+    /// [`DataType`]:
+    ///     https://docs.rs/polars/latest/polars/datatypes/enum.DataType.html
+    ///
+    pub fn set_dtype_format(
+        mut self,
+        dtype: DataType,
+        format: impl Into<Format>,
+    ) -> DataFrameOptions {
+        self.core.set_dtype_format(dtype, format.into());
+
+        self
+    }
+
+    // -----------------------------------------------------------------------
+    // Crate level helper methods.
+    // -----------------------------------------------------------------------
+
+    // Get the properties used to write the data cells of a dataframe column,
+    // and check that the column data type is supported.
+    pub(crate) fn column_properties(
+        &self,
+        column_name: &str,
+        dtype: &DataType,
+    ) -> Result<ColumnProperties<Tz>, XlsxError> {
+        if !is_supported_type(dtype) {
+            return Err(XlsxError::ParameterError(format!(
+                "Polars data type '{dtype}' in column '{column_name}' is not supported by Excel"
+            )));
+        }
+
+        let timezone = match dtype {
+            DataType::Datetime(_, Some(timezone)) => match timezone.parse::<Tz>() {
+                Ok(timezone) => Some(timezone),
+                Err(_) => {
+                    return Err(XlsxError::ParameterError(format!(
+                        "Unknown Polars datetime time zone '{timezone}' in column '{column_name}'"
+                    )));
+                }
+            },
+            _ => None,
+        };
+
+        Ok(ColumnProperties {
+            format: self.column_format(column_name, dtype),
+            timezone,
+        })
+    }
+
+    // Get the format for the data cells in a column, in order of precedence:
+    // user column format, user data type format, default data type format.
+    pub(crate) fn column_format(&self, column_name: &str, dtype: &DataType) -> Option<Format> {
+        self.core
+            .column_format(column_name, dtype, Self::default_dtype_format(dtype))
+    }
+
+    // Get the default Excel number format for a Polars data type, if any.
+    pub(crate) fn default_dtype_format(dtype: &DataType) -> Option<Format> {
+        match dtype {
+            DataType::Date => Some(Format::new().set_num_format(DATE_NUM_FORMAT)),
+            DataType::Datetime(_, _) => Some(Format::new().set_num_format(DATETIME_NUM_FORMAT)),
+            DataType::Time => Some(Format::new().set_num_format(TIME_NUM_FORMAT)),
+            DataType::Duration(_) => Some(Format::new().set_num_format(DURATION_NUM_FORMAT)),
+            DataType::Decimal(_, Some(scale)) => decimal_num_format(*scale),
+            _ => None,
+        }
+    }
+}
+
+// Check if a Polars data type can be written to Excel.
+fn is_supported_type(dtype: &DataType) -> bool {
+    matches!(
+        dtype,
+        DataType::Null
+            | DataType::Boolean
+            | DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::Int128
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::String
+            | DataType::Categorical(..)
+            | DataType::Enum(..)
+            | DataType::Date
+            | DataType::Datetime(_, _)
+            | DataType::Time
+            | DataType::Duration(_)
+            | DataType::Decimal(_, _)
+            | DataType::List(_)
+    )
+}
@@ -0,0 +1,176 @@
+// DataFrame unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod dataframe_tests {
+
+    use crate::{xmlwriter, DataFrameOptions, Format, Table, TableColumn, Worksheet, XlsxError};
+    use polars::prelude::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_default_dtype_formats() {
+        let tests = [
+            (DataType::Date, Some("yyyy\\-mm\\-dd;@")),
+            (
+                DataType::Datetime(TimeUnit::Milliseconds, None),
+                Some("yyyy\\-mm\\-dd\\ hh:mm:ss"),
+            ),
+            (
+                DataType::Datetime(TimeUnit::Nanoseconds, Some("UTC".into())),
+                Some("yyyy\\-mm\\-dd\\ hh:mm:ss"),
+            ),
+            (DataType::Time, Some("hh:mm:ss;@")),
+            (
+                DataType::Duration(TimeUnit::Microseconds),
+                Some("[h]:mm:ss"),
+            ),
+            (DataType::Int64, None),
+            (DataType::Float64, None),
+            (DataType::String, None),
+            (DataType::Boolean, None),
+        ];
+
+        for (dtype, expected) in tests {
+            let format = DataFrameOptions::default_dtype_format(&dtype);
+            let got = format.map(|format| format.num_format);
+
+            assert_eq!(expected.map(String::from), got, "{dtype:?}");
+        }
+    }
+
+    #[test]
+    fn test_column_format_precedence() {
+        let column_format = Format::new().set_num_format("0.000");
+        let dtype_format = Format::new().set_num_format("dd/mm/yyyy");
+
+        let options = DataFrameOptions::new()
+            .set_column_format("Sales", &column_format)
+            .set_dtype_format(DataType::Date, &dtype_format);
+
+        // Column format overrides everything else.
+        let got = options.column_format("Sales", &DataType::Date);
+        assert_eq!(Some(column_format.clone()), got);
+
+        // Dtype format overrides the default.
+        let got = options.column_format("Other", &DataType::Date);
+        assert_eq!(Some(dtype_format.clone()), got);
+
+        // Default format.
+        let got = options.column_format("Other", &DataType::Time);
+        assert_eq!(DataFrameOptions::default_dtype_format(&DataType::Time), got);
+
+        // No format.
+        let got = options.column_format("Other", &DataType::Int32);
+        assert_eq!(None, got);
+    }
+
+    #[test]
+    fn test_dtype_format_matches_kind() {
+        let format1 = Format::new().set_num_format("yyyy-mm-dd hh:mm");
+        let format2 = Format::new().set_num_format("dd/mm/yyyy hh:mm");
+
+        let options = DataFrameOptions::new()
+            .set_dtype_format(DataType::Datetime(TimeUnit::Milliseconds, None), &format1);
+
+        let dtype = DataType::Datetime(TimeUnit::Nanoseconds, Some("UTC".into()));
+        let got = options.column_format("Date", &dtype);
+        assert_eq!(Some(format1), got);
+
+        // A second format for the same kind of data type replaces the first.
+        let options =
+            options.set_dtype_format(DataType::Datetime(TimeUnit::Microseconds, None), &format2);
+
+        assert_eq!(1, options.core.dtype_formats.len());
+        let got = options.column_format("Date", &dtype);
+        assert_eq!(Some(format2), got);
+    }
+
+    #[test]
+    fn test_write_dataframe_limits() {
+        let mut worksheet = Worksheet::new();
+        let df = df!("col1" => &[1, 2, 3]).unwrap();
+
+        let result = worksheet.write_dataframe(&df, 1_048_574, 0);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.write_dataframe(&df, 1_048_573, 16_384);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.write_dataframe(&df, 1_048_572, 16_383);
+        assert!(result.is_ok());
+
+        // An empty dataframe is ignored.
+        let result = worksheet.write_dataframe(&DataFrame::empty(), 1_048_576, 0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_write_dataframe_unsupported_type() {
+        let mut worksheet = Worksheet::new();
+        let series = Series::new("col1".into(), &[b"abc".as_slice()]);
+        let df = DataFrame::new(vec![series.into()]).unwrap();
+
+        let result = worksheet.write_dataframe(&df, 0, 0);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn test_write_dataframe_unsupported_type_before_data() {
+        let mut worksheet = Worksheet::new();
+        let col1 = Series::new("col1".into(), &[1, 2, 3]);
+        let col2 = Series::full_null("col2".into(), 3, &DataType::Binary);
+        let df = DataFrame::new(vec![col1.into(), col2.into()]).unwrap();
+
+        // The error is raised before any cells, including the headers, are
+        // written.
+        let result = worksheet.write_dataframe(&df, 0, 0);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        worksheet.assemble_xml_file();
+        let got = xmlwriter::cursor_to_str(&worksheet.writer);
+        assert!(got.contains("<sheetData/>"));
+    }
+
+    #[test]
+    fn test_write_dataframe_unknown_timezone() {
+        let mut worksheet = Worksheet::new();
+        let series = Int64Chunked::new("col1".into(), &[0])
+            .into_datetime(TimeUnit::Milliseconds, Some("Mars/Olympus_Mons".into()))
+            .into_series();
+        let df = DataFrame::new(vec![series.into()]).unwrap();
+
+        let result = worksheet.write_dataframe(&df, 0, 0);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn test_write_dataframe_table_headers() {
+        let mut worksheet = Worksheet::new();
+        let df = df!(
+            "col1" => &[1, 2, 3],
+            "col2" => &[4, 5, 6],
+        )
+        .unwrap();
+
+        // User defined table column names take precedence.
+        let columns = vec![TableColumn::new().set_header("Renamed")];
+        let table = Table::new().set_columns(&columns);
+        let options = DataFrameOptions::new().set_table(table);
+
+        worksheet
+            .write_dataframe_with_options(&df, 0, 0, &options)
+            .unwrap();
+
+        let got: Vec<&str> = worksheet.tables[0]
+            .columns
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect();
+
+        assert_eq!(vec!["Renamed", "col2"], got);
+    }
+}
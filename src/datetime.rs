@@ -13,6 +13,12 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 #[cfg(feature = "chrono")]
 use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
 
+#[cfg(feature = "time")]
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+#[cfg(feature = "jiff")]
+use jiff::{civil, Zoned};
+
 #[cfg(not(all(
     feature = "wasm",
     target_arch = "wasm32",
@@ -1328,6 +1334,116 @@ impl ExcelDateTime {
 
         duration.num_milliseconds() as f64 / (24.0 * 60.0 * 60.0 * 1000.0)
     }
+
+    // Time crate date handling functions.
+
+    // Convert a time::PrimitiveDateTime to an Excel serial datetime.
+    #[cfg(feature = "time")]
+    pub(crate) fn time_datetime_to_excel(datetime: &PrimitiveDateTime) -> f64 {
+        let excel_date = Self::time_date_to_excel(&datetime.date());
+        let excel_time = Self::time_time_to_excel(&datetime.time());
+
+        excel_date + excel_time
+    }
+
+    // Convert a time::OffsetDateTime to an Excel serial datetime. Excel doesn't
+    // have timezones so the local date and time in the offset is used.
+    #[cfg(feature = "time")]
+    pub(crate) fn time_offset_datetime_to_excel(datetime: &OffsetDateTime) -> f64 {
+        let excel_date = Self::time_date_to_excel(&datetime.date());
+        let excel_time = Self::time_time_to_excel(&datetime.time());
+
+        excel_date + excel_time
+    }
+
+    // Convert a time::Date to an Excel serial date. The Julian day number is
+    // used to get the number of days since the 1899-12-31 epoch.
+    #[cfg(feature = "time")]
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(crate) fn time_date_to_excel(date: &Date) -> f64 {
+        // The Julian day number of 1899-12-31.
+        let epoch = 2_415_020;
+
+        Self::days_to_excel(f64::from(date.to_julian_day() - epoch))
+    }
+
+    // Convert a time::Time to an Excel time.
+    #[cfg(feature = "time")]
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(crate) fn time_time_to_excel(time: &Time) -> f64 {
+        let (hour, min, sec, milli) = time.as_hms_milli();
+
+        Self::hms_milli_to_excel(
+            i64::from(hour),
+            i64::from(min),
+            i64::from(sec),
+            i64::from(milli),
+        )
+    }
+
+    // Jiff date handling functions.
+
+    // Convert a jiff::civil::DateTime to an Excel serial datetime.
+    #[cfg(feature = "jiff")]
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(crate) fn jiff_datetime_to_excel(datetime: &civil::DateTime) -> f64 {
+        let excel_date = Self::jiff_date_to_excel(&datetime.date());
+        let excel_time = Self::jiff_time_to_excel(&datetime.time());
+
+        excel_date + excel_time
+    }
+
+    // Convert a jiff::Zoned to an Excel serial datetime. Excel doesn't have
+    // timezones so the civil date and time in the zone is used.
+    #[cfg(feature = "jiff")]
+    pub(crate) fn jiff_zoned_to_excel(zoned: &Zoned) -> f64 {
+        Self::jiff_datetime_to_excel(&zoned.datetime())
+    }
+
+    // Convert a jiff::civil::Date to an Excel serial date.
+    #[cfg(feature = "jiff")]
+    #[allow(clippy::cast_precision_loss)]
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(crate) fn jiff_date_to_excel(date: &civil::Date) -> f64 {
+        let epoch = civil::date(1899, 12, 31);
+        let duration = date.duration_since(epoch);
+
+        Self::days_to_excel((duration.as_secs() / DAY_SECONDS as i64) as f64)
+    }
+
+    // Convert a jiff::civil::Time to an Excel time.
+    #[cfg(feature = "jiff")]
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(crate) fn jiff_time_to_excel(time: &civil::Time) -> f64 {
+        Self::hms_milli_to_excel(
+            i64::from(time.hour()),
+            i64::from(time.minute()),
+            i64::from(time.second()),
+            i64::from(time.millisecond()),
+        )
+    }
+
+    // Adjust the number of days since the 1899-12-31 epoch for Excel's false
+    // 1900 leap day. Used by the external date library conversions.
+    #[cfg(any(feature = "time", feature = "jiff"))]
+    fn days_to_excel(days: f64) -> f64 {
+        if days > 59.0 {
+            days + 1.0
+        } else {
+            days
+        }
+    }
+
+    // Convert a time of day to an Excel time. The time portion of the Excel
+    // datetime is the number of milliseconds divided by the total number of
+    // milliseconds in the day. Used by the external date library conversions.
+    #[cfg(any(feature = "time", feature = "jiff"))]
+    #[allow(clippy::cast_precision_loss)]
+    fn hms_milli_to_excel(hour: i64, min: i64, sec: i64, milli: i64) -> f64 {
+        let milliseconds = ((hour * 60 + min) * 60 + sec) * 1000 + milli;
+
+        milliseconds as f64 / (24.0 * 60.0 * 60.0 * 1000.0)
+    }
 }
 
 impl Default for ExcelDateTime {
@@ -1367,7 +1483,14 @@ enum ExcelDateTimeType {
 /// optional `chrono` feature when adding `rust_xlsxwriter` to your
 /// `Cargo.toml`.
 ///
+/// The [`time`] and [`Jiff`] date/time types are also supported via the
+/// optional `time` and `jiff` features. Excel doesn't have time zones so for
+/// `time::OffsetDateTime` and `jiff::Zoned` the local date and time in the
+/// offset or time zone is used.
+///
 /// [`Chrono`]: https://docs.rs/chrono/latest/chrono
+/// [`time`]: https://docs.rs/time/latest/time
+/// [`Jiff`]: https://docs.rs/jiff/latest/jiff
 ///
 pub trait IntoExcelDateTime {
     /// Trait method to convert a date or time into an Excel serial datetime.
@@ -1435,6 +1558,134 @@ impl IntoExcelDateTime for NaiveTime {
     }
 }
 
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTime for &PrimitiveDateTime {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::time_datetime_to_excel(self)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTime for &OffsetDateTime {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::time_offset_datetime_to_excel(self)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTime for &Date {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::time_date_to_excel(self)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTime for &Time {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::time_time_to_excel(self)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTime for PrimitiveDateTime {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::time_datetime_to_excel(self)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTime for OffsetDateTime {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::time_offset_datetime_to_excel(self)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTime for Date {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::time_date_to_excel(self)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTime for Time {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::time_time_to_excel(self)
+    }
+}
+
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelDateTime for &civil::DateTime {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::jiff_datetime_to_excel(self)
+    }
+}
+
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelDateTime for &Zoned {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::jiff_zoned_to_excel(self)
+    }
+}
+
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelDateTime for &civil::Date {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::jiff_date_to_excel(self)
+    }
+}
+
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelDateTime for &civil::Time {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::jiff_time_to_excel(self)
+    }
+}
+
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelDateTime for civil::DateTime {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::jiff_datetime_to_excel(self)
+    }
+}
+
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelDateTime for Zoned {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::jiff_zoned_to_excel(self)
+    }
+}
+
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelDateTime for civil::Date {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::jiff_date_to_excel(self)
+    }
+}
+
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelDateTime for civil::Time {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::jiff_time_to_excel(self)
+    }
+}
+
 /// Implementation of the `serde::Serialize` trait for `ExcelDateTime`.
 ///
 /// An Excel datetime is a number (see the [`ExcelDateTime`] docs) so it will
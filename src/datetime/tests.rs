@@ -10,6 +10,12 @@ mod datetime_tests {
     #[cfg(feature = "chrono")]
     use chrono::prelude::*;
 
+    #[cfg(feature = "time")]
+    use time::{Date, Month, Time, UtcOffset};
+
+    #[cfg(feature = "jiff")]
+    use jiff::{civil, tz};
+
     use crate::{ExcelDateTime, XlsxError};
     use pretty_assertions::assert_eq;
 
@@ -2083,4 +2089,148 @@ mod datetime_tests {
             assert!(diff < 0.00000000001);
         }
     }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_dates_and_times() {
+        #[allow(clippy::excessive_precision)]
+        let datetimes = vec![
+            (1899, 12, 31, 0, 0, 0, 0, 0.0),
+            (1982, 8, 25, 0, 15, 20, 213, 30188.010650613425),
+            (2065, 4, 19, 0, 16, 48, 290, 60376.011670023145),
+            (2147, 12, 15, 0, 55, 25, 446, 90565.038488958337),
+            (9834, 9, 10, 23, 17, 12, 632, 2898088.9702850925),
+            (9999, 12, 31, 23, 59, 59, 0, 2958465.999988426),
+        ];
+
+        for test_data in datetimes {
+            let (year, month, day, hour, min, seconds, millis, expected) = test_data;
+            let month = Month::try_from(month).unwrap();
+            let datetime = Date::from_calendar_date(year, month, day)
+                .unwrap()
+                .with_hms_milli(hour, min, seconds, millis)
+                .unwrap();
+            assert_eq!(expected, ExcelDateTime::time_datetime_to_excel(&datetime));
+
+            // The local date and time of an offset datetime is used.
+            let offset = UtcOffset::from_hms(5, 30, 0).unwrap();
+            let datetime = datetime.assume_offset(offset);
+            assert_eq!(
+                expected,
+                ExcelDateTime::time_offset_datetime_to_excel(&datetime)
+            );
+        }
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_dates_only() {
+        let dates = vec![
+            (1899, 12, 31, 0.0),
+            (1900, 1, 1, 1.0),
+            (1900, 2, 27, 58.0),
+            (1900, 2, 28, 59.0),
+            (1900, 3, 1, 61.0),
+            (1900, 3, 2, 62.0),
+            (4321, 5, 31, 884404.0),
+            (4321, 6, 1, 884405.0),
+        ];
+
+        for test_data in dates {
+            let (year, month, day, expected) = test_data;
+            let month = Month::try_from(month).unwrap();
+            let date = Date::from_calendar_date(year, month, day).unwrap();
+            assert_eq!(expected, ExcelDateTime::time_date_to_excel(&date));
+        }
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_times_only() {
+        #[allow(clippy::excessive_precision)]
+        let times = vec![
+            (0, 0, 0, 0, 0.0),
+            (0, 15, 20, 213, 1.0650613425925924E-2),
+            (12, 0, 0, 0, 0.5),
+            (23, 59, 59, 999, 0.99999998842592586),
+        ];
+
+        for test_data in times {
+            let (hour, min, seconds, millis, expected) = test_data;
+            let time = Time::from_hms_milli(hour, min, seconds, millis).unwrap();
+            let mut diff = ExcelDateTime::time_time_to_excel(&time) - expected;
+            diff = diff.abs();
+            assert!(diff < 0.00000000001);
+        }
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn jiff_dates_and_times() {
+        #[allow(clippy::excessive_precision)]
+        let datetimes = vec![
+            (1899, 12, 31, 0, 0, 0, 0, 0.0),
+            (1982, 8, 25, 0, 15, 20, 213, 30188.010650613425),
+            (2065, 4, 19, 0, 16, 48, 290, 60376.011670023145),
+            (2147, 12, 15, 0, 55, 25, 446, 90565.038488958337),
+            (9834, 9, 10, 23, 17, 12, 632, 2898088.9702850925),
+            (9999, 12, 31, 23, 59, 59, 0, 2958465.999988426),
+        ];
+
+        for test_data in datetimes {
+            let (year, month, day, hour, min, seconds, millis, expected) = test_data;
+            let datetime = civil::date(year, month, day).at(hour, min, seconds, millis * 1_000_000);
+            assert_eq!(expected, ExcelDateTime::jiff_datetime_to_excel(&datetime));
+
+            // The civil date and time of a zoned datetime is used. Jiff
+            // timestamps don't extend to the end of 9999-12-31.
+            if year < 9999 {
+                let zoned = datetime
+                    .to_zoned(tz::TimeZone::fixed(tz::offset(8)))
+                    .unwrap();
+                assert_eq!(expected, ExcelDateTime::jiff_zoned_to_excel(&zoned));
+            }
+        }
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn jiff_dates_only() {
+        let dates = vec![
+            (1899, 12, 31, 0.0),
+            (1900, 1, 1, 1.0),
+            (1900, 2, 27, 58.0),
+            (1900, 2, 28, 59.0),
+            (1900, 3, 1, 61.0),
+            (1900, 3, 2, 62.0),
+            (4321, 5, 31, 884404.0),
+            (4321, 6, 1, 884405.0),
+        ];
+
+        for test_data in dates {
+            let (year, month, day, expected) = test_data;
+            let date = civil::date(year, month, day);
+            assert_eq!(expected, ExcelDateTime::jiff_date_to_excel(&date));
+        }
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn jiff_times_only() {
+        #[allow(clippy::excessive_precision)]
+        let times = vec![
+            (0, 0, 0, 0, 0.0),
+            (0, 15, 20, 213, 1.0650613425925924E-2),
+            (12, 0, 0, 0, 0.5),
+            (23, 59, 59, 999, 0.99999998842592586),
+        ];
+
+        for test_data in times {
+            let (hour, min, seconds, millis, expected) = test_data;
+            let time = civil::time(hour, min, seconds, millis * 1_000_000);
+            let mut diff = ExcelDateTime::jiff_time_to_excel(&time) - expected;
+            diff = diff.abs();
+            assert!(diff < 0.00000000001);
+        }
+    }
 }
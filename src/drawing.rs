@@ -13,16 +13,20 @@ use crate::xmlwriter::{
     xml_start_tag, xml_start_tag_only,
 };
 use crate::{
-    Color, ObjectMovement, Shape, ShapeFont, ShapeFormat, ShapeGradientFill, ShapeGradientFillType,
-    ShapeGradientStop, ShapeLine, ShapeLineDashType, ShapePatternFill, ShapeTextDirection,
-    ShapeTextHorizontalAlignment, Url,
+    Chart, Color, Image, ObjectMovement, Shape, ShapeFont, ShapeFormat, ShapeGradientFill,
+    ShapeGradientFillType, ShapeGradientStop, ShapeLine, ShapeLineArrowType, ShapeLineDashType,
+    ShapePatternFill, ShapePictureFill, ShapePictureFillType, ShapeTextDirection,
+    ShapeTextHorizontalAlignment, ShapeType, Url,
 };
 
 pub struct Drawing {
     pub(crate) writer: Cursor<Vec<u8>>,
     pub(crate) drawings: Vec<DrawingInfo>,
     pub(crate) shapes: Vec<Shape>,
+    pub(crate) groups: Vec<Vec<DrawingInfo>>,
+    pub(crate) is_chart_drawing: bool,
     shape_id: usize,
+    group_id: usize,
 }
 
 impl Drawing {
@@ -38,7 +42,10 @@ impl Drawing {
             writer,
             drawings: vec![],
             shapes: vec![],
+            groups: vec![],
+            is_chart_drawing: false,
             shape_id: 0,
+            group_id: 0,
         }
     }
 
@@ -50,6 +57,11 @@ impl Drawing {
     pub fn assemble_xml_file(&mut self) {
         xml_declaration(&mut self.writer);
 
+        if self.is_chart_drawing {
+            self.assemble_chart_drawing();
+            return;
+        }
+
         // Write the <xdr:wsDr> element.
         self.write_ws_dr();
 
@@ -59,9 +71,16 @@ impl Drawing {
                 // Write the <xdr:absoluteAnchor> element.
                 self.write_absolute_anchor(drawing);
             } else {
+                // Groups use an id for themselves and each of their objects.
+                let num_ids = if drawing.drawing_type == DrawingType::Group {
+                    1 + self.groups[self.group_id].len() as u32
+                } else {
+                    1
+                };
+
                 // Write the <xdr:twoCellAnchor> element.
                 self.write_two_cell_anchor(index, drawing);
-                index += 1;
+                index += num_ids;
             }
         }
 
@@ -69,6 +88,72 @@ impl Drawing {
         xml_end_tag(&mut self.writer, "xdr:wsDr");
     }
 
+    // Assemble the chartDrawing variant of the file used for shapes inserted
+    // into a chart.
+    fn assemble_chart_drawing(&mut self) {
+        // Write the <c:userShapes> element.
+        let attributes = [
+            (
+                "xmlns:c",
+                "http://schemas.openxmlformats.org/drawingml/2006/chart",
+            ),
+            (
+                "xmlns:a",
+                "http://schemas.openxmlformats.org/drawingml/2006/main",
+            ),
+        ];
+
+        xml_start_tag(&mut self.writer, "c:userShapes", &attributes);
+
+        for (index, drawing) in self.drawings.clone().iter().enumerate() {
+            // Write the <cdr:relSizeAnchor> element.
+            self.write_rel_size_anchor(index as u32 + 1, drawing);
+        }
+
+        xml_end_tag(&mut self.writer, "c:userShapes");
+    }
+
+    // Write the <cdr:relSizeAnchor> element.
+    fn write_rel_size_anchor(&mut self, index: u32, drawing_info: &DrawingInfo) {
+        let attributes = [(
+            "xmlns:cdr",
+            "http://schemas.openxmlformats.org/drawingml/2006/chartDrawing",
+        )];
+
+        xml_start_tag(&mut self.writer, "cdr:relSizeAnchor", &attributes);
+
+        // Write the <cdr:from> and <cdr:to> elements.
+        self.write_rel_size_coords("cdr:from", &drawing_info.from);
+        self.write_rel_size_coords("cdr:to", &drawing_info.to);
+
+        // Write the <cdr:sp> or <cdr:cxnSp> element.
+        self.write_next_shape(index, drawing_info);
+
+        xml_end_tag(&mut self.writer, "cdr:relSizeAnchor");
+    }
+
+    // Get the namespace prefix for the shape elements. The chartDrawing shape
+    // elements have the same structure as the spreadsheetDrawing elements but
+    // are in the "cdr" namespace.
+    fn prefix(&self) -> &'static str {
+        if self.is_chart_drawing {
+            "cdr"
+        } else {
+            "xdr"
+        }
+    }
+
+    // Write the <cdr:from> or <cdr:to> element. The coordinates are stored as
+    // a fraction of the chart width and height.
+    fn write_rel_size_coords(&mut self, tag: &str, coords: &DrawingCoordinates) {
+        xml_start_tag_only(&mut self.writer, tag);
+
+        xml_data_element_only(&mut self.writer, "cdr:x", &coords.col_offset.to_string());
+        xml_data_element_only(&mut self.writer, "cdr:y", &coords.row_offset.to_string());
+
+        xml_end_tag(&mut self.writer, tag);
+    }
+
     // Write the <xdr:wsDr> element.
     fn write_ws_dr(&mut self) {
         let attributes = [
@@ -107,12 +192,15 @@ impl Drawing {
 
         match drawing_info.drawing_type {
             DrawingType::Image => self.write_pic(index, drawing_info),
-            DrawingType::Chart => self.write_graphic_frame(index, drawing_info),
+            DrawingType::Chart => self.write_graphic_frame(index, drawing_info, false),
             DrawingType::Shape => {
-                let shape = self.shapes[self.shape_id].clone();
-                self.shape_id += 1;
+                self.write_next_shape(index, drawing_info);
+            }
+            DrawingType::Group => {
+                let objects = self.groups[self.group_id].clone();
+                self.group_id += 1;
 
-                self.write_sp(index, drawing_info, &shape);
+                self.write_grp_sp(index, drawing_info, &objects);
             }
             DrawingType::ChartSheet | DrawingType::Vml => {}
         }
@@ -121,6 +209,75 @@ impl Drawing {
         xml_end_tag(&mut self.writer, "xdr:twoCellAnchor");
     }
 
+    // Write the next stored shape as a <xdr:sp> or <xdr:cxnSp> element.
+    fn write_next_shape(&mut self, index: u32, drawing_info: &DrawingInfo) {
+        let shape = self.shapes[self.shape_id].clone();
+        self.shape_id += 1;
+
+        if shape.shape_type.is_connector() {
+            self.write_cxn_sp(index, drawing_info, &shape);
+        } else {
+            self.write_sp(index, drawing_info, &shape);
+        }
+    }
+
+    // Write the <xdr:grpSp> element.
+    fn write_grp_sp(&mut self, index: u32, drawing_info: &DrawingInfo, objects: &[DrawingInfo]) {
+        xml_start_tag_only(&mut self.writer, "xdr:grpSp");
+
+        // Write the <xdr:nvGrpSpPr> element.
+        xml_start_tag_only(&mut self.writer, "xdr:nvGrpSpPr");
+        self.write_c_nv_pr(index, drawing_info, "Group");
+        xml_empty_tag_only(&mut self.writer, "xdr:cNvGrpSpPr");
+        xml_end_tag(&mut self.writer, "xdr:nvGrpSpPr");
+
+        // Write the <xdr:grpSpPr> element.
+        self.write_grp_sp_pr(drawing_info);
+
+        // Write the grouped objects using the ids following the group id.
+        let mut object_index = index;
+        for object in objects {
+            object_index += 1;
+
+            match object.drawing_type {
+                DrawingType::Image => self.write_pic(object_index, object),
+                DrawingType::Chart => self.write_graphic_frame(object_index, object, true),
+                DrawingType::Shape => self.write_next_shape(object_index, object),
+                _ => {}
+            }
+        }
+
+        xml_end_tag(&mut self.writer, "xdr:grpSp");
+    }
+
+    // Write the <xdr:grpSpPr> element. The child offset and extent are the
+    // same as the group offset and extent so that the grouped objects use
+    // absolute worksheet positions.
+    fn write_grp_sp_pr(&mut self, drawing_info: &DrawingInfo) {
+        xml_start_tag_only(&mut self.writer, "xdr:grpSpPr");
+        xml_start_tag_only(&mut self.writer, "a:xfrm");
+
+        // Write the <a:off> element.
+        self.write_a_off(drawing_info);
+
+        // Write the <a:ext> element.
+        self.write_a_ext(drawing_info);
+
+        let x = drawing_info.col_absolute.to_string();
+        let y = drawing_info.row_absolute.to_string();
+        let cx = drawing_info.width.to_string();
+        let cy = drawing_info.height.to_string();
+
+        // Write the <a:chOff> element.
+        xml_empty_tag(&mut self.writer, "a:chOff", &[("x", x), ("y", y)]);
+
+        // Write the <a:chExt> element.
+        xml_empty_tag(&mut self.writer, "a:chExt", &[("cx", cx), ("cy", cy)]);
+
+        xml_end_tag(&mut self.writer, "a:xfrm");
+        xml_end_tag(&mut self.writer, "xdr:grpSpPr");
+    }
+
     // Write the <xdr:from> element.
     fn write_from(&mut self, coords: &DrawingCoordinates) {
         xml_start_tag_only(&mut self.writer, "xdr:from");
@@ -194,10 +351,12 @@ impl Drawing {
 
     // Write the <xdr:cNvPr> element.
     fn write_c_nv_pr(&mut self, index: u32, drawing_info: &DrawingInfo, name: &str) {
+        let prefix = self.prefix();
         let id = index + 1;
         let mut name = format!("{name} {index}");
 
-        if !name.starts_with("TextBox") && !drawing_info.name.is_empty() {
+        // Shapes store their text, rather than a name, in the drawing info.
+        if drawing_info.drawing_type != DrawingType::Shape && !drawing_info.name.is_empty() {
             name.clone_from(&drawing_info.name);
         }
 
@@ -208,7 +367,7 @@ impl Drawing {
         }
 
         if drawing_info.decorative || drawing_info.url.is_some() {
-            xml_start_tag(&mut self.writer, "xdr:cNvPr", &attributes);
+            xml_start_tag(&mut self.writer, &format!("{prefix}:cNvPr"), &attributes);
 
             if let Some(hyperlink) = &drawing_info.url {
                 // Write the <a:hlinkClick> element.
@@ -219,9 +378,9 @@ impl Drawing {
                 self.write_decorative();
             }
 
-            xml_end_tag(&mut self.writer, "xdr:cNvPr");
+            xml_end_tag(&mut self.writer, &format!("{prefix}:cNvPr"));
         } else {
-            xml_empty_tag(&mut self.writer, "xdr:cNvPr", &attributes);
+            xml_empty_tag(&mut self.writer, &format!("{prefix}:cNvPr"), &attributes);
         }
     }
 
@@ -297,6 +456,56 @@ impl Drawing {
         xml_end_tag(&mut self.writer, "xdr:blipFill");
     }
 
+    // Write the <a:blipFill> element for a shape picture fill.
+    fn write_shape_blip_fill(&mut self, fill: &ShapePictureFill) {
+        let attributes = [("rotWithShape", "1")];
+
+        xml_start_tag(&mut self.writer, "a:blipFill", &attributes);
+
+        // Write the <a:blip> element.
+        if fill.transparency > 0 {
+            let attributes = [
+                (
+                    "xmlns:r",
+                    "http://schemas.openxmlformats.org/officeDocument/2006/relationships"
+                        .to_string(),
+                ),
+                ("r:embed", format!("rId{}", fill.rel_id)),
+            ];
+            xml_start_tag(&mut self.writer, "a:blip", &attributes);
+
+            let amount = (100 - u32::from(fill.transparency)) * 1000;
+            let attributes = [("amt", amount.to_string())];
+            xml_empty_tag(&mut self.writer, "a:alphaModFix", &attributes);
+
+            xml_end_tag(&mut self.writer, "a:blip");
+        } else {
+            self.write_a_blip(fill.rel_id);
+        }
+
+        match fill.fill_type {
+            ShapePictureFillType::Stretch => {
+                xml_start_tag_only(&mut self.writer, "a:stretch");
+                xml_empty_tag_only(&mut self.writer, "a:fillRect");
+                xml_end_tag(&mut self.writer, "a:stretch");
+            }
+            ShapePictureFillType::Tile => {
+                // Write the <a:tile> element.
+                let attributes = [
+                    ("tx", "0"),
+                    ("ty", "0"),
+                    ("sx", "100000"),
+                    ("sy", "100000"),
+                    ("flip", "none"),
+                    ("algn", "tl"),
+                ];
+                xml_empty_tag(&mut self.writer, "a:tile", &attributes);
+            }
+        }
+
+        xml_end_tag(&mut self.writer, "a:blipFill");
+    }
+
     // Write the <a:blip> element.
     fn write_a_blip(&mut self, index: u32) {
         let attributes = [
@@ -324,14 +533,15 @@ impl Drawing {
         xml_end_tag(&mut self.writer, "a:xfrm");
 
         // Write the <a:prstGeom> element.
-        self.write_a_prst_geom();
+        self.write_a_prst_geom("rect");
 
         xml_end_tag(&mut self.writer, "xdr:spPr");
     }
 
     // Write the <xdr:spPr> element.
     fn write_shape_sp_pr(&mut self, drawing_info: &DrawingInfo, shape: &Shape) {
-        xml_start_tag_only(&mut self.writer, "xdr:spPr");
+        let prefix = self.prefix();
+        xml_start_tag_only(&mut self.writer, &format!("{prefix}:spPr"));
         xml_start_tag_only(&mut self.writer, "a:xfrm");
 
         // Write the <a:off> element.
@@ -343,12 +553,12 @@ impl Drawing {
         xml_end_tag(&mut self.writer, "a:xfrm");
 
         // Write the <a:prstGeom> element.
-        self.write_a_prst_geom();
+        self.write_a_prst_geom(&shape.shape_type.to_string());
 
         // Write the <a:solidFill> element.
-        self.write_shape_formatting(&shape.format);
+        self.write_shape_formatting(&shape.format, shape.shape_type == ShapeType::TextBox);
 
-        xml_end_tag(&mut self.writer, "xdr:spPr");
+        xml_end_tag(&mut self.writer, &format!("{prefix}:spPr"));
     }
 
     // Write the <a:off> element.
@@ -372,16 +582,18 @@ impl Drawing {
     }
 
     // Write the <a:prstGeom> element.
-    fn write_a_prst_geom(&mut self) {
-        let attributes = [("prst", "rect")];
+    fn write_a_prst_geom(&mut self, preset: &str) {
+        let attributes = [("prst", preset)];
 
         xml_start_tag(&mut self.writer, "a:prstGeom", &attributes);
         xml_empty_tag_only(&mut self.writer, "a:avLst");
         xml_end_tag(&mut self.writer, "a:prstGeom");
     }
 
-    // Write the <xdr:graphicFrame> element.
-    fn write_graphic_frame(&mut self, index: u32, drawing_info: &DrawingInfo) {
+    // Write the <xdr:graphicFrame> element. Charts in a group need the actual
+    // offset and extent of the chart since they are positioned relative to
+    // the group.
+    fn write_graphic_frame(&mut self, index: u32, drawing_info: &DrawingInfo, is_grouped: bool) {
         let attributes = [("macro", "")];
 
         xml_start_tag(&mut self.writer, "xdr:graphicFrame", &attributes);
@@ -390,7 +602,14 @@ impl Drawing {
         self.write_nv_graphic_frame_pr(index, drawing_info);
 
         // Write the <xdr:xfrm> element.
-        self.write_xfrm();
+        if is_grouped {
+            xml_start_tag_only(&mut self.writer, "xdr:xfrm");
+            self.write_a_off(drawing_info);
+            self.write_a_ext(drawing_info);
+            xml_end_tag(&mut self.writer, "xdr:xfrm");
+        } else {
+            self.write_xfrm();
+        }
 
         // Write the <a:graphic> element.
         self.write_a_graphic(drawing_info.rel_id);
@@ -495,6 +714,7 @@ impl Drawing {
 
     // Write the <xdr:sp> element.
     fn write_sp(&mut self, index: u32, drawing_info: &DrawingInfo, shape: &Shape) {
+        let prefix = self.prefix();
         let mut attributes = vec![("macro", String::new())];
 
         match &shape.text_link {
@@ -506,45 +726,177 @@ impl Drawing {
             }
         }
 
-        xml_start_tag(&mut self.writer, "xdr:sp", &attributes);
+        xml_start_tag(&mut self.writer, &format!("{prefix}:sp"), &attributes);
 
         // Write the <xdr:nvSpPr> element.
-        self.write_nv_sp_pr(index, drawing_info);
+        self.write_nv_sp_pr(index, drawing_info, shape);
 
         // Write the <xdr:spPr> element.
         self.write_shape_sp_pr(drawing_info, shape);
 
         // Write the <xdr:style> element.
-        self.write_style();
+        if shape.shape_type == ShapeType::TextBox {
+            self.write_style();
+        } else {
+            self.write_theme_style(false);
+        }
 
         // Write the <xdr:txBody> element.
         self.write_tx_body(drawing_info, shape);
 
-        xml_end_tag(&mut self.writer, "xdr:sp");
+        xml_end_tag(&mut self.writer, &format!("{prefix}:sp"));
     }
 
     // Write the <xdr:nvSpPr> element.
-    fn write_nv_sp_pr(&mut self, index: u32, drawing_info: &DrawingInfo) {
-        xml_start_tag_only(&mut self.writer, "xdr:nvSpPr");
+    fn write_nv_sp_pr(&mut self, index: u32, drawing_info: &DrawingInfo, shape: &Shape) {
+        let prefix = self.prefix();
+        xml_start_tag_only(&mut self.writer, &format!("{prefix}:nvSpPr"));
 
         // Write the <xdr:cNvPr> element.
-        self.write_c_nv_pr(index, drawing_info, "TextBox");
+        self.write_c_nv_pr(index, drawing_info, shape.shape_type.default_name());
 
         // Write the <xdr:cNvSpPr> element.
-        self.write_c_nv_sp_pr();
+        self.write_c_nv_sp_pr(shape.shape_type == ShapeType::TextBox);
 
-        xml_end_tag(&mut self.writer, "xdr:nvSpPr");
+        xml_end_tag(&mut self.writer, &format!("{prefix}:nvSpPr"));
     }
 
     // Write the <xdr:cNvSpPr> element.
-    fn write_c_nv_sp_pr(&mut self) {
-        let attributes = [("txBox", "1")];
+    fn write_c_nv_sp_pr(&mut self, is_textbox: bool) {
+        let prefix = self.prefix();
+        if is_textbox {
+            let attributes = [("txBox", "1")];
 
-        xml_empty_tag(&mut self.writer, "xdr:cNvSpPr", &attributes);
+            xml_empty_tag(&mut self.writer, &format!("{prefix}:cNvSpPr"), &attributes);
+        } else {
+            xml_empty_tag_only(&mut self.writer, &format!("{prefix}:cNvSpPr"));
+        }
     }
 
-    // Write the formatting elements for shapes.
-    fn write_shape_formatting(&mut self, format: &ShapeFormat) {
+    // Write the <xdr:cxnSp> element for connector shapes.
+    fn write_cxn_sp(&mut self, index: u32, drawing_info: &DrawingInfo, shape: &Shape) {
+        let prefix = self.prefix();
+        let attributes = [("macro", "")];
+
+        xml_start_tag(&mut self.writer, &format!("{prefix}:cxnSp"), &attributes);
+
+        // Write the <xdr:nvCxnSpPr> element.
+        self.write_nv_cxn_sp_pr(index, drawing_info, shape);
+
+        // Write the <xdr:spPr> element.
+        self.write_cxn_sp_pr(drawing_info, shape);
+
+        // Write the <xdr:style> element.
+        self.write_theme_style(true);
+
+        xml_end_tag(&mut self.writer, &format!("{prefix}:cxnSp"));
+    }
+
+    // Write the <xdr:nvCxnSpPr> element.
+    fn write_nv_cxn_sp_pr(&mut self, index: u32, drawing_info: &DrawingInfo, shape: &Shape) {
+        let prefix = self.prefix();
+        xml_start_tag_only(&mut self.writer, &format!("{prefix}:nvCxnSpPr"));
+
+        // Write the <xdr:cNvPr> element.
+        self.write_c_nv_pr(index, drawing_info, shape.shape_type.default_name());
+
+        // Write the <xdr:cNvCxnSpPr> element.
+        let has_sites = shape.connection.as_ref().is_some_and(|connection| {
+            connection.start_index.is_some() || connection.end_index.is_some()
+        });
+
+        if has_sites {
+            xml_start_tag_only(&mut self.writer, &format!("{prefix}:cNvCxnSpPr"));
+
+            if let Some(connection) = &shape.connection {
+                if let Some(site_index) = connection.start_index {
+                    // Write the <a:stCxn> element.
+                    self.write_a_cxn("a:stCxn", connection.start_id, site_index);
+                }
+
+                if let Some(site_index) = connection.end_index {
+                    // Write the <a:endCxn> element.
+                    self.write_a_cxn("a:endCxn", connection.end_id, site_index);
+                }
+            }
+
+            xml_end_tag(&mut self.writer, &format!("{prefix}:cNvCxnSpPr"));
+        } else {
+            xml_empty_tag_only(&mut self.writer, &format!("{prefix}:cNvCxnSpPr"));
+        }
+
+        xml_end_tag(&mut self.writer, &format!("{prefix}:nvCxnSpPr"));
+    }
+
+    // Write the <a:stCxn> or <a:endCxn> element.
+    fn write_a_cxn(&mut self, tag: &str, id: u32, site_index: u32) {
+        let attributes = [("id", id.to_string()), ("idx", site_index.to_string())];
+
+        xml_empty_tag(&mut self.writer, tag, &attributes);
+    }
+
+    // Write the <xdr:spPr> element for connector shapes.
+    fn write_cxn_sp_pr(&mut self, drawing_info: &DrawingInfo, shape: &Shape) {
+        let prefix = self.prefix();
+        xml_start_tag_only(&mut self.writer, &format!("{prefix}:spPr"));
+
+        let mut attributes = vec![];
+        let mut col_absolute = drawing_info.col_absolute as f64;
+        let mut row_absolute = drawing_info.row_absolute as f64;
+        let mut width = drawing_info.width;
+        let mut height = drawing_info.height;
+
+        if let Some(connection) = &shape.connection {
+            // Rotated connectors have their extents swapped around the same
+            // center point.
+            if connection.rotated {
+                attributes.push(("rot", "5400000"));
+                col_absolute += (width - height) / 2.0;
+                row_absolute += (height - width) / 2.0;
+                std::mem::swap(&mut width, &mut height);
+            }
+
+            if connection.flip_h {
+                attributes.push(("flipH", "1"));
+            }
+
+            if connection.flip_v {
+                attributes.push(("flipV", "1"));
+            }
+        }
+
+        xml_start_tag(&mut self.writer, "a:xfrm", &attributes);
+
+        // Write the <a:off> element.
+        let attributes = [
+            ("x", (col_absolute.round() as u64).to_string()),
+            ("y", (row_absolute.round() as u64).to_string()),
+        ];
+        xml_empty_tag(&mut self.writer, "a:off", &attributes);
+
+        // Write the <a:ext> element.
+        let attributes = [("cx", width.to_string()), ("cy", height.to_string())];
+        xml_empty_tag(&mut self.writer, "a:ext", &attributes);
+
+        xml_end_tag(&mut self.writer, "a:xfrm");
+
+        // Write the <a:prstGeom> element. Joined connectors may use a variant
+        // of the shape preset geometry.
+        match &shape.connection {
+            Some(connection) => self.write_a_prst_geom(connection.preset),
+            None => self.write_a_prst_geom(&shape.shape_type.to_string()),
+        }
+
+        // Write the <a:ln> element.
+        self.write_shape_formatting(&shape.format, false);
+
+        xml_end_tag(&mut self.writer, &format!("{prefix}:spPr"));
+    }
+
+    // Write the formatting elements for shapes. Textboxes have explicit
+    // default formatting. Other shapes get their default formatting from the
+    // theme via the <xdr:style> element.
+    fn write_shape_formatting(&mut self, format: &ShapeFormat, is_textbox: bool) {
         if format.no_fill {
             xml_empty_tag_only(&mut self.writer, "a:noFill");
         } else if let Some(solid_fill) = &format.solid_fill {
@@ -556,7 +908,12 @@ impl Drawing {
         } else if let Some(gradient_fill) = &format.gradient_fill {
             // Write the <a:gradFill> element.
             self.write_gradient_fill(gradient_fill);
-        } else {
+        } else if let Some(picture_fill) =
+            format.picture_fill.as_ref().filter(|fill| fill.rel_id > 0)
+        {
+            // Write the <a:blipFill> element.
+            self.write_shape_blip_fill(picture_fill);
+        } else if is_textbox {
             // Write the <a:solidFill> element.
             self.write_default_solid_fill();
         }
@@ -566,16 +923,16 @@ impl Drawing {
             self.write_a_ln_none();
         } else if let Some(line) = &format.line {
             // Write the <a:ln> element.
-            self.write_a_ln(line);
-        } else {
+            self.write_a_ln(line, is_textbox);
+        } else if is_textbox {
             // Write the default <a:ln> element.
             let line = ShapeLine::new();
-            self.write_a_ln(&line);
+            self.write_a_ln(&line, is_textbox);
         }
     }
 
     // Write the <a:ln> element.
-    fn write_a_ln(&mut self, line: &ShapeLine) {
+    fn write_a_ln(&mut self, line: &ShapeLine, is_textbox: bool) {
         let mut attributes = vec![];
 
         // Round width to nearest 0.25, like Excel.
@@ -595,12 +952,12 @@ impl Drawing {
                 // Write the <a:noFill> element.
                 self.write_a_no_fill();
             } else {
-                if line.color == Color::Default {
-                    // Write the <a:solidFill> element.
-                    self.write_line_solid_fill();
-                } else {
+                if line.color != Color::Default {
                     // Write the <a:solidFill> element.
                     self.write_a_solid_fill(line.color, line.transparency);
+                } else if is_textbox {
+                    // Write the <a:solidFill> element.
+                    self.write_line_solid_fill();
                 }
 
                 if line.dash_type != ShapeLineDashType::Solid {
@@ -608,11 +965,23 @@ impl Drawing {
                     self.write_a_prst_dash(line);
                 }
             }
-        } else {
+        } else if is_textbox {
             // Write the <a:solidFill> element.
             self.write_line_solid_fill();
         }
 
+        if line.start_arrow != ShapeLineArrowType::None {
+            // Write the <a:headEnd> element.
+            let attributes = [("type", line.start_arrow.to_string())];
+            xml_empty_tag(&mut self.writer, "a:headEnd", &attributes);
+        }
+
+        if line.end_arrow != ShapeLineArrowType::None {
+            // Write the <a:tailEnd> element.
+            let attributes = [("type", line.end_arrow.to_string())];
+            xml_empty_tag(&mut self.writer, "a:tailEnd", &attributes);
+        }
+
         xml_end_tag(&mut self.writer, "a:ln");
     }
 
@@ -912,7 +1281,8 @@ impl Drawing {
 
     // Write the <xdr:style> element.
     fn write_style(&mut self) {
-        xml_start_tag_only(&mut self.writer, "xdr:style");
+        let prefix = self.prefix();
+        xml_start_tag_only(&mut self.writer, &format!("{prefix}:style"));
 
         // Write the <a:lnRef> element.
         self.write_a_ln_ref();
@@ -926,7 +1296,48 @@ impl Drawing {
         // Write the <a:fontRef> element.
         self.write_a_font_ref();
 
-        xml_end_tag(&mut self.writer, "xdr:style");
+        xml_end_tag(&mut self.writer, &format!("{prefix}:style"));
+    }
+
+    // Write the <xdr:style> element for preset and connector shapes. These
+    // refer to the theme line, fill and font styles rather than the explicit
+    // colors used by textboxes.
+    fn write_theme_style(&mut self, is_connector: bool) {
+        let prefix = self.prefix();
+        let (line_index, fill_index, font_color) = if is_connector {
+            ("1", "0", "tx1")
+        } else {
+            ("2", "1", "lt1")
+        };
+
+        xml_start_tag_only(&mut self.writer, &format!("{prefix}:style"));
+
+        // Write the <a:lnRef> element. Shapes use a darker shade of the theme
+        // color for the border.
+        let attributes = [("idx", line_index)];
+        xml_start_tag(&mut self.writer, "a:lnRef", &attributes);
+        self.write_default_scheme_clr("accent1", !is_connector);
+        xml_end_tag(&mut self.writer, "a:lnRef");
+
+        // Write the <a:fillRef> element.
+        let attributes = [("idx", fill_index)];
+        xml_start_tag(&mut self.writer, "a:fillRef", &attributes);
+        self.write_default_scheme_clr("accent1", false);
+        xml_end_tag(&mut self.writer, "a:fillRef");
+
+        // Write the <a:effectRef> element.
+        let attributes = [("idx", "0")];
+        xml_start_tag(&mut self.writer, "a:effectRef", &attributes);
+        self.write_default_scheme_clr("accent1", false);
+        xml_end_tag(&mut self.writer, "a:effectRef");
+
+        // Write the <a:fontRef> element.
+        let attributes = [("idx", "minor")];
+        xml_start_tag(&mut self.writer, "a:fontRef", &attributes);
+        self.write_default_scheme_clr(font_color, false);
+        xml_end_tag(&mut self.writer, "a:fontRef");
+
+        xml_end_tag(&mut self.writer, &format!("{prefix}:style"));
     }
 
     // Write the <a:scrgbClr> element.
@@ -986,7 +1397,8 @@ impl Drawing {
 
     // Write the <xdr:txBody> element.
     fn write_tx_body(&mut self, drawing_info: &DrawingInfo, shape: &Shape) {
-        xml_start_tag_only(&mut self.writer, "xdr:txBody");
+        let prefix = self.prefix();
+        xml_start_tag_only(&mut self.writer, &format!("{prefix}:txBody"));
 
         // Write the <a:bodyPr> element.
         self.write_a_body_pr(shape);
@@ -1006,7 +1418,7 @@ impl Drawing {
             self.write_a_p(text, shape);
         }
 
-        xml_end_tag(&mut self.writer, "xdr:txBody");
+        xml_end_tag(&mut self.writer, &format!("{prefix}:txBody"));
     }
 
     // Write the <a:bodyPr> element.
@@ -1191,7 +1603,7 @@ impl Drawing {
         // Write the <xdr:ext> element.
         self.write_ext(drawing_info);
 
-        self.write_graphic_frame(1, drawing_info);
+        self.write_graphic_frame(1, drawing_info, false);
 
         xml_empty_tag_only(&mut self.writer, "xdr:clientData");
         xml_end_tag(&mut self.writer, "xdr:absoluteAnchor");
@@ -1228,6 +1640,298 @@ impl Drawing {
     }
 }
 
+// -----------------------------------------------------------------------
+// DrawingGroup
+// -----------------------------------------------------------------------
+
+/// The `DrawingGroup` struct represents a group of worksheet drawing objects.
+///
+/// A `DrawingGroup` can be used to group several [`Image`], [`Shape`] and
+/// [`Chart`] objects, including textboxes, so that they behave as a single
+/// object in Excel. The grouped objects move and resize together, for example when
+/// rows or columns are resized, and they can be selected and moved as one
+/// object by the user.
+///
+/// The position of each object is set as a pixel offset from the top left
+/// corner of the group. The group itself is inserted into a worksheet with
+/// [`Worksheet::insert_drawing_group()`](crate::Worksheet::insert_drawing_group)
+/// or
+/// [`Worksheet::insert_drawing_group_with_offset()`](crate::Worksheet::insert_drawing_group_with_offset).
+///
+/// The positioning properties of the individual objects, such as the object
+/// movement, are ignored and the group properties are used instead.
+///
+/// # Examples
+///
+/// This example demonstrates grouping an image, a textbox and an arrow shape
+/// so that they stay together when the worksheet rows are resized.
+///
+/// ```
+/// # // This code is available in examples/doc_drawing_group.rs
+/// #
+/// # use rust_xlsxwriter::{DrawingGroup, Image, Shape, ShapeType, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     // Create a new Excel file object.
+/// #     let mut workbook = Workbook::new();
+/// #
+/// #     // Add a worksheet to the workbook.
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+///     // Create the objects to group.
+///     let image = Image::new("examples/rust_logo.png")?.set_scale_to_size(64, 64, true);
+///     let textbox = Shape::textbox().set_text("Sales are up this quarter");
+///     let arrow = Shape::new(ShapeType::LeftArrow)
+///         .set_width(80)
+///         .set_height(40);
+///
+///     // Create a group with the objects at offsets from the top left corner.
+///     let group = DrawingGroup::new()
+///         .add_image(&image, 0, 0)
+///         .add_shape(&arrow, 80, 12)
+///         .add_shape(&textbox, 176, 0);
+///
+///     // Insert the group into the worksheet.
+///     worksheet.insert_drawing_group(1, 1, &group)?;
+/// #
+/// #     // Save the file to disk.
+/// #     workbook.save("drawing_group.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct DrawingGroup {
+    pub(crate) objects: Vec<DrawingGroupObject>,
+    pub(crate) x_offset: u32,
+    pub(crate) y_offset: u32,
+    pub(crate) object_movement: ObjectMovement,
+    pub(crate) name: String,
+    pub(crate) alt_text: String,
+}
+
+impl Default for DrawingGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DrawingGroup {
+    /// Create a new `DrawingGroup` object to represent a group of worksheet
+    /// drawing objects.
+    ///
+    pub fn new() -> DrawingGroup {
+        DrawingGroup {
+            objects: vec![],
+            x_offset: 0,
+            y_offset: 0,
+            object_movement: ObjectMovement::MoveAndSizeWithCells,
+            name: String::new(),
+            alt_text: String::new(),
+        }
+    }
+
+    /// Add an image to the group.
+    ///
+    /// # Parameters
+    ///
+    /// - `image`: The [`Image`] to add to the group.
+    /// - `x_offset`: The horizontal offset from the left of the group in
+    ///   pixels.
+    /// - `y_offset`: The vertical offset from the top of the group in pixels.
+    ///
+    pub fn add_image(mut self, image: &Image, x_offset: u32, y_offset: u32) -> DrawingGroup {
+        let mut image = image.clone();
+        image.x_offset = x_offset;
+        image.y_offset = y_offset;
+
+        self.objects.push(DrawingGroupObject::Image(image));
+        self
+    }
+
+    /// Add a shape or textbox to the group.
+    ///
+    /// # Parameters
+    ///
+    /// - `shape`: The [`Shape`] to add to the group.
+    /// - `x_offset`: The horizontal offset from the left of the group in
+    ///   pixels.
+    /// - `y_offset`: The vertical offset from the top of the group in pixels.
+    ///
+    pub fn add_shape(mut self, shape: &Shape, x_offset: u32, y_offset: u32) -> DrawingGroup {
+        let mut shape = shape.clone();
+        shape.x_offset = x_offset;
+        shape.y_offset = y_offset;
+
+        self.objects.push(DrawingGroupObject::Shape(shape));
+        self
+    }
+
+    /// Add a chart to the group.
+    ///
+    /// The chart is checked when the group is inserted into a worksheet, in
+    /// the same way as [`Worksheet::insert_chart()`](crate::Worksheet::insert_chart).
+    ///
+    /// # Parameters
+    ///
+    /// - `chart`: The [`Chart`] to add to the group.
+    /// - `x_offset`: The horizontal offset from the left of the group in
+    ///   pixels.
+    /// - `y_offset`: The vertical offset from the top of the group in pixels.
+    ///
+    pub fn add_chart(mut self, chart: &Chart, x_offset: u32, y_offset: u32) -> DrawingGroup {
+        let mut chart = chart.clone();
+        chart.x_offset = x_offset;
+        chart.y_offset = y_offset;
+
+        self.objects.push(DrawingGroupObject::Chart(chart));
+        self
+    }
+
+    /// Set the name of the group.
+    ///
+    /// Set the name of the group as displayed in the Excel "Selection Pane".
+    /// The default name is "Group N".
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The group name.
+    ///
+    pub fn set_name(mut self, name: impl Into<String>) -> DrawingGroup {
+        self.name = name.into();
+        self
+    }
+
+    /// Set the alt text for the group to help accessibility.
+    ///
+    /// # Parameters
+    ///
+    /// - `alt_text`: The alt text string to add to the group.
+    ///
+    pub fn set_alt_text(mut self, alt_text: impl Into<String>) -> DrawingGroup {
+        self.alt_text = alt_text.into();
+        self
+    }
+
+    /// Set the object movement options for a group.
+    ///
+    /// Set the option to define how the group will behave in Excel if the
+    /// cells under the group are moved, deleted, or have their size changed.
+    /// This applies to the group as a whole and the object movement options
+    /// of the grouped objects are ignored.
+    ///
+    /// See [`Image::set_object_movement()`] for a description of the
+    /// options.
+    ///
+    /// # Parameters
+    ///
+    /// - `option`: An object positioning behavior defined by the
+    ///   [`ObjectMovement`] enum.
+    ///
+    pub fn set_object_movement(mut self, option: ObjectMovement) -> DrawingGroup {
+        self.object_movement = option;
+        self
+    }
+
+    // Get the width and height of the group from the extent of its objects.
+    fn extent(&self) -> (f64, f64) {
+        let mut width = 0.0_f64;
+        let mut height = 0.0_f64;
+
+        for object in &self.objects {
+            let (x_offset, y_offset, object_width, object_height) = match object {
+                DrawingGroupObject::Image(image) => (
+                    image.x_offset,
+                    image.y_offset,
+                    image.width_scaled(),
+                    image.height_scaled(),
+                ),
+                DrawingGroupObject::Shape(shape) => (
+                    shape.x_offset,
+                    shape.y_offset,
+                    shape.width_scaled(),
+                    shape.height_scaled(),
+                ),
+                DrawingGroupObject::Chart(chart) => (
+                    chart.x_offset,
+                    chart.y_offset,
+                    chart.width_scaled(),
+                    chart.height_scaled(),
+                ),
+            };
+
+            width = width.max(f64::from(x_offset) + object_width);
+            height = height.max(f64::from(y_offset) + object_height);
+        }
+
+        (width, height)
+    }
+
+    // Get the charts in the group.
+    pub(crate) fn charts(&self) -> impl Iterator<Item = &Chart> {
+        self.objects.iter().filter_map(|object| match object {
+            DrawingGroupObject::Chart(chart) => Some(chart),
+            _ => None,
+        })
+    }
+
+    // Get mutable references to the charts in the group.
+    pub(crate) fn charts_mut(&mut self) -> impl Iterator<Item = &mut Chart> {
+        self.objects.iter_mut().filter_map(|object| match object {
+            DrawingGroupObject::Chart(chart) => Some(chart),
+            _ => None,
+        })
+    }
+}
+
+// The types of objects that can be stored in a drawing group.
+#[derive(Clone)]
+#[allow(clippy::large_enum_variant)]
+pub(crate) enum DrawingGroupObject {
+    Image(Image),
+    Shape(Shape),
+    Chart(Chart),
+}
+
+// Trait for objects that have a component stored in the drawing.xml file.
+impl DrawingObject for DrawingGroup {
+    fn x_offset(&self) -> u32 {
+        self.x_offset
+    }
+
+    fn y_offset(&self) -> u32 {
+        self.y_offset
+    }
+
+    fn width_scaled(&self) -> f64 {
+        self.extent().0
+    }
+
+    fn height_scaled(&self) -> f64 {
+        self.extent().1
+    }
+
+    fn object_movement(&self) -> ObjectMovement {
+        self.object_movement
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn alt_text(&self) -> String {
+        self.alt_text.clone()
+    }
+
+    fn decorative(&self) -> bool {
+        false
+    }
+
+    fn drawing_type(&self) -> DrawingType {
+        DrawingType::Group
+    }
+}
+
 // -----------------------------------------------------------------------
 // Helper enums/structs/functions.
 // -----------------------------------------------------------------------
@@ -1296,6 +2000,7 @@ pub(crate) struct DrawingInfo {
 pub(crate) enum DrawingType {
     Chart,
     ChartSheet,
+    Group,
     Image,
     Shape,
     Vml,
@@ -9,6 +9,7 @@ mod drawing_tests {
 
     use crate::test_functions::xml_to_vec;
     use crate::{drawing::*, xmlwriter};
+    use crate::{Chart, ChartType, Shape, ShapeConnection, ShapeLineArrowType, ShapeType};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -101,4 +102,419 @@ mod drawing_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_connector() {
+        let mut drawing = Drawing::new();
+
+        let from = DrawingCoordinates {
+            col: 2,
+            row: 7,
+            col_offset: 304800.0,
+            row_offset: 0.0,
+        };
+
+        let to = DrawingCoordinates {
+            col: 5,
+            row: 10,
+            col_offset: 0.0,
+            row_offset: 0.0,
+        };
+
+        let drawing_info = DrawingInfo {
+            from,
+            to,
+            col_absolute: 1524000,
+            row_absolute: 1333500,
+            width: 1524000.0,
+            height: 571500.0,
+            name: String::new(),
+            description: String::new(),
+            decorative: false,
+            rel_id: 3,
+            object_movement: ObjectMovement::MoveAndSizeWithCells,
+            drawing_type: DrawingType::Shape,
+            url: None,
+            is_portrait: false,
+        };
+
+        let mut connector = Shape::new(ShapeType::ElbowConnector)
+            .set_format(&ShapeLine::new().set_end_arrow(ShapeLineArrowType::Triangle));
+
+        connector.connection = Some(ShapeConnection {
+            start_id: 2,
+            start_index: Some(2),
+            end_id: 3,
+            end_index: Some(1),
+            flip_h: false,
+            flip_v: true,
+            rotated: true,
+            preset: "bentConnector2",
+        });
+
+        drawing.drawings.push(drawing_info);
+        drawing.shapes.push(connector);
+
+        drawing.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&drawing.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <xdr:wsDr xmlns:xdr="http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+                <xdr:twoCellAnchor>
+                    <xdr:from>
+                    <xdr:col>2</xdr:col>
+                    <xdr:colOff>304800</xdr:colOff>
+                    <xdr:row>7</xdr:row>
+                    <xdr:rowOff>0</xdr:rowOff>
+                    </xdr:from>
+                    <xdr:to>
+                    <xdr:col>5</xdr:col>
+                    <xdr:colOff>0</xdr:colOff>
+                    <xdr:row>10</xdr:row>
+                    <xdr:rowOff>0</xdr:rowOff>
+                    </xdr:to>
+                    <xdr:cxnSp macro="">
+                    <xdr:nvCxnSpPr>
+                        <xdr:cNvPr id="2" name="Elbow Connector 1"/>
+                        <xdr:cNvCxnSpPr>
+                        <a:stCxn id="2" idx="2"/>
+                        <a:endCxn id="3" idx="1"/>
+                        </xdr:cNvCxnSpPr>
+                    </xdr:nvCxnSpPr>
+                    <xdr:spPr>
+                        <a:xfrm rot="5400000" flipV="1">
+                        <a:off x="2000250" y="857250"/>
+                        <a:ext cx="571500" cy="1524000"/>
+                        </a:xfrm>
+                        <a:prstGeom prst="bentConnector2">
+                        <a:avLst/>
+                        </a:prstGeom>
+                        <a:ln w="9525" cmpd="sng">
+                        <a:tailEnd type="triangle"/>
+                        </a:ln>
+                    </xdr:spPr>
+                    <xdr:style>
+                        <a:lnRef idx="1">
+                        <a:schemeClr val="accent1"/>
+                        </a:lnRef>
+                        <a:fillRef idx="0">
+                        <a:schemeClr val="accent1"/>
+                        </a:fillRef>
+                        <a:effectRef idx="0">
+                        <a:schemeClr val="accent1"/>
+                        </a:effectRef>
+                        <a:fontRef idx="minor">
+                        <a:schemeClr val="tx1"/>
+                        </a:fontRef>
+                    </xdr:style>
+                    </xdr:cxnSp>
+                    <xdr:clientData/>
+                </xdr:twoCellAnchor>
+                </xdr:wsDr>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_group() {
+        let mut drawing = Drawing::new();
+
+        let from = DrawingCoordinates {
+            col: 1,
+            row: 1,
+            col_offset: 0.0,
+            row_offset: 0.0,
+        };
+
+        let to = DrawingCoordinates {
+            col: 2,
+            row: 4,
+            col_offset: 0.0,
+            row_offset: 0.0,
+        };
+
+        let group_info = DrawingInfo {
+            from,
+            to,
+            col_absolute: 609600,
+            row_absolute: 190500,
+            width: 609600.0,
+            height: 571500.0,
+            object_movement: ObjectMovement::MoveAndSizeWithCells,
+            drawing_type: DrawingType::Group,
+            ..DrawingInfo::default()
+        };
+
+        let image_info = DrawingInfo {
+            col_absolute: 609600,
+            row_absolute: 190500,
+            width: 609600.0,
+            height: 571500.0,
+            rel_id: 1,
+            drawing_type: DrawingType::Image,
+            ..DrawingInfo::default()
+        };
+
+        drawing.drawings.push(group_info);
+        drawing.groups.push(vec![image_info]);
+
+        drawing.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&drawing.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <xdr:wsDr xmlns:xdr="http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+                <xdr:twoCellAnchor>
+                    <xdr:from>
+                    <xdr:col>1</xdr:col>
+                    <xdr:colOff>0</xdr:colOff>
+                    <xdr:row>1</xdr:row>
+                    <xdr:rowOff>0</xdr:rowOff>
+                    </xdr:from>
+                    <xdr:to>
+                    <xdr:col>2</xdr:col>
+                    <xdr:colOff>0</xdr:colOff>
+                    <xdr:row>4</xdr:row>
+                    <xdr:rowOff>0</xdr:rowOff>
+                    </xdr:to>
+                    <xdr:grpSp>
+                    <xdr:nvGrpSpPr>
+                        <xdr:cNvPr id="2" name="Group 1"/>
+                        <xdr:cNvGrpSpPr/>
+                    </xdr:nvGrpSpPr>
+                    <xdr:grpSpPr>
+                        <a:xfrm>
+                        <a:off x="609600" y="190500"/>
+                        <a:ext cx="609600" cy="571500"/>
+                        <a:chOff x="609600" y="190500"/>
+                        <a:chExt cx="609600" cy="571500"/>
+                        </a:xfrm>
+                    </xdr:grpSpPr>
+                    <xdr:pic>
+                        <xdr:nvPicPr>
+                        <xdr:cNvPr id="3" name="Picture 2"/>
+                        <xdr:cNvPicPr>
+                            <a:picLocks noChangeAspect="1"/>
+                        </xdr:cNvPicPr>
+                        </xdr:nvPicPr>
+                        <xdr:blipFill>
+                        <a:blip xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:embed="rId1"/>
+                        <a:stretch>
+                            <a:fillRect/>
+                        </a:stretch>
+                        </xdr:blipFill>
+                        <xdr:spPr>
+                        <a:xfrm>
+                            <a:off x="609600" y="190500"/>
+                            <a:ext cx="609600" cy="571500"/>
+                        </a:xfrm>
+                        <a:prstGeom prst="rect">
+                            <a:avLst/>
+                        </a:prstGeom>
+                        </xdr:spPr>
+                    </xdr:pic>
+                    </xdr:grpSp>
+                    <xdr:clientData/>
+                </xdr:twoCellAnchor>
+                </xdr:wsDr>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_group_chart() {
+        let mut drawing = Drawing::new();
+
+        let from = DrawingCoordinates {
+            col: 1,
+            row: 1,
+            col_offset: 0.0,
+            row_offset: 0.0,
+        };
+
+        let to = DrawingCoordinates {
+            col: 9,
+            row: 16,
+            col_offset: 0.0,
+            row_offset: 0.0,
+        };
+
+        let group_info = DrawingInfo {
+            from,
+            to,
+            col_absolute: 609600,
+            row_absolute: 190500,
+            width: 4572000.0,
+            height: 2743200.0,
+            object_movement: ObjectMovement::MoveAndSizeWithCells,
+            drawing_type: DrawingType::Group,
+            ..DrawingInfo::default()
+        };
+
+        let chart_info = DrawingInfo {
+            col_absolute: 609600,
+            row_absolute: 190500,
+            width: 4572000.0,
+            height: 2743200.0,
+            rel_id: 1,
+            drawing_type: DrawingType::Chart,
+            ..DrawingInfo::default()
+        };
+
+        drawing.drawings.push(group_info);
+        drawing.groups.push(vec![chart_info]);
+
+        drawing.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&drawing.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <xdr:wsDr xmlns:xdr="http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+                <xdr:twoCellAnchor>
+                    <xdr:from>
+                    <xdr:col>1</xdr:col>
+                    <xdr:colOff>0</xdr:colOff>
+                    <xdr:row>1</xdr:row>
+                    <xdr:rowOff>0</xdr:rowOff>
+                    </xdr:from>
+                    <xdr:to>
+                    <xdr:col>9</xdr:col>
+                    <xdr:colOff>0</xdr:colOff>
+                    <xdr:row>16</xdr:row>
+                    <xdr:rowOff>0</xdr:rowOff>
+                    </xdr:to>
+                    <xdr:grpSp>
+                    <xdr:nvGrpSpPr>
+                        <xdr:cNvPr id="2" name="Group 1"/>
+                        <xdr:cNvGrpSpPr/>
+                    </xdr:nvGrpSpPr>
+                    <xdr:grpSpPr>
+                        <a:xfrm>
+                        <a:off x="609600" y="190500"/>
+                        <a:ext cx="4572000" cy="2743200"/>
+                        <a:chOff x="609600" y="190500"/>
+                        <a:chExt cx="4572000" cy="2743200"/>
+                        </a:xfrm>
+                    </xdr:grpSpPr>
+                    <xdr:graphicFrame macro="">
+                        <xdr:nvGraphicFramePr>
+                        <xdr:cNvPr id="3" name="Chart 2"/>
+                        <xdr:cNvGraphicFramePr/>
+                        </xdr:nvGraphicFramePr>
+                        <xdr:xfrm>
+                        <a:off x="609600" y="190500"/>
+                        <a:ext cx="4572000" cy="2743200"/>
+                        </xdr:xfrm>
+                        <a:graphic>
+                        <a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/chart">
+                            <c:chart xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:id="rId1"/>
+                        </a:graphicData>
+                        </a:graphic>
+                    </xdr:graphicFrame>
+                    </xdr:grpSp>
+                    <xdr:clientData/>
+                </xdr:twoCellAnchor>
+                </xdr:wsDr>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_chart_drawing() {
+        let mut chart = Chart::new(ChartType::Column);
+        let shape = Shape::textbox().set_text("Note").set_height(144);
+        chart.insert_shape(&shape, 48, 72);
+
+        let mut drawing = chart.user_shapes_drawing();
+
+        drawing.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&drawing.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <c:userShapes xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+                  <cdr:relSizeAnchor xmlns:cdr="http://schemas.openxmlformats.org/drawingml/2006/chartDrawing">
+                    <cdr:from>
+                      <cdr:x>0.1</cdr:x>
+                      <cdr:y>0.25</cdr:y>
+                    </cdr:from>
+                    <cdr:to>
+                      <cdr:x>0.5</cdr:x>
+                      <cdr:y>0.75</cdr:y>
+                    </cdr:to>
+                    <cdr:sp macro="" textlink="">
+                      <cdr:nvSpPr>
+                        <cdr:cNvPr id="2" name="TextBox 1"/>
+                        <cdr:cNvSpPr txBox="1"/>
+                      </cdr:nvSpPr>
+                      <cdr:spPr>
+                        <a:xfrm>
+                          <a:off x="457200" y="685800"/>
+                          <a:ext cx="1828800" cy="1371600"/>
+                        </a:xfrm>
+                        <a:prstGeom prst="rect">
+                          <a:avLst/>
+                        </a:prstGeom>
+                        <a:solidFill>
+                          <a:schemeClr val="lt1"/>
+                        </a:solidFill>
+                        <a:ln w="9525" cmpd="sng">
+                          <a:solidFill>
+                            <a:schemeClr val="lt1">
+                              <a:shade val="50000"/>
+                            </a:schemeClr>
+                          </a:solidFill>
+                        </a:ln>
+                      </cdr:spPr>
+                      <cdr:style>
+                        <a:lnRef idx="0">
+                          <a:scrgbClr r="0" g="0" b="0"/>
+                        </a:lnRef>
+                        <a:fillRef idx="0">
+                          <a:scrgbClr r="0" g="0" b="0"/>
+                        </a:fillRef>
+                        <a:effectRef idx="0">
+                          <a:scrgbClr r="0" g="0" b="0"/>
+                        </a:effectRef>
+                        <a:fontRef idx="minor">
+                          <a:schemeClr val="dk1"/>
+                        </a:fontRef>
+                      </cdr:style>
+                      <cdr:txBody>
+                        <a:bodyPr wrap="square" rtlCol="0" anchor="t"/>
+                        <a:lstStyle/>
+                        <a:p>
+                          <a:r>
+                            <a:rPr lang="en-US" sz="1100"/>
+                            <a:t>Note</a:t>
+                          </a:r>
+                        </a:p>
+                      </cdr:txBody>
+                    </cdr:sp>
+                  </cdr:relSizeAnchor>
+                </c:userShapes>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
@@ -17,6 +17,9 @@ use polars::prelude::polars_err;
 #[cfg(feature = "polars")]
 use polars::prelude::PolarsError;
 
+#[cfg(feature = "arrow")]
+use arrow_schema::ArrowError;
+
 #[cfg(feature = "serde")]
 use serde::de;
 
@@ -82,10 +85,19 @@ pub enum XlsxError {
     /// Image has zero width or height, or the dimensions couldn't be read.
     ImageDimensionError,
 
+    /// A font file couldn't be read by
+    /// [`FontMetrics`](crate::FontMetrics) because it isn't a TrueType or
+    /// OpenType font, or because it doesn't contain the required font tables.
+    FontError(String),
+
     /// A general error that is raised when a chart parameter is incorrect, or a
     /// chart is configured incorrectly.
     ChartError(String),
 
+    /// Error raised when a method that applies to worksheet cells, rows or
+    /// columns is called on a chartsheet.
+    ChartsheetError(String),
+
     /// A general error that is raised when a sparkline parameter is incorrect,
     /// or a sparkline is configured incorrectly.
     SparklineError(String),
@@ -193,6 +205,20 @@ pub enum XlsxError {
     #[cfg(feature = "polars")]
     #[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
     PolarsError(PolarsError),
+
+    /// Wrapper for a variety of [arrow_schema::ArrowError] errors. This can
+    /// be useful for code that uses Arrow functions in an `XlsxError` error
+    /// scope. This requires the `arrow` feature to be enabled.
+    #[cfg(feature = "arrow")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+    ArrowError(ArrowError),
+
+    /// Error when rendering a worksheet to PDF, for example when the text
+    /// contains characters that aren't supported by the standard PDF fonts.
+    /// This requires the `render` feature to be enabled.
+    #[cfg(feature = "render")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "render")))]
+    RenderError(String),
 }
 
 impl Error for XlsxError {}
@@ -292,10 +318,18 @@ impl fmt::Display for XlsxError {
                 write!(f, "Image with or height couldn't be read from file.")
             }
 
+            XlsxError::FontError(error) => {
+                write!(f, "Font error: '{error}'.")
+            }
+
             XlsxError::ChartError(error) => {
                 write!(f, "Chart error: '{error}'.")
             }
 
+            XlsxError::ChartsheetError(error) => {
+                write!(f, "Chartsheet error: '{error}'.")
+            }
+
             XlsxError::SparklineError(error) => {
                 write!(f, "Sparkline error: '{error}'.")
             }
@@ -359,6 +393,16 @@ impl fmt::Display for XlsxError {
             XlsxError::PolarsError(error) => {
                 write!(f, "{error}")
             }
+
+            #[cfg(feature = "arrow")]
+            XlsxError::ArrowError(error) => {
+                write!(f, "{error}")
+            }
+
+            #[cfg(feature = "render")]
+            XlsxError::RenderError(error) => {
+                write!(f, "Render error: '{error}'.")
+            }
         }
     }
 }
@@ -393,6 +437,22 @@ impl From<XlsxError> for PolarsError {
     }
 }
 
+// Convert from Arrow errors to XlsxError to allow easier interoperability.
+#[cfg(feature = "arrow")]
+impl From<ArrowError> for XlsxError {
+    fn from(e: ArrowError) -> XlsxError {
+        XlsxError::ArrowError(e)
+    }
+}
+
+// Convert from XlsxError to Arrow errors to allow easier interoperability.
+#[cfg(feature = "arrow")]
+impl From<XlsxError> for ArrowError {
+    fn from(e: XlsxError) -> ArrowError {
+        ArrowError::ComputeError(format!("rust_xlsxwriter error: '{e}'"))
+    }
+}
+
 // Convert from XlsxError to JsValue errors to allow easier interoperability.
 #[cfg(all(
     feature = "wasm",
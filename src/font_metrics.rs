@@ -0,0 +1,816 @@
+// font_metrics - A module for calculating the display width of text in
+// worksheet cells.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+use crate::format::Font;
+use crate::{XlsxError, MAX_AUTOFIT_WIDTH_PIXELS};
+
+// The number of pixels per em for the default Excel font size of 11 points at
+// 96 DPI.
+const DEFAULT_EM_PIXELS: f64 = 11.0 * 96.0 / 72.0;
+
+// The vertical padding, in pixels, between the text and the cell edges.
+const CELL_PADDING_PIXELS: u16 = 2;
+
+#[derive(Clone, Debug)]
+/// The `FontMetrics` struct represents the character widths of a TrueType or
+/// OpenType font file.
+///
+/// The [`Worksheet::autofit()`](crate::Worksheet::autofit) method uses
+/// built-in character widths for the fonts that are most commonly used in
+/// Excel: Calibri, Arial, Times New Roman and Courier New, along with their
+/// metric compatible equivalents. Other fonts are measured using the Calibri
+/// widths scaled to the font size.
+///
+/// If your worksheets use other fonts, or if you need a more precise autofit
+/// for bold and italic text, you can load the metrics from the font file
+/// itself and add them to the worksheet with
+/// [`Worksheet::add_autofit_font()`](crate::Worksheet::add_autofit_font). The
+/// font name, and its bold and italic style, are read from the file and are
+/// matched against the font properties of the cell formats.
+///
+/// Only the character widths are read from the font file. The file isn't
+/// embedded in, or required by, the output xlsx file.
+///
+/// # Examples
+///
+/// The following example demonstrates autofitting a column that uses a font
+/// without built-in metrics.
+///
+/// ```ignore
+/// use rust_xlsxwriter::{FontMetrics, Format, Workbook, XlsxError};
+///
+/// fn main() -> Result<(), XlsxError> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.add_worksheet();
+///
+///     // Load the metrics of the font used in the worksheet.
+///     let font_metrics = FontMetrics::new("fonts/Verdana.ttf")?;
+///     worksheet.add_autofit_font(&font_metrics);
+///
+///     let format = Format::new().set_font_name("Verdana");
+///     worksheet.write_with_format(0, 0, "Hello Verdana", &format)?;
+///
+///     // Autofit the column using the Verdana character widths.
+///     worksheet.autofit();
+///
+///     workbook.save("fonts.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+pub struct FontMetrics {
+    pub(crate) font_name: String,
+    pub(crate) bold: bool,
+    pub(crate) italic: bool,
+    units_per_em: u16,
+    line_height: u16,
+    widths: HashMap<char, u16>,
+}
+
+impl FontMetrics {
+    /// Create a `FontMetrics` object from a TrueType or OpenType font file.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The path of the font file as a `&str` or as a [`std::path`]
+    ///   `Path` or `PathBuf` instance. The file can be a `.ttf` or `.otf` font
+    ///   or the first font in a `.ttc` font collection.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::IoError`] - I/O errors if the path doesn't exist or is
+    ///   restricted.
+    /// - [`XlsxError::FontError`] - The file isn't a font file or it doesn't
+    ///   contain the required font tables.
+    ///
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<FontMetrics, XlsxError> {
+        let file = File::open(path)?;
+        let mut reader = BufReader::new(file);
+        let mut data = vec![];
+        reader.read_to_end(&mut data)?;
+
+        Self::new_from_buffer(&data)
+    }
+
+    /// Create a `FontMetrics` object from a `u8` buffer.
+    ///
+    /// This method is similar to [`FontMetrics::new()`], see above, except the
+    /// font data can be in a buffer instead of a file path.
+    ///
+    /// # Parameters
+    ///
+    /// - `buffer`: The font data as a u8 slice.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::FontError`] - The buffer doesn't contain a font or the
+    ///   font doesn't contain the required font tables.
+    ///
+    pub fn new_from_buffer(buffer: &[u8]) -> Result<FontMetrics, XlsxError> {
+        let font_file = FontFile::new(buffer)?;
+
+        let head = font_file.table(b"head")?;
+        let units_per_em = read_u16(head, 18)?;
+        let mac_style = read_u16(head, 44)?;
+
+        if units_per_em == 0 {
+            return Err(font_error("font has a zero units per em value"));
+        }
+
+        let advances = font_file.advance_widths()?;
+        let glyphs = font_file.character_glyphs()?;
+        let line_height = font_file.line_height()?;
+
+        let widths = glyphs
+            .into_iter()
+            .filter_map(|(char, glyph)| {
+                let index = usize::from(glyph).min(advances.len() - 1);
+                (glyph != 0).then_some((char, advances[index]))
+            })
+            .collect();
+
+        Ok(FontMetrics {
+            font_name: font_file.family_name()?,
+            bold: mac_style & 0x01 != 0,
+            italic: mac_style & 0x02 != 0,
+            units_per_em,
+            line_height,
+            widths,
+        })
+    }
+
+    /// Set the font name used to match the metrics to cell formats.
+    ///
+    /// By default the font family name is read from the font file. This
+    /// method can be used to override it, for example to use the metrics of
+    /// a metric compatible font such as "Carlito" for a different font such
+    /// as "Calibri".
+    ///
+    /// # Parameters
+    ///
+    /// - `font_name`: The font name as used in
+    ///   [`Format::set_font_name()`](crate::Format::set_font_name).
+    ///
+    pub fn set_font_name(mut self, font_name: impl Into<String>) -> FontMetrics {
+        self.font_name = font_name.into();
+        self
+    }
+
+    /// Get the font name used to match the metrics to cell formats.
+    ///
+    /// This is the family name read from the font file, or the name set with
+    /// [`FontMetrics::set_font_name()`].
+    ///
+    pub fn font_name(&self) -> &str {
+        &self.font_name
+    }
+
+    /// Check if the font is a bold font.
+    ///
+    pub fn is_bold(&self) -> bool {
+        self.bold
+    }
+
+    /// Check if the font is an italic font.
+    ///
+    pub fn is_italic(&self) -> bool {
+        self.italic
+    }
+
+    // Get the width of a character as a fraction of an em. Returns `None` if
+    // the character isn't in the font.
+    fn char_width(&self, char: char) -> Option<f64> {
+        self.widths
+            .get(&char)
+            .map(|width| f64::from(*width) / f64::from(self.units_per_em))
+    }
+
+    // Get the height of a line of text as a fraction of an em.
+    fn line_height(&self) -> f64 {
+        f64::from(self.line_height) / f64::from(self.units_per_em)
+    }
+}
+
+// -----------------------------------------------------------------------
+// Cell text widths.
+// -----------------------------------------------------------------------
+
+// The font properties of a cell format, used to calculate the pixel width of
+// the cell text.
+#[derive(Clone, Debug)]
+pub(crate) struct CellFont<'a> {
+    builtin_font: BuiltinFont,
+    user_font: Option<&'a FontMetrics>,
+    bold: bool,
+    em_pixels: f64,
+}
+
+impl<'a> CellFont<'a> {
+    // Get the metrics for a cell font. User supplied metrics are matched by
+    // font name and style, or by font name only if there isn't a font with
+    // the same style.
+    pub(crate) fn new(font: &Font, user_fonts: &'a [FontMetrics]) -> CellFont<'a> {
+        let font_name = if font.name.is_empty() {
+            "Calibri"
+        } else {
+            font.name.as_str()
+        };
+
+        let size = match font.size.parse::<f64>() {
+            Ok(size) if size > 0.0 => size,
+            _ => 11.0,
+        };
+
+        let user_fonts: Vec<&FontMetrics> = user_fonts
+            .iter()
+            .filter(|user_font| user_font.font_name.eq_ignore_ascii_case(font_name))
+            .collect();
+
+        let user_font = user_fonts
+            .iter()
+            .find(|user_font| user_font.bold == font.bold && user_font.italic == font.italic)
+            .or_else(|| {
+                user_fonts
+                    .iter()
+                    .find(|user_font| user_font.bold == font.bold)
+            })
+            .or_else(|| user_fonts.first())
+            .copied();
+
+        CellFont {
+            builtin_font: BuiltinFont::new(font_name),
+            user_font,
+            bold: font.bold,
+            em_pixels: size * 96.0 / 72.0,
+        }
+    }
+
+    // Get the pixel width of a string in the cell font. Each character width
+    // is rounded to the nearest pixel in the same way as the Windows text
+    // rendering used by Excel.
+    pub(crate) fn text_width(&self, text: &str) -> u16 {
+        let max_width = f64::from(MAX_AUTOFIT_WIDTH_PIXELS);
+        let mut width = 0.0;
+
+        for char in text.chars() {
+            let em_width = self
+                .user_font
+                .and_then(|user_font| user_font.char_width(char))
+                .unwrap_or_else(|| self.builtin_font.char_width(char, self.bold));
+
+            width += (em_width * self.em_pixels).round();
+
+            // Limit the autofit width to Excel's limit of 1790 pixels.
+            if width >= max_width {
+                return MAX_AUTOFIT_WIDTH_PIXELS;
+            }
+        }
+
+        width as u16
+    }
+
+    // Get the pixel height of a line of text in the cell font.
+    pub(crate) fn line_height(&self) -> u16 {
+        let line_height = self
+            .user_font
+            .map_or_else(|| self.builtin_font.line_height(), FontMetrics::line_height);
+
+        (line_height * self.em_pixels).ceil() as u16
+    }
+
+    // Get the pixel height of the text in a cell, including the cell padding.
+    // Wrapped text is split at newlines and at the last space that fits in the
+    // wrap width. Rotated text uses the height of the rotated text box.
+    pub(crate) fn text_height(&self, text: &str, wrap_width: Option<u16>, rotation: i16) -> u16 {
+        let line_height = f64::from(self.line_height());
+
+        let lines: Vec<&str> = match wrap_width {
+            Some(_) => text.lines().collect(),
+            None => vec![text],
+        };
+
+        let num_lines: u32 = match wrap_width {
+            Some(wrap_width) if wrap_width > 0 => lines
+                .iter()
+                .map(|line| self.wrapped_line_count(line, wrap_width))
+                .sum(),
+            _ => lines.len() as u32,
+        };
+
+        let height = match rotation {
+            // Stacked text has one character per line.
+            255 => {
+                let num_chars = lines
+                    .iter()
+                    .map(|line| line.chars().count())
+                    .max()
+                    .unwrap_or(0);
+
+                num_chars as f64 * line_height
+            }
+            0 => f64::from(num_lines.max(1)) * line_height,
+            _ => {
+                // Angles from -1 to -90 are stored as 91 to 180.
+                let angle = if rotation > 90 {
+                    rotation - 90
+                } else {
+                    rotation
+                };
+                let angle = f64::from(angle).to_radians();
+
+                let text_width = lines
+                    .iter()
+                    .map(|line| self.text_width(line))
+                    .max()
+                    .unwrap_or(0);
+
+                f64::from(text_width) * angle.sin()
+                    + f64::from(num_lines.max(1)) * line_height * angle.cos()
+            }
+        };
+
+        (height.round() as u16).saturating_add(CELL_PADDING_PIXELS)
+    }
+
+    // Get the number of lines needed to display a line of text when it is
+    // wrapped at a pixel width. Words that are wider than the wrap width are
+    // split over several lines.
+    fn wrapped_line_count(&self, text: &str, wrap_width: u16) -> u32 {
+        let space_width = self.text_width(" ");
+        let mut num_lines = 1;
+        let mut line_width = 0;
+
+        for (index, word) in text.split(' ').enumerate() {
+            let word_width = self.text_width(word);
+
+            if index == 0 {
+                line_width = word_width;
+            } else if line_width + space_width + word_width <= wrap_width {
+                line_width += space_width + word_width;
+            } else {
+                num_lines += 1;
+                line_width = word_width;
+            }
+
+            while line_width > wrap_width {
+                num_lines += 1;
+                line_width -= wrap_width;
+            }
+        }
+
+        num_lines
+    }
+}
+
+impl Default for CellFont<'_> {
+    fn default() -> Self {
+        CellFont {
+            builtin_font: BuiltinFont::Calibri,
+            user_font: None,
+            bold: false,
+            em_pixels: DEFAULT_EM_PIXELS,
+        }
+    }
+}
+
+// The fonts with built-in character widths. Fonts are mapped to the metric
+// compatible built-in font, if there is one, or otherwise to Calibri.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BuiltinFont {
+    Calibri,
+    Arial,
+    TimesNewRoman,
+    CourierNew,
+}
+
+impl BuiltinFont {
+    fn new(font_name: &str) -> BuiltinFont {
+        let font_name = font_name.to_ascii_lowercase();
+
+        match font_name.as_str() {
+            "arial" | "helvetica" | "liberation sans" | "arimo" => BuiltinFont::Arial,
+            "times new roman" | "times" | "liberation serif" | "tinos" => {
+                BuiltinFont::TimesNewRoman
+            }
+            "courier new" | "courier" | "liberation mono" | "cousine" => BuiltinFont::CourierNew,
+            _ => BuiltinFont::Calibri,
+        }
+    }
+
+    // Get the height of a line of text as a fraction of an em. These are based
+    // on the ascent, descent and line gap of the fonts and match the Excel
+    // default row heights, such as 15 points for Calibri 11 and 12.75 points
+    // for Arial 10.
+    fn line_height(self) -> f64 {
+        match self {
+            BuiltinFont::Calibri => 2500.0 / 2048.0,
+            BuiltinFont::Arial => 2288.0 / 2048.0,
+            BuiltinFont::TimesNewRoman => 2355.0 / 2048.0,
+            BuiltinFont::CourierNew => 2320.0 / 2048.0,
+        }
+    }
+
+    // Get the width of a character as a fraction of an em. Italic fonts use
+    // the widths of the upright font. The Calibri Bold widths are estimated
+    // from the Calibri widths using the relative widths of Arial Bold and
+    // Arial.
+    fn char_width(self, char: char, bold: bool) -> f64 {
+        if is_wide_char(char) {
+            return 1.0;
+        }
+
+        let code = char as usize;
+        let index = code.wrapping_sub(32);
+        let is_ascii = (32..127).contains(&code);
+
+        match self {
+            BuiltinFont::Calibri => {
+                let width = f64::from(calibri_pixel_width(char)) / DEFAULT_EM_PIXELS;
+
+                if bold && is_ascii {
+                    width * f64::from(HELVETICA_BOLD_WIDTHS[index])
+                        / f64::from(HELVETICA_WIDTHS[index])
+                } else {
+                    width
+                }
+            }
+            BuiltinFont::Arial | BuiltinFont::TimesNewRoman => {
+                let (widths, default_width) = match (self, bold) {
+                    (BuiltinFont::Arial, false) => (&HELVETICA_WIDTHS, 556),
+                    (BuiltinFont::Arial, true) => (&HELVETICA_BOLD_WIDTHS, 556),
+                    (_, false) => (&TIMES_WIDTHS, 500),
+                    (_, true) => (&TIMES_BOLD_WIDTHS, 500),
+                };
+
+                if is_ascii {
+                    f64::from(widths[index]) / 1000.0
+                } else {
+                    f64::from(default_width) / 1000.0
+                }
+            }
+            BuiltinFont::CourierNew => 0.6,
+        }
+    }
+}
+
+// Check if a character is displayed at full width, such as CJK characters and
+// emoji.
+fn is_wide_char(char: char) -> bool {
+    matches!(char as u32,
+        0x1100..=0x115F
+        | 0x2E80..=0x303E
+        | 0x3041..=0x33FF
+        | 0x3400..=0x4DBF
+        | 0x4E00..=0x9FFF
+        | 0xA000..=0xA4CF
+        | 0xAC00..=0xD7A3
+        | 0xF900..=0xFAFF
+        | 0xFE30..=0xFE4F
+        | 0xFF00..=0xFF60
+        | 0xFFE0..=0xFFE6
+        | 0x1F300..=0x1F64F
+        | 0x1F900..=0x1F9FF
+        | 0x20000..=0x3FFFD)
+}
+
+// Get the pixel width of a character in the default Excel font of Calibri 11.
+// The widths are taken from Excel. Non-ascii characters are given a default
+// width of 8 pixels.
+#[allow(clippy::match_same_arms)]
+fn calibri_pixel_width(char: char) -> u16 {
+    match char {
+        ' ' | '\'' => 3,
+
+        ',' | '.' | ':' | ';' | 'I' | '`' | 'i' | 'j' | 'l' => 4,
+
+        '!' | '(' | ')' | '-' | 'J' | '[' | ']' | 'f' | 'r' | 't' | '{' | '}' => 5,
+
+        '"' | '/' | 'L' | '\\' | 'c' | 's' | 'z' => 6,
+
+        '#' | '$' | '*' | '+' | '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9' | '<'
+        | '=' | '>' | '?' | 'E' | 'F' | 'S' | 'T' | 'Y' | 'Z' | '^' | '_' | 'a' | 'g' | 'k'
+        | 'v' | 'x' | 'y' | '|' | '~' => 7,
+
+        'B' | 'C' | 'K' | 'P' | 'R' | 'X' | 'b' | 'd' | 'e' | 'h' | 'n' | 'o' | 'p' | 'q' | 'u' => {
+            8
+        }
+
+        'A' | 'D' | 'G' | 'H' | 'U' | 'V' => 9,
+
+        '&' | 'N' | 'O' | 'Q' => 10,
+
+        '%' | 'w' => 11,
+
+        'M' | 'm' => 12,
+
+        '@' | 'W' => 13,
+
+        _ => 8,
+    }
+}
+
+// Character widths, in 1/1000 em, of the ASCII characters from 32 to 126 in
+// Helvetica and Times, which are metric compatible with Arial and Times New
+// Roman. Taken from the Adobe font metrics files.
+#[rustfmt::skip]
+pub(crate) const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556,
+    1015, 667, 667, 722, 722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 278, 278, 278, 469, 556,
+    333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500, 222, 833, 556, 556,
+    556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+#[rustfmt::skip]
+pub(crate) const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278,
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611,
+    975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778,
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556,
+    333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611,
+    611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+
+#[rustfmt::skip]
+pub(crate) const TIMES_WIDTHS: [u16; 95] = [
+    250, 333, 408, 500, 500, 833, 778, 180, 333, 333, 500, 564, 250, 333, 250, 278,
+    500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 278, 278, 564, 564, 564, 444,
+    921, 722, 667, 667, 722, 611, 556, 722, 722, 333, 389, 722, 611, 889, 722, 722,
+    556, 722, 667, 556, 611, 722, 722, 944, 722, 722, 611, 333, 278, 333, 469, 500,
+    333, 444, 500, 444, 500, 444, 333, 500, 500, 278, 278, 500, 278, 778, 500, 500,
+    500, 500, 333, 389, 278, 500, 500, 722, 500, 500, 444, 480, 200, 480, 541,
+];
+
+#[rustfmt::skip]
+pub(crate) const TIMES_BOLD_WIDTHS: [u16; 95] = [
+    250, 333, 555, 500, 500, 1000, 833, 278, 333, 333, 500, 570, 250, 333, 250, 278,
+    500, 500, 500, 500, 500, 500, 500, 500, 500, 500, 333, 333, 570, 570, 570, 500,
+    930, 722, 667, 722, 722, 667, 611, 778, 778, 389, 500, 778, 667, 944, 722, 778,
+    611, 778, 722, 556, 667, 722, 722, 1000, 722, 722, 667, 333, 278, 333, 581, 500,
+    333, 500, 556, 444, 556, 444, 333, 500, 556, 278, 333, 556, 278, 833, 556, 500,
+    556, 556, 444, 389, 333, 556, 500, 722, 500, 500, 444, 394, 220, 394, 520,
+];
+
+// -----------------------------------------------------------------------
+// Font file parsing.
+// -----------------------------------------------------------------------
+
+// A TrueType or OpenType font file and its table directory. Only the tables
+// required for the character widths are read.
+struct FontFile<'a> {
+    tables: HashMap<[u8; 4], &'a [u8]>,
+}
+
+impl<'a> FontFile<'a> {
+    fn new(data: &'a [u8]) -> Result<FontFile<'a>, XlsxError> {
+        // Use the first font in a font collection.
+        let offset = if data.starts_with(b"ttcf") {
+            read_u32(data, 12)? as usize
+        } else {
+            0
+        };
+
+        let version = read_u32(data, offset)?;
+        if ![0x0001_0000, 0x7472_7565, 0x4F54_544F].contains(&version) {
+            return Err(font_error("unknown font file type"));
+        }
+
+        let num_tables = read_u16(data, offset + 4)?;
+        let mut tables = HashMap::new();
+
+        for i in 0..usize::from(num_tables) {
+            let record = offset + 12 + i * 16;
+            let tag = read_bytes(data, record, 4)?;
+            let table_offset = read_u32(data, record + 8)? as usize;
+            let table_length = read_u32(data, record + 12)? as usize;
+
+            let table = read_bytes(data, table_offset, table_length)?;
+            tables.insert([tag[0], tag[1], tag[2], tag[3]], table);
+        }
+
+        Ok(FontFile { tables })
+    }
+
+    fn table(&self, tag: &[u8; 4]) -> Result<&'a [u8], XlsxError> {
+        self.tables.get(tag).copied().ok_or_else(|| {
+            font_error(&format!(
+                "font doesn't contain a '{}' table",
+                String::from_utf8_lossy(tag)
+            ))
+        })
+    }
+
+    // Get the advance widths of the glyphs from the "hmtx" table. Glyphs
+    // after the last horizontal metric use the last advance width.
+    fn advance_widths(&self) -> Result<Vec<u16>, XlsxError> {
+        let hhea = self.table(b"hhea")?;
+        let hmtx = self.table(b"hmtx")?;
+        let num_metrics = read_u16(hhea, 34)?;
+
+        if num_metrics == 0 {
+            return Err(font_error("font doesn't contain any glyph metrics"));
+        }
+
+        (0..usize::from(num_metrics))
+            .map(|glyph| read_u16(hmtx, glyph * 4))
+            .collect()
+    }
+
+    // Get the height of a line of text, in font units, from the Windows ascent
+    // and descent in the "OS/2" table, or from the "hhea" table if the font
+    // doesn't have an "OS/2" table.
+    fn line_height(&self) -> Result<u16, XlsxError> {
+        if let Ok(os2) = self.table(b"OS/2") {
+            let ascent = read_u16(os2, 74)?;
+            let descent = read_u16(os2, 76)?;
+
+            return Ok(ascent.saturating_add(descent));
+        }
+
+        let hhea = self.table(b"hhea")?;
+        let ascent = read_u16(hhea, 4)? as i16;
+        let descent = read_u16(hhea, 6)? as i16;
+        let line_gap = read_u16(hhea, 8)? as i16;
+
+        Ok((i32::from(ascent) - i32::from(descent) + i32::from(line_gap)).clamp(0, 0xFFFF) as u16)
+    }
+
+    // Get the glyph ids of the characters in the font from the Unicode
+    // "cmap" subtable.
+    fn character_glyphs(&self) -> Result<HashMap<char, u16>, XlsxError> {
+        let cmap = self.table(b"cmap")?;
+        let num_subtables = read_u16(cmap, 2)?;
+
+        let mut unicode_offset = None;
+        let mut full_unicode_offset = None;
+
+        for i in 0..usize::from(num_subtables) {
+            let record = 4 + i * 8;
+            let platform_id = read_u16(cmap, record)?;
+            let encoding_id = read_u16(cmap, record + 2)?;
+            let offset = read_u32(cmap, record + 4)? as usize;
+            let format = read_u16(cmap, offset)?;
+
+            match (platform_id, encoding_id, format) {
+                (0 | 3, _, 12) => full_unicode_offset = Some(offset),
+                (0, _, 4) | (3, 1, 4) => unicode_offset = Some(offset),
+                _ => {}
+            }
+        }
+
+        match (full_unicode_offset, unicode_offset) {
+            (Some(offset), _) => Self::cmap_format12(cmap, offset),
+            (None, Some(offset)) => Self::cmap_format4(cmap, offset),
+            (None, None) => Err(font_error("font doesn't contain a Unicode character map")),
+        }
+    }
+
+    // Read a segment mapping to delta values subtable.
+    fn cmap_format4(cmap: &[u8], offset: usize) -> Result<HashMap<char, u16>, XlsxError> {
+        let seg_count = usize::from(read_u16(cmap, offset + 6)? / 2);
+        let end_codes = offset + 14;
+        let start_codes = end_codes + seg_count * 2 + 2;
+        let id_deltas = start_codes + seg_count * 2;
+        let id_range_offsets = id_deltas + seg_count * 2;
+
+        let mut glyphs = HashMap::new();
+
+        for segment in 0..seg_count {
+            let end_code = read_u16(cmap, end_codes + segment * 2)?;
+            let start_code = read_u16(cmap, start_codes + segment * 2)?;
+            let id_delta = read_u16(cmap, id_deltas + segment * 2)?;
+            let range_offset_position = id_range_offsets + segment * 2;
+            let id_range_offset = usize::from(read_u16(cmap, range_offset_position)?);
+
+            if start_code == 0xFFFF || start_code > end_code {
+                continue;
+            }
+
+            for code in start_code..=end_code {
+                let glyph = if id_range_offset == 0 {
+                    code.wrapping_add(id_delta)
+                } else {
+                    let position = range_offset_position
+                        + id_range_offset
+                        + usize::from(code - start_code) * 2;
+
+                    match read_u16(cmap, position)? {
+                        0 => 0,
+                        glyph => glyph.wrapping_add(id_delta),
+                    }
+                };
+
+                if let Some(char) = char::from_u32(u32::from(code)) {
+                    glyphs.insert(char, glyph);
+                }
+            }
+        }
+
+        Ok(glyphs)
+    }
+
+    // Read a segmented coverage subtable.
+    fn cmap_format12(cmap: &[u8], offset: usize) -> Result<HashMap<char, u16>, XlsxError> {
+        let num_groups = read_u32(cmap, offset + 12)? as usize;
+        let mut glyphs = HashMap::new();
+
+        for group in 0..num_groups {
+            let record = offset + 16 + group * 12;
+            let start_code = read_u32(cmap, record)?;
+            let end_code = read_u32(cmap, record + 4)?.min(0x10FFFF);
+            let start_glyph = read_u32(cmap, record + 8)?;
+
+            for code in start_code..=end_code {
+                let glyph = start_glyph
+                    .checked_add(code - start_code)
+                    .ok_or_else(|| font_error("font character map glyph id is out of range"))?;
+
+                if let (Some(char), Ok(glyph)) = (char::from_u32(code), u16::try_from(glyph)) {
+                    glyphs.insert(char, glyph);
+                }
+            }
+        }
+
+        Ok(glyphs)
+    }
+
+    // Get the font family name from the "name" table. Windows Unicode names
+    // are preferred over Macintosh names.
+    fn family_name(&self) -> Result<String, XlsxError> {
+        let name = self.table(b"name")?;
+        let count = read_u16(name, 2)?;
+        let storage = usize::from(read_u16(name, 4)?);
+
+        let mut family_name = None;
+
+        for i in 0..usize::from(count) {
+            let record = 6 + i * 12;
+            let platform_id = read_u16(name, record)?;
+            let name_id = read_u16(name, record + 6)?;
+            let length = usize::from(read_u16(name, record + 8)?);
+            let offset = usize::from(read_u16(name, record + 10)?);
+
+            if name_id != 1 {
+                continue;
+            }
+
+            let bytes = read_bytes(name, storage + offset, length)?;
+
+            match platform_id {
+                0 | 3 => {
+                    let units: Vec<u16> = bytes
+                        .chunks_exact(2)
+                        .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                        .collect();
+
+                    family_name = Some(String::from_utf16_lossy(&units));
+                    break;
+                }
+                1 if family_name.is_none() => {
+                    family_name = Some(bytes.iter().map(|byte| char::from(*byte)).collect());
+                }
+                _ => {}
+            }
+        }
+
+        family_name.ok_or_else(|| font_error("font doesn't contain a family name"))
+    }
+}
+
+// Read a slice of bytes from a font file buffer, with bounds checking.
+fn read_bytes(data: &[u8], offset: usize, length: usize) -> Result<&[u8], XlsxError> {
+    offset
+        .checked_add(length)
+        .and_then(|end| data.get(offset..end))
+        .ok_or_else(|| font_error("font data is truncated or corrupt"))
+}
+
+// Read a big-endian u16 from a font file buffer.
+fn read_u16(data: &[u8], offset: usize) -> Result<u16, XlsxError> {
+    let bytes = read_bytes(data, offset, 2)?;
+    Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
+// Read a big-endian u32 from a font file buffer.
+fn read_u32(data: &[u8], offset: usize) -> Result<u32, XlsxError> {
+    let bytes = read_bytes(data, offset, 4)?;
+    Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+fn font_error(message: &str) -> XlsxError {
+    XlsxError::FontError(message.to_string())
+}
@@ -0,0 +1,297 @@
+// Font metrics unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod font_metrics_tests {
+
+    use crate::font_metrics::{CellFont, FontFile, FontMetrics};
+    use crate::{Format, XlsxError};
+
+    // Create a minimal TrueType font with the required tables and the
+    // advance widths of some characters, in 1/1000 em.
+    fn create_font(family_name: &str, mac_style: u16, char_widths: &[(char, u16)]) -> Vec<u8> {
+        let mut head = vec![0; 54];
+        head[18..20].copy_from_slice(&1000_u16.to_be_bytes());
+        head[44..46].copy_from_slice(&mac_style.to_be_bytes());
+
+        // Glyph 0 is the missing character glyph.
+        let num_glyphs = char_widths.len() as u16 + 1;
+        let mut hhea = vec![0; 36];
+        hhea[4..6].copy_from_slice(&800_i16.to_be_bytes());
+        hhea[6..8].copy_from_slice(&(-200_i16).to_be_bytes());
+        hhea[34..36].copy_from_slice(&num_glyphs.to_be_bytes());
+
+        let mut hmtx = vec![0; 4];
+        for (_, width) in char_widths {
+            hmtx.extend_from_slice(&width.to_be_bytes());
+            hmtx.extend_from_slice(&[0, 0]);
+        }
+
+        // A format 4 cmap with a segment for each character and the required
+        // final segment.
+        let mut segments: Vec<(u16, u16)> = char_widths
+            .iter()
+            .enumerate()
+            .map(|(index, (char, _))| (*char as u16, index as u16 + 1))
+            .collect();
+        segments.push((0xFFFF, 0));
+
+        let seg_count = segments.len() as u16;
+        let mut cmap = vec![];
+        for value in [0, 1, 3, 1] {
+            cmap.extend_from_slice(&u16::to_be_bytes(value));
+        }
+        cmap.extend_from_slice(&12_u32.to_be_bytes());
+        for value in [4, 16 + 8 * seg_count, 0, seg_count * 2, 0, 0, 0] {
+            cmap.extend_from_slice(&u16::to_be_bytes(value));
+        }
+        for (code, _) in &segments {
+            cmap.extend_from_slice(&code.to_be_bytes());
+        }
+        cmap.extend_from_slice(&[0, 0]);
+        for (code, _) in &segments {
+            cmap.extend_from_slice(&code.to_be_bytes());
+        }
+        for (code, glyph) in &segments {
+            cmap.extend_from_slice(&glyph.wrapping_sub(*code).to_be_bytes());
+        }
+        for _ in &segments {
+            cmap.extend_from_slice(&[0, 0]);
+        }
+
+        let family_name: Vec<u8> = family_name
+            .encode_utf16()
+            .flat_map(u16::to_be_bytes)
+            .collect();
+        let mut name = vec![];
+        for value in [0, 1, 18, 3, 1, 0x409, 1, family_name.len() as u16, 0] {
+            name.extend_from_slice(&u16::to_be_bytes(value));
+        }
+        name.extend_from_slice(&family_name);
+
+        let tables = [
+            (b"cmap", cmap),
+            (b"head", head),
+            (b"hhea", hhea),
+            (b"hmtx", hmtx),
+            (b"name", name),
+        ];
+
+        let mut font = vec![0, 1, 0, 0, 0, tables.len() as u8, 0, 0, 0, 0, 0, 0];
+        let mut offset = 12 + tables.len() * 16;
+        for (tag, table) in &tables {
+            font.extend_from_slice(*tag);
+            font.extend_from_slice(&[0; 4]);
+            font.extend_from_slice(&(offset as u32).to_be_bytes());
+            font.extend_from_slice(&(table.len() as u32).to_be_bytes());
+            offset += table.len();
+        }
+        for (_, table) in &tables {
+            font.extend_from_slice(table);
+        }
+
+        font
+    }
+
+    fn text_width(text: &str, format: &Format, fonts: &[FontMetrics]) -> u16 {
+        CellFont::new(&format.font, fonts).text_width(text)
+    }
+
+    #[test]
+    fn test_builtin_font_widths() {
+        let default = Format::default();
+        let bold = Format::new().set_bold();
+        let italic = Format::new().set_italic();
+        let large = Format::new().set_font_size(22);
+        let arial = Format::new().set_font_name("Arial").set_font_size(10);
+        let arial_bold = Format::new()
+            .set_font_name("Arial")
+            .set_font_size(10)
+            .set_bold();
+        let times = Format::new().set_font_name("Times New Roman");
+        let courier = Format::new().set_font_name("Courier New");
+        let unknown = Format::new().set_font_name("Unknown").set_font_size(22);
+
+        let test_data = [
+            ("Hello", &default, 33),
+            ("12345", &default, 35),
+            ("Hello", &bold, 36),
+            ("12345", &bold, 35),
+            ("Hello", &italic, 33),
+            ("Hello", &large, 66),
+            ("Hello", &arial, 30),
+            ("12345", &arial, 35),
+            ("Hello", &arial_bold, 33),
+            ("Hello", &times, 33),
+            ("Hello", &courier, 45),
+            ("Hello", &unknown, 66),
+            ("", &default, 0),
+        ];
+
+        for (text, format, expected) in test_data {
+            assert_eq!(expected, text_width(text, format, &[]), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_wide_char_widths() {
+        let default = Format::default();
+        let arial = Format::new().set_font_name("Arial").set_font_size(10);
+
+        assert_eq!(30, text_width("日本", &default, &[]));
+        assert_eq!(45, text_width("한국어", &default, &[]));
+        assert_eq!(15, text_width("Ａ", &default, &[]));
+        assert_eq!(15, text_width("😀", &default, &[]));
+        assert_eq!(26, text_width("日本", &arial, &[]));
+
+        // Non-wide, non-ascii characters.
+        assert_eq!(16, text_width("éé", &default, &[]));
+    }
+
+    #[test]
+    fn test_max_width() {
+        let default = Format::default();
+        let string = "W".repeat(200);
+
+        assert_eq!(1790, text_width(&string, &default, &[]));
+    }
+
+    #[test]
+    fn test_line_heights() {
+        let test_data = [
+            (Format::default(), 18),
+            (Format::new().set_font_size(22), 36),
+            (Format::new().set_font_name("Arial").set_font_size(10), 15),
+            (
+                Format::new()
+                    .set_font_name("Times New Roman")
+                    .set_font_size(12),
+                19,
+            ),
+        ];
+
+        for (format, expected) in test_data {
+            assert_eq!(expected, CellFont::new(&format.font, &[]).line_height());
+        }
+    }
+
+    #[test]
+    fn test_text_heights() {
+        let font = CellFont::default();
+
+        let test_data = [
+            ("Hello", None, 0, 20),
+            ("", None, 0, 20),
+            ("Line 1\nLine 2", None, 0, 20),
+            ("Line 1\nLine 2", Some(100), 0, 38),
+            ("Line 1\nLine 2\nLine 3", Some(100), 0, 56),
+            ("aaaa aaaa", Some(59), 0, 20),
+            ("aaaa aaaa", Some(58), 0, 38),
+            ("aaaa aaaa aaaa", Some(57), 0, 56),
+            ("aaaaaaaaaa", Some(57), 0, 38),
+            ("", Some(57), 0, 20),
+            ("Hello", None, 90, 35),
+            ("Hello", None, 180, 35),
+            ("Hello", None, 45, 38),
+            ("Hello", None, 255, 92),
+        ];
+
+        for (text, wrap_width, rotation, expected) in test_data {
+            assert_eq!(
+                expected,
+                font.text_height(text, wrap_width, rotation),
+                "{text} {wrap_width:?} {rotation}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_font_file() {
+        let regular = create_font("Test Sans", 0, &[('A', 500), ('B', 1000)]);
+        let bold = create_font("Test Sans", 1, &[('A', 750), ('B', 1500)]);
+
+        let regular = FontMetrics::new_from_buffer(&regular).unwrap();
+        let bold = FontMetrics::new_from_buffer(&bold).unwrap();
+
+        assert_eq!("Test Sans", regular.font_name());
+        assert!(!regular.is_bold());
+        assert!(!regular.is_italic());
+        assert!(bold.is_bold());
+        assert!(!bold.is_italic());
+
+        let fonts = [regular, bold];
+        let format = Format::new().set_font_name("Test Sans").set_font_size(15);
+
+        // 20 pixels per em for 15 points.
+        assert_eq!(10, text_width("A", &format, &fonts));
+        assert_eq!(30, text_width("AB", &format, &fonts));
+        assert_eq!(45, text_width("AB", &format.clone().set_bold(), &fonts));
+
+        // The line height is the ascent and descent of 1 em.
+        assert_eq!(20, CellFont::new(&format.font, &fonts).line_height());
+
+        // The font name is matched without case.
+        let format = Format::new().set_font_name("TEST SANS").set_font_size(15);
+        assert_eq!(30, text_width("AB", &format, &fonts));
+
+        // Italic formats use the upright font if there isn't an italic font.
+        let format = format.set_italic();
+        assert_eq!(30, text_width("AB", &format, &fonts));
+
+        // Characters that aren't in the font use the built-in widths.
+        let format = Format::new().set_font_name("Test Sans");
+        assert_eq!(7 + 8, text_width("AC", &format, &fonts));
+
+        // Other fonts don't use the font file.
+        let format = Format::new().set_font_name("Calibri");
+        assert_eq!(17, text_width("AB", &format, &fonts));
+    }
+
+    #[test]
+    fn test_font_file_name() {
+        let font = create_font("Carlito", 0, &[('A', 500)]);
+        let font = FontMetrics::new_from_buffer(&font)
+            .unwrap()
+            .set_font_name("Calibri");
+
+        assert_eq!("Calibri", font.font_name());
+
+        let fonts = [font];
+        assert_eq!(7, text_width("A", &Format::default(), &fonts));
+    }
+
+    #[test]
+    fn test_invalid_font_file() {
+        let font = create_font("Test Sans", 0, &[('A', 500)]);
+
+        let result = FontMetrics::new_from_buffer(b"Not a font file");
+        assert!(matches!(result, Err(XlsxError::FontError(_))));
+
+        let result = FontMetrics::new_from_buffer(&font[..100]);
+        assert!(matches!(result, Err(XlsxError::FontError(_))));
+
+        let result = FontMetrics::new_from_buffer(&[]);
+        assert!(matches!(result, Err(XlsxError::FontError(_))));
+
+        let result = FontMetrics::new("non_existent_font.ttf");
+        assert!(matches!(result, Err(XlsxError::IoError(_))));
+    }
+
+    #[test]
+    fn test_invalid_cmap_format12() {
+        // A format 12 cmap group with a start glyph id that overflows for the
+        // second character in the group.
+        let mut cmap = vec![];
+        cmap.extend_from_slice(&12_u16.to_be_bytes());
+        cmap.extend_from_slice(&[0; 10]);
+        for value in [1, 0x41, 0x42, u32::MAX] {
+            cmap.extend_from_slice(&u32::to_be_bytes(value));
+        }
+
+        let result = FontFile::cmap_format12(&cmap, 0);
+        assert!(matches!(result, Err(XlsxError::FontError(_))));
+    }
+}
@@ -561,6 +561,7 @@ pub struct Format {
     pub(crate) checkbox: bool,
     pub(crate) quote_prefix: bool,
     pub(crate) is_dxf_format: bool,
+    pub(crate) cell_style: String,
 }
 
 impl Hash for Format {
@@ -576,6 +577,7 @@ impl Hash for Format {
         self.locked.hash(state);
         self.checkbox.hash(state);
         self.quote_prefix.hash(state);
+        self.cell_style.hash(state);
     }
 }
 
@@ -591,6 +593,7 @@ impl PartialEq for Format {
             && self.locked == other.locked
             && self.checkbox == other.checkbox
             && self.quote_prefix == other.quote_prefix
+            && self.cell_style == other.cell_style
     }
 }
 
@@ -644,6 +647,7 @@ impl Format {
             hidden: false,
             checkbox: false,
             quote_prefix: false,
+            cell_style: String::new(),
         }
     }
 
@@ -661,6 +665,10 @@ impl Format {
     /// precedence can be reversed by reversing the order of the primary and
     /// secondary formats, see the second example below.
     ///
+    /// If the primary format isn't based on a named cell style then it will
+    /// also take the cell style of the secondary format. See
+    /// [`Workbook::add_cell_style()`](crate::Workbook::add_cell_style).
+    ///
     /// # Parameters
     ///
     /// - `other`: A Format object to merge with the primary Format.
@@ -768,7 +776,8 @@ impl Format {
     /// format.
     ///
     /// See also [Number Format Categories] and [Number Formats in Different
-    /// Locales].
+    /// Locales]. The [`NumberFormat`](crate::NumberFormat) struct can be used
+    /// to get the text that Excel displays for a number with a number format.
     ///
     /// [Number Format Categories]: crate::Format#number-format-categories
     /// [Number Formats in Different Locales]:
@@ -2528,6 +2537,10 @@ impl Format {
         self.quote_prefix |= other.quote_prefix;
         self.locked &= other.locked;
 
+        if self.cell_style.is_empty() {
+            self.cell_style.clone_from(&other.cell_style);
+        }
+
         if self.num_format.is_empty() {
             self.num_format.clone_from(&other.num_format);
         }
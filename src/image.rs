@@ -15,6 +15,9 @@ use std::io::BufReader;
 use std::io::Read;
 use std::path::Path;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::drawing::{DrawingObject, DrawingType};
 use crate::vml::VmlInfo;
 use crate::{Url, XlsxError};
@@ -1128,6 +1131,7 @@ impl DrawingObject for Image {
 /// Used with [`Image::set_object_movement()`].
 ///
 #[derive(Clone, Debug, PartialEq, Eq, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ObjectMovement {
     /// Move and size the worksheet object with the cells. Default for charts.
     MoveAndSizeWithCells,
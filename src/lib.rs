@@ -31,7 +31,7 @@
 //! - Conditional formatting.
 //! - Data validation.
 //! - Cell Notes.
-//! - Textboxes.
+//! - Textboxes, preset shapes and connectors.
 //! - Checkboxes.
 //! - Sparklines.
 //! - Worksheet PNG/JPEG/GIF/BMP images.
@@ -83,7 +83,8 @@
 //!   conditional formatting in worksheets.
 //! - [`DataValidation`]: Working with data validation in worksheets.
 //! - [`Note`]: Adding Notes to worksheet cells.
-//! - [`Shape`]: Adding Textbox shapes to worksheets.
+//! - [`Shape`]: Adding Textbox and other shapes to worksheets.
+//! - [`DrawingGroup`]: Grouping images and shapes in worksheets.
 //! - [`Macros`](crate::macros): Working with Macros.
 //! - [`Sparklines`](crate::sparkline): Working with Sparklines.
 //! - [`ExcelDateTime`]: A type to represent dates and times in Excel format.
@@ -183,18 +184,31 @@
 //! - `constant_memory`: Keeps memory usage to a minimum when writing
 //!   large files. See [Constant Memory
 //!   Mode](performance/index.html#constant-memory-mode).
-//! - `serde`: Adds support for Serde serialization. This is off by default.
+//! - `serde`: Adds support for Serde serialization, and for saving and loading
+//!   [`ChartTemplate`] chart styles. This is off by default.
 //! - `chrono`: Adds support for Chrono date/time types to the API. This is off
 //!   by default.
+//! - `time`: Adds support for `time` crate date/time types to the API. This
+//!   is off by default.
+//! - `jiff`: Adds support for Jiff date/time types to the API. This is off by
+//!   default.
 //! - `zlib`: Adds a dependency on zlib and a C compiler. This includes the same
 //!   features as `default` but is 1.5x faster for large files.
-//! - `polars`: Adds support for mapping between `PolarsError` and
-//!   `rust_xlsxwriter::XlsxError` to make code that handles both types of errors
-//!   easier to write. See also
+//! - `polars`: Adds support for writing Polars dataframes with
+//!   [`Worksheet::write_dataframe()`] and for mapping between `PolarsError`
+//!   and `rust_xlsxwriter::XlsxError` to make code that handles both types of
+//!   errors easier to write. See also
 //!   [`polars_excel_writer`](https://crates.io/crates/polars_excel_writer).
+//! - `arrow`: Adds support for writing Apache Arrow `RecordBatch` data with
+//!   [`Worksheet::write_record_batch()`] and
+//!   [`Worksheet::write_record_batches()`] and for mapping between
+//!   `ArrowError` and `rust_xlsxwriter::XlsxError`.
 //! - `wasm`: Adds a dependency on `js-sys` and `wasm-bindgen` to allow
 //!   compilation for wasm/JavaScript targets. See also
 //!   [wasm-xlsxwriter](https://github.com/estie-inc/wasm-xlsxwriter).
+//! - `wasm-bindings`: Exports a JavaScript API, via `wasm-bindgen`, for
+//!   creating files client-side. See the [`wasm`] module. This includes the
+//!   `wasm` feature.
 //! - `rust_decimal`: Adds support for writing the
 //!   [`rust_decimal`](https://crates.io/crates/rust_decimal) `Decimal` type
 //!   with `Worksheet::write()`, provided it can be represented by [`f64`].
@@ -202,6 +216,10 @@
 //!   worksheet cells for large data files. It gives a performance boost above
 //!   300,000 numeric cells and can be up to 30% faster than the default number
 //!   formatting for 5,000,000 numeric cells.
+//! - `render`: Adds support for rendering the printed pages of worksheets to
+//!   PDF documents, for example to create previews, with
+//!   [`Worksheet::save_to_pdf()`] and [`Workbook::save_to_pdf()`]. Only PDF
+//!   output is supported. This is off by default.
 //!
 mod app;
 mod button;
@@ -209,6 +227,7 @@ mod color;
 mod comment;
 mod content_types;
 mod core;
+mod csv_import;
 mod custom;
 mod data_validation;
 mod datetime;
@@ -216,11 +235,13 @@ mod drawing;
 mod error;
 mod feature_property_bag;
 mod filter;
+mod font_metrics;
 mod format;
 mod formula;
 mod image;
 mod metadata;
 mod note;
+mod number_format;
 mod packager;
 mod properties;
 mod protection;
@@ -239,6 +260,22 @@ mod url;
 mod vml;
 mod xmlwriter;
 
+#[cfg(any(feature = "polars", feature = "arrow"))]
+mod data_options;
+
+#[cfg(feature = "polars")]
+mod dataframe;
+
+#[cfg(feature = "arrow")]
+mod record_batch;
+
+#[cfg(feature = "render")]
+mod render;
+
+#[cfg(feature = "wasm-bindings")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasm-bindings")))]
+pub mod wasm;
+
 #[cfg(feature = "serde")]
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 pub mod serializer;
@@ -261,14 +298,18 @@ mod test_functions;
 // Re-export the public APIs.
 pub use button::*;
 pub use color::*;
+pub use csv_import::*;
 pub use data_validation::*;
 pub use datetime::*;
+pub use drawing::DrawingGroup;
 pub use error::*;
 pub use filter::*;
+pub use font_metrics::FontMetrics;
 pub use format::*;
 pub use formula::*;
 pub use image::*;
 pub use note::*;
+pub use number_format::NumberFormat;
 pub use properties::*;
 pub use protection::*;
 pub use shape::*;
@@ -296,6 +337,12 @@ pub use workbook::*;
 #[doc(hidden)]
 pub use utility::*;
 
+#[cfg(feature = "polars")]
+pub use dataframe::*;
+
+#[cfg(feature = "arrow")]
+pub use record_batch::*;
+
 #[cfg(feature = "serde")]
 #[doc(hidden)]
 pub use serializer::*;
@@ -0,0 +1,1644 @@
+// number_format - A module for formatting numbers with Excel number formats.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use crate::{Color, Format, IntoExcelDateTime};
+
+// -----------------------------------------------------------------------
+// NumberFormat
+// -----------------------------------------------------------------------
+
+/// The `NumberFormat` struct is used to display numbers and dates in the same
+/// way as Excel.
+///
+/// Excel stores numbers, dates and times as `f64` values and displays them
+/// using the number format of the cell, see [`Format::set_num_format()`]. The
+/// `NumberFormat` struct applies an Excel number format string to a number,
+/// date or string and returns the text that Excel would display in the cell.
+/// This can be used to estimate column widths, to create previews of worksheet
+/// data, or to create the result strings of formulas.
+///
+/// The following parts of the Excel number format syntax are supported:
+///
+/// - Digit placeholders, decimal points, thousands separators and scaling
+///   commas such as `0`, `#,##0.00`, `0.0??` and `0.0,,`.
+/// - Percentages and scientific notation such as `0.00%`, `0.00E+00` and
+///   `##0.0E+0`.
+/// - Fractions such as `# ?/?`, `# ??/??` and `?/16`.
+/// - Dates and times such as `yyyy-mm-dd`, `dddd, mmmm d` and `h:mm:ss.00
+///   AM/PM`.
+/// - Elapsed times such as `[h]:mm:ss` and `[mm]:ss`.
+/// - Up to four sections for positive numbers, negative numbers, zero and
+///   text, such as `#,##0;[Red](#,##0);"-";"Note: "@`.
+/// - Conditions such as `[>=1000000]0.0,,"M";[>=1000]0.0,"K";0`.
+/// - Colors such as `[Red]` or `[Blue]`, see [`NumberFormat::font_color()`].
+/// - Quoted and escaped literal strings, and currency and locale tags such as
+///   `[$€-407]` or `[$-409]`.
+///
+/// Numbers and dates are displayed in the US English locale. Properties that
+/// depend on the column width, such as the `*` repeat character, or on the
+/// system locale, such as `[DBNum1]`, are ignored.
+///
+/// # Examples
+///
+/// The following example demonstrates displaying numbers and dates using Excel
+/// number formats.
+///
+/// ```
+/// # // This code is available in examples/doc_number_format_intro.rs
+/// #
+/// # use rust_xlsxwriter::{Color, ExcelDateTime, Format, NumberFormat, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+///     // Display numbers with a currency format.
+///     let number_format = NumberFormat::new("$#,##0.00;[Red]($#,##0.00)");
+///
+///     assert_eq!(number_format.format_number(1234.5), "$1,234.50");
+///     assert_eq!(number_format.format_number(-1234.5), "($1,234.50)");
+///     assert_eq!(number_format.font_color(-1234.5), Some(Color::Red));
+///
+///     // Display a date and time.
+///     let number_format = NumberFormat::new("yyyy-mm-dd hh:mm");
+///     let datetime = ExcelDateTime::from_ymd(2025, 1, 15)?.and_hms(14, 30, 0)?;
+///
+///     assert_eq!(number_format.format_datetime(&datetime), "2025-01-15 14:30");
+///
+///     // Display a number using the number format of a `Format`.
+///     let format = Format::new().set_num_format("0.0%");
+///     let number_format = NumberFormat::from_format(&format);
+///
+///     assert_eq!(number_format.format_number(0.256), "25.6%");
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct NumberFormat {
+    sections: Vec<Section>,
+    text_section: Option<Section>,
+}
+
+impl NumberFormat {
+    /// Create a new `NumberFormat` from an Excel number format string.
+    ///
+    /// # Parameters
+    ///
+    /// - `num_format`: An Excel number format string such as `"#,##0.00"` or
+    ///   `"yyyy-mm-dd"`. An empty string is the same as the Excel "General"
+    ///   format. See [`Format::set_num_format()`] for more information on
+    ///   number formats.
+    ///
+    pub fn new(num_format: &str) -> NumberFormat {
+        let mut sections: Vec<Section> = split_sections(num_format)
+            .iter()
+            .map(|section| Section::new(section))
+            .collect();
+
+        // The text section is the fourth section, or an earlier section that
+        // contains the "@" text placeholder.
+        let text_section = if sections.len() > 3 {
+            Some(sections.remove(3))
+        } else {
+            sections
+                .iter()
+                .position(|section| section.kind == SectionKind::Text)
+                .map(|index| sections.remove(index))
+        };
+
+        sections.truncate(3);
+
+        // Numbers are displayed in the "General" format if the number format
+        // only has a text section.
+        if sections.is_empty() {
+            sections.push(Section::new("General"));
+        }
+
+        NumberFormat {
+            sections,
+            text_section,
+        }
+    }
+
+    /// Create a new `NumberFormat` from the number format of a [`Format`].
+    ///
+    /// This uses the Excel built-in number format set with
+    /// [`Format::set_num_format_index()`], if there is one, or the number
+    /// format string set with [`Format::set_num_format()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_xlsxwriter::{Format, NumberFormat};
+    ///
+    /// let format = Format::new().set_num_format_index(3);
+    /// let number_format = NumberFormat::from_format(&format);
+    ///
+    /// assert_eq!(number_format.format_number(1234567), "1,234,567");
+    /// ```
+    ///
+    pub fn from_format(format: &Format) -> NumberFormat {
+        // User defined number formats in Excel start from index 164.
+        if (1..164).contains(&format.num_format_index) {
+            NumberFormat::new(builtin_num_format(format.num_format_index))
+        } else {
+            NumberFormat::new(&format.num_format)
+        }
+    }
+
+    /// Format a number as it would be displayed in Excel.
+    ///
+    /// Negative numbers displayed with a date or time format, and non-finite
+    /// numbers, are displayed as `########` and `#NUM!` in the same way as
+    /// Excel.
+    ///
+    /// # Parameters
+    ///
+    /// - `number`: The number to format. It can be any type that converts to
+    ///   `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_xlsxwriter::NumberFormat;
+    ///
+    /// let number_format = NumberFormat::new("#,##0.00");
+    /// assert_eq!(number_format.format_number(1234.567), "1,234.57");
+    ///
+    /// let number_format = NumberFormat::new("# ?/?");
+    /// assert_eq!(number_format.format_number(1.5), "1 1/2");
+    ///
+    /// let number_format = NumberFormat::new("[h]:mm");
+    /// assert_eq!(number_format.format_number(1.5), "36:00");
+    ///
+    /// let number_format = NumberFormat::new(r#"[>=1000]0.0,"K";0"#);
+    /// assert_eq!(number_format.format_number(12345), "12.3K");
+    /// assert_eq!(number_format.format_number(123), "123");
+    /// ```
+    ///
+    pub fn format_number(&self, number: impl Into<f64>) -> String {
+        self.format_number_with_color(number.into()).0
+    }
+
+    /// Format a date or time as it would be displayed in Excel.
+    ///
+    /// # Parameters
+    ///
+    /// - `datetime`: A date/time instance that implements
+    ///   [`IntoExcelDateTime`](crate::IntoExcelDateTime).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use rust_xlsxwriter::{ExcelDateTime, NumberFormat, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// let datetime = ExcelDateTime::from_ymd(2025, 3, 7)?.and_hms(9, 5, 30)?;
+    ///
+    /// let number_format = NumberFormat::new("dddd, mmmm d, yyyy");
+    /// assert_eq!(number_format.format_datetime(&datetime), "Friday, March 7, 2025");
+    ///
+    /// let number_format = NumberFormat::new("h:mm AM/PM");
+    /// assert_eq!(number_format.format_datetime(&datetime), "9:05 AM");
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn format_datetime(&self, datetime: impl IntoExcelDateTime) -> String {
+        self.format_number(datetime.to_excel_serial_date())
+    }
+
+    /// Format a string as it would be displayed in Excel.
+    ///
+    /// Strings are displayed using the text section of the number format, if
+    /// there is one, with the `@` placeholder replaced by the string. Otherwise
+    /// the string is displayed unchanged.
+    ///
+    /// # Parameters
+    ///
+    /// - `string`: The string to format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_xlsxwriter::NumberFormat;
+    ///
+    /// let number_format = NumberFormat::new(r#"0.00;-0.00;0;"Item: "@"#);
+    /// assert_eq!(number_format.format_string("Apple"), "Item: Apple");
+    /// ```
+    ///
+    pub fn format_string(&self, string: &str) -> String {
+        match &self.text_section {
+            Some(section) => section.format_text(string),
+            None => string.to_string(),
+        }
+    }
+
+    /// Get the font color that Excel uses to display a number, if any.
+    ///
+    /// Number format sections can set the font color of a cell, for example to
+    /// display negative numbers in red with `#,##0;[Red]-#,##0`. This method
+    /// returns the color of the section that is used to display a number, or
+    /// `None` if the section doesn't have a color.
+    ///
+    /// # Parameters
+    ///
+    /// - `number`: The number to check. It can be any type that converts to
+    ///   `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_xlsxwriter::{Color, NumberFormat};
+    ///
+    /// let number_format = NumberFormat::new("[Blue]0;[Red]-0");
+    ///
+    /// assert_eq!(number_format.font_color(1), Some(Color::Blue));
+    /// assert_eq!(number_format.font_color(-1), Some(Color::Red));
+    /// ```
+    ///
+    pub fn font_color(&self, number: impl Into<f64>) -> Option<Color> {
+        self.format_number_with_color(number.into()).1
+    }
+
+    // Format a number and also return the color, if any, of the format section
+    // that was used.
+    pub(crate) fn format_number_with_color(&self, value: f64) -> (String, Option<Color>) {
+        // Excel doesn't support NaN or infinite values.
+        if !value.is_finite() {
+            return ("#NUM!".to_string(), None);
+        }
+
+        let (section, use_sign) = self.section_for_number(value);
+
+        let text = match section.kind {
+            SectionKind::DateTime => {
+                if !(0.0..MAX_DATETIME).contains(&value) {
+                    // Excel can't display negative dates or times, or dates
+                    // after the year 9999.
+                    "#".repeat(8)
+                } else {
+                    section.format_datetime(value)
+                }
+            }
+            SectionKind::Fraction | SectionKind::Number | SectionKind::Text => {
+                let text = if section.kind == SectionKind::Fraction {
+                    section.format_fraction(value.abs())
+                } else {
+                    section.format_numeric(value.abs())
+                };
+
+                if use_sign && value < 0.0 {
+                    format!("-{text}")
+                } else {
+                    text
+                }
+            }
+        };
+
+        (text, section.color)
+    }
+
+    // Check if the format is the default "General" format.
+    pub(crate) fn is_general(&self) -> bool {
+        self.sections.len() == 1 && self.sections[0].tokens == [Token::General]
+    }
+
+    // Get the section that applies to a number, and whether the sign of a
+    // negative number should be displayed. Excel only adds a minus sign when
+    // the first section is used for a negative number.
+    fn section_for_number(&self, value: f64) -> (&Section, bool) {
+        let sections = &self.sections;
+
+        if sections.iter().any(|section| section.condition.is_some()) {
+            return self.section_for_condition(value);
+        }
+
+        match sections.len() {
+            1 => (&sections[0], true),
+            2 => {
+                if value < 0.0 {
+                    (&sections[1], false)
+                } else {
+                    (&sections[0], false)
+                }
+            }
+            _ => {
+                if value > 0.0 {
+                    (&sections[0], false)
+                } else if value < 0.0 {
+                    (&sections[1], false)
+                } else {
+                    (&sections[2], false)
+                }
+            }
+        }
+    }
+
+    // Get the section that applies to a number in a format with conditions
+    // such as `[>=100]`. The first section with a matching condition, or
+    // without a condition, is used. The minus sign of a negative number is
+    // displayed unless the section is only for numbers less than zero.
+    fn section_for_condition(&self, value: f64) -> (&Section, bool) {
+        let section = self
+            .sections
+            .iter()
+            .find(|section| {
+                section
+                    .condition
+                    .map_or(true, |condition| condition.matches(value))
+            })
+            .unwrap_or(&self.sections[self.sections.len() - 1]);
+
+        let use_sign = !section
+            .condition
+            .is_some_and(|condition| condition.is_negative_only());
+
+        (section, use_sign)
+    }
+}
+
+// -----------------------------------------------------------------------
+// Section
+// -----------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SectionKind {
+    Number,
+    Fraction,
+    DateTime,
+    Text,
+}
+
+// A number format section. Each format can have up to 4 sections separated by
+// semicolons for positive numbers, negative numbers, zero and text.
+#[derive(Clone, Debug)]
+struct Section {
+    tokens: Vec<Token>,
+    kind: SectionKind,
+    color: Option<Color>,
+    condition: Option<Condition>,
+    use_thousands: bool,
+    percent_count: i32,
+    scale_count: i32,
+}
+
+impl Section {
+    // Parse a number format section into tokens.
+    fn new(section: &str) -> Section {
+        let mut tokens = vec![];
+        let mut color = None;
+        let mut condition = None;
+        let chars: Vec<char> = section.chars().collect();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let char = chars[i];
+
+            match char {
+                // Quoted literal string.
+                '"' => {
+                    let mut literal = String::new();
+                    i += 1;
+                    while i < chars.len() && chars[i] != '"' {
+                        literal.push(chars[i]);
+                        i += 1;
+                    }
+                    tokens.push(Token::Literal(literal));
+                }
+
+                // Escaped literal character.
+                '\\' => {
+                    if let Some(next) = chars.get(i + 1) {
+                        tokens.push(Token::Literal(next.to_string()));
+                        i += 1;
+                    }
+                }
+
+                // Padding the width of the following character.
+                '_' => {
+                    tokens.push(Token::Literal(" ".to_string()));
+                    i += 1;
+                }
+
+                // Repeat the following character to fill the cell. This is
+                // ignored since it depends on the column width.
+                '*' => {
+                    i += 1;
+                }
+
+                // Color, condition, elapsed time, currency or other bracketed
+                // properties.
+                '[' => {
+                    let mut property = String::new();
+                    i += 1;
+                    while i < chars.len() && chars[i] != ']' {
+                        property.push(chars[i]);
+                        i += 1;
+                    }
+
+                    if let Some(currency) = property.strip_prefix('$') {
+                        // Currency and locale tags such as [$€-407]. Only the
+                        // currency symbol, if any, is displayed.
+                        let symbol = currency.split('-').next().unwrap_or_default();
+                        tokens.push(Token::Literal(symbol.to_string()));
+                    } else if let Some(section_color) = color_from_name(&property) {
+                        color = Some(section_color);
+                    } else if let Some(section_condition) = Condition::new(&property) {
+                        condition = Some(section_condition);
+                    } else if let Some(token) = elapsed_time_token(&property) {
+                        tokens.push(token);
+                    }
+                }
+
+                '0' | '#' | '?' => tokens.push(Token::Digit(char)),
+                '.' => tokens.push(Token::DecimalPoint),
+                ',' => tokens.push(Token::Comma),
+                '%' => tokens.push(Token::Percent),
+                '@' => tokens.push(Token::Text),
+
+                // The slash of a fraction, with an optional fixed denominator
+                // such as "?/16".
+                '/' if matches!(tokens.last(), Some(Token::Digit(_)))
+                    && matches!(chars.get(i + 1), Some('0'..='9' | '#' | '?')) =>
+                {
+                    tokens.push(Token::Fraction);
+
+                    if matches!(chars.get(i + 1), Some('1'..='9')) {
+                        let mut denominator = String::new();
+                        while let Some(digit) =
+                            chars.get(i + 1).filter(|char| char.is_ascii_digit())
+                        {
+                            denominator.push(*digit);
+                            i += 1;
+                        }
+                        tokens.push(Token::Denominator(denominator.parse().unwrap_or(1)));
+                    }
+                }
+
+                'E' | 'e' if matches!(chars.get(i + 1), Some('+' | '-')) => {
+                    tokens.push(Token::Exponent(chars[i + 1] == '+'));
+                    i += 1;
+                }
+
+                'G' | 'g' if starts_with_ignore_case(&chars[i..], "general") => {
+                    tokens.push(Token::General);
+                    i += "general".len() - 1;
+                }
+
+                'A' | 'a' if starts_with_ignore_case(&chars[i..], "am/pm") => {
+                    let am: String = chars[i..i + 2].iter().collect();
+                    let pm: String = chars[i + 3..i + 5].iter().collect();
+                    tokens.push(Token::AmPm(am, pm));
+                    i += "am/pm".len() - 1;
+                }
+
+                'A' | 'a' if starts_with_ignore_case(&chars[i..], "a/p") => {
+                    tokens.push(Token::AmPm(chars[i].to_string(), chars[i + 2].to_string()));
+                    i += "a/p".len() - 1;
+                }
+
+                'y' | 'Y' | 'm' | 'M' | 'd' | 'D' | 'h' | 'H' | 's' | 'S' => {
+                    let lowercase = char.to_ascii_lowercase();
+                    let mut length = 1;
+                    while i + 1 < chars.len() && chars[i + 1].to_ascii_lowercase() == lowercase {
+                        length += 1;
+                        i += 1;
+                    }
+
+                    let token = match lowercase {
+                        'y' => Token::Year(length),
+                        'm' => Token::Month(length),
+                        'd' => Token::Day(length),
+                        'h' => Token::Hour(length),
+                        _ => Token::Second(length),
+                    };
+                    tokens.push(token);
+                }
+
+                _ => tokens.push(Token::Literal(char.to_string())),
+            }
+
+            i += 1;
+        }
+
+        let kind = if tokens.contains(&Token::Text) {
+            SectionKind::Text
+        } else if tokens.iter().any(Token::is_datetime) {
+            SectionKind::DateTime
+        } else if tokens.contains(&Token::Fraction) {
+            SectionKind::Fraction
+        } else {
+            SectionKind::Number
+        };
+
+        let mut section = Section {
+            tokens,
+            kind,
+            color,
+            condition,
+            use_thousands: false,
+            percent_count: 0,
+            scale_count: 0,
+        };
+
+        match kind {
+            SectionKind::DateTime => section.resolve_minutes(),
+            SectionKind::Number | SectionKind::Fraction => section.resolve_commas(),
+            SectionKind::Text => {}
+        }
+
+        section
+    }
+
+    // Distinguish between months and minutes. An "m" or "mm" token is a minute
+    // if it follows an hour or precedes a second.
+    fn resolve_minutes(&mut self) {
+        let datetime_indices: Vec<usize> = self
+            .tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, token)| token.is_datetime())
+            .map(|(index, _)| index)
+            .collect();
+
+        for (position, &index) in datetime_indices.iter().enumerate() {
+            let Token::Month(length) = self.tokens[index] else {
+                continue;
+            };
+
+            if length > 2 {
+                continue;
+            }
+
+            let after_hour = position > 0
+                && matches!(
+                    self.tokens[datetime_indices[position - 1]],
+                    Token::Hour(_) | Token::ElapsedHour(_)
+                );
+
+            let before_second = datetime_indices.get(position + 1).is_some_and(|&next| {
+                matches!(
+                    self.tokens[next],
+                    Token::Second(_) | Token::ElapsedSecond(_)
+                )
+            });
+
+            if after_hour || before_second {
+                self.tokens[index] = Token::Minute(length);
+            }
+        }
+    }
+
+    // Determine whether commas in a numeric format are thousands separators,
+    // scaling factors, or literal characters. Also count the percent signs,
+    // which multiply the number by 100.
+    fn resolve_commas(&mut self) {
+        let integer_end = self
+            .tokens
+            .iter()
+            .position(|token| matches!(token, Token::DecimalPoint | Token::Exponent(_)))
+            .unwrap_or(self.tokens.len());
+
+        let mantissa_end = self
+            .tokens
+            .iter()
+            .position(|token| matches!(token, Token::Exponent(_)))
+            .unwrap_or(self.tokens.len());
+
+        let last_integer_digit = self.tokens[..integer_end]
+            .iter()
+            .rposition(|token| matches!(token, Token::Digit(_)));
+
+        let last_digit = self.tokens[..mantissa_end]
+            .iter()
+            .rposition(|token| matches!(token, Token::Digit(_)));
+
+        for index in 0..self.tokens.len() {
+            match self.tokens[index] {
+                Token::Comma => {
+                    let digit_before = self.tokens[..index]
+                        .iter()
+                        .any(|token| matches!(token, Token::Digit(_)));
+
+                    if digit_before && Some(index) < last_integer_digit {
+                        self.use_thousands = true;
+                        self.tokens[index] = Token::Literal(String::new());
+                    } else if last_digit.is_some_and(|last| {
+                        index > last
+                            && self.tokens[last + 1..index]
+                                .iter()
+                                .all(|token| *token == Token::Comma || token.is_empty_literal())
+                    }) {
+                        self.scale_count += 1;
+                        self.tokens[index] = Token::Literal(String::new());
+                    } else {
+                        self.tokens[index] = Token::Literal(",".to_string());
+                    }
+                }
+                Token::Percent => self.percent_count += 1,
+                _ => {}
+            }
+        }
+    }
+
+    // Apply the percent and scaling factors of the section to a number.
+    fn scale_number(&self, value: f64) -> f64 {
+        let mut value = value;
+        for _ in 0..self.percent_count {
+            value *= 100.0;
+        }
+        for _ in 0..self.scale_count {
+            value /= 1000.0;
+        }
+
+        value
+    }
+
+    // Format a positive number using a numeric section.
+    fn format_numeric(&self, value: f64) -> String {
+        let value = self.scale_number(value);
+
+        let exponent_index = self
+            .tokens
+            .iter()
+            .position(|token| matches!(token, Token::Exponent(_)));
+
+        let mantissa_end = exponent_index.unwrap_or(self.tokens.len());
+
+        let decimal_index = self.tokens[..mantissa_end]
+            .iter()
+            .position(|token| *token == Token::DecimalPoint);
+
+        let integer_end = decimal_index.unwrap_or(mantissa_end);
+
+        let integer_placeholders: Vec<char> = self.tokens[..integer_end]
+            .iter()
+            .filter_map(Token::digit)
+            .collect();
+
+        let decimal_placeholders: Vec<char> = self.tokens[integer_end..mantissa_end]
+            .iter()
+            .filter_map(Token::digit)
+            .collect();
+
+        let mut exponent = 0;
+        let (integer_digits, decimal_digits) = match exponent_index {
+            Some(_) => {
+                let (mantissa, mantissa_exponent) = scientific_parts(
+                    value,
+                    integer_placeholders.len(),
+                    integer_placeholders.contains(&'#'),
+                    decimal_placeholders.len(),
+                );
+                exponent = mantissa_exponent;
+                mantissa
+            }
+            None => round_to_digits(value, decimal_placeholders.len()),
+        };
+
+        // Excel doesn't display a leading zero unless there is a "0" digit
+        // placeholder.
+        let integer_digits: Vec<char> = if integer_digits == "0" {
+            vec![]
+        } else {
+            integer_digits.chars().collect()
+        };
+
+        let decimal_digits = trim_decimal_digits(&decimal_digits, &decimal_placeholders);
+
+        let mut output = String::new();
+        let mut integer_position = 0;
+        let mut decimal_position = 0;
+        let mut exponent_digits_written = false;
+
+        for (index, token) in self.tokens.iter().enumerate() {
+            match token {
+                Token::Digit(placeholder) if index < integer_end => {
+                    let digits = placeholder_digits(
+                        &integer_digits,
+                        *placeholder,
+                        integer_position,
+                        integer_placeholders.len(),
+                        self.use_thousands,
+                    );
+                    output.push_str(&digits);
+                    integer_position += 1;
+                }
+                Token::Digit(_) if index < mantissa_end => {
+                    output.push_str(&decimal_digits[decimal_position]);
+                    decimal_position += 1;
+                }
+                Token::Digit(_) if !exponent_digits_written => {
+                    let count = self.tokens[index..]
+                        .iter()
+                        .filter(|token| matches!(token, Token::Digit('0')))
+                        .count();
+                    output.push_str(&format!("{:0count$}", exponent.abs()));
+                    exponent_digits_written = true;
+                }
+                Token::DecimalPoint if index == integer_end => {
+                    if integer_placeholders.is_empty() {
+                        output.extend(integer_digits.iter());
+                    }
+                    output.push('.');
+                }
+                Token::DecimalPoint => output.push('.'),
+                Token::Exponent(show_plus) => {
+                    if exponent < 0 {
+                        output.push_str("E-");
+                    } else if *show_plus {
+                        output.push_str("E+");
+                    } else {
+                        output.push('E');
+                    }
+                }
+                Token::General => output.push_str(&format_general(value)),
+                Token::Percent => output.push('%'),
+                Token::Literal(literal) => output.push_str(literal),
+                _ => {}
+            }
+        }
+
+        output
+    }
+
+    // Format a positive number as a fraction such as "1 1/2" or "3/16". If
+    // there are integer placeholders before the numerator then the whole part
+    // of the number is displayed separately and the fraction is replaced with
+    // spaces when it is zero.
+    fn format_fraction(&self, value: f64) -> String {
+        let value = self.scale_number(value);
+        let tokens = &self.tokens;
+
+        let slash = tokens
+            .iter()
+            .position(|token| *token == Token::Fraction)
+            .unwrap_or_default();
+
+        let numerator_start = tokens[..slash]
+            .iter()
+            .rposition(|token| token.digit().is_none())
+            .map_or(0, |index| index + 1);
+
+        let denominator_end = tokens[slash + 1..]
+            .iter()
+            .position(|token| !matches!(token, Token::Digit(_) | Token::Denominator(_)))
+            .map_or(tokens.len(), |index| slash + 1 + index);
+
+        let integer_placeholders: Vec<char> = tokens[..numerator_start]
+            .iter()
+            .filter_map(Token::digit)
+            .collect();
+
+        let numerator_placeholders = slash - numerator_start;
+
+        let denominator_placeholders = tokens[slash + 1..denominator_end]
+            .iter()
+            .filter(|token| token.digit().is_some())
+            .count();
+
+        let fixed_denominator =
+            tokens[slash + 1..denominator_end]
+                .iter()
+                .find_map(|token| match token {
+                    Token::Denominator(denominator) => Some(*denominator),
+                    _ => None,
+                });
+
+        let has_integer = !integer_placeholders.is_empty();
+        let (mut integer, fraction) = if has_integer {
+            (value.trunc(), value.fract())
+        } else {
+            (0.0, value)
+        };
+
+        let (mut numerator, denominator) = match fixed_denominator {
+            Some(denominator) => ((fraction * denominator as f64).round() as u64, denominator),
+            None => closest_fraction(fraction, denominator_placeholders),
+        };
+
+        if has_integer && numerator == denominator {
+            integer += 1.0;
+            numerator = 0;
+        }
+
+        let hide_fraction = has_integer && numerator == 0;
+
+        // The whole number part isn't displayed if it is zero, unless the
+        // fraction is also zero.
+        let integer_digits: Vec<char> = if integer == 0.0 && !hide_fraction {
+            vec![]
+        } else {
+            round_to_digits(integer, 0).0.chars().collect()
+        };
+
+        let numerator_digits: Vec<char> = numerator.to_string().chars().collect();
+        let denominator_digits: Vec<char> = denominator.to_string().chars().collect();
+
+        // The tokens from after the last integer digit to the end of the
+        // denominator are hidden when the fraction is zero.
+        let hidden_start = tokens[..numerator_start]
+            .iter()
+            .rposition(|token| token.digit().is_some())
+            .map_or(0, |index| index + 1);
+
+        let mut output = String::new();
+        let mut integer_position = 0;
+        let mut numerator_position = 0;
+        let mut denominator_position = 0;
+
+        for (index, token) in tokens.iter().enumerate() {
+            let text = match token {
+                Token::Digit(placeholder) if index < numerator_start => {
+                    integer_position += 1;
+                    placeholder_digits(
+                        &integer_digits,
+                        *placeholder,
+                        integer_position - 1,
+                        integer_placeholders.len(),
+                        self.use_thousands,
+                    )
+                }
+                Token::Digit(placeholder) if index < slash => {
+                    numerator_position += 1;
+                    placeholder_digits(
+                        &numerator_digits,
+                        *placeholder,
+                        numerator_position - 1,
+                        numerator_placeholders,
+                        false,
+                    )
+                }
+                Token::Digit(placeholder) if index < denominator_end => {
+                    denominator_position += 1;
+                    denominator_placeholder_digits(
+                        &denominator_digits,
+                        *placeholder,
+                        denominator_position - 1,
+                        denominator_placeholders,
+                    )
+                }
+                Token::Fraction => "/".to_string(),
+                Token::Denominator(denominator) => denominator.to_string(),
+                Token::General => format_general(value),
+                Token::Percent => "%".to_string(),
+                Token::Literal(literal) => literal.clone(),
+                _ => String::new(),
+            };
+
+            if hide_fraction && (hidden_start..denominator_end).contains(&index) {
+                output.push_str(&" ".repeat(text.chars().count()));
+            } else {
+                output.push_str(&text);
+            }
+        }
+
+        output
+    }
+
+    // Format a positive number as a date and/or time.
+    fn format_datetime(&self, value: f64) -> String {
+        // Round the time to the number of decimal places of the seconds.
+        let subsecond_places = self.subsecond_places();
+        let scale = 10_i64.pow(subsecond_places as u32);
+
+        let total = (value * 86_400.0 * scale as f64).round() as i64;
+        let subseconds = total % scale;
+        let total_seconds = total / scale;
+        let days = total_seconds / 86_400;
+        let seconds = total_seconds % 86_400;
+
+        let (year, month, day) = excel_date(days);
+        let hour = seconds / 3600;
+        let minute = seconds % 3600 / 60;
+        let second = seconds % 60;
+
+        let has_am_pm = self
+            .tokens
+            .iter()
+            .any(|token| matches!(token, Token::AmPm(_, _)));
+
+        let display_hour = if has_am_pm {
+            match hour % 12 {
+                0 => 12,
+                hour => hour,
+            }
+        } else {
+            hour
+        };
+
+        let subsecond_digits: Vec<char> =
+            format!("{subseconds:0subsecond_places$}").chars().collect();
+        let mut subsecond_position = 0;
+
+        let mut output = String::new();
+
+        for token in &self.tokens {
+            match token {
+                Token::Year(length) => {
+                    if *length <= 2 {
+                        output.push_str(&format!("{:02}", year % 100));
+                    } else {
+                        output.push_str(&format!("{year:04}"));
+                    }
+                }
+                Token::Month(length) => {
+                    let name = MONTH_NAMES[(month - 1) as usize];
+                    match length {
+                        1 => output.push_str(&month.to_string()),
+                        2 => output.push_str(&format!("{month:02}")),
+                        3 => output.push_str(&name[..3]),
+                        5 => output.push_str(&name[..1]),
+                        _ => output.push_str(name),
+                    }
+                }
+                Token::Day(length) => {
+                    let name = DAY_NAMES[days.rem_euclid(7) as usize];
+                    match length {
+                        1 => output.push_str(&day.to_string()),
+                        2 => output.push_str(&format!("{day:02}")),
+                        3 => output.push_str(&name[..3]),
+                        _ => output.push_str(name),
+                    }
+                }
+                Token::Hour(length) => output.push_str(&pad_number(display_hour, *length.min(&2))),
+                Token::Minute(length) => output.push_str(&pad_number(minute, *length.min(&2))),
+                Token::Second(length) => output.push_str(&pad_number(second, *length.min(&2))),
+                Token::ElapsedHour(length) => {
+                    output.push_str(&pad_number(total_seconds / 3600, *length));
+                }
+                Token::ElapsedMinute(length) => {
+                    output.push_str(&pad_number(total_seconds / 60, *length));
+                }
+                Token::ElapsedSecond(length) => {
+                    output.push_str(&pad_number(total_seconds, *length));
+                }
+                Token::AmPm(am, pm) => {
+                    if hour < 12 {
+                        output.push_str(am);
+                    } else {
+                        output.push_str(pm);
+                    }
+                }
+                Token::Digit(digit) => {
+                    if subsecond_places > 0 {
+                        let digit = subsecond_digits
+                            .get(subsecond_position)
+                            .copied()
+                            .unwrap_or('0');
+                        output.push(digit);
+                        subsecond_position += 1;
+                    } else {
+                        output.push(*digit);
+                    }
+                }
+                Token::Literal(literal) => output.push_str(literal),
+                Token::DecimalPoint => output.push('.'),
+                Token::Comma => output.push(','),
+                Token::Percent => output.push('%'),
+                Token::Fraction => output.push('/'),
+                Token::Denominator(denominator) => output.push_str(&denominator.to_string()),
+                Token::Exponent(_) => output.push('E'),
+                Token::General => output.push_str(&format_general(value)),
+                Token::Text => {}
+            }
+        }
+
+        output
+    }
+
+    // Get the number of decimal places used to display fractional seconds,
+    // such as 2 for "ss.00". Excel supports up to 3 decimal places.
+    fn subsecond_places(&self) -> usize {
+        let Some(second) = self
+            .tokens
+            .iter()
+            .position(|token| matches!(token, Token::Second(_) | Token::ElapsedSecond(_)))
+        else {
+            return 0;
+        };
+
+        if self.tokens.get(second + 1) != Some(&Token::DecimalPoint) {
+            return 0;
+        }
+
+        self.tokens[second + 2..]
+            .iter()
+            .take_while(|token| token.digit().is_some())
+            .count()
+            .min(3)
+    }
+
+    // Format a string using a text section.
+    fn format_text(&self, text: &str) -> String {
+        let mut output = String::new();
+
+        for token in &self.tokens {
+            match token {
+                Token::Text => output.push_str(text),
+                Token::Literal(literal) => output.push_str(literal),
+                Token::Digit(digit) => output.push(*digit),
+                Token::DecimalPoint => output.push('.'),
+                Token::Comma => output.push(','),
+                Token::Percent => output.push('%'),
+                Token::Fraction => output.push('/'),
+                _ => {}
+            }
+        }
+
+        output
+    }
+}
+
+// -----------------------------------------------------------------------
+// Condition
+// -----------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ConditionOperator {
+    EqualTo,
+    NotEqualTo,
+    GreaterThan,
+    GreaterThanOrEqualTo,
+    LessThan,
+    LessThanOrEqualTo,
+}
+
+// A section condition such as `[>=100]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Condition {
+    operator: ConditionOperator,
+    value: f64,
+}
+
+impl Condition {
+    // Parse a bracketed condition property such as ">=100".
+    fn new(property: &str) -> Option<Condition> {
+        let operators = [
+            ("<=", ConditionOperator::LessThanOrEqualTo),
+            (">=", ConditionOperator::GreaterThanOrEqualTo),
+            ("<>", ConditionOperator::NotEqualTo),
+            ("<", ConditionOperator::LessThan),
+            (">", ConditionOperator::GreaterThan),
+            ("=", ConditionOperator::EqualTo),
+        ];
+
+        operators.iter().find_map(|(symbol, operator)| {
+            let value = property.strip_prefix(symbol)?.trim().parse().ok()?;
+
+            Some(Condition {
+                operator: *operator,
+                value,
+            })
+        })
+    }
+
+    // Check if a number matches the condition.
+    fn matches(self, number: f64) -> bool {
+        match self.operator {
+            ConditionOperator::EqualTo => number == self.value,
+            ConditionOperator::NotEqualTo => number != self.value,
+            ConditionOperator::GreaterThan => number > self.value,
+            ConditionOperator::GreaterThanOrEqualTo => number >= self.value,
+            ConditionOperator::LessThan => number < self.value,
+            ConditionOperator::LessThanOrEqualTo => number <= self.value,
+        }
+    }
+
+    // Check if the condition only matches numbers that are zero or less.
+    fn is_negative_only(self) -> bool {
+        matches!(
+            self.operator,
+            ConditionOperator::LessThan | ConditionOperator::LessThanOrEqualTo
+        ) && self.value <= 0.0
+    }
+}
+
+// -----------------------------------------------------------------------
+// Token
+// -----------------------------------------------------------------------
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Literal(String),
+    General,
+    Text,
+    Digit(char),
+    DecimalPoint,
+    Comma,
+    Percent,
+    Fraction,
+    Denominator(u64),
+    Exponent(bool),
+    Year(usize),
+    Month(usize),
+    Day(usize),
+    Hour(usize),
+    Minute(usize),
+    Second(usize),
+    ElapsedHour(usize),
+    ElapsedMinute(usize),
+    ElapsedSecond(usize),
+    AmPm(String, String),
+}
+
+impl Token {
+    fn is_datetime(&self) -> bool {
+        matches!(
+            self,
+            Token::Year(_)
+                | Token::Month(_)
+                | Token::Day(_)
+                | Token::Hour(_)
+                | Token::Minute(_)
+                | Token::Second(_)
+                | Token::ElapsedHour(_)
+                | Token::ElapsedMinute(_)
+                | Token::ElapsedSecond(_)
+                | Token::AmPm(_, _)
+        )
+    }
+
+    fn is_empty_literal(&self) -> bool {
+        matches!(self, Token::Literal(literal) if literal.is_empty())
+    }
+
+    fn digit(&self) -> Option<char> {
+        match self {
+            Token::Digit(digit) => Some(*digit),
+            _ => None,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------
+// Helper functions.
+// -----------------------------------------------------------------------
+
+// The Excel serial date for 10000-01-01. Later dates can't be displayed.
+const MAX_DATETIME: f64 = 2_958_466.0;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+// Day names indexed from an Excel serial date modulo 7.
+const DAY_NAMES: [&str; 7] = [
+    "Saturday",
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+];
+
+// Split a number format into sections at semicolons that aren't quoted,
+// escaped or in brackets.
+fn split_sections(num_format: &str) -> Vec<String> {
+    let mut sections = vec![];
+    let mut section = String::new();
+    let mut in_quotes = false;
+    let mut in_brackets = false;
+    let mut chars = num_format.chars();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' => in_quotes = !in_quotes,
+            '[' if !in_quotes => in_brackets = true,
+            ']' if !in_quotes => in_brackets = false,
+            '\\' if !in_quotes => {
+                section.push(char);
+                if let Some(next) = chars.next() {
+                    section.push(next);
+                }
+                continue;
+            }
+            ';' if !in_quotes && !in_brackets => {
+                sections.push(std::mem::take(&mut section));
+                continue;
+            }
+            _ => {}
+        }
+
+        section.push(char);
+    }
+
+    sections.push(section);
+
+    // An empty format is the same as "General".
+    if sections.len() == 1 && sections[0].is_empty() {
+        sections[0] = "General".to_string();
+    }
+
+    sections
+}
+
+// Check if a slice of chars starts with a lowercase string, ignoring case.
+fn starts_with_ignore_case(chars: &[char], string: &str) -> bool {
+    chars.len() >= string.len()
+        && chars
+            .iter()
+            .zip(string.chars())
+            .all(|(char, other)| char.to_ascii_lowercase() == other)
+}
+
+// Map the color names used in number formats to colors.
+fn color_from_name(name: &str) -> Option<Color> {
+    match name.to_ascii_lowercase().as_str() {
+        "black" => Some(Color::Black),
+        "blue" => Some(Color::Blue),
+        "cyan" => Some(Color::Cyan),
+        "green" => Some(Color::Lime),
+        "magenta" => Some(Color::Magenta),
+        "red" => Some(Color::Red),
+        "white" => Some(Color::White),
+        "yellow" => Some(Color::Yellow),
+        _ => None,
+    }
+}
+
+// Get the token for an elapsed time property such as "h" in "[h]:mm".
+fn elapsed_time_token(property: &str) -> Option<Token> {
+    let first = property.chars().next()?.to_ascii_lowercase();
+    let length = property.len();
+
+    if !property
+        .chars()
+        .all(|char| char.to_ascii_lowercase() == first)
+    {
+        return None;
+    }
+
+    match first {
+        'h' => Some(Token::ElapsedHour(length)),
+        'm' => Some(Token::ElapsedMinute(length)),
+        's' => Some(Token::ElapsedSecond(length)),
+        _ => None,
+    }
+}
+
+// Zero pad a date/time number to the length of a token like "hh".
+fn pad_number(number: i64, length: usize) -> String {
+    format!("{number:0length$}")
+}
+
+// Get the digits for an integer or numerator placeholder. Digits are right
+// aligned to the placeholders and any digits that overflow them are added to
+// the leftmost placeholder.
+fn placeholder_digits(
+    digits: &[char],
+    placeholder: char,
+    position: usize,
+    num_placeholders: usize,
+    use_thousands: bool,
+) -> String {
+    let mut output = String::new();
+    let place = num_placeholders - 1 - position;
+
+    // The leftmost placeholder also gets any overflow digits.
+    let first_place = if position == 0 {
+        digits.len().max(place + 1) - 1
+    } else {
+        place
+    };
+
+    for current_place in (place..=first_place).rev() {
+        let digit = if current_place < digits.len() {
+            digits[digits.len() - 1 - current_place]
+        } else if current_place == place {
+            match placeholder {
+                '0' => '0',
+                '?' => ' ',
+                _ => continue,
+            }
+        } else {
+            continue;
+        };
+
+        output.push(digit);
+
+        if use_thousands && current_place > 0 && current_place % 3 == 0 {
+            output.push(',');
+        }
+    }
+
+    output
+}
+
+// Get the digits for a denominator placeholder. Digits are left aligned to the
+// placeholders and any digits that overflow them are added to the rightmost
+// placeholder.
+fn denominator_placeholder_digits(
+    digits: &[char],
+    placeholder: char,
+    position: usize,
+    num_placeholders: usize,
+) -> String {
+    if position + 1 == num_placeholders && position < digits.len() {
+        digits[position..].iter().collect()
+    } else if position < digits.len() {
+        digits[position].to_string()
+    } else if placeholder == '?' {
+        " ".to_string()
+    } else {
+        String::new()
+    }
+}
+
+// Find the fraction that is closest to a positive number with a denominator of
+// up to the given number of digits. Excel uses the smallest denominator if
+// there is more than one.
+fn closest_fraction(value: f64, digits: usize) -> (u64, u64) {
+    let max_denominator = 10_u64.pow(digits.clamp(1, 4) as u32) - 1;
+    let mut closest = (value.round() as u64, 1);
+    let mut smallest_error = (value - value.round()).abs();
+
+    for denominator in 2..=max_denominator {
+        if smallest_error == 0.0 {
+            break;
+        }
+
+        let numerator = (value * denominator as f64).round();
+        let error = (value - numerator / denominator as f64).abs();
+
+        if error + f64::EPSILON < smallest_error {
+            closest = (numerator as u64, denominator);
+            smallest_error = error;
+        }
+    }
+
+    closest
+}
+
+// Round a positive number to a number of decimal places and return the
+// integer and decimal digits. Excel only stores 15 significant digits so the
+// number is reduced to that precision first and then rounded half up.
+fn round_to_digits(value: f64, decimal_places: usize) -> (String, String) {
+    let scientific = format!("{value:.14e}");
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let exponent: i64 = exponent.parse().unwrap_or_default();
+
+    let mut digits: Vec<u8> = mantissa
+        .bytes()
+        .filter(u8::is_ascii_digit)
+        .map(|digit| digit - b'0')
+        .collect();
+
+    // Position of the decimal point in the digits.
+    let mut point = exponent + 1;
+    if point < 1 {
+        let padding = (1 - point) as usize;
+        digits.splice(0..0, std::iter::repeat(0).take(padding));
+        point = 1;
+    }
+
+    let mut point = point as usize;
+    if digits.len() < point {
+        digits.resize(point, 0);
+    }
+
+    let keep = point + decimal_places;
+    if digits.len() > keep {
+        let round_up = digits[keep] >= 5;
+        digits.truncate(keep);
+
+        if round_up {
+            let mut index = keep;
+            loop {
+                if index == 0 {
+                    digits.insert(0, 1);
+                    point += 1;
+                    break;
+                }
+                index -= 1;
+                if digits[index] == 9 {
+                    digits[index] = 0;
+                } else {
+                    digits[index] += 1;
+                    break;
+                }
+            }
+        }
+    } else {
+        digits.resize(keep, 0);
+    }
+
+    let to_string = |digits: &[u8]| -> String {
+        digits
+            .iter()
+            .map(|digit| char::from(b'0' + digit))
+            .collect()
+    };
+
+    let integer = to_string(&digits[..point]);
+    let integer = integer.trim_start_matches('0');
+    let integer = if integer.is_empty() { "0" } else { integer };
+
+    (integer.to_string(), to_string(&digits[point..]))
+}
+
+// Get the rounded mantissa digits and exponent for a number displayed in
+// scientific notation. If the integer placeholders contain a "#" then the
+// exponent is a multiple of the number of placeholders, as in engineering
+// notation. Otherwise the mantissa fills the integer placeholders.
+fn scientific_parts(
+    value: f64,
+    integer_places: usize,
+    is_engineering: bool,
+    decimal_places: usize,
+) -> ((String, String), i64) {
+    let integer_places = integer_places.max(1) as i64;
+
+    if value == 0.0 {
+        return (round_to_digits(0.0, decimal_places), 0);
+    }
+
+    let mut exponent = value.log10().floor() as i64;
+    loop {
+        let shifted = if is_engineering {
+            exponent - exponent.rem_euclid(integer_places)
+        } else {
+            exponent - (integer_places - 1)
+        };
+
+        let mantissa = value / 10f64.powi(shifted as i32);
+        let (integer, decimal) = round_to_digits(mantissa, decimal_places);
+
+        // Rounding can overflow into an extra integer digit, such as 9.99 to
+        // 10.0, in which case the exponent is increased.
+        if integer.len() as i64 > integer_places {
+            exponent += 1;
+            continue;
+        }
+
+        return ((integer, decimal), shifted);
+    }
+}
+
+// Convert decimal digits to the strings displayed for each decimal
+// placeholder. Trailing zeros are removed for "#" placeholders and replaced
+// with spaces for "?" placeholders.
+fn trim_decimal_digits(digits: &str, placeholders: &[char]) -> Vec<String> {
+    let mut output: Vec<String> = digits.chars().map(|digit| digit.to_string()).collect();
+
+    for (index, placeholder) in placeholders.iter().enumerate().rev() {
+        if output[index] != "0" {
+            break;
+        }
+
+        match placeholder {
+            '#' => output[index] = String::new(),
+            '?' => output[index] = " ".to_string(),
+            _ => break,
+        }
+    }
+
+    output
+}
+
+// Format a positive number in the Excel "General" format. This displays up to
+// 11 characters, switching to scientific notation for very large or small
+// numbers.
+pub(crate) fn format_general(value: f64) -> String {
+    if value == 0.0 {
+        return "0".to_string();
+    }
+
+    let exponent = value.abs().log10().floor() as i64;
+
+    if !(-9..11).contains(&exponent) {
+        let ((integer, decimal), exponent) = scientific_parts(value.abs(), 1, false, 5);
+        let decimal = decimal.trim_end_matches('0');
+        let sign = if exponent < 0 { '-' } else { '+' };
+        let mantissa = if decimal.is_empty() {
+            integer
+        } else {
+            format!("{integer}.{decimal}")
+        };
+
+        return format!("{mantissa}E{sign}{:02}", exponent.abs());
+    }
+
+    let decimal_places = if exponent < 0 {
+        9
+    } else {
+        (10 - (exponent + 1)).max(0) as usize
+    };
+
+    let (integer, decimal) = round_to_digits(value.abs(), decimal_places);
+    let decimal = decimal.trim_end_matches('0');
+
+    if decimal.is_empty() {
+        integer
+    } else {
+        format!("{integer}.{decimal}")
+    }
+}
+
+// Convert an Excel serial date, in the 1900 epoch, to a year, month and day.
+// This follows Excel in treating 1900 as a leap year so that day 60 is
+// 1900-02-29 and day 0 is 1900-01-00.
+fn excel_date(days: i64) -> (i64, i64, i64) {
+    match days {
+        0 => (1900, 1, 0),
+        60 => (1900, 2, 29),
+        _ => {
+            // Days since 1970-01-01.
+            let unix_days = if days < 60 {
+                days - 25_568
+            } else {
+                days - 25_569
+            };
+
+            civil_from_days(unix_days)
+        }
+    }
+}
+
+// Convert days since the Unix epoch to a year, month and day using Howard
+// Hinnant's civil calendar algorithm.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_position = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_position + 2) / 5 + 1;
+    let month = if month_position < 10 {
+        month_position + 3
+    } else {
+        month_position - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+// Get the format string for an Excel built-in number format index. The date
+// formats 14 and 22 are displayed with a 4 digit year in the US English locale.
+fn builtin_num_format(index: u16) -> &'static str {
+    match index {
+        1 => "0",
+        2 => "0.00",
+        3 => "#,##0",
+        4 => "#,##0.00",
+        5 => "($#,##0_);($#,##0)",
+        6 => "($#,##0_);[Red]($#,##0)",
+        7 => "($#,##0.00_);($#,##0.00)",
+        8 => "($#,##0.00_);[Red]($#,##0.00)",
+        9 => "0%",
+        10 => "0.00%",
+        11 => "0.00E+00",
+        12 => "# ?/?",
+        13 => "# ??/??",
+        14 => "m/d/yyyy",
+        15 => "d-mmm-yy",
+        16 => "d-mmm",
+        17 => "mmm-yy",
+        18 => "h:mm AM/PM",
+        19 => "h:mm:ss AM/PM",
+        20 => "h:mm",
+        21 => "h:mm:ss",
+        22 => "m/d/yyyy h:mm",
+        37 => "(#,##0_);(#,##0)",
+        38 => "(#,##0_);[Red](#,##0)",
+        39 => "(#,##0.00_);(#,##0.00)",
+        40 => "(#,##0.00_);[Red](#,##0.00)",
+        41 => r#"_(* #,##0_);_(* (#,##0);_(* "-"_);_(@_)"#,
+        42 => r#"_($* #,##0_);_($* (#,##0);_($* "-"_);_(@_)"#,
+        43 => r#"_(* #,##0.00_);_(* (#,##0.00);_(* "-"??_);_(@_)"#,
+        44 => r#"_($* #,##0.00_);_($* (#,##0.00);_($* "-"??_);_(@_)"#,
+        45 => "mm:ss",
+        46 => "[h]:mm:ss",
+        47 => "mm:ss.0",
+        48 => "##0.0E+0",
+        49 => "@",
+        _ => "General",
+    }
+}
@@ -0,0 +1,385 @@
+// Number format unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod number_format_tests {
+
+    use crate::number_format::NumberFormat;
+    use crate::{Color, ExcelDateTime, Format, XlsxError};
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_general_format() {
+        let test_data = vec![
+            (0.0, "0"),
+            (1.0, "1"),
+            (-1.0, "-1"),
+            (1.5, "1.5"),
+            (123456789.0, "123456789"),
+            (1234567890.0, "1234567890"),
+            (12345678901.0, "12345678901"),
+            (123456789012.0, "1.23457E+11"),
+            (0.1, "0.1"),
+            (0.1 + 0.2, "0.3"),
+            (1.0 / 3.0, "0.333333333"),
+            (123.456789012, "123.456789"),
+            (0.000123456789, "0.000123457"),
+            (0.00000000012, "1.2E-10"),
+            (1e20, "1E+20"),
+        ];
+
+        let num_format = NumberFormat::new("General");
+        for (value, expected) in test_data {
+            assert_eq!(expected, num_format.format_number(value), "value: {value}");
+        }
+    }
+
+    #[test]
+    fn test_numeric_formats() {
+        let test_data = vec![
+            ("0", 1234.5, "1235"),
+            ("0", 0.4, "0"),
+            ("0", -3.0, "-3"),
+            ("0.00", 1234.5, "1234.50"),
+            ("0.00", 2.675, "2.68"),
+            ("0.00", 0.005, "0.01"),
+            ("#,##0", 1234567.0, "1,234,567"),
+            ("#,##0", 123.0, "123"),
+            ("#,##0.00", 1234.5, "1,234.50"),
+            ("#,##0.00", -1234.5, "-1,234.50"),
+            ("0,000", 5.0, "0,005"),
+            ("#.##", 0.5, ".5"),
+            ("#.##", 5.0, "5."),
+            ("0.0#", 1.5, "1.5"),
+            ("0.0#", 1.25, "1.25"),
+            ("0.??", 1.5, "1.5 "),
+            ("000", 7.0, "007"),
+            ("000-0000", 5551234.0, "555-1234"),
+            ("0%", 0.25, "25%"),
+            ("0.00%", 0.12345, "12.35%"),
+            ("#,##0,", 1234567.0, "1,235"),
+            ("#,##0,,", 1234567890.0, "1,235"),
+            ("0.0,,\"M\"", 1234567.0, "1.2M"),
+            ("$#,##0.00", 1234.5, "$1,234.50"),
+            ("\"Total: \"0", 12.0, "Total: 12"),
+            ("0\\k", 12.0, "12k"),
+            ("0_);(0)", 5.0, "5 "),
+            ("0_);(0)", -5.0, "(5)"),
+            ("#,##0;[Red](#,##0)", -1234.0, "(1,234)"),
+            ("0;-0;\"zero\"", 0.0, "zero"),
+            ("[$€-407] #,##0.00", 1234.5, "€ 1,234.50"),
+            ("General\" units\"", 12.5, "12.5 units"),
+            (".00", 12.5, "12.50"),
+        ];
+
+        for (num_format, value, expected) in test_data {
+            assert_eq!(
+                expected,
+                NumberFormat::new(num_format).format_number(value),
+                "format: {num_format}, value: {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_scientific_formats() {
+        let test_data = vec![
+            ("0.00E+00", 12345.0, "1.23E+04"),
+            ("0.00E+00", 0.00012345, "1.23E-04"),
+            ("0.00E+00", 0.0, "0.00E+00"),
+            ("0.00E+00", 9.999, "1.00E+01"),
+            ("0.00E-00", 12345.0, "1.23E04"),
+            ("##0.0E+0", 12345.0, "12.3E+3"),
+            ("##0.0E+0", 1234567.0, "1.2E+6"),
+            ("00.00E+00", 12345.0, "12.35E+03"),
+        ];
+
+        for (num_format, value, expected) in test_data {
+            assert_eq!(
+                expected,
+                NumberFormat::new(num_format).format_number(value),
+                "format: {num_format}, value: {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_datetime_formats() {
+        // 2024-01-15 13:45:30.
+        let datetime = 45306.0 + (13.0 * 3600.0 + 45.0 * 60.0 + 30.0) / 86400.0;
+
+        let test_data = vec![
+            ("yyyy-mm-dd", datetime, "2024-01-15"),
+            ("yyyy-mm-dd hh:mm:ss", datetime, "2024-01-15 13:45:30"),
+            ("d/m/yy", datetime, "15/1/24"),
+            ("m/d/yy", datetime, "1/15/24"),
+            ("dd mmm yyyy", datetime, "15 Jan 2024"),
+            ("dddd, mmmm d", datetime, "Monday, January 15"),
+            ("ddd", datetime, "Mon"),
+            ("mmmmm", datetime, "J"),
+            ("h:mm AM/PM", datetime, "1:45 PM"),
+            ("h:mm am/pm", 0.25, "6:00 am"),
+            ("hh:mm A/P", 0.0, "12:00 A"),
+            ("h:mm:ss", datetime, "13:45:30"),
+            ("mm:ss", datetime, "45:30"),
+            ("yyyy-mm-dd", 1.0, "1900-01-01"),
+            ("yyyy-mm-dd", 59.0, "1900-02-28"),
+            ("yyyy-mm-dd", 60.0, "1900-02-29"),
+            ("yyyy-mm-dd", 61.0, "1900-03-01"),
+            ("yyyy-mm-dd", 0.0, "1900-01-00"),
+            ("yyyy-mm-dd", 2958465.0, "9999-12-31"),
+            ("hh:mm", 0.999999, "00:00"),
+            ("yyyy-mm-dd", -1.0, "########"),
+        ];
+
+        for (num_format, value, expected) in test_data {
+            assert_eq!(
+                expected,
+                NumberFormat::new(num_format).format_number(value),
+                "format: {num_format}, value: {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_colors() {
+        let num_format = NumberFormat::new("#,##0;[Red]-#,##0");
+
+        assert_eq!(
+            ("1,234".to_string(), None),
+            num_format.format_number_with_color(1234.0)
+        );
+        assert_eq!(
+            ("-1,234".to_string(), Some(Color::Red)),
+            num_format.format_number_with_color(-1234.0)
+        );
+    }
+
+    #[test]
+    fn test_builtin_formats() {
+        let test_data = vec![
+            (0, 1234.5, "1234.5"),
+            (3, 1234.5, "1,235"),
+            (4, 1234.5, "1,234.50"),
+            (10, 0.5, "50.00%"),
+            (12, 1.25, "1 1/4"),
+            (14, 45306.0, "1/15/2024"),
+            (37, -1234.0, "(1,234)"),
+            (44, -1234.5, " $(1,234.50)"),
+            (46, 1.5, "36:00:00"),
+            (47, 0.5 / 86400.0, "00:00.5"),
+        ];
+
+        for (index, value, expected) in test_data {
+            let format = Format::new().set_num_format_index(index);
+            assert_eq!(
+                expected,
+                NumberFormat::from_format(&format).format_number(value),
+                "index: {index}, value: {value}"
+            );
+        }
+
+        // The built-in format index takes precedence over the format string,
+        // as in Excel.
+        let format = Format::new().set_num_format_index(3).set_num_format("0.0");
+        assert_eq!(
+            "1,235",
+            NumberFormat::from_format(&format).format_number(1234.5)
+        );
+    }
+
+    #[test]
+    fn test_fraction_formats() {
+        let test_data = vec![
+            ("# ?/?", 1.5, "1 1/2"),
+            ("# ?/?", 0.5, " 1/2"),
+            ("# ?/?", 3.0, "3    "),
+            ("# ?/?", 0.0, "0    "),
+            ("# ?/?", 0.99, "1    "),
+            ("# ?/?", -2.25, "-2 1/4"),
+            ("# ?/?", 0.3333, " 1/3"),
+            ("# ??/??", PI, "3 14/99"),
+            ("# ??/??", 0.2, "  1/5 "),
+            ("# ???/???", PI, "3  16/113"),
+            ("?/?", 2.5, "5/2"),
+            ("0/0", 0.0, "0/1"),
+            ("# ?/4", 1.3, "1 1/4"),
+            ("# ??/16", 0.5, "  8/16"),
+            ("?/100", 0.25, "25/100"),
+        ];
+
+        for (num_format, value, expected) in test_data {
+            assert_eq!(
+                expected,
+                NumberFormat::new(num_format).format_number(value),
+                "format: {num_format}, value: {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_conditional_formats() {
+        let test_data = vec![
+            (
+                r#"[>=1000000]0.0,,"M";[>=1000]0.0,"K";0"#,
+                2_500_000.0,
+                "2.5M",
+            ),
+            (r#"[>=1000000]0.0,,"M";[>=1000]0.0,"K";0"#, 12345.0, "12.3K"),
+            (r#"[>=1000000]0.0,,"M";[>=1000]0.0,"K";0"#, 123.0, "123"),
+            (r#"[>=1000000]0.0,,"M";[>=1000]0.0,"K";0"#, -123.0, "-123"),
+            (r#"[<0]"neg "0;"pos "0"#, -5.0, "neg 5"),
+            (r#"[<0]"neg "0;"pos "0"#, 5.0, "pos 5"),
+            ("[<=9999999]###-####;(###) ###-####", 5551234.0, "555-1234"),
+            (
+                "[<=9999999]###-####;(###) ###-####",
+                2125551234.0,
+                "(212) 555-1234",
+            ),
+            (r"[=1]0\ \i\t\e\m;0\ \i\t\e\m\s", 1.0, "1 item"),
+            (r"[=1]0\ \i\t\e\m;0\ \i\t\e\m\s", 2.0, "2 items"),
+            ("[Red][<>0]0.00;0", 1.5, "1.50"),
+        ];
+
+        for (num_format, value, expected) in test_data {
+            assert_eq!(
+                expected,
+                NumberFormat::new(num_format).format_number(value),
+                "format: {num_format}, value: {value}"
+            );
+        }
+
+        let num_format = NumberFormat::new("[Blue][>=100]0;[Red][<100]0");
+        assert_eq!(Some(Color::Blue), num_format.font_color(100));
+        assert_eq!(Some(Color::Red), num_format.font_color(99));
+    }
+
+    #[test]
+    fn test_text_formats() {
+        let test_data = vec![
+            ("@", "Hello", "Hello"),
+            (r#""Name: "@"#, "Hello", "Name: Hello"),
+            (r#"0;-0;0;"<"@">""#, "Hello", "<Hello>"),
+            (r#"0;-0;0;"Text""#, "Hello", "Text"),
+            ("0.00", "Hello", "Hello"),
+            (";;;", "Hello", ""),
+            (
+                r#"_(* #,##0_);_(* (#,##0);_(* "-"_);_(@_)"#,
+                "Hello",
+                " Hello ",
+            ),
+        ];
+
+        for (num_format, value, expected) in test_data {
+            assert_eq!(
+                expected,
+                NumberFormat::new(num_format).format_string(value),
+                "format: {num_format}, value: {value}"
+            );
+        }
+
+        // Numbers are displayed in the General format with a text only format.
+        assert_eq!("1234.5", NumberFormat::new("@").format_number(1234.5));
+        assert_eq!("", NumberFormat::new(";;;").format_number(1234.5));
+        assert_eq!("0", NumberFormat::new(r#"0;-0;0;"<"@">""#).format_number(0));
+    }
+
+    #[test]
+    fn test_elapsed_time_formats() {
+        let test_data = vec![
+            ("[h]:mm", 1.5, "36:00"),
+            ("[h]:mm:ss", 2.0 + 3661.0 / 86400.0, "49:01:01"),
+            ("[hh]:mm", 0.25, "06:00"),
+            ("[mm]:ss", 1.0 / 24.0 + 5.0 / 86400.0, "60:05"),
+            ("[m]", 1.0, "1440"),
+            ("[ss]", 0.01, "864"),
+            ("[h]:mm", -1.0, "########"),
+        ];
+
+        for (num_format, value, expected) in test_data {
+            assert_eq!(
+                expected,
+                NumberFormat::new(num_format).format_number(value),
+                "format: {num_format}, value: {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_subsecond_formats() {
+        let time = (10.0 * 3600.0 + 20.0 * 60.0 + 30.456) / 86400.0;
+
+        let test_data = vec![
+            ("hh:mm:ss.0", time, "10:20:30.5"),
+            ("hh:mm:ss.00", time, "10:20:30.46"),
+            ("hh:mm:ss.000", time, "10:20:30.456"),
+            ("mm:ss.0", time, "20:30.5"),
+            ("[s].00", 1.5 / 86400.0, "1.50"),
+            ("hh:mm:ss", time, "10:20:30"),
+            ("ss.0", 59.96 / 86400.0, "00.0"),
+            ("dd.mm.yyyy hh:mm:ss.0", 45306.5, "15.01.2024 12:00:00.0"),
+        ];
+
+        for (num_format, value, expected) in test_data {
+            assert_eq!(
+                expected,
+                NumberFormat::new(num_format).format_number(value),
+                "format: {num_format}, value: {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_locale_formats() {
+        let test_data = vec![
+            ("[$$-409]#,##0.00", 1234.5, "$1,234.50"),
+            ("[$€-407]#,##0.00", 1234.5, "€1,234.50"),
+            ("#,##0.00 [$€-407]", 1234.5, "1,234.50 €"),
+            ("[$USD] 0", 5.0, "USD 5"),
+            ("[$-409]mmmm d, yyyy", 45306.0, "January 15, 2024"),
+            (
+                "[$-F800]dddd, mmmm dd, yyyy",
+                45306.0,
+                "Monday, January 15, 2024",
+            ),
+            (
+                "[$-x-sysdate]dddd, mmmm dd, yyyy",
+                45306.0,
+                "Monday, January 15, 2024",
+            ),
+            ("[DBNum1]0", 5.0, "5"),
+        ];
+
+        for (num_format, value, expected) in test_data {
+            assert_eq!(
+                expected,
+                NumberFormat::new(num_format).format_number(value),
+                "format: {num_format}, value: {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_datetime() -> Result<(), XlsxError> {
+        let datetime = ExcelDateTime::from_ymd(2025, 3, 7)?.and_hms(9, 5, 30)?;
+        let num_format = NumberFormat::new("ddd d mmm yyyy h:mm:ss");
+
+        assert_eq!(
+            "Fri 7 Mar 2025 9:05:30",
+            num_format.format_datetime(&datetime)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_non_finite_numbers() {
+        let num_format = NumberFormat::new("0.00");
+
+        assert_eq!("#NUM!", num_format.format_number(f64::NAN));
+        assert_eq!("#NUM!", num_format.format_number(f64::INFINITY));
+    }
+}
@@ -54,6 +54,7 @@ use zip::write::SimpleFileOptions;
 use zip::{DateTime, ZipWriter};
 
 use crate::app::App;
+use crate::chart::Chart;
 use crate::content_types::ContentTypes;
 use crate::core::Core;
 use crate::custom::Custom;
@@ -262,6 +263,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             content_types.add_drawing_name(i + 1);
         }
 
+        for i in 0..options.num_chart_drawings {
+            content_types.add_chart_drawing_name(options.num_drawings + i + 1);
+        }
+
         for i in 0..options.num_charts {
             content_types.add_chart_name(i + 1);
         }
@@ -555,6 +560,38 @@ impl<W: Write + Seek + Send> Packager<W> {
         Ok(())
     }
 
+    // Write a chart rels file.
+    pub(crate) fn write_chart_rels_file(
+        &mut self,
+        chart: &Chart,
+        index: usize,
+    ) -> Result<(), XlsxError> {
+        let mut rels = Relationship::new();
+
+        // The chart shapes drawing is always the first relationship.
+        if chart.user_shapes_drawing_id > 0 {
+            let drawing_id = chart.user_shapes_drawing_id;
+            rels.add_document_relationship(
+                "chartUserShapes",
+                &format!("../drawings/drawing{drawing_id}.xml"),
+                "",
+            );
+        }
+
+        for relationship in &chart.drawing_relationships {
+            rels.add_document_relationship(&relationship.0, &relationship.1, &relationship.2);
+        }
+
+        let filename = format!("xl/charts/_rels/chart{index}.xml.rels");
+
+        self.zip.start_file(filename, self.zip_options)?;
+
+        rels.assemble_xml_file();
+        self.zip.write_all(rels.writer.get_ref())?;
+
+        Ok(())
+    }
+
     // Write a drawing rels file.
     pub(crate) fn write_drawing_rels_file(
         &mut self,
@@ -678,6 +715,8 @@ impl<W: Write + Seek + Send> Packager<W> {
             workbook.has_comments,
             false,
         );
+        styles.table_styles = &workbook.table_styles;
+        styles.named_styles = &workbook.named_styles;
 
         self.zip.start_file("xl/styles.xml", self.zip_options)?;
 
@@ -989,7 +1028,7 @@ impl<W: Write + Seek + Send> Packager<W> {
                 }
             }
 
-            for image in worksheet.images.values() {
+            for image in worksheet.drawing_images() {
                 if !unique_worksheet_images.contains(&image.hash) {
                     let filename =
                         format!("xl/media/image{index}.{}", image.image_type.extension());
@@ -1024,11 +1063,27 @@ impl<W: Write + Seek + Send> Packager<W> {
         let mut index = 1;
 
         for worksheet in &mut workbook.worksheets {
-            for chart in worksheet.charts.values_mut() {
+            for chart in worksheet.all_charts_mut() {
                 let filename = format!("xl/charts/chart{index}.xml");
                 self.zip.start_file(filename, self.zip_options)?;
                 chart.assemble_xml_file();
                 self.zip.write_all(chart.writer.get_ref())?;
+
+                // Write the drawing file for shapes in the chart.
+                if chart.user_shapes_drawing_id > 0 {
+                    let drawing_id = chart.user_shapes_drawing_id;
+                    let filename = format!("xl/drawings/drawing{drawing_id}.xml");
+                    self.zip.start_file(filename, self.zip_options)?;
+
+                    let mut drawing = chart.user_shapes_drawing();
+                    drawing.assemble_xml_file();
+                    self.zip.write_all(drawing.writer.get_ref())?;
+                }
+
+                if chart.user_shapes_drawing_id > 0 || !chart.drawing_relationships.is_empty() {
+                    self.write_chart_rels_file(chart, index)?;
+                }
+
                 index += 1;
             }
         }
@@ -1119,6 +1174,7 @@ pub(crate) struct PackagerOptions {
     pub(crate) num_worksheets: u16,
     pub(crate) num_chartsheets: u16,
     pub(crate) num_drawings: u16,
+    pub(crate) num_chart_drawings: u16,
     pub(crate) num_charts: u16,
     pub(crate) num_tables: u16,
     pub(crate) num_comments: u16,
@@ -1146,6 +1202,7 @@ impl PackagerOptions {
             num_worksheets: 0,
             num_chartsheets: 0,
             num_drawings: 0,
+            num_chart_drawings: 0,
             num_charts: 0,
             num_tables: 0,
             num_comments: 0,
@@ -211,6 +211,10 @@ There are some limitations and restrictions when using "constant memory" mode.
 - Functions that set formatting separately from data, such as
   [`Worksheet::set_cell_format()`](crate::Worksheet::set_cell_format), will
   only work on the current row.
+- Chart data caches are only populated for worksheet ranges that are declared
+  before the data is written. This is done automatically for charts inserted
+  into the same worksheet prior to writing the data. For other cases, see
+  [`Worksheet::retain_chart_data()`](crate::Worksheet::retain_chart_data).
 
 [tempfile]: https://crates.io/crates/tempfile
 
@@ -0,0 +1,361 @@
+// record_batch - A module for writing Arrow record batches to worksheets.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use arrow_array::timezone::Tz;
+use arrow_schema::{DataType, Field};
+
+use crate::data_options::{
+    decimal_num_format, ColumnProperties, DataOptions, DATETIME_NUM_FORMAT, DATE_NUM_FORMAT,
+    DURATION_NUM_FORMAT, TIME_NUM_FORMAT,
+};
+use crate::{Format, Table, XlsxError};
+
+/// The `RecordBatchOptions` struct is used to set options for writing Arrow
+/// record batches.
+///
+/// `RecordBatchOptions` is used in conjunction with
+/// [`Worksheet::write_record_batch_with_options()`](crate::Worksheet::write_record_batch_with_options)
+/// and
+/// [`Worksheet::write_record_batches_with_options()`](crate::Worksheet::write_record_batches_with_options)
+/// to control how Apache Arrow [`RecordBatch`] data is written to a worksheet.
+/// It can be used to set or hide the headers, wrap the data in a worksheet
+/// [`Table`], override the default number formats for each data type or
+/// column, and handle null values.
+///
+/// The default number formats used for Arrow data types are:
+///
+/// | Arrow type             | Excel number format       |
+/// | :--------------------- | :------------------------ |
+/// | `Date32`, `Date64`     | `yyyy-mm-dd;@`            |
+/// | `Timestamp`            | `yyyy-mm-dd hh:mm:ss`     |
+/// | `Time32`, `Time64`     | `hh:mm:ss;@`              |
+/// | `Duration`             | `[h]:mm:ss`               |
+/// | `Decimal128`           | `0.00`, based on scale    |
+///
+/// Other numeric types are written without a number format. `Utf8`,
+/// `LargeUtf8` and `Utf8View` data is written as Excel strings and `Boolean`
+/// data is written as Excel booleans. `Dictionary` data is written, and
+/// formatted, according to the type of the dictionary values.
+///
+/// Excel doesn't have a time zone aware datetime type so Arrow `Timestamp`
+/// values with a time zone are converted to the local time in that zone. For
+/// example a value of `2024-01-01T12:00:00Z` with a time zone of
+/// `Europe/Berlin` is written as `2024-01-01 13:00:00`.
+///
+/// Float `NaN` and `Inf` values are handled in the same way as they are for
+/// [`Worksheet::write_number()`](crate::Worksheet::write_number()). See
+/// [`Worksheet::set_nan_value()`](crate::Worksheet::set_nan_value()) to
+/// change the default strings that are used.
+///
+/// [`RecordBatch`]:
+///     https://docs.rs/arrow-array/latest/arrow_array/struct.RecordBatch.html
+///
+/// # Examples
+///
+/// The following example demonstrates writing an Arrow record batch to a
+/// worksheet table with some custom formatting.
+///
+/// ```
+/// # // This code is available in examples/doc_record_batch_options.rs
+/// #
+/// use std::sync::Arc;
+///
+/// use arrow_array::{ArrayRef, Float64Array, RecordBatch, StringArray};
+/// use rust_xlsxwriter::{Format, RecordBatchOptions, Table, Workbook, XlsxError};
+///
+/// fn main() -> Result<(), XlsxError> {
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.add_worksheet();
+///
+///     // Create a sample record batch.
+///     let regions: ArrayRef = Arc::new(StringArray::from(vec!["North", "South", "East", "West"]));
+///     let sales: ArrayRef = Arc::new(Float64Array::from(vec![1000.0, 2300.5, 1800.0, 2750.25]));
+///
+///     let batch = RecordBatch::try_from_iter(vec![("Region", regions), ("Sales", sales)])?;
+///
+///     // Set some options for the record batch.
+///     let options = RecordBatchOptions::new()
+///         .set_table(Table::new())
+///         .set_column_format("Sales", Format::new().set_num_format("#,##0.00"))
+///         .set_autofit(true);
+///
+///     // Write the record batch to the worksheet.
+///     worksheet.write_record_batch_with_options(&batch, 0, 0, &options)?;
+///
+///     // Save the file to disk.
+///     workbook.save("record_batch.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+#[derive(Clone)]
+#[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+pub struct RecordBatchOptions {
+    pub(crate) core: DataOptions<DataType>,
+}
+
+impl Default for RecordBatchOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RecordBatchOptions {
+    /// Create a new `RecordBatchOptions` object.
+    ///
+    /// Create a `RecordBatchOptions` struct to be used with the
+    /// [`Worksheet::write_record_batch_with_options()`](crate::Worksheet::write_record_batch_with_options)
+    /// and
+    /// [`Worksheet::write_record_batches_with_options()`](crate::Worksheet::write_record_batches_with_options)
+    /// methods.
+    ///
+    pub fn new() -> RecordBatchOptions {
+        RecordBatchOptions {
+            core: DataOptions::new(),
+        }
+    }
+
+    /// Hide the record batch column headers.
+    ///
+    /// By default the record batch schema field names are written as headers
+    /// above the data. This option can be used to write the data only.
+    ///
+    /// If the data is also written as a [`Table`] then the table header row is
+    /// also turned off.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn hide_headers(mut self, enable: bool) -> RecordBatchOptions {
+        self.core.has_headers = !enable;
+        self
+    }
+
+    /// Set the format for the record batch column headers.
+    ///
+    /// See [`Format`] for more information on formatting.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the headers.
+    ///
+    pub fn set_header_format(mut self, format: impl Into<Format>) -> RecordBatchOptions {
+        self.core.header_format = Some(format.into());
+        self
+    }
+
+    /// Write the record batch data as a worksheet table.
+    ///
+    /// Add a worksheet [`Table`] around the record batch data. The table range
+    /// is calculated from the total number of rows written and the table
+    /// headers are taken from the schema field names.
+    ///
+    /// If the table has a total row it is added below the data.
+    ///
+    /// # Parameters
+    ///
+    /// - `table`: A [`Table`] object with the required properties set.
+    ///
+    pub fn set_table(mut self, table: impl Into<Table>) -> RecordBatchOptions {
+        self.core.table = Some(table.into());
+        self
+    }
+
+    /// Autofit the worksheet columns after the record batch data is written.
+    ///
+    /// This is equivalent to calling
+    /// [`Worksheet::autofit()`](crate::Worksheet::autofit) after the data has
+    /// been written. Note, this applies to all of the data in the worksheet
+    /// and not just the record batch data. In constant memory mode it only
+    /// applies to the data in the current, unflushed, row.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_autofit(mut self, enable: bool) -> RecordBatchOptions {
+        self.core.autofit = enable;
+        self
+    }
+
+    /// Set the string value used to represent null values.
+    ///
+    /// By default Arrow null values are ignored and the corresponding cell is
+    /// left blank, or written as a formatted blank cell if the column has a
+    /// format. This option can be used to write a string such as `"NULL"` or
+    /// `"N/A"` instead.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The string to use for null values.
+    ///
+    pub fn set_null_value(mut self, value: impl Into<String>) -> RecordBatchOptions {
+        self.core.null_value = Some(value.into());
+        self
+    }
+
+    /// Set the format for a named record batch column.
+    ///
+    /// This format overrides any default or data type format for the data
+    /// cells in the column. The header cell isn't affected.
+    ///
+    /// # Parameters
+    ///
+    /// - `column_name`: The name of the schema field for the column.
+    /// - `format`: The [`Format`] property for the column data.
+    ///
+    pub fn set_column_format(
+        mut self,
+        column_name: impl Into<String>,
+        format: impl Into<Format>,
+    ) -> RecordBatchOptions {
+        self.core
+            .column_formats
+            .insert(column_name.into(), format.into());
+        self
+    }
+
+    /// Set the format for an Arrow data type.
+    ///
+    /// This format overrides the default format for columns of the
+    /// corresponding data type. The data type is matched on its kind only, so
+    /// for example `DataType::Timestamp(TimeUnit::Millisecond, None)` also
+    /// matches timestamp columns with other time units or time zones.
+    /// Dictionary columns are matched on the type of the dictionary values.
+    ///
+    /// # Parameters
+    ///
+    /// - `dtype`: An Arrow [`DataType`].
+    /// - `format`: The [`Format`] property for the data type.
+    ///
+    /// [`DataType`]:
+    ///     https://docs.rs/arrow-schema/latest/arrow_schema/enum.DataType.html
+    ///
+    pub fn set_dtype_format(
+        mut self,
+        dtype: DataType,
+        format: impl Into<Format>,
+    ) -> RecordBatchOptions {
+        self.core.set_dtype_format(dtype, format.into());
+
+        self
+    }
+
+    // -----------------------------------------------------------------------
+    // Crate level helper methods.
+    // -----------------------------------------------------------------------
+
+    // Get the properties used to write the data cells of a record batch
+    // column, and check that the column data type is supported.
+    pub(crate) fn column_properties(
+        &self,
+        field: &Field,
+    ) -> Result<ColumnProperties<Tz>, XlsxError> {
+        let dtype = value_type(field.data_type());
+
+        if !is_supported_type(dtype) {
+            return Err(XlsxError::ParameterError(format!(
+                "Arrow data type '{}' in column '{}' is not supported by Excel",
+                field.data_type(),
+                field.name()
+            )));
+        }
+
+        let timezone = match dtype {
+            DataType::Timestamp(_, Some(timezone)) => match timezone.parse::<Tz>() {
+                Ok(timezone) => Some(timezone),
+                Err(_) => {
+                    return Err(XlsxError::ParameterError(format!(
+                        "Unknown Arrow timestamp time zone '{timezone}' in column '{}'",
+                        field.name()
+                    )));
+                }
+            },
+            _ => None,
+        };
+
+        Ok(ColumnProperties {
+            format: self.column_format(field.name(), dtype),
+            timezone,
+        })
+    }
+
+    // Get the format for the data cells in a column, in order of precedence:
+    // user column format, user data type format, default data type format.
+    pub(crate) fn column_format(&self, column_name: &str, dtype: &DataType) -> Option<Format> {
+        let dtype = value_type(dtype);
+
+        self.core
+            .column_format(column_name, dtype, Self::default_dtype_format(dtype))
+    }
+
+    // Get the default Excel number format for an Arrow data type, if any.
+    pub(crate) fn default_dtype_format(dtype: &DataType) -> Option<Format> {
+        match value_type(dtype) {
+            DataType::Date32 | DataType::Date64 => {
+                Some(Format::new().set_num_format(DATE_NUM_FORMAT))
+            }
+            DataType::Timestamp(_, _) => Some(Format::new().set_num_format(DATETIME_NUM_FORMAT)),
+            DataType::Time32(_) | DataType::Time64(_) => {
+                Some(Format::new().set_num_format(TIME_NUM_FORMAT))
+            }
+            DataType::Duration(_) => Some(Format::new().set_num_format(DURATION_NUM_FORMAT)),
+            DataType::Decimal128(_, scale) => {
+                decimal_num_format(usize::try_from(*scale).unwrap_or(0))
+            }
+            _ => None,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------
+// Helper functions.
+// -----------------------------------------------------------------------
+
+// Get the type of the values in a column. For dictionary columns this is the
+// type of the dictionary values.
+fn value_type(dtype: &DataType) -> &DataType {
+    match dtype {
+        DataType::Dictionary(_, values) => value_type(values),
+        _ => dtype,
+    }
+}
+
+// Check if an Arrow data type can be written to Excel.
+fn is_supported_type(dtype: &DataType) -> bool {
+    matches!(
+        dtype,
+        DataType::Null
+            | DataType::Boolean
+            | DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float16
+            | DataType::Float32
+            | DataType::Float64
+            | DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Utf8View
+            | DataType::Date32
+            | DataType::Date64
+            | DataType::Timestamp(_, _)
+            | DataType::Time32(_)
+            | DataType::Time64(_)
+            | DataType::Duration(_)
+            | DataType::Decimal128(_, _)
+    )
+}
@@ -0,0 +1,184 @@
+// RecordBatch unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod record_batch_tests {
+
+    use std::sync::Arc;
+
+    use crate::{Format, RecordBatchOptions, Table, TableColumn, Worksheet, XlsxError};
+    use arrow_array::{ArrayRef, BinaryArray, Int32Array, RecordBatch, TimestampSecondArray};
+    use arrow_schema::{DataType, TimeUnit};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_default_dtype_formats() {
+        let tests = [
+            (DataType::Date32, Some("yyyy\\-mm\\-dd;@")),
+            (DataType::Date64, Some("yyyy\\-mm\\-dd;@")),
+            (
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+                Some("yyyy\\-mm\\-dd\\ hh:mm:ss"),
+            ),
+            (
+                DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into())),
+                Some("yyyy\\-mm\\-dd\\ hh:mm:ss"),
+            ),
+            (DataType::Time32(TimeUnit::Second), Some("hh:mm:ss;@")),
+            (DataType::Time64(TimeUnit::Nanosecond), Some("hh:mm:ss;@")),
+            (DataType::Duration(TimeUnit::Microsecond), Some("[h]:mm:ss")),
+            (DataType::Decimal128(10, 3), Some("0.000")),
+            (DataType::Decimal128(10, 0), None),
+            (
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Date32)),
+                Some("yyyy\\-mm\\-dd;@"),
+            ),
+            (DataType::Int64, None),
+            (DataType::Float64, None),
+            (DataType::Utf8, None),
+            (DataType::Boolean, None),
+        ];
+
+        for (dtype, expected) in tests {
+            let format = RecordBatchOptions::default_dtype_format(&dtype);
+            let got = format.map(|format| format.num_format);
+
+            assert_eq!(expected.map(String::from), got, "{dtype:?}");
+        }
+    }
+
+    #[test]
+    fn test_column_format_precedence() {
+        let column_format = Format::new().set_num_format("0.000");
+        let dtype_format = Format::new().set_num_format("dd/mm/yyyy");
+
+        let options = RecordBatchOptions::new()
+            .set_column_format("Sales", &column_format)
+            .set_dtype_format(DataType::Date32, &dtype_format);
+
+        // Column format overrides everything else.
+        let got = options.column_format("Sales", &DataType::Date32);
+        assert_eq!(Some(column_format.clone()), got);
+
+        // Dtype format overrides the default.
+        let got = options.column_format("Other", &DataType::Date32);
+        assert_eq!(Some(dtype_format.clone()), got);
+
+        // Dictionary columns match on the value type.
+        let dtype = DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Date32));
+        let got = options.column_format("Other", &dtype);
+        assert_eq!(Some(dtype_format.clone()), got);
+
+        // Default format.
+        let dtype = DataType::Time32(TimeUnit::Second);
+        let got = options.column_format("Other", &dtype);
+        assert_eq!(RecordBatchOptions::default_dtype_format(&dtype), got);
+
+        // No format.
+        let got = options.column_format("Other", &DataType::Int32);
+        assert_eq!(None, got);
+    }
+
+    #[test]
+    fn test_dtype_format_matches_kind() {
+        let format1 = Format::new().set_num_format("yyyy-mm-dd hh:mm");
+        let format2 = Format::new().set_num_format("dd/mm/yyyy hh:mm");
+
+        let options = RecordBatchOptions::new()
+            .set_dtype_format(DataType::Timestamp(TimeUnit::Millisecond, None), &format1);
+
+        let dtype = DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()));
+        let got = options.column_format("Date", &dtype);
+        assert_eq!(Some(format1), got);
+
+        // A second format for the same kind of data type replaces the first.
+        let options =
+            options.set_dtype_format(DataType::Timestamp(TimeUnit::Second, None), &format2);
+
+        assert_eq!(1, options.core.dtype_formats.len());
+        let got = options.column_format("Date", &dtype);
+        assert_eq!(Some(format2), got);
+    }
+
+    #[test]
+    fn test_write_record_batch_limits() {
+        let mut worksheet = Worksheet::new();
+        let array: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let batch = RecordBatch::try_from_iter(vec![("col1", array)]).unwrap();
+
+        let result = worksheet.write_record_batch(&batch, 1_048_574, 0);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.write_record_batch(&batch, 1_048_573, 16_384);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        let result = worksheet.write_record_batch(&batch, 1_048_572, 16_383);
+        assert!(result.is_ok());
+
+        // An empty sequence of record batches is ignored.
+        let batches: Vec<RecordBatch> = vec![];
+        let result = worksheet.write_record_batches(batches, 1_048_576, 0);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_write_record_batch_unsupported_type() {
+        let mut worksheet = Worksheet::new();
+        let array: ArrayRef = Arc::new(BinaryArray::from(vec![b"abc".as_slice()]));
+        let batch = RecordBatch::try_from_iter(vec![("col1", array)]).unwrap();
+
+        let result = worksheet.write_record_batch(&batch, 0, 0);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn test_write_record_batch_unknown_timezone() {
+        let mut worksheet = Worksheet::new();
+        let array: ArrayRef =
+            Arc::new(TimestampSecondArray::from(vec![0]).with_timezone("Mars/Olympus_Mons"));
+        let batch = RecordBatch::try_from_iter(vec![("col1", array)]).unwrap();
+
+        let result = worksheet.write_record_batch(&batch, 0, 0);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn test_write_record_batches_schema_mismatch() {
+        let mut worksheet = Worksheet::new();
+        let array1: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let array2: ArrayRef = Arc::new(TimestampSecondArray::from(vec![1, 2, 3]));
+        let batch1 = RecordBatch::try_from_iter(vec![("col1", array1)]).unwrap();
+        let batch2 = RecordBatch::try_from_iter(vec![("col1", array2)]).unwrap();
+
+        let result = worksheet.write_record_batches([&batch1, &batch2], 0, 0);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn test_write_record_batch_table_headers() {
+        let mut worksheet = Worksheet::new();
+        let array1: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let array2: ArrayRef = Arc::new(Int32Array::from(vec![4, 5, 6]));
+        let batch = RecordBatch::try_from_iter(vec![("col1", array1), ("col2", array2)]).unwrap();
+
+        // User defined table column names take precedence.
+        let columns = vec![TableColumn::new().set_header("Renamed")];
+        let table = Table::new().set_columns(&columns);
+        let options = RecordBatchOptions::new().set_table(table);
+
+        worksheet
+            .write_record_batch_with_options(&batch, 0, 0, &options)
+            .unwrap();
+
+        let got: Vec<&str> = worksheet.tables[0]
+            .columns
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect();
+
+        assert_eq!(vec!["Renamed", "col2"], got);
+    }
+}
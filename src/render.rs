@@ -0,0 +1,1754 @@
+// render - A module for rendering worksheets to PDF documents.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+// The render module lays out the printed pages of a worksheet and writes them
+// to a PDF document. The layout is an approximation of Excel's print output
+// for use in previews.
+//
+// Text is rendered using the PDF standard fonts so that no font files need to
+// be embedded. Fonts are mapped to the closest standard font: serif fonts such
+// as "Times New Roman" to Times, monospace fonts such as "Consolas" to Courier
+// and all other fonts, including the default "Calibri", to Helvetica.
+
+mod tests;
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::time::SystemTime;
+
+use crate::font_metrics::{
+    HELVETICA_BOLD_WIDTHS, HELVETICA_WIDTHS, TIMES_BOLD_WIDTHS, TIMES_WIDTHS,
+};
+use crate::number_format::NumberFormat;
+use crate::{
+    ColNum, Color, Format, FormatAlign, FormatBorder, FormatDiagonalBorder, FormatPattern,
+    FormatUnderline, RowNum, XlsxError,
+};
+
+// Padding between the cell edges and the text, in points.
+const CELL_PADDING: f64 = 1.5;
+
+// The width of an indent level, in points.
+const INDENT_WIDTH: f64 = 7.5;
+
+// -----------------------------------------------------------------------
+// Render data.
+// -----------------------------------------------------------------------
+
+// The data and properties of a worksheet required to render its printed pages.
+// This is populated by the worksheet.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RenderSheet {
+    pub(crate) name: String,
+
+    // The cell range to print as (first_row, first_col, last_row, last_col).
+    // This is `None` if the worksheet is empty.
+    pub(crate) range: Option<(RowNum, ColNum, RowNum, ColNum)>,
+
+    pub(crate) cells: HashMap<(RowNum, ColNum), RenderCell>,
+    pub(crate) formats: Vec<Format>,
+
+    // The column widths and row heights, in points, for the cells in the
+    // print range. Hidden rows and columns have a size of 0.
+    pub(crate) col_widths: Vec<f64>,
+    pub(crate) row_heights: Vec<f64>,
+
+    pub(crate) merged_ranges: Vec<(RowNum, ColNum, RowNum, ColNum)>,
+    pub(crate) page_setup: RenderPageSetup,
+}
+
+// A worksheet cell value and an index into the `RenderSheet` formats.
+#[derive(Clone, Debug)]
+pub(crate) struct RenderCell {
+    pub(crate) value: Option<RenderValue>,
+    pub(crate) format_index: usize,
+}
+
+#[derive(Clone, Debug)]
+pub(crate) enum RenderValue {
+    Number(f64),
+    String(String),
+    Boolean(bool),
+}
+
+// The worksheet page setup properties that affect the printed output.
+#[derive(Clone, Debug)]
+pub(crate) struct RenderPageSetup {
+    pub(crate) paper_size: u8,
+    pub(crate) is_portrait: bool,
+    pub(crate) margin_left: f64,
+    pub(crate) margin_right: f64,
+    pub(crate) margin_top: f64,
+    pub(crate) margin_bottom: f64,
+    pub(crate) margin_header: f64,
+    pub(crate) margin_footer: f64,
+    pub(crate) header: String,
+    pub(crate) footer: String,
+    pub(crate) print_scale: u16,
+    pub(crate) fit_to_page: bool,
+    pub(crate) fit_width: u16,
+    pub(crate) fit_height: u16,
+    pub(crate) center_horizontally: bool,
+    pub(crate) center_vertically: bool,
+    pub(crate) print_gridlines: bool,
+    pub(crate) down_then_over: bool,
+    pub(crate) horizontal_breaks: Vec<u32>,
+    pub(crate) vertical_breaks: Vec<u32>,
+    pub(crate) first_page_number: u16,
+}
+
+impl Default for RenderPageSetup {
+    fn default() -> Self {
+        RenderPageSetup {
+            paper_size: 0,
+            is_portrait: true,
+            margin_left: 0.7,
+            margin_right: 0.7,
+            margin_top: 0.75,
+            margin_bottom: 0.75,
+            margin_header: 0.3,
+            margin_footer: 0.3,
+            header: String::new(),
+            footer: String::new(),
+            print_scale: 100,
+            fit_to_page: false,
+            fit_width: 1,
+            fit_height: 1,
+            center_horizontally: false,
+            center_vertically: false,
+            print_gridlines: false,
+            down_then_over: true,
+            horizontal_breaks: vec![],
+            vertical_breaks: vec![],
+            first_page_number: 0,
+        }
+    }
+}
+
+// A rendered page with its dimensions in points and its PDF content stream.
+#[derive(Clone, Debug)]
+pub(crate) struct RenderPage {
+    pub(crate) width: f64,
+    pub(crate) height: f64,
+    pub(crate) content: String,
+}
+
+// -----------------------------------------------------------------------
+// Public (crate) functions.
+// -----------------------------------------------------------------------
+
+// Render the printed pages of one or more worksheets to a PDF document.
+pub(crate) fn render_to_pdf(sheets: &[RenderSheet]) -> Result<Vec<u8>, XlsxError> {
+    let mut pages = vec![];
+    for sheet in sheets {
+        pages.extend(render_pages(sheet)?);
+    }
+
+    Ok(write_pdf(&pages))
+}
+
+// Lay out the printed pages of a worksheet.
+pub(crate) fn render_pages(sheet: &RenderSheet) -> Result<Vec<RenderPage>, XlsxError> {
+    let page_setup = &sheet.page_setup;
+    let (mut page_width, mut page_height) = paper_dimensions(page_setup.paper_size);
+    if !page_setup.is_portrait {
+        std::mem::swap(&mut page_width, &mut page_height);
+    }
+
+    let Some((first_row, first_col, _, _)) = sheet.range else {
+        // Excel doesn't print empty worksheets but a document needs at least
+        // one page so we return a page with just the header and footer.
+        let mut content = String::new();
+        write_header_footer(&mut content, sheet, page_width, page_height, 1, 1)?;
+
+        return Ok(vec![RenderPage {
+            width: page_width,
+            height: page_height,
+            content,
+        }]);
+    };
+
+    let printable_width = page_width - (page_setup.margin_left + page_setup.margin_right) * 72.0;
+    let printable_height = page_height - (page_setup.margin_top + page_setup.margin_bottom) * 72.0;
+
+    let total_width: f64 = sheet.col_widths.iter().sum();
+    let total_height: f64 = sheet.row_heights.iter().sum();
+
+    let scale = print_scale(
+        page_setup,
+        printable_width,
+        printable_height,
+        total_width,
+        total_height,
+    );
+
+    // Excel ignores manual page breaks when fitting the data to pages.
+    let (horizontal_breaks, vertical_breaks) = if page_setup.fit_to_page {
+        (&[][..], &[][..])
+    } else {
+        (
+            &page_setup.horizontal_breaks[..],
+            &page_setup.vertical_breaks[..],
+        )
+    };
+
+    let col_breaks: Vec<usize> = vertical_breaks
+        .iter()
+        .filter(|&&col| col > u32::from(first_col))
+        .map(|&col| (col - u32::from(first_col)) as usize)
+        .collect();
+
+    let row_breaks: Vec<usize> = horizontal_breaks
+        .iter()
+        .filter(|&&row| row > first_row)
+        .map(|&row| (row - first_row) as usize)
+        .collect();
+
+    let col_pages = paginate(&sheet.col_widths, printable_width / scale, &col_breaks);
+    let row_pages = paginate(&sheet.row_heights, printable_height / scale, &row_breaks);
+
+    let mut page_areas = vec![];
+    if page_setup.down_then_over {
+        for &cols in &col_pages {
+            for &rows in &row_pages {
+                page_areas.push((rows, cols));
+            }
+        }
+    } else {
+        for &rows in &row_pages {
+            for &cols in &col_pages {
+                page_areas.push((rows, cols));
+            }
+        }
+    }
+
+    let layout = Layout::new(sheet);
+    let total_pages = page_areas.len();
+    let mut pages = vec![];
+
+    for (index, (rows, cols)) in page_areas.into_iter().enumerate() {
+        let area_width = layout.col_x[cols.1 + 1] - layout.col_x[cols.0];
+        let area_height = layout.row_y[rows.1 + 1] - layout.row_y[rows.0];
+
+        let mut origin_x = page_setup.margin_left * 72.0;
+        let mut origin_y = page_height - page_setup.margin_top * 72.0;
+
+        if page_setup.center_horizontally {
+            origin_x += (printable_width - area_width * scale).max(0.0) / 2.0;
+        }
+        if page_setup.center_vertically {
+            origin_y -= (printable_height - area_height * scale).max(0.0) / 2.0;
+        }
+
+        let mut content = String::new();
+        let _ = writeln!(
+            content,
+            "q {} 0 0 {} {} {} cm",
+            num(scale),
+            num(scale),
+            num(origin_x),
+            num(origin_y)
+        );
+        let _ = writeln!(
+            content,
+            "0 {} {} {} re W n",
+            num(-area_height),
+            num(area_width),
+            num(area_height)
+        );
+
+        let area = PageArea {
+            first_row: rows.0,
+            last_row: rows.1,
+            first_col: cols.0,
+            last_col: cols.1,
+        };
+
+        layout.write_page(&mut content, &area)?;
+        content.push_str("Q\n");
+
+        let page_number = usize::from(page_setup.first_page_number.max(1)) + index;
+        write_header_footer(
+            &mut content,
+            sheet,
+            page_width,
+            page_height,
+            page_number,
+            total_pages,
+        )?;
+
+        pages.push(RenderPage {
+            width: page_width,
+            height: page_height,
+            content,
+        });
+    }
+
+    Ok(pages)
+}
+
+// -----------------------------------------------------------------------
+// Page layout.
+// -----------------------------------------------------------------------
+
+// The first row, first column, last row and last column of a cell or merged
+// range as indices relative to the print range.
+type CellBounds = (usize, usize, usize, usize);
+
+// The range of cells on a page as indices relative to the print range.
+struct PageArea {
+    first_row: usize,
+    last_row: usize,
+    first_col: usize,
+    last_col: usize,
+}
+
+// The cell positions and resolved properties used to draw the pages.
+struct Layout<'a> {
+    sheet: &'a RenderSheet,
+    first_row: RowNum,
+    first_col: ColNum,
+    col_x: Vec<f64>,
+    row_y: Vec<f64>,
+    merged_cells: HashMap<(usize, usize), CellBounds>,
+    num_formats: Vec<NumberFormat>,
+}
+
+impl<'a> Layout<'a> {
+    fn new(sheet: &'a RenderSheet) -> Layout<'a> {
+        let (first_row, first_col, _, _) = sheet.range.unwrap_or_default();
+
+        let mut col_x = vec![0.0];
+        for width in &sheet.col_widths {
+            col_x.push(col_x[col_x.len() - 1] + width);
+        }
+
+        let mut row_y = vec![0.0];
+        for height in &sheet.row_heights {
+            row_y.push(row_y[row_y.len() - 1] + height);
+        }
+
+        // Map each cell in a merged range, relative to the print range, to the
+        // bounds of the range. Ranges are clipped to the print range.
+        let num_rows = sheet.row_heights.len();
+        let num_cols = sheet.col_widths.len();
+        let mut merged_cells = HashMap::new();
+
+        for &(merge_first_row, merge_first_col, merge_last_row, merge_last_col) in
+            &sheet.merged_ranges
+        {
+            if merge_last_row < first_row || merge_last_col < first_col {
+                continue;
+            }
+
+            let bounds = (
+                merge_first_row.saturating_sub(first_row) as usize,
+                usize::from(merge_first_col.saturating_sub(first_col)),
+                ((merge_last_row - first_row) as usize).min(num_rows.saturating_sub(1)),
+                usize::from(merge_last_col - first_col).min(num_cols.saturating_sub(1)),
+            );
+
+            for row in bounds.0..=bounds.2 {
+                for col in bounds.1..=bounds.3 {
+                    merged_cells.insert((row, col), bounds);
+                }
+            }
+        }
+
+        let num_formats = sheet
+            .formats
+            .iter()
+            .map(NumberFormat::from_format)
+            .collect();
+
+        Layout {
+            sheet,
+            first_row,
+            first_col,
+            col_x,
+            row_y,
+            merged_cells,
+            num_formats,
+        }
+    }
+
+    // Get a cell from its indices relative to the print range.
+    fn cell(&self, row: usize, col: usize) -> Option<&RenderCell> {
+        let row = self.first_row + row as RowNum;
+        let col = self.first_col + col as ColNum;
+
+        self.sheet.cells.get(&(row, col))
+    }
+
+    fn format(&self, cell: &RenderCell) -> &Format {
+        &self.sheet.formats[cell.format_index]
+    }
+
+    // Check if a cell can be overwritten by text overflowing from a
+    // neighboring cell.
+    fn is_empty(&self, row: usize, col: usize) -> bool {
+        !self.merged_cells.contains_key(&(row, col))
+            && self
+                .cell(row, col)
+                .map_or(true, |cell| cell.value.is_none())
+    }
+
+    // Get the rectangle of a cell range relative to a page, with the y axis
+    // pointing down.
+    fn rect(&self, area: &PageArea, bounds: CellBounds) -> Rect {
+        let x = self.col_x[bounds.1] - self.col_x[area.first_col];
+        let y = self.row_y[bounds.0] - self.row_y[area.first_row];
+
+        Rect {
+            x,
+            y,
+            width: self.col_x[bounds.3 + 1] - self.col_x[bounds.1],
+            height: self.row_y[bounds.2 + 1] - self.row_y[bounds.0],
+        }
+    }
+
+    // Get the cells on a page along with the range that they cover. Merged
+    // ranges are returned once with the cell at the top left of the range.
+    fn page_cells(&self, area: &PageArea) -> Vec<(usize, usize, CellBounds)> {
+        let mut cells = vec![];
+
+        for row in area.first_row..=area.last_row {
+            for col in area.first_col..=area.last_col {
+                match self.merged_cells.get(&(row, col)) {
+                    Some(&bounds) => {
+                        if row == bounds.0.max(area.first_row)
+                            && col == bounds.1.max(area.first_col)
+                        {
+                            cells.push((bounds.0, bounds.1, bounds));
+                        }
+                    }
+                    None => cells.push((row, col, (row, col, row, col))),
+                }
+            }
+        }
+
+        cells
+    }
+
+    // Draw the cells on a page.
+    fn write_page(&self, content: &mut String, area: &PageArea) -> Result<(), XlsxError> {
+        let page_cells = self.page_cells(area);
+
+        if self.sheet.page_setup.print_gridlines {
+            self.write_gridlines(content, area);
+        }
+
+        // Draw the cell fills.
+        for &(row, col, bounds) in &page_cells {
+            let fill_color = self
+                .cell(row, col)
+                .and_then(|cell| fill_color(self.format(cell)));
+            let is_merged = bounds.0 != bounds.2 || bounds.1 != bounds.3;
+
+            // Cover the gridlines in merged ranges.
+            let fill_color = match fill_color {
+                None if is_merged && self.sheet.page_setup.print_gridlines => Some((1.0, 1.0, 1.0)),
+                _ => fill_color,
+            };
+
+            if let Some(color) = fill_color {
+                let rect = self.rect(area, bounds);
+                let _ = writeln!(
+                    content,
+                    "{} rg {} {} {} {} re f",
+                    rgb(color),
+                    num(rect.x),
+                    num(-rect.y - rect.height),
+                    num(rect.width),
+                    num(rect.height)
+                );
+            }
+        }
+
+        // Draw the cell text.
+        for &(row, col, bounds) in &page_cells {
+            if let Some(cell) = self.cell(row, col) {
+                if let Some(value) = &cell.value {
+                    self.write_cell_text(content, area, cell, value, bounds)?;
+                }
+            }
+        }
+
+        // Draw the cell borders.
+        for row in area.first_row..=area.last_row {
+            for col in area.first_col..=area.last_col {
+                if let Some(cell) = self.cell(row, col) {
+                    let rect = self.rect(area, (row, col, row, col));
+                    write_borders(content, self.format(cell), &rect);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Draw the worksheet gridlines for a page.
+    fn write_gridlines(&self, content: &mut String, area: &PageArea) {
+        let width = self.col_x[area.last_col + 1] - self.col_x[area.first_col];
+        let height = self.row_y[area.last_row + 1] - self.row_y[area.first_row];
+
+        content.push_str("0.75 0.75 0.75 RG 0.25 w [] 0 d\n");
+
+        for row in area.first_row..=area.last_row + 1 {
+            let y = self.row_y[row] - self.row_y[area.first_row];
+            let _ = writeln!(content, "0 {} m {} {} l S", num(-y), num(width), num(-y));
+        }
+
+        for col in area.first_col..=area.last_col + 1 {
+            let x = self.col_x[col] - self.col_x[area.first_col];
+            let _ = writeln!(content, "{} 0 m {} {} l S", num(x), num(x), num(-height));
+        }
+    }
+
+    // Draw the text of a cell, taking the alignment, wrapping, rotation and
+    // overflow into neighboring cells into account.
+    fn write_cell_text(
+        &self,
+        content: &mut String,
+        area: &PageArea,
+        cell: &RenderCell,
+        value: &RenderValue,
+        bounds: CellBounds,
+    ) -> Result<(), XlsxError> {
+        let format = self.format(cell);
+        let font = PdfFont::from_format(format);
+        let alignment = &format.alignment;
+        let mut text_color = color_to_rgb(format.font.color).unwrap_or((0.0, 0.0, 0.0));
+        let cell_rect = self.rect(area, bounds);
+        let inner_width = cell_rect.width - 2.0 * CELL_PADDING;
+
+        let (text, default_align) = match value {
+            RenderValue::Number(number) => {
+                let num_format = &self.num_formats[cell.format_index];
+                let (text, color) = num_format.format_number_with_color(*number);
+                if let Some(color) = color.and_then(color_to_rgb) {
+                    text_color = color;
+                }
+
+                (
+                    fit_number(&text, *number, num_format, &font, inner_width),
+                    FormatAlign::Right,
+                )
+            }
+            RenderValue::String(string) => (string.clone(), FormatAlign::Left),
+            RenderValue::Boolean(boolean) => {
+                let text = if *boolean { "TRUE" } else { "FALSE" };
+                (text.to_string(), FormatAlign::Center)
+            }
+        };
+
+        if text.is_empty() {
+            return Ok(());
+        }
+
+        let horizontal = match alignment.horizontal {
+            FormatAlign::General => default_align,
+            align => align,
+        };
+
+        let is_merged = bounds.0 != bounds.2 || bounds.1 != bounds.3;
+        let is_string = matches!(value, RenderValue::String(_));
+        let rotation = alignment.rotation;
+
+        // Get the lines of text to display.
+        let lines: Vec<String> = if rotation == 255 {
+            text.chars().map(|char| char.to_string()).collect()
+        } else if alignment.text_wrap && rotation == 0 {
+            wrap_text(&text, &font, inner_width)
+        } else {
+            vec![text.replace('\n', "")]
+        };
+
+        // Get the area that the text is clipped to. Strings that aren't
+        // wrapped can overflow into empty cells to the left or right.
+        let mut clip_rect = cell_rect;
+        let mut text_rect = cell_rect;
+
+        if horizontal == FormatAlign::CenterAcross && !is_merged {
+            let mut last_col = bounds.1;
+            while last_col < self.sheet.col_widths.len() - 1
+                && self.is_empty(bounds.0, last_col + 1)
+                && self.cell(bounds.0, last_col + 1).is_some_and(|cell| {
+                    self.format(cell).alignment.horizontal == FormatAlign::CenterAcross
+                })
+            {
+                last_col += 1;
+            }
+
+            text_rect = self.rect(area, (bounds.0, bounds.1, bounds.0, last_col));
+            clip_rect = text_rect;
+        } else if is_string && !is_merged && !alignment.text_wrap && rotation == 0 {
+            let text_width = font.text_width(&lines[0]) + 2.0 * CELL_PADDING;
+            let (first_col, last_col) = self.overflow_columns(bounds, horizontal, text_width);
+            clip_rect = self.rect(area, (bounds.0, first_col, bounds.0, last_col));
+        }
+
+        let _ = writeln!(
+            content,
+            "q {} {} {} {} re W n",
+            num(clip_rect.x),
+            num(-clip_rect.y - clip_rect.height),
+            num(clip_rect.width),
+            num(clip_rect.height)
+        );
+
+        if rotation != 0 && rotation != 255 {
+            let angle = if rotation > 90 {
+                -f64::from(rotation - 90)
+            } else {
+                f64::from(rotation)
+            };
+
+            write_rotated_text(
+                content,
+                &font,
+                &lines[0],
+                angle,
+                &text_rect,
+                alignment.vertical,
+                text_color,
+            )?;
+        } else {
+            let horizontal = if rotation == 255 {
+                FormatAlign::Center
+            } else {
+                horizontal
+            };
+
+            let indent = f64::from(alignment.indent) * INDENT_WIDTH;
+            let line_height = if rotation == 255 {
+                font.size * 1.1
+            } else {
+                font.size * 1.25
+            };
+
+            let block_height =
+                (lines.len() - 1) as f64 * line_height + font.ascent() + font.descent();
+
+            let first_baseline = match alignment.vertical {
+                FormatAlign::Top => text_rect.y + CELL_PADDING + font.ascent(),
+                FormatAlign::VerticalCenter
+                | FormatAlign::VerticalJustify
+                | FormatAlign::VerticalDistributed => {
+                    text_rect.y + (text_rect.height - block_height) / 2.0 + font.ascent()
+                }
+                _ => {
+                    text_rect.y + text_rect.height
+                        - CELL_PADDING
+                        - font.descent()
+                        - (lines.len() - 1) as f64 * line_height
+                }
+            };
+
+            for (index, line) in lines.iter().enumerate() {
+                let line = if horizontal == FormatAlign::Fill {
+                    fill_line(line, &font, inner_width)
+                } else {
+                    line.clone()
+                };
+
+                let line_width = font.text_width(&line);
+                let x = match horizontal {
+                    FormatAlign::Right => {
+                        text_rect.x + text_rect.width - CELL_PADDING - indent - line_width
+                    }
+                    FormatAlign::Center | FormatAlign::CenterAcross => {
+                        text_rect.x + (text_rect.width - line_width) / 2.0
+                    }
+                    _ => text_rect.x + CELL_PADDING + indent,
+                };
+
+                let baseline = first_baseline + index as f64 * line_height;
+                write_text(content, &font, &line, x, -baseline, text_color)?;
+                write_text_decoration(content, format, &font, x, -baseline, line_width, text_color);
+            }
+        }
+
+        content.push_str("Q\n");
+
+        Ok(())
+    }
+
+    // Get the range of columns that a string can overflow into, based on its
+    // alignment and the neighboring empty cells.
+    fn overflow_columns(
+        &self,
+        bounds: CellBounds,
+        horizontal: FormatAlign,
+        text_width: f64,
+    ) -> (usize, usize) {
+        let row = bounds.0;
+        let num_cols = self.sheet.col_widths.len();
+        let mut first_col = bounds.1;
+        let mut last_col = bounds.1;
+        let width = |first: usize, last: usize| self.col_x[last + 1] - self.col_x[first];
+
+        match horizontal {
+            FormatAlign::Left | FormatAlign::General | FormatAlign::Justify => {
+                while width(first_col, last_col) < text_width
+                    && last_col + 1 < num_cols
+                    && self.is_empty(row, last_col + 1)
+                {
+                    last_col += 1;
+                }
+            }
+            FormatAlign::Right => {
+                while width(first_col, last_col) < text_width
+                    && first_col > 0
+                    && self.is_empty(row, first_col - 1)
+                {
+                    first_col -= 1;
+                }
+            }
+            FormatAlign::Center | FormatAlign::Distributed => {
+                // The overflow is symmetrical so it stops at the first side
+                // that doesn't have an empty cell.
+                while width(first_col, last_col) < text_width
+                    && first_col > 0
+                    && last_col + 1 < num_cols
+                    && self.is_empty(row, first_col - 1)
+                    && self.is_empty(row, last_col + 1)
+                {
+                    first_col -= 1;
+                    last_col += 1;
+                }
+            }
+            _ => {}
+        }
+
+        (first_col, last_col)
+    }
+}
+
+// A rectangle relative to the top left of a page area, with the y axis
+// pointing down.
+#[derive(Clone, Copy, Debug)]
+struct Rect {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+// Get the scale factor for the printed cells based on the print scale or the
+// "fit to pages" options.
+fn print_scale(
+    page_setup: &RenderPageSetup,
+    printable_width: f64,
+    printable_height: f64,
+    total_width: f64,
+    total_height: f64,
+) -> f64 {
+    if !page_setup.fit_to_page {
+        return f64::from(page_setup.print_scale.clamp(10, 400)) / 100.0;
+    }
+
+    let mut scale: f64 = 1.0;
+
+    if page_setup.fit_width > 0 && total_width > 0.0 {
+        scale = scale.min(printable_width * f64::from(page_setup.fit_width) / total_width);
+    }
+
+    if page_setup.fit_height > 0 && total_height > 0.0 {
+        scale = scale.min(printable_height * f64::from(page_setup.fit_height) / total_height);
+    }
+
+    scale.max(0.1)
+}
+
+// Split a sequence of row heights or column widths into pages. The manual
+// breaks are the indices of the rows or columns that start a new page.
+fn paginate(sizes: &[f64], available: f64, breaks: &[usize]) -> Vec<(usize, usize)> {
+    let mut pages = vec![];
+    let mut start = 0;
+    let mut used = 0.0;
+
+    for (index, size) in sizes.iter().enumerate() {
+        let is_break = breaks.contains(&index);
+
+        if index > start && (is_break || used + size > available) {
+            pages.push((start, index - 1));
+            start = index;
+            used = 0.0;
+        }
+
+        used += size;
+    }
+
+    pages.push((start, sizes.len().saturating_sub(1)));
+    pages
+}
+
+// Get the dimensions, in points, of the paper sizes supported by
+// `Worksheet::set_paper_size()`. The default is US Letter.
+fn paper_dimensions(paper_size: u8) -> (f64, f64) {
+    let inches = |width: f64, height: f64| (width * 72.0, height * 72.0);
+    let mm = |width: f64, height: f64| (width * 72.0 / 25.4, height * 72.0 / 25.4);
+
+    match paper_size {
+        3 => inches(11.0, 17.0),
+        4 => inches(17.0, 11.0),
+        5 => inches(8.5, 14.0),
+        6 => inches(5.5, 8.5),
+        7 => inches(7.25, 10.5),
+        8 => mm(297.0, 420.0),
+        9 | 10 => mm(210.0, 297.0),
+        11 => mm(148.0, 210.0),
+        12 | 33 => mm(250.0, 354.0),
+        13 => mm(182.0, 257.0),
+        14 | 41 => inches(8.5, 13.0),
+        15 => mm(215.0, 275.0),
+        16 => inches(10.0, 14.0),
+        17 => inches(11.0, 17.0),
+        19 => inches(3.875, 8.875),
+        20 => inches(4.125, 9.5),
+        21 => inches(4.5, 10.375),
+        22 => inches(4.75, 11.0),
+        23 => inches(5.0, 11.5),
+        24 => inches(17.0, 22.0),
+        25 => inches(22.0, 34.0),
+        26 => inches(34.0, 44.0),
+        27 => mm(110.0, 220.0),
+        28 => mm(324.0, 458.0),
+        29 => mm(229.0, 324.0),
+        30 => mm(162.0, 229.0),
+        31 => mm(114.0, 162.0),
+        32 => mm(114.0, 229.0),
+        34 => mm(176.0, 250.0),
+        35 => mm(176.0, 125.0),
+        36 => mm(110.0, 230.0),
+        37 => inches(3.875, 7.5),
+        38 => inches(3.625, 6.5),
+        39 => inches(14.875, 11.0),
+        40 => inches(8.5, 12.0),
+        _ => inches(8.5, 11.0),
+    }
+}
+
+// Reduce the precision of a "General" number to fit a cell, as Excel does, or
+// replace any number that doesn't fit with "#" characters.
+fn fit_number(
+    text: &str,
+    number: f64,
+    num_format: &NumberFormat,
+    font: &PdfFont,
+    width: f64,
+) -> String {
+    if font.text_width(text) <= width {
+        return text.to_string();
+    }
+
+    if num_format.is_general() && number.abs() < 1e11 && number.fract() != 0.0 {
+        let integer_digits = number.abs().trunc().to_string().len();
+
+        for decimal_places in (0..10usize.saturating_sub(integer_digits)).rev() {
+            let text = NumberFormat::new(&format!("0.{}", "#".repeat(decimal_places)))
+                .format_number(number);
+            let text = text.trim_end_matches('.');
+
+            if font.text_width(text) <= width {
+                return text.to_string();
+            }
+        }
+    }
+
+    let hash_width = font.text_width("#");
+    let count = if hash_width > 0.0 {
+        (width / hash_width).floor().max(1.0) as usize
+    } else {
+        1
+    };
+
+    "#".repeat(count)
+}
+
+// Split text into lines that fit a width, breaking at spaces where possible.
+fn wrap_text(text: &str, font: &PdfFont, width: f64) -> Vec<String> {
+    let mut lines = vec![];
+
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+
+        for word in paragraph.split(' ') {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{line} {word}")
+            };
+
+            if font.text_width(&candidate) <= width || line.is_empty() {
+                line = candidate;
+            } else {
+                lines.push(std::mem::take(&mut line));
+                line = word.to_string();
+            }
+
+            // Break words that are longer than the width.
+            while font.text_width(&line) > width && line.chars().count() > 1 {
+                let mut split = String::new();
+                let mut rest = line.chars().peekable();
+                while let Some(&char) = rest.peek() {
+                    let mut next = split.clone();
+                    next.push(char);
+                    if font.text_width(&next) > width && !split.is_empty() {
+                        break;
+                    }
+                    split = next;
+                    rest.next();
+                }
+
+                let rest: String = rest.collect();
+                if rest.is_empty() {
+                    break;
+                }
+
+                lines.push(split);
+                line = rest;
+            }
+        }
+
+        lines.push(line);
+    }
+
+    lines
+}
+
+// Repeat the text of a cell with "Fill" alignment to fill the cell width.
+fn fill_line(text: &str, font: &PdfFont, width: f64) -> String {
+    let text_width = font.text_width(text);
+    if text_width <= 0.0 {
+        return text.to_string();
+    }
+
+    let count = (width / text_width).floor().max(1.0) as usize;
+    text.repeat(count)
+}
+
+// -----------------------------------------------------------------------
+// Drawing functions.
+// -----------------------------------------------------------------------
+
+// Write a line of text at a baseline position.
+fn write_text(
+    content: &mut String,
+    font: &PdfFont,
+    text: &str,
+    x: f64,
+    y: f64,
+    color: (f64, f64, f64),
+) -> Result<(), XlsxError> {
+    let _ = writeln!(
+        content,
+        "BT {} {} rg 1 0 0 1 {} {} Tm ({}) Tj ET",
+        font.operators(),
+        rgb(color),
+        num(x),
+        num(y),
+        pdf_string(text)?
+    );
+
+    Ok(())
+}
+
+// Write a line of rotated text centered horizontally in a cell.
+fn write_rotated_text(
+    content: &mut String,
+    font: &PdfFont,
+    text: &str,
+    angle: f64,
+    rect: &Rect,
+    vertical: FormatAlign,
+    color: (f64, f64, f64),
+) -> Result<(), XlsxError> {
+    let (sin, cos) = angle.to_radians().sin_cos();
+    let text_width = font.text_width(text);
+    let text_height = font.ascent() + font.descent();
+
+    // The bounding box of the rotated text.
+    let box_height = (text_width * sin).abs() + (text_height * cos).abs();
+
+    let center_x = rect.x + rect.width / 2.0;
+    let center_y = match vertical {
+        FormatAlign::Top => rect.y + CELL_PADDING + box_height / 2.0,
+        FormatAlign::VerticalCenter
+        | FormatAlign::VerticalJustify
+        | FormatAlign::VerticalDistributed => rect.y + rect.height / 2.0,
+        _ => rect.y + rect.height - CELL_PADDING - box_height / 2.0,
+    };
+
+    // Offset the text origin so that the center of the text is at the center
+    // of the bounding box.
+    let middle = (font.ascent() - font.descent()) / 2.0;
+    let origin_x = center_x - (text_width / 2.0 * cos - middle * sin);
+    let origin_y = -center_y - (text_width / 2.0 * sin + middle * cos);
+
+    let _ = writeln!(
+        content,
+        "BT {} {} rg {} {} {} {} {} {} Tm ({}) Tj ET",
+        font.operators(),
+        rgb(color),
+        num(cos),
+        num(sin),
+        num(-sin),
+        num(cos),
+        num(origin_x),
+        num(origin_y),
+        pdf_string(text)?
+    );
+
+    Ok(())
+}
+
+// Write the underline and strikethrough lines for a line of text.
+fn write_text_decoration(
+    content: &mut String,
+    format: &Format,
+    font: &PdfFont,
+    x: f64,
+    y: f64,
+    width: f64,
+    color: (f64, f64, f64),
+) {
+    let thickness = font.size * 0.05;
+    let mut offsets = vec![];
+
+    match format.font.underline {
+        FormatUnderline::Single | FormatUnderline::SingleAccounting => {
+            offsets.push(-font.size * 0.12);
+        }
+        FormatUnderline::Double | FormatUnderline::DoubleAccounting => {
+            offsets.push(-font.size * 0.1);
+            offsets.push(-font.size * 0.22);
+        }
+        FormatUnderline::None => {}
+    }
+
+    if format.font.strikethrough {
+        offsets.push(font.size * 0.28);
+    }
+
+    for offset in offsets {
+        let _ = writeln!(
+            content,
+            "{} RG {} w [] 0 d {} {} m {} {} l S",
+            rgb(color),
+            num(thickness),
+            num(x),
+            num(y + offset),
+            num(x + width),
+            num(y + offset)
+        );
+    }
+}
+
+// Write the borders of a cell.
+fn write_borders(content: &mut String, format: &Format, rect: &Rect) {
+    let borders = &format.borders;
+    let left = rect.x;
+    let right = rect.x + rect.width;
+    let top = -rect.y;
+    let bottom = -rect.y - rect.height;
+
+    let edges = [
+        (
+            borders.top_style,
+            borders.top_color,
+            (left, top, right, top),
+        ),
+        (
+            borders.bottom_style,
+            borders.bottom_color,
+            (left, bottom, right, bottom),
+        ),
+        (
+            borders.left_style,
+            borders.left_color,
+            (left, top, left, bottom),
+        ),
+        (
+            borders.right_style,
+            borders.right_color,
+            (right, top, right, bottom),
+        ),
+    ];
+
+    for (style, color, line) in edges {
+        write_border_line(content, style, color, line);
+    }
+
+    if borders.diagonal_style != FormatBorder::None {
+        let (style, color) = (borders.diagonal_style, borders.diagonal_color);
+
+        if matches!(
+            borders.diagonal_type,
+            FormatDiagonalBorder::BorderUp | FormatDiagonalBorder::BorderUpDown
+        ) {
+            write_border_line(content, style, color, (left, bottom, right, top));
+        }
+
+        if matches!(
+            borders.diagonal_type,
+            FormatDiagonalBorder::BorderDown | FormatDiagonalBorder::BorderUpDown
+        ) {
+            write_border_line(content, style, color, (left, top, right, bottom));
+        }
+    }
+}
+
+// Write a border line with the width and dash pattern of a border style.
+fn write_border_line(
+    content: &mut String,
+    style: FormatBorder,
+    color: Color,
+    (x1, y1, x2, y2): (f64, f64, f64, f64),
+) {
+    let (width, dash): (f64, &str) = match style {
+        FormatBorder::None => return,
+        FormatBorder::Thin | FormatBorder::Double => (0.5, ""),
+        FormatBorder::Medium => (1.0, ""),
+        FormatBorder::Thick => (1.75, ""),
+        FormatBorder::Hair => (0.5, "0.75 0.75"),
+        FormatBorder::Dotted => (0.5, "1 1"),
+        FormatBorder::Dashed => (0.5, "3 1"),
+        FormatBorder::MediumDashed => (1.0, "4.5 1.5"),
+        FormatBorder::DashDot => (0.5, "4.5 1.5 1.5 1.5"),
+        FormatBorder::MediumDashDot | FormatBorder::SlantDashDot => (1.0, "4.5 1.5 1.5 1.5"),
+        FormatBorder::DashDotDot => (0.5, "4.5 1.5 1.5 1.5 1.5 1.5"),
+        FormatBorder::MediumDashDotDot => (1.0, "4.5 1.5 1.5 1.5 1.5 1.5"),
+    };
+
+    let color = color_to_rgb(color).unwrap_or((0.0, 0.0, 0.0));
+
+    // Double borders are drawn as two thin parallel lines.
+    let offsets: &[f64] = if style == FormatBorder::Double {
+        &[-0.75, 0.75]
+    } else {
+        &[0.0]
+    };
+
+    for offset in offsets {
+        let (dx, dy) = if x1 == x2 {
+            (*offset, 0.0)
+        } else {
+            (0.0, *offset)
+        };
+
+        let _ = writeln!(
+            content,
+            "{} RG {} w [{}] 0 d {} {} m {} {} l S",
+            rgb(color),
+            num(width),
+            dash,
+            num(x1 + dx),
+            num(y1 + dy),
+            num(x2 + dx),
+            num(y2 + dy)
+        );
+    }
+}
+
+// -----------------------------------------------------------------------
+// Headers and footers.
+// -----------------------------------------------------------------------
+
+// Write the header and footer of a page.
+fn write_header_footer(
+    content: &mut String,
+    sheet: &RenderSheet,
+    page_width: f64,
+    page_height: f64,
+    page_number: usize,
+    total_pages: usize,
+) -> Result<(), XlsxError> {
+    let page_setup = &sheet.page_setup;
+    let left = page_setup.margin_left * 72.0;
+    let right = page_width - page_setup.margin_right * 72.0;
+
+    let header = parse_header_footer(&page_setup.header, &sheet.name, page_number, total_pages);
+    for section in &header {
+        let font = PdfFont::new(PdfFontFamily::Helvetica, false, false, section.size);
+        let first_baseline = page_height - page_setup.margin_header * 72.0 - font.ascent();
+
+        for (index, line) in section.lines.iter().enumerate() {
+            let baseline = first_baseline - index as f64 * font.size * 1.25;
+            let x = section.position.x(left, right, font.text_width(line));
+            write_text(content, &font, line, x, baseline, (0.0, 0.0, 0.0))?;
+        }
+    }
+
+    let footer = parse_header_footer(&page_setup.footer, &sheet.name, page_number, total_pages);
+    for section in &footer {
+        let font = PdfFont::new(PdfFontFamily::Helvetica, false, false, section.size);
+        let last_baseline = page_setup.margin_footer * 72.0 + font.descent();
+        let num_lines = section.lines.len();
+
+        for (index, line) in section.lines.iter().enumerate() {
+            let baseline = last_baseline + (num_lines - 1 - index) as f64 * font.size * 1.25;
+            let x = section.position.x(left, right, font.text_width(line));
+            write_text(content, &font, line, x, baseline, (0.0, 0.0, 0.0))?;
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum HeaderPosition {
+    Left,
+    Center,
+    Right,
+}
+
+impl HeaderPosition {
+    // Get the x position of a line of header/footer text.
+    fn x(self, left: f64, right: f64, width: f64) -> f64 {
+        match self {
+            HeaderPosition::Left => left,
+            HeaderPosition::Center => left + (right - left - width) / 2.0,
+            HeaderPosition::Right => right - width,
+        }
+    }
+}
+
+// The text of the left, center or right section of a header or footer.
+#[derive(Clone, Debug, PartialEq)]
+struct HeaderSection {
+    position: HeaderPosition,
+    lines: Vec<String>,
+    size: f64,
+}
+
+// Parse a header or footer string into its sections, replacing the control
+// codes for page numbers, sheet names, dates and times. Font and image codes
+// are ignored.
+fn parse_header_footer(
+    text: &str,
+    sheet_name: &str,
+    page_number: usize,
+    total_pages: usize,
+) -> Vec<HeaderSection> {
+    let mut sections: Vec<HeaderSection> = vec![];
+    let mut position = HeaderPosition::Center;
+    let mut current = String::new();
+    let mut size = 11.0;
+    let mut chars = text.chars().peekable();
+
+    let mut finish_section = |position: HeaderPosition, current: &mut String, size: f64| {
+        if !current.is_empty() {
+            sections.push(HeaderSection {
+                position,
+                lines: current.split('\n').map(str::to_string).collect(),
+                size,
+            });
+            current.clear();
+        }
+    };
+
+    while let Some(char) = chars.next() {
+        if char != '&' {
+            current.push(char);
+            continue;
+        }
+
+        let Some(code) = chars.next() else {
+            break;
+        };
+
+        match code {
+            '&' => current.push('&'),
+            'L' | 'C' | 'R' => {
+                finish_section(position, &mut current, size);
+                size = 11.0;
+                position = match code {
+                    'L' => HeaderPosition::Left,
+                    'C' => HeaderPosition::Center,
+                    _ => HeaderPosition::Right,
+                };
+            }
+            'P' => current.push_str(&page_number.to_string()),
+            'N' => current.push_str(&total_pages.to_string()),
+            'A' => current.push_str(sheet_name),
+            'D' => current.push_str(&current_datetime("m/d/yyyy")),
+            'T' => current.push_str(&current_datetime("h:mm AM/PM")),
+
+            // Font name and style, such as &"Arial,Bold".
+            '"' => {
+                for char in chars.by_ref() {
+                    if char == '"' {
+                        break;
+                    }
+                }
+            }
+
+            // Font color, such as &KFF0000.
+            'K' => {
+                for _ in 0..6 {
+                    chars.next();
+                }
+            }
+
+            // Font size, such as &20.
+            '0'..='9' => {
+                let mut digits = code.to_string();
+                while let Some(&digit) = chars.peek() {
+                    if !digit.is_ascii_digit() {
+                        break;
+                    }
+                    digits.push(digit);
+                    chars.next();
+                }
+                size = digits.parse().unwrap_or(11.0);
+            }
+
+            // Ignore the other codes such as the file name, images and font
+            // styles.
+            _ => {}
+        }
+    }
+
+    finish_section(position, &mut current, size);
+    sections
+}
+
+// Get the current date or time in an Excel number format.
+fn current_datetime(num_format: &str) -> String {
+    let seconds = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|duration| duration.as_secs_f64())
+        .unwrap_or_default();
+
+    // Convert from the Unix epoch to an Excel serial date.
+    let serial_date = seconds / 86_400.0 + 25_569.0;
+
+    NumberFormat::new(num_format).format_number(serial_date)
+}
+
+// -----------------------------------------------------------------------
+// Fonts and colors.
+// -----------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PdfFontFamily {
+    Helvetica,
+    Times,
+    Courier,
+}
+
+// A PDF standard font with a size in points. The horizontal scale is used to
+// match the narrower character widths of fonts such as Calibri.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PdfFont {
+    family: PdfFontFamily,
+    bold: bool,
+    italic: bool,
+    size: f64,
+    scale: f64,
+}
+
+impl PdfFont {
+    fn new(family: PdfFontFamily, bold: bool, italic: bool, size: f64) -> PdfFont {
+        PdfFont {
+            family,
+            bold,
+            italic,
+            size,
+            scale: 1.0,
+        }
+    }
+
+    // Map the font properties of a format to the closest standard font.
+    fn from_format(format: &Format) -> PdfFont {
+        let name = format.font.name.to_ascii_lowercase();
+
+        let family = if ["courier", "consolas", "mono", "lucida console"]
+            .iter()
+            .any(|font| name.contains(font))
+        {
+            PdfFontFamily::Courier
+        } else if [
+            "times",
+            "cambria",
+            "georgia",
+            "garamond",
+            "palatino",
+            "book antiqua",
+            "serif",
+        ]
+        .iter()
+        .any(|font| name.contains(font))
+            && !name.contains("sans")
+        {
+            PdfFontFamily::Times
+        } else {
+            PdfFontFamily::Helvetica
+        };
+
+        let size = format.font.size.parse::<f64>().unwrap_or(11.0);
+        let mut font = PdfFont::new(family, format.font.bold, format.font.italic, size);
+
+        // Calibri, the default Excel font, is around 9% narrower than
+        // Helvetica.
+        if name.contains("calibri") {
+            font.scale = 0.91;
+        }
+
+        font
+    }
+
+    // The index of the font in the PDF font resources.
+    fn index(&self) -> usize {
+        let family = match self.family {
+            PdfFontFamily::Helvetica => 0,
+            PdfFontFamily::Times => 1,
+            PdfFontFamily::Courier => 2,
+        };
+
+        family * 4 + usize::from(self.bold) + 2 * usize::from(self.italic)
+    }
+
+    fn resource_name(&self) -> String {
+        format!("F{}", self.index() + 1)
+    }
+
+    fn ascent(&self) -> f64 {
+        self.size * 0.75
+    }
+
+    fn descent(&self) -> f64 {
+        self.size * 0.22
+    }
+
+    // Get the width of a string in points. Oblique and italic fonts use the
+    // widths of the upright font.
+    fn text_width(&self, text: &str) -> f64 {
+        let widths: Option<&[u16; 95]> = match (self.family, self.bold) {
+            (PdfFontFamily::Helvetica, false) => Some(&HELVETICA_WIDTHS),
+            (PdfFontFamily::Helvetica, true) => Some(&HELVETICA_BOLD_WIDTHS),
+            (PdfFontFamily::Times, false) => Some(&TIMES_WIDTHS),
+            (PdfFontFamily::Times, true) => Some(&TIMES_BOLD_WIDTHS),
+            (PdfFontFamily::Courier, _) => None,
+        };
+
+        let default_width = match self.family {
+            PdfFontFamily::Helvetica => 556,
+            PdfFontFamily::Times => 500,
+            PdfFontFamily::Courier => 600,
+        };
+
+        let units: u32 = text
+            .chars()
+            .map(|char| {
+                let code = char as u32;
+                match widths {
+                    Some(widths) if (32..127).contains(&code) => {
+                        u32::from(widths[(code - 32) as usize])
+                    }
+                    _ => default_width,
+                }
+            })
+            .sum();
+
+        f64::from(units) * self.size * self.scale / 1000.0
+    }
+
+    // Get the PDF operators to select the font in a text object.
+    fn operators(&self) -> String {
+        if self.scale == 1.0 {
+            format!("/{} {} Tf", self.resource_name(), num(self.size))
+        } else {
+            format!(
+                "/{} {} Tf {} Tz",
+                self.resource_name(),
+                num(self.size),
+                num(self.scale * 100.0)
+            )
+        }
+    }
+}
+
+// The names of the PDF standard fonts in the order of `PdfFont::index()`.
+const FONT_NAMES: [&str; 12] = [
+    "Helvetica",
+    "Helvetica-Bold",
+    "Helvetica-Oblique",
+    "Helvetica-BoldOblique",
+    "Times-Roman",
+    "Times-Bold",
+    "Times-Italic",
+    "Times-BoldItalic",
+    "Courier",
+    "Courier-Bold",
+    "Courier-Oblique",
+    "Courier-BoldOblique",
+];
+
+// Get the RGB values, in the range 0.0 to 1.0, of a color. Returns `None` for
+// the default and automatic colors.
+fn color_to_rgb(color: Color) -> Option<(f64, f64, f64)> {
+    if color.is_auto_or_default() {
+        return None;
+    }
+
+    let hex = match color {
+        Color::Theme(theme, _) => {
+            let base = THEME_COLORS.get(usize::from(theme)).copied().unwrap_or(0);
+            let (_, lum_mod, lum_off) = color.chart_scheme();
+            return Some(apply_luminance(base, lum_mod, lum_off));
+        }
+        _ => u32::from_str_radix(&color.rgb_hex_value(), 16).unwrap_or_default(),
+    };
+
+    Some(hex_to_rgb(hex))
+}
+
+// The RGB values of the default Office theme colors.
+const THEME_COLORS: [u32; 10] = [
+    0xFFFFFF, 0x000000, 0xEEECE1, 0x1F497D, 0x4F81BD, 0xC0504D, 0x9BBB59, 0x8064A2, 0x4BACC6,
+    0xF79646,
+];
+
+fn hex_to_rgb(hex: u32) -> (f64, f64, f64) {
+    (
+        f64::from((hex >> 16) & 0xFF) / 255.0,
+        f64::from((hex >> 8) & 0xFF) / 255.0,
+        f64::from(hex & 0xFF) / 255.0,
+    )
+}
+
+// Apply the luminance modulation and offset of a theme color shade. The values
+// are in 1/1000 of a percent.
+fn apply_luminance(hex: u32, lum_mod: u32, lum_off: u32) -> (f64, f64, f64) {
+    let (red, green, blue) = hex_to_rgb(hex);
+    if lum_mod == 0 && lum_off == 0 {
+        return (red, green, blue);
+    }
+
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let lightness = (max + min) / 2.0;
+    let delta = max - min;
+
+    let (hue, saturation) = if delta == 0.0 {
+        (0.0, 0.0)
+    } else {
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == red {
+            ((green - blue) / delta).rem_euclid(6.0)
+        } else if max == green {
+            (blue - red) / delta + 2.0
+        } else {
+            (red - green) / delta + 4.0
+        };
+        (hue * 60.0, saturation)
+    };
+
+    let lightness = (lightness * f64::from(lum_mod) / 100_000.0 + f64::from(lum_off) / 100_000.0)
+        .clamp(0.0, 1.0);
+
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0).rem_euclid(2.0) - 1.0).abs());
+    let m = lightness - chroma / 2.0;
+
+    let (red, green, blue) = match (hue / 60.0) as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    (red + m, green + m, blue + m)
+}
+
+// Get the fill color of a format. Patterned fills are approximated by a blend
+// of the foreground and background colors.
+fn fill_color(format: &Format) -> Option<(f64, f64, f64)> {
+    let fill = &format.fill;
+    let foreground = color_to_rgb(fill.foreground_color);
+    let background = color_to_rgb(fill.background_color);
+
+    match fill.pattern {
+        FormatPattern::None | FormatPattern::Solid => background.or(foreground),
+        _ => {
+            let foreground = foreground.unwrap_or((0.0, 0.0, 0.0));
+            let background = background.unwrap_or((1.0, 1.0, 1.0));
+            Some((
+                (foreground.0 + background.0) / 2.0,
+                (foreground.1 + background.1) / 2.0,
+                (foreground.2 + background.2) / 2.0,
+            ))
+        }
+    }
+}
+
+// -----------------------------------------------------------------------
+// PDF writing.
+// -----------------------------------------------------------------------
+
+// Write the pages to a PDF document. The document uses the standard PDF fonts
+// so no font data needs to be embedded.
+pub(crate) fn write_pdf(pages: &[RenderPage]) -> Vec<u8> {
+    let mut objects: Vec<Vec<u8>> = vec![];
+
+    // The object numbers of the fixed objects.
+    let num_fonts = FONT_NAMES.len();
+    let resources_id = 3 + num_fonts;
+    let info_id = resources_id + 1;
+    let first_page_id = info_id + 1;
+
+    let kids: Vec<String> = (0..pages.len())
+        .map(|index| format!("{} 0 R", first_page_id + 2 * index))
+        .collect();
+
+    objects.push(b"<< /Type /Catalog /Pages 2 0 R >>".to_vec());
+    objects.push(
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids.join(" "),
+            pages.len()
+        )
+        .into_bytes(),
+    );
+
+    for name in FONT_NAMES {
+        objects.push(
+            format!(
+                "<< /Type /Font /Subtype /Type1 /BaseFont /{name} /Encoding /WinAnsiEncoding >>"
+            )
+            .into_bytes(),
+        );
+    }
+
+    let fonts: Vec<String> = (0..num_fonts)
+        .map(|index| format!("/F{} {} 0 R", index + 1, index + 3))
+        .collect();
+    objects.push(format!("<< /Font << {} >> >>", fonts.join(" ")).into_bytes());
+
+    objects.push(b"<< /Producer (rust_xlsxwriter) >>".to_vec());
+
+    for (index, page) in pages.iter().enumerate() {
+        let content_id = first_page_id + 2 * index + 1;
+
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources {resources_id} 0 R /Contents {content_id} 0 R >>",
+                num(page.width),
+                num(page.height)
+            )
+            .into_bytes(),
+        );
+
+        let mut stream = format!("<< /Length {} >>\nstream\n", page.content.len()).into_bytes();
+        stream.extend_from_slice(page.content.as_bytes());
+        stream.extend_from_slice(b"\nendstream");
+        objects.push(stream);
+    }
+
+    let mut pdf = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+    let mut offsets = vec![];
+
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+        pdf.extend_from_slice(object);
+        pdf.extend_from_slice(b"\nendobj\n");
+    }
+
+    let xref_offset = pdf.len();
+    let mut xref = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        let _ = writeln!(xref, "{offset:010} 00000 n ");
+    }
+
+    let _ = write!(
+        xref,
+        "trailer\n<< /Size {} /Root 1 0 R /Info {info_id} 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+        objects.len() + 1
+    );
+
+    pdf.extend_from_slice(xref.as_bytes());
+    pdf
+}
+
+// Convert text to a PDF literal string in the WinAnsi encoding used by the
+// standard PDF fonts. Text with characters that can't be encoded, such as CJK
+// or Cyrillic characters, returns an error rather than an incorrect preview.
+fn pdf_string(text: &str) -> Result<String, XlsxError> {
+    let mut string = String::new();
+
+    for char in text.chars() {
+        let code = match char {
+            '€' => 0x80,
+            '‚' => 0x82,
+            'ƒ' => 0x83,
+            '„' => 0x84,
+            '…' => 0x85,
+            '†' => 0x86,
+            '‡' => 0x87,
+            'ˆ' => 0x88,
+            '‰' => 0x89,
+            'Š' => 0x8A,
+            '‹' => 0x8B,
+            'Œ' => 0x8C,
+            'Ž' => 0x8E,
+            '‘' => 0x91,
+            '’' => 0x92,
+            '“' => 0x93,
+            '”' => 0x94,
+            '•' => 0x95,
+            '–' => 0x96,
+            '—' => 0x97,
+            '˜' => 0x98,
+            '™' => 0x99,
+            'š' => 0x9A,
+            '›' => 0x9B,
+            'œ' => 0x9C,
+            'ž' => 0x9E,
+            'Ÿ' => 0x9F,
+            '\u{20}'..='\u{7E}' | '\u{A0}'..='\u{FF}' => char as u32,
+            _ => {
+                return Err(XlsxError::RenderError(format!(
+                    "Character '{char}' in text '{text}' isn't supported by the standard PDF fonts"
+                )))
+            }
+        };
+
+        match code {
+            0x28 => string.push_str("\\("),
+            0x29 => string.push_str("\\)"),
+            0x5C => string.push_str("\\\\"),
+            0x20..=0x7E => string.push(char::from(code as u8)),
+            _ => {
+                let _ = write!(string, "\\{code:03o}");
+            }
+        }
+    }
+
+    Ok(string)
+}
+
+// Format a number for a PDF content stream with up to 3 decimal places.
+fn num(value: f64) -> String {
+    let string = format!("{value:.3}");
+    let string = string.trim_end_matches('0').trim_end_matches('.');
+
+    if string == "-0" {
+        "0".to_string()
+    } else {
+        string.to_string()
+    }
+}
+
+// Format RGB color values for a PDF color operator.
+fn rgb(color: (f64, f64, f64)) -> String {
+    format!("{} {} {}", num(color.0), num(color.1), num(color.2))
+}
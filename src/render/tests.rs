@@ -0,0 +1,395 @@
+// Render unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod render_tests {
+
+    use crate::render::{
+        color_to_rgb, num, paginate, parse_header_footer, pdf_string, render_pages, wrap_text,
+        write_pdf, HeaderPosition, HeaderSection, PdfFont, PdfFontFamily, RenderPage,
+    };
+    use crate::{Color, Format, FormatAlign, FormatBorder, Workbook, Worksheet, XlsxError};
+
+    // Get the text drawn on a page from the PDF text operators.
+    fn page_text(page: &RenderPage) -> Vec<String> {
+        page.content
+            .lines()
+            .filter_map(|line| {
+                let start = line.find(" Tm (")? + 5;
+                let end = line.rfind(") Tj")?;
+                Some(line[start..end].to_string())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_num() {
+        assert_eq!("0", num(0.0));
+        assert_eq!("0", num(-0.0001));
+        assert_eq!("1.5", num(1.5));
+        assert_eq!("-12.346", num(-12.3456));
+        assert_eq!("612", num(612.0));
+    }
+
+    #[test]
+    fn test_pdf_string() -> Result<(), XlsxError> {
+        assert_eq!("Hello", pdf_string("Hello")?);
+        assert_eq!("\\(a\\) \\\\", pdf_string("(a) \\")?);
+        assert_eq!("caf\\351", pdf_string("café")?);
+        assert_eq!("\\200 100", pdf_string("€ 100")?);
+
+        // Characters outside the WinAnsi encoding aren't supported.
+        assert!(matches!(pdf_string("中"), Err(XlsxError::RenderError(_))));
+        assert!(matches!(
+            pdf_string("Привет"),
+            Err(XlsxError::RenderError(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_unsupported_text() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        worksheet.write(0, 0, "Hello")?;
+        assert!(worksheet.save_to_pdf_buffer().is_ok());
+
+        worksheet.write(1, 0, "日本語")?;
+        let result = worksheet.save_to_pdf_buffer();
+        assert!(matches!(result, Err(XlsxError::RenderError(_))));
+
+        // Unsupported characters in the header are also an error.
+        let mut worksheet = Worksheet::new();
+        worksheet.write(0, 0, "Hello")?;
+        worksheet.set_header("&CОтчёт");
+        let result = worksheet.save_to_pdf_buffer();
+        assert!(matches!(result, Err(XlsxError::RenderError(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_text_width() {
+        let font = PdfFont::new(PdfFontFamily::Helvetica, false, false, 10.0);
+        assert_eq!(5.56, font.text_width("0"));
+        assert_eq!(22.78, font.text_width("Hello"));
+
+        let font = PdfFont::new(PdfFontFamily::Courier, true, false, 10.0);
+        assert_eq!(30.0, font.text_width("Hello"));
+
+        let font = PdfFont::from_format(&Format::new().set_font_name("Arial"));
+        assert_eq!(1.0, font.scale);
+        assert_eq!(PdfFontFamily::Helvetica, font.family);
+
+        let font = PdfFont::from_format(&Format::new().set_font_name("Times New Roman"));
+        assert_eq!(PdfFontFamily::Times, font.family);
+
+        let font = PdfFont::from_format(&Format::new().set_font_name("Consolas").set_bold());
+        assert_eq!(PdfFontFamily::Courier, font.family);
+        assert_eq!("F10", font.resource_name());
+    }
+
+    #[test]
+    fn test_paginate() {
+        assert_eq!(vec![(0, 2)], paginate(&[10.0, 10.0, 10.0], 30.0, &[]));
+        assert_eq!(
+            vec![(0, 1), (2, 2)],
+            paginate(&[10.0, 10.0, 10.0], 25.0, &[])
+        );
+        assert_eq!(
+            vec![(0, 0), (1, 2)],
+            paginate(&[10.0, 10.0, 10.0], 30.0, &[1])
+        );
+
+        // A row that is larger than the page is printed on its own page.
+        assert_eq!(vec![(0, 0), (1, 1)], paginate(&[50.0, 10.0], 30.0, &[]));
+    }
+
+    #[test]
+    fn test_wrap_text() {
+        let font = PdfFont::new(PdfFontFamily::Courier, false, false, 10.0);
+
+        // Each character is 6 points wide.
+        assert_eq!(
+            vec!["aaa bbb", "ccc"],
+            wrap_text("aaa bbb ccc", &font, 45.0)
+        );
+        assert_eq!(vec!["aaa", "bbb"], wrap_text("aaa\nbbb", &font, 45.0));
+        assert_eq!(vec!["aaaaaaa", "aaa"], wrap_text("aaaaaaaaaa", &font, 45.0));
+    }
+
+    #[test]
+    fn test_parse_header_footer() {
+        let sections =
+            parse_header_footer("&LPage &P of &N&C&\"Arial,Bold\"&14&A&R&&", "Sheet1", 2, 5);
+
+        assert_eq!(
+            vec![
+                HeaderSection {
+                    position: HeaderPosition::Left,
+                    lines: vec!["Page 2 of 5".to_string()],
+                    size: 11.0,
+                },
+                HeaderSection {
+                    position: HeaderPosition::Center,
+                    lines: vec!["Sheet1".to_string()],
+                    size: 14.0,
+                },
+                HeaderSection {
+                    position: HeaderPosition::Right,
+                    lines: vec!["&".to_string()],
+                    size: 11.0,
+                },
+            ],
+            sections
+        );
+
+        let sections = parse_header_footer("Line 1\nLine 2&KFF0000", "Sheet1", 1, 1);
+        assert_eq!(
+            vec![HeaderSection {
+                position: HeaderPosition::Center,
+                lines: vec!["Line 1".to_string(), "Line 2".to_string()],
+                size: 11.0,
+            }],
+            sections
+        );
+    }
+
+    #[test]
+    fn test_color_to_rgb() {
+        assert_eq!(None, color_to_rgb(Color::Default));
+        assert_eq!(Some((1.0, 0.0, 0.0)), color_to_rgb(Color::Red));
+        assert_eq!(Some((0.0, 0.0, 1.0)), color_to_rgb(Color::RGB(0x0000FF)));
+        assert_eq!(Some((1.0, 1.0, 1.0)), color_to_rgb(Color::Theme(0, 0)));
+
+        // White darkened by 50%.
+        assert_eq!(Some((0.5, 0.5, 0.5)), color_to_rgb(Color::Theme(0, 5)));
+    }
+
+    #[test]
+    fn test_write_pdf() {
+        let pages = vec![
+            RenderPage {
+                width: 612.0,
+                height: 792.0,
+                content: "BT /F1 11 Tf (Hello) Tj ET".to_string(),
+            },
+            RenderPage {
+                width: 792.0,
+                height: 612.0,
+                content: String::new(),
+            },
+        ];
+
+        let pdf = write_pdf(&pages);
+        let text = String::from_utf8_lossy(&pdf);
+
+        assert!(text.starts_with("%PDF-1.4\n"));
+        assert!(text.ends_with("%%EOF\n"));
+        assert!(text.contains("/Kids [17 0 R 19 0 R] /Count 2"));
+        assert!(text.contains("/MediaBox [0 0 792 612]"));
+        assert!(text.contains("<< /Length 26 >>\nstream\nBT /F1 11 Tf (Hello) Tj ET\nendstream"));
+
+        // Check that the cross-reference table offsets point to the objects.
+        let xref_start: usize = text
+            .split("startxref\n")
+            .nth(1)
+            .and_then(|offset| offset.lines().next())
+            .and_then(|offset| offset.parse().ok())
+            .unwrap();
+
+        let xref = String::from_utf8_lossy(&pdf[xref_start..]);
+        assert!(xref.starts_with("xref\n0 21\n"));
+
+        for (index, line) in xref.lines().skip(3).take(20).enumerate() {
+            let offset: usize = line[..10].parse().unwrap();
+            let object = format!("{} 0 obj", index + 1);
+            assert!(pdf[offset..].starts_with(object.as_bytes()));
+        }
+    }
+
+    #[test]
+    fn test_render_cells() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        let bold = Format::new().set_bold();
+        let number_format = Format::new().set_num_format("#,##0.00");
+
+        worksheet.write_with_format(0, 0, "Name", &bold)?;
+        worksheet.write(1, 0, "Total")?;
+        worksheet.write_with_format(1, 1, 1234.5, &number_format)?;
+        worksheet.write(2, 0, true)?;
+        worksheet.write_formula(3, 0, crate::Formula::new("=1+1").set_result("2"))?;
+
+        let pages = render_pages(&worksheet.render_sheet()?)?;
+        assert_eq!(1, pages.len());
+        assert_eq!((612.0, 792.0), (pages[0].width, pages[0].height));
+
+        let text = page_text(&pages[0]);
+        assert_eq!(vec!["Name", "Total", "1,234.50", "TRUE", "2"], text);
+
+        // The bold text uses the Helvetica-Bold font.
+        assert!(pages[0]
+            .content
+            .contains("/F2 11 Tf 91 Tz 0 0 0 rg 1 0 0 1 1.5 -11.08 Tm (Name)"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_numbers_that_dont_fit() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        let number_format = Format::new().set_num_format("#,##0.00");
+
+        worksheet.write_with_format(0, 0, 123_456_789.0, &number_format)?;
+        worksheet.write(1, 0, 1.234_567_890_123_4)?;
+        worksheet.write(2, 0, 1_234_567_890.0)?;
+
+        let pages = render_pages(&worksheet.render_sheet()?)?;
+        let text = page_text(&pages[0]);
+
+        assert_eq!(vec!["########", "1.234568", "########"], text);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_text_overflow() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.write(0, 0, "A long string that overflows")?;
+        worksheet.write(1, 0, "A long string that is clipped")?;
+        worksheet.write(1, 1, "B")?;
+        worksheet.write(1, 2, "C")?;
+
+        let pages = render_pages(&worksheet.render_sheet()?)?;
+
+        // The first string is clipped to the empty cells to the right and the
+        // second string is clipped to its cell.
+        assert!(pages[0].content.contains("q 0 -15 144 15 re W n\nBT"));
+        assert!(pages[0].content.contains("q 0 -30 48 15 re W n\nBT"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_merged_range() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        let format = Format::new()
+            .set_align(FormatAlign::Center)
+            .set_border(FormatBorder::Thin);
+
+        worksheet.merge_range(0, 0, 0, 2, "Merged", &format)?;
+
+        let pages = render_pages(&worksheet.render_sheet()?)?;
+        let content = &pages[0].content;
+
+        // The text is clipped to the merged range and centered in it.
+        assert!(content.contains("q 0 -15 144 15 re W n\nBT"));
+        assert_eq!(vec!["Merged"], page_text(&pages[0]));
+
+        // The borders are drawn around each cell in the range.
+        assert_eq!(12, content.matches(" l S").count());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_page_setup() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+
+        for row in 0..100 {
+            worksheet.write(row, 0, row)?;
+        }
+
+        worksheet.set_paper_size(9);
+        worksheet.set_landscape();
+        worksheet.set_header("&CPage &P of &N");
+        worksheet.set_page_breaks(&[10])?;
+
+        let pages = render_pages(&worksheet.render_sheet()?)?;
+
+        // A4 landscape.
+        assert_eq!(
+            ("841.89", "595.276"),
+            (num(pages[0].width).as_str(), num(pages[0].height).as_str())
+        );
+
+        // The first page ends at the page break. The remaining 90 rows of 15
+        // points are split at the printable height of 487.276 points.
+        assert_eq!(4, pages.len());
+        assert_eq!(11, page_text(&pages[0]).len());
+        assert_eq!(
+            Some(&"Page 1 of 4".to_string()),
+            page_text(&pages[0]).last()
+        );
+        assert_eq!(33, page_text(&pages[1]).len());
+        assert_eq!(
+            Some(&"Page 4 of 4".to_string()),
+            page_text(&pages[3]).last()
+        );
+
+        // Fit the data to 1 page.
+        worksheet.set_print_fit_to_pages(1, 1);
+        worksheet.set_page_breaks(&[])?;
+
+        let pages = render_pages(&worksheet.render_sheet()?)?;
+        assert_eq!(1, pages.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_print_area() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+
+        worksheet.write(0, 0, "A1")?;
+        worksheet.write(1, 1, "B2")?;
+        worksheet.write(2, 2, "C3")?;
+        worksheet.set_print_area(1, 1, 2, 1)?;
+
+        let pages = render_pages(&worksheet.render_sheet()?)?;
+        assert_eq!(vec!["B2"], page_text(&pages[0]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_workbook() -> Result<(), XlsxError> {
+        let mut workbook = Workbook::new();
+        let format = Format::new().set_num_format("0.0%");
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write_with_format(0, 0, 0.25, &format)?;
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write(0, 0, "Hidden")?;
+        worksheet.set_hidden(true);
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.write(0, 0, "Sheet3")?;
+
+        let pdf = workbook.save_to_pdf_buffer()?;
+        let text = String::from_utf8_lossy(&pdf);
+
+        assert!(text.contains("/Count 2"));
+        assert!(text.contains("(25.0%) Tj"));
+        assert!(text.contains("(Sheet3) Tj"));
+        assert!(!text.contains("(Hidden) Tj"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_empty_worksheet() -> Result<(), XlsxError> {
+        let worksheet = Worksheet::new();
+
+        let pages = render_pages(&worksheet.render_sheet()?)?;
+        assert_eq!(1, pages.len());
+        assert!(pages[0].content.is_empty());
+
+        Ok(())
+    }
+}
@@ -1169,6 +1169,14 @@
 //! [`NaiveDateTime`]:
 //!     https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDateTime.html
 //!
+//! The [`time`](https://docs.rs/time/latest/time) and
+//! [`Jiff`](https://docs.rs/jiff/latest/jiff) date/time types are also
+//! supported via the `time` and `jiff` features and the
+//! [`Utility::serialize_time_to_excel()`](crate::utility::serialize_time_to_excel())
+//! and
+//! [`Utility::serialize_jiff_to_excel()`](crate::utility::serialize_jiff_to_excel())
+//! helper functions and their `Option` equivalents.
+//!
 //! The [`ExcelDateTime`] type is serialized automatically since it implements
 //! the [`Serialize`] trait. The [`Chrono`] types also implement [`Serialize`]
 //! but they will serialize to an Excel string in RFC3339 format. To serialize
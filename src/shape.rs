@@ -9,13 +9,12 @@
 use std::fmt;
 
 use crate::drawing::{DrawingObject, DrawingType};
-use crate::{Color, Formula, ObjectMovement, Url, XlsxError};
+use crate::{Color, Formula, Image, ObjectMovement, Url, XlsxError};
 
 #[derive(Clone)]
 /// The `Shape` struct represents a worksheet shape object.
 ///
-/// Currently, the only Excel shape type that is implemented is the `Textbox`
-/// shape:
+/// The most commonly used Excel shape is the `Textbox` shape:
 ///
 /// ```
 /// # // This code is available in examples/app_textbox.rs
@@ -52,35 +51,72 @@ use crate::{Color, Formula, ObjectMovement, Url, XlsxError};
 /// See also the [`Worksheet::insert_shape()`](crate::Worksheet::insert_shape)
 /// and
 /// [`Worksheet::insert_shape_with_offset()`](crate::Worksheet::insert_shape_with_offset)
-/// methods. Note that it isn't possible to insert textboxes into other
-/// `rust_xlsxwriter` objects such as [`Chart`](crate::Chart).
+/// methods. Shapes can also be inserted into a chart using
+/// [`Chart::insert_shape()`](crate::Chart::insert_shape).
 ///
-/// ## Support for other Excel shape types
+/// ## Other Excel shape types
 ///
-/// Currently, the only Excel shape type that is supported is the `Textbox`
-/// shape.
+/// In addition to textboxes, `rust_xlsxwriter` supports the Excel preset
+/// shapes defined in the [`ShapeType`] enum, such as rectangles, ellipses,
+/// block arrows, callouts, flowchart symbols and stars. These are created
+/// with [`Shape::new()`] and support the same text and formatting options as
+/// the textbox shape.
 ///
-/// The internal structure of [`Shape`] and the associated XML-generating code
-/// is structured to support other shape types, but none are currently
-/// implemented. The rationale for this is:
+/// The [`ShapeType`] enum also defines straight, elbow and curved connector
+/// shapes. These can be used to join two other shapes with a line, for example
+/// to create a simple flowchart. See
+/// [`Worksheet::insert_connector()`](crate::Worksheet::insert_connector).
 ///
-/// - Unlike applications like `PowerPoint`, the shape object is not widely used
-///   in Excel.
-/// - The most common shape used in Excel is the Textbox/Rectangle.
-/// - Alternative ways of displaying information, such as [`Image`](crate::Image)
-///   or [`Note`](crate::Note), are already supported.
-/// - Each shape or connector type requires a significant number of test cases
-///   to verify their functionality and interaction.
+/// ```
+/// # // This code is available in examples/app_shape_flowchart.rs
+/// #
+/// use rust_xlsxwriter::{Shape, ShapeLine, ShapeLineArrowType, ShapeType, Workbook, XlsxError};
+///
+/// fn main() -> Result<(), XlsxError> {
+///     // Create a new Excel file object.
+///     let mut workbook = Workbook::new();
+///
+///     // Add a worksheet to the workbook.
+///     let worksheet = workbook.add_worksheet();
+///
+///     // Create some flowchart shapes.
+///     let start = Shape::new(ShapeType::FlowchartTerminator)
+///         .set_text("Start")
+///         .set_width(120)
+///         .set_height(40);
+///
+///     let process = Shape::new(ShapeType::FlowchartProcess)
+///         .set_text("Process the data")
+///         .set_width(120)
+///         .set_height(60);
+///
+///     let decision = Shape::new(ShapeType::FlowchartDecision)
+///         .set_text("Valid?")
+///         .set_width(120)
+///         .set_height(80);
 ///
-/// The last is the main reason that I don't wish to support other shape types.
-/// The implementation burden is small, but the test and maintenance burden is
-/// large. As such, I won't accept Pull Requests to add more shape types.
-/// However, I will leave the door open for feature requests that provide a
-/// justification.
+///     // Insert the shapes into the worksheet.
+///     worksheet.insert_shape(1, 1, &start)?;
+///     worksheet.insert_shape(5, 1, &process)?;
+///     worksheet.insert_shape(10, 1, &decision)?;
 ///
+///     // Create a connector with an arrowhead at the end.
+///     let connector = Shape::new(ShapeType::StraightConnector)
+///         .set_format(&ShapeLine::new().set_end_arrow(ShapeLineArrowType::Triangle));
+///
+///     // Join the shapes with connectors.
+///     worksheet.insert_connector(1, 1, 5, 1, &connector)?;
+///     worksheet.insert_connector(5, 1, 10, 1, &connector)?;
+///
+///     // Save the file to disk.
+///     workbook.save("shape_flowchart.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
 pub struct Shape {
-    height: f64,
-    width: f64,
+    pub(crate) height: f64,
+    pub(crate) width: f64,
     pub(crate) x_offset: u32,
     pub(crate) y_offset: u32,
     pub(crate) text: String,
@@ -92,7 +128,10 @@ pub struct Shape {
     pub(crate) font: ShapeFont,
     pub(crate) text_options: ShapeText,
     pub(crate) url: Option<Url>,
-    pub(crate) _shape_type: ShapeType,
+    pub(crate) shape_type: ShapeType,
+    pub(crate) start_connection_site: ShapeConnectionSite,
+    pub(crate) end_connection_site: ShapeConnectionSite,
+    pub(crate) connection: Option<ShapeConnection>,
 }
 
 impl Shape {
@@ -105,8 +144,8 @@ impl Shape {
     /// See also the
     /// [`Worksheet::insert_shape()`](crate::Worksheet::insert_shape) and
     /// [`Worksheet::insert_shape_with_offset()`](crate::Worksheet::insert_shape_with_offset)
-    /// methods. Note, it isn't possible to insert textboxes into other
-    /// `rust_xlsxwriter` objects such as [`Chart`](crate::Chart).
+    /// methods. Textboxes can also be inserted into a chart using
+    /// [`Chart::insert_shape()`](crate::Chart::insert_shape).
     ///
     /// # Examples
     ///
@@ -146,6 +185,67 @@ impl Shape {
     /// <img src="https://rustxlsxwriter.github.io/images/shape_text_options_set_direction.png">
     ///
     pub fn textbox() -> Shape {
+        Shape::new(ShapeType::TextBox)
+    }
+
+    /// Create a new Shape object to represent an Excel preset shape.
+    ///
+    /// Create a shape of one of the preset types defined in the [`ShapeType`]
+    /// enum such as a rectangle, an ellipse, a block arrow or a flowchart
+    /// symbol. Unlike textboxes, the text in these shapes is centered
+    /// horizontally and vertically by default, and the fill, line and font
+    /// colors are taken from the workbook theme, like Excel.
+    ///
+    /// Connector shape types, such as [`ShapeType::StraightConnector`], are
+    /// used to join two other shapes via
+    /// [`Worksheet::insert_connector()`](crate::Worksheet::insert_connector).
+    ///
+    /// # Parameters
+    ///
+    /// - `shape_type`: The shape type defined by the [`ShapeType`] enum.
+    ///
+    /// # Examples
+    ///
+    /// This example demonstrates adding some preset shapes to a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_shape_new.rs
+    /// #
+    /// # use rust_xlsxwriter::{Shape, ShapeType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create some preset shapes.
+    ///     let rectangle = Shape::new(ShapeType::RoundedRectangle).set_text("Rectangle");
+    ///     let ellipse = Shape::new(ShapeType::Ellipse).set_text("Ellipse");
+    ///     let star = Shape::new(ShapeType::Star5).set_text("Star");
+    ///
+    ///     // Insert the shapes in the worksheet.
+    ///     worksheet.insert_shape(1, 1, &rectangle)?;
+    ///     worksheet.insert_shape(1, 5, &ellipse)?;
+    ///     worksheet.insert_shape(1, 9, &star)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("shape.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn new(shape_type: ShapeType) -> Shape {
+        // Preset shapes center their text, like Excel.
+        let text_options = if shape_type == ShapeType::TextBox || shape_type.is_connector() {
+            ShapeText::default()
+        } else {
+            ShapeText::new()
+                .set_horizontal_alignment(ShapeTextHorizontalAlignment::Center)
+                .set_vertical_alignment(ShapeTextVerticalAlignment::Middle)
+        };
+
         Shape {
             x_offset: 0,
             y_offset: 0,
@@ -159,9 +259,12 @@ impl Shape {
             decorative: false,
             format: ShapeFormat::default(),
             font: ShapeFont::default(),
-            text_options: ShapeText::default(),
+            text_options,
             url: None,
-            _shape_type: ShapeType::TextBox,
+            shape_type,
+            start_connection_site: ShapeConnectionSite::Automatic,
+            end_connection_site: ShapeConnectionSite::Automatic,
+            connection: None,
         }
     }
 
@@ -618,6 +721,96 @@ impl Shape {
         self.object_movement = option;
         self
     }
+
+    /// Set the connection sites used by a connector shape.
+    ///
+    /// Connector shapes such as [`ShapeType::ElbowConnector`] are attached to
+    /// a connection site on the shapes that they join. By default the sites
+    /// are chosen automatically based on the relative position of the shapes:
+    /// the left and right sides for shapes that are side by side, and the top
+    /// and bottom for shapes that are one above the other. This method can be
+    /// used to set the sites explicitly.
+    ///
+    /// Connection sites are supported for rectangular, oval and diamond
+    /// shapes, and the equivalent flowchart shapes. For other shapes the
+    /// connector is positioned at the site but isn't attached to the shape.
+    ///
+    /// This property only applies to connector shapes and is ignored for
+    /// other shape types. See
+    /// [`Worksheet::insert_connector()`](crate::Worksheet::insert_connector).
+    ///
+    /// # Parameters
+    ///
+    /// - `start_site`: The connection site on the start shape, defined by a
+    ///   [`ShapeConnectionSite`] enum value.
+    /// - `end_site`: The connection site on the end shape, defined by a
+    ///   [`ShapeConnectionSite`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// This example demonstrates joining two shapes with an elbow connector
+    /// using explicit connection sites.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_shape_set_connection_sites.rs
+    /// #
+    /// # use rust_xlsxwriter::{Shape, ShapeConnectionSite, ShapeType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create two shapes to connect.
+    ///     let shape1 = Shape::new(ShapeType::Rectangle).set_text("Start");
+    ///     let shape2 = Shape::new(ShapeType::Ellipse).set_text("End");
+    ///
+    ///     worksheet.insert_shape(1, 1, &shape1)?;
+    ///     worksheet.insert_shape(10, 5, &shape2)?;
+    ///
+    ///     // Connect the bottom of the first shape to the left of the second.
+    ///     let connector = Shape::new(ShapeType::ElbowConnector)
+    ///         .set_connection_sites(ShapeConnectionSite::Bottom, ShapeConnectionSite::Left);
+    ///
+    ///     worksheet.insert_connector(1, 1, 10, 5, &connector)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("shape.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn set_connection_sites(
+        mut self,
+        start_site: ShapeConnectionSite,
+        end_site: ShapeConnectionSite,
+    ) -> Shape {
+        self.start_connection_site = start_site;
+        self.end_connection_site = end_site;
+        self
+    }
+
+    // Get the image used in the shape picture fill, if there is one.
+    pub(crate) fn picture_fill_image(&self) -> Option<&Image> {
+        self.format.picture_fill.as_ref().map(|fill| &fill.image)
+    }
+}
+
+// Connector properties, calculated when the worksheet drawing is prepared,
+// that link a connector to the cNvPr ids and connection sites of its start
+// and end shapes.
+#[derive(Clone)]
+pub(crate) struct ShapeConnection {
+    pub(crate) start_id: u32,
+    pub(crate) start_index: Option<u32>,
+    pub(crate) end_id: u32,
+    pub(crate) end_index: Option<u32>,
+    pub(crate) flip_h: bool,
+    pub(crate) flip_v: bool,
+    pub(crate) rotated: bool,
+    pub(crate) preset: &'static str,
 }
 
 // -----------------------------------------------------------------------
@@ -646,6 +839,8 @@ impl Shape {
 ///   properties.
 /// - [`ShapeFormat::set_gradient_fill()`]: Set the [`ShapeGradientFill`]
 ///   properties.
+/// - [`ShapeFormat::set_picture_fill()`]: Set the [`ShapePictureFill`]
+///   properties.
 /// - [`ShapeFormat::set_no_fill()`]: Turn off the fill for the shape object.
 /// - [`ShapeFormat::set_line()`]: Set the [`ShapeLine`] properties.
 /// - [`ShapeFormat::set_no_line()`]: Turn off the line for the shape object.
@@ -706,6 +901,7 @@ pub struct ShapeFormat {
     pub(crate) solid_fill: Option<ShapeSolidFill>,
     pub(crate) pattern_fill: Option<ShapePatternFill>,
     pub(crate) gradient_fill: Option<ShapeGradientFill>,
+    pub(crate) picture_fill: Option<ShapePictureFill>,
 }
 
 impl Default for ShapeFormat {
@@ -725,6 +921,7 @@ impl ShapeFormat {
             solid_fill: None,
             pattern_fill: None,
             gradient_fill: None,
+            picture_fill: None,
         }
     }
 
@@ -1033,6 +1230,60 @@ impl ShapeFormat {
         self.gradient_fill = Some(fill.clone());
         self
     }
+
+    /// Set the picture fill formatting for a shape element.
+    ///
+    /// See the [`ShapePictureFill`] struct for details on the picture fill
+    /// properties that can be set.
+    ///
+    /// Note, picture fills aren't supported for shapes inserted into charts
+    /// with [`Chart::insert_shape()`](crate::Chart::insert_shape).
+    ///
+    /// # Parameters
+    ///
+    /// - `fill`: A [`ShapePictureFill`] struct reference.
+    ///
+    /// # Examples
+    ///
+    /// This example demonstrates adding a shape with an image fill.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_shape_format_set_picture_fill.rs
+    /// #
+    /// # use rust_xlsxwriter::{
+    /// #     Image, Shape, ShapeFormat, ShapePictureFill, ShapePictureFillType, ShapeType, Workbook,
+    /// #     XlsxError,
+    /// # };
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a shape with a tiled image fill.
+    ///     let image = Image::new("examples/rust_logo.png")?;
+    ///
+    ///     let shape = Shape::new(ShapeType::Ellipse).set_format(
+    ///         &ShapeFormat::new()
+    ///             .set_picture_fill(&ShapePictureFill::new(&image).set_type(ShapePictureFillType::Tile)),
+    ///     );
+    ///
+    ///     // Insert the shape in a cell.
+    ///     worksheet.insert_shape(1, 1, &shape)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("shape.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_picture_fill(mut self, fill: &ShapePictureFill) -> ShapeFormat {
+        self.picture_fill = Some(fill.clone());
+        self
+    }
 }
 
 // -----------------------------------------------------------------------
@@ -1094,6 +1345,8 @@ pub struct ShapeLine {
     pub(crate) transparency: u8,
     pub(crate) dash_type: ShapeLineDashType,
     pub(crate) hidden: bool,
+    pub(crate) start_arrow: ShapeLineArrowType,
+    pub(crate) end_arrow: ShapeLineArrowType,
 }
 
 impl ShapeLine {
@@ -1107,6 +1360,8 @@ impl ShapeLine {
             transparency: 0,
             dash_type: ShapeLineDashType::Solid,
             hidden: false,
+            start_arrow: ShapeLineArrowType::None,
+            end_arrow: ShapeLineArrowType::None,
         }
     }
 
@@ -1372,6 +1627,73 @@ impl ShapeLine {
         self.hidden = enable;
         self
     }
+
+    /// Set the arrowhead type at the start of a line.
+    ///
+    /// Arrowheads only apply to the lines of connector shapes such as
+    /// [`ShapeType::StraightConnector`]. See
+    /// [`Worksheet::insert_connector()`](crate::Worksheet::insert_connector).
+    ///
+    /// # Parameters
+    ///
+    /// - `arrow_type`: A [`ShapeLineArrowType`] enum value.
+    ///
+    pub fn set_start_arrow(mut self, arrow_type: ShapeLineArrowType) -> ShapeLine {
+        self.start_arrow = arrow_type;
+        self
+    }
+
+    /// Set the arrowhead type at the end of a line.
+    ///
+    /// See [`ShapeLine::set_start_arrow()`] above.
+    ///
+    /// # Parameters
+    ///
+    /// - `arrow_type`: A [`ShapeLineArrowType`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// This example demonstrates joining two shapes with a connector that has
+    /// an arrowhead at the end.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_shape_line_set_end_arrow.rs
+    /// #
+    /// # use rust_xlsxwriter::{Shape, ShapeLine, ShapeLineArrowType, ShapeType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create two shapes to connect.
+    ///     let shape1 = Shape::new(ShapeType::Rectangle).set_text("From");
+    ///     let shape2 = Shape::new(ShapeType::Rectangle).set_text("To");
+    ///
+    ///     worksheet.insert_shape(1, 1, &shape1)?;
+    ///     worksheet.insert_shape(1, 6, &shape2)?;
+    ///
+    ///     // Create a connector with an arrowhead.
+    ///     let connector = Shape::new(ShapeType::StraightConnector).set_format(
+    ///         &ShapeLine::new()
+    ///             .set_color("#FF0000")
+    ///             .set_end_arrow(ShapeLineArrowType::Triangle),
+    ///     );
+    ///
+    ///     worksheet.insert_connector(1, 1, 1, 6, &connector)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("shape.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn set_end_arrow(mut self, arrow_type: ShapeLineArrowType) -> ShapeLine {
+        self.end_arrow = arrow_type;
+        self
+    }
 }
 
 // -----------------------------------------------------------------------
@@ -2073,6 +2395,98 @@ impl ShapeGradientStop {
     }
 }
 
+// -----------------------------------------------------------------------
+// ShapePictureFill
+// -----------------------------------------------------------------------
+
+/// The `ShapePictureFill` struct represents a picture fill for a shape.
+///
+/// The [`ShapePictureFill`] struct represents the formatting properties for an
+/// image used to fill a shape, such as a logo or a texture.
+///
+/// `ShapePictureFill` is a sub property of the [`ShapeFormat`] struct and is
+/// used with the [`ShapeFormat::set_picture_fill()`] method.
+///
+/// It is used in conjunction with the [`Shape`] struct. See the example in
+/// [`ShapeFormat::set_picture_fill()`].
+///
+#[derive(Clone)]
+pub struct ShapePictureFill {
+    pub(crate) image: Image,
+    pub(crate) fill_type: ShapePictureFillType,
+    pub(crate) transparency: u8,
+    pub(crate) rel_id: u32,
+}
+
+impl ShapePictureFill {
+    /// Create a new `ShapePictureFill` object to represent a Shape picture
+    /// fill.
+    ///
+    /// # Parameters
+    ///
+    /// - `image`: The [`Image`] to use for the fill.
+    ///
+    pub fn new(image: &Image) -> ShapePictureFill {
+        ShapePictureFill {
+            image: image.clone(),
+            fill_type: ShapePictureFillType::Stretch,
+            transparency: 0,
+            rel_id: 0,
+        }
+    }
+
+    /// Set the way the image is used to fill the shape.
+    ///
+    /// The default is to stretch the image to fill the shape.
+    ///
+    /// # Parameters
+    ///
+    /// - `fill_type`: The picture fill type defined by a
+    ///   [`ShapePictureFillType`] enum value.
+    ///
+    pub fn set_type(mut self, fill_type: ShapePictureFillType) -> ShapePictureFill {
+        self.fill_type = fill_type;
+        self
+    }
+
+    /// Set the transparency of the picture fill.
+    ///
+    /// # Parameters
+    ///
+    /// - `transparency`: The color transparency in the range 0 <= transparency
+    ///   <= 100. The default value is 0.
+    ///
+    pub fn set_transparency(mut self, transparency: u8) -> ShapePictureFill {
+        if transparency <= 100 {
+            self.transparency = transparency;
+        }
+
+        self
+    }
+}
+
+impl PartialEq for ShapePictureFill {
+    fn eq(&self, other: &Self) -> bool {
+        self.image.hash == other.image.hash
+            && self.fill_type == other.fill_type
+            && self.transparency == other.transparency
+    }
+}
+
+/// The `ShapePictureFillType` enum defines how an image fills a shape.
+///
+/// It is used with [`ShapePictureFill::set_type()`].
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ShapePictureFillType {
+    /// Stretch the image to fill the shape. This is the default.
+    #[default]
+    Stretch,
+
+    /// Tile the image, at its original size, to fill the shape.
+    Tile,
+}
+
 // -----------------------------------------------------------------------
 // ShapeText
 // -----------------------------------------------------------------------
@@ -2301,15 +2715,563 @@ impl ShapeText {
 // Shape enums
 // -----------------------------------------------------------------------
 
-// The `ShapeType` enum defines the [`Shape`] types.
-//
-// Note, currently only the type supported is TextBox. See the explanation at
-// the start of this document.
-//
-#[derive(Clone, PartialEq, Eq)]
-pub(crate) enum ShapeType {
-    // The Textbox shape.
+/// The `ShapeType` enum defines the [`Shape`] types.
+///
+/// The shape types map to the Excel/DrawingML preset geometries of the same
+/// or similar names. They are grouped below into basic shapes, block arrows,
+/// callouts, flowchart symbols, stars and banners, and connectors.
+///
+/// See [`Shape::new()`] and
+/// [`Worksheet::insert_connector()`](crate::Worksheet::insert_connector).
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShapeType {
+    /// A Textbox shape. This is the shape created by [`Shape::textbox()`].
+    #[default]
     TextBox,
+
+    /// A Rectangle shape.
+    Rectangle,
+
+    /// A Rectangle shape with rounded corners.
+    RoundedRectangle,
+
+    /// An Ellipse or Oval shape.
+    Ellipse,
+
+    /// An Isosceles Triangle shape.
+    Triangle,
+
+    /// A Right Triangle shape.
+    RightTriangle,
+
+    /// A Diamond shape.
+    Diamond,
+
+    /// A Parallelogram shape.
+    Parallelogram,
+
+    /// A Trapezoid shape.
+    Trapezoid,
+
+    /// A regular Pentagon shape.
+    Pentagon,
+
+    /// A Hexagon shape.
+    Hexagon,
+
+    /// An Octagon shape.
+    Octagon,
+
+    /// A Plus or Cross shape.
+    Plus,
+
+    /// A Can or Cylinder shape.
+    Can,
+
+    /// A Cube shape.
+    Cube,
+
+    /// A Donut shape.
+    Donut,
+
+    /// A "No" symbol shape.
+    NoSymbol,
+
+    /// A Smiley Face shape.
+    SmileyFace,
+
+    /// A Heart shape.
+    Heart,
+
+    /// A Lightning Bolt shape.
+    LightningBolt,
+
+    /// A Sun shape.
+    Sun,
+
+    /// A Moon shape.
+    Moon,
+
+    /// A Cloud shape.
+    Cloud,
+
+    /// A Right Arrow shape.
+    RightArrow,
+
+    /// A Left Arrow shape.
+    LeftArrow,
+
+    /// An Up Arrow shape.
+    UpArrow,
+
+    /// A Down Arrow shape.
+    DownArrow,
+
+    /// A Left-Right Arrow shape.
+    LeftRightArrow,
+
+    /// An Up-Down Arrow shape.
+    UpDownArrow,
+
+    /// A Quad Arrow shape.
+    QuadArrow,
+
+    /// A Bent Arrow shape.
+    BentArrow,
+
+    /// A U-Turn Arrow shape.
+    UTurnArrow,
+
+    /// A Curved Right Arrow shape.
+    CurvedRightArrow,
+
+    /// A Striped Right Arrow shape.
+    StripedRightArrow,
+
+    /// A Notched Right Arrow shape.
+    NotchedRightArrow,
+
+    /// A Pentagon Arrow shape.
+    PentagonArrow,
+
+    /// A Chevron shape.
+    Chevron,
+
+    /// A Rectangular Callout shape.
+    RectangularCallout,
+
+    /// A Rounded Rectangular Callout shape.
+    RoundedRectangularCallout,
+
+    /// An Oval Callout shape.
+    OvalCallout,
+
+    /// A Cloud Callout shape.
+    CloudCallout,
+
+    /// A Line Callout shape.
+    LineCallout,
+
+    /// A Flowchart Process shape.
+    FlowchartProcess,
+
+    /// A Flowchart Alternate Process shape.
+    FlowchartAlternateProcess,
+
+    /// A Flowchart Decision shape.
+    FlowchartDecision,
+
+    /// A Flowchart Data (Input/Output) shape.
+    FlowchartData,
+
+    /// A Flowchart Predefined Process shape.
+    FlowchartPredefinedProcess,
+
+    /// A Flowchart Internal Storage shape.
+    FlowchartInternalStorage,
+
+    /// A Flowchart Document shape.
+    FlowchartDocument,
+
+    /// A Flowchart Multidocument shape.
+    FlowchartMultidocument,
+
+    /// A Flowchart Terminator shape.
+    FlowchartTerminator,
+
+    /// A Flowchart Preparation shape.
+    FlowchartPreparation,
+
+    /// A Flowchart Manual Input shape.
+    FlowchartManualInput,
+
+    /// A Flowchart Manual Operation shape.
+    FlowchartManualOperation,
+
+    /// A Flowchart Connector (circle) shape.
+    FlowchartConnector,
+
+    /// A Flowchart Off-page Connector shape.
+    FlowchartOffPageConnector,
+
+    /// A Flowchart Card shape.
+    FlowchartCard,
+
+    /// A Flowchart Punched Tape shape.
+    FlowchartPunchedTape,
+
+    /// A Flowchart Summing Junction shape.
+    FlowchartSummingJunction,
+
+    /// A Flowchart Or shape.
+    FlowchartOr,
+
+    /// A Flowchart Collate shape.
+    FlowchartCollate,
+
+    /// A Flowchart Sort shape.
+    FlowchartSort,
+
+    /// A Flowchart Extract shape.
+    FlowchartExtract,
+
+    /// A Flowchart Merge shape.
+    FlowchartMerge,
+
+    /// A Flowchart Stored Data shape.
+    FlowchartStoredData,
+
+    /// A Flowchart Delay shape.
+    FlowchartDelay,
+
+    /// A Flowchart Sequential Access Storage shape.
+    FlowchartSequentialAccessStorage,
+
+    /// A Flowchart Magnetic Disk shape.
+    FlowchartMagneticDisk,
+
+    /// A Flowchart Direct Access Storage shape.
+    FlowchartDirectAccessStorage,
+
+    /// A Flowchart Display shape.
+    FlowchartDisplay,
+
+    /// A 4-Point Star shape.
+    Star4,
+
+    /// A 5-Point Star shape.
+    Star5,
+
+    /// A 6-Point Star shape.
+    Star6,
+
+    /// An 8-Point Star shape.
+    Star8,
+
+    /// A 10-Point Star shape.
+    Star10,
+
+    /// A 12-Point Star shape.
+    Star12,
+
+    /// A 16-Point Star shape.
+    Star16,
+
+    /// A 24-Point Star shape.
+    Star24,
+
+    /// A 32-Point Star shape.
+    Star32,
+
+    /// An Explosion shape.
+    Explosion1,
+
+    /// An alternative Explosion shape.
+    Explosion2,
+
+    /// A Wave banner shape.
+    Wave,
+
+    /// A Double Wave banner shape.
+    DoubleWave,
+
+    /// A Straight connector. See [`Worksheet::insert_connector()`](crate::Worksheet::insert_connector).
+    StraightConnector,
+
+    /// An Elbow connector. See [`Worksheet::insert_connector()`](crate::Worksheet::insert_connector).
+    ElbowConnector,
+
+    /// A Curved connector. See [`Worksheet::insert_connector()`](crate::Worksheet::insert_connector).
+    CurvedConnector,
+}
+
+impl ShapeType {
+    // Check if the shape type is a connector shape.
+    pub(crate) fn is_connector(self) -> bool {
+        matches!(
+            self,
+            ShapeType::StraightConnector | ShapeType::ElbowConnector | ShapeType::CurvedConnector
+        )
+    }
+
+    // Get the default name that Excel uses for the shape type.
+    pub(crate) fn default_name(self) -> &'static str {
+        match self {
+            ShapeType::TextBox => "TextBox",
+            ShapeType::Rectangle => "Rectangle",
+            ShapeType::RoundedRectangle => "Rounded Rectangle",
+            ShapeType::Ellipse => "Oval",
+            ShapeType::Triangle => "Isosceles Triangle",
+            ShapeType::RightTriangle => "Right Triangle",
+            ShapeType::Diamond => "Diamond",
+            ShapeType::Parallelogram => "Parallelogram",
+            ShapeType::Trapezoid => "Trapezoid",
+            ShapeType::Pentagon => "Regular Pentagon",
+            ShapeType::Hexagon => "Hexagon",
+            ShapeType::Octagon => "Octagon",
+            ShapeType::Plus => "Cross",
+            ShapeType::Can => "Can",
+            ShapeType::Cube => "Cube",
+            ShapeType::Donut => "Donut",
+            ShapeType::NoSymbol => "\"No\" Symbol",
+            ShapeType::SmileyFace => "Smiley Face",
+            ShapeType::Heart => "Heart",
+            ShapeType::LightningBolt => "Lightning Bolt",
+            ShapeType::Sun => "Sun",
+            ShapeType::Moon => "Moon",
+            ShapeType::Cloud => "Cloud",
+            ShapeType::RightArrow => "Right Arrow",
+            ShapeType::LeftArrow => "Left Arrow",
+            ShapeType::UpArrow => "Up Arrow",
+            ShapeType::DownArrow => "Down Arrow",
+            ShapeType::LeftRightArrow => "Left-Right Arrow",
+            ShapeType::UpDownArrow => "Up-Down Arrow",
+            ShapeType::QuadArrow => "Quad Arrow",
+            ShapeType::BentArrow => "Bent Arrow",
+            ShapeType::UTurnArrow => "U-Turn Arrow",
+            ShapeType::CurvedRightArrow => "Curved Right Arrow",
+            ShapeType::StripedRightArrow => "Striped Right Arrow",
+            ShapeType::NotchedRightArrow => "Notched Right Arrow",
+            ShapeType::PentagonArrow => "Pentagon",
+            ShapeType::Chevron => "Chevron",
+            ShapeType::RectangularCallout => "Rectangular Callout",
+            ShapeType::RoundedRectangularCallout => "Rounded Rectangular Callout",
+            ShapeType::OvalCallout => "Oval Callout",
+            ShapeType::CloudCallout => "Cloud Callout",
+            ShapeType::LineCallout => "Line Callout 1",
+            ShapeType::FlowchartProcess => "Flowchart: Process",
+            ShapeType::FlowchartAlternateProcess => "Flowchart: Alternate Process",
+            ShapeType::FlowchartDecision => "Flowchart: Decision",
+            ShapeType::FlowchartData => "Flowchart: Data",
+            ShapeType::FlowchartPredefinedProcess => "Flowchart: Predefined Process",
+            ShapeType::FlowchartInternalStorage => "Flowchart: Internal Storage",
+            ShapeType::FlowchartDocument => "Flowchart: Document",
+            ShapeType::FlowchartMultidocument => "Flowchart: Multidocument",
+            ShapeType::FlowchartTerminator => "Flowchart: Terminator",
+            ShapeType::FlowchartPreparation => "Flowchart: Preparation",
+            ShapeType::FlowchartManualInput => "Flowchart: Manual Input",
+            ShapeType::FlowchartManualOperation => "Flowchart: Manual Operation",
+            ShapeType::FlowchartConnector => "Flowchart: Connector",
+            ShapeType::FlowchartOffPageConnector => "Flowchart: Off-page Connector",
+            ShapeType::FlowchartCard => "Flowchart: Card",
+            ShapeType::FlowchartPunchedTape => "Flowchart: Punched Tape",
+            ShapeType::FlowchartSummingJunction => "Flowchart: Summing Junction",
+            ShapeType::FlowchartOr => "Flowchart: Or",
+            ShapeType::FlowchartCollate => "Flowchart: Collate",
+            ShapeType::FlowchartSort => "Flowchart: Sort",
+            ShapeType::FlowchartExtract => "Flowchart: Extract",
+            ShapeType::FlowchartMerge => "Flowchart: Merge",
+            ShapeType::FlowchartStoredData => "Flowchart: Stored Data",
+            ShapeType::FlowchartDelay => "Flowchart: Delay",
+            ShapeType::FlowchartSequentialAccessStorage => "Flowchart: Sequential Access Storage",
+            ShapeType::FlowchartMagneticDisk => "Flowchart: Magnetic Disk",
+            ShapeType::FlowchartDirectAccessStorage => "Flowchart: Direct Access Storage",
+            ShapeType::FlowchartDisplay => "Flowchart: Display",
+            ShapeType::Star4 => "4-Point Star",
+            ShapeType::Star5 => "5-Point Star",
+            ShapeType::Star6 => "6-Point Star",
+            ShapeType::Star8 => "8-Point Star",
+            ShapeType::Star10 => "10-Point Star",
+            ShapeType::Star12 => "12-Point Star",
+            ShapeType::Star16 => "16-Point Star",
+            ShapeType::Star24 => "24-Point Star",
+            ShapeType::Star32 => "32-Point Star",
+            ShapeType::Explosion1 => "Explosion 1",
+            ShapeType::Explosion2 => "Explosion 2",
+            ShapeType::Wave => "Wave",
+            ShapeType::DoubleWave => "Double Wave",
+            ShapeType::StraightConnector => "Straight Connector",
+            ShapeType::ElbowConnector => "Elbow Connector",
+            ShapeType::CurvedConnector => "Curved Connector",
+        }
+    }
+
+    // Get the index of a connection site for the shape type. Only the shapes
+    // with regular top/left/bottom/right connection sites are supported.
+    pub(crate) fn connection_site_index(self, site: ShapeConnectionSite) -> Option<u32> {
+        match self {
+            ShapeType::TextBox
+            | ShapeType::Rectangle
+            | ShapeType::RoundedRectangle
+            | ShapeType::Diamond
+            | ShapeType::FlowchartProcess
+            | ShapeType::FlowchartAlternateProcess
+            | ShapeType::FlowchartDecision
+            | ShapeType::FlowchartPredefinedProcess
+            | ShapeType::FlowchartInternalStorage
+            | ShapeType::FlowchartTerminator
+            | ShapeType::FlowchartPreparation => match site {
+                ShapeConnectionSite::Top => Some(0),
+                ShapeConnectionSite::Left => Some(1),
+                ShapeConnectionSite::Bottom => Some(2),
+                ShapeConnectionSite::Right => Some(3),
+                ShapeConnectionSite::Automatic => None,
+            },
+            ShapeType::Ellipse | ShapeType::FlowchartConnector => match site {
+                ShapeConnectionSite::Top => Some(0),
+                ShapeConnectionSite::Left => Some(2),
+                ShapeConnectionSite::Bottom => Some(4),
+                ShapeConnectionSite::Right => Some(6),
+                ShapeConnectionSite::Automatic => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ShapeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TextBox => write!(f, "rect"),
+            Self::Rectangle => write!(f, "rect"),
+            Self::RoundedRectangle => write!(f, "roundRect"),
+            Self::Ellipse => write!(f, "ellipse"),
+            Self::Triangle => write!(f, "triangle"),
+            Self::RightTriangle => write!(f, "rtTriangle"),
+            Self::Diamond => write!(f, "diamond"),
+            Self::Parallelogram => write!(f, "parallelogram"),
+            Self::Trapezoid => write!(f, "trapezoid"),
+            Self::Pentagon => write!(f, "pentagon"),
+            Self::Hexagon => write!(f, "hexagon"),
+            Self::Octagon => write!(f, "octagon"),
+            Self::Plus => write!(f, "plus"),
+            Self::Can => write!(f, "can"),
+            Self::Cube => write!(f, "cube"),
+            Self::Donut => write!(f, "donut"),
+            Self::NoSymbol => write!(f, "noSmoking"),
+            Self::SmileyFace => write!(f, "smileyFace"),
+            Self::Heart => write!(f, "heart"),
+            Self::LightningBolt => write!(f, "lightningBolt"),
+            Self::Sun => write!(f, "sun"),
+            Self::Moon => write!(f, "moon"),
+            Self::Cloud => write!(f, "cloud"),
+            Self::RightArrow => write!(f, "rightArrow"),
+            Self::LeftArrow => write!(f, "leftArrow"),
+            Self::UpArrow => write!(f, "upArrow"),
+            Self::DownArrow => write!(f, "downArrow"),
+            Self::LeftRightArrow => write!(f, "leftRightArrow"),
+            Self::UpDownArrow => write!(f, "upDownArrow"),
+            Self::QuadArrow => write!(f, "quadArrow"),
+            Self::BentArrow => write!(f, "bentArrow"),
+            Self::UTurnArrow => write!(f, "uturnArrow"),
+            Self::CurvedRightArrow => write!(f, "curvedRightArrow"),
+            Self::StripedRightArrow => write!(f, "stripedRightArrow"),
+            Self::NotchedRightArrow => write!(f, "notchedRightArrow"),
+            Self::PentagonArrow => write!(f, "homePlate"),
+            Self::Chevron => write!(f, "chevron"),
+            Self::RectangularCallout => write!(f, "wedgeRectCallout"),
+            Self::RoundedRectangularCallout => write!(f, "wedgeRoundRectCallout"),
+            Self::OvalCallout => write!(f, "wedgeEllipseCallout"),
+            Self::CloudCallout => write!(f, "cloudCallout"),
+            Self::LineCallout => write!(f, "borderCallout1"),
+            Self::FlowchartProcess => write!(f, "flowChartProcess"),
+            Self::FlowchartAlternateProcess => write!(f, "flowChartAlternateProcess"),
+            Self::FlowchartDecision => write!(f, "flowChartDecision"),
+            Self::FlowchartData => write!(f, "flowChartInputOutput"),
+            Self::FlowchartPredefinedProcess => write!(f, "flowChartPredefinedProcess"),
+            Self::FlowchartInternalStorage => write!(f, "flowChartInternalStorage"),
+            Self::FlowchartDocument => write!(f, "flowChartDocument"),
+            Self::FlowchartMultidocument => write!(f, "flowChartMultidocument"),
+            Self::FlowchartTerminator => write!(f, "flowChartTerminator"),
+            Self::FlowchartPreparation => write!(f, "flowChartPreparation"),
+            Self::FlowchartManualInput => write!(f, "flowChartManualInput"),
+            Self::FlowchartManualOperation => write!(f, "flowChartManualOperation"),
+            Self::FlowchartConnector => write!(f, "flowChartConnector"),
+            Self::FlowchartOffPageConnector => write!(f, "flowChartOffpageConnector"),
+            Self::FlowchartCard => write!(f, "flowChartPunchedCard"),
+            Self::FlowchartPunchedTape => write!(f, "flowChartPunchedTape"),
+            Self::FlowchartSummingJunction => write!(f, "flowChartSummingJunction"),
+            Self::FlowchartOr => write!(f, "flowChartOr"),
+            Self::FlowchartCollate => write!(f, "flowChartCollate"),
+            Self::FlowchartSort => write!(f, "flowChartSort"),
+            Self::FlowchartExtract => write!(f, "flowChartExtract"),
+            Self::FlowchartMerge => write!(f, "flowChartMerge"),
+            Self::FlowchartStoredData => write!(f, "flowChartOnlineStorage"),
+            Self::FlowchartDelay => write!(f, "flowChartDelay"),
+            Self::FlowchartSequentialAccessStorage => write!(f, "flowChartMagneticTape"),
+            Self::FlowchartMagneticDisk => write!(f, "flowChartMagneticDisk"),
+            Self::FlowchartDirectAccessStorage => write!(f, "flowChartMagneticDrum"),
+            Self::FlowchartDisplay => write!(f, "flowChartDisplay"),
+            Self::Star4 => write!(f, "star4"),
+            Self::Star5 => write!(f, "star5"),
+            Self::Star6 => write!(f, "star6"),
+            Self::Star8 => write!(f, "star8"),
+            Self::Star10 => write!(f, "star10"),
+            Self::Star12 => write!(f, "star12"),
+            Self::Star16 => write!(f, "star16"),
+            Self::Star24 => write!(f, "star24"),
+            Self::Star32 => write!(f, "star32"),
+            Self::Explosion1 => write!(f, "irregularSeal1"),
+            Self::Explosion2 => write!(f, "irregularSeal2"),
+            Self::Wave => write!(f, "wave"),
+            Self::DoubleWave => write!(f, "doubleWave"),
+            Self::StraightConnector => write!(f, "straightConnector1"),
+            Self::ElbowConnector => write!(f, "bentConnector3"),
+            Self::CurvedConnector => write!(f, "curvedConnector3"),
+        }
+    }
+}
+
+/// The `ShapeConnectionSite` enum defines the connection sites used to attach
+/// a connector to a [`Shape`].
+///
+/// See [`Shape::set_connection_sites()`].
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShapeConnectionSite {
+    /// Choose the connection sites based on the relative position of the
+    /// connected shapes. This is the default.
+    #[default]
+    Automatic,
+
+    /// Connect to the top of the shape.
+    Top,
+
+    /// Connect to the bottom of the shape.
+    Bottom,
+
+    /// Connect to the left side of the shape.
+    Left,
+
+    /// Connect to the right side of the shape.
+    Right,
+}
+
+/// The `ShapeLineArrowType` enum defines the [`Shape`] line arrowhead types.
+///
+/// See [`ShapeLine::set_start_arrow()`] and [`ShapeLine::set_end_arrow()`].
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShapeLineArrowType {
+    /// No arrowhead. This is the default.
+    #[default]
+    None,
+
+    /// A solid triangle arrowhead.
+    Triangle,
+
+    /// An open arrowhead.
+    Open,
+
+    /// A stealth (notched) arrowhead.
+    Stealth,
+
+    /// A diamond arrowhead.
+    Diamond,
+
+    /// An oval arrowhead.
+    Oval,
+}
+
+impl fmt::Display for ShapeLineArrowType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Oval => write!(f, "oval"),
+            Self::Open => write!(f, "arrow"),
+            Self::Diamond => write!(f, "diamond"),
+            Self::Stealth => write!(f, "stealth"),
+            Self::Triangle => write!(f, "triangle"),
+        }
+    }
 }
 
 /// The `ShapeLineDashType` enum defines the [`Shape`] line dash types.
@@ -3352,3 +4314,9 @@ impl IntoShapeFormat for &ShapeGradientFill {
         ShapeFormat::new().set_gradient_fill(self).clone()
     }
 }
+
+impl IntoShapeFormat for &ShapePictureFill {
+    fn new_shape_format(&self) -> ShapeFormat {
+        ShapeFormat::new().set_picture_fill(self).clone()
+    }
+}
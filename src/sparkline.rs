@@ -592,7 +592,15 @@ impl Sparkline {
     where
         T: IntoChartRange,
     {
-        self.data_range = range.new_chart_range();
+        let range = range.new_chart_range();
+
+        // Literal data ranges aren't supported for sparklines.
+        if range.is_literal {
+            eprintln!("Literal data ranges aren't supported for sparklines. The range is ignored.");
+        } else {
+            self.data_range = range;
+        }
+
         self
     }
 
@@ -1013,7 +1021,15 @@ impl Sparkline {
     where
         T: IntoChartRange,
     {
-        self.date_range = range.new_chart_range();
+        let range = range.new_chart_range();
+
+        // Literal data ranges aren't supported for sparklines.
+        if range.is_literal {
+            eprintln!("Literal data ranges aren't supported for sparklines. The range is ignored.");
+        } else {
+            self.date_range = range;
+        }
+
         self
     }
 
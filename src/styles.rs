@@ -15,8 +15,8 @@ use crate::xmlwriter::{
     xml_start_tag_only,
 };
 use crate::{
-    Alignment, Border, Color, Fill, Font, FormatAlign, FormatBorder, FormatDiagonalBorder,
-    FormatPattern, FormatScript, FormatUnderline,
+    Alignment, Border, Color, CustomTableStyle, Fill, Font, FormatAlign, FormatBorder,
+    FormatDiagonalBorder, FormatPattern, FormatScript, FormatUnderline,
 };
 
 pub struct Styles<'a> {
@@ -31,6 +31,17 @@ pub struct Styles<'a> {
     has_comments: bool,
     is_rich_string_style: bool,
     hyperlink_font_id: u16,
+    pub(crate) table_styles: &'a [CustomTableStyle],
+    pub(crate) named_styles: &'a [NamedStyle],
+}
+
+// A user defined named cell style, such as "Heading" or "Currency", that is
+// displayed in the Excel "Cell Styles" gallery.
+#[derive(Clone)]
+pub(crate) struct NamedStyle {
+    pub(crate) name: String,
+    pub(crate) format: Format,
+    pub(crate) xf_index: u32,
 }
 
 impl<'a> Styles<'a> {
@@ -66,6 +77,8 @@ impl<'a> Styles<'a> {
             has_comments,
             is_rich_string_style,
             hyperlink_font_id: 0,
+            table_styles: &[],
+            named_styles: &[],
         }
     }
 
@@ -500,10 +513,7 @@ impl<'a> Styles<'a> {
 
     // Write the <cellStyleXfs> element.
     fn write_cell_style_xfs(&mut self) {
-        let mut count = 1;
-        if self.has_hyperlink_style {
-            count = 2;
-        }
+        let count = self.named_style_xf_offset() + self.named_styles.len();
 
         let attributes = [("count", count.to_string())];
 
@@ -516,9 +526,69 @@ impl<'a> Styles<'a> {
             self.write_hyperlink_style_xf();
         }
 
+        for named_style in self.named_styles {
+            self.write_named_style_xf(&self.xf_formats[named_style.xf_index as usize]);
+        }
+
         xml_end_tag(&mut self.writer, "cellStyleXfs");
     }
 
+    // Write the style <xf> element for a user defined named style.
+    fn write_named_style_xf(&mut self, xf_format: &Format) {
+        let has_alignment = xf_format.has_alignment();
+        let has_protection = xf_format.has_protection();
+
+        let attributes = [
+            ("numFmtId", xf_format.num_format_index.to_string()),
+            ("fontId", xf_format.font_index.to_string()),
+            ("fillId", xf_format.fill_index.to_string()),
+            ("borderId", xf_format.border_index.to_string()),
+        ];
+
+        if has_alignment || has_protection {
+            xml_start_tag(&mut self.writer, "xf", &attributes);
+
+            if has_alignment {
+                self.write_alignment(xf_format.alignment);
+            }
+
+            if has_protection {
+                self.write_protection(xf_format);
+            }
+
+            xml_end_tag(&mut self.writer, "xf");
+        } else {
+            xml_empty_tag(&mut self.writer, "xf", &attributes);
+        }
+    }
+
+    // Get the index of the first user defined named style in the cellStyleXfs
+    // elements. This follows the "Normal" and optional "Hyperlink" styles.
+    fn named_style_xf_offset(&self) -> usize {
+        if self.has_hyperlink_style {
+            2
+        } else {
+            1
+        }
+    }
+
+    // Get the cellStyleXfs index for a cell format. This is the index of the
+    // named style that the format is based on, if any, or else the index of
+    // the "Hyperlink" or "Normal" style.
+    fn cell_style_xf_id(&self, xf_format: &Format) -> usize {
+        if !xf_format.cell_style.is_empty() {
+            if let Some(position) = self
+                .named_styles
+                .iter()
+                .position(|named_style| named_style.name == xf_format.cell_style)
+            {
+                return self.named_style_xf_offset() + position;
+            }
+        }
+
+        usize::from(xf_format.font.is_hyperlink)
+    }
+
     // Write the style <xf> element for the "Normal" style.
     fn write_normal_style_xf(&mut self) {
         let attributes = [
@@ -587,7 +657,7 @@ impl<'a> Styles<'a> {
         let has_alignment = xf_format.has_alignment();
         let has_protection = xf_format.has_protection();
         let apply_alignment = xf_format.apply_alignment();
-        let xf_id = i32::from(is_hyperlink);
+        let xf_id = self.cell_style_xf_id(xf_format);
 
         let mut attributes = vec![
             ("numFmtId", xf_format.num_format_index.to_string()),
@@ -762,10 +832,8 @@ impl<'a> Styles<'a> {
 
     // Write the <cellStyles> element.
     fn write_cell_styles(&mut self) {
-        let mut count = 1;
-        if self.has_hyperlink_style {
-            count = 2;
-        }
+        let offset = self.named_style_xf_offset();
+        let count = offset + self.named_styles.len();
 
         let attributes = [("count", count.to_string())];
 
@@ -777,9 +845,20 @@ impl<'a> Styles<'a> {
         }
         self.write_normal_cell_style();
 
+        for (index, named_style) in self.named_styles.iter().enumerate() {
+            self.write_named_cell_style(&named_style.name, offset + index);
+        }
+
         xml_end_tag(&mut self.writer, "cellStyles");
     }
 
+    // Write the <cellStyle> element for a user defined named style.
+    fn write_named_cell_style(&mut self, name: &str, xf_id: usize) {
+        let attributes = [("name", name.to_string()), ("xfId", xf_id.to_string())];
+
+        xml_empty_tag(&mut self.writer, "cellStyle", &attributes);
+    }
+
     // Write the <cellStyle> element for the "Normal" style.
     fn write_normal_cell_style(&mut self) {
         let attributes = [("name", "Normal"), ("xfId", "0"), ("builtinId", "0")];
@@ -804,44 +883,88 @@ impl<'a> Styles<'a> {
             xml_start_tag(&mut self.writer, "dxfs", &attributes);
 
             for dxf_format in self.dxf_formats {
-                xml_start_tag_only(&mut self.writer, "dxf");
+                self.write_dxf(dxf_format, "dxf");
+            }
 
-                if dxf_format.has_dxf_font() {
-                    self.write_font(&dxf_format.font, true);
-                }
+            xml_end_tag(&mut self.writer, "dxfs");
+        }
+    }
 
-                if dxf_format.num_format_index > 0 {
-                    self.write_num_fmt(dxf_format.num_format_index, &dxf_format.num_format);
-                }
+    // Write the <dxf> element. The tag name is configurable so that the element
+    // can also be written as <x14:dxf> in worksheet conditional formats.
+    pub(crate) fn write_dxf(&mut self, dxf_format: &Format, tag: &str) {
+        xml_start_tag_only(&mut self.writer, tag);
 
-                if dxf_format.has_dxf_fill() {
-                    self.write_fill(&dxf_format.fill, true);
-                }
+        if dxf_format.has_dxf_font() {
+            self.write_font(&dxf_format.font, true);
+        }
 
-                if dxf_format.has_border {
-                    self.write_border(&dxf_format.borders, true);
-                }
+        if dxf_format.num_format_index > 0 {
+            self.write_num_fmt(dxf_format.num_format_index, &dxf_format.num_format);
+        }
 
-                if dxf_format.has_checkbox() {
-                    self.write_dxf_format_extensions();
-                }
+        if dxf_format.has_dxf_fill() {
+            self.write_fill(&dxf_format.fill, true);
+        }
 
-                xml_end_tag(&mut self.writer, "dxf");
-            }
+        if dxf_format.has_border {
+            self.write_border(&dxf_format.borders, true);
+        }
 
-            xml_end_tag(&mut self.writer, "dxfs");
+        if dxf_format.has_checkbox() {
+            self.write_dxf_format_extensions();
         }
+
+        xml_end_tag(&mut self.writer, tag);
     }
 
     // Write the <tableStyles> element.
     fn write_table_styles(&mut self) {
         let attributes = [
-            ("count", "0"),
-            ("defaultTableStyle", "TableStyleMedium9"),
-            ("defaultPivotStyle", "PivotStyleLight16"),
+            ("count", self.table_styles.len().to_string()),
+            ("defaultTableStyle", "TableStyleMedium9".to_string()),
+            ("defaultPivotStyle", "PivotStyleLight16".to_string()),
         ];
 
-        xml_empty_tag(&mut self.writer, "tableStyles", &attributes);
+        if self.table_styles.is_empty() {
+            xml_empty_tag(&mut self.writer, "tableStyles", &attributes);
+            return;
+        }
+
+        xml_start_tag(&mut self.writer, "tableStyles", &attributes);
+
+        for table_style in self.table_styles {
+            self.write_table_style(table_style);
+        }
+
+        xml_end_tag(&mut self.writer, "tableStyles");
+    }
+
+    // Write the <tableStyle> element.
+    fn write_table_style(&mut self, table_style: &CustomTableStyle) {
+        let attributes = [
+            ("name", table_style.name.clone()),
+            ("pivot", "0".to_string()),
+            ("count", table_style.elements.len().to_string()),
+        ];
+
+        xml_start_tag(&mut self.writer, "tableStyle", &attributes);
+
+        for element in &table_style.elements {
+            let mut attributes = vec![("type", element.element_type.to_string())];
+
+            if element.size > 1 {
+                attributes.push(("size", element.size.to_string()));
+            }
+
+            if let Some(dxf_index) = element.dxf_index {
+                attributes.push(("dxfId", dxf_index.to_string()));
+            }
+
+            xml_empty_tag(&mut self.writer, "tableStyleElement", &attributes);
+        }
+
+        xml_end_tag(&mut self.writer, "tableStyle");
     }
 
     // Write the <numFmts> element.
@@ -7,9 +7,9 @@
 #[cfg(test)]
 mod styles_tests {
 
-    use crate::styles::Styles;
+    use crate::styles::{NamedStyle, Styles};
     use crate::test_functions::xml_to_vec;
-    use crate::{xmlwriter, Format};
+    use crate::{xmlwriter, CustomTableStyle, Format};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -84,4 +84,209 @@ mod styles_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_table_styles() {
+        let mut xf_format = Format::new();
+        xf_format.set_font_index(0, true);
+        xf_format.set_border_index(0, true);
+
+        let mut dxf_format = Format::new().set_bold();
+        dxf_format.is_dxf_format = true;
+
+        let xf_formats = vec![xf_format];
+        let dxf_formats = vec![dxf_format];
+        let mut styles = Styles::new(
+            &xf_formats,
+            &dxf_formats,
+            1,
+            2,
+            1,
+            vec![],
+            false,
+            false,
+            false,
+        );
+
+        let mut table_style = CustomTableStyle::new("MyStyle")
+            .set_header_row_format(&Format::new().set_bold())
+            .set_first_row_stripe_size(2);
+
+        table_style.elements[0].dxf_index = Some(0);
+        let table_styles = vec![table_style];
+        styles.table_styles = &table_styles;
+
+        styles.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&styles.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+                <fonts count="1">
+                    <font>
+                    <sz val="11"/>
+                    <color theme="1"/>
+                    <name val="Calibri"/>
+                    <family val="2"/>
+                    <scheme val="minor"/>
+                    </font>
+                </fonts>
+                <fills count="2">
+                    <fill>
+                    <patternFill patternType="none"/>
+                    </fill>
+                    <fill>
+                    <patternFill patternType="gray125"/>
+                    </fill>
+                </fills>
+                <borders count="1">
+                    <border>
+                    <left/>
+                    <right/>
+                    <top/>
+                    <bottom/>
+                    <diagonal/>
+                    </border>
+                </borders>
+                <cellStyleXfs count="1">
+                    <xf numFmtId="0" fontId="0" fillId="0" borderId="0"/>
+                </cellStyleXfs>
+                <cellXfs count="1">
+                    <xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/>
+                </cellXfs>
+                <cellStyles count="1">
+                    <cellStyle name="Normal" xfId="0" builtinId="0"/>
+                </cellStyles>
+                <dxfs count="1">
+                    <dxf>
+                    <font>
+                        <b/>
+                    </font>
+                    </dxf>
+                </dxfs>
+                <tableStyles count="1" defaultTableStyle="TableStyleMedium9" defaultPivotStyle="PivotStyleLight16">
+                    <tableStyle name="MyStyle" pivot="0" count="2">
+                    <tableStyleElement type="headerRow" dxfId="0"/>
+                    <tableStyleElement type="firstRowStripe" size="2"/>
+                    </tableStyle>
+                </tableStyles>
+                </styleSheet>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_named_styles() {
+        let mut xf_format = Format::new();
+        xf_format.set_font_index(0, true);
+        xf_format.set_border_index(0, true);
+
+        let mut style_format = Format::new().set_bold();
+        style_format.cell_style = "My Style".to_string();
+        style_format.set_font_index(1, true);
+        style_format.set_border_index(0, false);
+
+        let mut cell_format = style_format.clone().set_italic();
+        cell_format.set_font_index(2, true);
+        cell_format.set_border_index(0, false);
+
+        let xf_formats = vec![xf_format, style_format.clone(), cell_format];
+        let dxf_formats = vec![];
+        let mut styles = Styles::new(
+            &xf_formats,
+            &dxf_formats,
+            3,
+            2,
+            1,
+            vec![],
+            false,
+            false,
+            false,
+        );
+
+        let named_styles = vec![NamedStyle {
+            name: "My Style".to_string(),
+            format: style_format,
+            xf_index: 1,
+        }];
+        styles.named_styles = &named_styles;
+
+        styles.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&styles.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <styleSheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">
+                <fonts count="3">
+                    <font>
+                    <sz val="11"/>
+                    <color theme="1"/>
+                    <name val="Calibri"/>
+                    <family val="2"/>
+                    <scheme val="minor"/>
+                    </font>
+                    <font>
+                    <b/>
+                    <sz val="11"/>
+                    <color theme="1"/>
+                    <name val="Calibri"/>
+                    <family val="2"/>
+                    <scheme val="minor"/>
+                    </font>
+                    <font>
+                    <b/>
+                    <i/>
+                    <sz val="11"/>
+                    <color theme="1"/>
+                    <name val="Calibri"/>
+                    <family val="2"/>
+                    <scheme val="minor"/>
+                    </font>
+                </fonts>
+                <fills count="2">
+                    <fill>
+                    <patternFill patternType="none"/>
+                    </fill>
+                    <fill>
+                    <patternFill patternType="gray125"/>
+                    </fill>
+                </fills>
+                <borders count="1">
+                    <border>
+                    <left/>
+                    <right/>
+                    <top/>
+                    <bottom/>
+                    <diagonal/>
+                    </border>
+                </borders>
+                <cellStyleXfs count="2">
+                    <xf numFmtId="0" fontId="0" fillId="0" borderId="0"/>
+                    <xf numFmtId="0" fontId="1" fillId="0" borderId="0"/>
+                </cellStyleXfs>
+                <cellXfs count="3">
+                    <xf numFmtId="0" fontId="0" fillId="0" borderId="0" xfId="0"/>
+                    <xf numFmtId="0" fontId="1" fillId="0" borderId="0" xfId="1" applyFont="1"/>
+                    <xf numFmtId="0" fontId="2" fillId="0" borderId="0" xfId="1" applyFont="1"/>
+                </cellXfs>
+                <cellStyles count="2">
+                    <cellStyle name="Normal" xfId="0" builtinId="0"/>
+                    <cellStyle name="My Style" xfId="1"/>
+                </cellStyles>
+                <dxfs count="0"/>
+                <tableStyles count="0" defaultTableStyle="TableStyleMedium9" defaultPivotStyle="PivotStyleLight16"/>
+                </styleSheet>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
@@ -116,6 +116,7 @@ pub struct Table {
     pub(crate) index: u32,
     pub(crate) name: String,
     pub(crate) style: TableStyle,
+    pub(crate) custom_style: Option<String>,
 
     pub(crate) cell_range: CellRange,
 
@@ -127,6 +128,7 @@ pub struct Table {
     pub(crate) show_banded_columns: bool,
     pub(crate) show_autofilter: bool,
     pub(crate) is_serde_table: bool,
+    pub(crate) has_external_headers: bool,
 }
 
 impl Table {
@@ -203,6 +205,7 @@ impl Table {
             index: 0,
             name: String::new(),
             style: TableStyle::Medium9,
+            custom_style: None,
             cell_range: CellRange::default(),
             show_first_column: false,
             show_last_column: false,
@@ -212,6 +215,7 @@ impl Table {
             show_header_row: true,
             show_total_row: false,
             is_serde_table: false,
+            has_external_headers: false,
         }
     }
 
@@ -1056,6 +1060,8 @@ impl Table {
     /// You can set one of these styles using a [`TableStyle`] enum value. The
     /// default table style in Excel is equivalent to [`TableStyle::Medium9`].
     ///
+    /// To use a user defined style see [`Table::set_custom_style()`].
+    ///
     /// # Parameters
     ///
     /// - `style`: a [`TableStyle`] enum value.
@@ -1111,6 +1117,29 @@ impl Table {
     ///
     pub fn set_style(mut self, style: TableStyle) -> Table {
         self.style = style;
+        self.custom_style = None;
+        self
+    }
+
+    /// Set a user defined style for a table.
+    ///
+    /// Set a [`CustomTableStyle`] for the table. The style must also be added
+    /// to the workbook with
+    /// [`Workbook::add_table_style()`](crate::Workbook::add_table_style)
+    /// before the file is saved. Style names are matched case-insensitively,
+    /// like in Excel.
+    ///
+    /// This overrides any style set with [`Table::set_style()`], and vice
+    /// versa.
+    ///
+    /// See [`CustomTableStyle`] for an example.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: A reference to a [`CustomTableStyle`] object.
+    ///
+    pub fn set_custom_style(mut self, style: &CustomTableStyle) -> Table {
+        self.custom_style = Some(style.name.clone());
         self
     }
 
@@ -1314,7 +1343,9 @@ impl Table {
     fn write_table_style_info(&mut self) {
         let mut attributes = vec![];
 
-        if self.style != TableStyle::None {
+        if let Some(name) = &self.custom_style {
+            attributes.push(("name", name.clone()));
+        } else if self.style != TableStyle::None {
             attributes.push(("name", self.style.to_string()));
         }
 
@@ -1986,10 +2017,12 @@ pub enum TableFunction {
     /// Use the average function as the table total.
     Average,
 
-    /// Use the count function as the table total.
+    /// Use the count function as the table total. This counts the non-empty
+    /// cells in the column, like the Excel `COUNTA()` function.
     Count,
 
-    /// Use the count numbers function as the table total.
+    /// Use the count numbers function as the table total. This counts the
+    /// cells that contain numbers, like the Excel `COUNT()` function.
     CountNumbers,
 
     /// Use the max function as the table total.
@@ -2342,6 +2375,371 @@ impl fmt::Display for TableStyle {
     }
 }
 
+// -----------------------------------------------------------------------
+// CustomTableStyle
+// -----------------------------------------------------------------------
+
+/// The `CustomTableStyle` struct represents a user defined table style.
+///
+/// Excel allows users to define their own table styles in addition to the
+/// built-in Light, Medium and Dark styles of [`TableStyle`]. A custom table
+/// style is made up of formats for different elements of the table such as
+/// the whole table, the header row, the total row or the row stripes.
+///
+/// The formats are applied as "differential" formats, like conditional
+/// formats, so only the font, fill, border and number format properties of a
+/// [`Format`] are used.
+///
+/// A custom table style must be added to the workbook with
+/// [`Workbook::add_table_style()`](crate::Workbook::add_table_style) and can
+/// then be applied to any table with [`Table::set_custom_style()`]. Since the style is
+/// part of the table rather than the cells it stays in place when the table is
+/// sorted, filtered or extended in Excel.
+///
+/// # Examples
+///
+/// Example of adding a custom table style to a workbook and a table.
+///
+/// ```
+/// # // This code is available in examples/doc_custom_table_style.rs
+/// #
+/// # use rust_xlsxwriter::{Color, CustomTableStyle, Format, FormatBorder, Table, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     // Create a new Excel file object.
+/// #     let mut workbook = Workbook::new();
+/// #
+///     // Create a custom table style with some brand colors.
+///     let custom_style = CustomTableStyle::new("BrandStyle")
+///         .set_whole_table_format(
+///             &Format::new()
+///                 .set_border(FormatBorder::Thin)
+///                 .set_border_color("#1F3864"),
+///         )
+///         .set_header_row_format(
+///             &Format::new()
+///                 .set_bold()
+///                 .set_font_color(Color::White)
+///                 .set_background_color("#1F3864"),
+///         )
+///         .set_first_row_stripe_format(&Format::new().set_background_color("#D9E1F2"));
+///
+///     // Add the style to the workbook so it can be used by tables.
+///     workbook.add_table_style(&custom_style)?;
+///
+///     // Add a worksheet to the workbook.
+///     let worksheet = workbook.add_worksheet();
+/// #
+/// #     // Some sample data for the table.
+/// #     let items = ["Apples", "Pears", "Bananas", "Oranges"];
+/// #     let data = [
+/// #         [10000, 5000, 8000, 6000],
+/// #         [2000, 3000, 4000, 5000],
+/// #         [6000, 6000, 6500, 6000],
+/// #         [500, 300, 200, 700],
+/// #     ];
+/// #
+/// #     // Write the table data.
+/// #     worksheet.write_column(3, 1, items)?;
+/// #     worksheet.write_row_matrix(3, 2, data)?;
+/// #
+/// #     // Set the column widths for clarity.
+/// #     worksheet.set_column_range_width(1, 6, 12)?;
+///
+///     // Create a new table and set the custom style.
+///     let table = Table::new().set_custom_style(&custom_style);
+///
+///     // Add the table to the worksheet.
+///     worksheet.add_table(2, 1, 6, 5, &table)?;
+/// #
+/// #     // Save the file to disk.
+/// #     workbook.save("tables.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone)]
+pub struct CustomTableStyle {
+    pub(crate) name: String,
+    pub(crate) elements: Vec<TableStyleElement>,
+}
+
+impl CustomTableStyle {
+    /// Create a new `CustomTableStyle` object.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the style. This is displayed in the Excel table
+    ///   style gallery. It must be unique in the workbook and must not be the
+    ///   same as one of the Excel built-in style names.
+    ///
+    pub fn new(name: impl Into<String>) -> CustomTableStyle {
+        CustomTableStyle {
+            name: name.into(),
+            elements: vec![],
+        }
+    }
+
+    /// Set the format for the whole table.
+    ///
+    /// This format is applied to all cells in the table and is overridden by
+    /// the formats of the more specific table elements.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the table element.
+    ///
+    pub fn set_whole_table_format(self, format: &Format) -> CustomTableStyle {
+        self.set_element(TableStyleElementType::WholeTable, format)
+    }
+
+    /// Set the format for the table header row.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the table element.
+    ///
+    pub fn set_header_row_format(self, format: &Format) -> CustomTableStyle {
+        self.set_element(TableStyleElementType::HeaderRow, format)
+    }
+
+    /// Set the format for the table total row.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the table element.
+    ///
+    pub fn set_total_row_format(self, format: &Format) -> CustomTableStyle {
+        self.set_element(TableStyleElementType::TotalRow, format)
+    }
+
+    /// Set the format for the first column of the table.
+    ///
+    /// This is only displayed if the table first column option is on, see
+    /// [`Table::set_first_column()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the table element.
+    ///
+    pub fn set_first_column_format(self, format: &Format) -> CustomTableStyle {
+        self.set_element(TableStyleElementType::FirstColumn, format)
+    }
+
+    /// Set the format for the last column of the table.
+    ///
+    /// This is only displayed if the table last column option is on, see
+    /// [`Table::set_last_column()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the table element.
+    ///
+    pub fn set_last_column_format(self, format: &Format) -> CustomTableStyle {
+        self.set_element(TableStyleElementType::LastColumn, format)
+    }
+
+    /// Set the format for the first row stripe of the table.
+    ///
+    /// This is only displayed if the table banded rows option is on, see
+    /// [`Table::set_banded_rows()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the table element.
+    ///
+    pub fn set_first_row_stripe_format(self, format: &Format) -> CustomTableStyle {
+        self.set_element(TableStyleElementType::FirstRowStripe, format)
+    }
+
+    /// Set the format for the second row stripe of the table.
+    ///
+    /// This is only displayed if the table banded rows option is on, see
+    /// [`Table::set_banded_rows()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the table element.
+    ///
+    pub fn set_second_row_stripe_format(self, format: &Format) -> CustomTableStyle {
+        self.set_element(TableStyleElementType::SecondRowStripe, format)
+    }
+
+    /// Set the format for the first column stripe of the table.
+    ///
+    /// This is only displayed if the table banded columns option is on, see
+    /// [`Table::set_banded_columns()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the table element.
+    ///
+    pub fn set_first_column_stripe_format(self, format: &Format) -> CustomTableStyle {
+        self.set_element(TableStyleElementType::FirstColumnStripe, format)
+    }
+
+    /// Set the format for the second column stripe of the table.
+    ///
+    /// This is only displayed if the table banded columns option is on, see
+    /// [`Table::set_banded_columns()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the table element.
+    ///
+    pub fn set_second_column_stripe_format(self, format: &Format) -> CustomTableStyle {
+        self.set_element(TableStyleElementType::SecondColumnStripe, format)
+    }
+
+    /// Set the number of rows in the first row stripe.
+    ///
+    /// By default each row stripe is 1 row high. Excel allows stripe sizes in
+    /// the range 1-9.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of rows in the stripe.
+    ///
+    pub fn set_first_row_stripe_size(self, size: u8) -> CustomTableStyle {
+        self.set_element_size(TableStyleElementType::FirstRowStripe, size)
+    }
+
+    /// Set the number of rows in the second row stripe.
+    ///
+    /// See [`CustomTableStyle::set_first_row_stripe_size()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of rows in the stripe.
+    ///
+    pub fn set_second_row_stripe_size(self, size: u8) -> CustomTableStyle {
+        self.set_element_size(TableStyleElementType::SecondRowStripe, size)
+    }
+
+    /// Set the number of columns in the first column stripe.
+    ///
+    /// By default each column stripe is 1 column wide. Excel allows stripe
+    /// sizes in the range 1-9.
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of columns in the stripe.
+    ///
+    pub fn set_first_column_stripe_size(self, size: u8) -> CustomTableStyle {
+        self.set_element_size(TableStyleElementType::FirstColumnStripe, size)
+    }
+
+    /// Set the number of columns in the second column stripe.
+    ///
+    /// See [`CustomTableStyle::set_first_column_stripe_size()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `size`: The number of columns in the stripe.
+    ///
+    pub fn set_second_column_stripe_size(self, size: u8) -> CustomTableStyle {
+        self.set_element_size(TableStyleElementType::SecondColumnStripe, size)
+    }
+
+    // Add or replace the format for a table style element. The elements are
+    // kept in the order that Excel writes them.
+    fn set_element(
+        mut self,
+        element_type: TableStyleElementType,
+        format: &Format,
+    ) -> CustomTableStyle {
+        match self
+            .elements
+            .iter_mut()
+            .find(|element| element.element_type == element_type)
+        {
+            Some(element) => element.format = Some(format.clone()),
+            None => {
+                self.elements.push(TableStyleElement {
+                    element_type,
+                    format: Some(format.clone()),
+                    size: 1,
+                    dxf_index: None,
+                });
+                self.elements.sort_by_key(|element| element.element_type);
+            }
+        }
+
+        self
+    }
+
+    // Set the size for a table style stripe element.
+    fn set_element_size(
+        mut self,
+        element_type: TableStyleElementType,
+        size: u8,
+    ) -> CustomTableStyle {
+        if !(1..=9).contains(&size) {
+            eprintln!("Table style stripe size '{size}' outside Excel range: 1 <= size <= 9.");
+            return self;
+        }
+
+        match self
+            .elements
+            .iter_mut()
+            .find(|element| element.element_type == element_type)
+        {
+            Some(element) => element.size = size,
+            None => {
+                self.elements.push(TableStyleElement {
+                    element_type,
+                    format: None,
+                    size,
+                    dxf_index: None,
+                });
+                self.elements.sort_by_key(|element| element.element_type);
+            }
+        }
+
+        self
+    }
+}
+
+// A table style element such as the header row or a row stripe.
+#[derive(Clone)]
+pub(crate) struct TableStyleElement {
+    pub(crate) element_type: TableStyleElementType,
+    pub(crate) format: Option<Format>,
+    pub(crate) size: u8,
+    pub(crate) dxf_index: Option<u32>,
+}
+
+// The table style elements in the order that Excel writes them.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum TableStyleElementType {
+    WholeTable,
+    HeaderRow,
+    TotalRow,
+    FirstColumn,
+    LastColumn,
+    FirstRowStripe,
+    SecondRowStripe,
+    FirstColumnStripe,
+    SecondColumnStripe,
+}
+
+impl fmt::Display for TableStyleElementType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WholeTable => write!(f, "wholeTable"),
+            Self::HeaderRow => write!(f, "headerRow"),
+            Self::TotalRow => write!(f, "totalRow"),
+            Self::FirstColumn => write!(f, "firstColumn"),
+            Self::LastColumn => write!(f, "lastColumn"),
+            Self::FirstRowStripe => write!(f, "firstRowStripe"),
+            Self::SecondRowStripe => write!(f, "secondRowStripe"),
+            Self::FirstColumnStripe => write!(f, "firstColumnStripe"),
+            Self::SecondColumnStripe => write!(f, "secondColumnStripe"),
+        }
+    }
+}
+
 /// Convert a [`Table`] ref to a [`Table`] object.
 ///
 /// This is used as a syntactic shortcut for serialize APIs to allow either
@@ -9,7 +9,9 @@ mod table_tests {
 
     use crate::table::Table;
     use crate::test_functions::xml_to_vec;
-    use crate::{xmlwriter, TableColumn, TableFunction, Worksheet, XlsxError};
+    use crate::{
+        xmlwriter, CustomTableStyle, Format, TableColumn, TableFunction, Worksheet, XlsxError,
+    };
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -580,4 +582,154 @@ mod table_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble11() {
+        let custom_style =
+            CustomTableStyle::new("MyStyle").set_header_row_format(&Format::new().set_bold());
+
+        let mut table = Table::new().set_custom_style(&custom_style);
+        let worksheet = Worksheet::new();
+
+        table.cell_range.first_row = 2;
+        table.cell_range.first_col = 2;
+        table.cell_range.last_row = 12;
+        table.cell_range.last_col = 5;
+        table.index = 1;
+
+        let default_headers = worksheet.default_table_headers(
+            table.cell_range.first_row,
+            table.cell_range.first_col,
+            table.cell_range.last_col,
+            table.show_header_row,
+        );
+
+        table.initialize_columns(&default_headers).unwrap();
+        table.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&table.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <table xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" id="1" name="Table1" displayName="Table1" ref="C3:F13" totalsRowShown="0">
+                <autoFilter ref="C3:F13"/>
+                <tableColumns count="4">
+                    <tableColumn id="1" name="Column1"/>
+                    <tableColumn id="2" name="Column2"/>
+                    <tableColumn id="3" name="Column3"/>
+                    <tableColumn id="4" name="Column4"/>
+                </tableColumns>
+                <tableStyleInfo name="MyStyle" showFirstColumn="0" showLastColumn="0" showRowStripes="1" showColumnStripes="0"/>
+                </table>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_table_resize_errors() {
+        let mut worksheet = Worksheet::new();
+
+        let table = Table::new().set_name("Table1").set_total_row(true);
+        worksheet.add_table(0, 0, 4, 2, &table).unwrap();
+
+        let table = Table::new().set_name("Table2");
+        worksheet.add_table(8, 0, 10, 2, &table).unwrap();
+
+        // Unknown table name.
+        let result = worksheet.resize_table("Table3", 6);
+        assert!(matches!(result, Err(XlsxError::TableError(_))));
+
+        // Table with only a header row.
+        let result = worksheet.resize_table("Table1", 0);
+        assert!(matches!(result, Err(XlsxError::TableError(_))));
+
+        // Overlaps the second table.
+        let result = worksheet.resize_table("Table1", 8);
+        assert!(matches!(result, Err(XlsxError::TableRangeOverlaps(_, _))));
+
+        // More values than columns.
+        let result = worksheet.table_append_row("table1", [1, 2, 3, 4]);
+        assert!(matches!(result, Err(XlsxError::TableError(_))));
+
+        // Valid resizes, using case-insensitive names.
+        worksheet.resize_table("table1", 7).unwrap();
+        worksheet.table_append_row("TABLE2", [1, 2, 3]).unwrap();
+        worksheet.resize_table("Table1", 1).unwrap();
+
+        assert_eq!(worksheet.tables[0].cell_range.last_row, 1);
+        assert_eq!(worksheet.tables[1].cell_range.last_row, 11);
+    }
+
+    #[test]
+    fn test_table_shrink_clears_column_data() {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_selected(true);
+
+        let columns = vec![
+            TableColumn::new().set_format(Format::new().set_bold()),
+            TableColumn::new().set_formula("Table1[@[Column1]]*2"),
+        ];
+
+        let table = Table::new()
+            .set_name("Table1")
+            .set_columns(&columns)
+            .set_header_row(false);
+
+        worksheet.write_column(0, 0, [1, 2, 3]).unwrap();
+        worksheet.add_table(0, 0, 2, 1, &table).unwrap();
+
+        // The format and formula should be removed from the last 2 rows but
+        // the data should be retained.
+        worksheet.resize_table("Table1", 0).unwrap();
+
+        worksheet.set_global_xf_indices(&[0, 1]);
+        worksheet.assemble_xml_file();
+
+        let got = xmlwriter::cursor_to_str(&worksheet.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1:B3"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData>
+                <row r="1" spans="1:2">
+                  <c r="A1" s="1">
+                    <v>1</v>
+                  </c>
+                  <c r="B1">
+                    <f>Table1[[#This Row],[Column1]]*2</f>
+                    <v>0</v>
+                  </c>
+                </row>
+                <row r="2" spans="1:2">
+                  <c r="A2">
+                    <v>2</v>
+                  </c>
+                </row>
+                <row r="3" spans="1:2">
+                  <c r="A3">
+                    <v>3</v>
+                  </c>
+                </row>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+              <tableParts count="1">
+                <tablePart r:id="rId1"/>
+              </tableParts>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
@@ -27,8 +27,9 @@
 #![warn(missing_docs)]
 mod tests;
 
+use crate::font_metrics::CellFont;
+use crate::Format;
 use crate::COL_MAX;
-use crate::MAX_AUTOFIT_WIDTH_PIXELS;
 use crate::ROW_MAX;
 
 #[cfg(feature = "serde")]
@@ -519,6 +520,248 @@ where
     }
 }
 
+/// Serialize a `time` crate date/time to an Excel value.
+///
+/// This is a helper function for serializing [`time`] crate date/time fields
+/// using [Serde](https://serde.rs).
+///
+/// The function works for the following types:
+///   - [`PrimitiveDateTime`]
+///   - [`OffsetDateTime`]: the date and time in its offset is used.
+///   - [`Date`]
+///   - [`Time`]
+///
+/// [`time`]: https://docs.rs/time/latest/time
+/// [`PrimitiveDateTime`]:
+///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+/// [`OffsetDateTime`]:
+///     https://docs.rs/time/latest/time/struct.OffsetDateTime.html
+/// [`Date`]: https://docs.rs/time/latest/time/struct.Date.html
+/// [`Time`]: https://docs.rs/time/latest/time/struct.Time.html
+///
+/// `Option<T>` `time` types can be handled with
+/// [`serialize_time_option_to_excel()`].
+///
+/// See [Working with Serde](crate::serializer#working-with-serde) for more
+/// information about serialization with `rust_xlsxwriter`.
+///
+/// # Parameters
+///
+/// - `datetime`: A date/time instance that implements [`IntoExcelDateTime`].
+/// - `serializer`: A type/instance that implements the [`serde`] `Serializer`
+///   trait.
+///
+/// # Errors
+///
+/// - [`XlsxError::SerdeError`] - A wrapped serialization error.
+///
+/// # Examples
+///
+/// Example of a serializable struct with a `time` crate date value with a
+/// helper function.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_serialize_datetime_time.rs
+/// #
+/// use serde::Serialize;
+/// use time::Date;
+///
+/// use rust_xlsxwriter::utility::serialize_time_to_excel;
+///
+/// fn main() {
+///     #[derive(Serialize)]
+///     struct Student {
+///         full_name: String,
+///
+///         #[serde(serialize_with = "serialize_time_to_excel")]
+///         birth_date: Date,
+///
+///         id_number: u32,
+///     }
+/// }
+/// ```
+///
+#[cfg(all(feature = "serde", feature = "time"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "time"))))]
+pub fn serialize_time_to_excel<S>(
+    datetime: impl IntoExcelDateTime,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_f64(datetime.to_excel_serial_date())
+}
+
+/// Serialize an `Option` `time` crate date/time to an Excel value.
+///
+/// This is a helper function for serializing [`time`] crate date/time fields
+/// using [Serde](https://serde.rs). A `None` value is serialized as a blank
+/// cell with the same cell format as other values of the field type.
+///
+/// The function works for the following `Option<T>` where T is:
+///   - [`PrimitiveDateTime`]
+///   - [`OffsetDateTime`]: the date and time in its offset is used.
+///   - [`Date`]
+///   - [`Time`]
+///
+/// [`time`]: https://docs.rs/time/latest/time
+/// [`PrimitiveDateTime`]:
+///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+/// [`OffsetDateTime`]:
+///     https://docs.rs/time/latest/time/struct.OffsetDateTime.html
+/// [`Date`]: https://docs.rs/time/latest/time/struct.Date.html
+/// [`Time`]: https://docs.rs/time/latest/time/struct.Time.html
+///
+/// Non `Option<T>` `time` types can be handled with
+/// [`serialize_time_to_excel()`].
+///
+/// # Parameters
+///
+/// - `datetime`: A date/time instance that implements [`IntoExcelDateTime`]
+///   wrapped in an [`Option`].
+/// - `serializer`: A type/instance that implements the [`serde`] `Serializer`
+///   trait.
+///
+/// # Errors
+///
+/// - [`XlsxError::SerdeError`] - A wrapped serialization error.
+///
+#[cfg(all(feature = "serde", feature = "time"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "time"))))]
+pub fn serialize_time_option_to_excel<S>(
+    datetime: &Option<impl IntoExcelDateTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match datetime {
+        Some(datetime) => serializer.serialize_f64(datetime.to_excel_serial_date()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Serialize a Jiff date/time to an Excel value.
+///
+/// This is a helper function for serializing [`Jiff`] date/time fields
+/// using [Serde](https://serde.rs).
+///
+/// The function works for the following types:
+///   - [`civil::DateTime`]
+///   - [`Zoned`]: the civil date and time in its time zone is used.
+///   - [`civil::Date`]
+///   - [`civil::Time`]
+///
+/// [`Jiff`]: https://docs.rs/jiff/latest/jiff
+/// [`civil::DateTime`]:
+///     https://docs.rs/jiff/latest/jiff/civil/struct.DateTime.html
+/// [`Zoned`]: https://docs.rs/jiff/latest/jiff/struct.Zoned.html
+/// [`civil::Date`]: https://docs.rs/jiff/latest/jiff/civil/struct.Date.html
+/// [`civil::Time`]: https://docs.rs/jiff/latest/jiff/civil/struct.Time.html
+///
+/// `Option<T>` Jiff types can be handled with
+/// [`serialize_jiff_option_to_excel()`].
+///
+/// See [Working with Serde](crate::serializer#working-with-serde) for more
+/// information about serialization with `rust_xlsxwriter`.
+///
+/// # Parameters
+///
+/// - `datetime`: A date/time instance that implements [`IntoExcelDateTime`].
+/// - `serializer`: A type/instance that implements the [`serde`] `Serializer`
+///   trait.
+///
+/// # Errors
+///
+/// - [`XlsxError::SerdeError`] - A wrapped serialization error.
+///
+/// # Examples
+///
+/// Example of a serializable struct with a Jiff date value with a
+/// helper function.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_serialize_datetime_jiff.rs
+/// #
+/// use jiff::civil::Date;
+/// use serde::Serialize;
+///
+/// use rust_xlsxwriter::utility::serialize_jiff_to_excel;
+///
+/// fn main() {
+///     #[derive(Serialize)]
+///     struct Student {
+///         full_name: String,
+///
+///         #[serde(serialize_with = "serialize_jiff_to_excel")]
+///         birth_date: Date,
+///
+///         id_number: u32,
+///     }
+/// }
+/// ```
+///
+#[cfg(all(feature = "serde", feature = "jiff"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "jiff"))))]
+pub fn serialize_jiff_to_excel<S>(
+    datetime: impl IntoExcelDateTime,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_f64(datetime.to_excel_serial_date())
+}
+
+/// Serialize an `Option` Jiff date/time to an Excel value.
+///
+/// This is a helper function for serializing [`Jiff`] date/time fields
+/// using [Serde](https://serde.rs). A `None` value is serialized as a blank
+/// cell with the same cell format as other values of the field type.
+///
+/// The function works for the following `Option<T>` where T is:
+///   - [`civil::DateTime`]
+///   - [`Zoned`]: the civil date and time in its time zone is used.
+///   - [`civil::Date`]
+///   - [`civil::Time`]
+///
+/// [`Jiff`]: https://docs.rs/jiff/latest/jiff
+/// [`civil::DateTime`]:
+///     https://docs.rs/jiff/latest/jiff/civil/struct.DateTime.html
+/// [`Zoned`]: https://docs.rs/jiff/latest/jiff/struct.Zoned.html
+/// [`civil::Date`]: https://docs.rs/jiff/latest/jiff/civil/struct.Date.html
+/// [`civil::Time`]: https://docs.rs/jiff/latest/jiff/civil/struct.Time.html
+///
+/// Non `Option<T>` Jiff types can be handled with
+/// [`serialize_jiff_to_excel()`].
+///
+/// # Parameters
+///
+/// - `datetime`: A date/time instance that implements [`IntoExcelDateTime`]
+///   wrapped in an [`Option`].
+/// - `serializer`: A type/instance that implements the [`serde`] `Serializer`
+///   trait.
+///
+/// # Errors
+///
+/// - [`XlsxError::SerdeError`] - A wrapped serialization error.
+///
+#[cfg(all(feature = "serde", feature = "jiff"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "jiff"))))]
+pub fn serialize_jiff_option_to_excel<S>(
+    datetime: &Option<impl IntoExcelDateTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match datetime {
+        Some(datetime) => serializer.serialize_f64(datetime.to_excel_serial_date()),
+        None => serializer.serialize_none(),
+    }
+}
+
 // Convert zero indexed row and col cell references to a non-absolute chart
 // "Sheet1!A1:B1" style range string.
 pub(crate) fn chart_range(
@@ -981,8 +1224,8 @@ pub(crate) fn validate_vba_name(name: &str) -> Result<(), XlsxError> {
 /// Notes:
 ///
 /// - The width calculation is based on the default Excel font type of Calibri
-///   and character size of 11. It will not give correct results for other fonts
-///   or font sizes.
+///   and character size of 11. Use [`cell_autofit_width_with_format()`] for
+///   strings in other fonts or font sizes.
 ///
 /// - If you are autofitting a header with an autofilter dropdown you should add
 ///   an additional 6 pixels to account for the dropdown symbol.
@@ -1043,49 +1286,44 @@ pub fn cell_autofit_width(string: &str) -> u16 {
     pixel_width(string) + cell_padding
 }
 
-// Get the pixel width of a string based on character widths taken from Excel.
-// Non-ascii characters are given a default width of 8 pixels.
-#[allow(clippy::match_same_arms)]
-pub(crate) fn pixel_width(string: &str) -> u16 {
-    let mut length = 0;
-
-    // Limit the autofit width to Excel's limit of 1790 pixels.
-    if string.chars().count() > 233 {
-        return MAX_AUTOFIT_WIDTH_PIXELS;
-    }
-
-    for char in string.chars() {
-        match char {
-            ' ' | '\'' => length += 3,
-
-            ',' | '.' | ':' | ';' | 'I' | '`' | 'i' | 'j' | 'l' => length += 4,
-
-            '!' | '(' | ')' | '-' | 'J' | '[' | ']' | 'f' | 'r' | 't' | '{' | '}' => length += 5,
-
-            '"' | '/' | 'L' | '\\' | 'c' | 's' | 'z' => length += 6,
-
-            '#' | '$' | '*' | '+' | '0' | '1' | '2' | '3' | '4' | '5' | '6' | '7' | '8' | '9'
-            | '<' | '=' | '>' | '?' | 'E' | 'F' | 'S' | 'T' | 'Y' | 'Z' | '^' | '_' | 'a' | 'g'
-            | 'k' | 'v' | 'x' | 'y' | '|' | '~' => length += 7,
-
-            'B' | 'C' | 'K' | 'P' | 'R' | 'X' | 'b' | 'd' | 'e' | 'h' | 'n' | 'o' | 'p' | 'q'
-            | 'u' => length += 8,
-
-            'A' | 'D' | 'G' | 'H' | 'U' | 'V' => length += 9,
-
-            '&' | 'N' | 'O' | 'Q' => length += 10,
-
-            '%' | 'w' => length += 11,
-
-            'M' | 'm' => length += 12,
-
-            '@' | 'W' => length += 13,
+/// Calculate the width required to auto-fit a string in a cell with a format.
+///
+/// This function is similar to [`cell_autofit_width()`], see above, except
+/// that the width is calculated using the font name, size and bold property of
+/// the format. Widths are available for Calibri, Arial, Times New Roman and
+/// Courier New, and their metric compatible equivalents. Other fonts use the
+/// Calibri widths scaled to the font size.
+///
+/// # Parameters
+///
+/// - `string`: The string reference to calculate the cell width.
+/// - `format`: The [`Format`] used to display the string.
+///
+/// # Examples
+///
+/// The following example demonstrates calculating the autofit width of a
+/// string in different fonts.
+///
+/// ```
+/// # use rust_xlsxwriter::{cell_autofit_width_with_format, Format};
+/// #
+/// let bold = Format::new().set_bold();
+/// let arial = Format::new().set_font_name("Arial").set_font_size(10);
+///
+/// assert_eq!(cell_autofit_width_with_format("Hello", &Format::default()), 40);
+/// assert_eq!(cell_autofit_width_with_format("Hello", &bold), 43);
+/// assert_eq!(cell_autofit_width_with_format("Hello", &arial), 37);
+/// ```
+///
+pub fn cell_autofit_width_with_format(string: &str, format: &Format) -> u16 {
+    let cell_padding = 7;
 
-            _ => length += 8,
-        }
-    }
+    CellFont::new(&format.font, &[]).text_width(string) + cell_padding
+}
 
-    std::cmp::min(length, MAX_AUTOFIT_WIDTH_PIXELS)
+// Get the pixel width of a string in the default Excel font of Calibri 11.
+pub(crate) fn pixel_width(string: &str) -> u16 {
+    CellFont::default().text_width(string)
 }
 
 // Hash a worksheet password. Based on the algorithm in ECMA-376-4:2016, Office
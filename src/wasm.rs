@@ -0,0 +1,1028 @@
+// wasm - A module for exposing the `rust_xlsxwriter` API to JavaScript.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+//! JavaScript bindings for `rust_xlsxwriter`.
+//!
+//! When the `wasm-bindings` feature is enabled and the library is compiled for
+//! a `wasm32` target the following types are exported to JavaScript via
+//! [`wasm-bindgen`](https://docs.rs/wasm-bindgen):
+//!
+//! - `Workbook`: a wrapper around [`Workbook`].
+//! - `Worksheet`: a wrapper around [`Worksheet`].
+//! - `Format`: a wrapper around [`Format`].
+//! - `Chart` and `ChartSeries`: wrappers around [`Chart`] and [`ChartSeries`].
+//! - `Table`: a wrapper around [`Table`].
+//! - `ChartType`, `FormatAlign` and `FormatBorder` enums.
+//!
+//! The JavaScript API uses the camelCase version of the Rust method names.
+//! Files are created in memory and returned as a `Uint8Array` by
+//! `Workbook.saveToBuffer()` so they can be saved or downloaded client-side.
+//!
+//! Errors are thrown as JavaScript exceptions containing the [`XlsxError`]
+//! message.
+//!
+//! The `Format`, `Chart`, `ChartSeries` and `Table` classes are values: each
+//! property method returns a new object with the property set and leaves the
+//! original object unchanged. This allows a base object to be reused as a
+//! template. Objects are copied when they are passed to a worksheet so
+//! changing them afterwards has no effect on the worksheet.
+//!
+//! The `Workbook` and `Worksheet` classes are handles: a `Worksheet` refers
+//! to a worksheet in the workbook that created it and all changes are applied
+//! directly to that worksheet.
+//!
+//! # Examples
+//!
+//! The exported classes are included in the JavaScript package of any
+//! `cdylib` crate that depends on `rust_xlsxwriter` with the `wasm-bindings`
+//! feature enabled. For example, with a `my_app` crate built with `wasm-pack build
+//! --target web`:
+//!
+//! ```javascript
+//! import init, { Workbook, Format, Chart, ChartSeries, ChartType, Table }
+//!     from "./pkg/my_app.js";
+//!
+//! await init();
+//!
+//! const workbook = new Workbook();
+//! const worksheet = workbook.addWorksheet();
+//! const bold = new Format().setBold();
+//!
+//! worksheet.writeWithFormat(0, 0, "Month", bold);
+//! worksheet.writeWithFormat(0, 1, "Sales", bold);
+//!
+//! for (const [i, sales] of [10, 40, 50, 20].entries()) {
+//!     worksheet.write(i + 1, 0, new Date(2024, i, 1));
+//!     worksheet.write(i + 1, 1, sales);
+//! }
+//!
+//! worksheet.addTable(0, 0, 4, 1, new Table().setColumns(["Month", "Sales"]));
+//!
+//! const chart = new Chart(ChartType.Column)
+//!     .addSeries(new ChartSeries().setValues("Sheet1", 1, 1, 4, 1))
+//!     .setTitle("Sales");
+//! worksheet.insertChart(1, 3, chart);
+//!
+//! const buffer = workbook.saveToBuffer();
+//! const blob = new Blob([buffer], {
+//!     type: "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+//! });
+//! ```
+
+mod tests;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use js_sys::{Date, Uint8Array};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    Chart, ChartSeries, ChartType, ColNum, ExcelDateTime, Format, FormatAlign, FormatBorder,
+    RowNum, Table, TableColumn, Workbook, Worksheet, XlsxError,
+};
+
+// -----------------------------------------------------------------------
+// JsWorkbook
+// -----------------------------------------------------------------------
+
+/// The `Workbook` JavaScript class, a wrapper around [`Workbook`].
+///
+/// Worksheets created by the workbook share a reference to it so that they can
+/// be modified independently from JavaScript.
+#[wasm_bindgen(js_name = "Workbook")]
+pub struct JsWorkbook {
+    workbook: Rc<RefCell<Workbook>>,
+}
+
+#[wasm_bindgen(js_class = "Workbook")]
+impl JsWorkbook {
+    /// Create a new `Workbook` object. See [`Workbook::new()`].
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> JsWorkbook {
+        JsWorkbook {
+            workbook: Rc::new(RefCell::new(Workbook::new())),
+        }
+    }
+
+    /// Add a new worksheet to the workbook. See [`Workbook::add_worksheet()`].
+    #[wasm_bindgen(js_name = "addWorksheet")]
+    pub fn add_worksheet(&self) -> JsWorksheet {
+        let mut workbook = self.workbook.borrow_mut();
+        workbook.add_worksheet();
+
+        JsWorksheet {
+            workbook: Rc::clone(&self.workbook),
+            index: workbook.worksheets().len() - 1,
+        }
+    }
+
+    /// Get a worksheet by its zero indexed position in the workbook. See
+    /// [`Workbook::worksheet_from_index()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::UnknownWorksheetNameOrIndex`] - Unknown worksheet index.
+    #[wasm_bindgen(js_name = "worksheetFromIndex")]
+    pub fn worksheet_from_index(&self, index: usize) -> Result<JsWorksheet, JsValue> {
+        self.workbook
+            .borrow_mut()
+            .worksheet_from_index(index)
+            .map_err(js_error)?;
+
+        Ok(JsWorksheet {
+            workbook: Rc::clone(&self.workbook),
+            index,
+        })
+    }
+
+    /// Get a worksheet by its name. See [`Workbook::worksheet_from_name()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::UnknownWorksheetNameOrIndex`] - Unknown worksheet name.
+    #[wasm_bindgen(js_name = "worksheetFromName")]
+    pub fn worksheet_from_name(&self, name: &str) -> Result<JsWorksheet, JsValue> {
+        let mut workbook = self.workbook.borrow_mut();
+        workbook.worksheet_from_name(name).map_err(js_error)?;
+
+        let index = workbook
+            .worksheets()
+            .iter()
+            .position(|worksheet| worksheet.name() == name)
+            .unwrap_or_default();
+
+        Ok(JsWorksheet {
+            workbook: Rc::clone(&self.workbook),
+            index,
+        })
+    }
+
+    /// Save the workbook to a `Uint8Array` buffer. See
+    /// [`Workbook::save_to_buffer()`].
+    ///
+    /// # Errors
+    ///
+    /// Any of the errors returned by [`Workbook::save()`].
+    #[wasm_bindgen(js_name = "saveToBuffer")]
+    pub fn save_to_buffer(&self) -> Result<Uint8Array, JsValue> {
+        let buffer = self
+            .workbook
+            .borrow_mut()
+            .save_to_buffer()
+            .map_err(js_error)?;
+
+        Ok(Uint8Array::from(buffer.as_slice()))
+    }
+}
+
+// -----------------------------------------------------------------------
+// JsWorksheet
+// -----------------------------------------------------------------------
+
+/// The `Worksheet` JavaScript class, a wrapper around [`Worksheet`].
+///
+/// Worksheets are created with `Workbook.addWorksheet()`.
+#[wasm_bindgen(js_name = "Worksheet")]
+pub struct JsWorksheet {
+    workbook: Rc<RefCell<Workbook>>,
+    index: usize,
+}
+
+#[wasm_bindgen(js_class = "Worksheet")]
+impl JsWorksheet {
+    /// Get the worksheet name. See [`Worksheet::name()`].
+    pub fn name(&self) -> String {
+        self.with_worksheet(|worksheet| Ok(worksheet.name()))
+            .unwrap_or_default()
+    }
+
+    /// Set the worksheet name. See [`Worksheet::set_name()`].
+    ///
+    /// # Errors
+    ///
+    /// Any of the name errors returned by [`Worksheet::set_name()`].
+    #[wasm_bindgen(js_name = "setName")]
+    pub fn set_name(&self, name: &str) -> Result<(), JsValue> {
+        self.with_worksheet(|worksheet| worksheet.set_name(name).map(|_| ()))
+    }
+
+    /// Write a JavaScript value to a cell.
+    ///
+    /// Strings, numbers and booleans are written as the equivalent Excel
+    /// types. `Date` objects are written as Excel datetimes using their local
+    /// date and time. `null` and `undefined` are ignored.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - Unsupported JavaScript type.
+    pub fn write(&self, row: RowNum, col: ColNum, value: JsValue) -> Result<(), JsValue> {
+        self.with_worksheet(|worksheet| write_js_value(worksheet, row, col, &value, None))
+    }
+
+    /// Write a formatted JavaScript value to a cell.
+    ///
+    /// See `write()` above for the supported types. `null` and `undefined` are
+    /// written as formatted blank cells.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - Unsupported JavaScript type.
+    #[wasm_bindgen(js_name = "writeWithFormat")]
+    pub fn write_with_format(
+        &self,
+        row: RowNum,
+        col: ColNum,
+        value: JsValue,
+        format: &JsFormat,
+    ) -> Result<(), JsValue> {
+        self.with_worksheet(|worksheet| {
+            write_js_value(worksheet, row, col, &value, Some(&format.format))
+        })
+    }
+
+    /// Write a formula to a cell. See [`Worksheet::write_formula()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    #[wasm_bindgen(js_name = "writeFormula")]
+    pub fn write_formula(&self, row: RowNum, col: ColNum, formula: &str) -> Result<(), JsValue> {
+        self.with_worksheet(|worksheet| worksheet.write_formula(row, col, formula).map(|_| ()))
+    }
+
+    /// Write a formatted formula to a cell. See
+    /// [`Worksheet::write_formula_with_format()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    #[wasm_bindgen(js_name = "writeFormulaWithFormat")]
+    pub fn write_formula_with_format(
+        &self,
+        row: RowNum,
+        col: ColNum,
+        formula: &str,
+        format: &JsFormat,
+    ) -> Result<(), JsValue> {
+        self.with_worksheet(|worksheet| {
+            worksheet
+                .write_formula_with_format(row, col, formula, &format.format)
+                .map(|_| ())
+        })
+    }
+
+    /// Write a url/hyperlink to a cell. See [`Worksheet::write_url()`].
+    ///
+    /// # Errors
+    ///
+    /// Any of the errors returned by [`Worksheet::write_url()`].
+    #[wasm_bindgen(js_name = "writeUrl")]
+    pub fn write_url(&self, row: RowNum, col: ColNum, url: &str) -> Result<(), JsValue> {
+        self.with_worksheet(|worksheet| worksheet.write_url(row, col, url).map(|_| ()))
+    }
+
+    /// Merge a range of cells. See [`Worksheet::merge_range()`].
+    ///
+    /// # Errors
+    ///
+    /// Any of the errors returned by [`Worksheet::merge_range()`].
+    #[wasm_bindgen(js_name = "mergeRange")]
+    pub fn merge_range(
+        &self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        string: &str,
+        format: &JsFormat,
+    ) -> Result<(), JsValue> {
+        self.with_worksheet(|worksheet| {
+            worksheet
+                .merge_range(
+                    first_row,
+                    first_col,
+                    last_row,
+                    last_col,
+                    string,
+                    &format.format,
+                )
+                .map(|_| ())
+        })
+    }
+
+    /// Set the width for a worksheet column. See
+    /// [`Worksheet::set_column_width()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    #[wasm_bindgen(js_name = "setColumnWidth")]
+    pub fn set_column_width(&self, col: ColNum, width: f64) -> Result<(), JsValue> {
+        self.with_worksheet(|worksheet| worksheet.set_column_width(col, width).map(|_| ()))
+    }
+
+    /// Set the format for a column of cells. See
+    /// [`Worksheet::set_column_format()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    #[wasm_bindgen(js_name = "setColumnFormat")]
+    pub fn set_column_format(&self, col: ColNum, format: &JsFormat) -> Result<(), JsValue> {
+        self.with_worksheet(|worksheet| {
+            worksheet.set_column_format(col, &format.format).map(|_| ())
+        })
+    }
+
+    /// Set the height for a row of cells. See [`Worksheet::set_row_height()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    #[wasm_bindgen(js_name = "setRowHeight")]
+    pub fn set_row_height(&self, row: RowNum, height: f64) -> Result<(), JsValue> {
+        self.with_worksheet(|worksheet| worksheet.set_row_height(row, height).map(|_| ()))
+    }
+
+    /// Set the format for a row of cells. See [`Worksheet::set_row_format()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    #[wasm_bindgen(js_name = "setRowFormat")]
+    pub fn set_row_format(&self, row: RowNum, format: &JsFormat) -> Result<(), JsValue> {
+        self.with_worksheet(|worksheet| worksheet.set_row_format(row, &format.format).map(|_| ()))
+    }
+
+    /// Freeze panes in a worksheet. See [`Worksheet::set_freeze_panes()`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    #[wasm_bindgen(js_name = "setFreezePanes")]
+    pub fn set_freeze_panes(&self, row: RowNum, col: ColNum) -> Result<(), JsValue> {
+        self.with_worksheet(|worksheet| worksheet.set_freeze_panes(row, col).map(|_| ()))
+    }
+
+    /// Autofit the worksheet column widths. See [`Worksheet::autofit()`].
+    pub fn autofit(&self) {
+        let _ = self.with_worksheet(|worksheet| {
+            worksheet.autofit();
+            Ok(())
+        });
+    }
+
+    /// Add a table to the worksheet. See [`Worksheet::add_table()`].
+    ///
+    /// # Errors
+    ///
+    /// Any of the errors returned by [`Worksheet::add_table()`].
+    #[wasm_bindgen(js_name = "addTable")]
+    pub fn add_table(
+        &self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        table: &JsTable,
+    ) -> Result<(), JsValue> {
+        self.with_worksheet(|worksheet| {
+            worksheet
+                .add_table(first_row, first_col, last_row, last_col, &table.table)
+                .map(|_| ())
+        })
+    }
+
+    /// Insert a chart into the worksheet. See [`Worksheet::insert_chart()`].
+    ///
+    /// # Errors
+    ///
+    /// Any of the errors returned by [`Worksheet::insert_chart()`].
+    #[wasm_bindgen(js_name = "insertChart")]
+    pub fn insert_chart(&self, row: RowNum, col: ColNum, chart: &JsChart) -> Result<(), JsValue> {
+        self.with_worksheet(|worksheet| worksheet.insert_chart(row, col, &chart.chart).map(|_| ()))
+    }
+}
+
+impl JsWorksheet {
+    // Run a worksheet method on the worksheet referred to by the wrapper and
+    // map any error to a JavaScript error.
+    fn with_worksheet<T>(
+        &self,
+        function: impl FnOnce(&mut Worksheet) -> Result<T, XlsxError>,
+    ) -> Result<T, JsValue> {
+        let mut workbook = self.workbook.borrow_mut();
+        let worksheet = workbook
+            .worksheet_from_index(self.index)
+            .map_err(js_error)?;
+
+        function(worksheet).map_err(js_error)
+    }
+}
+
+// Convert an XlsxError to a JavaScript error value.
+fn js_error(error: XlsxError) -> JsValue {
+    JsValue::from_str(&error.to_string())
+}
+
+// Write a JavaScript value to a worksheet cell based on its type.
+fn write_js_value(
+    worksheet: &mut Worksheet,
+    row: RowNum,
+    col: ColNum,
+    value: &JsValue,
+    format: Option<&Format>,
+) -> Result<(), XlsxError> {
+    if value.is_null() || value.is_undefined() {
+        if let Some(format) = format {
+            worksheet.write_blank(row, col, format)?;
+        }
+    } else if let Some(string) = value.as_string() {
+        match format {
+            Some(format) => worksheet.write_string_with_format(row, col, string, format)?,
+            None => worksheet.write_string(row, col, string)?,
+        };
+    } else if let Some(number) = value.as_f64() {
+        match format {
+            Some(format) => worksheet.write_number_with_format(row, col, number, format)?,
+            None => worksheet.write_number(row, col, number)?,
+        };
+    } else if let Some(boolean) = value.as_bool() {
+        match format {
+            Some(format) => worksheet.write_boolean_with_format(row, col, boolean, format)?,
+            None => worksheet.write_boolean(row, col, boolean)?,
+        };
+    } else if let Some(date) = value.dyn_ref::<Date>() {
+        // Excel doesn't have time zones so the local date and time is used.
+        let local_time = date.get_time() - date.get_timezone_offset() * 60_000.0;
+        let datetime = ExcelDateTime::from_serial_datetime(25_569.0 + local_time / 86_400_000.0)?;
+
+        match format {
+            Some(format) => worksheet.write_datetime_with_format(row, col, datetime, format)?,
+            None => worksheet.write_datetime(row, col, datetime)?,
+        };
+    } else {
+        return Err(XlsxError::ParameterError(format!(
+            "Unsupported JavaScript type for cell ({row}, {col}): {value:?}"
+        )));
+    }
+
+    Ok(())
+}
+
+// -----------------------------------------------------------------------
+// JsFormat
+// -----------------------------------------------------------------------
+
+/// The `Format` JavaScript class, a wrapper around [`Format`].
+///
+/// The property methods return a new `Format` so that they can be chained.
+#[wasm_bindgen(js_name = "Format")]
+#[derive(Clone)]
+pub struct JsFormat {
+    format: Format,
+}
+
+#[wasm_bindgen(js_class = "Format")]
+impl JsFormat {
+    /// Create a new `Format` object. See [`Format::new()`].
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> JsFormat {
+        JsFormat {
+            format: Format::new(),
+        }
+    }
+
+    /// Set the bold property. See [`Format::set_bold()`].
+    #[wasm_bindgen(js_name = "setBold")]
+    pub fn set_bold(&self) -> JsFormat {
+        self.with_format(Format::set_bold)
+    }
+
+    /// Set the italic property. See [`Format::set_italic()`].
+    #[wasm_bindgen(js_name = "setItalic")]
+    pub fn set_italic(&self) -> JsFormat {
+        self.with_format(Format::set_italic)
+    }
+
+    /// Set the font name. See [`Format::set_font_name()`].
+    #[wasm_bindgen(js_name = "setFontName")]
+    pub fn set_font_name(&self, font_name: &str) -> JsFormat {
+        self.with_format(|format| format.set_font_name(font_name))
+    }
+
+    /// Set the font size. See [`Format::set_font_size()`].
+    #[wasm_bindgen(js_name = "setFontSize")]
+    pub fn set_font_size(&self, font_size: f64) -> JsFormat {
+        self.with_format(|format| format.set_font_size(font_size))
+    }
+
+    /// Set the font color from a HTML style `"#RRGGBB"` string or a color
+    /// name like `"red"`. See [`Format::set_font_color()`].
+    #[wasm_bindgen(js_name = "setFontColor")]
+    pub fn set_font_color(&self, color: &str) -> JsFormat {
+        self.with_format(|format| format.set_font_color(color))
+    }
+
+    /// Set the background color from a HTML style `"#RRGGBB"` string or a
+    /// color name like `"red"`. See [`Format::set_background_color()`].
+    #[wasm_bindgen(js_name = "setBackgroundColor")]
+    pub fn set_background_color(&self, color: &str) -> JsFormat {
+        self.with_format(|format| format.set_background_color(color))
+    }
+
+    /// Set the number format. See [`Format::set_num_format()`].
+    #[wasm_bindgen(js_name = "setNumFormat")]
+    pub fn set_num_format(&self, num_format: &str) -> JsFormat {
+        self.with_format(|format| format.set_num_format(num_format))
+    }
+
+    /// Set the text wrap property. See [`Format::set_text_wrap()`].
+    #[wasm_bindgen(js_name = "setTextWrap")]
+    pub fn set_text_wrap(&self) -> JsFormat {
+        self.with_format(Format::set_text_wrap)
+    }
+
+    /// Set the cell alignment. See [`Format::set_align()`].
+    #[wasm_bindgen(js_name = "setAlign")]
+    pub fn set_align(&self, align: JsFormatAlign) -> JsFormat {
+        self.with_format(|format| format.set_align(align.into()))
+    }
+
+    /// Set the cell border style. See [`Format::set_border()`].
+    #[wasm_bindgen(js_name = "setBorder")]
+    pub fn set_border(&self, border: JsFormatBorder) -> JsFormat {
+        self.with_format(|format| format.set_border(border.into()))
+    }
+
+    /// Set the cell border color. See [`Format::set_border_color()`].
+    #[wasm_bindgen(js_name = "setBorderColor")]
+    pub fn set_border_color(&self, color: &str) -> JsFormat {
+        self.with_format(|format| format.set_border_color(color))
+    }
+}
+
+impl JsFormat {
+    // Apply a builder method to a copy of the format.
+    fn with_format(&self, function: impl FnOnce(Format) -> Format) -> JsFormat {
+        JsFormat {
+            format: function(self.format.clone()),
+        }
+    }
+}
+
+/// The `FormatAlign` JavaScript enum. See [`FormatAlign`].
+#[wasm_bindgen(js_name = "FormatAlign")]
+#[derive(Clone, Copy)]
+#[allow(missing_docs)]
+pub enum JsFormatAlign {
+    General,
+    Left,
+    Center,
+    Right,
+    Fill,
+    Justify,
+    CenterAcross,
+    Distributed,
+    Top,
+    Bottom,
+    VerticalCenter,
+    VerticalJustify,
+    VerticalDistributed,
+}
+
+impl From<JsFormatAlign> for FormatAlign {
+    fn from(align: JsFormatAlign) -> FormatAlign {
+        match align {
+            JsFormatAlign::General => FormatAlign::General,
+            JsFormatAlign::Left => FormatAlign::Left,
+            JsFormatAlign::Center => FormatAlign::Center,
+            JsFormatAlign::Right => FormatAlign::Right,
+            JsFormatAlign::Fill => FormatAlign::Fill,
+            JsFormatAlign::Justify => FormatAlign::Justify,
+            JsFormatAlign::CenterAcross => FormatAlign::CenterAcross,
+            JsFormatAlign::Distributed => FormatAlign::Distributed,
+            JsFormatAlign::Top => FormatAlign::Top,
+            JsFormatAlign::Bottom => FormatAlign::Bottom,
+            JsFormatAlign::VerticalCenter => FormatAlign::VerticalCenter,
+            JsFormatAlign::VerticalJustify => FormatAlign::VerticalJustify,
+            JsFormatAlign::VerticalDistributed => FormatAlign::VerticalDistributed,
+        }
+    }
+}
+
+/// The `FormatBorder` JavaScript enum. See [`FormatBorder`].
+#[wasm_bindgen(js_name = "FormatBorder")]
+#[derive(Clone, Copy)]
+#[allow(missing_docs)]
+pub enum JsFormatBorder {
+    None,
+    Thin,
+    Medium,
+    Dashed,
+    Dotted,
+    Thick,
+    Double,
+    Hair,
+    MediumDashed,
+    DashDot,
+    MediumDashDot,
+    DashDotDot,
+    MediumDashDotDot,
+    SlantDashDot,
+}
+
+impl From<JsFormatBorder> for FormatBorder {
+    fn from(border: JsFormatBorder) -> FormatBorder {
+        match border {
+            JsFormatBorder::None => FormatBorder::None,
+            JsFormatBorder::Thin => FormatBorder::Thin,
+            JsFormatBorder::Medium => FormatBorder::Medium,
+            JsFormatBorder::Dashed => FormatBorder::Dashed,
+            JsFormatBorder::Dotted => FormatBorder::Dotted,
+            JsFormatBorder::Thick => FormatBorder::Thick,
+            JsFormatBorder::Double => FormatBorder::Double,
+            JsFormatBorder::Hair => FormatBorder::Hair,
+            JsFormatBorder::MediumDashed => FormatBorder::MediumDashed,
+            JsFormatBorder::DashDot => FormatBorder::DashDot,
+            JsFormatBorder::MediumDashDot => FormatBorder::MediumDashDot,
+            JsFormatBorder::DashDotDot => FormatBorder::DashDotDot,
+            JsFormatBorder::MediumDashDotDot => FormatBorder::MediumDashDotDot,
+            JsFormatBorder::SlantDashDot => FormatBorder::SlantDashDot,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------
+// JsChart
+// -----------------------------------------------------------------------
+
+/// The `Chart` JavaScript class, a wrapper around [`Chart`].
+///
+/// The property methods return a new `Chart` so that they can be chained.
+#[wasm_bindgen(js_name = "Chart")]
+#[derive(Clone)]
+pub struct JsChart {
+    chart: Chart,
+}
+
+#[wasm_bindgen(js_class = "Chart")]
+impl JsChart {
+    /// Create a new `Chart` object. See [`Chart::new()`].
+    #[wasm_bindgen(constructor)]
+    pub fn new(chart_type: JsChartType) -> JsChart {
+        JsChart {
+            chart: Chart::new(chart_type.into()),
+        }
+    }
+
+    /// Add a data series to the chart. See [`Chart::push_series()`].
+    #[wasm_bindgen(js_name = "addSeries")]
+    pub fn add_series(&self, series: &JsChartSeries) -> JsChart {
+        self.with_chart(|chart| {
+            chart.push_series(&series.series);
+        })
+    }
+
+    /// Set the chart title. See [`ChartTitle::set_name()`](crate::ChartTitle::set_name).
+    #[wasm_bindgen(js_name = "setTitle")]
+    pub fn set_title(&self, name: &str) -> JsChart {
+        self.with_chart(|chart| {
+            chart.title().set_name(name);
+        })
+    }
+
+    /// Set the X axis title. See [`ChartAxis::set_name()`](crate::ChartAxis::set_name).
+    #[wasm_bindgen(js_name = "setXAxisName")]
+    pub fn set_x_axis_name(&self, name: &str) -> JsChart {
+        self.with_chart(|chart| {
+            chart.x_axis().set_name(name);
+        })
+    }
+
+    /// Set the Y axis title. See [`ChartAxis::set_name()`](crate::ChartAxis::set_name).
+    #[wasm_bindgen(js_name = "setYAxisName")]
+    pub fn set_y_axis_name(&self, name: &str) -> JsChart {
+        self.with_chart(|chart| {
+            chart.y_axis().set_name(name);
+        })
+    }
+
+    /// Hide the chart legend. See [`ChartLegend::set_hidden()`](crate::ChartLegend::set_hidden).
+    #[wasm_bindgen(js_name = "setLegendHidden")]
+    pub fn set_legend_hidden(&self) -> JsChart {
+        self.with_chart(|chart| {
+            chart.legend().set_hidden();
+        })
+    }
+
+    /// Set the chart style. See [`Chart::set_style()`].
+    #[wasm_bindgen(js_name = "setStyle")]
+    pub fn set_style(&self, style: u8) -> JsChart {
+        self.with_chart(|chart| {
+            chart.set_style(style);
+        })
+    }
+
+    /// Set the chart width in pixels. See [`Chart::set_width()`].
+    #[wasm_bindgen(js_name = "setWidth")]
+    pub fn set_width(&self, width: u32) -> JsChart {
+        self.with_chart(|chart| {
+            chart.set_width(width);
+        })
+    }
+
+    /// Set the chart height in pixels. See [`Chart::set_height()`].
+    #[wasm_bindgen(js_name = "setHeight")]
+    pub fn set_height(&self, height: u32) -> JsChart {
+        self.with_chart(|chart| {
+            chart.set_height(height);
+        })
+    }
+}
+
+impl JsChart {
+    // Apply a chart method to a copy of the chart.
+    fn with_chart(&self, function: impl FnOnce(&mut Chart)) -> JsChart {
+        let mut chart = self.chart.clone();
+        function(&mut chart);
+
+        JsChart { chart }
+    }
+}
+
+/// The `ChartSeries` JavaScript class, a wrapper around [`ChartSeries`].
+///
+/// The property methods return a new `ChartSeries` so that they can be
+/// chained.
+#[wasm_bindgen(js_name = "ChartSeries")]
+#[derive(Clone)]
+pub struct JsChartSeries {
+    series: ChartSeries,
+}
+
+#[wasm_bindgen(js_class = "ChartSeries")]
+impl JsChartSeries {
+    /// Create a new `ChartSeries` object. See [`ChartSeries::new()`].
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> JsChartSeries {
+        JsChartSeries {
+            series: ChartSeries::new(),
+        }
+    }
+
+    /// Set the series name. See [`ChartSeries::set_name()`].
+    #[wasm_bindgen(js_name = "setName")]
+    pub fn set_name(&self, name: &str) -> JsChartSeries {
+        self.with_series(|series| {
+            series.set_name(name);
+        })
+    }
+
+    /// Set the series values from a worksheet range. See
+    /// [`ChartSeries::set_values()`].
+    #[wasm_bindgen(js_name = "setValues")]
+    pub fn set_values(
+        &self,
+        sheet_name: &str,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> JsChartSeries {
+        self.with_series(|series| {
+            series.set_values((sheet_name, first_row, first_col, last_row, last_col));
+        })
+    }
+
+    /// Set the series categories from a worksheet range. See
+    /// [`ChartSeries::set_categories()`].
+    #[wasm_bindgen(js_name = "setCategories")]
+    pub fn set_categories(
+        &self,
+        sheet_name: &str,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> JsChartSeries {
+        self.with_series(|series| {
+            series.set_categories((sheet_name, first_row, first_col, last_row, last_col));
+        })
+    }
+}
+
+impl JsChartSeries {
+    // Apply a series method to a copy of the series.
+    fn with_series(&self, function: impl FnOnce(&mut ChartSeries)) -> JsChartSeries {
+        let mut series = self.series.clone();
+        function(&mut series);
+
+        JsChartSeries { series }
+    }
+}
+
+/// The `ChartType` JavaScript enum. See [`ChartType`].
+#[wasm_bindgen(js_name = "ChartType")]
+#[derive(Clone, Copy)]
+#[allow(missing_docs)]
+pub enum JsChartType {
+    Area,
+    AreaStacked,
+    AreaPercentStacked,
+    Area3D,
+    Area3DStacked,
+    Area3DPercentStacked,
+    Bar,
+    BarStacked,
+    BarPercentStacked,
+    Bar3D,
+    Bar3DStacked,
+    Bar3DPercentStacked,
+    Column,
+    ColumnStacked,
+    ColumnPercentStacked,
+    Column3D,
+    Column3DStacked,
+    Column3DPercentStacked,
+    Column3DStandard,
+    Doughnut,
+    Line,
+    LineStacked,
+    LinePercentStacked,
+    Line3D,
+    Pie,
+    Pie3D,
+    Radar,
+    RadarWithMarkers,
+    RadarFilled,
+    Scatter,
+    ScatterStraight,
+    ScatterStraightWithMarkers,
+    ScatterSmooth,
+    ScatterSmoothWithMarkers,
+    Stock,
+    Surface3D,
+    Surface3DWireframe,
+    SurfaceContour,
+    SurfaceContourWireframe,
+}
+
+impl From<JsChartType> for ChartType {
+    fn from(chart_type: JsChartType) -> ChartType {
+        match chart_type {
+            JsChartType::Area => ChartType::Area,
+            JsChartType::AreaStacked => ChartType::AreaStacked,
+            JsChartType::AreaPercentStacked => ChartType::AreaPercentStacked,
+            JsChartType::Area3D => ChartType::Area3D,
+            JsChartType::Area3DStacked => ChartType::Area3DStacked,
+            JsChartType::Area3DPercentStacked => ChartType::Area3DPercentStacked,
+            JsChartType::Bar => ChartType::Bar,
+            JsChartType::BarStacked => ChartType::BarStacked,
+            JsChartType::BarPercentStacked => ChartType::BarPercentStacked,
+            JsChartType::Bar3D => ChartType::Bar3D,
+            JsChartType::Bar3DStacked => ChartType::Bar3DStacked,
+            JsChartType::Bar3DPercentStacked => ChartType::Bar3DPercentStacked,
+            JsChartType::Column => ChartType::Column,
+            JsChartType::ColumnStacked => ChartType::ColumnStacked,
+            JsChartType::ColumnPercentStacked => ChartType::ColumnPercentStacked,
+            JsChartType::Column3D => ChartType::Column3D,
+            JsChartType::Column3DStacked => ChartType::Column3DStacked,
+            JsChartType::Column3DPercentStacked => ChartType::Column3DPercentStacked,
+            JsChartType::Column3DStandard => ChartType::Column3DStandard,
+            JsChartType::Doughnut => ChartType::Doughnut,
+            JsChartType::Line => ChartType::Line,
+            JsChartType::LineStacked => ChartType::LineStacked,
+            JsChartType::LinePercentStacked => ChartType::LinePercentStacked,
+            JsChartType::Line3D => ChartType::Line3D,
+            JsChartType::Pie => ChartType::Pie,
+            JsChartType::Pie3D => ChartType::Pie3D,
+            JsChartType::Radar => ChartType::Radar,
+            JsChartType::RadarWithMarkers => ChartType::RadarWithMarkers,
+            JsChartType::RadarFilled => ChartType::RadarFilled,
+            JsChartType::Scatter => ChartType::Scatter,
+            JsChartType::ScatterStraight => ChartType::ScatterStraight,
+            JsChartType::ScatterStraightWithMarkers => ChartType::ScatterStraightWithMarkers,
+            JsChartType::ScatterSmooth => ChartType::ScatterSmooth,
+            JsChartType::ScatterSmoothWithMarkers => ChartType::ScatterSmoothWithMarkers,
+            JsChartType::Stock => ChartType::Stock,
+            JsChartType::Surface3D => ChartType::Surface3D,
+            JsChartType::Surface3DWireframe => ChartType::Surface3DWireframe,
+            JsChartType::SurfaceContour => ChartType::SurfaceContour,
+            JsChartType::SurfaceContourWireframe => ChartType::SurfaceContourWireframe,
+        }
+    }
+}
+
+// -----------------------------------------------------------------------
+// JsTable
+// -----------------------------------------------------------------------
+
+/// The `Table` JavaScript class, a wrapper around [`Table`].
+///
+/// The property methods return a new `Table` so that they can be chained.
+#[wasm_bindgen(js_name = "Table")]
+#[derive(Clone)]
+pub struct JsTable {
+    table: Table,
+}
+
+#[wasm_bindgen(js_class = "Table")]
+impl JsTable {
+    /// Create a new `Table` object. See [`Table::new()`].
+    #[wasm_bindgen(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> JsTable {
+        JsTable {
+            table: Table::new(),
+        }
+    }
+
+    /// Set the table name. See [`Table::set_name()`].
+    #[wasm_bindgen(js_name = "setName")]
+    pub fn set_name(&self, name: &str) -> JsTable {
+        self.with_table(|table| table.set_name(name))
+    }
+
+    /// Set the table column header names. See [`Table::set_columns()`].
+    #[wasm_bindgen(js_name = "setColumns")]
+    pub fn set_columns(&self, names: Vec<String>) -> JsTable {
+        let columns: Vec<TableColumn> = names
+            .iter()
+            .map(|name| TableColumn::new().set_header(name))
+            .collect();
+
+        self.with_table(|table| table.set_columns(&columns))
+    }
+
+    /// Turn on/off the table header row. See [`Table::set_header_row()`].
+    #[wasm_bindgen(js_name = "setHeaderRow")]
+    pub fn set_header_row(&self, enable: bool) -> JsTable {
+        self.with_table(|table| table.set_header_row(enable))
+    }
+
+    /// Turn on/off the table total row. See [`Table::set_total_row()`].
+    #[wasm_bindgen(js_name = "setTotalRow")]
+    pub fn set_total_row(&self, enable: bool) -> JsTable {
+        self.with_table(|table| table.set_total_row(enable))
+    }
+
+    /// Turn on/off banded rows. See [`Table::set_banded_rows()`].
+    #[wasm_bindgen(js_name = "setBandedRows")]
+    pub fn set_banded_rows(&self, enable: bool) -> JsTable {
+        self.with_table(|table| table.set_banded_rows(enable))
+    }
+
+    /// Turn on/off banded columns. See [`Table::set_banded_columns()`].
+    #[wasm_bindgen(js_name = "setBandedColumns")]
+    pub fn set_banded_columns(&self, enable: bool) -> JsTable {
+        self.with_table(|table| table.set_banded_columns(enable))
+    }
+
+    /// Turn on/off first column highlighting. See
+    /// [`Table::set_first_column()`].
+    #[wasm_bindgen(js_name = "setFirstColumn")]
+    pub fn set_first_column(&self, enable: bool) -> JsTable {
+        self.with_table(|table| table.set_first_column(enable))
+    }
+
+    /// Turn on/off last column highlighting. See
+    /// [`Table::set_last_column()`].
+    #[wasm_bindgen(js_name = "setLastColumn")]
+    pub fn set_last_column(&self, enable: bool) -> JsTable {
+        self.with_table(|table| table.set_last_column(enable))
+    }
+
+    /// Turn on/off the table autofilter. See [`Table::set_autofilter()`].
+    #[wasm_bindgen(js_name = "setAutofilter")]
+    pub fn set_autofilter(&self, enable: bool) -> JsTable {
+        self.with_table(|table| table.set_autofilter(enable))
+    }
+}
+
+impl JsTable {
+    // Apply a builder method to a copy of the table.
+    fn with_table(&self, function: impl FnOnce(Table) -> Table) -> JsTable {
+        JsTable {
+            table: function(self.table.clone()),
+        }
+    }
+}
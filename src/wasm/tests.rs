@@ -0,0 +1,101 @@
+// wasm unit tests. These test the Rust side of the JavaScript bindings and
+// run on the host.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod wasm_tests {
+
+    use crate::wasm::{
+        JsChart, JsChartSeries, JsChartType, JsFormat, JsFormatAlign, JsTable, JsWorkbook,
+    };
+    use crate::{Format, FormatAlign};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_format_setters_return_new_object() {
+        let base = JsFormat::new().set_bold();
+        let centered = base.set_align(JsFormatAlign::Center);
+
+        assert_eq!(base.format, Format::new().set_bold());
+        assert_eq!(
+            centered.format,
+            Format::new().set_bold().set_align(FormatAlign::Center)
+        );
+    }
+
+    #[test]
+    fn test_chart_setters_return_new_object() {
+        let series = JsChartSeries::new().set_values("Sheet1", 0, 0, 4, 0);
+
+        let base = JsChart::new(JsChartType::Column);
+        let chart = base.add_series(&series);
+
+        assert_eq!(base.chart.series.len(), 0);
+        assert_eq!(chart.chart.series.len(), 1);
+    }
+
+    #[test]
+    fn test_chart_series_setters_return_new_object() {
+        let base = JsChartSeries::new().set_values("Sheet1", 0, 0, 4, 0);
+        let series = base.set_values("Sheet1", 0, 1, 4, 1);
+
+        assert_eq!(base.series.value_range.formula(), "Sheet1!A1:A5");
+        assert_eq!(series.series.value_range.formula(), "Sheet1!B1:B5");
+    }
+
+    #[test]
+    fn test_table_setters_return_new_object() {
+        let base = JsTable::new().set_total_row(true);
+        let table = base.set_name("Sales");
+
+        assert_eq!(base.table.name, "");
+        assert_eq!(table.table.name, "Sales");
+        assert!(table.table.show_total_row);
+    }
+
+    #[test]
+    fn test_worksheet_handles_share_workbook() {
+        let workbook = JsWorkbook::new();
+        let worksheet1 = workbook.add_worksheet();
+        let worksheet2 = workbook.add_worksheet();
+
+        worksheet2.set_name("Data").unwrap();
+        worksheet2.write_formula(0, 0, "=1+1").unwrap();
+
+        let worksheet = workbook.worksheet_from_name("Data").unwrap();
+
+        assert_eq!(worksheet1.name(), "Sheet1");
+        assert_eq!(worksheet.name(), "Data");
+        assert_eq!(worksheet.index, 1);
+        assert_eq!(
+            workbook.workbook.borrow_mut().worksheets()[1].name(),
+            "Data"
+        );
+    }
+
+    #[test]
+    fn test_worksheet_objects_are_copied() {
+        let workbook = JsWorkbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        let format = JsFormat::new().set_bold();
+        let table = JsTable::new().set_name("Sales");
+
+        worksheet.set_column_format(0, &format).unwrap();
+        worksheet.add_table(0, 0, 4, 1, &table).unwrap();
+
+        // Changing the objects after they are added doesn't affect the
+        // worksheet.
+        let _ = format.set_italic();
+        let _ = table.set_name("Other");
+
+        let mut workbook = workbook.workbook.borrow_mut();
+        let worksheet = workbook.worksheet_from_index(0).unwrap();
+
+        assert_eq!(worksheet.tables[0].name, "Sales");
+        assert!(workbook.save_to_buffer().is_ok());
+    }
+}
@@ -232,18 +232,22 @@ use std::io::BufWriter;
 #[cfg(feature = "constant_memory")]
 use std::path::PathBuf;
 
+#[cfg(feature = "render")]
+use crate::render;
+
 use crate::error::XlsxError;
 use crate::feature_property_bag::FeaturePropertyBagTypes;
 use crate::format::Format;
 use crate::packager::Packager;
 use crate::packager::PackagerOptions;
 use crate::shared_strings_table::SharedStringsTable;
-use crate::worksheet::Worksheet;
+use crate::styles::NamedStyle;
+use crate::worksheet::{Worksheet, COL_MAX, ROW_MAX};
 
 use crate::{
-    utility, Border, Chart, ChartRange, ChartRangeCacheData, ColNum, Color, DefinedName,
-    DefinedNameType, DocProperties, Fill, Font, FormatPattern, Image, RowNum, Visible,
-    NUM_IMAGE_FORMATS,
+    utility, Border, Chart, ChartRange, ChartRangeCacheData, ColNum, Color, CustomTableStyle,
+    DataValidation, DataValidationRuleInternal, DataValidationType, DefinedName, DefinedNameType,
+    DocProperties, Fill, Font, FormatPattern, Formula, Image, RowNum, Visible, NUM_IMAGE_FORMATS,
 };
 
 use crate::xmlwriter::{
@@ -339,6 +343,8 @@ pub struct Workbook {
     pub(crate) has_comments: bool,
     pub(crate) string_table: Arc<Mutex<SharedStringsTable>>,
     pub(crate) feature_property_bags: HashSet<FeaturePropertyBagTypes>,
+    pub(crate) table_styles: Vec<CustomTableStyle>,
+    pub(crate) named_styles: Vec<NamedStyle>,
 
     xf_indices: Arc<RwLock<HashMap<Format, u32>>>,
     dxf_indices: HashMap<Format, u32>,
@@ -350,6 +356,9 @@ pub struct Workbook {
     num_worksheets: u16,
     num_chartsheets: u16,
     use_large_file: bool,
+    dependent_list_count: u16,
+    hidden_list_sheet: Option<usize>,
+    hidden_list_col: ColNum,
 
     #[cfg(feature = "constant_memory")]
     tempdir: Option<PathBuf>,
@@ -432,7 +441,12 @@ impl Workbook {
             num_worksheets: 0,
             num_chartsheets: 0,
             use_large_file: false,
+            dependent_list_count: 0,
+            hidden_list_sheet: None,
+            hidden_list_col: 0,
             feature_property_bags: HashSet::new(),
+            table_styles: vec![],
+            named_styles: vec![],
 
             #[cfg(feature = "constant_memory")]
             tempdir: None,
@@ -820,6 +834,29 @@ impl Workbook {
     /// worksheet display options such as headers and footers, margins, tab
     /// selection, and print properties.
     ///
+    /// The following [`Worksheet`] methods are supported by chartsheets:
+    ///
+    /// - Page setup: [`Worksheet::set_landscape()`],
+    ///   [`Worksheet::set_portrait()`], [`Worksheet::set_paper_size()`],
+    ///   [`Worksheet::set_margins()`], [`Worksheet::set_print_first_page_number()`],
+    ///   [`Worksheet::set_print_black_and_white()`] and
+    ///   [`Worksheet::set_print_draft()`].
+    /// - Headers and footers: [`Worksheet::set_header()`],
+    ///   [`Worksheet::set_footer()`] and the related image and scaling
+    ///   options.
+    /// - Display: [`Worksheet::set_zoom()`], [`Worksheet::set_tab_color()`],
+    ///   [`Worksheet::set_active()`], [`Worksheet::set_selected()`] and
+    ///   [`Worksheet::set_hidden()`].
+    /// - Protection: [`Worksheet::protect()`],
+    ///   [`Worksheet::protect_with_password()`] and
+    ///   [`Worksheet::protect_with_options()`]. Only the "contents" and "edit
+    ///   objects" options apply to chartsheets.
+    ///
+    /// Chartsheets don't have cells so methods that write data, or that apply
+    /// to cells, rows or columns, return [`XlsxError::ChartsheetError`].
+    /// Worksheet print options that don't have a chartsheet equivalent, such
+    /// as print scaling, fit to pages and gridlines, are ignored.
+    ///
     /// The chartsheets will be given standard Excel name like `Chart1`,
     /// `Chart2`, etc. Alternatively, the name can be set using
     /// [`Worksheet::set_name()`].
@@ -879,6 +916,52 @@ impl Workbook {
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/chartsheet.png">
     ///
+    /// The following example demonstrates setting up a chartsheet for printing
+    /// in landscape mode with a footer, and protecting it.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chartsheet_page_setup.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 10)?;
+    /// #     worksheet.write(1, 0, 60)?;
+    /// #     worksheet.write(2, 0, 30)?;
+    /// #     worksheet.write(3, 0, 10)?;
+    /// #     worksheet.write(4, 0, 50)?;
+    /// #
+    /// #     // Create a new chart.
+    /// #     let mut chart = Chart::new(ChartType::Column);
+    /// #     chart.add_series().set_values("Sheet1!$A$1:$A$5");
+    /// #
+    ///     // Create a new chartsheet and add the chart to it.
+    ///     let chartsheet = workbook.add_chartsheet();
+    ///     chartsheet.insert_chart(0, 0, &chart)?;
+    ///
+    ///     // Set the print and display options.
+    ///     chartsheet
+    ///         .set_name("Revenue")?
+    ///         .set_landscape()
+    ///         .set_paper_size(9)
+    ///         .set_footer("&LCompany Confidential&RPage &P of &N")
+    ///         .set_tab_color("#4472C4")
+    ///         .set_zoom(90);
+    ///
+    ///     // Protect the chart from changes.
+    ///     chartsheet.protect();
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chartsheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
     pub fn add_chartsheet(&mut self) -> &mut Worksheet {
         let name = format!("Chart{}", self.num_chartsheets + 1);
         self.num_chartsheets += 1;
@@ -1415,6 +1498,100 @@ impl Workbook {
         Ok(())
     }
 
+    /// Save the printed pages of the workbook's worksheets as a PDF file.
+    ///
+    /// The `save_to_pdf()` method renders the printed pages of each visible
+    /// worksheet in the workbook, in order, to a single PDF document. This can
+    /// be used to create previews of a workbook without Excel or a conversion
+    /// application such as LibreOffice. Chartsheets and hidden worksheets are
+    /// ignored.
+    ///
+    /// See [`Worksheet::save_to_pdf()`] for details of the rendered pages and
+    /// the supported page setup properties.
+    ///
+    /// This method requires the `render` feature.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The path of the new PDF file to create.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - A worksheet uses constant memory mode
+    ///   so the cell data isn't available to render.
+    /// - [`XlsxError::RenderError`] - The text in a worksheet contains
+    ///   characters that aren't supported by the standard PDF fonts.
+    /// - [`XlsxError::IoError`] - A wrapper for various IO errors when creating
+    ///   the PDF file.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates saving a preview of a workbook as a
+    /// PDF file.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_save_to_pdf.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write(0, 0, "Hello")?;
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write(0, 0, "World")?;
+    ///
+    ///     // Save the workbook and a preview of its worksheets.
+    ///     workbook.save("workbook.xlsx")?;
+    ///     workbook.save_to_pdf("workbook.pdf")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    #[cfg(feature = "render")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "render")))]
+    pub fn save_to_pdf<P: AsRef<Path>>(&self, path: P) -> Result<(), XlsxError> {
+        let buffer = self.save_to_pdf_buffer()?;
+        std::fs::write(path, buffer)?;
+
+        Ok(())
+    }
+
+    /// Save the printed pages of the workbook's worksheets as a PDF document
+    /// in a byte vector.
+    ///
+    /// The `save_to_pdf_buffer()` method is similar to the
+    /// [`Workbook::save_to_pdf()`] method except that it returns the PDF
+    /// document as a `Vec<u8>` buffer, for example to attach to an email.
+    ///
+    /// This method requires the `render` feature.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - A worksheet uses constant memory mode
+    ///   so the cell data isn't available to render.
+    /// - [`XlsxError::RenderError`] - The text in a worksheet contains
+    ///   characters that aren't supported by the standard PDF fonts.
+    ///
+    #[cfg(feature = "render")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "render")))]
+    pub fn save_to_pdf_buffer(&self) -> Result<Vec<u8>, XlsxError> {
+        let mut render_sheets = vec![];
+
+        for worksheet in &self.worksheets {
+            if worksheet.is_chartsheet || worksheet.visible != Visible::Default {
+                continue;
+            }
+
+            render_sheets.push(worksheet.render_sheet()?);
+        }
+
+        render::render_to_pdf(&render_sheets)
+    }
+
     /// Create a defined name in the workbook to use as a variable.
     ///
     /// The `define_name()` method is used to define a variable name that can
@@ -1583,6 +1760,415 @@ impl Workbook {
         Ok(self)
     }
 
+    /// Add a named cell style to the workbook.
+    ///
+    /// Add a user defined cell style, such as "Corp Heading" or "Corp
+    /// Currency", to the workbook. Named styles are displayed in the Excel
+    /// "Cell Styles" gallery so that users editing the file can apply the same
+    /// formatting to other cells.
+    ///
+    /// The method returns a [`Format`] that is based on the named style. Cells
+    /// written with this format, or with a format derived from it, are linked
+    /// to the style in Excel. Formats can be derived from the style format by
+    /// cloning it and adding additional properties, or via [`Format::merge()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the cell style as displayed in Excel.
+    /// - `format`: The [`Format`] properties of the cell style.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The following Excel error cases will
+    ///   raise a `ParameterError` error:
+    ///   * If the style name is blank.
+    ///   * If the style name is "Normal" or "Hyperlink" since these are used
+    ///     for the Excel built-in styles.
+    ///   * If the style name has already been used in the workbook
+    ///     (case-insensitive).
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding named cell styles to a
+    /// workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_add_cell_style.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, FormatBorder, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     // Add some named cell styles to the workbook.
+    ///     let heading = workbook.add_cell_style(
+    ///         "Corp Heading",
+    ///         &Format::new()
+    ///             .set_bold()
+    ///             .set_font_size(14)
+    ///             .set_border_bottom(FormatBorder::Medium),
+    ///     )?;
+    ///
+    ///     let currency =
+    ///         workbook.add_cell_style("Corp Currency", &Format::new().set_num_format("$#,##0.00"))?;
+    ///
+    ///     // Create a format that is based on one of the styles.
+    ///     let total = currency.clone().set_bold();
+    ///
+    ///     // Write some data with the style formats.
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.set_column_width(0, 20)?;
+    ///
+    ///     worksheet.write_with_format(0, 0, "Sales", &heading)?;
+    ///     worksheet.write_with_format(1, 0, 1234.5, &currency)?;
+    ///     worksheet.write_with_format(2, 0, 5432.1, &currency)?;
+    ///     worksheet.write_formula_with_format(3, 0, "=SUM(A2:A3)", &total)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("cell_styles.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_cell_style(
+        &mut self,
+        name: impl Into<String>,
+        format: &Format,
+    ) -> Result<Format, XlsxError> {
+        let name = name.into();
+
+        if name.trim().is_empty() {
+            let error = "Cell style name cannot be blank".to_string();
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        let lowercase_name = name.to_lowercase();
+        if lowercase_name == "normal" || lowercase_name == "hyperlink" {
+            let error = format!("Cell style name '{name}' is reserved for Excel built-in styles");
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        if self
+            .named_styles
+            .iter()
+            .any(|named_style| named_style.name.to_lowercase() == lowercase_name)
+        {
+            let error = format!("Cell style name '{name}' has already been used");
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        let mut format = format.clone();
+        format.cell_style.clone_from(&name);
+
+        self.named_styles.push(NamedStyle {
+            name,
+            format: format.clone(),
+            xf_index: 0,
+        });
+
+        Ok(format)
+    }
+
+    /// Add a custom table style to the workbook.
+    ///
+    /// Add a user defined [`CustomTableStyle`] to the workbook so that it can
+    /// be applied to worksheet tables via
+    /// [`Table::set_custom_style()`](crate::Table::set_custom_style). The style is
+    /// also displayed in the "Custom" section of the Excel table style gallery.
+    ///
+    /// See [`CustomTableStyle`] for an example.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: A reference to a [`CustomTableStyle`] object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::TableError`] - The following Excel error cases will
+    ///   raise a `TableError` error:
+    ///   * If the style name is blank.
+    ///   * If the style name starts with `TableStyle` or `PivotStyle` since
+    ///     these are reserved for the Excel built-in styles.
+    ///   * If the style name has already been used in the workbook
+    ///     (case-insensitive).
+    ///
+    pub fn add_table_style(
+        &mut self,
+        style: &CustomTableStyle,
+    ) -> Result<&mut Workbook, XlsxError> {
+        let name = &style.name;
+
+        if name.trim().is_empty() {
+            let error = "Custom table style name cannot be blank".to_string();
+            return Err(XlsxError::TableError(error));
+        }
+
+        let lowercase_name = name.to_lowercase();
+        if lowercase_name.starts_with("tablestyle") || lowercase_name.starts_with("pivotstyle") {
+            let error =
+                format!("Custom table style name '{name}' is reserved for Excel built-in styles");
+            return Err(XlsxError::TableError(error));
+        }
+
+        if self
+            .table_styles
+            .iter()
+            .any(|table_style| table_style.name.to_lowercase() == lowercase_name)
+        {
+            let error = format!("Custom table style name '{name}' has already been used");
+            return Err(XlsxError::TableError(error));
+        }
+
+        self.table_styles.push(style.clone());
+
+        Ok(self)
+    }
+
+    /// Add a dependent, or cascading, drop-down list data validation.
+    ///
+    /// Dependent drop-down lists are lists where the values available in one
+    /// cell depend on the value selected in another cell. For example, a
+    /// "Region" list that only shows the regions of the country selected in a
+    /// "Country" column.
+    ///
+    /// Excel doesn't support this directly but it can be created by storing
+    /// each child list in a range, giving the range a defined name based on
+    /// the parent value and then using an `INDIRECT()` list formula to look up
+    /// the range from the parent cell. The `add_dependent_list_validation()`
+    /// method handles all of these steps:
+    ///
+    /// - The child lists are written to a hidden worksheet called `_Lists`.
+    ///   This worksheet is added the first time it is needed and is also used
+    ///   for long [`DataValidation::allow_list_strings()`] lists. If a
+    ///   worksheet called `_Lists` already exists a unique name like `_Lists1`
+    ///   is used instead.
+    /// - A global defined name like `_List1_France` is created for each child
+    ///   list. The `_ListN_` prefix is unique to each call so that the same
+    ///   parent value can be used at different levels of a cascade.
+    /// - A list data validation is added to the child column with a formula
+    ///   like `=INDIRECT("_List1_"&SUBSTITUTE($A2," ","_"))`.
+    ///
+    /// The parent column will usually have a standard list validation, see
+    /// [`DataValidation::allow_list_strings()`]. Multi-level cascades, such as
+    /// country → region → city, can be created by calling the method once for
+    /// each level, with the previous child column as the parent column.
+    ///
+    /// # Parameters
+    ///
+    /// - `sheetname`: The name of the worksheet to add the data validation to.
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `last_row`: The last row of the range.
+    /// - `parent_col`: The column that contains the parent values.
+    /// - `child_col`: The column to add the dependent list validation to.
+    /// - `lists`: An iterator of `(parent, children)` pairs such as a
+    ///   `Vec<(&str, Vec<&str>)>` or a `BTreeMap<String, Vec<String>>`. The
+    ///   child lists are written in the order of the iterator.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::UnknownWorksheetNameOrIndex`] - Unknown worksheet name.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row.
+    /// - [`XlsxError::DataValidationError`] - The following Excel error cases
+    ///   will raise a `DataValidationError` error:
+    ///   * If a parent value is blank or contains characters, other than
+    ///     spaces, that aren't allowed in an Excel defined name.
+    ///   * If a parent value is duplicated. Since Excel defined names are
+    ///     case-insensitive and spaces are replaced with underscores, values
+    ///     like "New York", "New_York" and "new york" are duplicates.
+    ///   * If a defined name for a list is already in use in the workbook.
+    ///   * If a child list is empty.
+    ///
+    /// The parameters are validated before anything is added to the workbook
+    /// so the workbook is unchanged if an error is returned.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding dependent drop-down lists to
+    /// a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_add_dependent_list_validation.rs
+    /// #
+    /// # use rust_xlsxwriter::{DataValidation, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     // Add a worksheet with headers for the drop-down lists.
+    ///     let worksheet = workbook.add_worksheet().set_name("Orders")?;
+    ///     worksheet.write_row(0, 0, ["Country", "Region", "City"])?;
+    ///     worksheet.set_column_range_width(0, 2, 16)?;
+    ///
+    ///     // Add a standard list validation for the top level values.
+    ///     let data_validation = DataValidation::new().allow_list_strings(&["France", "Japan"])?;
+    ///     worksheet.add_data_validation(1, 0, 20, 0, &data_validation)?;
+    ///
+    ///     // Add a dependent list of regions for each country.
+    ///     let regions = vec![
+    ///         ("France", vec!["Brittany", "Normandy"]),
+    ///         ("Japan", vec!["Hokkaido", "Kanto"]),
+    ///     ];
+    ///     workbook.add_dependent_list_validation("Orders", 1, 20, 0, 1, regions)?;
+    ///
+    ///     // Add a dependent list of cities for each region.
+    ///     let cities = vec![
+    ///         ("Brittany", vec!["Brest", "Rennes"]),
+    ///         ("Normandy", vec!["Caen", "Rouen"]),
+    ///         ("Hokkaido", vec!["Hakodate", "Sapporo"]),
+    ///         ("Kanto", vec!["Tokyo", "Yokohama"]),
+    ///     ];
+    ///     workbook.add_dependent_list_validation("Orders", 1, 20, 1, 2, cities)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("dependent_lists.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_dependent_list_validation<I, K, C, V>(
+        &mut self,
+        sheetname: &str,
+        first_row: RowNum,
+        last_row: RowNum,
+        parent_col: ColNum,
+        child_col: ColNum,
+        lists: I,
+    ) -> Result<&mut Workbook, XlsxError>
+    where
+        I: IntoIterator<Item = (K, C)>,
+        K: AsRef<str>,
+        C: IntoIterator<Item = V>,
+        V: AsRef<str>,
+    {
+        let Some(sheet_index) = self
+            .worksheets
+            .iter()
+            .position(|worksheet| worksheet.name == sheetname)
+        else {
+            return Err(XlsxError::UnknownWorksheetNameOrIndex(
+                sheetname.to_string(),
+            ));
+        };
+
+        // Check the ranges before anything is added to the workbook.
+        if last_row >= ROW_MAX || parent_col >= COL_MAX || child_col >= COL_MAX {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        if first_row > last_row {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // Collect and validate the lists before writing anything. The defined
+        // names for the lists must be unique, case-insensitively, after spaces
+        // in the parent values are replaced with underscores.
+        let prefix = format!("_List{}_", self.dependent_list_count + 1);
+        let mut parents: Vec<String> = vec![];
+        let mut names: Vec<String> = vec![];
+        let mut child_lists: Vec<Vec<String>> = vec![];
+        for (parent, children) in lists {
+            let parent = parent.as_ref().to_string();
+
+            if parent.trim().is_empty() {
+                let error = "Dependent list parent value cannot be blank".to_string();
+                return Err(XlsxError::DataValidationError(error));
+            }
+
+            if !parent
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, ' ' | '_' | '.' | '\\'))
+            {
+                let error = format!(
+                    "Dependent list parent value '{parent}' cannot contain characters that \
+                     aren't allowed in an Excel defined name"
+                );
+                return Err(XlsxError::DataValidationError(error));
+            }
+
+            let name = format!("{prefix}{}", parent.replace(' ', "_"));
+            let lowercase_name = name.to_lowercase();
+
+            if names
+                .iter()
+                .any(|existing| existing.to_lowercase() == lowercase_name)
+            {
+                let error = format!(
+                    "Dependent list parent value '{parent}' is duplicated, ignoring case and \
+                     treating spaces as underscores"
+                );
+                return Err(XlsxError::DataValidationError(error));
+            }
+
+            if self.user_defined_names.iter().any(|defined_name| {
+                matches!(defined_name.name_type, DefinedNameType::Global)
+                    && defined_name.name.to_lowercase() == lowercase_name
+            }) {
+                let error = format!(
+                    "Dependent list defined name '{name}' for parent value '{parent}' is \
+                     already in use"
+                );
+                return Err(XlsxError::DataValidationError(error));
+            }
+
+            let children: Vec<String> = children
+                .into_iter()
+                .map(|child| child.as_ref().to_string())
+                .collect();
+
+            if children.is_empty() {
+                let error = format!("Dependent list for parent value '{parent}' is empty");
+                return Err(XlsxError::DataValidationError(error));
+            }
+
+            if children.len() >= ROW_MAX as usize {
+                return Err(XlsxError::RowColumnLimitError);
+            }
+
+            parents.push(parent);
+            names.push(name);
+            child_lists.push(children);
+        }
+
+        // Check that there are enough columns in the hidden worksheet.
+        if usize::from(self.hidden_list_col) + child_lists.len() > usize::from(COL_MAX) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Add the data validation to the child column.
+        let parent_cell = format!(
+            "${}{}",
+            utility::column_number_to_name(parent_col),
+            first_row + 1
+        );
+        let formula = format!("=INDIRECT(\"{prefix}\"&SUBSTITUTE({parent_cell},\" \",\"_\"))");
+
+        let data_validation = DataValidation::new().allow_list_formula(Formula::new(formula));
+        self.worksheets[sheet_index].add_data_validation(
+            first_row,
+            child_col,
+            last_row,
+            child_col,
+            &data_validation,
+        )?;
+        self.dependent_list_count += 1;
+
+        // Write each child list to the hidden worksheet and define a name for
+        // the range.
+        for ((parent, name), children) in parents.iter().zip(names).zip(child_lists) {
+            let range = self.write_hidden_list(parent, &children)?;
+            self.define_name(name, &format!("={range}"))?;
+        }
+
+        Ok(self)
+    }
+
     /// Set the Excel document metadata properties.
     ///
     /// Set various Excel document metadata properties such as Author or
@@ -2044,6 +2630,9 @@ impl Workbook {
         // Ensure one sheet is active/selected.
         self.set_active_worksheets();
 
+        // Move any long data validation lists to a hidden worksheet.
+        self.prepare_long_validation_lists()?;
+
         // Check for the use of hyperlink style in the worksheets and if so add
         // a hyperlink style to the global formats.
         for worksheet in &self.worksheets {
@@ -2115,6 +2704,14 @@ impl Workbook {
             }
             worksheet_xf_indices.push(indices);
         }
+
+        // Add the named cell style formats to the XF formats so that their
+        // font, fill and border properties are included in the styles.
+        let mut named_styles = std::mem::take(&mut self.named_styles);
+        for named_style in &mut named_styles {
+            named_style.xf_index = self.format_xf_index(&named_style.format);
+        }
+        self.named_styles = named_styles;
         let mut worksheet_dxf_indices: Vec<Vec<u32>> = vec![];
         for formats in &worksheet_dxf_formats {
             let mut indices = vec![];
@@ -2125,6 +2722,19 @@ impl Workbook {
             worksheet_dxf_indices.push(indices);
         }
 
+        // Add the custom table style formats to the DXF formats.
+        let mut table_styles = std::mem::take(&mut self.table_styles);
+        for table_style in &mut table_styles {
+            for element in &mut table_style.elements {
+                if let Some(format) = &element.format {
+                    let mut format = format.clone();
+                    format.is_dxf_format = true;
+                    element.dxf_index = Some(self.format_dxf_index(&format));
+                }
+            }
+        }
+        self.table_styles = table_styles;
+
         // We extract the XF Formats used as keys in the index lookup to a
         // vector of formats sorted by their index number.
         let xf_indices = self.xf_indices.read().expect("RwLock poisoned");
@@ -2179,6 +2789,12 @@ impl Workbook {
         // Prepare the formats for writing with styles.rs.
         self.prepare_format_properties();
 
+        // Pass the prepared dxf formats to the worksheets for use in Excel 2010
+        // conditional formats.
+        for worksheet in &mut self.worksheets {
+            worksheet.set_global_dxf_formats(&self.dxf_formats);
+        }
+
         // Prepare worksheet tables.
         self.prepare_tables()?;
 
@@ -2201,6 +2817,108 @@ impl Workbook {
         Ok(())
     }
 
+    // Get the index of the hidden worksheet used for data validation lists,
+    // adding it if required. The worksheet is called "_Lists", or "_Lists1",
+    // "_Lists2", etc. if the name is already used by another worksheet.
+    fn hidden_list_sheet_index(&mut self) -> Result<usize, XlsxError> {
+        if let Some(index) = self.hidden_list_sheet {
+            return Ok(index);
+        }
+
+        // Find a name that isn't in use, ignoring case like Excel, before
+        // adding the worksheet.
+        let is_used = |name: &str| {
+            self.worksheets
+                .iter()
+                .any(|worksheet| worksheet.name.to_lowercase() == name.to_lowercase())
+        };
+
+        let mut name = "_Lists".to_string();
+        let mut suffix = 1;
+        while is_used(&name) {
+            name = format!("_Lists{suffix}");
+            suffix += 1;
+        }
+
+        let mut worksheet = Worksheet::new();
+        worksheet.set_name(&name)?.set_hidden(true);
+        self.worksheets.push(worksheet);
+
+        let index = self.worksheets.len() - 1;
+        self.hidden_list_sheet = Some(index);
+
+        Ok(index)
+    }
+
+    // Write a list of strings to the next free column of the hidden "_Lists"
+    // worksheet, adding the worksheet if required. The first row is used for
+    // an optional header. Returns an absolute reference to the list range.
+    fn write_hidden_list(&mut self, header: &str, list: &[String]) -> Result<String, XlsxError> {
+        let sheet_index = self.hidden_list_sheet_index()?;
+
+        let col = self.hidden_list_col;
+        let worksheet = &mut self.worksheets[sheet_index];
+
+        if !header.is_empty() {
+            worksheet.write_string(0, col, header)?;
+        }
+        worksheet.write_column(1, col, list)?;
+
+        self.hidden_list_col += 1;
+
+        let range = utility::cell_range_absolute(1, col, list.len() as RowNum, col);
+        let quoted_sheet_name = utility::quote_sheet_name(&worksheet.name);
+
+        Ok(format!("{quoted_sheet_name}!{range}"))
+    }
+
+    // Move data validation lists that exceed Excel's 255 character limit for
+    // inline lists to the hidden "_Lists" worksheet and refer to them via a
+    // range. Lists are only moved once so this is safe for repeated saves.
+    fn prepare_long_validation_lists(&mut self) -> Result<(), XlsxError> {
+        let mut long_lists = vec![];
+        for (sheet_index, worksheet) in self.worksheets.iter().enumerate() {
+            for (cell_range, data_validation) in &worksheet.data_validations {
+                if data_validation.validation_type == DataValidationType::List
+                    && !data_validation.long_list.is_empty()
+                {
+                    long_lists.push((sheet_index, cell_range.clone()));
+                }
+            }
+        }
+
+        // Identical lists share the same range.
+        let mut list_ranges: HashMap<Vec<String>, String> = HashMap::new();
+
+        for (sheet_index, cell_range) in long_lists {
+            let list = std::mem::take(
+                &mut self.worksheets[sheet_index]
+                    .data_validations
+                    .get_mut(&cell_range)
+                    .unwrap()
+                    .long_list,
+            );
+
+            let range = match list_ranges.get(&list) {
+                Some(range) => range.clone(),
+                None => {
+                    let range = self.write_hidden_list("", &list)?;
+                    list_ranges.insert(list, range.clone());
+                    range
+                }
+            };
+
+            if let Some(data_validation) = self.worksheets[sheet_index]
+                .data_validations
+                .get_mut(&cell_range)
+            {
+                data_validation.rule = DataValidationRuleInternal::ListSource(range);
+            }
+        }
+
+        Ok(())
+    }
+
     // Iterates through the worksheets and finds which is the user-defined Active
     // sheet. If none has been set, then defaults to the first sheet, like Excel.
     fn set_active_worksheets(&mut self) {
@@ -2320,20 +3038,41 @@ impl Workbook {
                 );
             }
 
-            if !worksheet.charts.is_empty() {
-                worksheet.prepare_worksheet_charts(chart_id, drawing_id);
-                chart_id += worksheet.charts.len() as u32;
+            let num_charts = worksheet.all_charts().count() as u32;
+            if num_charts > 0 {
+                worksheet.prepare_worksheet_charts(
+                    &mut worksheet_image_ids,
+                    &mut image_id,
+                    chart_id,
+                    drawing_id,
+                );
+                chart_id += num_charts;
             }
 
             if !worksheet.shapes.is_empty() {
-                worksheet.prepare_worksheet_shapes(shape_id, drawing_id);
-                shape_id += worksheet.shapes.len() as u32;
+                worksheet.prepare_worksheet_shapes(
+                    &mut worksheet_image_ids,
+                    &mut image_id,
+                    shape_id,
+                    drawing_id,
+                );
+                shape_id += (worksheet.shapes.len() + worksheet.shape_connectors.len()) as u32;
+            }
+
+            if !worksheet.drawing_groups.is_empty() {
+                worksheet.prepare_worksheet_drawing_groups(
+                    &mut worksheet_image_ids,
+                    &mut image_id,
+                    &mut shape_id,
+                    drawing_id,
+                );
             }
 
             // Increase the drawing number/id for image/chart file.
             if !worksheet.images.is_empty()
                 || !worksheet.charts.is_empty()
                 || !worksheet.shapes.is_empty()
+                || !worksheet.drawing_groups.is_empty()
             {
                 drawing_id += 1;
             }
@@ -2342,6 +3081,19 @@ impl Workbook {
                 worksheet.prepare_header_footer_images(&mut header_footer_image_ids, &mut image_id);
             }
         }
+
+        // Charts with inserted shapes have their own drawing file. These are
+        // numbered after the worksheet drawing files.
+        for worksheet in &mut self.worksheets {
+            for chart in worksheet.all_charts_mut() {
+                if chart.shapes.is_empty() {
+                    chart.user_shapes_drawing_id = 0;
+                } else {
+                    chart.user_shapes_drawing_id = drawing_id;
+                    drawing_id += 1;
+                }
+            }
+        }
     }
 
     // Prepare and check each table in the workbook.
@@ -2368,6 +3120,27 @@ impl Workbook {
             }
         }
 
+        // Check that any custom table styles have been added to the workbook.
+        // Excel matches style names case-insensitively.
+        for worksheet in &self.worksheets {
+            for table in &worksheet.tables {
+                if let Some(name) = &table.custom_style {
+                    let lowercase_name = name.to_lowercase();
+                    if !self
+                        .table_styles
+                        .iter()
+                        .any(|table_style| table_style.name.to_lowercase() == lowercase_name)
+                    {
+                        let error = format!(
+                            "Table '{}' uses custom table style '{name}' which hasn't been added to the workbook",
+                            table.name
+                        );
+                        return Err(XlsxError::TableError(error));
+                    }
+                }
+            }
+        }
+
         Ok(())
     }
 
@@ -2385,13 +3158,9 @@ impl Workbook {
 
         // Add the chart ranges to the cache lookup table.
         for worksheet in &self.worksheets {
-            if !worksheet.charts.is_empty() {
-                for chart in worksheet.charts.values() {
-                    Self::insert_chart_ranges_to_cache(chart, &mut chart_caches);
-
-                    if let Some(chart) = &chart.combined_chart {
-                        Self::insert_chart_ranges_to_cache(chart, &mut chart_caches);
-                    }
+            for chart in worksheet.all_charts() {
+                for range in chart.cell_ranges() {
+                    chart_caches.insert(range.key(), ChartRangeCacheData::new());
                 }
             }
         }
@@ -2412,13 +3181,11 @@ impl Workbook {
 
         // Fill the caches back into the chart ranges.
         for worksheet in &mut self.worksheets {
-            if !worksheet.charts.is_empty() {
-                for chart in worksheet.charts.values_mut() {
-                    Self::update_chart_ranges_from_cache(chart, &mut chart_caches);
+            for chart in worksheet.all_charts_mut() {
+                Self::update_chart_ranges_from_cache(chart, &mut chart_caches);
 
-                    if let Some(chart) = &mut chart.combined_chart {
-                        Self::update_chart_ranges_from_cache(chart, &mut chart_caches);
-                    }
+                for chart in &mut chart.combined_charts {
+                    Self::update_chart_ranges_from_cache(chart, &mut chart_caches);
                 }
             }
         }
@@ -2426,36 +3193,6 @@ impl Workbook {
         Ok(())
     }
 
-    // Insert all the various chart ranges into the lookup range cache.
-    fn insert_chart_ranges_to_cache(
-        chart: &Chart,
-        chart_caches: &mut HashMap<(String, RowNum, ColNum, RowNum, ColNum), ChartRangeCacheData>,
-    ) {
-        Self::insert_to_chart_cache(&chart.title.range, chart_caches);
-        Self::insert_to_chart_cache(&chart.x_axis.title.range, chart_caches);
-        Self::insert_to_chart_cache(&chart.y_axis.title.range, chart_caches);
-
-        for series in &chart.series {
-            Self::insert_to_chart_cache(&series.title.range, chart_caches);
-            Self::insert_to_chart_cache(&series.value_range, chart_caches);
-            Self::insert_to_chart_cache(&series.category_range, chart_caches);
-
-            for data_label in &series.custom_data_labels {
-                Self::insert_to_chart_cache(&data_label.title.range, chart_caches);
-            }
-
-            if let Some(error_bars) = &series.y_error_bars {
-                Self::insert_to_chart_cache(&error_bars.plus_range, chart_caches);
-                Self::insert_to_chart_cache(&error_bars.minus_range, chart_caches);
-            }
-
-            if let Some(error_bars) = &series.x_error_bars {
-                Self::insert_to_chart_cache(&error_bars.plus_range, chart_caches);
-                Self::insert_to_chart_cache(&error_bars.minus_range, chart_caches);
-            }
-        }
-    }
-
     // Update all the various chart ranges from the lookup range cache.
     fn update_chart_ranges_from_cache(
         chart: &mut Chart,
@@ -2488,17 +3225,6 @@ impl Workbook {
         }
     }
 
-    // Insert a chart range (expressed as a hash/key value) into the chart cache
-    // for lookup later.
-    fn insert_to_chart_cache(
-        range: &ChartRange,
-        chart_caches: &mut HashMap<(String, RowNum, ColNum, RowNum, ColNum), ChartRangeCacheData>,
-    ) {
-        if range.has_data() {
-            chart_caches.insert(range.key(), ChartRangeCacheData::new());
-        }
-    }
-
     // Populate a chart range cache with data read from the worksheet.
     fn update_range_cache(
         range: &mut ChartRange,
@@ -2782,8 +3508,11 @@ impl Workbook {
                 package_options.num_drawings += 1;
             }
 
-            if !worksheet.charts.is_empty() {
-                package_options.num_charts += worksheet.charts.len() as u16;
+            for chart in worksheet.all_charts() {
+                package_options.num_charts += 1;
+                if !chart.shapes.is_empty() {
+                    package_options.num_chart_drawings += 1;
+                }
             }
 
             if !worksheet.tables.is_empty() {
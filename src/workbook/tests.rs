@@ -7,8 +7,12 @@
 #[cfg(test)]
 mod workbook_tests {
 
+    use crate::worksheet::COL_MAX;
     use crate::{test_functions::xml_to_vec, XlsxError};
-    use crate::{xmlwriter, Table, Workbook};
+    use crate::{
+        xmlwriter, CustomTableStyle, DataValidation, DataValidationRuleInternal, Format, Table,
+        Workbook,
+    };
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -97,4 +101,286 @@ mod workbook_tests {
 
         assert!(matches!(result, Err(XlsxError::TableNameReused(_))));
     }
+
+    #[test]
+    fn custom_table_style_names() {
+        let mut workbook = Workbook::default();
+
+        let result = workbook.add_table_style(&CustomTableStyle::new(""));
+        assert!(matches!(result, Err(XlsxError::TableError(_))));
+
+        let result = workbook.add_table_style(&CustomTableStyle::new("TableStyleMedium2"));
+        assert!(matches!(result, Err(XlsxError::TableError(_))));
+
+        let result = workbook.add_table_style(&CustomTableStyle::new("PivotStyleLight1"));
+        assert!(matches!(result, Err(XlsxError::TableError(_))));
+
+        let result = workbook.add_table_style(&CustomTableStyle::new("MyStyle"));
+        assert!(result.is_ok());
+
+        let result = workbook.add_table_style(&CustomTableStyle::new("mystyle"));
+        assert!(matches!(result, Err(XlsxError::TableError(_))));
+    }
+
+    #[test]
+    fn unknown_custom_table_style() {
+        let mut workbook = Workbook::default();
+        let worksheet = workbook.add_worksheet();
+
+        let custom_style = CustomTableStyle::new("MyStyle");
+        let table = Table::new().set_custom_style(&custom_style);
+
+        worksheet.add_table(0, 0, 9, 9, &table).unwrap();
+
+        let result = workbook.prepare_tables();
+        assert!(matches!(result, Err(XlsxError::TableError(_))));
+
+        workbook.add_table_style(&custom_style).unwrap();
+
+        let result = workbook.prepare_tables();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn custom_table_style_case_insensitive() {
+        let mut workbook = Workbook::default();
+        let worksheet = workbook.add_worksheet();
+
+        let table = Table::new().set_custom_style(&CustomTableStyle::new("mystyle"));
+
+        worksheet.add_table(0, 0, 9, 9, &table).unwrap();
+        workbook
+            .add_table_style(&CustomTableStyle::new("MyStyle"))
+            .unwrap();
+
+        let result = workbook.prepare_tables();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn cell_style_names() {
+        let mut workbook = Workbook::default();
+        let format = Format::new().set_bold();
+
+        let result = workbook.add_cell_style("", &format);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = workbook.add_cell_style("Normal", &format);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = workbook.add_cell_style("hyperlink", &format);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = workbook.add_cell_style("My Style", &format);
+        assert!(result.is_ok());
+
+        let result = workbook.add_cell_style("MY STYLE", &format);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn cell_style_formats() {
+        let mut workbook = Workbook::default();
+        let format = Format::new().set_bold();
+
+        let style_format = workbook.add_cell_style("My Style", &format).unwrap();
+        assert_eq!(style_format.cell_style, "My Style");
+        assert_ne!(style_format, format);
+
+        // Derived formats keep the cell style.
+        let derived = style_format.clone().set_italic();
+        assert_eq!(derived.cell_style, "My Style");
+
+        let merged = Format::new().set_italic().merge(&style_format);
+        assert_eq!(merged.cell_style, "My Style");
+    }
+
+    #[test]
+    fn dependent_list_validation() {
+        let mut workbook = Workbook::default();
+        workbook.add_worksheet().set_name("Orders").unwrap();
+
+        let result = workbook.add_dependent_list_validation("Other", 1, 10, 0, 1, [("A", ["B"])]);
+        assert!(matches!(
+            result,
+            Err(XlsxError::UnknownWorksheetNameOrIndex(_))
+        ));
+
+        let result = workbook.add_dependent_list_validation("Orders", 1, 10, 0, 1, [("", ["B"])]);
+        assert!(matches!(result, Err(XlsxError::DataValidationError(_))));
+
+        let result = workbook.add_dependent_list_validation(
+            "Orders",
+            1,
+            10,
+            0,
+            1,
+            [("Côte d'Ivoire", ["B"])],
+        );
+        assert!(matches!(result, Err(XlsxError::DataValidationError(_))));
+
+        let result = workbook.add_dependent_list_validation(
+            "Orders",
+            1,
+            10,
+            0,
+            1,
+            [("A", ["B"]), ("A", ["C"])],
+        );
+        assert!(matches!(result, Err(XlsxError::DataValidationError(_))));
+
+        let empty: [&str; 0] = [];
+        let result = workbook.add_dependent_list_validation("Orders", 1, 10, 0, 1, [("A", empty)]);
+        assert!(matches!(result, Err(XlsxError::DataValidationError(_))));
+
+        // The hidden worksheet isn't added for invalid lists.
+        assert_eq!(workbook.worksheets.len(), 1);
+
+        let lists = vec![
+            ("New Zealand", vec!["Auckland", "Otago"]),
+            ("Peru", vec!["Lima"]),
+        ];
+        let result = workbook.add_dependent_list_validation("Orders", 1, 10, 0, 1, lists);
+        assert!(result.is_ok());
+
+        let lists = vec![("Otago", vec!["Dunedin"])];
+        let result = workbook.add_dependent_list_validation("Orders", 1, 10, 1, 2, lists);
+        assert!(result.is_ok());
+
+        assert_eq!(workbook.worksheets.len(), 2);
+        assert_eq!(workbook.worksheets[1].name, "_Lists");
+
+        let names: Vec<(&str, &str)> = workbook
+            .user_defined_names
+            .iter()
+            .map(|defined_name| (defined_name.name.as_str(), defined_name.range.as_str()))
+            .collect();
+
+        assert_eq!(
+            names,
+            vec![
+                ("_List1_New_Zealand", "_Lists!$A$2:$A$3"),
+                ("_List1_Peru", "_Lists!$B$2"),
+                ("_List2_Otago", "_Lists!$C$2"),
+            ]
+        );
+    }
+
+    #[test]
+    fn dependent_list_validation_unchanged_on_error() {
+        let mut workbook = Workbook::default();
+        workbook.add_worksheet().set_name("Orders").unwrap();
+        workbook.define_name("_List1_Peru", "=Orders!$A$1").unwrap();
+
+        // Names that are the same after spaces are replaced with underscores.
+        let result = workbook.add_dependent_list_validation(
+            "Orders",
+            1,
+            10,
+            0,
+            1,
+            [("A B", ["C"]), ("A_B", ["D"])],
+        );
+        assert!(matches!(result, Err(XlsxError::DataValidationError(_))));
+
+        // Names that are the same, ignoring case.
+        let result = workbook.add_dependent_list_validation(
+            "Orders",
+            1,
+            10,
+            0,
+            1,
+            [("Apple", ["C"]), ("apple", ["D"])],
+        );
+        assert!(matches!(result, Err(XlsxError::DataValidationError(_))));
+
+        // Name that is already defined in the workbook.
+        let result = workbook.add_dependent_list_validation(
+            "Orders",
+            1,
+            10,
+            0,
+            1,
+            [("Chile", ["Santiago"]), ("PERU", ["Lima"])],
+        );
+        assert!(matches!(result, Err(XlsxError::DataValidationError(_))));
+
+        // Invalid ranges.
+        let result = workbook.add_dependent_list_validation("Orders", 10, 1, 0, 1, [("A", ["B"])]);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+
+        let result =
+            workbook.add_dependent_list_validation("Orders", 1, 10, 0, COL_MAX, [("A", ["B"])]);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+
+        // Check that nothing was added to the workbook.
+        assert_eq!(workbook.worksheets.len(), 1);
+        assert!(workbook.worksheets[0].data_validations.is_empty());
+        assert_eq!(workbook.user_defined_names.len(), 1);
+        assert_eq!(workbook.dependent_list_count, 0);
+    }
+
+    #[test]
+    fn hidden_list_sheet_unique_name() {
+        let mut workbook = Workbook::default();
+        workbook.add_worksheet().set_name("Orders").unwrap();
+        workbook.add_worksheet().set_name("_lists").unwrap();
+        workbook.add_worksheet().set_name("_Lists1").unwrap();
+
+        let result = workbook.add_dependent_list_validation("Orders", 1, 10, 0, 1, [("A", ["B"])]);
+        assert!(result.is_ok());
+
+        assert_eq!(workbook.worksheets.len(), 4);
+        assert_eq!(workbook.worksheets[3].name, "_Lists2");
+        assert_eq!(workbook.user_defined_names[0].range, "_Lists2!$A$2");
+
+        // The hidden worksheet is reused.
+        let result = workbook.add_dependent_list_validation("Orders", 1, 10, 1, 2, [("B", ["C"])]);
+        assert!(result.is_ok());
+
+        assert_eq!(workbook.worksheets.len(), 4);
+        assert_eq!(workbook.user_defined_names[1].range, "_Lists2!$B$2");
+    }
+
+    #[test]
+    fn long_validation_list() -> Result<(), XlsxError> {
+        let mut workbook = Workbook::default();
+        let list: Vec<String> = (1..=100).map(|i| format!("Item {i}")).collect();
+
+        let data_validation = DataValidation::new().allow_list_strings(&list)?;
+        let short_validation = DataValidation::new().allow_list_strings(&["Foo", "Bar"])?;
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.add_data_validation(0, 0, 9, 0, &data_validation)?;
+        worksheet.add_data_validation(0, 1, 9, 1, &data_validation)?;
+        worksheet.add_data_validation(0, 2, 9, 2, &short_validation)?;
+
+        workbook.save_to_buffer()?;
+
+        // Save twice to check that the list is only written once.
+        workbook.save_to_buffer()?;
+
+        assert_eq!(workbook.worksheets.len(), 2);
+        assert_eq!(workbook.worksheets[1].name, "_Lists");
+
+        let rules: Vec<String> = workbook.worksheets[0]
+            .data_validations
+            .values()
+            .map(|data_validation| match &data_validation.rule {
+                DataValidationRuleInternal::ListSource(list) => list.clone(),
+                _ => String::new(),
+            })
+            .collect();
+
+        assert_eq!(
+            rules,
+            vec![
+                "_Lists!$A$2:$A$101".to_string(),
+                "_Lists!$A$2:$A$101".to_string(),
+                "\"Foo,Bar\"".to_string(),
+            ]
+        );
+
+        Ok(())
+    }
 }
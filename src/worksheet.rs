@@ -1352,6 +1352,7 @@ use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, HashMap, HashSet};
 use std::io::Cursor;
 use std::io::Write;
+use std::io::{BufReader, Read};
 use std::mem;
 use std::sync::{Arc, Mutex, RwLock};
 use std::{cmp, fmt};
@@ -1368,20 +1369,79 @@ use std::fs::File;
 #[cfg(feature = "chrono")]
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 
+#[cfg(feature = "time")]
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+#[cfg(feature = "jiff")]
+use jiff::{civil, Zoned};
+
 #[cfg(feature = "rust_decimal")]
 use rust_decimal::prelude::{Decimal, ToPrimitive};
 
+#[cfg(feature = "polars")]
+use polars::prelude::{AnyValue, DataFrame, Series, TimeUnit};
+
+#[cfg(feature = "polars")]
+use chrono::{DateTime, Offset, TimeZone};
+
+#[cfg(feature = "polars")]
+use chrono_tz::Tz;
+
+#[cfg(feature = "polars")]
+use crate::DataFrameOptions;
+
+#[cfg(any(feature = "polars", feature = "arrow"))]
+use crate::data_options::ColumnProperties;
+
+#[cfg(feature = "arrow")]
+use std::borrow::Borrow;
+
+#[cfg(feature = "arrow")]
+use arrow_array::cast::AsArray;
+
+#[cfg(feature = "arrow")]
+use arrow_array::{temporal_conversions::as_datetime_with_timezone, timezone::Tz as ArrowTz};
+
+#[cfg(feature = "arrow")]
+use arrow_array::types::{
+    Date32Type, Date64Type, Decimal128Type, DurationMicrosecondType, DurationMillisecondType,
+    DurationNanosecondType, DurationSecondType, Float16Type, Float32Type, Float64Type, Int16Type,
+    Int32Type, Int64Type, Int8Type, Time32MillisecondType, Time32SecondType, Time64MicrosecondType,
+    Time64NanosecondType, TimestampMicrosecondType, TimestampMillisecondType,
+    TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type, UInt64Type, UInt8Type,
+};
+
+#[cfg(feature = "arrow")]
+use arrow_array::{downcast_dictionary_array, Array, RecordBatch};
+
+#[cfg(feature = "arrow")]
+use arrow_schema::{DataType as ArrowDataType, TimeUnit as ArrowTimeUnit};
+
+#[cfg(feature = "arrow")]
+use crate::RecordBatchOptions;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "render")]
+use std::path::Path;
+
+#[cfg(feature = "render")]
+use crate::render::{self, RenderCell, RenderPageSetup, RenderSheet, RenderValue};
+
 #[cfg(feature = "serde")]
 use crate::{
     deserialize_headers, serializer::SerializerState, CustomSerializeField,
     SerializationHeaderConfig, SerializeFieldOptions, SerializerHeader, TableData, XlsxSerialize,
 };
 
-use crate::drawing::{Drawing, DrawingCoordinates, DrawingInfo, DrawingObject, DrawingType};
+use crate::csv_import::{CsvReader, CsvValue};
+use crate::drawing::{
+    Drawing, DrawingCoordinates, DrawingGroup, DrawingGroupObject, DrawingInfo, DrawingObject,
+    DrawingType,
+};
 use crate::error::XlsxError;
+use crate::font_metrics::{CellFont, FontMetrics};
 use crate::format::Format;
 use crate::formula::Formula;
 use crate::shared_strings_table::SharedStringsTable;
@@ -1393,10 +1453,12 @@ use crate::xmlwriter::{
 };
 use crate::{
     utility, xmlwriter, Button, Chart, ChartEmptyCells, ChartRangeCacheData,
-    ChartRangeCacheDataType, Color, ConditionalFormat, DataValidation, DataValidationErrorStyle,
-    DataValidationRuleInternal, DataValidationType, ExcelDateTime, FilterCondition, FilterCriteria,
-    FilterData, FilterDataType, HeaderImagePosition, HyperlinkType, Image, IntoExcelDateTime, Note,
-    ObjectMovement, ProtectionOptions, Shape, Sparkline, SparklineType, Table, TableFunction, Url,
+    ChartRangeCacheDataType, Color, ConditionalFormat, CsvImportOptions, DataValidation,
+    DataValidationErrorStyle, DataValidationRuleInternal, DataValidationType, ExcelDateTime,
+    FilterCondition, FilterCriteria, FilterData, FilterDataType, HeaderImagePosition,
+    HyperlinkType, Image, IntoExcelDateTime, Note, NumberFormat, ObjectMovement, ProtectionOptions,
+    Shape, ShapeConnection, ShapeConnectionSite, ShapeType, Sparkline, SparklineType, Table,
+    TableColumn, TableFunction, Url,
 };
 
 /// Integer type to represent a zero indexed row number. Excel's limit for rows
@@ -1516,6 +1578,8 @@ pub struct Worksheet {
     pub(crate) buttons: BTreeMap<(RowNum, ColNum, u32, u32), Button>,
     pub(crate) notes: BTreeMap<RowNum, BTreeMap<ColNum, Note>>,
     pub(crate) shapes: BTreeMap<(RowNum, ColNum, u32, u32), Shape>,
+    pub(crate) shape_connectors: Vec<(RowNum, ColNum, RowNum, ColNum, Shape)>,
+    pub(crate) drawing_groups: BTreeMap<(RowNum, ColNum, u32, u32), DrawingGroup>,
     pub(crate) tables: Vec<Table>,
     pub(crate) has_embedded_image_descriptions: bool,
     pub(crate) embedded_images: Vec<Image>,
@@ -1534,6 +1598,8 @@ pub struct Worksheet {
     pub(crate) has_workbook_global_xfs: bool,
     pub(crate) has_workbook_global_sst: bool,
     pub(crate) background_image: Option<Image>,
+    autofit_fonts: Vec<FontMetrics>,
+    autofit_row_heights: bool,
 
     // These collections need to be reset on resave.
     drawing_rel_ids: HashMap<String, u32>,
@@ -1610,7 +1676,7 @@ pub struct Worksheet {
     cells_with_autofilter: HashMap<(RowNum, ColNum), (FilterType, CellRange)>,
     conditional_formats: BTreeMap<String, Vec<Box<dyn ConditionalFormat + Sync + Send>>>,
     conditional_format_order: Vec<String>,
-    data_validations: BTreeMap<String, DataValidation>,
+    pub(crate) data_validations: BTreeMap<String, DataValidation>,
     has_conditional_formats: bool,
     use_x14_extensions: bool,
     has_x14_conditional_formats: bool,
@@ -1637,6 +1703,12 @@ pub struct Worksheet {
     #[cfg(feature = "constant_memory")]
     write_ahead: BTreeMap<RowNum, BTreeMap<ColNum, CellType>>,
 
+    #[cfg(feature = "constant_memory")]
+    chart_data_ranges: Vec<(RowNum, ColNum, RowNum, ColNum)>,
+
+    #[cfg(feature = "constant_memory")]
+    chart_data: BTreeMap<RowNum, BTreeMap<ColNum, CellType>>,
+
     #[cfg(feature = "serde")]
     pub(crate) serializer_state: SerializerState,
 }
@@ -1801,6 +1873,8 @@ impl Worksheet {
             hyperlinks: BTreeMap::new(),
             images: BTreeMap::new(),
             shapes: BTreeMap::new(),
+            shape_connectors: vec![],
+            drawing_groups: BTreeMap::new(),
             drawing: Drawing::new(),
             image_types: [false; NUM_IMAGE_FORMATS],
             header_footer_images: [None, None, None, None, None, None],
@@ -1851,6 +1925,8 @@ impl Worksheet {
             outline_symbols_above: false,
             outline_symbols_left: false,
             background_image: None,
+            autofit_fonts: vec![],
+            autofit_row_heights: false,
 
             // These collections need to be reset on resave.
             comment_relationships: vec![],
@@ -1878,6 +1954,12 @@ impl Worksheet {
             #[cfg(feature = "constant_memory")]
             write_ahead: BTreeMap::new(),
 
+            #[cfg(feature = "constant_memory")]
+            chart_data_ranges: vec![],
+
+            #[cfg(feature = "constant_memory")]
+            chart_data: BTreeMap::new(),
+
             #[cfg(feature = "serde")]
             serializer_state: SerializerState::new(),
         }
@@ -2101,6 +2183,35 @@ impl Worksheet {
     /// [`chrono::NaiveTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveTime.html
     /// [`chrono::NaiveDateTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDateTime.html
     ///
+    /// If the `time` feature is enabled you can use the following types:
+    ///
+    /// - [`time::PrimitiveDateTime`].
+    /// - [`time::OffsetDateTime`]: the date and time in its offset is used.
+    /// - [`time::Date`].
+    /// - [`time::Time`].
+    ///
+    /// [`time::PrimitiveDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+    /// [`time::OffsetDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.OffsetDateTime.html
+    /// [`time::Date`]: https://docs.rs/time/latest/time/struct.Date.html
+    /// [`time::Time`]: https://docs.rs/time/latest/time/struct.Time.html
+    ///
+    /// If the `jiff` feature is enabled you can use the following types:
+    ///
+    /// - [`jiff::civil::DateTime`].
+    /// - [`jiff::Zoned`]: the civil date and time in its time zone is used.
+    /// - [`jiff::civil::Date`].
+    /// - [`jiff::civil::Time`].
+    ///
+    /// [`jiff::civil::DateTime`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.DateTime.html
+    /// [`jiff::Zoned`]: https://docs.rs/jiff/latest/jiff/struct.Zoned.html
+    /// [`jiff::civil::Date`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.Date.html
+    /// [`jiff::civil::Time`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.Time.html
+    ///
     /// If the `rust_decimal` feature is enabled you can write the [`Decimal`] type via [`rust_decimal`].
     /// This requires that the `Decimal` can be represented as a `f64` in Excel.
     ///
@@ -2163,6 +2274,35 @@ impl Worksheet {
     /// [`chrono::NaiveTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveTime.html
     /// [`chrono::NaiveDateTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDateTime.html
     ///
+    /// If the `time` feature is enabled you can use the following types:
+    ///
+    /// - [`time::PrimitiveDateTime`].
+    /// - [`time::OffsetDateTime`]: the date and time in its offset is used.
+    /// - [`time::Date`].
+    /// - [`time::Time`].
+    ///
+    /// [`time::PrimitiveDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+    /// [`time::OffsetDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.OffsetDateTime.html
+    /// [`time::Date`]: https://docs.rs/time/latest/time/struct.Date.html
+    /// [`time::Time`]: https://docs.rs/time/latest/time/struct.Time.html
+    ///
+    /// If the `jiff` feature is enabled you can use the following types:
+    ///
+    /// - [`jiff::civil::DateTime`].
+    /// - [`jiff::Zoned`]: the civil date and time in its time zone is used.
+    /// - [`jiff::civil::Date`].
+    /// - [`jiff::civil::Time`].
+    ///
+    /// [`jiff::civil::DateTime`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.DateTime.html
+    /// [`jiff::Zoned`]: https://docs.rs/jiff/latest/jiff/struct.Zoned.html
+    /// [`jiff::civil::Date`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.Date.html
+    /// [`jiff::civil::Time`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.Time.html
+    ///
     /// Users can also use this method to write their own data types to Excel by
     /// implementing the [`IntoExcelData`] trait.
     ///
@@ -4307,6 +4447,35 @@ impl Worksheet {
     /// [`chrono::NaiveTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveTime.html
     /// [`chrono::NaiveDateTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDateTime.html
     ///
+    /// If the `time` feature is enabled you can use the following types:
+    ///
+    /// - [`time::PrimitiveDateTime`].
+    /// - [`time::OffsetDateTime`]: the date and time in its offset is used.
+    /// - [`time::Date`].
+    /// - [`time::Time`].
+    ///
+    /// [`time::PrimitiveDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+    /// [`time::OffsetDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.OffsetDateTime.html
+    /// [`time::Date`]: https://docs.rs/time/latest/time/struct.Date.html
+    /// [`time::Time`]: https://docs.rs/time/latest/time/struct.Time.html
+    ///
+    /// If the `jiff` feature is enabled you can use the following types:
+    ///
+    /// - [`jiff::civil::DateTime`].
+    /// - [`jiff::Zoned`]: the civil date and time in its time zone is used.
+    /// - [`jiff::civil::Date`].
+    /// - [`jiff::civil::Time`].
+    ///
+    /// [`jiff::civil::DateTime`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.DateTime.html
+    /// [`jiff::Zoned`]: https://docs.rs/jiff/latest/jiff/struct.Zoned.html
+    /// [`jiff::civil::Date`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.Date.html
+    /// [`jiff::civil::Time`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.Time.html
+    ///
     /// Excel stores dates and times as a floating point number with a number
     /// format to defined how it is displayed. The number format is set via a
     /// [`Format`] struct which can also control visual formatting such as bold
@@ -4497,6 +4666,35 @@ impl Worksheet {
     /// [`chrono::NaiveDateTime`]:
     ///     https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDateTime.html
     ///
+    /// If the `time` feature is enabled you can use the following types:
+    ///
+    /// - [`time::PrimitiveDateTime`].
+    /// - [`time::OffsetDateTime`]: the date and time in its offset is used.
+    /// - [`time::Date`].
+    /// - [`time::Time`].
+    ///
+    /// [`time::PrimitiveDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+    /// [`time::OffsetDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.OffsetDateTime.html
+    /// [`time::Date`]: https://docs.rs/time/latest/time/struct.Date.html
+    /// [`time::Time`]: https://docs.rs/time/latest/time/struct.Time.html
+    ///
+    /// If the `jiff` feature is enabled you can use the following types:
+    ///
+    /// - [`jiff::civil::DateTime`].
+    /// - [`jiff::Zoned`]: the civil date and time in its time zone is used.
+    /// - [`jiff::civil::Date`].
+    /// - [`jiff::civil::Time`].
+    ///
+    /// [`jiff::civil::DateTime`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.DateTime.html
+    /// [`jiff::Zoned`]: https://docs.rs/jiff/latest/jiff/struct.Zoned.html
+    /// [`jiff::civil::Date`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.Date.html
+    /// [`jiff::civil::Time`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.Time.html
+    ///
     /// # Parameters
     ///
     /// - `row`: The zero indexed row number.
@@ -4591,6 +4789,35 @@ impl Worksheet {
     /// [`chrono::NaiveTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveTime.html
     /// [`chrono::NaiveDateTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDateTime.html
     ///
+    /// If the `time` feature is enabled you can use the following types:
+    ///
+    /// - [`time::PrimitiveDateTime`].
+    /// - [`time::OffsetDateTime`]: the date and time in its offset is used.
+    /// - [`time::Date`].
+    /// - [`time::Time`].
+    ///
+    /// [`time::PrimitiveDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+    /// [`time::OffsetDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.OffsetDateTime.html
+    /// [`time::Date`]: https://docs.rs/time/latest/time/struct.Date.html
+    /// [`time::Time`]: https://docs.rs/time/latest/time/struct.Time.html
+    ///
+    /// If the `jiff` feature is enabled you can use the following types:
+    ///
+    /// - [`jiff::civil::DateTime`].
+    /// - [`jiff::Zoned`]: the civil date and time in its time zone is used.
+    /// - [`jiff::civil::Date`].
+    /// - [`jiff::civil::Time`].
+    ///
+    /// [`jiff::civil::DateTime`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.DateTime.html
+    /// [`jiff::Zoned`]: https://docs.rs/jiff/latest/jiff/struct.Zoned.html
+    /// [`jiff::civil::Date`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.Date.html
+    /// [`jiff::civil::Time`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.Time.html
+    ///
     /// Excel stores dates and times as a floating point number with a number
     /// format to defined how it is displayed. The number format is set via a
     /// [`Format`] struct which can also control visual formatting such as bold
@@ -4686,6 +4913,35 @@ impl Worksheet {
     /// [`chrono::NaiveTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveTime.html
     /// [`chrono::NaiveDateTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDateTime.html
     ///
+    /// If the `time` feature is enabled you can use the following types:
+    ///
+    /// - [`time::PrimitiveDateTime`].
+    /// - [`time::OffsetDateTime`]: the date and time in its offset is used.
+    /// - [`time::Date`].
+    /// - [`time::Time`].
+    ///
+    /// [`time::PrimitiveDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+    /// [`time::OffsetDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.OffsetDateTime.html
+    /// [`time::Date`]: https://docs.rs/time/latest/time/struct.Date.html
+    /// [`time::Time`]: https://docs.rs/time/latest/time/struct.Time.html
+    ///
+    /// If the `jiff` feature is enabled you can use the following types:
+    ///
+    /// - [`jiff::civil::DateTime`].
+    /// - [`jiff::Zoned`]: the civil date and time in its time zone is used.
+    /// - [`jiff::civil::Date`].
+    /// - [`jiff::civil::Time`].
+    ///
+    /// [`jiff::civil::DateTime`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.DateTime.html
+    /// [`jiff::Zoned`]: https://docs.rs/jiff/latest/jiff/struct.Zoned.html
+    /// [`jiff::civil::Date`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.Date.html
+    /// [`jiff::civil::Time`]:
+    ///     https://docs.rs/jiff/latest/jiff/civil/struct.Time.html
+    ///
     /// Excel stores dates and times as a floating point number with a number
     /// format to defined how it is displayed. The number format is set via a
     /// [`Format`] struct which can also control visual formatting such as bold
@@ -4971,6 +5227,8 @@ impl Worksheet {
         string: &str,
         format: &Format,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check rows and cols are in the allowed range.
         if !self.check_dimensions(first_row, first_col)
             || !self.check_dimensions(last_row, last_col)
@@ -5184,6 +5442,8 @@ impl Worksheet {
         x_offset: u32,
         y_offset: u32,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check row and columns are in the allowed range.
         if !self.check_dimensions_only(row, col) {
             return Err(XlsxError::RowColumnLimitError);
@@ -5418,6 +5678,8 @@ impl Worksheet {
         image: &Image,
         keep_aspect_ratio: bool,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check row and columns are in the allowed range.
         if !self.check_dimensions_only(row, col) {
             return Err(XlsxError::RowColumnLimitError);
@@ -5640,9 +5902,7 @@ impl Worksheet {
 
         let mut chart = chart.clone();
         chart.is_chartsheet = self.is_chartsheet;
-
-        // Check that the chart has been set up correctly.
-        chart.validate()?;
+        self.check_inserted_chart(&mut chart)?;
 
         chart.x_offset = x_offset;
         chart.y_offset = y_offset;
@@ -5663,6 +5923,135 @@ impl Worksheet {
         Ok(self)
     }
 
+    // Set up and validate a chart that is inserted into the worksheet, either
+    // directly or as part of a drawing group.
+    fn check_inserted_chart(&mut self, chart: &mut Chart) -> Result<(), XlsxError> {
+        // Move any series with a different chart type into a combined chart.
+        chart.group_series_by_chart_type();
+
+        // Check that the chart has been set up correctly.
+        chart.validate()?;
+
+        // Retain the chart data in this worksheet when it is flushed to disk
+        // in constant memory mode so that it can be used in the chart caches.
+        #[cfg(feature = "constant_memory")]
+        if self.use_constant_memory {
+            for range in chart.cell_ranges() {
+                let (sheet_name, first_row, first_col, last_row, last_col) = range.key();
+                if sheet_name == self.name {
+                    self.chart_data_ranges
+                        .push((first_row, first_col, last_row, last_col));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Retain worksheet data that is used in a chart in "constant memory"
+    /// mode.
+    ///
+    /// Excel charts store a cache of the data that they refer to. This isn't
+    /// strictly necessary for Excel, which recalculates the data when the file
+    /// is loaded, but it is required by some non-Excel applications to render
+    /// the chart.
+    ///
+    /// In "constant memory" mode the worksheet data is written to disk row by
+    /// row so it isn't available to populate the chart caches when the file is
+    /// saved. To work around this the data in a chart range can be retained
+    /// in memory until the file is saved. This is done automatically for the
+    /// ranges of charts inserted into the same worksheet via
+    /// [`Worksheet::insert_chart()`]. This method can be used to retain the
+    /// data for charts in other worksheets.
+    ///
+    /// In both cases the range must be declared before the data is written,
+    /// since any rows that have already been written to disk can't be
+    /// retained.
+    ///
+    /// This method has no effect on standard worksheets, which keep all of
+    /// their data in memory until the file is saved.
+    ///
+    /// Constant memory mode requires the `rust_xlsxwriter` `constant_memory`
+    /// feature flag.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates retaining the data of a "constant
+    /// memory" worksheet so that it can be used in the cache of a chart in
+    /// another worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_retain_chart_data.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     // Add a worksheet in "constant memory" mode to store the data.
+    ///     let worksheet = workbook.add_worksheet_with_constant_memory();
+    ///
+    ///     // Retain the chart data before it is written.
+    ///     worksheet.retain_chart_data(0, 0, 4, 0)?;
+    ///
+    ///     worksheet.write_column(0, 0, [10, 40, 50, 20, 10])?;
+    ///
+    ///     // Add a chart that refers to the data in another worksheet.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$5");
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.insert_chart(0, 0, &chart)?;
+    /// #
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "constant_memory")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "constant_memory")))]
+    pub fn retain_chart_data(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        if self.use_constant_memory {
+            self.chart_data_ranges
+                .push((first_row, first_col, last_row, last_col));
+        }
+
+        Ok(self)
+    }
+
     /// Add a Note to a cell.
     ///
     /// A Note is a post-it style message that is revealed when the user mouses
@@ -5748,6 +6137,8 @@ impl Worksheet {
         col: ColNum,
         note: &Note,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check row and columns are in the allowed range.
         if !self.check_dimensions(row, col) {
             return Err(XlsxError::RowColumnLimitError);
@@ -5786,7 +6177,7 @@ impl Worksheet {
         Ok(self)
     }
 
-    /// Insert a textbox shape into a worksheet.
+    /// Insert a textbox or other shape into a worksheet.
     ///
     /// This method can be used to insert an Excel Textbox shape with text into
     /// a worksheet.
@@ -5795,9 +6186,9 @@ impl Worksheet {
     /// methods that can be used to configure the size and appearance of the
     /// textbox.
     ///
-    /// Note, no Excel shape other than Textbox is supported. See [Support for
-    /// other Excel shape
-    /// types](crate::Shape#support-for-other-excel-shape-types).
+    /// Other Excel preset shapes, such as rectangles, ellipses and flowchart
+    /// symbols, can also be inserted. See [Other Excel shape
+    /// types](crate::Shape#other-excel-shape-types).
     ///
     /// # Parameters
     ///
@@ -5854,7 +6245,7 @@ impl Worksheet {
         Ok(self)
     }
 
-    /// Insert a textbox shape into a worksheet cell at an offset.
+    /// Insert a textbox or other shape into a worksheet cell at an offset.
     ///
     /// This method can be used to insert an Excel Textbox shape with text into
     /// a worksheet cell at a pixel offset.
@@ -5863,9 +6254,9 @@ impl Worksheet {
     /// methods that can be used to configure the size and appearance of the
     /// textbox.
     ///
-    /// Note, no Excel shape other than Textbox is supported. See [Support for
-    /// other Excel shape
-    /// types](crate::Shape#support-for-other-excel-shape-types).
+    /// Other Excel preset shapes, such as rectangles, ellipses and flowchart
+    /// symbols, can also be inserted. See [Other Excel shape
+    /// types](crate::Shape#other-excel-shape-types).
     ///
     /// # Parameters
     ///
@@ -5922,6 +6313,8 @@ impl Worksheet {
         x_offset: u32,
         y_offset: u32,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check row and columns are in the allowed range.
         if !self.check_dimensions_only(row, col) {
             return Err(XlsxError::RowColumnLimitError);
@@ -5937,44 +6330,67 @@ impl Worksheet {
         Ok(self)
     }
 
-    /// Make all worksheet notes visible when the file loads.
+    /// Insert a connector shape to join two shapes in a worksheet.
     ///
-    /// By default Excel hides cell notes until the user mouses over the parent
-    /// cell. However, if required you can make all worksheet notes visible when
-    /// the worksheet loads. You can also make individual notes visible using
-    /// the [`Note::set_visible()`](crate::Note::set_visible) method.
+    /// This method can be used to join two shapes, that have already been
+    /// inserted with [`Worksheet::insert_shape()`] or
+    /// [`Worksheet::insert_shape_with_offset()`], with a straight, elbow or
+    /// curved connector line. The shapes are identified by the cells that
+    /// they were inserted into. If there is more than one shape in a cell the
+    /// first one, in offset order, is used.
+    ///
+    /// The connector is attached to connection sites on the shapes so that it
+    /// stays joined to them if they are moved in Excel. The sites are chosen
+    /// automatically or they can be set explicitly with
+    /// [`Shape::set_connection_sites()`]. The line style and arrowheads of
+    /// the connector can be set with [`Shape::set_format()`] and
+    /// [`ShapeLine`](crate::ShapeLine).
     ///
     /// # Parameters
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    /// - `start_row`: The zero indexed row number of the start shape.
+    /// - `start_col`: The zero indexed column number of the start shape.
+    /// - `end_row`: The zero indexed row number of the end shape.
+    /// - `end_col`: The zero indexed column number of the end shape.
+    /// - `connector`: A connector [`Shape`] created with one of the connector
+    ///   types of [`ShapeType`](crate::ShapeType), such as
+    ///   [`ShapeType::StraightConnector`](crate::ShapeType::StraightConnector).
     ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - The shape isn't a connector type or
+    ///   there isn't a shape in one of the start or end cells.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates adding notes to a worksheet and
-    /// setting the worksheet property to make them all visible.
+    /// This example demonstrates joining two shapes in a worksheet with a
+    /// connector.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_show_all_notes.rs
+    /// # // This code is available in examples/doc_worksheet_insert_connector.rs
     /// #
-    /// # use rust_xlsxwriter::{Note, Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Shape, ShapeType, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
+    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     // Create a new note.
-    ///     let note = Note::new("Some text for the note");
+    ///     // Create two shapes and insert them in the worksheet.
+    ///     let shape1 = Shape::new(ShapeType::Rectangle).set_text("Shape 1");
+    ///     let shape2 = Shape::new(ShapeType::Rectangle).set_text("Shape 2");
     ///
-    ///     // Add the note to some worksheet cells.
-    ///     worksheet.insert_note(1, 0, &note)?;
-    ///     worksheet.insert_note(3, 3, &note)?;
-    ///     worksheet.insert_note(6, 0, &note)?;
-    ///     worksheet.insert_note(8, 3, &note)?;
+    ///     worksheet.insert_shape(1, 1, &shape1)?;
+    ///     worksheet.insert_shape(10, 6, &shape2)?;
     ///
-    ///     // Display all the notes in the worksheet.
-    ///     worksheet.show_all_notes(true);
+    ///     // Join the shapes with an elbow connector.
+    ///     let connector = Shape::new(ShapeType::ElbowConnector);
+    ///
+    ///     worksheet.insert_connector(1, 1, 10, 6, &connector)?;
     /// #
     /// #     // Save the file to disk.
     /// #     workbook.save("worksheet.xlsx")?;
@@ -5982,58 +6398,88 @@ impl Worksheet {
     /// #     Ok(())
     /// # }
     /// ```
-    ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_show_all_notes.png">
-    ///
-    pub fn show_all_notes(&mut self, enable: bool) -> &mut Worksheet {
-        self.show_all_notes = enable;
-        self
+    pub fn insert_connector(
+        &mut self,
+        start_row: RowNum,
+        start_col: ColNum,
+        end_row: RowNum,
+        end_col: ColNum,
+        connector: &Shape,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
+        // Check row and columns are in the allowed range.
+        if !self.check_dimensions_only(start_row, start_col)
+            || !self.check_dimensions_only(end_row, end_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        if !connector.shape_type.is_connector() {
+            return Err(XlsxError::ParameterError(
+                "Shape type must be a connector for Worksheet::insert_connector()".to_string(),
+            ));
+        }
+
+        for (row, col) in [(start_row, start_col), (end_row, end_col)] {
+            if self.shape_key_at_cell(row, col).is_none() {
+                return Err(XlsxError::ParameterError(format!(
+                    "No shape found in cell ({row}, {col}) for connector"
+                )));
+            }
+        }
+
+        self.shape_connectors
+            .push((start_row, start_col, end_row, end_col, connector.clone()));
+
+        Ok(self)
     }
 
-    /// Set the default author name for all the notes in the worksheet.
-    ///
-    /// The Note author is the creator of the note. In Excel the author name is
-    /// taken from the user name in the options/preference dialog. The note
-    /// author name appears in two places: at the start of the note text in bold
-    /// and at the bottom of the worksheet in the status bar.
+    /// Insert a group of drawing objects into a worksheet.
     ///
-    /// If no name is specified the default name "Author" will be applied to the
-    /// note. The author name for individual notes can be set via the
-    /// [`Note::set_author()`](crate::Note::set_author) method. Alternatively
-    /// this method can be used to set the default author name for all notes in
-    /// a worksheet.
+    /// This method can be used to insert a [`DrawingGroup`] of images, shapes,
+    /// textboxes and charts into a worksheet. The objects in the group move
+    /// and resize together in Excel. See the [`DrawingGroup`] documentation
+    /// for more details.
     ///
     /// # Parameters
     ///
-    /// - `name`: The note author name. Must be less than or equal to the Excel
-    ///   limit of 52 characters.
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `group`: The [`DrawingGroup`] to insert into the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - The group doesn't contain any
+    ///   objects.
+    /// - [`XlsxError::ChartError`] - A chart in the group isn't set up
+    ///   correctly. See [`Worksheet::insert_chart()`].
     ///
     /// # Examples
     ///
-    /// The following example demonstrates adding notes to a worksheet and setting
-    /// the default author name.
+    /// This example demonstrates inserting a group of shapes into a worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_default_note_author.rs
+    /// # // This code is available in examples/doc_worksheet_insert_drawing_group.rs
     /// #
-    /// # use rust_xlsxwriter::{Note, Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{DrawingGroup, Shape, ShapeType, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
+    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     // Create a new note.
-    ///     let note = Note::new("Some text for the note");
+    ///     // Create a group with a callout and a textbox.
+    ///     let group = DrawingGroup::new()
+    ///         .add_shape(&Shape::new(ShapeType::OvalCallout).set_text("Note"), 0, 0)
+    ///         .add_shape(&Shape::textbox().set_text("Some details"), 200, 0);
     ///
-    ///     // Add the note to some worksheet cells.
-    ///     worksheet.insert_note(2, 0, &note)?;
-    ///
-    ///     // Display all the notes in the worksheet.
-    ///     worksheet.set_default_note_author("Rust");
+    ///     // Insert the group into the worksheet.
+    ///     worksheet.insert_drawing_group(1, 1, &group)?;
     /// #
     /// #     // Save the file to disk.
     /// #     workbook.save("worksheet.xlsx")?;
@@ -6041,15 +6487,188 @@ impl Worksheet {
     /// #     Ok(())
     /// # }
     /// ```
+    pub fn insert_drawing_group(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        group: &DrawingGroup,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.insert_drawing_group_with_offset(row, col, group, 0, 0)?;
+
+        Ok(self)
+    }
+
+    /// Insert a group of drawing objects into a worksheet cell at an offset.
     ///
-    /// Output file:
+    /// This method is similar to [`Worksheet::insert_drawing_group()`] except
+    /// that the group can be offset from the top left corner of the cell.
     ///
-    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_default_note_author.png">
+    /// # Parameters
     ///
-    pub fn set_default_note_author(&mut self, name: impl Into<String>) -> &mut Worksheet {
-        let name = name.into();
-        if name.chars().count() > 52 {
-            eprintln!("Author string must be less than the Excel limit of 52 characters: {name}");
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `group`: The [`DrawingGroup`] to insert into the cell.
+    /// - `x_offset`: The horizontal offset within the cell in pixels.
+    /// - `y_offset`: The vertical offset within the cell in pixels.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - The group doesn't contain any
+    ///   objects.
+    /// - [`XlsxError::ChartError`] - A chart in the group isn't set up
+    ///   correctly. See [`Worksheet::insert_chart()`].
+    ///
+    pub fn insert_drawing_group_with_offset(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        group: &DrawingGroup,
+        x_offset: u32,
+        y_offset: u32,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
+        // Check row and columns are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        if group.objects.is_empty() {
+            return Err(XlsxError::ParameterError(
+                "DrawingGroup must contain at least one object".to_string(),
+            ));
+        }
+
+        let mut group = group.clone();
+        group.x_offset = x_offset;
+        group.y_offset = y_offset;
+
+        for chart in group.charts_mut() {
+            self.check_inserted_chart(chart)?;
+        }
+
+        // Store workbook objects in row by column position order.
+        self.drawing_groups
+            .insert((row, col, y_offset, x_offset), group);
+
+        Ok(self)
+    }
+
+    /// Make all worksheet notes visible when the file loads.
+    ///
+    /// By default Excel hides cell notes until the user mouses over the parent
+    /// cell. However, if required you can make all worksheet notes visible when
+    /// the worksheet loads. You can also make individual notes visible using
+    /// the [`Note::set_visible()`](crate::Note::set_visible) method.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding notes to a worksheet and
+    /// setting the worksheet property to make them all visible.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_show_all_notes.rs
+    /// #
+    /// # use rust_xlsxwriter::{Note, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a new note.
+    ///     let note = Note::new("Some text for the note");
+    ///
+    ///     // Add the note to some worksheet cells.
+    ///     worksheet.insert_note(1, 0, &note)?;
+    ///     worksheet.insert_note(3, 3, &note)?;
+    ///     worksheet.insert_note(6, 0, &note)?;
+    ///     worksheet.insert_note(8, 3, &note)?;
+    ///
+    ///     // Display all the notes in the worksheet.
+    ///     worksheet.show_all_notes(true);
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_show_all_notes.png">
+    ///
+    pub fn show_all_notes(&mut self, enable: bool) -> &mut Worksheet {
+        self.show_all_notes = enable;
+        self
+    }
+
+    /// Set the default author name for all the notes in the worksheet.
+    ///
+    /// The Note author is the creator of the note. In Excel the author name is
+    /// taken from the user name in the options/preference dialog. The note
+    /// author name appears in two places: at the start of the note text in bold
+    /// and at the bottom of the worksheet in the status bar.
+    ///
+    /// If no name is specified the default name "Author" will be applied to the
+    /// note. The author name for individual notes can be set via the
+    /// [`Note::set_author()`](crate::Note::set_author) method. Alternatively
+    /// this method can be used to set the default author name for all notes in
+    /// a worksheet.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The note author name. Must be less than or equal to the Excel
+    ///   limit of 52 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding notes to a worksheet and setting
+    /// the default author name.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_default_note_author.rs
+    /// #
+    /// # use rust_xlsxwriter::{Note, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a new note.
+    ///     let note = Note::new("Some text for the note");
+    ///
+    ///     // Add the note to some worksheet cells.
+    ///     worksheet.insert_note(2, 0, &note)?;
+    ///
+    ///     // Display all the notes in the worksheet.
+    ///     worksheet.set_default_note_author("Rust");
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_default_note_author.png">
+    ///
+    pub fn set_default_note_author(&mut self, name: impl Into<String>) -> &mut Worksheet {
+        let name = name.into();
+        if name.chars().count() > 52 {
+            eprintln!("Author string must be less than the Excel limit of 52 characters: {name}");
             return self;
         }
 
@@ -6162,6 +6781,8 @@ impl Worksheet {
         x_offset: u32,
         y_offset: u32,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check row and columns are in the allowed range.
         if !self.check_dimensions_only(row, col) {
             return Err(XlsxError::RowColumnLimitError);
@@ -6419,6 +7040,8 @@ impl Worksheet {
         row: RowNum,
         height: impl Into<f64>,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         let height = height.into();
 
         // If the height is 0 then the Excel treats the row as hidden with
@@ -6437,7 +7060,10 @@ impl Worksheet {
 
         // Update an existing row metadata object or create a new one.
         match self.changed_rows.get_mut(&row) {
-            Some(row_options) => row_options.height = height,
+            Some(row_options) => {
+                row_options.height = height;
+                row_options.autofit = false;
+            }
             None => {
                 let row_options = RowOptions {
                     height,
@@ -6445,6 +7071,7 @@ impl Worksheet {
                     hidden: false,
                     level: 0,
                     collapsed: false,
+                    autofit: false,
                     format: None,
                 };
                 self.changed_rows.insert(row, row_options);
@@ -6578,6 +7205,8 @@ impl Worksheet {
         row: RowNum,
         format: &Format,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Get a suitable minimum value for the dimension check/set.
         let min_col = self.get_min_col();
 
@@ -6599,6 +7228,7 @@ impl Worksheet {
                     hidden: false,
                     level: 0,
                     collapsed: false,
+                    autofit: false,
                     format: Some(format.clone()),
                 };
                 self.changed_rows.insert(row, row_options);
@@ -7604,6 +8234,8 @@ impl Worksheet {
     /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_row_hidden.png">
     ///
     pub fn set_row_hidden(&mut self, row: RowNum) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Get a suitable minimum value for the dimension check/set.
         let min_col = self.get_min_col();
 
@@ -7622,6 +8254,7 @@ impl Worksheet {
                     hidden: true,
                     level: 0,
                     collapsed: false,
+                    autofit: false,
                     format: None,
                 };
                 self.changed_rows.insert(row, row_options);
@@ -7647,6 +8280,8 @@ impl Worksheet {
     ///   limits.
     ///
     pub fn set_row_unhidden(&mut self, row: RowNum) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Get a suitable minimum value for the dimension check/set.
         let min_col = self.get_min_col();
 
@@ -8041,6 +8676,8 @@ impl Worksheet {
         col: ColNum,
         format: &Format,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Get a suitable minimum value for the dimension check/set.
         let min_row = self.get_min_row();
 
@@ -8118,6 +8755,8 @@ impl Worksheet {
     /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_column_hidden.png">
     ///
     pub fn set_column_hidden(&mut self, col: ColNum) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check if column is in the allowed range without updating dimensions.
         if col >= COL_MAX {
             return Err(XlsxError::RowColumnLimitError);
@@ -8418,6 +9057,8 @@ impl Worksheet {
         last_row: RowNum,
         last_col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check rows and cols are in the allowed range.
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
@@ -8757,6 +9398,8 @@ impl Worksheet {
         last_col: ColNum,
         table: &Table,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check rows and cols are in the allowed range.
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
@@ -8783,15 +9426,13 @@ impl Worksheet {
         table.cell_range = CellRange::new(first_row, first_col, last_row, last_col);
         table.initialize_columns(&default_headers)?;
 
-        let first_data_row = table.first_data_row();
-        let last_data_row = table.last_data_row();
-
         // Write the worksheet information required for each column.
         for (offset, column) in table.columns.iter_mut().enumerate() {
             let col = first_col + offset as u16;
 
-            // Write the header. We skip this when writing serde headers.
-            if table.show_header_row && !table.is_serde_table {
+            // Write the header. We skip this when writing serde headers or
+            // when the headers have already been written with the data.
+            if table.show_header_row && !table.is_serde_table && !table.has_external_headers {
                 match &column.header_format {
                     Some(header_format) => {
                         self.write_string_with_format(first_row, col, &column.name, header_format)?;
@@ -8802,52 +9443,15 @@ impl Worksheet {
                 }
             }
 
-            // In constant memory mode add any formulas or totals to the
-            // write-ahead buffer.
-            self.set_writing_ahead(true);
-
-            // Get a copy of the column format or use the default format. This
-            // is mainly to work around constant memory cases which can't use the
-            // update_cell_format() approach below.
-            let col_format = match &column.format {
-                Some(format) => format.clone(),
-                None => Format::default(),
-            };
-
-            // Write the total row strings or formulas.
-            if table.show_total_row {
-                if !column.total_label.is_empty() {
-                    self.write_string_with_format(last_row, col, &column.total_label, &col_format)?;
-                } else if column.total_function != TableFunction::None {
-                    let formula = column.total_function();
-                    self.write_formula_with_format(last_row, col, formula, &col_format)?;
-                }
-            }
-
-            // Write the column formula as worksheet formulas.
-            if let Some(formula) = &column.formula {
-                for row in first_data_row..=last_data_row {
-                    self.write_formula_with_format(row, col, formula, &col_format)?;
-                }
-            }
-
             // Set the column format local index if required.
             if let Some(format) = column.format.as_mut() {
                 format.dxf_index = self.format_dxf_index(format);
-                let format_index = self.format_xf_index(format);
-                for row in first_data_row..=last_data_row {
-                    self.update_cell_format(row, col, format_index);
-                }
-
-                if table.show_total_row && column.total_function != TableFunction::None {
-                    self.update_cell_format(last_row, col, format_index);
-                }
             }
-
-            // Stop writing ahead in constant memory mode.
-            self.set_writing_ahead(false);
         }
 
+        // Write the total row and the column formulas and formats.
+        self.write_table_column_data(&table, table.first_data_row(), table.last_data_row())?;
+
         // Create a cell range for storage and range testing.
         let cell_range = CellRange::new(first_row, first_col, last_row, last_col);
 
@@ -8899,72 +9503,311 @@ impl Worksheet {
         Ok(self)
     }
 
-    /// Add a conditional format to highlight cells based on rules.
+    /// Change the number of rows in an existing worksheet table.
     ///
-    /// Conditional formatting is a feature of Excel which allows you to apply a
-    /// format to a cell or a range of cells based on certain criteria. This is
-    /// generally used to highlight particular values in a range of data.
+    /// The range of a worksheet table is fixed when it is added via
+    /// [`Worksheet::add_table()`]. This method can be used to change the last
+    /// row of the table afterwards, for example when data is streamed into the
+    /// table in chunks and the final size isn't known in advance.
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/conditional_format_cell_intro.png">
+    /// If the table has a total row it is moved to the new last row. Any column
+    /// formulas and formats set via [`TableColumn`](crate::TableColumn) are
+    /// extended to the new data rows. If the table is shrunk the column
+    /// formulas and formats are removed from the rows that are no longer part
+    /// of the table but any other data in those rows is left unchanged.
     ///
-    /// The [`ConditionalFormat`](crate::conditional_format) variants are used
-    /// to represent the types of conditional format that can be applied in
-    /// Excel.
+    /// The table must have been given a name via [`Table::set_name()`] so that
+    /// it can be identified.
     ///
-    /// Multiple formatting rules that affect the same range or overlapping
-    /// ranges are applied in the order that they are added to the worksheet.
+    /// Note, the table is resized via the worksheet rather than via a `Table`
+    /// method since the worksheet stores a copy of the table when it is added
+    /// with [`Worksheet::add_table()`]. Changes to the original `Table`
+    /// object after that don't affect the worksheet.
     ///
     /// # Parameters
     ///
-    /// - `first_row`: The first row of the range. (All zero indexed.)
-    /// - `first_col`: The first column of the range.
-    /// - `last_row`: The last row of the range.
-    /// - `last_col`: The last column of the range.
-    /// - `conditional_format`: A conditional format instance that implements
-    ///   the [`ConditionalFormat`] trait.
+    /// - `name`: The name of the table (case-insensitive).
+    /// - `last_row`: The new last row of the table, including the total row if
+    ///   present.
     ///
     /// # Errors
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
-    ///   row.
-    /// - [`XlsxError::ConditionalFormatError`] - A general error that is raised
-    ///   when a conditional formatting parameter is incorrect or missing.
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::TableError`] - The table name wasn't found in the
+    ///   worksheet or the table would only have a header row.
+    /// - [`XlsxError::TableRangeOverlaps`] - The new table range overlaps
+    ///   another table range.
     ///
     /// # Examples
     ///
-    /// Example of adding a cell type conditional formatting to a worksheet.
-    /// Cells with values >= 50 are in light red. Values < 50 are in light
-    /// green.
+    /// Example of resizing a worksheet table after it has been added.
     ///
     /// ```
-    /// # // This code is available in examples/doc_conditional_format_cell1.rs
+    /// # // This code is available in examples/doc_worksheet_resize_table.rs
     /// #
-    /// # use rust_xlsxwriter::{
-    /// #     ConditionalFormatCell, ConditionalFormatCellRule, Format, Workbook, XlsxError,
-    /// # };
+    /// # use rust_xlsxwriter::{Table, TableColumn, TableFunction, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     // Add some sample data.
-    /// #     let data = [
-    /// #         [90, 80, 50, 10, 20, 90, 40, 90, 30, 40],
-    /// #         [20, 10, 90, 100, 30, 60, 70, 60, 50, 90],
-    /// #         [10, 50, 60, 50, 20, 50, 80, 30, 40, 60],
-    /// #         [10, 90, 20, 40, 10, 40, 50, 70, 90, 50],
-    /// #         [70, 100, 10, 90, 10, 10, 20, 100, 100, 40],
-    /// #         [20, 60, 10, 100, 30, 10, 20, 60, 100, 10],
-    /// #         [10, 60, 10, 80, 100, 80, 30, 30, 70, 40],
-    /// #         [30, 90, 60, 10, 10, 100, 40, 40, 30, 40],
-    /// #         [80, 90, 10, 20, 20, 50, 80, 20, 60, 90],
-    /// #         [60, 80, 30, 30, 10, 50, 80, 60, 50, 30],
-    /// #     ];
-    /// #     worksheet.write_row_matrix(2, 1, data)?;
+    ///     // Create a table with a total row for the first chunk of data.
+    ///     let columns = vec![
+    ///         TableColumn::new()
+    ///             .set_header("Product")
+    ///             .set_total_label("Totals"),
+    ///         TableColumn::new()
+    ///             .set_header("Sales")
+    ///             .set_total_function(TableFunction::Sum),
+    ///     ];
+    ///
+    ///     let table = Table::new()
+    ///         .set_name("Sales")
+    ///         .set_columns(&columns)
+    ///         .set_total_row(true);
+    ///
+    ///     worksheet.add_table(0, 0, 3, 1, &table)?;
+    ///     worksheet.write_column(1, 0, ["Apples", "Pears"])?;
+    ///     worksheet.write_column(1, 1, [1000, 2000])?;
+    ///
+    ///     // Extend the table for the next chunk of data. The total row moves
+    ///     // from row 4 to row 6.
+    ///     worksheet.resize_table("Sales", 5)?;
+    ///     worksheet.write_column(3, 0, ["Bananas", "Oranges"])?;
+    ///     worksheet.write_column(3, 1, [3000, 4000])?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("tables.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn resize_table(
+        &mut self,
+        name: &str,
+        last_row: RowNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let index = self.table_index_from_name(name)?;
+        self.resize_table_rows(index, last_row)?;
+
+        Ok(self)
+    }
+
+    /// Append a row of data to an existing worksheet table.
+    ///
+    /// Write a row of data after the last data row of a table and extend the
+    /// table range to include it. If the table has a total row it is moved
+    /// down by one row. This allows data to be streamed into a table without
+    /// knowing its final size when the table is added via
+    /// [`Worksheet::add_table()`].
+    ///
+    /// The data is written to the table columns in order, in the same way as
+    /// [`Worksheet::write_row()`]. Columns that have a
+    /// formula set via
+    /// [`TableColumn::set_formula()`](crate::TableColumn::set_formula) are
+    /// skipped since the formula is added to the new row automatically. If a
+    /// column has a format set via
+    /// [`TableColumn::set_format()`](crate::TableColumn::set_format) it is
+    /// applied to the data.
+    ///
+    /// Since the data values must all be of the same type, rows with mixed
+    /// data types, such as a string label and numbers, should be added by
+    /// extending the table with [`Worksheet::resize_table()`] and writing the
+    /// data with the standard [`Worksheet::write()`] methods.
+    ///
+    /// The table must have been given a name via [`Table::set_name()`] so that
+    /// it can be identified.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the table (case-insensitive).
+    /// - `data`: Arrays or array-like data structures that implement
+    ///   [`IntoIterator`] and that contain a data type that implements
+    ///   [`IntoExcelData`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::TableError`] - The table name wasn't found in the
+    ///   worksheet or there are more data values than table columns.
+    /// - [`XlsxError::TableRangeOverlaps`] - The new table range overlaps
+    ///   another table range.
+    ///
+    /// # Examples
+    ///
+    /// Example of appending rows of data to a worksheet table.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_table_append_row.rs
+    /// #
+    /// # use rust_xlsxwriter::{Formula, Table, TableColumn, TableFunction, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a table with a formula column and a total row.
+    ///     let columns = vec![
+    ///         TableColumn::new()
+    ///             .set_header("Q1")
+    ///             .set_total_function(TableFunction::Sum),
+    ///         TableColumn::new()
+    ///             .set_header("Q2")
+    ///             .set_total_function(TableFunction::Sum),
+    ///         TableColumn::new()
+    ///             .set_header("Half Year")
+    ///             .set_formula(Formula::new("SUM(Sales[@[Q1]:[Q2]])"))
+    ///             .set_total_function(TableFunction::Sum),
+    ///     ];
+    ///
+    ///     let table = Table::new()
+    ///         .set_name("Sales")
+    ///         .set_columns(&columns)
+    ///         .set_total_row(true);
+    ///
+    ///     // Add the table with the first row of data.
+    ///     worksheet.add_table(0, 0, 2, 2, &table)?;
+    ///     worksheet.write_row(1, 0, [10000, 5000])?;
+    ///
+    ///     // Append the remaining rows. The "Half Year" formula is added
+    ///     // automatically.
+    ///     worksheet.table_append_row("Sales", [2000, 3000])?;
+    ///     worksheet.table_append_row("Sales", [6000, 6000])?;
+    ///     worksheet.table_append_row("Sales", [500, 300])?;
+    /// #
+    /// #     // Set the column widths for clarity.
+    /// #     worksheet.set_column_range_width(0, 2, 12)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("tables.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn table_append_row<I>(&mut self, name: &str, data: I) -> Result<&mut Worksheet, XlsxError>
+    where
+        I: IntoIterator,
+        I::Item: IntoExcelData,
+    {
+        let index = self.table_index_from_name(name)?;
+        let table = self.tables[index].clone();
+        let data: Vec<I::Item> = data.into_iter().collect();
+
+        // The data is written to the columns that don't have a formula.
+        let data_columns: Vec<(ColNum, Option<Format>)> = table
+            .columns
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| column.formula.is_none())
+            .map(|(offset, column)| {
+                (
+                    table.cell_range.first_col + offset as ColNum,
+                    column.format.clone(),
+                )
+            })
+            .collect();
+
+        if data.len() > data_columns.len() {
+            return Err(XlsxError::TableError(format!(
+                "Table '{}' has {} data columns but {} values were appended",
+                table.name,
+                data_columns.len(),
+                data.len()
+            )));
+        }
+
+        let row = table.last_data_row() + 1;
+        self.resize_table_rows(index, table.cell_range.last_row + 1)?;
+
+        for (item, (col, format)) in data.into_iter().zip(data_columns) {
+            match format {
+                Some(format) => self.write_with_format(row, col, item, &format)?,
+                None => self.write(row, col, item)?,
+            };
+        }
+
+        Ok(self)
+    }
+
+    /// Add a conditional format to highlight cells based on rules.
+    ///
+    /// Conditional formatting is a feature of Excel which allows you to apply a
+    /// format to a cell or a range of cells based on certain criteria. This is
+    /// generally used to highlight particular values in a range of data.
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/conditional_format_cell_intro.png">
+    ///
+    /// The [`ConditionalFormat`](crate::conditional_format) variants are used
+    /// to represent the types of conditional format that can be applied in
+    /// Excel.
+    ///
+    /// Multiple formatting rules that affect the same range or overlapping
+    /// ranges are applied in the order that they are added to the worksheet.
+    /// This can be overridden for individual rules with the `set_priority()`
+    /// method of the conditional format types, for example
+    /// [`ConditionalFormatCell::set_priority()`](crate::ConditionalFormatCell::set_priority).
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    /// - `conditional_format`: A conditional format instance that implements
+    ///   the [`ConditionalFormat`] trait.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row.
+    /// - [`XlsxError::ConditionalFormatError`] - A general error that is raised
+    ///   when a conditional formatting parameter is incorrect or missing.
+    ///
+    /// # Examples
+    ///
+    /// Example of adding a cell type conditional formatting to a worksheet.
+    /// Cells with values >= 50 are in light red. Values < 50 are in light
+    /// green.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_conditional_format_cell1.rs
+    /// #
+    /// # use rust_xlsxwriter::{
+    /// #     ConditionalFormatCell, ConditionalFormatCellRule, Format, Workbook, XlsxError,
+    /// # };
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some sample data.
+    /// #     let data = [
+    /// #         [90, 80, 50, 10, 20, 90, 40, 90, 30, 40],
+    /// #         [20, 10, 90, 100, 30, 60, 70, 60, 50, 90],
+    /// #         [10, 50, 60, 50, 20, 50, 80, 30, 40, 60],
+    /// #         [10, 90, 20, 40, 10, 40, 50, 70, 90, 50],
+    /// #         [70, 100, 10, 90, 10, 10, 20, 100, 100, 40],
+    /// #         [20, 60, 10, 100, 30, 10, 20, 60, 100, 10],
+    /// #         [10, 60, 10, 80, 100, 80, 30, 30, 70, 40],
+    /// #         [30, 90, 60, 10, 10, 100, 40, 40, 30, 40],
+    /// #         [80, 90, 10, 20, 20, 50, 80, 20, 60, 90],
+    /// #         [60, 80, 30, 30, 10, 50, 80, 60, 50, 30],
+    /// #     ];
+    /// #     worksheet.write_row_matrix(2, 1, data)?;
     /// #
     /// #     // Set the column widths for clarity.
     /// #     worksheet.set_column_range_width(1, 10, 6)?;
@@ -9016,6 +9859,8 @@ impl Worksheet {
     where
         T: ConditionalFormat + Send + Sync,
     {
+        self.check_chartsheet()?;
+
         // Check rows and cols are in the allowed range.
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
@@ -9177,6 +10022,8 @@ impl Worksheet {
         last_col: ColNum,
         data_validation: &DataValidation,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check rows and cols are in the allowed range.
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
@@ -9286,6 +10133,8 @@ impl Worksheet {
         col: ColNum,
         sparkline: &Sparkline,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check row and col are in the allowed range.
         if !self.check_dimensions_only(row, col) {
             return Err(XlsxError::RowColumnLimitError);
@@ -9420,6 +10269,8 @@ impl Worksheet {
         last_col: ColNum,
         sparkline: &Sparkline,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check rows and cols are in the allowed range.
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
@@ -9819,6 +10670,8 @@ impl Worksheet {
         name: &str,
         password: &str,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check rows and cols are in the allowed range.
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
@@ -9907,6 +10760,8 @@ impl Worksheet {
         last_row: RowNum,
         last_col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check rows and cols are in the allowed range.
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
@@ -9992,6 +10847,8 @@ impl Worksheet {
         row: RowNum,
         col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check row and col are in the allowed range.
         if !self.check_dimensions_only(row, col) {
             return Err(XlsxError::RowColumnLimitError);
@@ -10233,6 +11090,8 @@ impl Worksheet {
         col: ColNum,
         format: &Format,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check row and col are in the allowed range.
         if !self.check_dimensions(row, col) {
             return Err(XlsxError::RowColumnLimitError);
@@ -10338,6 +11197,8 @@ impl Worksheet {
         last_col: ColNum,
         format: &Format,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check rows and cols are in the allowed range.
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
@@ -10459,6 +11320,8 @@ impl Worksheet {
         cell_format: &Format,
         border_format: &Format,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check rows and cols are in the allowed range.
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
@@ -12175,737 +13038,1445 @@ impl Worksheet {
     }
 
     // -----------------------------------------------------------------------
-    // Worksheet page setup methods.
+    // Worksheet polars methods.
     // -----------------------------------------------------------------------
 
-    /// Display the worksheet cells from right to left for some versions of
-    /// Excel.
+    /// Write a Polars dataframe to a worksheet.
     ///
-    /// The `set_right_to_left()` method is used to change the default direction
-    /// of the worksheet from left-to-right, with the A1 cell in the top left,
-    /// to right-to-left, with the A1 cell in the top right.
+    /// Write a Polars [`DataFrame`] to a worksheet, with the column names as
+    /// headers and the column data below them. The data is written using
+    /// default number formats for Polars date, datetime, time, duration and
+    /// decimal data types. See [`DataFrameOptions`] for a list of the default
+    /// formats and the Polars to Excel type mappings.
     ///
-    /// This is useful when creating Arabic, Hebrew or other near or far eastern
-    /// worksheets that use right-to-left as the default direction.
+    /// See also
+    /// [`Worksheet::write_dataframe_with_options()`](Worksheet::write_dataframe_with_options)
+    /// to add a table, autofit the columns or to set custom formats.
     ///
-    /// Depending on your use case, and text, you may also need to use the
-    /// [`Format::set_reading_direction()`](crate::Format::set_reading_direction)
-    /// method to set the direction of the text within the cells.
+    /// This method requires the `polars` feature.
+    ///
+    /// [`DataFrame`]: https://docs.rs/polars/latest/polars/frame/struct.DataFrame.html
     ///
     /// # Parameters
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    /// - `dataframe`: A Polars [`DataFrame`] to write to the worksheet.
+    /// - `row`: The zero indexed row number of the header cells.
+    /// - `col`: The zero indexed column number of the first column.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - The dataframe contains a data type
+    ///   that isn't supported by Excel, such as `Binary` or `Struct`, or a
+    ///   datetime with an unknown time zone.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates changing the default worksheet and
-    /// cell text direction changed from left-to-right to right-to-left, as
-    /// required by some middle eastern versions of Excel.
+    /// The following example demonstrates writing a Polars dataframe to a
+    /// worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_right_to_left.rs
-    /// #
-    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
-    /// #     let mut workbook = Workbook::new();
+    /// # // This code is available in examples/doc_worksheet_write_dataframe.rs
     /// #
-    ///     // Add the cell formats.
-    ///     let format_left_to_right = Format::new().set_reading_direction(1);
-    ///     let format_right_to_left = Format::new().set_reading_direction(2);
-    ///
-    ///     // Add a worksheet in the standard left to right direction.
-    ///     let worksheet1 = workbook.add_worksheet();
+    /// use polars::prelude::*;
+    /// use rust_xlsxwriter::{Workbook, XlsxError};
     ///
-    ///     // Make the column wider for clarity.
-    ///     worksheet1.set_column_width(0,25)?;
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
     ///
-    ///     // Standard direction:         | A1 | B1 | C1 | ...
-    ///     worksheet1.write(0, 0, "نص عربي / English text")?;
-    ///     worksheet1.write_with_format(1, 0, "نص عربي / English text", &format_left_to_right)?;
-    ///     worksheet1.write_with_format(2, 0, "نص عربي / English text", &format_right_to_left)?;
+    ///     // Create a sample dataframe.
+    ///     let df: DataFrame = df!(
+    ///         "String" => &["North", "South", "East", "West"],
+    ///         "Integer" => &[1, 2, 3, 4],
+    ///         "Float" => &[4.0, 5.0, 6.0, 7.0],
+    ///         "Boolean" => &[true, false, true, false],
+    ///     )?;
     ///
-    ///     // Add a worksheet and change it to right to left direction.
-    ///     let worksheet2 = workbook.add_worksheet();
-    ///     worksheet2.set_right_to_left(true);
+    ///     // Write the dataframe to the worksheet.
+    ///     worksheet.write_dataframe(&df, 0, 0)?;
     ///
-    ///     // Make the column wider for clarity.
-    ///     worksheet2.set_column_width(0, 25)?;
+    ///     // Save the file to disk.
+    ///     workbook.save("dataframe.xlsx")?;
     ///
-    ///     // Right to left direction:    ... | C1 | B1 | A1 |
-    ///     worksheet2.write(0, 0, "نص عربي / English text")?;
-    ///     worksheet2.write_with_format(1, 0, "نص عربي / English text", &format_left_to_right)?;
-    ///     worksheet2.write_with_format(2, 0, "نص عربي / English text", &format_right_to_left)?;
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
+    ///     Ok(())
+    /// }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_right_to_left.png">
-    ///
-    pub fn set_right_to_left(&mut self, enable: bool) -> &mut Worksheet {
-        self.right_to_left = enable;
-        self
+    #[cfg(feature = "polars")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
+    pub fn write_dataframe(
+        &mut self,
+        dataframe: &DataFrame,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.write_dataframe_with_options(dataframe, row, col, &DataFrameOptions::default())
     }
 
-    /// Make a worksheet the active/initially visible worksheet in a workbook.
+    /// Write a Polars dataframe to a worksheet with options.
     ///
-    /// The `set_active()` method is used to specify which worksheet is
-    /// initially visible in a multi-sheet workbook. If no worksheet is set then
-    /// the first worksheet is made the active worksheet, like in Excel.
+    /// Write a Polars [`DataFrame`] to a worksheet, as with
+    /// [`Worksheet::write_dataframe()`](Worksheet::write_dataframe), but with
+    /// additional options to hide or format the headers, wrap the data in a
+    /// worksheet [`Table`], autofit the columns, handle null values and
+    /// override the default formats. See [`DataFrameOptions`] for details.
+    ///
+    /// This method requires the `polars` feature.
+    ///
+    /// [`DataFrame`]: https://docs.rs/polars/latest/polars/frame/struct.DataFrame.html
     ///
     /// # Parameters
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    /// - `dataframe`: A Polars [`DataFrame`] to write to the worksheet.
+    /// - `row`: The zero indexed row number of the header cells.
+    /// - `col`: The zero indexed column number of the first column.
+    /// - `options`: A [`DataFrameOptions`] object with the write options.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - The dataframe contains a data type
+    ///   that isn't supported by Excel, such as `Binary` or `Struct`, or a
+    ///   datetime with an unknown time zone.
+    /// - [`XlsxError::TableError`] - Table errors, such as overlapping
+    ///   tables. See [`Worksheet::add_table()`].
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting a worksheet as the visible
-    /// worksheet when a file is opened.
+    /// The following example demonstrates writing a Polars dataframe with
+    /// dates and null values to a worksheet table.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_active.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
+    /// # // This code is available in examples/doc_worksheet_write_dataframe_with_options.rs
     /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    ///     let worksheet1 = Worksheet::new();
-    ///     let worksheet3 = Worksheet::new();
-    ///     let mut worksheet2 = Worksheet::new();
+    /// use polars::prelude::*;
+    /// use rust_xlsxwriter::{DataFrameOptions, Format, Table, Workbook, XlsxError};
     ///
-    ///     worksheet2.set_active(true);
-    /// #
-    /// #   workbook.push_worksheet(worksheet1);
-    /// #   workbook.push_worksheet(worksheet2);
-    /// #   workbook.push_worksheet(worksheet3);
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
     ///
-    /// Output file:
+    ///     // Create a sample dataframe with a date column and a null value.
+    ///     let dates = Series::new("Date".into(), &[19_723, 19_724, 19_725]).cast(&DataType::Date)?;
     ///
-    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_active.png">
+    ///     let df: DataFrame = df!(
+    ///         "Item" => &["Apples", "Pears", "Plums"],
+    ///         "Quantity" => &[Some(10), None, Some(30)],
+    ///         "Date" => dates,
+    ///     )?;
     ///
-    pub fn set_active(&mut self, enable: bool) -> &mut Worksheet {
-        self.active = enable;
+    ///     // Set the dataframe options.
+    ///     let options = DataFrameOptions::new()
+    ///         .set_table(Table::new())
+    ///         .set_header_format(Format::new().set_bold())
+    ///         .set_dtype_format(DataType::Date, Format::new().set_num_format("dd/mm/yyyy"))
+    ///         .set_null_value("N/A")
+    ///         .set_autofit(true);
+    ///
+    ///     // Write the dataframe to the worksheet.
+    ///     worksheet.write_dataframe_with_options(&df, 1, 1, &options)?;
+    ///
+    ///     // Save the file to disk.
+    ///     workbook.save("dataframe.xlsx")?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    #[cfg(feature = "polars")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "polars")))]
+    pub fn write_dataframe_with_options(
+        &mut self,
+        dataframe: &DataFrame,
+        row: RowNum,
+        col: ColNum,
+        options: &DataFrameOptions,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        if dataframe.width() == 0 {
+            return Ok(self);
+        }
 
-        // Activated worksheets must also be selected and cannot be hidden.
-        if self.active {
-            self.selected = true;
-            self.visible = Visible::Default;
+        let has_headers = options.core.show_headers();
+
+        // Calculate the dataframe range and check that it fits in the
+        // worksheet. Tables need at least one data row.
+        let (Ok(num_rows), Ok(num_cols)) = (
+            RowNum::try_from(dataframe.height()),
+            ColNum::try_from(dataframe.width()),
+        ) else {
+            return Err(XlsxError::RowColumnLimitError);
+        };
+
+        let first_data_row = row + RowNum::from(has_headers);
+        let mut last_row = if options.core.table.is_some() {
+            first_data_row.saturating_add(num_rows.max(1)) - 1
+        } else {
+            first_data_row.saturating_add(num_rows).saturating_sub(1)
+        };
+        let last_col = col.saturating_add(num_cols) - 1;
+
+        if !self.check_dimensions_only(last_row, last_col) {
+            return Err(XlsxError::RowColumnLimitError);
         }
 
-        self
+        // Get the properties for each column, and check that the data types
+        // are supported, before any data is written.
+        let properties = dataframe
+            .get_columns()
+            .iter()
+            .map(|column| options.column_properties(column.name().as_str(), column.dtype()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let column_names: Vec<&str> = dataframe
+            .get_columns()
+            .iter()
+            .map(|column| column.name().as_str())
+            .collect();
+
+        let headers = if has_headers {
+            self.write_data_headers(
+                row,
+                col,
+                &column_names,
+                options.core.header_format.as_ref(),
+                options.core.table.as_ref(),
+            )?
+        } else {
+            vec![]
+        };
+
+        // Write the data row by row so that it can be streamed to a worksheet
+        // in constant memory mode.
+        let series: Vec<Series> = dataframe
+            .get_columns()
+            .iter()
+            .map(|column| column.as_materialized_series().rechunk())
+            .collect();
+
+        let mut columns: Vec<_> = series.iter().map(Series::iter).collect();
+
+        for row in (first_data_row..).take(dataframe.height()) {
+            for ((col, values), column) in (col..=last_col).zip(&mut columns).zip(&properties) {
+                if let Some(value) = values.next() {
+                    self.write_polars_value(row, col, &value, column, options)?;
+                }
+            }
+        }
+
+        if let Some(table) = &options.core.table {
+            if table.has_total_row() {
+                last_row += 1;
+            }
+
+            self.add_data_table(row, col, last_row, last_col, table, &headers)?;
+        }
+
+        if options.core.autofit {
+            self.autofit();
+        }
+
+        Ok(self)
     }
 
-    /// Set a worksheet tab as selected.
+    // Write a Polars AnyValue to a worksheet cell, mapping it to the closest
+    // Excel type.
+    #[cfg(feature = "polars")]
+    #[allow(clippy::cast_precision_loss)]
+    fn write_polars_value(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        value: &AnyValue,
+        column: &ColumnProperties<Tz>,
+        options: &DataFrameOptions,
+    ) -> Result<(), XlsxError> {
+        // Excel serial date for the Unix epoch, 1970-01-01.
+        const UNIX_EPOCH: f64 = 25569.0;
+
+        // Convert a Polars time unit to the number of units in a day.
+        let units_per_day = |unit: &TimeUnit| match unit {
+            TimeUnit::Nanoseconds => 86_400_000_000_000.0,
+            TimeUnit::Microseconds => 86_400_000_000.0,
+            TimeUnit::Milliseconds => 86_400_000.0,
+        };
+
+        let format = column.format.as_ref();
+
+        match value {
+            AnyValue::Null => {
+                self.write_data_null(row, col, options.core.null_value.as_ref(), format)?;
+            }
+            AnyValue::Boolean(boolean) => {
+                self.store_boolean(row, col, *boolean, format)?;
+            }
+            AnyValue::String(_)
+            | AnyValue::StringOwned(_)
+            | AnyValue::Categorical(..)
+            | AnyValue::CategoricalOwned(..)
+            | AnyValue::Enum(..)
+            | AnyValue::EnumOwned(..) => {
+                self.store_string(row, col, value.str_value().into_owned(), format)?;
+            }
+            AnyValue::UInt8(number) => {
+                self.store_number(row, col, *number, format)?;
+            }
+            AnyValue::UInt16(number) => {
+                self.store_number(row, col, *number, format)?;
+            }
+            AnyValue::UInt32(number) => {
+                self.store_number(row, col, *number, format)?;
+            }
+            AnyValue::UInt64(number) => {
+                self.store_number(row, col, *number as f64, format)?;
+            }
+            AnyValue::Int8(number) => {
+                self.store_number(row, col, *number, format)?;
+            }
+            AnyValue::Int16(number) => {
+                self.store_number(row, col, *number, format)?;
+            }
+            AnyValue::Int32(number) => {
+                self.store_number(row, col, *number, format)?;
+            }
+            AnyValue::Int64(number) => {
+                self.store_number(row, col, *number as f64, format)?;
+            }
+            AnyValue::Int128(number) => {
+                self.store_number(row, col, *number as f64, format)?;
+            }
+            AnyValue::Float32(number) => {
+                self.store_number(row, col, *number, format)?;
+            }
+            AnyValue::Float64(number) => {
+                self.store_number(row, col, *number, format)?;
+            }
+            AnyValue::Date(days) => {
+                let number = UNIX_EPOCH + f64::from(*days);
+                self.store_datetime(row, col, number, format)?;
+            }
+            AnyValue::Datetime(timestamp, unit, _)
+            | AnyValue::DatetimeOwned(timestamp, unit, _) => {
+                // Adjust time zone aware datetimes to the local time. The
+                // offset is applied in the timestamp units to avoid rounding
+                // errors.
+                let units_per_second = (units_per_day(unit) / 86_400.0) as i64;
+                let offset = column.timezone.map_or(0, |timezone| {
+                    DateTime::from_timestamp(timestamp.div_euclid(units_per_second), 0).map_or(
+                        0,
+                        |utc_time| {
+                            timezone
+                                .offset_from_utc_datetime(&utc_time.naive_utc())
+                                .fix()
+                                .local_minus_utc()
+                        },
+                    )
+                });
+                let timestamp = timestamp + i64::from(offset) * units_per_second;
+
+                let number = UNIX_EPOCH + timestamp as f64 / units_per_day(unit);
+                self.store_datetime(row, col, number, format)?;
+            }
+            AnyValue::Duration(duration, unit) => {
+                let number = *duration as f64 / units_per_day(unit);
+                self.store_datetime(row, col, number, format)?;
+            }
+            AnyValue::Time(nanoseconds) => {
+                let number = *nanoseconds as f64 / units_per_day(&TimeUnit::Nanoseconds);
+                self.store_datetime(row, col, number, format)?;
+            }
+            AnyValue::Decimal(number, scale) => {
+                let number = *number as f64 / 10_f64.powi(*scale as i32);
+                self.store_number(row, col, number, format)?;
+            }
+            AnyValue::List(series) => {
+                let series = series.rechunk();
+                let items: Vec<String> = series
+                    .iter()
+                    .filter(|item| !item.is_null())
+                    .map(|item| item.str_value().into_owned())
+                    .collect();
+
+                self.store_string(row, col, items.join(", "), format)?;
+            }
+            _ => {
+                return Err(XlsxError::ParameterError(format!(
+                    "Polars data type '{}' is not supported by Excel",
+                    value.dtype()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Worksheet arrow methods.
+    // -----------------------------------------------------------------------
+
+    /// Write an Apache Arrow record batch to a worksheet.
     ///
-    /// The `set_selected()` method is used to indicate that a worksheet is
-    /// selected in a multi-sheet workbook.
+    /// Write an Arrow [`RecordBatch`] to a worksheet, with the schema field
+    /// names as headers and the column data below them. The data is written
+    /// using default number formats for Arrow date, timestamp, time, duration
+    /// and decimal data types. See [`RecordBatchOptions`] for a list of the
+    /// default formats and the Arrow to Excel type mappings.
     ///
-    /// A selected worksheet has its tab highlighted. Selecting worksheets is a
-    /// way of grouping them together so that, for example, several worksheets
-    /// could be printed in one go. A worksheet that has been activated via the
-    /// [`Worksheet::set_active()`] method will also appear as selected.
+    /// See also
+    /// [`Worksheet::write_record_batch_with_options()`](Worksheet::write_record_batch_with_options)
+    /// to add a table, autofit the columns or to set custom formats, and
+    /// [`Worksheet::write_record_batches()`](Worksheet::write_record_batches)
+    /// to write a sequence of record batches.
+    ///
+    /// This method requires the `arrow` feature.
+    ///
+    /// [`RecordBatch`]:
+    ///     https://docs.rs/arrow-array/latest/arrow_array/struct.RecordBatch.html
     ///
     /// # Parameters
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    /// - `batch`: An Arrow [`RecordBatch`] to write to the worksheet.
+    /// - `row`: The zero indexed row number of the header cells.
+    /// - `col`: The zero indexed column number of the first column.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - The record batch contains a data type
+    ///   that isn't supported by Excel, such as `Binary` or `Struct`, or a
+    ///   timestamp with an unknown time zone.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates selecting worksheet in a workbook.
-    /// The active worksheet is selected by default so in this example the first
-    /// two worksheets are selected.
+    /// The following example demonstrates writing an Arrow record batch to a
+    /// worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_selected.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
+    /// # // This code is available in examples/doc_worksheet_write_record_batch.rs
     /// #
-    ///     let worksheet1 = Worksheet::new();
-    ///     let worksheet3 = Worksheet::new();
-    ///     let mut worksheet2 = Worksheet::new();
+    /// use std::sync::Arc;
     ///
-    ///     worksheet2.set_selected(true);
-    /// #
-    /// #   workbook.push_worksheet(worksheet1);
-    /// #   workbook.push_worksheet(worksheet2);
-    /// #   workbook.push_worksheet(worksheet3);
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
+    /// use arrow_array::{ArrayRef, BooleanArray, Date32Array, Int32Array, RecordBatch, StringArray};
+    /// use rust_xlsxwriter::{Workbook, XlsxError};
     ///
-    /// Output file:
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_selected.png">
+    ///     // Create a sample record batch. The dates are days since 1970-01-01.
+    ///     let strings: ArrayRef = Arc::new(StringArray::from(vec!["North", "South", "East"]));
+    ///     let integers: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+    ///     let booleans: ArrayRef = Arc::new(BooleanArray::from(vec![true, false, true]));
+    ///     let dates: ArrayRef = Arc::new(Date32Array::from(vec![19_723, 19_724, 19_725]));
     ///
-    pub fn set_selected(&mut self, enable: bool) -> &mut Worksheet {
-        self.selected = enable;
-
-        // Selected worksheets cannot be hidden.
-        if self.selected {
-            self.visible = Visible::Default;
-        }
-
-        self
-    }
-
-    /// Hide a worksheet.
+    ///     let batch = RecordBatch::try_from_iter(vec![
+    ///         ("String", strings),
+    ///         ("Integer", integers),
+    ///         ("Boolean", booleans),
+    ///         ("Date", dates),
+    ///     ])?;
     ///
-    /// The `set_hidden()` method is used to hide a worksheet. This can be used
-    /// to hide a worksheet in order to avoid confusing a user with intermediate
-    /// data or calculations.
+    ///     // Write the record batch to the worksheet.
+    ///     worksheet.write_record_batch(&batch, 0, 0)?;
     ///
-    /// In Excel a hidden worksheet can not be activated or selected so this
-    /// method is mutually exclusive with the [`Worksheet::set_active()`] and
-    /// [`Worksheet::set_selected()`] methods. In addition, since the first
-    /// worksheet will default to being the active worksheet, you cannot hide
-    /// the first worksheet without activating another sheet.
+    ///     // Save the file to disk.
+    ///     workbook.save("record_batch.xlsx")?;
     ///
-    /// # Parameters
+    ///     Ok(())
+    /// }
+    /// ```
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    #[cfg(feature = "arrow")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+    pub fn write_record_batch(
+        &mut self,
+        batch: &RecordBatch,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.write_record_batches_with_options([batch], row, col, &RecordBatchOptions::default())
+    }
+
+    /// Write an Apache Arrow record batch to a worksheet with options.
     ///
-    /// # Examples
+    /// Write an Arrow [`RecordBatch`] to a worksheet, as with
+    /// [`Worksheet::write_record_batch()`](Worksheet::write_record_batch), but
+    /// with additional options to hide or format the headers, wrap the data in
+    /// a worksheet [`Table`], autofit the columns, handle null values and
+    /// override the default formats. See [`RecordBatchOptions`] for details.
     ///
-    /// The following example demonstrates hiding a worksheet.
+    /// This method requires the `arrow` feature.
     ///
-    /// ```
-    /// # // This code is available in examples/doc_worksheet_set_hidden.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    ///     let worksheet1 = Worksheet::new();
-    ///     let worksheet3 = Worksheet::new();
-    ///     let mut worksheet2 = Worksheet::new();
+    /// [`RecordBatch`]:
+    ///     https://docs.rs/arrow-array/latest/arrow_array/struct.RecordBatch.html
     ///
-    ///     worksheet2.set_hidden(true);
-    /// #
-    /// #    workbook.push_worksheet(worksheet1);
-    /// #    workbook.push_worksheet(worksheet2);
-    /// #    workbook.push_worksheet(worksheet3);
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
+    /// # Parameters
     ///
-    /// Output file:
+    /// - `batch`: An Arrow [`RecordBatch`] to write to the worksheet.
+    /// - `row`: The zero indexed row number of the header cells.
+    /// - `col`: The zero indexed column number of the first column.
+    /// - `options`: A [`RecordBatchOptions`] object with the write options.
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_hidden.png">
+    /// # Errors
     ///
-    pub fn set_hidden(&mut self, enable: bool) -> &mut Worksheet {
-        if enable {
-            self.visible = Visible::Hidden;
-        } else {
-            self.visible = Visible::Default;
-        }
-
-        // Hidden worksheets cannot be active or hidden.
-        if self.visible == Visible::Hidden {
-            self.selected = false;
-            self.active = false;
-        }
-
-        self
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - The record batch contains a data type
+    ///   that isn't supported by Excel, such as `Binary` or `Struct`, or a
+    ///   timestamp with an unknown time zone.
+    /// - [`XlsxError::TableError`] - Table errors, such as overlapping
+    ///   tables. See [`Worksheet::add_table()`].
+    ///
+    #[cfg(feature = "arrow")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+    pub fn write_record_batch_with_options(
+        &mut self,
+        batch: &RecordBatch,
+        row: RowNum,
+        col: ColNum,
+        options: &RecordBatchOptions,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.write_record_batches_with_options([batch], row, col, options)
     }
 
-    /// Hide a worksheet. Can only be unhidden in Excel by VBA.
+    /// Write a sequence of Apache Arrow record batches to a worksheet.
     ///
-    /// The `set_very_hidden()` method can be used to hide a worksheet similar
-    /// to the [`Worksheet::set_hidden()`] method. The difference is that the
-    /// worksheet cannot be unhidden in the the Excel user interface. The Excel
-    /// worksheet `xlSheetVeryHidden` option can only be unset programmatically
-    /// by VBA.
+    /// Write a sequence of Arrow [`RecordBatch`]es, such as the output of a
+    /// query engine, to a worksheet. The headers are taken from the schema of
+    /// the first record batch and the data from each batch is written directly
+    /// below the data of the previous one. All of the record batches must have
+    /// the same schema data types.
+    ///
+    /// The data is written a row at a time, and a batch at a time, so it can
+    /// be used with a worksheet in constant memory mode, see
+    /// [`Workbook::add_worksheet_with_constant_memory()`](crate::Workbook::add_worksheet_with_constant_memory),
+    /// to write large amounts of data without holding all of the record
+    /// batches, or all of the worksheet cells, in memory. In that case the
+    /// record batches should be written in row order after any other data
+    /// above them.
+    ///
+    /// This method requires the `arrow` feature.
+    ///
+    /// [`RecordBatch`]:
+    ///     https://docs.rs/arrow-array/latest/arrow_array/struct.RecordBatch.html
     ///
     /// # Parameters
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    /// - `batches`: An iterator of Arrow [`RecordBatch`]es, or references to
+    ///   them, to write to the worksheet.
+    /// - `row`: The zero indexed row number of the header cells.
+    /// - `col`: The zero indexed column number of the first column.
     ///
-    pub fn set_very_hidden(&mut self, enable: bool) -> &mut Worksheet {
-        if enable {
-            self.visible = Visible::VeryHidden;
-        } else {
-            self.visible = Visible::Default;
-        }
-
-        // Hidden worksheets cannot be active or hidden.
-        if self.visible == Visible::VeryHidden {
-            self.selected = false;
-            self.active = false;
-        }
-
-        self
-    }
-
-    /// Set current worksheet as the first visible sheet tab.
+    /// # Errors
     ///
-    /// The [`Worksheet::set_active()`]  method determines which worksheet is
-    /// initially selected. However, if there are a large number of worksheets
-    /// the selected worksheet may not appear on the screen. To avoid this you
-    /// can select which is the leftmost visible worksheet tab using
-    /// `set_first_tab()`.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - A record batch contains a data type
+    ///   that isn't supported by Excel, or its schema doesn't match the schema
+    ///   of the first record batch.
     ///
-    /// This method is not required very often. The default is the first
-    /// worksheet.
+    /// Note, record batches written before the error occurred will have
+    /// already been written to the worksheet.
     ///
-    /// # Parameters
+    /// # Examples
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    /// The following example demonstrates writing several Arrow record batches
+    /// to a worksheet.
     ///
-    pub fn set_first_tab(&mut self, enable: bool) -> &mut Worksheet {
-        self.first_sheet = enable;
-
-        // First visible worksheet cannot be hidden.
-        if self.selected {
-            self.visible = Visible::Default;
-        }
-        self
-    }
-
-    /// Set the color of the worksheet tab.
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_record_batches.rs
+    /// #
+    /// use std::sync::Arc;
     ///
-    /// The `set_tab_color()` method can be used to change the color of the
-    /// worksheet tab. This is useful for highlighting the important tab in a
-    /// group of worksheets.
+    /// use arrow_array::{ArrayRef, Float64Array, Int64Array, RecordBatch};
+    /// use rust_xlsxwriter::{Workbook, XlsxError};
     ///
-    /// # Parameters
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
     ///
-    /// - `color`: The tab color property defined by a [`Color`] enum
-    ///   value.
+    ///     // For large amounts of data you can use a worksheet created with
+    ///     // `add_worksheet_with_constant_memory()` instead.
+    ///     let worksheet = workbook.add_worksheet();
     ///
-    /// # Examples
+    ///     // Create some sample record batches.
+    ///     let batches = (0..10).map(|batch_num| {
+    ///         let ids: ArrayRef = Arc::new(Int64Array::from_iter_values(
+    ///             (0..100).map(|id| batch_num * 100 + id),
+    ///         ));
+    ///         let values: ArrayRef = Arc::new(Float64Array::from_iter_values(
+    ///             (0..100).map(|value| f64::from(value) / 10.0),
+    ///         ));
     ///
-    /// The following example demonstrates set the tab color of worksheets.
+    ///         RecordBatch::try_from_iter(vec![("Id", ids), ("Value", values)]).unwrap()
+    ///     });
     ///
-    /// ```
-    /// # // This code is available in examples/doc_worksheet_set_tab_color.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, Worksheet, Color, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    ///     let mut worksheet1 = Worksheet::new();
-    ///     let mut worksheet2 = Worksheet::new();
-    ///     let mut worksheet3 = Worksheet::new();
-    ///     let mut worksheet4 = Worksheet::new();
+    ///     // Write the record batches to the worksheet.
+    ///     worksheet.write_record_batches(batches, 0, 0)?;
     ///
-    ///     worksheet1.set_tab_color(Color::Red);
-    ///     worksheet2.set_tab_color(Color::Green);
-    ///     worksheet3.set_tab_color(Color::RGB(0xFF9900));
+    ///     // Save the file to disk.
+    ///     workbook.save("record_batches.xlsx")?;
     ///
-    ///     // worksheet4 will have the default color.
-    ///     worksheet4.set_active(true);
-    /// #
-    /// #    workbook.push_worksheet(worksheet1);
-    /// #    workbook.push_worksheet(worksheet2);
-    /// #    workbook.push_worksheet(worksheet3);
-    /// #    workbook.push_worksheet(worksheet4);
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
+    ///     Ok(())
+    /// }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_tab_color.png">
-    ///
-    pub fn set_tab_color(&mut self, color: impl Into<Color>) -> &mut Worksheet {
-        let color = color.into();
-        if color.is_valid() {
-            self.tab_color = color;
-        }
-
-        self
+    #[cfg(feature = "arrow")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+    pub fn write_record_batches<I>(
+        &mut self,
+        batches: I,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<RecordBatch>,
+    {
+        self.write_record_batches_with_options(batches, row, col, &RecordBatchOptions::default())
     }
 
-    /// Set the paper type/size when printing.
-    ///
-    /// This method is used to set the paper format for the printed output of a
-    /// worksheet. The following paper styles are available:
+    /// Write a sequence of Apache Arrow record batches to a worksheet with
+    /// options.
     ///
-    /// | Index    | Paper format            | Paper size           |
-    /// | :------- | :---------------------- | :------------------- |
-    /// | 0        | Printer default         | Printer default      |
-    /// | 1        | Letter                  | 8 1/2 x 11 in        |
-    /// | 2        | Letter Small            | 8 1/2 x 11 in        |
-    /// | 3        | Tabloid                 | 11 x 17 in           |
-    /// | 4        | Ledger                  | 17 x 11 in           |
-    /// | 5        | Legal                   | 8 1/2 x 14 in        |
-    /// | 6        | Statement               | 5 1/2 x 8 1/2 in     |
-    /// | 7        | Executive               | 7 1/4 x 10 1/2 in    |
-    /// | 8        | A3                      | 297 x 420 mm         |
-    /// | 9        | A4                      | 210 x 297 mm         |
-    /// | 10       | A4 Small                | 210 x 297 mm         |
-    /// | 11       | A5                      | 148 x 210 mm         |
-    /// | 12       | B4                      | 250 x 354 mm         |
-    /// | 13       | B5                      | 182 x 257 mm         |
-    /// | 14       | Folio                   | 8 1/2 x 13 in        |
-    /// | 15       | Quarto                  | 215 x 275 mm         |
-    /// | 16       | ---                     | 10x14 in             |
-    /// | 17       | ---                     | 11x17 in             |
-    /// | 18       | Note                    | 8 1/2 x 11 in        |
-    /// | 19       | Envelope 9              | 3 7/8 x 8 7/8        |
-    /// | 20       | Envelope 10             | 4 1/8 x 9 1/2        |
-    /// | 21       | Envelope 11             | 4 1/2 x 10 3/8       |
-    /// | 22       | Envelope 12             | 4 3/4 x 11           |
-    /// | 23       | Envelope 14             | 5 x 11 1/2           |
-    /// | 24       | C size sheet            | ---                  |
-    /// | 25       | D size sheet            | ---                  |
-    /// | 26       | E size sheet            | ---                  |
-    /// | 27       | Envelope DL             | 110 x 220 mm         |
-    /// | 28       | Envelope C3             | 324 x 458 mm         |
-    /// | 29       | Envelope C4             | 229 x 324 mm         |
-    /// | 30       | Envelope C5             | 162 x 229 mm         |
-    /// | 31       | Envelope C6             | 114 x 162 mm         |
-    /// | 32       | Envelope C65            | 114 x 229 mm         |
-    /// | 33       | Envelope B4             | 250 x 353 mm         |
-    /// | 34       | Envelope B5             | 176 x 250 mm         |
-    /// | 35       | Envelope B6             | 176 x 125 mm         |
-    /// | 36       | Envelope                | 110 x 230 mm         |
-    /// | 37       | Monarch                 | 3.875 x 7.5 in       |
-    /// | 38       | Envelope                | 3 5/8 x 6 1/2 in     |
-    /// | 39       | Fanfold                 | 14 7/8 x 11 in       |
-    /// | 40       | German Std Fanfold      | 8 1/2 x 12 in        |
-    /// | 41       | German Legal Fanfold    | 8 1/2 x 13 in        |
+    /// Write a sequence of Arrow [`RecordBatch`]es to a worksheet, as with
+    /// [`Worksheet::write_record_batches()`](Worksheet::write_record_batches),
+    /// but with additional options to hide or format the headers, wrap the
+    /// data in a worksheet [`Table`], autofit the columns, handle null values
+    /// and override the default formats. See [`RecordBatchOptions`] for
+    /// details.
     ///
-    /// Note, it is likely that not all of these paper types will be available
-    /// to the end user since it will depend on the paper formats that the
-    /// user's printer supports. Therefore, it is best to stick to standard
-    /// paper types of 1 for US Letter and 9 for A4.
+    /// This method requires the `arrow` feature.
     ///
-    /// If you do not specify a paper type the worksheet will print using the
-    /// printer's default paper style.
+    /// [`RecordBatch`]:
+    ///     https://docs.rs/arrow-array/latest/arrow_array/struct.RecordBatch.html
     ///
     /// # Parameters
     ///
-    /// - `paper_size`: The paper size index from the list above .
+    /// - `batches`: An iterator of Arrow [`RecordBatch`]es, or references to
+    ///   them, to write to the worksheet.
+    /// - `row`: The zero indexed row number of the header cells.
+    /// - `col`: The zero indexed column number of the first column.
+    /// - `options`: A [`RecordBatchOptions`] object with the write options.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - A record batch contains a data type
+    ///   that isn't supported by Excel, or its schema doesn't match the schema
+    ///   of the first record batch.
+    /// - [`XlsxError::TableError`] - Table errors, such as overlapping
+    ///   tables. See [`Worksheet::add_table()`].
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the worksheet paper size/type for
-    /// the printed output.
+    /// The following example demonstrates writing Arrow record batches with
+    /// timestamps and null values to a worksheet table.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_paper.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
+    /// # // This code is available in examples/doc_worksheet_write_record_batches_with_options.rs
     /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #
-    ///     // Set the printer paper size.
-    ///     worksheet.set_paper_size(9); // A4 paper size.
-    ///
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
-    pub fn set_paper_size(&mut self, paper_size: u8) -> &mut Worksheet {
-        self.paper_size = paper_size;
-        self.page_setup_changed = true;
-        self
-    }
-
-    /// Set the order in which pages are printed.
-    ///
-    /// The `set_page_order()` method is used to change the default print
-    /// direction. This is referred to by Excel as the sheet "page order":
-    ///
-    /// The default page order is shown below for a worksheet that extends over
-    /// 4 pages. The order is called "down then over":
+    /// use std::sync::Arc;
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_page_order.png">
+    /// use arrow_array::{ArrayRef, Int32Array, RecordBatch, StringArray, TimestampSecondArray};
+    /// use rust_xlsxwriter::{Format, RecordBatchOptions, Table, Workbook, XlsxError};
     ///
-    /// However, by using `set_page_order(false)` the print order will be
-    /// changed to "over then down".
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
     ///
-    /// # Parameters
+    ///     // Create some sample record batches. The timestamps are in seconds
+    ///     // since 1970-01-01 UTC and are converted to local New York time.
+    ///     let mut batches = vec![];
+    ///     for (items, quantities, timestamps) in [
+    ///         (["Apples", "Pears"], [Some(10), None], [1_704_103_200, 1_704_189_600]),
+    ///         (["Plums", "Kiwis"], [Some(30), Some(40)], [1_704_276_000, 1_704_362_400]),
+    ///     ] {
+    ///         let items: ArrayRef = Arc::new(StringArray::from(items.to_vec()));
+    ///         let quantities: ArrayRef = Arc::new(Int32Array::from(quantities.to_vec()));
+    ///         let timestamps: ArrayRef = Arc::new(
+    ///             TimestampSecondArray::from(timestamps.to_vec()).with_timezone("America/New_York"),
+    ///         );
+    ///
+    ///         batches.push(RecordBatch::try_from_iter(vec![
+    ///             ("Item", items),
+    ///             ("Quantity", quantities),
+    ///             ("Delivery", timestamps),
+    ///         ])?);
+    ///     }
     ///
-    /// - `enable`: Turn the property on/off. Set `true` to get "Down, then
-    ///   over" (the default) and `false` to get "Over, then down".
+    ///     // Set the record batch options.
+    ///     let options = RecordBatchOptions::new()
+    ///         .set_table(Table::new())
+    ///         .set_header_format(Format::new().set_bold())
+    ///         .set_column_format("Delivery", Format::new().set_num_format("dd/mm/yyyy hh:mm"))
+    ///         .set_null_value("N/A")
+    ///         .set_autofit(true);
     ///
-    /// # Examples
+    ///     // Write the record batches to the worksheet.
+    ///     worksheet.write_record_batches_with_options(&batches, 1, 1, &options)?;
     ///
-    /// The following example demonstrates setting the worksheet printed page
-    /// order.
+    ///     // Save the file to disk.
+    ///     workbook.save("record_batches.xlsx")?;
     ///
+    ///     Ok(())
+    /// }
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_page_order.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #
-    ///     // Set the page print to "over then down"
-    ///     worksheet.set_page_order(false);
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
-    pub fn set_page_order(&mut self, enable: bool) -> &mut Worksheet {
-        self.default_page_order = enable;
+    ///
+    #[cfg(feature = "arrow")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "arrow")))]
+    pub fn write_record_batches_with_options<I>(
+        &mut self,
+        batches: I,
+        row: RowNum,
+        col: ColNum,
+        options: &RecordBatchOptions,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<RecordBatch>,
+    {
+        let has_headers = options.core.show_headers();
 
-        if !enable {
-            self.page_setup_changed = true;
-        }
-        self
-    }
+        let first_data_row = row + RowNum::from(has_headers);
+        let mut next_row = first_data_row;
+        let mut last_col = col;
+        let mut headers = vec![];
+        let mut data_types: Option<Vec<ArrowDataType>> = None;
+        let mut properties = vec![];
 
-    /// Set the page orientation to landscape.
-    ///
-    /// The `set_landscape()` method is used to set the orientation of a
-    /// worksheet's printed page to landscape.
-    ///
-    /// # Examples
-    ///
-    /// The following example demonstrates setting the worksheet page orientation to
-    /// landscape.
-    ///
-    /// ```
-    /// # // This code is available in examples/doc_worksheet_set_landscape.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #
-    ///     worksheet.set_landscape();
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
-    ///
-    pub fn set_landscape(&mut self) -> &mut Worksheet {
-        self.is_portrait = false;
-        self.page_setup_changed = true;
-        self
-    }
+        for batch in batches {
+            let batch = batch.borrow();
+            let schema = batch.schema_ref();
 
-    /// Set the page orientation to portrait.
-    ///
-    ///  This `set_portrait()` method  is used to set the orientation of a
-    ///  worksheet's printed page to portrait. The default worksheet orientation
-    ///  is portrait, so this function is rarely required.
-    ///
-    pub fn set_portrait(&mut self) -> &mut Worksheet {
-        self.is_portrait = true;
-        self.page_setup_changed = true;
-        self
-    }
+            match &data_types {
+                // Use the first record batch to write the headers and get the
+                // properties for each column.
+                None => {
+                    if batch.num_columns() == 0 {
+                        return Ok(self);
+                    }
 
-    /// Set the page view mode to normal layout.
-    ///
-    /// This method is used to display the worksheet in “View -> Normal”
-    /// mode. This is the default.
-    ///
-    pub fn set_view_normal(&mut self) -> &mut Worksheet {
-        self.page_view = PageView::Normal;
-        self
+                    let Ok(num_cols) = ColNum::try_from(batch.num_columns()) else {
+                        return Err(XlsxError::RowColumnLimitError);
+                    };
+
+                    last_col = col.saturating_add(num_cols) - 1;
+
+                    if !self.check_dimensions_only(row, last_col) {
+                        return Err(XlsxError::RowColumnLimitError);
+                    }
+
+                    properties = schema
+                        .fields()
+                        .iter()
+                        .map(|field| options.column_properties(field))
+                        .collect::<Result<Vec<_>, _>>()?;
+
+                    if has_headers {
+                        let column_names: Vec<&str> = schema
+                            .fields()
+                            .iter()
+                            .map(|field| field.name().as_str())
+                            .collect();
+
+                        headers = self.write_data_headers(
+                            row,
+                            col,
+                            &column_names,
+                            options.core.header_format.as_ref(),
+                            options.core.table.as_ref(),
+                        )?;
+                    }
+
+                    data_types = Some(
+                        schema
+                            .fields()
+                            .iter()
+                            .map(|field| field.data_type().clone())
+                            .collect(),
+                    );
+                }
+
+                // Check that subsequent record batches have the same schema.
+                Some(data_types) => {
+                    if !schema
+                        .fields()
+                        .iter()
+                        .map(|field| field.data_type())
+                        .eq(data_types.iter())
+                    {
+                        return Err(XlsxError::ParameterError(
+                            "Record batch schema doesn't match the first record batch schema"
+                                .to_string(),
+                        ));
+                    }
+                }
+            }
+
+            if batch.num_rows() == 0 {
+                continue;
+            }
+
+            let Ok(num_rows) = RowNum::try_from(batch.num_rows()) else {
+                return Err(XlsxError::RowColumnLimitError);
+            };
+
+            let last_row = next_row.saturating_add(num_rows) - 1;
+            if !self.check_dimensions_only(last_row, last_col) {
+                return Err(XlsxError::RowColumnLimitError);
+            }
+
+            // Write the data row by row so that it can be streamed to a
+            // worksheet in constant memory mode.
+            for (row, index) in (next_row..=last_row).zip(0..batch.num_rows()) {
+                for ((col, array), column) in (col..=last_col).zip(batch.columns()).zip(&properties)
+                {
+                    self.write_arrow_value(row, col, array.as_ref(), index, column, options)?;
+                }
+            }
+
+            next_row = last_row + 1;
+        }
+
+        if data_types.is_none() {
+            return Ok(self);
+        }
+
+        if let Some(table) = &options.core.table {
+            // Tables need at least one data row.
+            let mut last_row = next_row.max(first_data_row + 1) - 1;
+
+            if table.has_total_row() {
+                last_row += 1;
+            }
+
+            self.add_data_table(row, col, last_row, last_col, table, &headers)?;
+        }
+
+        if options.core.autofit {
+            self.autofit();
+        }
+
+        Ok(self)
     }
 
-    /// Set the page view mode to page layout.
-    ///
-    /// This method is used to display the worksheet in “View -> Page Layout”
-    /// mode.
-    ///
-    pub fn set_view_page_layout(&mut self) -> &mut Worksheet {
-        self.page_view = PageView::PageLayout;
-        self.page_setup_changed = true;
-        self
+    // Write an Arrow array value to a worksheet cell, mapping it to the
+    // closest Excel type.
+    #[cfg(feature = "arrow")]
+    #[allow(clippy::cast_precision_loss)]
+    fn write_arrow_value(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        array: &dyn Array,
+        index: usize,
+        column: &ColumnProperties<ArrowTz>,
+        options: &RecordBatchOptions,
+    ) -> Result<(), XlsxError> {
+        // Excel serial date for the Unix epoch, 1970-01-01.
+        const UNIX_EPOCH: f64 = 25569.0;
+
+        // Convert an Arrow time unit to the number of units in a day.
+        let units_per_day = |unit: &ArrowTimeUnit| match unit {
+            ArrowTimeUnit::Second => 86_400.0,
+            ArrowTimeUnit::Millisecond => 86_400_000.0,
+            ArrowTimeUnit::Microsecond => 86_400_000_000.0,
+            ArrowTimeUnit::Nanosecond => 86_400_000_000_000.0,
+        };
+
+        let format = column.format.as_ref();
+
+        if array.is_null(index) || *array.data_type() == ArrowDataType::Null {
+            self.write_data_null(row, col, options.core.null_value.as_ref(), format)?;
+
+            return Ok(());
+        }
+
+        match array.data_type() {
+            ArrowDataType::Boolean => {
+                let boolean = array.as_boolean().value(index);
+                self.store_boolean(row, col, boolean, format)?;
+            }
+            ArrowDataType::Utf8 => {
+                let string = array.as_string::<i32>().value(index);
+                self.store_string(row, col, string.to_string(), format)?;
+            }
+            ArrowDataType::LargeUtf8 => {
+                let string = array.as_string::<i64>().value(index);
+                self.store_string(row, col, string.to_string(), format)?;
+            }
+            ArrowDataType::Utf8View => {
+                let string = array.as_string_view().value(index);
+                self.store_string(row, col, string.to_string(), format)?;
+            }
+            ArrowDataType::Int8 => {
+                let number = array.as_primitive::<Int8Type>().value(index);
+                self.store_number(row, col, number, format)?;
+            }
+            ArrowDataType::Int16 => {
+                let number = array.as_primitive::<Int16Type>().value(index);
+                self.store_number(row, col, number, format)?;
+            }
+            ArrowDataType::Int32 => {
+                let number = array.as_primitive::<Int32Type>().value(index);
+                self.store_number(row, col, number, format)?;
+            }
+            ArrowDataType::Int64 => {
+                let number = array.as_primitive::<Int64Type>().value(index);
+                self.store_number(row, col, number as f64, format)?;
+            }
+            ArrowDataType::UInt8 => {
+                let number = array.as_primitive::<UInt8Type>().value(index);
+                self.store_number(row, col, number, format)?;
+            }
+            ArrowDataType::UInt16 => {
+                let number = array.as_primitive::<UInt16Type>().value(index);
+                self.store_number(row, col, number, format)?;
+            }
+            ArrowDataType::UInt32 => {
+                let number = array.as_primitive::<UInt32Type>().value(index);
+                self.store_number(row, col, number, format)?;
+            }
+            ArrowDataType::UInt64 => {
+                let number = array.as_primitive::<UInt64Type>().value(index);
+                self.store_number(row, col, number as f64, format)?;
+            }
+            ArrowDataType::Float16 => {
+                let number = array.as_primitive::<Float16Type>().value(index);
+                self.store_number(row, col, number.to_f64(), format)?;
+            }
+            ArrowDataType::Float32 => {
+                let number = array.as_primitive::<Float32Type>().value(index);
+                self.store_number(row, col, number, format)?;
+            }
+            ArrowDataType::Float64 => {
+                let number = array.as_primitive::<Float64Type>().value(index);
+                self.store_number(row, col, number, format)?;
+            }
+            ArrowDataType::Date32 => {
+                let days = array.as_primitive::<Date32Type>().value(index);
+                let number = UNIX_EPOCH + f64::from(days);
+                self.store_datetime(row, col, number, format)?;
+            }
+            ArrowDataType::Date64 => {
+                let milliseconds = array.as_primitive::<Date64Type>().value(index);
+                let number =
+                    UNIX_EPOCH + milliseconds as f64 / units_per_day(&ArrowTimeUnit::Millisecond);
+                self.store_datetime(row, col, number, format)?;
+            }
+            ArrowDataType::Timestamp(unit, _) => {
+                let (timestamp, local_time) = match unit {
+                    ArrowTimeUnit::Second => {
+                        let timestamp = array.as_primitive::<TimestampSecondType>().value(index);
+                        let local_time = column.timezone.and_then(|timezone| {
+                            as_datetime_with_timezone::<TimestampSecondType>(timestamp, timezone)
+                        });
+                        (timestamp, local_time)
+                    }
+                    ArrowTimeUnit::Millisecond => {
+                        let timestamp = array
+                            .as_primitive::<TimestampMillisecondType>()
+                            .value(index);
+                        let local_time = column.timezone.and_then(|timezone| {
+                            as_datetime_with_timezone::<TimestampMillisecondType>(
+                                timestamp, timezone,
+                            )
+                        });
+                        (timestamp, local_time)
+                    }
+                    ArrowTimeUnit::Microsecond => {
+                        let timestamp = array
+                            .as_primitive::<TimestampMicrosecondType>()
+                            .value(index);
+                        let local_time = column.timezone.and_then(|timezone| {
+                            as_datetime_with_timezone::<TimestampMicrosecondType>(
+                                timestamp, timezone,
+                            )
+                        });
+                        (timestamp, local_time)
+                    }
+                    ArrowTimeUnit::Nanosecond => {
+                        let timestamp =
+                            array.as_primitive::<TimestampNanosecondType>().value(index);
+                        let local_time = column.timezone.and_then(|timezone| {
+                            as_datetime_with_timezone::<TimestampNanosecondType>(
+                                timestamp, timezone,
+                            )
+                        });
+                        (timestamp, local_time)
+                    }
+                };
+
+                // Adjust time zone aware timestamps to the local time. The
+                // offset is applied in the timestamp units to avoid rounding
+                // errors.
+                let offset = local_time.map_or(0, |local_time| {
+                    (local_time.naive_local() - local_time.naive_utc()).num_seconds()
+                });
+                let units_per_second = (units_per_day(unit) / 86_400.0) as i64;
+                let timestamp = timestamp + offset * units_per_second;
+
+                let number = UNIX_EPOCH + timestamp as f64 / units_per_day(unit);
+                self.store_datetime(row, col, number, format)?;
+            }
+            ArrowDataType::Time32(unit) => {
+                let time = match unit {
+                    ArrowTimeUnit::Second => array.as_primitive::<Time32SecondType>().value(index),
+                    _ => array.as_primitive::<Time32MillisecondType>().value(index),
+                };
+                let number = f64::from(time) / units_per_day(unit);
+                self.store_datetime(row, col, number, format)?;
+            }
+            ArrowDataType::Time64(unit) => {
+                let time = match unit {
+                    ArrowTimeUnit::Nanosecond => {
+                        array.as_primitive::<Time64NanosecondType>().value(index)
+                    }
+                    _ => array.as_primitive::<Time64MicrosecondType>().value(index),
+                };
+                let number = time as f64 / units_per_day(unit);
+                self.store_datetime(row, col, number, format)?;
+            }
+            ArrowDataType::Duration(unit) => {
+                let duration = match unit {
+                    ArrowTimeUnit::Second => {
+                        array.as_primitive::<DurationSecondType>().value(index)
+                    }
+                    ArrowTimeUnit::Millisecond => {
+                        array.as_primitive::<DurationMillisecondType>().value(index)
+                    }
+                    ArrowTimeUnit::Microsecond => {
+                        array.as_primitive::<DurationMicrosecondType>().value(index)
+                    }
+                    ArrowTimeUnit::Nanosecond => {
+                        array.as_primitive::<DurationNanosecondType>().value(index)
+                    }
+                };
+                let number = duration as f64 / units_per_day(unit);
+                self.store_datetime(row, col, number, format)?;
+            }
+            ArrowDataType::Decimal128(_, scale) => {
+                let number = array.as_primitive::<Decimal128Type>().value(index);
+                let number = number as f64 / 10_f64.powi(i32::from(*scale));
+                self.store_number(row, col, number, format)?;
+            }
+            ArrowDataType::Dictionary(_, _) => {
+                downcast_dictionary_array!(
+                    array => {
+                        if let Some(key) = array.key(index) {
+                            let values = array.values().as_ref();
+                            self.write_arrow_value(row, col, values, key, column, options)?;
+                        }
+                    }
+                    _ => {}
+                );
+            }
+            _ => {
+                return Err(XlsxError::ParameterError(format!(
+                    "Arrow data type '{}' is not supported by Excel",
+                    array.data_type()
+                )));
+            }
+        }
+
+        Ok(())
     }
 
-    /// Set the page view mode to page break preview.
+    // -----------------------------------------------------------------------
+    // Worksheet import methods.
+    // -----------------------------------------------------------------------
+
+    /// Import CSV data into a worksheet.
     ///
-    /// This method is used to display the worksheet in “View -> Page Break
-    /// Preview” mode.
+    /// Read CSV, TSV or other delimited data from a reader, such as a
+    /// [`File`](std::fs::File) or a byte slice, and write it to the worksheet.
+    /// The type of each field is inferred from its content so that numbers,
+    /// booleans, dates and times are written as the corresponding Excel types
+    /// with default formats. See [`CsvImportOptions`] for the type inference
+    /// rules.
     ///
-    pub fn set_view_page_break_preview(&mut self) -> &mut Worksheet {
-        self.page_view = PageView::PageBreaks;
-        self.page_setup_changed = true;
-        self
-    }
-
-    /// Set the horizontal page breaks on a worksheet.
+    /// The [`CsvImportOptions`] can be used to set the delimiter and quote
+    /// characters, the cell where the data starts, whether the first record is
+    /// a header row, wrap the data in a worksheet [`Table`], autofit the
+    /// columns or set the formats of individual columns.
     ///
-    /// The `set_page_breaks()` method adds horizontal page breaks to a
-    /// worksheet. A page break causes all the data that follows it to be
-    /// printed on the next page. Horizontal page breaks act between rows.
+    /// The data is read and written a record at a time so it can be used with
+    /// a worksheet in constant memory mode to import large files.
     ///
     /// # Parameters
     ///
-    /// - `breaks`: A list of one or more row numbers where the page breaks
-    ///   occur. To create a page break between rows 20 and 21 you must specify
-    ///   the break at row 21. However in zero index notation this is actually
-    ///   row 20. So you can pretend for a small while that you are using 1
-    ///   index notation.
+    /// - `reader`: A reader that implements [`Read`](std::io::Read). The data
+    ///   must be UTF-8 encoded.
+    /// - `options`: A [`CsvImportOptions`] object with the import options.
     ///
     /// # Errors
     ///
     /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
     ///   worksheet limits.
-    /// - [`XlsxError::ParameterError`] - The number of page breaks exceeds
-    ///   Excel's limit of 1023 page breaks.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - The CSV data contains an unterminated
+    ///   quoted field, or a record with more fields than the header row when
+    ///   the data is written as a table.
+    /// - [`XlsxError::IoError`] - The data couldn't be read or isn't valid
+    ///   UTF-8.
+    /// - [`XlsxError::TableError`] - Table errors, such as duplicate header
+    ///   names. See [`Worksheet::add_table()`].
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting page breaks for a worksheet.
+    /// The following example demonstrates importing a CSV file into a
+    /// worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_page_breaks.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #     worksheet.write_string(100, 100, "Test")?;
-    /// #
-    ///     // Set a page break at rows 20, 40 and 60.
-    ///     worksheet.set_page_breaks(&[20, 40, 60])?;
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
+    /// # // This code is available in examples/doc_worksheet_import_csv.rs
     /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
-    ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_page_breaks.png">
-    ///
-    pub fn set_page_breaks(&mut self, breaks: &[RowNum]) -> Result<&mut Worksheet, XlsxError> {
-        // Ignore empty input.
-        if breaks.is_empty() {
-            return Ok(self);
-        }
-
-        // Sort list and remove any duplicates and 0.
-        let breaks = Self::process_pagebreaks(breaks)?;
-
-        // Check max break value is within Excel column limit.
-        if *breaks.last().unwrap() >= ROW_MAX {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        self.horizontal_breaks = breaks;
-
-        Ok(self)
-    }
-
-    /// Set the vertical page breaks on a worksheet.
+    /// use std::fs::File;
     ///
-    /// The `set_vertical_page_breaks()` method adds vertical page breaks to a
-    /// worksheet. This is much less common than the
-    /// [`Worksheet::set_page_breaks()`] method shown above.
+    /// use rust_xlsxwriter::{CsvImportOptions, Workbook, XlsxError};
     ///
-    /// # Parameters
+    /// fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
     ///
-    /// - `breaks`: A list of one or more column numbers where the page breaks
-    ///   occur.
+    ///     // Import a CSV file into the worksheet.
+    ///     let file = File::open("examples/sales.csv")?;
+    ///     worksheet.import_csv(file, &CsvImportOptions::new())?;
     ///
-    /// # Errors
+    ///     // Save the file to disk.
+    ///     workbook.save("csv_import.xlsx")?;
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::ParameterError`] - The number of page breaks exceeds
-    ///   Excel's limit of 1023 page breaks.
+    ///     Ok(())
+    /// }
+    /// ```
     ///
-    pub fn set_vertical_page_breaks(
+    pub fn import_csv(
         &mut self,
-        breaks: &[u32],
+        reader: impl Read,
+        options: &CsvImportOptions,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Ignore empty input.
-        if breaks.is_empty() {
-            return Ok(self);
+        let mut reader = CsvReader::new(
+            BufReader::new(reader),
+            options.delimiter,
+            options.quote_char,
+        );
+
+        let row = options.first_row;
+        let col = options.first_col;
+
+        // Read the header record, if present.
+        let mut column_names = vec![];
+        if options.has_headers {
+            match reader.read_record()? {
+                Some(record) => {
+                    column_names = record.into_iter().map(|field| field.text).collect();
+                }
+                None => return Ok(self),
+            }
         }
 
-        // Sort list and remove any duplicates and 0.
-        let breaks = Self::process_pagebreaks(breaks)?;
+        let write_headers =
+            options.has_headers && options.table.as_ref().map_or(true, Table::has_header_row);
 
-        // Check max break value is within Excel col limit.
-        if *breaks.last().unwrap() >= u32::from(COL_MAX) {
-            return Err(XlsxError::RowColumnLimitError);
+        let mut headers = vec![];
+        let mut last_col = col;
+
+        if write_headers {
+            let Ok(num_cols) = ColNum::try_from(column_names.len()) else {
+                return Err(XlsxError::RowColumnLimitError);
+            };
+
+            last_col = col.saturating_add(num_cols) - 1;
+            if !self.check_dimensions_only(row, last_col) {
+                return Err(XlsxError::RowColumnLimitError);
+            }
+
+            let column_names: Vec<&str> = column_names.iter().map(String::as_str).collect();
+
+            headers = self.write_data_headers(
+                row,
+                col,
+                &column_names,
+                options.header_format.as_ref(),
+                options.table.as_ref(),
+            )?;
         }
 
-        self.vertical_breaks = breaks;
+        let first_data_row = row + RowNum::from(write_headers);
+        let mut next_row = first_data_row;
+        let mut column_formats: Vec<Option<&Format>> = vec![];
+
+        // Write the data record by record so that it can be streamed to a
+        // worksheet in constant memory mode.
+        while let Some(record) = reader.read_record()? {
+            let Ok(num_cols) = ColNum::try_from(record.len()) else {
+                return Err(XlsxError::RowColumnLimitError);
+            };
+
+            let record_last_col = col.saturating_add(num_cols) - 1;
+            if !self.check_dimensions_only(next_row, record_last_col) {
+                return Err(XlsxError::RowColumnLimitError);
+            }
+
+            // A table range is set by the header row so the data can't be
+            // wider than it.
+            if write_headers && options.table.is_some() && record_last_col > last_col {
+                return Err(XlsxError::ParameterError(format!(
+                    "CSV record for worksheet row {} has {} fields which is more than \
+                     the {} columns in the table header row",
+                    next_row + 1,
+                    record.len(),
+                    column_names.len()
+                )));
+            }
+
+            // Get the user defined formats for any new columns.
+            while column_formats.len() < record.len() {
+                let index = column_formats.len();
+                let column_name = match column_names.get(index) {
+                    Some(column_name) => column_name.clone(),
+                    None => format!("Column{}", index + 1),
+                };
+
+                column_formats.push(options.column_formats.get(&column_name));
+            }
+
+            for ((col, field), column_format) in (col..).zip(record).zip(&column_formats) {
+                // Quoted fields are written as strings without type inference.
+                let value = if options.type_inference && !field.is_quoted {
+                    CsvValue::infer(&field.text)
+                } else if field.text.is_empty() {
+                    CsvValue::Empty
+                } else {
+                    CsvValue::String
+                };
+
+                let format = options.field_format(*column_format, &value);
+
+                match value {
+                    CsvValue::Empty => {
+                        if let Some(format) = format {
+                            self.write_blank(next_row, col, format)?;
+                        }
+                    }
+                    CsvValue::Number(number) => {
+                        self.store_number(next_row, col, number, format)?;
+                    }
+                    CsvValue::Boolean(boolean) => {
+                        self.store_boolean(next_row, col, boolean, format)?;
+                    }
+                    CsvValue::Date(number)
+                    | CsvValue::DateTime(number)
+                    | CsvValue::Time(number) => {
+                        self.store_datetime(next_row, col, number, format)?;
+                    }
+                    CsvValue::String => {
+                        self.store_string(next_row, col, field.text, format)?;
+                    }
+                }
+            }
+
+            if !write_headers {
+                last_col = last_col.max(record_last_col);
+            }
+
+            next_row += 1;
+        }
+
+        if let Some(table) = &options.table {
+            // Tables need at least one data row.
+            let mut last_row = next_row.max(first_data_row + 1) - 1;
+
+            if table.has_total_row() {
+                last_row += 1;
+            }
+
+            if write_headers || next_row > first_data_row {
+                self.add_data_table(row, col, last_row, last_col, table, &headers)?;
+            }
+        }
+
+        if options.autofit {
+            self.autofit();
+        }
 
         Ok(self)
     }
 
-    /// Set the worksheet zoom factor.
+    // Write a null dataframe or record batch value as the user defined null
+    // string, as a formatted blank cell or ignore it.
+    #[cfg(any(feature = "polars", feature = "arrow"))]
+    fn write_data_null(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        null_value: Option<&String>,
+        format: Option<&Format>,
+    ) -> Result<(), XlsxError> {
+        match (null_value, format) {
+            (Some(null_value), _) => {
+                self.store_string(row, col, null_value.clone(), format)?;
+            }
+            (None, Some(format)) => {
+                self.write_blank(row, col, format)?;
+            }
+            (None, None) => {}
+        }
+
+        Ok(())
+    }
+
+    // Write the column headers for a dataframe, record batch or CSV data. Any
+    // user defined table column names and header formats take precedence.
+    // Returns the header names that were written.
+    fn write_data_headers(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        column_names: &[&str],
+        header_format: Option<&Format>,
+        table: Option<&Table>,
+    ) -> Result<Vec<String>, XlsxError> {
+        let mut headers = vec![];
+
+        for (index, column_name) in column_names.iter().enumerate() {
+            let col = col + index as ColNum;
+            let table_column = table.and_then(|table| table.columns.get(index));
+
+            let header = match table_column {
+                Some(column) if !column.name.is_empty() => column.name.clone(),
+                _ => (*column_name).to_string(),
+            };
+
+            let format = table_column
+                .and_then(|column| column.header_format.as_ref())
+                .or(header_format);
+
+            match format {
+                Some(format) => self.write_string_with_format(row, col, &header, format)?,
+                None => self.write_string(row, col, &header)?,
+            };
+
+            headers.push(header);
+        }
+
+        Ok(headers)
+    }
+
+    // Add a table around dataframe, record batch or CSV data. The headers have
+    // already been written to the worksheet so they are stored in the table
+    // columns rather than being rewritten, or read back, which isn't possible
+    // in constant memory mode. An empty list of headers turns off the table
+    // header row.
+    fn add_data_table(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        table: &Table,
+        headers: &[String],
+    ) -> Result<(), XlsxError> {
+        let mut table = table.clone();
+
+        if headers.is_empty() {
+            table.show_header_row = false;
+        } else {
+            table
+                .columns
+                .resize_with(headers.len(), TableColumn::default);
+
+            for (column, header) in table.columns.iter_mut().zip(headers) {
+                column.name.clone_from(header);
+            }
+
+            table.has_external_headers = true;
+        }
+
+        self.add_table(first_row, first_col, last_row, last_col, &table)?;
+
+        Ok(())
+    }
+
+    // -----------------------------------------------------------------------
+    // Worksheet page setup methods.
+    // -----------------------------------------------------------------------
+
+    /// Display the worksheet cells from right to left for some versions of
+    /// Excel.
     ///
-    /// Set the worksheet zoom factor in the range 10 <= zoom <= 400.
+    /// The `set_right_to_left()` method is used to change the default direction
+    /// of the worksheet from left-to-right, with the A1 cell in the top left,
+    /// to right-to-left, with the A1 cell in the top right.
     ///
-    /// The default zoom level is 100. The `set_zoom()` method does not affect
-    /// the scale of the printed page in Excel. For that you should use
-    /// [`Worksheet::set_print_scale()`].
+    /// This is useful when creating Arabic, Hebrew or other near or far eastern
+    /// worksheets that use right-to-left as the default direction.
+    ///
+    /// Depending on your use case, and text, you may also need to use the
+    /// [`Format::set_reading_direction()`](crate::Format::set_reading_direction)
+    /// method to set the direction of the text within the cells.
     ///
     /// # Parameters
     ///
-    /// - `zoom`: The worksheet zoom level.
+    /// - `enable`: Turn the property on/off. It is off by default.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the worksheet zoom level.
+    /// The following example demonstrates changing the default worksheet and
+    /// cell text direction changed from left-to-right to right-to-left, as
+    /// required by some middle eastern versions of Excel.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_zoom.rs
+    /// # // This code is available in examples/doc_worksheet_set_right_to_left.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #
-    ///     worksheet.write_string(0, 0, "Hello")?;
-    ///     worksheet.set_zoom(200);
+    ///     // Add the cell formats.
+    ///     let format_left_to_right = Format::new().set_reading_direction(1);
+    ///     let format_right_to_left = Format::new().set_reading_direction(2);
+    ///
+    ///     // Add a worksheet in the standard left to right direction.
+    ///     let worksheet1 = workbook.add_worksheet();
+    ///
+    ///     // Make the column wider for clarity.
+    ///     worksheet1.set_column_width(0,25)?;
+    ///
+    ///     // Standard direction:         | A1 | B1 | C1 | ...
+    ///     worksheet1.write(0, 0, "نص عربي / English text")?;
+    ///     worksheet1.write_with_format(1, 0, "نص عربي / English text", &format_left_to_right)?;
+    ///     worksheet1.write_with_format(2, 0, "نص عربي / English text", &format_right_to_left)?;
+    ///
+    ///     // Add a worksheet and change it to right to left direction.
+    ///     let worksheet2 = workbook.add_worksheet();
+    ///     worksheet2.set_right_to_left(true);
+    ///
+    ///     // Make the column wider for clarity.
+    ///     worksheet2.set_column_width(0, 25)?;
+    ///
+    ///     // Right to left direction:    ... | C1 | B1 | A1 |
+    ///     worksheet2.write(0, 0, "نص عربي / English text")?;
+    ///     worksheet2.write_with_format(1, 0, "نص عربي / English text", &format_left_to_right)?;
+    ///     worksheet2.write_with_format(2, 0, "نص عربي / English text", &format_right_to_left)?;
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -12915,75 +14486,45 @@ impl Worksheet {
     ///
     /// Output file:
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_zoom.png">
+    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_right_to_left.png">
     ///
-    pub fn set_zoom(&mut self, zoom: u16) -> &mut Worksheet {
-        if !(10..=400).contains(&zoom) {
-            eprintln!("Zoom factor {zoom} outside Excel range: 10 <= zoom <= 400.");
-            return self;
-        }
-
-        self.zoom = zoom;
+    pub fn set_right_to_left(&mut self, enable: bool) -> &mut Worksheet {
+        self.right_to_left = enable;
         self
     }
 
-    /// Freeze panes in a worksheet.
-    ///
-    /// The `set_freeze_panes()` method can be used to divide a worksheet into
-    /// horizontal or vertical regions known as panes and to “freeze” these
-    /// panes so that the splitter bars are not visible.
-    ///
-    /// As with Excel the split is to the top and left of the cell. So to freeze
-    /// the top row and leftmost column you would use `(1, 1)` (zero-indexed).
-    /// Also, you can set one of the row and col parameters as 0 if you do not
-    /// want either the vertical or horizontal split. See the example below.
+    /// Make a worksheet the active/initially visible worksheet in a workbook.
     ///
-    /// In Excel it is also possible to set "split" panes without freezing them.
-    /// That feature isn't currently supported by `rust_xlsxwriter`.
+    /// The `set_active()` method is used to specify which worksheet is
+    /// initially visible in a multi-sheet workbook. If no worksheet is set then
+    /// the first worksheet is made the active worksheet, like in Excel.
     ///
     /// # Parameters
     ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
-    ///
-    /// # Errors
-    ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
+    /// - `enable`: Turn the property on/off. It is off by default.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the worksheet panes.
+    /// The following example demonstrates setting a worksheet as the visible
+    /// worksheet when a file is opened.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_freeze_panes.rs
+    /// # // This code is available in examples/doc_worksheet_set_active.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     let mut worksheet1 = Worksheet::new();
-    /// #     let mut worksheet2 = Worksheet::new();
-    /// #     let mut worksheet3 = Worksheet::new();
-    /// #
-    /// #     worksheet1.write_string(0, 0, "Scroll down")?;
-    /// #     worksheet2.write_string(0, 0, "Scroll across")?;
-    /// #     worksheet3.write_string(0, 0, "Scroll down or across")?;
-    /// #
-    ///     // Freeze the top row only.
-    ///     worksheet1.set_freeze_panes(1, 0)?;
-    ///
-    ///     // Freeze the leftmost column only.
-    ///     worksheet2.set_freeze_panes(0, 1)?;
+    ///     let worksheet1 = Worksheet::new();
+    ///     let worksheet3 = Worksheet::new();
+    ///     let mut worksheet2 = Worksheet::new();
     ///
-    ///     // Freeze the top row and leftmost column.
-    ///     worksheet3.set_freeze_panes(1, 1)?;
+    ///     worksheet2.set_active(true);
     /// #
-    /// #     workbook.push_worksheet(worksheet1);
-    /// #     workbook.push_worksheet(worksheet2);
-    /// #     workbook.push_worksheet(worksheet3);
+    /// #   workbook.push_worksheet(worksheet1);
+    /// #   workbook.push_worksheet(worksheet2);
+    /// #   workbook.push_worksheet(worksheet3);
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -12993,62 +14534,57 @@ impl Worksheet {
     ///
     /// Output file:
     ///
-    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_freeze_panes.png">
+    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_active.png">
     ///
-    pub fn set_freeze_panes(
-        &mut self,
-        row: RowNum,
-        col: ColNum,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Check row and col are in the allowed range.
-        if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+    pub fn set_active(&mut self, enable: bool) -> &mut Worksheet {
+        self.active = enable;
+
+        // Activated worksheets must also be selected and cannot be hidden.
+        if self.active {
+            self.selected = true;
+            self.visible = Visible::Default;
         }
 
-        self.panes.freeze_cell = (row, col);
-        Ok(self)
+        self
     }
 
-    /// Set the top most cell in the scrolling area of a freeze pane.
+    /// Set a worksheet tab as selected.
     ///
-    /// This method is used in conjunction with the
-    /// [`Worksheet::set_freeze_panes()`] method to set the top most visible
-    /// cell in the scrolling range. For example you may want to freeze the top
-    /// row but have the worksheet pre-scrolled so that cell `A20` is visible in
-    /// the scrolled area. See the example below.
+    /// The `set_selected()` method is used to indicate that a worksheet is
+    /// selected in a multi-sheet workbook.
     ///
-    /// # Parameters
-    ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
+    /// A selected worksheet has its tab highlighted. Selecting worksheets is a
+    /// way of grouping them together so that, for example, several worksheets
+    /// could be printed in one go. A worksheet that has been activated via the
+    /// [`Worksheet::set_active()`] method will also appear as selected.
     ///
-    /// # Errors
+    /// # Parameters
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
+    /// - `enable`: Turn the property on/off. It is off by default.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the worksheet panes and also
-    /// setting the topmost visible cell in the scrolled area.
+    /// The following example demonstrates selecting worksheet in a workbook.
+    /// The active worksheet is selected by default so in this example the first
+    /// two worksheets are selected.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_freeze_panes_top_cell.rs
+    /// # // This code is available in examples/doc_worksheet_set_selected.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #
-    /// #     worksheet.write_string(0, 0, "Scroll down")?;
-    /// #
-    ///     // Freeze the top row only.
-    ///     worksheet.set_freeze_panes(1, 0)?;
+    ///     let worksheet1 = Worksheet::new();
+    ///     let worksheet3 = Worksheet::new();
+    ///     let mut worksheet2 = Worksheet::new();
     ///
-    ///     // Pre-scroll to the row 20.
-    ///     worksheet.set_freeze_panes_top_cell(19, 0)?;
+    ///     worksheet2.set_selected(true);
+    /// #
+    /// #   workbook.push_worksheet(worksheet1);
+    /// #   workbook.push_worksheet(worksheet2);
+    /// #   workbook.push_worksheet(worksheet3);
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -13059,200 +14595,56 @@ impl Worksheet {
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_freeze_panes_top_cell.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_selected.png">
     ///
-    pub fn set_freeze_panes_top_cell(
-        &mut self,
-        row: RowNum,
-        col: ColNum,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Check row and col are in the allowed range.
-        if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+    pub fn set_selected(&mut self, enable: bool) -> &mut Worksheet {
+        self.selected = enable;
+
+        // Selected worksheets cannot be hidden.
+        if self.selected {
+            self.visible = Visible::Default;
         }
 
-        self.panes.top_cell = (row, col);
-        Ok(self)
+        self
     }
 
-    /// Set the printed page header caption.
-    ///
-    /// The `set_header()` method can be used to set the header for a worksheet.
-    ///
-    /// Headers and footers are generated using a string which is a combination
-    /// of plain text and optional control characters.
-    ///
-    /// The available control characters are:
-    ///
-    /// | Control              | Category      | Description           |
-    /// | -------------------- | ------------- | --------------------- |
-    /// | `&L`                 | Alignment     | Left                  |
-    /// | `&C`                 |               | Center                |
-    /// | `&R`                 |               | Right                 |
-    /// | `&[Page]`  or `&P`   | Information   | Page number           |
-    /// | `&[Pages]` or `&N`   |               | Total number of pages |
-    /// | `&[Date]`  or `&D`   |               | Date                  |
-    /// | `&[Time]`  or `&T`   |               | Time                  |
-    /// | `&[File]`  or `&F`   |               | File name             |
-    /// | `&[Tab]`   or `&A`   |               | Worksheet name        |
-    /// | `&[Path]`  or `&Z`   |               | Workbook path         |
-    /// | `&fontsize`          | Font          | Font size             |
-    /// | `&"font,style"`      |               | Font name and style   |
-    /// | `&U`                 |               | Single underline      |
-    /// | `&E`                 |               | Double underline      |
-    /// | `&S`                 |               | Strikethrough         |
-    /// | `&X`                 |               | Superscript           |
-    /// | `&Y`                 |               | Subscript             |
-    /// | `&[Picture]` or `&G` | Images        | Picture/image         |
-    /// | `&&`                 | Miscellaneous | Literal ampersand &   |
-    ///
-    /// Some of the placeholder variables have a long version like `&[Page]` and
-    /// a short version like `&P`. The longer version is displayed in the Excel
-    /// interface but the shorter version is the way that it is stored in the
-    /// file format. Either version is okay since `rust_xlsxwriter` will
-    /// translate as required.
-    ///
-    /// Headers and footers have 3 edit areas to the left, center and right.
-    /// Text can be aligned to these areas by prefixing the text with the
-    /// control characters `&L`, `&C` and `&R`.
-    ///
-    /// For example:
-    ///
-    /// ```text
-    /// worksheet.set_header("&LHello");
-    ///
-    ///  ---------------------------------------------------------------
-    /// |                                                               |
-    /// | Hello                                                         |
-    /// |                                                               |
-    ///
-    /// worksheet.set_header("&CHello");
-    ///
-    ///  ---------------------------------------------------------------
-    /// |                                                               |
-    /// |                          Hello                                |
-    /// |                                                               |
-    ///
-    /// worksheet.set_header("&RHello");
-    ///
-    ///  ---------------------------------------------------------------
-    /// |                                                               |
-    /// |                                                         Hello |
-    /// |                                                               |
-    /// ```
-    ///
-    /// You can also have text in each of the alignment areas:
-    ///
-    /// ```text
-    /// worksheet.set_header("&LCiao&CBello&RCielo");
-    ///
-    ///  ---------------------------------------------------------------
-    /// |                                                               |
-    /// | Ciao                     Bello                          Cielo |
-    /// |                                                               |
-    /// ```
-    ///
-    /// The information control characters act as variables/templates that Excel
-    /// will update/expand as the workbook or worksheet changes.
-    ///
-    /// ```text
-    /// worksheet.set_header("&CPage &[Page] of &[Pages]");
-    ///
-    ///  ---------------------------------------------------------------
-    /// |                                                               |
-    /// |                        Page 1 of 6                            |
-    /// |                                                               |
-    /// ```
-    ///
-    /// Times and dates are in the user's default format:
-    ///
-    /// ```text
-    /// worksheet.set_header("&CUpdated at &[Time]");
-    ///
-    ///  ---------------------------------------------------------------
-    /// |                                                               |
-    /// |                    Updated at 12:30 PM                        |
-    /// |                                                               |
-    /// ```
-    ///
-    /// To include a single literal ampersand `&` in a header or footer you
-    /// should use a double ampersand `&&`:
-    ///
-    /// ```text
-    /// worksheet.set_header("&CCuriouser && Curiouser - Attorneys at Law");
-    ///
-    ///  ---------------------------------------------------------------
-    /// |                                                               |
-    /// |                   Curiouser & Curiouser                       |
-    /// |                                                               |
-    /// ```
-    ///
-    /// You can specify the font size of a section of the text by prefixing it
-    /// with the control character `&n` where `n` is the font size:
-    ///
-    /// ```text
-    /// worksheet1.set_header("&C&30Hello Big");
-    /// worksheet2.set_header("&C&10Hello Small");
-    /// ```
-    ///
-    /// You can specify the font of a section of the text by prefixing it with
-    /// the control sequence `&"font,style"` where `fontname` is a font name
-    /// such as Windows font descriptions: "Regular", "Italic", "Bold" or "Bold
-    /// Italic": "Courier New" or "Times New Roman" and `style` is one of the
-    /// standard Windows font descriptions like “Regular”, “Italic”, “Bold” or
-    /// “Bold Italic”:
-    ///
-    /// ```text
-    /// worksheet1.set_header(r#"&C&"Courier New,Italic"Hello"#);
-    /// worksheet2.set_header(r#"&C&"Courier New,Bold Italic"Hello"#);
-    /// worksheet3.set_header(r#"&C&"Times New Roman,Regular"Hello"#);
-    /// ```
-    ///
-    /// It is possible to combine all of these features together to create
-    /// complex headers and footers. If you set up a complex header in Excel you
-    /// can transfer it to `rust_xlsxwriter` by inspecting the string in the
-    /// Excel file. For example the following shows how unzip and grep the Excel
-    /// XML sub-files on a Linux system. The example uses libxml's xmllint to
-    /// format the XML for clarity:
-    ///
-    /// ```text
-    /// $ unzip myfile.xlsm -d myfile
-    /// $ xmllint --format `find myfile -name "*.xml" | xargs` | \
-    ///     egrep "Header|Footer" | sed 's/&amp;/\&/g'
+    /// Hide a worksheet.
     ///
-    ///  <headerFooter scaleWithDoc="0">
-    ///    <oddHeader>&L&P</oddHeader>
-    ///  </headerFooter>
-    /// ```
+    /// The `set_hidden()` method is used to hide a worksheet. This can be used
+    /// to hide a worksheet in order to avoid confusing a user with intermediate
+    /// data or calculations.
     ///
-    /// Note: Excel requires that the header or footer string be less than 256
-    /// characters, including the control characters. Strings longer than this
-    /// will not be written, and a warning will be output.
+    /// In Excel a hidden worksheet can not be activated or selected so this
+    /// method is mutually exclusive with the [`Worksheet::set_active()`] and
+    /// [`Worksheet::set_selected()`] methods. In addition, since the first
+    /// worksheet will default to being the active worksheet, you cannot hide
+    /// the first worksheet without activating another sheet.
     ///
     /// # Parameters
     ///
-    /// - `header`: The header string with optional control characters.
+    /// - `enable`: Turn the property on/off. It is off by default.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the worksheet header.
+    /// The following example demonstrates hiding a worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_header.rs
+    /// # // This code is available in examples/doc_worksheet_set_hidden.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #
-    ///     worksheet.set_header("&CPage &P of &N");
+    ///     let worksheet1 = Worksheet::new();
+    ///     let worksheet3 = Worksheet::new();
+    ///     let mut worksheet2 = Worksheet::new();
+    ///
+    ///     worksheet2.set_hidden(true);
     /// #
-    /// #     worksheet.write_string(0, 0, "Hello")?;
-    /// #     worksheet.write_string(200, 0, "Hello")?;
-    /// #     worksheet.set_view_page_layout();
+    /// #    workbook.push_worksheet(worksheet1);
+    /// #    workbook.push_worksheet(worksheet2);
+    /// #    workbook.push_worksheet(worksheet3);
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -13263,114 +14655,117 @@ impl Worksheet {
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_header.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_hidden.png">
     ///
-    pub fn set_header(&mut self, header: impl Into<String>) -> &mut Worksheet {
-        let header = header.into();
-        let header_expanded = header
-            .replace("&[Tab]", "&A")
-            .replace("&[Date]", "&D")
-            .replace("&[File]", "&F")
-            .replace("&[Page]", "&P")
-            .replace("&[Path]", "&Z")
-            .replace("&[Time]", "&T")
-            .replace("&[Pages]", "&N")
-            .replace("&[Picture]", "&G");
-
-        if header_expanded.chars().count() > 255 {
-            eprintln!("Header string exceeds Excel's limit of 255 characters.");
-            return self;
+    pub fn set_hidden(&mut self, enable: bool) -> &mut Worksheet {
+        if enable {
+            self.visible = Visible::Hidden;
+        } else {
+            self.visible = Visible::Default;
+        }
+
+        // Hidden worksheets cannot be active or hidden.
+        if self.visible == Visible::Hidden {
+            self.selected = false;
+            self.active = false;
         }
 
-        self.header = header;
-        self.page_setup_changed = true;
-        self.head_footer_changed = true;
         self
     }
 
-    /// Set the printed page footer caption.
-    ///
-    /// The `set_footer()` method can be used to set the footer for a worksheet.
+    /// Hide a worksheet. Can only be unhidden in Excel by VBA.
     ///
-    /// See the documentation for [`Worksheet::set_header()`] for more details
-    /// on the syntax of the header/footer string.
+    /// The `set_very_hidden()` method can be used to hide a worksheet similar
+    /// to the [`Worksheet::set_hidden()`] method. The difference is that the
+    /// worksheet cannot be unhidden in the the Excel user interface. The Excel
+    /// worksheet `xlSheetVeryHidden` option can only be unset programmatically
+    /// by VBA.
     ///
     /// # Parameters
     ///
-    /// - `footer`: The footer string with optional control characters.
+    /// - `enable`: Turn the property on/off. It is off by default.
     ///
-    pub fn set_footer(&mut self, footer: impl Into<String>) -> &mut Worksheet {
-        let footer = footer.into();
-        let footer_expanded = footer
-            .replace("&[Tab]", "&A")
-            .replace("&[Date]", "&D")
-            .replace("&[File]", "&F")
-            .replace("&[Page]", "&P")
-            .replace("&[Path]", "&Z")
-            .replace("&[Time]", "&T")
-            .replace("&[Pages]", "&N")
-            .replace("&[Picture]", "&G");
+    pub fn set_very_hidden(&mut self, enable: bool) -> &mut Worksheet {
+        if enable {
+            self.visible = Visible::VeryHidden;
+        } else {
+            self.visible = Visible::Default;
+        }
 
-        if footer_expanded.chars().count() > 255 {
-            eprintln!("Footer string exceeds Excel's limit of 255 characters.");
-            return self;
+        // Hidden worksheets cannot be active or hidden.
+        if self.visible == Visible::VeryHidden {
+            self.selected = false;
+            self.active = false;
         }
 
-        self.footer = footer;
-        self.page_setup_changed = true;
-        self.head_footer_changed = true;
         self
     }
 
-    /// Insert an image in a worksheet header.
+    /// Set current worksheet as the first visible sheet tab.
     ///
-    /// Insert an image in a worksheet header in one of the 3 sections supported
-    /// by Excel: Left, Center and Right. This needs to be preceded by a call to
-    /// [`Worksheet::set_header()`] where a corresponding `&[Picture]` element
-    /// is added to the header formatting string such as `"&L&[Picture]"`.
+    /// The [`Worksheet::set_active()`]  method determines which worksheet is
+    /// initially selected. However, if there are a large number of worksheets
+    /// the selected worksheet may not appear on the screen. To avoid this you
+    /// can select which is the leftmost visible worksheet tab using
+    /// `set_first_tab()`.
+    ///
+    /// This method is not required very often. The default is the first
+    /// worksheet.
     ///
     /// # Parameters
     ///
-    /// - `position`: The image position as defined by the
-    ///   [`HeaderImagePosition`] enum.
+    /// - `enable`: Turn the property on/off. It is off by default.
     ///
-    /// # Errors
+    pub fn set_first_tab(&mut self, enable: bool) -> &mut Worksheet {
+        self.first_sheet = enable;
+
+        // First visible worksheet cannot be hidden.
+        if self.selected {
+            self.visible = Visible::Default;
+        }
+        self
+    }
+
+    /// Set the color of the worksheet tab.
     ///
-    /// - [`XlsxError::ParameterError`] - Parameter error if there isn't a
-    ///   corresponding `&[Picture]`/`&[G]` variable in the header string.
+    /// The `set_tab_color()` method can be used to change the color of the
+    /// worksheet tab. This is useful for highlighting the important tab in a
+    /// group of worksheets.
+    ///
+    /// # Parameters
+    ///
+    /// - `color`: The tab color property defined by a [`Color`] enum
+    ///   value.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates adding a header image to a worksheet.
+    /// The following example demonstrates set the tab color of worksheets.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_header_image.rs
+    /// # // This code is available in examples/doc_worksheet_set_tab_color.rs
     /// #
-    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError, HeaderImagePosition};
+    /// # use rust_xlsxwriter::{Workbook, Worksheet, Color, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #
-    /// #     // Scale the image so it fits in the header.
-    ///       let image = Image::new("examples/rust_logo.png")?
-    ///           .set_scale_height(0.5)
-    ///           .set_scale_width(0.5);
-    /// #
-    ///     // Insert the watermark image in the header.
-    ///     worksheet.set_header("&C&[Picture]");
-    ///     worksheet.set_header_image(&image, HeaderImagePosition::Center)?;
-    /// #
-    /// #     // Increase the top margin to 1.2 for clarity. The -1.0 values are ignored.
-    /// #     worksheet.set_margins(-1.0, -1.0, 1.2, -1.0, -1.0, -1.0);
+    ///     let mut worksheet1 = Worksheet::new();
+    ///     let mut worksheet2 = Worksheet::new();
+    ///     let mut worksheet3 = Worksheet::new();
+    ///     let mut worksheet4 = Worksheet::new();
+    ///
+    ///     worksheet1.set_tab_color(Color::Red);
+    ///     worksheet2.set_tab_color(Color::Green);
+    ///     worksheet3.set_tab_color(Color::RGB(0xFF9900));
+    ///
+    ///     // worksheet4 will have the default color.
+    ///     worksheet4.set_active(true);
     /// #
-    /// #     // Set Page View mode so the watermark is visible.
-    /// #     worksheet.set_view_page_layout();
+    /// #    workbook.push_worksheet(worksheet1);
+    /// #    workbook.push_worksheet(worksheet2);
+    /// #    workbook.push_worksheet(worksheet3);
+    /// #    workbook.push_worksheet(worksheet4);
     /// #
-    /// #     // Save the file to disk.
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
@@ -13379,176 +14774,173 @@ impl Worksheet {
     ///
     /// Output file:
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_header_image.png">
+    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_tab_color.png">
     ///
-    /// An example of adding a worksheet watermark image using the
-    /// `rust_xlsxwriter` library. This is based on the method of putting an
-    /// image in the worksheet header as suggested in the [Microsoft
-    /// documentation].
+    pub fn set_tab_color(&mut self, color: impl Into<Color>) -> &mut Worksheet {
+        let color = color.into();
+        if color.is_valid() {
+            self.tab_color = color;
+        }
+
+        self
+    }
+
+    /// Set the paper type/size when printing.
     ///
-    /// [Microsoft documentation]:
-    ///     https://support.microsoft.com/en-us/office/add-a-watermark-in-excel-a372182a-d733-484e-825c-18ddf3edf009
+    /// This method is used to set the paper format for the printed output of a
+    /// worksheet. The following paper styles are available:
+    ///
+    /// | Index    | Paper format            | Paper size           |
+    /// | :------- | :---------------------- | :------------------- |
+    /// | 0        | Printer default         | Printer default      |
+    /// | 1        | Letter                  | 8 1/2 x 11 in        |
+    /// | 2        | Letter Small            | 8 1/2 x 11 in        |
+    /// | 3        | Tabloid                 | 11 x 17 in           |
+    /// | 4        | Ledger                  | 17 x 11 in           |
+    /// | 5        | Legal                   | 8 1/2 x 14 in        |
+    /// | 6        | Statement               | 5 1/2 x 8 1/2 in     |
+    /// | 7        | Executive               | 7 1/4 x 10 1/2 in    |
+    /// | 8        | A3                      | 297 x 420 mm         |
+    /// | 9        | A4                      | 210 x 297 mm         |
+    /// | 10       | A4 Small                | 210 x 297 mm         |
+    /// | 11       | A5                      | 148 x 210 mm         |
+    /// | 12       | B4                      | 250 x 354 mm         |
+    /// | 13       | B5                      | 182 x 257 mm         |
+    /// | 14       | Folio                   | 8 1/2 x 13 in        |
+    /// | 15       | Quarto                  | 215 x 275 mm         |
+    /// | 16       | ---                     | 10x14 in             |
+    /// | 17       | ---                     | 11x17 in             |
+    /// | 18       | Note                    | 8 1/2 x 11 in        |
+    /// | 19       | Envelope 9              | 3 7/8 x 8 7/8        |
+    /// | 20       | Envelope 10             | 4 1/8 x 9 1/2        |
+    /// | 21       | Envelope 11             | 4 1/2 x 10 3/8       |
+    /// | 22       | Envelope 12             | 4 3/4 x 11           |
+    /// | 23       | Envelope 14             | 5 x 11 1/2           |
+    /// | 24       | C size sheet            | ---                  |
+    /// | 25       | D size sheet            | ---                  |
+    /// | 26       | E size sheet            | ---                  |
+    /// | 27       | Envelope DL             | 110 x 220 mm         |
+    /// | 28       | Envelope C3             | 324 x 458 mm         |
+    /// | 29       | Envelope C4             | 229 x 324 mm         |
+    /// | 30       | Envelope C5             | 162 x 229 mm         |
+    /// | 31       | Envelope C6             | 114 x 162 mm         |
+    /// | 32       | Envelope C65            | 114 x 229 mm         |
+    /// | 33       | Envelope B4             | 250 x 353 mm         |
+    /// | 34       | Envelope B5             | 176 x 250 mm         |
+    /// | 35       | Envelope B6             | 176 x 125 mm         |
+    /// | 36       | Envelope                | 110 x 230 mm         |
+    /// | 37       | Monarch                 | 3.875 x 7.5 in       |
+    /// | 38       | Envelope                | 3 5/8 x 6 1/2 in     |
+    /// | 39       | Fanfold                 | 14 7/8 x 11 in       |
+    /// | 40       | German Std Fanfold      | 8 1/2 x 12 in        |
+    /// | 41       | German Legal Fanfold    | 8 1/2 x 13 in        |
+    ///
+    /// Note, it is likely that not all of these paper types will be available
+    /// to the end user since it will depend on the paper formats that the
+    /// user's printer supports. Therefore, it is best to stick to standard
+    /// paper types of 1 for US Letter and 9 for A4.
+    ///
+    /// If you do not specify a paper type the worksheet will print using the
+    /// printer's default paper style.
+    ///
+    /// # Parameters
+    ///
+    /// - `paper_size`: The paper size index from the list above .
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the worksheet paper size/type for
+    /// the printed output.
     ///
     /// ```
-    /// # // This code is available in examples/app_watermark.rs
+    /// # // This code is available in examples/doc_worksheet_set_paper.rs
     /// #
-    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError, HeaderImagePosition};
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     let image = Image::new("examples/watermark.png")?;
+    ///     // Set the printer paper size.
+    ///     worksheet.set_paper_size(9); // A4 paper size.
     ///
-    ///     // Insert the watermark image in the header.
-    ///     worksheet.set_header("&C&[Picture]");
-    ///     worksheet.set_header_image(&image, HeaderImagePosition::Center)?;
-    /// #
-    /// #     // Set Page View mode so the watermark is visible.
-    /// #     worksheet.set_view_page_layout();
-    /// #
-    /// #     // Save the file to disk.
-    /// #     workbook.save("watermark.xlsx")?;
+    /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
     /// ```
+    pub fn set_paper_size(&mut self, paper_size: u8) -> &mut Worksheet {
+        self.paper_size = paper_size;
+        self.page_setup_changed = true;
+        self
+    }
+
+    /// Set the order in which pages are printed.
     ///
-    /// Output file:
+    /// The `set_page_order()` method is used to change the default print
+    /// direction. This is referred to by Excel as the sheet "page order":
     ///
-    /// <img src="https://rustxlsxwriter.github.io/images/app_watermark.png">
+    /// The default page order is shown below for a worksheet that extends over
+    /// 4 pages. The order is called "down then over":
     ///
-    pub fn set_header_image(
-        &mut self,
-        image: &Image,
-        position: HeaderImagePosition,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Check that there is a matching  &[Picture]/&[G] variable in the
-        // header string.
-        if !Self::verify_header_footer_image(&self.header, &position) {
-            let error = format!(
-                "No &[Picture] or &[G] variable in header string: '{}' for position = '{:?}'",
-                self.header, position
-            );
-            return Err(XlsxError::ParameterError(error));
-        }
-
-        let mut image = image.clone();
-        image.header_position = position.clone();
-        image.is_header = true;
-        self.header_footer_images[position as usize] = Some(image);
-
-        Ok(self)
-    }
-
-    /// Insert an image in a worksheet footer.
-    ///
-    /// See the documentation for [`Worksheet::set_header_image()`] for more
-    /// details.
-    ///
-    /// # Parameters
-    ///
-    /// - `position`: The image position as defined by the
-    ///   [`HeaderImagePosition`] enum.
-    ///
-    /// # Errors
-    ///
-    /// - [`XlsxError::ParameterError`] - Parameter error if there isn't a
-    ///   corresponding `&[Picture]`/`&[G]` variable in the header string.
-    ///
-    pub fn set_footer_image(
-        &mut self,
-        image: &Image,
-        position: HeaderImagePosition,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Check that there is a matching  &[Picture]/&[G] variable in the
-        // footer string.
-        if !Self::verify_header_footer_image(&self.footer, &position) {
-            let error = format!(
-                "No &[Picture] or &[G] variable in footer string: '{}' for position = '{:?}'",
-                self.footer, position
-            );
-            return Err(XlsxError::ParameterError(error));
-        }
-
-        let mut image = image.clone();
-        image.header_position = position.clone();
-        image.is_header = false;
-        self.header_footer_images[3 + position as usize] = Some(image);
-
-        Ok(self)
-    }
-
-    /// Set the page setup option to scale the header/footer with the document.
-    ///
-    /// This option determines whether the headers and footers use the same
-    /// scaling as the worksheet. This defaults to "on" in Excel.
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_page_order.png">
     ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Header/Footer](../worksheet/index.html#page-setup---headerfooter).
+    /// However, by using `set_page_order(false)` the print order will be
+    /// changed to "over then down".
     ///
     /// # Parameters
     ///
-    /// - `enable`: Turn the property on/off. It is on by default.
-    ///
-    pub fn set_header_footer_scale_with_doc(&mut self, enable: bool) -> &mut Worksheet {
-        self.header_footer_scale_with_doc = enable;
-
-        if !enable {
-            self.page_setup_changed = true;
-            self.head_footer_changed = true;
-        }
-
-        self
-    }
-
-    /// Set the page setup option to align the header/footer with the margins.
-    ///
-    /// This option determines whether the headers and footers align with the
-    /// left and right margins of the worksheet. This defaults to "on" in Excel.
+    /// - `enable`: Turn the property on/off. Set `true` to get "Down, then
+    ///   over" (the default) and `false` to get "Over, then down".
     ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Header/Footer](../worksheet/index.html#page-setup---headerfooter).
+    /// # Examples
     ///
-    /// # Parameters
+    /// The following example demonstrates setting the worksheet printed page
+    /// order.
     ///
-    /// - `enable`: Turn the property on/off. It is on by default. S
-    pub fn set_header_footer_align_with_page(&mut self, enable: bool) -> &mut Worksheet {
-        self.header_footer_align_with_page = enable;
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_page_order.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Set the page print to "over then down"
+    ///     worksheet.set_page_order(false);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn set_page_order(&mut self, enable: bool) -> &mut Worksheet {
+        self.default_page_order = enable;
 
         if !enable {
             self.page_setup_changed = true;
-            self.head_footer_changed = true;
         }
         self
     }
 
-    /// Set the page margins.
-    ///
-    /// The `set_margins()` method is used to set the margins of the worksheet
-    /// when it is printed. The units are in inches. Specifying `-1.0` for any
-    /// parameter will give the default Excel value. The defaults are shown
-    /// below.
-    ///
-    /// # Parameters
+    /// Set the page orientation to landscape.
     ///
-    /// - `left`: Left margin in inches. Excel default is 0.7.
-    /// - `right`: Right margin in inches. Excel default is 0.7.
-    /// - `top`: Top margin in inches. Excel default is 0.75.
-    /// - `bottom`: Bottom margin in inches. Excel default is 0.75.
-    /// - `header`: Header margin in inches. Excel default is 0.3.
-    /// - `footer`: Footer margin in inches. Excel default is 0.3.
+    /// The `set_landscape()` method is used to set the orientation of a
+    /// worksheet's printed page to landscape.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the worksheet margins.
+    /// The following example demonstrates setting the worksheet page orientation to
+    /// landscape.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_margins.rs
+    /// # // This code is available in examples/doc_worksheet_set_landscape.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
@@ -13558,7 +14950,7 @@ impl Worksheet {
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     worksheet.set_margins(1.0, 1.25, 1.5, 1.75, 0.75, 0.25);
+    ///     worksheet.set_landscape();
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -13566,194 +14958,183 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file:
+    pub fn set_landscape(&mut self) -> &mut Worksheet {
+        self.is_portrait = false;
+        self.page_setup_changed = true;
+        self
+    }
+
+    /// Set the page orientation to portrait.
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_margins.png">
+    ///  This `set_portrait()` method  is used to set the orientation of a
+    ///  worksheet's printed page to portrait. The default worksheet orientation
+    ///  is portrait, so this function is rarely required.
     ///
-    pub fn set_margins(
-        &mut self,
-        left: f64,
-        right: f64,
-        top: f64,
-        bottom: f64,
-        header: f64,
-        footer: f64,
-    ) -> &mut Worksheet {
-        if left >= 0.0 {
-            self.margin_left = left;
-            self.page_setup_changed = true;
-        }
-        if right >= 0.0 {
-            self.margin_right = right;
-            self.page_setup_changed = true;
-        }
-        if top >= 0.0 {
-            self.margin_top = top;
-            self.page_setup_changed = true;
-        }
-        if bottom >= 0.0 {
-            self.margin_bottom = bottom;
-            self.page_setup_changed = true;
-        }
-        if header >= 0.0 {
-            self.margin_header = header;
-            self.page_setup_changed = true;
-        }
-        if footer >= 0.0 {
-            self.margin_footer = footer;
-            self.page_setup_changed = true;
-        }
+    pub fn set_portrait(&mut self) -> &mut Worksheet {
+        self.is_portrait = true;
+        self.page_setup_changed = true;
+        self
+    }
+
+    /// Set the page view mode to normal layout.
+    ///
+    /// This method is used to display the worksheet in “View -> Normal”
+    /// mode. This is the default.
+    ///
+    pub fn set_view_normal(&mut self) -> &mut Worksheet {
+        self.page_view = PageView::Normal;
+        self
+    }
 
+    /// Set the page view mode to page layout.
+    ///
+    /// This method is used to display the worksheet in “View -> Page Layout”
+    /// mode.
+    ///
+    pub fn set_view_page_layout(&mut self) -> &mut Worksheet {
+        self.page_view = PageView::PageLayout;
+        self.page_setup_changed = true;
         self
     }
 
-    /// Set the first page number when printing.
+    /// Set the page view mode to page break preview.
     ///
-    /// The `set_print_first_page_number()` method is used to set the page
-    /// number of the first page when the worksheet is printed out. This option
-    /// will only have and effect if you have a header/footer with the `&[Page]`
-    /// control character, see [`Worksheet::set_header()`].
+    /// This method is used to display the worksheet in “View -> Page Break
+    /// Preview” mode.
     ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Page](../worksheet/index.html#page-setup---page).
+    pub fn set_view_page_break_preview(&mut self) -> &mut Worksheet {
+        self.page_view = PageView::PageBreaks;
+        self.page_setup_changed = true;
+        self
+    }
+
+    /// Set the horizontal page breaks on a worksheet.
+    ///
+    /// The `set_page_breaks()` method adds horizontal page breaks to a
+    /// worksheet. A page break causes all the data that follows it to be
+    /// printed on the next page. Horizontal page breaks act between rows.
     ///
     /// # Parameters
     ///
-    /// - `page_number`: The page number of the first printed page.
+    /// - `breaks`: A list of one or more row numbers where the page breaks
+    ///   occur. To create a page break between rows 20 and 21 you must specify
+    ///   the break at row 21. However in zero index notation this is actually
+    ///   row 20. So you can pretend for a small while that you are using 1
+    ///   index notation.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - The number of page breaks exceeds
+    ///   Excel's limit of 1023 page breaks.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the page number on the
-    /// printed page.
+    /// The following example demonstrates setting page breaks for a worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_print_first_page_number.rs
+    /// # // This code is available in examples/doc_worksheet_set_page_breaks.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
+    /// #     worksheet.write_string(100, 100, "Test")?;
     /// #
-    ///     worksheet.set_header("&CPage &P of &N");
-    ///     worksheet.set_print_first_page_number(2);
+    ///     // Set a page break at rows 20, 40 and 60.
+    ///     worksheet.set_page_breaks(&[20, 40, 60])?;
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn set_print_first_page_number(&mut self, page_number: u16) -> &mut Worksheet {
-        self.first_page_number = page_number;
-        self.page_setup_changed = true;
-        self
-    }
-
-    /// Set the page setup option to set the print scale.
     ///
-    /// Set the scale factor of the printed page, in the range 10 <= scale <=
-    /// 400.
-    ///
-    /// The default scale factor is 100. The `set_print_scale()` method
-    /// does not affect the scale of the visible page in Excel. For that you
-    /// should use [`Worksheet::set_zoom()`].
-    ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Page](../worksheet/index.html#page-setup---page).
-    ///
-    /// # Parameters
-    ///
-    /// - `scale`: The print scale factor.
-    ///
-    /// # Examples
-    ///
-    /// The following example demonstrates setting the scale of the worksheet page
-    /// when printed.
+    /// Output file:
     ///
-    /// ```
-    /// # // This code is available in examples/doc_worksheet_set_print_scale.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #
-    ///     // Scale the printed worksheet to 50%.
-    ///     worksheet.set_print_scale(50);
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_page_breaks.png">
     ///
-    pub fn set_print_scale(&mut self, scale: u16) -> &mut Worksheet {
-        if !(10..=400).contains(&scale) {
-            eprintln!("Scale factor {scale} outside Excel range: 10 <= zoom <= 400.");
-            return self;
+    pub fn set_page_breaks(&mut self, breaks: &[RowNum]) -> Result<&mut Worksheet, XlsxError> {
+        // Ignore empty input.
+        if breaks.is_empty() {
+            return Ok(self);
         }
 
-        // This property is mutually exclusive with fit to page.
-        self.fit_to_page = false;
+        // Sort list and remove any duplicates and 0.
+        let breaks = Self::process_pagebreaks(breaks)?;
 
-        self.print_scale = scale;
-        self.page_setup_changed = true;
-        self
+        // Check max break value is within Excel column limit.
+        if *breaks.last().unwrap() >= ROW_MAX {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        self.horizontal_breaks = breaks;
+
+        Ok(self)
     }
 
-    /// Fit the printed area to a specific number of pages both vertically and
-    /// horizontally.
+    /// Set the vertical page breaks on a worksheet.
     ///
-    /// The `set_print_fit_to_pages()` method is used to fit the printed area to
-    /// a specific number of pages both vertically and horizontally. If the
-    /// printed area exceeds the specified number of pages it will be scaled
-    /// down to fit. This ensures that the printed area will always appear on
-    /// the specified number of pages even if the page size or margins change:
+    /// The `set_vertical_page_breaks()` method adds vertical page breaks to a
+    /// worksheet. This is much less common than the
+    /// [`Worksheet::set_page_breaks()`] method shown above.
     ///
-    /// ```text
-    ///     worksheet1.set_print_fit_to_pages(1, 1); // Fit to 1x1 pages.
-    ///     worksheet2.set_print_fit_to_pages(2, 1); // Fit to 2x1 pages.
-    ///     worksheet3.set_print_fit_to_pages(1, 2); // Fit to 1x2 pages.
-    /// ```
+    /// # Parameters
     ///
-    /// The print area can be defined using the `set_print_area()` method.
+    /// - `breaks`: A list of one or more column numbers where the page breaks
+    ///   occur.
     ///
-    /// A common requirement is to fit the printed output to `n` pages wide but
-    /// have the height be as long as necessary. To achieve this set the
-    /// `height` to 0, see the example below.
+    /// # Errors
     ///
-    /// **Notes**:
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - The number of page breaks exceeds
+    ///   Excel's limit of 1023 page breaks.
     ///
-    /// - The `set_print_fit_to_pages()` will override any manual page breaks
-    ///   that are defined in the worksheet.
+    pub fn set_vertical_page_breaks(
+        &mut self,
+        breaks: &[u32],
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Ignore empty input.
+        if breaks.is_empty() {
+            return Ok(self);
+        }
+
+        // Sort list and remove any duplicates and 0.
+        let breaks = Self::process_pagebreaks(breaks)?;
+
+        // Check max break value is within Excel col limit.
+        if *breaks.last().unwrap() >= u32::from(COL_MAX) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        self.vertical_breaks = breaks;
+
+        Ok(self)
+    }
+
+    /// Set the worksheet zoom factor.
     ///
-    /// - When using `set_print_fit_to_pages()` it may also be required to set
-    ///   the printer paper size using [`Worksheet::set_paper_size()`] or else
-    ///   Excel will default to "US Letter".
+    /// Set the worksheet zoom factor in the range 10 <= zoom <= 400.
     ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Page](../worksheet/index.html#page-setup---page).
+    /// The default zoom level is 100. The `set_zoom()` method does not affect
+    /// the scale of the printed page in Excel. For that you should use
+    /// [`Worksheet::set_print_scale()`].
     ///
     /// # Parameters
     ///
-    /// - `width`: Number of pages horizontally.
-    /// - `height`: Number of pages vertically.
+    /// - `zoom`: The worksheet zoom level.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the scale of the worksheet to
-    /// fit a defined number of pages vertically and horizontally. This example
-    /// shows a common use case which is to fit the printed output to 1 page
-    /// wide but have the height be as long as necessary.
+    /// The following example demonstrates setting the worksheet zoom level.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_print_fit_to_pages.rs
+    /// # // This code is available in examples/doc_worksheet_set_zoom.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
@@ -13763,8 +15144,8 @@ impl Worksheet {
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     // Set the printed output to fit 1 page wide and as long as necessary.
-    ///     worksheet.set_print_fit_to_pages(1, 0);
+    ///     worksheet.write_string(0, 0, "Hello")?;
+    ///     worksheet.set_zoom(200);
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -13772,99 +15153,77 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output:
+    /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_print_fit_to_pages.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_zoom.png">
     ///
-    pub fn set_print_fit_to_pages(&mut self, width: u16, height: u16) -> &mut Worksheet {
-        self.fit_width = width;
-        self.fit_height = height;
-
-        // This property is mutually exclusive with print scale.
-        self.print_scale = 100;
+    pub fn set_zoom(&mut self, zoom: u16) -> &mut Worksheet {
+        if !(10..=400).contains(&zoom) {
+            eprintln!("Zoom factor {zoom} outside Excel range: 10 <= zoom <= 400.");
+            return self;
+        }
 
-        self.fit_to_page = true;
-        self.page_setup_changed = true;
+        self.zoom = zoom;
         self
     }
 
-    /// Center the printed page horizontally.
-    ///
-    /// Center the worksheet data horizontally between the margins on the
-    /// printed page
-    ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Margins](../worksheet/index.html#page-setup---margins).
-    ///
-    /// # Parameters
-    ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    /// Freeze panes in a worksheet.
     ///
-    pub fn set_print_center_horizontally(&mut self, enable: bool) -> &mut Worksheet {
-        self.center_horizontally = enable;
-
-        if enable {
-            self.print_options_changed = true;
-            self.page_setup_changed = true;
-        }
-        self
-    }
-
-    /// Center the printed page vertically.
+    /// The `set_freeze_panes()` method can be used to divide a worksheet into
+    /// horizontal or vertical regions known as panes and to “freeze” these
+    /// panes so that the splitter bars are not visible.
     ///
-    /// Center the worksheet data vertically between the margins on the printed
-    /// page
+    /// As with Excel the split is to the top and left of the cell. So to freeze
+    /// the top row and leftmost column you would use `(1, 1)` (zero-indexed).
+    /// Also, you can set one of the row and col parameters as 0 if you do not
+    /// want either the vertical or horizontal split. See the example below.
     ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Margins](../worksheet/index.html#page-setup---margins).
+    /// In Excel it is also possible to set "split" panes without freezing them.
+    /// That feature isn't currently supported by `rust_xlsxwriter`.
     ///
     /// # Parameters
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
-    ///
-    pub fn set_print_center_vertically(&mut self, enable: bool) -> &mut Worksheet {
-        self.center_vertically = enable;
-
-        if enable {
-            self.print_options_changed = true;
-            self.page_setup_changed = true;
-        }
-        self
-    }
-
-    /// Set the option to turn on/off the screen gridlines.
-    ///
-    /// The `set_screen_gridlines()` method is use to turn on/off gridlines on
-    /// displayed worksheet. It is on by default.
-    ///
-    /// To turn on/off the printed gridlines see the
-    /// [`Worksheet::set_print_gridlines()`] method below.
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
     ///
-    /// # Parameters
+    /// # Errors
     ///
-    /// - `enable`: Turn the property on/off. It is on by default.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates turn off the worksheet worksheet screen
-    /// gridlines.
+    /// The following example demonstrates setting the worksheet panes.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_screen_gridlines.rs
+    /// # // This code is available in examples/doc_worksheet_set_freeze_panes.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Workbook, Worksheet, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
+    /// #     let mut worksheet1 = Worksheet::new();
+    /// #     let mut worksheet2 = Worksheet::new();
+    /// #     let mut worksheet3 = Worksheet::new();
     /// #
-    ///     worksheet.write(0, 0, "Hello")?;
+    /// #     worksheet1.write_string(0, 0, "Scroll down")?;
+    /// #     worksheet2.write_string(0, 0, "Scroll across")?;
+    /// #     worksheet3.write_string(0, 0, "Scroll down or across")?;
+    /// #
+    ///     // Freeze the top row only.
+    ///     worksheet1.set_freeze_panes(1, 0)?;
     ///
-    ///     // Turn off the screen gridlines.
-    ///     worksheet.set_screen_gridlines(false);
+    ///     // Freeze the leftmost column only.
+    ///     worksheet2.set_freeze_panes(0, 1)?;
+    ///
+    ///     // Freeze the top row and leftmost column.
+    ///     worksheet3.set_freeze_panes(1, 1)?;
+    /// #
+    /// #     workbook.push_worksheet(worksheet1);
+    /// #     workbook.push_worksheet(worksheet2);
+    /// #     workbook.push_worksheet(worksheet3);
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -13874,158 +15233,64 @@ impl Worksheet {
     ///
     /// Output file:
     ///
-    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_screen_gridlines.png">
+    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_freeze_panes.png">
     ///
-    pub fn set_screen_gridlines(&mut self, enable: bool) -> &mut Worksheet {
-        self.screen_gridlines = enable;
-
-        self
-    }
-
-    /// Set the page setup option to turn on printed gridlines.
-    ///
-    /// The `set_print_gridlines()` method is use to turn on/off gridlines on
-    /// the printed pages. It is off by default.
-    ///
-    /// To turn on/off the screen gridlines see the
-    /// [`Worksheet::set_screen_gridlines()`] method above.
-    ///
-    ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Sheet](../worksheet/index.html#page-setup---sheet).
-    ///
-    /// # Parameters
-    ///
-    /// - `enable`: Turn the property on/off. It is off by default.
-    ///
-    pub fn set_print_gridlines(&mut self, enable: bool) -> &mut Worksheet {
-        self.print_gridlines = enable;
-
-        if enable {
-            self.print_options_changed = true;
-            self.page_setup_changed = true;
-        }
-        self
-    }
-
-    /// Set the page setup option to print in black and white.
-    ///
-    /// This `set_print_black_and_white()` method can be used to force printing
-    /// in black and white only. It is off by default.
-    ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Sheet](../worksheet/index.html#page-setup---sheet).
-    ///
-    /// # Parameters
-    ///
-    /// - `enable`: Turn the property on/off. It is off by default.
-    ///
-    pub fn set_print_black_and_white(&mut self, enable: bool) -> &mut Worksheet {
-        self.print_black_and_white = enable;
-
-        if enable {
-            self.page_setup_changed = true;
-        }
-        self
-    }
-
-    /// Set the page setup option to print in draft quality.
-    ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Sheet](../worksheet/index.html#page-setup---sheet).
-    ///
-    /// # Parameters
-    ///
-    /// - `enable`: Turn the property on/off. It is off by default.
-    ///
-    pub fn set_print_draft(&mut self, enable: bool) -> &mut Worksheet {
-        self.print_draft = enable;
+    pub fn set_freeze_panes(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
 
-        if enable {
-            self.page_setup_changed = true;
+        // Check row and col are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
         }
-        self
-    }
-
-    /// Set the page setup option to print the row and column headers on the
-    /// printed page.
-    ///
-    /// The `set_print_headings()` method turns on the row and column headers
-    /// when printing a worksheet. This option is off by default.
-    ///
-    /// See also the documentation on [Worksheet Page Setup -
-    /// Sheet](../worksheet/index.html#page-setup---sheet).
-    ///
-    /// # Parameters
-    ///
-    /// - `enable`: Turn the property on/off. It is off by default.
-    ///
-    pub fn set_print_headings(&mut self, enable: bool) -> &mut Worksheet {
-        self.print_headings = enable;
 
-        if enable {
-            self.print_options_changed = true;
-            self.page_setup_changed = true;
-        }
-        self
+        self.panes.freeze_cell = (row, col);
+        Ok(self)
     }
 
-    /// Set the print area for the worksheet.
-    ///
-    /// This method is used to specify the area of the worksheet that will be
-    /// printed.
-    ///
-    /// In order to specify an entire row or column range such as `1:20` or
-    /// `A:H` you must specify the corresponding maximum column or row range.
-    /// For example:
-    ///
-    /// - `(0, 0, 31, 16_383) == 1:32`.
-    /// - `(0, 0, 1_048_575, 12) == A:M`.
-    ///
-    /// In these examples 16,383 is the maximum column and 1,048,575 is the
-    /// maximum row (zero indexed).
+    /// Set the top most cell in the scrolling area of a freeze pane.
     ///
-    /// See also the example below and the documentation on
-    /// [Worksheet Page Setup - Sheet](../worksheet/index.html#page-setup---sheet).
+    /// This method is used in conjunction with the
+    /// [`Worksheet::set_freeze_panes()`] method to set the top most visible
+    /// cell in the scrolling range. For example you may want to freeze the top
+    /// row but have the worksheet pre-scrolled so that cell `A20` is visible in
+    /// the scrolled area. See the example below.
     ///
     /// # Parameters
     ///
-    /// - `first_row`: The first row of the range. (All zero indexed.)
-    /// - `first_col`: The first column of the range.
-    /// - `last_row`: The last row of the range.
-    /// - `last_col`: The last column of the range.
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
     ///
     /// # Errors
     ///
     /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
     ///   worksheet limits.
-    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
-    ///   than the last row or column.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the print area for several
-    /// worksheets.
+    /// The following example demonstrates setting the worksheet panes and also
+    /// setting the topmost visible cell in the scrolled area.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_print_area.rs
+    /// # // This code is available in examples/doc_worksheet_set_freeze_panes_top_cell.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
-    ///     let worksheet1 = workbook.add_worksheet();
-    ///     // Set the print area to "A1:M32"
-    ///     worksheet1.set_print_area(0, 0, 31, 12)?;
-    ///
-    ///     let worksheet2 = workbook.add_worksheet();
-    ///     // Set the print area to "1:32"
-    ///     worksheet2.set_print_area(0, 0, 31, 16_383)?;
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     worksheet.write_string(0, 0, "Scroll down")?;
+    /// #
+    ///     // Freeze the top row only.
+    ///     worksheet.set_freeze_panes(1, 0)?;
     ///
-    ///     let worksheet3 = workbook.add_worksheet();
-    ///     // Set the print area to "A:M"
-    ///     worksheet3.set_print_area(0, 0, 1_048_575, 12)?;
+    ///     // Pre-scroll to the row 20.
+    ///     worksheet.set_freeze_panes_top_cell(19, 0)?;
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -14033,253 +15298,191 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file, page setup dialog for worksheet1:
+    /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_print_area.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_freeze_panes_top_cell.png">
     ///
-    pub fn set_print_area(
+    pub fn set_freeze_panes_top_cell(
         &mut self,
-        first_row: RowNum,
-        first_col: ColNum,
-        last_row: RowNum,
-        last_col: ColNum,
+        row: RowNum,
+        col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check rows and cols are in the allowed range.
-        if !self.check_dimensions_only(first_row, first_col)
-            || !self.check_dimensions_only(last_row, last_col)
-        {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        // Check order of first/last values.
-        if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
-        }
+        self.check_chartsheet()?;
 
-        // The print range is the entire worksheet, therefore it is the same
-        // as the default, so we can ignore it.
-        if first_row == 0 && first_col == 0 && last_row == ROW_MAX - 1 && last_col == COL_MAX - 1 {
-            return Ok(self);
+        // Check row and col are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(XlsxError::RowColumnLimitError);
         }
 
-        // Store the defined name information.
-        self.print_area_defined_name.in_use = true;
-        self.print_area_defined_name.name_type = DefinedNameType::PrintArea;
-        self.print_area_defined_name.first_row = first_row;
-        self.print_area_defined_name.first_col = first_col;
-        self.print_area_defined_name.last_row = last_row;
-        self.print_area_defined_name.last_col = last_col;
-
-        self.page_setup_changed = true;
+        self.panes.top_cell = (row, col);
         Ok(self)
     }
 
-    /// Set the number of rows to repeat at the top of each printed page.
-    ///
-    /// For large Excel documents it is often desirable to have the first row or
-    /// rows of the worksheet print out at the top of each page.
+    /// Set the printed page header caption.
     ///
-    /// See the example below and the documentation on [Worksheet Page Setup -
-    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    /// The `set_header()` method can be used to set the header for a worksheet.
     ///
-    /// # Parameters
+    /// Headers and footers are generated using a string which is a combination
+    /// of plain text and optional control characters.
     ///
-    /// - `first_row`: The first row of the range. (Zero indexed.)
-    /// - `last_row`: The last row of the range.
+    /// The available control characters are:
     ///
-    /// # Errors
+    /// | Control              | Category      | Description           |
+    /// | -------------------- | ------------- | --------------------- |
+    /// | `&L`                 | Alignment     | Left                  |
+    /// | `&C`                 |               | Center                |
+    /// | `&R`                 |               | Right                 |
+    /// | `&[Page]`  or `&P`   | Information   | Page number           |
+    /// | `&[Pages]` or `&N`   |               | Total number of pages |
+    /// | `&[Date]`  or `&D`   |               | Date                  |
+    /// | `&[Time]`  or `&T`   |               | Time                  |
+    /// | `&[File]`  or `&F`   |               | File name             |
+    /// | `&[Tab]`   or `&A`   |               | Worksheet name        |
+    /// | `&[Path]`  or `&Z`   |               | Workbook path         |
+    /// | `&fontsize`          | Font          | Font size             |
+    /// | `&"font,style"`      |               | Font name and style   |
+    /// | `&U`                 |               | Single underline      |
+    /// | `&E`                 |               | Double underline      |
+    /// | `&S`                 |               | Strikethrough         |
+    /// | `&X`                 |               | Superscript           |
+    /// | `&Y`                 |               | Subscript             |
+    /// | `&[Picture]` or `&G` | Images        | Picture/image         |
+    /// | `&&`                 | Miscellaneous | Literal ampersand &   |
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
-    ///   row.
+    /// Some of the placeholder variables have a long version like `&[Page]` and
+    /// a short version like `&P`. The longer version is displayed in the Excel
+    /// interface but the shorter version is the way that it is stored in the
+    /// file format. Either version is okay since `rust_xlsxwriter` will
+    /// translate as required.
     ///
-    /// # Examples
+    /// Headers and footers have 3 edit areas to the left, center and right.
+    /// Text can be aligned to these areas by prefixing the text with the
+    /// control characters `&L`, `&C` and `&R`.
     ///
-    /// The following example demonstrates setting the rows to repeat on each
-    /// printed page.
+    /// For example:
     ///
-    /// ```
-    /// # // This code is available in examples/doc_worksheet_set_repeat_rows.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    ///     let worksheet1 = workbook.add_worksheet();
-    ///     // Repeat the first row in the printed output.
-    ///     worksheet1.set_repeat_rows(0, 0)?;
+    /// ```text
+    /// worksheet.set_header("&LHello");
     ///
-    ///     let worksheet2 = workbook.add_worksheet();
-    ///     // Repeat the first 2 rows in the printed output.
-    ///     worksheet2.set_repeat_rows(0, 1)?;
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
+    ///  ---------------------------------------------------------------
+    /// |                                                               |
+    /// | Hello                                                         |
+    /// |                                                               |
     ///
-    /// Output file, page setup dialog for worksheet2:
+    /// worksheet.set_header("&CHello");
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_repeat_rows.png">
+    ///  ---------------------------------------------------------------
+    /// |                                                               |
+    /// |                          Hello                                |
+    /// |                                                               |
     ///
-    pub fn set_repeat_rows(
-        &mut self,
-        first_row: RowNum,
-        last_row: RowNum,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Check rows are in the allowed range.
-        if !self.check_dimensions_only(first_row, 0) || !self.check_dimensions_only(last_row, 0) {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        // Check order of first/last values.
-        if first_row > last_row {
-            return Err(XlsxError::RowColumnOrderError);
-        }
-
-        // Store the range data.
-        self.repeat_row_cols_defined_name.in_use = true;
-        self.repeat_row_cols_defined_name.name_type = DefinedNameType::PrintTitles;
-        self.repeat_row_cols_defined_name.first_row = first_row;
-        self.repeat_row_cols_defined_name.last_row = last_row;
-
-        self.page_setup_changed = true;
-        Ok(self)
-    }
-
-    /// Set the columns to repeat at the left hand side of each printed page.
+    /// worksheet.set_header("&RHello");
     ///
-    /// For large Excel documents it is often desirable to have the first column
-    /// or columns of the worksheet print out at the left hand side of each
-    /// page.
+    ///  ---------------------------------------------------------------
+    /// |                                                               |
+    /// |                                                         Hello |
+    /// |                                                               |
+    /// ```
     ///
-    /// See the example below and the documentation on [Worksheet Page Setup -
-    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    /// You can also have text in each of the alignment areas:
     ///
-    /// # Parameters
+    /// ```text
+    /// worksheet.set_header("&LCiao&CBello&RCielo");
     ///
-    /// - `first_col`: The first column of the range. (Zero indexed.)
-    /// - `last_col`: The last column of the range.
+    ///  ---------------------------------------------------------------
+    /// |                                                               |
+    /// | Ciao                     Bello                          Cielo |
+    /// |                                                               |
+    /// ```
     ///
-    /// # Errors
+    /// The information control characters act as variables/templates that Excel
+    /// will update/expand as the workbook or worksheet changes.
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
-    ///   than the last row or column.
+    /// ```text
+    /// worksheet.set_header("&CPage &[Page] of &[Pages]");
     ///
-    /// # Examples
+    ///  ---------------------------------------------------------------
+    /// |                                                               |
+    /// |                        Page 1 of 6                            |
+    /// |                                                               |
+    /// ```
     ///
-    /// The following example demonstrates setting the columns to repeat on each
-    /// printed page.
+    /// Times and dates are in the user's default format:
+    ///
+    /// ```text
+    /// worksheet.set_header("&CUpdated at &[Time]");
     ///
+    ///  ---------------------------------------------------------------
+    /// |                                                               |
+    /// |                    Updated at 12:30 PM                        |
+    /// |                                                               |
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_repeat_columns.rs
-    /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
-    /// #
-    /// # fn main() -> Result<(), XlsxError> {
-    /// #     let mut workbook = Workbook::new();
-    /// #
-    ///     let worksheet1 = workbook.add_worksheet();
-    ///     // Repeat the first column in the printed output.
-    ///     worksheet1.set_repeat_columns(0, 0)?;
     ///
-    ///     let worksheet2 = workbook.add_worksheet();
-    ///     // Repeat the first 2 columns in the printed output.
-    ///     worksheet2.set_repeat_columns(0, 1)?;
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
+    /// To include a single literal ampersand `&` in a header or footer you
+    /// should use a double ampersand `&&`:
+    ///
+    /// ```text
+    /// worksheet.set_header("&CCuriouser && Curiouser - Attorneys at Law");
+    ///
+    ///  ---------------------------------------------------------------
+    /// |                                                               |
+    /// |                   Curiouser & Curiouser                       |
+    /// |                                                               |
     /// ```
     ///
-    /// Output file, page setup dialog for worksheet2:
+    /// You can specify the font size of a section of the text by prefixing it
+    /// with the control character `&n` where `n` is the font size:
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_repeat_columns.png">
+    /// ```text
+    /// worksheet1.set_header("&C&30Hello Big");
+    /// worksheet2.set_header("&C&10Hello Small");
+    /// ```
     ///
-    pub fn set_repeat_columns(
-        &mut self,
-        first_col: ColNum,
-        last_col: ColNum,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Check columns are in the allowed range.
-        if !self.check_dimensions_only(0, first_col) || !self.check_dimensions_only(0, last_col) {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        // Check order of first/last values.
-        if first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
-        }
-
-        // Store the defined name information.
-        self.repeat_row_cols_defined_name.in_use = true;
-        self.repeat_row_cols_defined_name.name_type = DefinedNameType::PrintTitles;
-        self.repeat_row_cols_defined_name.first_col = first_col;
-        self.repeat_row_cols_defined_name.last_col = last_col;
-
-        self.page_setup_changed = true;
-        Ok(self)
-    }
-
-    /// Autofit the worksheet column widths to the widest data in the column,
-    /// approximately.
+    /// You can specify the font of a section of the text by prefixing it with
+    /// the control sequence `&"font,style"` where `fontname` is a font name
+    /// such as Windows font descriptions: "Regular", "Italic", "Bold" or "Bold
+    /// Italic": "Courier New" or "Times New Roman" and `style` is one of the
+    /// standard Windows font descriptions like “Regular”, “Italic”, “Bold” or
+    /// “Bold Italic”:
     ///
-    /// Excel autofits columns at runtime when it has access to all of the
-    /// required worksheet information as well as the Windows functions for
-    /// calculating display areas based on fonts and formatting.
+    /// ```text
+    /// worksheet1.set_header(r#"&C&"Courier New,Italic"Hello"#);
+    /// worksheet2.set_header(r#"&C&"Courier New,Bold Italic"Hello"#);
+    /// worksheet3.set_header(r#"&C&"Times New Roman,Regular"Hello"#);
+    /// ```
     ///
-    /// The `rust_xlsxwriter` library doesn't have access to these Windows
-    /// functions so it simulates autofit by calculating string widths based on
-    /// metrics taken from Excel.
+    /// It is possible to combine all of these features together to create
+    /// complex headers and footers. If you set up a complex header in Excel you
+    /// can transfer it to `rust_xlsxwriter` by inspecting the string in the
+    /// Excel file. For example the following shows how unzip and grep the Excel
+    /// XML sub-files on a Linux system. The example uses libxml's xmllint to
+    /// format the XML for clarity:
     ///
-    /// This isn't perfect but for most cases it should be sufficient and
-    /// indistinguishable from the output of Excel. However there are some
-    /// limitations to be aware of when using this method:
+    /// ```text
+    /// $ unzip myfile.xlsm -d myfile
+    /// $ xmllint --format `find myfile -name "*.xml" | xargs` | \
+    ///     egrep "Header|Footer" | sed 's/&amp;/\&/g'
     ///
-    /// - It is based on the default Excel font type and size of Calibri 11. It
-    ///   will not give accurate results for other fonts or font sizes.
-    /// - It doesn't take formatting of numbers or dates account, although this
-    ///   may be addressed in a later version.
-    /// - Autofit is a relatively expensive operation since it performs a
-    ///   calculation for all the populated cells in a worksheet. See the note
-    ///   on performance below.
+    ///  <headerFooter scaleWithDoc="0">
+    ///    <oddHeader>&L&P</oddHeader>
+    ///  </headerFooter>
+    /// ```
     ///
-    /// For cases that don't match your desired output you can set explicit
-    /// column widths via [`Worksheet::set_column_width()`] or
-    /// [`Worksheet::set_column_width_pixels()`]. The `autofit()` method ignores
-    /// columns that have already been explicitly set if the width is greater
-    /// than the calculated autofit width. Alternatively, setting the column
-    /// width explicitly after calling `autofit()` will override the autofit
-    /// value. See also [`Worksheet::autofit_to_max_width()`] below.
+    /// Note: Excel requires that the header or footer string be less than 256
+    /// characters, including the control characters. Strings longer than this
+    /// will not be written, and a warning will be output.
     ///
-    /// **Performance**: By default `autofit()` performs a length calculation
-    /// for each populated cell in a worksheet. For very large worksheets this
-    /// could be slow. However, it is possible to mitigate this by calling
-    /// `autofit()` after writing the first 100 or 200 rows. This will produce a
-    /// reasonably accurate autofit for the first visible page of data without
-    /// incurring the performance penalty of autofitting thousands of
-    /// non-visible rows.
+    /// # Parameters
     ///
-    /// # Examples
+    /// - `header`: The header string with optional control characters.
     ///
-    /// The following example demonstrates auto-fitting the worksheet column
-    /// widths based on the data in the columns. See all the [Autofitting
-    /// Columns] example in the user guide/examples directory.
+    /// # Examples
     ///
-    /// [Autofitting Columns]:
-    ///     https://rustxlsxwriter.github.io/examples/autofit.html
+    /// The following example demonstrates setting the worksheet header.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_autofit.rs
+    /// # // This code is available in examples/doc_worksheet_set_header.rs
     /// #
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
@@ -14289,15 +15492,11 @@ impl Worksheet {
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     // Add some data to the worksheet.
-    ///     worksheet.write_string(0, 0, "Hello")?;
-    ///     worksheet.write_string(0, 1, "Hello")?;
-    ///     worksheet.write_string(1, 1, "Hello World")?;
-    ///     worksheet.write_number(0, 2, 123)?;
-    ///     worksheet.write_number(0, 3, 123456)?;
-    ///
-    ///     // Autofit the columns.
-    ///     worksheet.autofit();
+    ///     worksheet.set_header("&CPage &P of &N");
+    /// #
+    /// #     worksheet.write_string(0, 0, "Hello")?;
+    /// #     worksheet.write_string(200, 0, "Hello")?;
+    /// #     worksheet.set_view_page_layout();
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -14308,121 +15507,114 @@ impl Worksheet {
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_autofit.png">
-    ///
-    ///
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_header.png">
     ///
-    pub fn autofit(&mut self) -> &mut Worksheet {
-        self.autofit_worksheet(MAX_AUTOFIT_WIDTH_PIXELS)
-    }
+    pub fn set_header(&mut self, header: impl Into<String>) -> &mut Worksheet {
+        let header = header.into();
+        let header_expanded = header
+            .replace("&[Tab]", "&A")
+            .replace("&[Date]", "&D")
+            .replace("&[File]", "&F")
+            .replace("&[Page]", "&P")
+            .replace("&[Path]", "&Z")
+            .replace("&[Time]", "&T")
+            .replace("&[Pages]", "&N")
+            .replace("&[Picture]", "&G");
 
-    /// Autofit the worksheet columns up to a maximum width.
-    ///
-    /// The [`Worksheet::autofit()`] method above simulates Excel's column
-    /// autofit. On undesirable side-effect of this is that Excel autofits very
-    /// long strings up to limit of 255 characters/1790 pixels. This is often
-    /// too wide to display on a single screen at normal zoom. As such the
-    /// `autofit_to_max_width()` method is provided to enable a smaller upper
-    /// limit for autofitting long strings. A value of 300 pixels is recommended
-    /// as a good compromise between column width and readability.
-    ///
-    /// # Parameters
-    ///
-    /// - `max_autofit_width`: The maximum column width, in pixels, to use for
-    ///   autofitting.
-    ///
-    ///
-    pub fn autofit_to_max_width(&mut self, max_autofit_width: u16) -> &mut Worksheet {
-        let max_autofit_width = std::cmp::min(max_autofit_width, MAX_AUTOFIT_WIDTH_PIXELS);
-        self.autofit_worksheet(max_autofit_width)
+        if header_expanded.chars().count() > 255 {
+            eprintln!("Header string exceeds Excel's limit of 255 characters.");
+            return self;
+        }
+
+        self.header = header;
+        self.page_setup_changed = true;
+        self.head_footer_changed = true;
+        self
     }
 
-    /// Set the worksheet name used in VBA macros.
-    ///
-    /// This method can be used to set the VBA name for the worksheet. This is
-    /// sometimes required when a VBA macro included via
-    /// [`Workbook::add_vba_project()`](crate::Workbook::add_vba_project())
-    /// makes reference to the worksheet with a name other than the default
-    /// Excel VBA names of `Sheet1`, `Sheet2`, etc.
-    ///
-    /// See also the
-    /// [`Workbook::set_vba_name()`](crate::Workbook::set_vba_name()) method for
-    /// setting the workbook VBA name.
-    ///
-    /// The name must be a valid Excel VBA object name as defined by the
-    /// following rules:
+    /// Set the printed page footer caption.
     ///
-    /// - The name must be less than 32 characters.
-    /// - The name can only contain word characters: letters, numbers and
-    ///   underscores.
-    /// - The name must start with a letter.
-    /// - The name cannot be blank.
+    /// The `set_footer()` method can be used to set the footer for a worksheet.
     ///
-    /// The name must be also be unique across the worksheets in the workbook.
+    /// See the documentation for [`Worksheet::set_header()`] for more details
+    /// on the syntax of the header/footer string.
     ///
     /// # Parameters
     ///
-    /// - `name`: The vba name. It must follow the Excel rules, shown above.
-    ///
-    /// # Errors
-    ///
-    /// - [`XlsxError::VbaNameError`] - The name doesn't meet one of Excel's
-    ///   criteria, shown above.
+    /// - `footer`: The footer string with optional control characters.
     ///
-    pub fn set_vba_name(&mut self, name: impl Into<String>) -> Result<&mut Worksheet, XlsxError> {
-        let name = name.into();
-        utility::validate_vba_name(&name)?;
-        self.vba_codename = Some(name);
+    pub fn set_footer(&mut self, footer: impl Into<String>) -> &mut Worksheet {
+        let footer = footer.into();
+        let footer_expanded = footer
+            .replace("&[Tab]", "&A")
+            .replace("&[Date]", "&D")
+            .replace("&[File]", "&F")
+            .replace("&[Page]", "&P")
+            .replace("&[Path]", "&Z")
+            .replace("&[Time]", "&T")
+            .replace("&[Pages]", "&N")
+            .replace("&[Picture]", "&G");
 
-        Ok(self)
+        if footer_expanded.chars().count() > 255 {
+            eprintln!("Footer string exceeds Excel's limit of 255 characters.");
+            return self;
+        }
+
+        self.footer = footer;
+        self.page_setup_changed = true;
+        self.head_footer_changed = true;
+        self
     }
 
-    /// Set the default string used for NaN values.
-    ///
-    /// Excel doesn't support storing `NaN` (Not a Number) values. If a `NAN` is
-    /// generated as the result of a calculation Excel stores and displays the
-    /// error `#NUM!`. However, this error isn't usually used outside of a
-    /// formula result and it isn't stored as a number.
+    /// Insert an image in a worksheet header.
     ///
-    /// In order to deal with [`f64::NAN`] in a reasonable way `rust_xlsxwriter`
-    /// writes it as the string "NAN". The `set_nan_value()` method allows you
-    /// to override this default value.
+    /// Insert an image in a worksheet header in one of the 3 sections supported
+    /// by Excel: Left, Center and Right. This needs to be preceded by a call to
+    /// [`Worksheet::set_header()`] where a corresponding `&[Picture]` element
+    /// is added to the header formatting string such as `"&L&[Picture]"`.
     ///
     /// # Parameters
     ///
-    /// - `value`: The string to use for NaN values.
+    /// - `position`: The image position as defined by the
+    ///   [`HeaderImagePosition`] enum.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - Parameter error if there isn't a
+    ///   corresponding `&[Picture]`/`&[G]` variable in the header string.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates handling NaN and Infinity values and
-    /// also setting custom string representations.
+    /// The following example demonstrates adding a header image to a worksheet.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_nan_string.rs
+    /// # // This code is available in examples/doc_worksheet_set_header_image.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError, HeaderImagePosition};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     // Write NaN and Infinity default values.
-    ///     worksheet.write(0, 0, "Default:")?;
-    ///     worksheet.write(0, 1, f64::NAN)?;
-    ///     worksheet.write(1, 1, f64::INFINITY)?;
-    ///     worksheet.write(2, 1, f64::NEG_INFINITY)?;
-    ///
-    ///     // Overwrite the default values.
-    ///     worksheet.set_nan_value("Nan");
-    ///     worksheet.set_infinity_value("Infinity");
-    ///     worksheet.set_neg_infinity_value("NegInfinity");
-    ///
-    ///     // Write NaN and Infinity custom values.
-    ///     worksheet.write(4, 0, "Custom:")?;
-    ///     worksheet.write(4, 1, f64::NAN)?;
-    ///     worksheet.write(5, 1, f64::INFINITY)?;
-    ///     worksheet.write(6, 1, f64::NEG_INFINITY)?;
+    /// #     // Scale the image so it fits in the header.
+    ///       let image = Image::new("examples/rust_logo.png")?
+    ///           .set_scale_height(0.5)
+    ///           .set_scale_width(0.5);
+    /// #
+    ///     // Insert the watermark image in the header.
+    ///     worksheet.set_header("&C&[Picture]");
+    ///     worksheet.set_header_image(&image, HeaderImagePosition::Center)?;
+    /// #
+    /// #     // Increase the top margin to 1.2 for clarity. The -1.0 values are ignored.
+    /// #     worksheet.set_margins(-1.0, -1.0, 1.2, -1.0, -1.0, -1.0);
+    /// #
+    /// #     // Set Page View mode so the watermark is visible.
+    /// #     worksheet.set_view_page_layout();
     /// #
+    /// #     // Save the file to disk.
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
@@ -14432,69 +15624,20 @@ impl Worksheet {
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_nan_string.png">
-    ///
-    pub fn set_nan_value(&mut self, value: impl Into<String>) -> &mut Worksheet {
-        self.nan = value.into();
-        self
-    }
-
-    /// Set the default string used for Infinite values.
-    ///
-    /// Excel doesn't support storing `Inf` (infinity) values. If an `Inf` is
-    /// generated as the result of a calculation Excel stores and displays the
-    /// error `#DIV/0`. However, this error isn't usually used outside of a
-    /// formula result and it isn't stored as a number.
-    ///
-    /// In order to deal with [`f64::INFINITY`] in a reasonable way
-    /// `rust_xlsxwriter` writes it as the string "INF". The
-    /// `set_infinite_value()` method allows you to override this default value.
-    ///
-    /// See the example for [`Worksheet::set_nan_value()`] above.
-    ///
-    /// # Parameters
-    ///
-    /// - `value`: The string to use for `Inf` values.
-    ///
-    pub fn set_infinity_value(&mut self, value: impl Into<String>) -> &mut Worksheet {
-        self.infinity = value.into();
-        self
-    }
-
-    /// Set the default string used for negative Infinite values.
-    ///
-    /// Excel doesn't support storing `-Inf` (negative infinity) values. If a
-    /// `-Inf` is generated as the result of a calculation Excel stores and
-    /// displays the error `#DIV/0`. However, this error isn't usually used
-    /// outside of a formula result and it isn't stored as a number.
-    ///
-    ///
-    /// In order to deal with [`f64::NEG_INFINITY`] in a reasonable way
-    /// `rust_xlsxwriter` writes it as the string "-INF". The
-    /// `set_infinite_value()` method method allows you to override this default
-    /// value.
-    ///
-    /// See the example for [`Worksheet::set_nan_value()`] above.
-    ///
-    /// # Parameters
-    ///
-    /// - `value`: The string to use for `-Inf` values.
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_header_image.png">
     ///
-    pub fn set_neg_infinity_value(&mut self, value: impl Into<String>) -> &mut Worksheet {
-        self.neg_infinity = value.into();
-        self
-    }
-
-    /// Ignore an Excel error or warning in a worksheet cell.
+    /// An example of adding a worksheet watermark image using the
+    /// `rust_xlsxwriter` library. This is based on the method of putting an
+    /// image in the worksheet header as suggested in the [Microsoft
+    /// documentation].
     ///
-    /// Excel flags a number of data errors and inconsistencies with a a small
-    /// green triangle in the top left hand corner of the cell. For example the
-    /// following causes a warning of "Number Stored as Text":
+    /// [Microsoft documentation]:
+    ///     https://support.microsoft.com/en-us/office/add-a-watermark-in-excel-a372182a-d733-484e-825c-18ddf3edf009
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_ignore_error1.rs
+    /// # // This code is available in examples/app_watermark.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Image, Workbook, XlsxError, HeaderImagePosition};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     // Create a new Excel file object.
@@ -14503,102 +15646,165 @@ impl Worksheet {
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    ///     // Write a string that looks like a number.
-    ///     worksheet.write_string(1, 2, "123")?;
+    ///     let image = Image::new("examples/watermark.png")?;
+    ///
+    ///     // Insert the watermark image in the header.
+    ///     worksheet.set_header("&C&[Picture]");
+    ///     worksheet.set_header_image(&image, HeaderImagePosition::Center)?;
+    /// #
+    /// #     // Set Page View mode so the watermark is visible.
+    /// #     worksheet.set_view_page_layout();
     /// #
     /// #     // Save the file to disk.
-    /// #     workbook.save("worksheet.xlsx")?;
+    /// #     workbook.save("watermark.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
     /// ```
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_ignore_error1.png">
-    ///
-    /// These warnings can be useful indicators that there is an issue in the
-    /// spreadsheet but sometimes it is preferable to turn them off. At the file
-    /// level these errors can be ignored for a cell or cell range using
-    /// `Worksheet::ignore_error()` and [`Worksheet::ignore_error_range()`] (see
-    /// below).
-    ///
-    /// The errors and warnings that can be turned off at the file level are
-    /// represented by the [`IgnoreError`] enum values. These equate, with some
-    /// minor exceptions, to the error categories shown in the Excel Error
-    /// Checking dialog:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/ignore_errors_dialog.png">
-    ///
-    /// (Note: some of the items shown in the above dialog such as "Misleading
-    /// Number Formats" aren't saved in the output file by Excel and can't be
-    /// turned off permanently.)
-    ///
-    /// <br>
-    ///
-    /// The `Worksheet::ignore_error()` method can be called repeatedly to
-    /// ignore errors in different cells but **Excel only allows one ignored
-    /// error per cell**.
-    ///
-    /// An error can be turned off for a range of cells using the
-    /// [`Worksheet::ignore_error_range()`] method (see below).
+    /// Output file:
     ///
-    /// # Parameters
+    /// <img src="https://rustxlsxwriter.github.io/images/app_watermark.png">
     ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
-    /// - `error_type`: An [`IgnoreError`] enum value.
+    pub fn set_header_image(
+        &mut self,
+        image: &Image,
+        position: HeaderImagePosition,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check that there is a matching  &[Picture]/&[G] variable in the
+        // header string.
+        if !Self::verify_header_footer_image(&self.header, &position) {
+            let error = format!(
+                "No &[Picture] or &[G] variable in header string: '{}' for position = '{:?}'",
+                self.header, position
+            );
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        let mut image = image.clone();
+        image.header_position = position.clone();
+        image.is_header = true;
+        self.header_footer_images[position as usize] = Some(image);
+
+        Ok(self)
+    }
+
+    /// Insert an image in a worksheet footer.
+    ///
+    /// See the documentation for [`Worksheet::set_header_image()`] for more
+    /// details.
+    ///
+    /// # Parameters
+    ///
+    /// - `position`: The image position as defined by the
+    ///   [`HeaderImagePosition`] enum.
     ///
     /// # Errors
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::ParameterError`] - Parameter error if more than one rule
-    ///   is added to the same cell.
+    /// - [`XlsxError::ParameterError`] - Parameter error if there isn't a
+    ///   corresponding `&[Picture]`/`&[G]` variable in the header string.
+    ///
+    pub fn set_footer_image(
+        &mut self,
+        image: &Image,
+        position: HeaderImagePosition,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check that there is a matching  &[Picture]/&[G] variable in the
+        // footer string.
+        if !Self::verify_header_footer_image(&self.footer, &position) {
+            let error = format!(
+                "No &[Picture] or &[G] variable in footer string: '{}' for position = '{:?}'",
+                self.footer, position
+            );
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        let mut image = image.clone();
+        image.header_position = position.clone();
+        image.is_header = false;
+        self.header_footer_images[3 + position as usize] = Some(image);
+
+        Ok(self)
+    }
+
+    /// Set the page setup option to scale the header/footer with the document.
+    ///
+    /// This option determines whether the headers and footers use the same
+    /// scaling as the worksheet. This defaults to "on" in Excel.
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Header/Footer](../worksheet/index.html#page-setup---headerfooter).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_header_footer_scale_with_doc(&mut self, enable: bool) -> &mut Worksheet {
+        self.header_footer_scale_with_doc = enable;
+
+        if !enable {
+            self.page_setup_changed = true;
+            self.head_footer_changed = true;
+        }
+
+        self
+    }
+
+    /// Set the page setup option to align the header/footer with the margins.
+    ///
+    /// This option determines whether the headers and footers align with the
+    /// left and right margins of the worksheet. This defaults to "on" in Excel.
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Header/Footer](../worksheet/index.html#page-setup---headerfooter).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default. S
+    pub fn set_header_footer_align_with_page(&mut self, enable: bool) -> &mut Worksheet {
+        self.header_footer_align_with_page = enable;
+
+        if !enable {
+            self.page_setup_changed = true;
+            self.head_footer_changed = true;
+        }
+        self
+    }
+
+    /// Set the page margins.
+    ///
+    /// The `set_margins()` method is used to set the margins of the worksheet
+    /// when it is printed. The units are in inches. Specifying `-1.0` for any
+    /// parameter will give the default Excel value. The defaults are shown
+    /// below.
+    ///
+    /// # Parameters
+    ///
+    /// - `left`: Left margin in inches. Excel default is 0.7.
+    /// - `right`: Right margin in inches. Excel default is 0.7.
+    /// - `top`: Top margin in inches. Excel default is 0.75.
+    /// - `bottom`: Bottom margin in inches. Excel default is 0.75.
+    /// - `header`: Header margin in inches. Excel default is 0.3.
+    /// - `footer`: Footer margin in inches. Excel default is 0.3.
     ///
     /// # Examples
     ///
-    /// An example of turning off worksheet cells errors/warnings using using
-    /// the `rust_xlsxwriter` library.
+    /// The following example demonstrates setting the worksheet margins.
     ///
     /// ```
-    /// # // This code is available in examples/app_ignore_errors.rs
+    /// # // This code is available in examples/doc_worksheet_set_margins.rs
     /// #
-    /// # use rust_xlsxwriter::{Format, IgnoreError, Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    /// #     // Create a new Excel file object.
     /// #     let mut workbook = Workbook::new();
     /// #
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     // Create a format to use in descriptions.
-    /// #     let bold = Format::new().set_bold();
-    /// #
-    /// #     // Make the column wider for clarity.
-    /// #     worksheet.set_column_width(1, 16)?;
-    /// #
-    /// #     // Write some descriptions for the cells.
-    /// #     worksheet.write_with_format(1, 1, "Warning:", &bold)?;
-    /// #     worksheet.write_with_format(2, 1, "Warning turned off:", &bold)?;
-    /// #     worksheet.write_with_format(4, 1, "Warning:", &bold)?;
-    /// #     worksheet.write_with_format(5, 1, "Warning turned off:", &bold)?;
-    /// #
-    ///     // Write strings that looks like numbers. This will cause an Excel warning.
-    ///     worksheet.write_string(1, 2, "123")?;
-    ///     worksheet.write_string(2, 2, "123")?;
-    ///
-    ///     // Write a divide by zero formula. This will also cause an Excel warning.
-    ///     worksheet.write_formula(4, 2, "=1/0")?;
-    ///     worksheet.write_formula(5, 2, "=1/0")?;
-    ///
-    ///     // Turn off some of the warnings:
-    ///     worksheet.ignore_error(2, 2, IgnoreError::NumberStoredAsText)?;
-    ///     worksheet.ignore_error(5, 2, IgnoreError::FormulaError)?;
+    ///     worksheet.set_margins(1.0, 1.25, 1.5, 1.75, 0.75, 0.25);
     /// #
-    /// #     // Save the file to disk.
-    /// #     workbook.save("ignore_errors.xlsx")?;
+    /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
@@ -14607,72 +15813,1258 @@ impl Worksheet {
     /// Output file:
     ///
     /// <img
-    /// src="https://rustxlsxwriter.github.io/images/app_ignore_errors.png">
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_margins.png">
     ///
-    pub fn ignore_error(
+    pub fn set_margins(
         &mut self,
-        row: RowNum,
-        col: ColNum,
-        error_type: IgnoreError,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        self.ignore_error_range(row, col, row, col, error_type)
+        left: f64,
+        right: f64,
+        top: f64,
+        bottom: f64,
+        header: f64,
+        footer: f64,
+    ) -> &mut Worksheet {
+        if left >= 0.0 {
+            self.margin_left = left;
+            self.page_setup_changed = true;
+        }
+        if right >= 0.0 {
+            self.margin_right = right;
+            self.page_setup_changed = true;
+        }
+        if top >= 0.0 {
+            self.margin_top = top;
+            self.page_setup_changed = true;
+        }
+        if bottom >= 0.0 {
+            self.margin_bottom = bottom;
+            self.page_setup_changed = true;
+        }
+        if header >= 0.0 {
+            self.margin_header = header;
+            self.page_setup_changed = true;
+        }
+        if footer >= 0.0 {
+            self.margin_footer = footer;
+            self.page_setup_changed = true;
+        }
+
+        self
     }
 
-    /// Ignore an Excel error or warning in a range of worksheet cells.
+    /// Set the first page number when printing.
     ///
-    /// See [`Worksheet::ignore_error()`] above for a detailed explanation of
-    /// Excel worksheet errors.
+    /// The `set_print_first_page_number()` method is used to set the page
+    /// number of the first page when the worksheet is printed out. This option
+    /// will only have and effect if you have a header/footer with the `&[Page]`
+    /// control character, see [`Worksheet::set_header()`].
     ///
-    /// The `Worksheet::ignore_error_range()` method can be used to ignore an
-    /// error in a range, a row, a column, or the entire worksheet and it can be
-    /// called repeatedly to ignore errors in different cells ranges. It should
-    /// be noted however that **Excel only allows one ignored error per cell**.
-    /// The `rust_xlsxwriter` library verifies that multiple rules aren't added
-    /// to the same cell or cell range and will raise an error if this occurs.
-    /// However it doesn't currently verify whether cells within ranges overlap.
-    /// It is up to the user to ensure that this doesn't happen when using
-    /// ranges.
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Page](../worksheet/index.html#page-setup---page).
     ///
     /// # Parameters
     ///
-    /// - `first_row`: The first row of the range. (All zero indexed.)
-    /// - `first_col`: The first column of the range.
-    /// - `last_row`: The last row of the range.
-    /// - `last_col`: The last column of the range.
-    /// - `error_type`: An [`IgnoreError`] enum value.
+    /// - `page_number`: The page number of the first printed page.
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
-    ///   than the last row or column.
-    /// - [`XlsxError::ParameterError`] - Parameter error if more than one rule
-    ///   is added to the same range.
+    /// The following example demonstrates setting the page number on the
+    /// printed page.
     ///
-    pub fn ignore_error_range(
-        &mut self,
-        first_row: RowNum,
-        first_col: ColNum,
-        last_row: RowNum,
-        last_col: ColNum,
-        error_type: IgnoreError,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Check rows and cols are in the allowed range.
-        if !self.check_dimensions_only(first_row, first_col)
-            || !self.check_dimensions_only(last_row, last_col)
-        {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        // Check order of first/last values.
-        if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
-        }
-
-        let range = utility::cell_range(first_row, first_col, last_row, last_col);
-
-        // Check if the range was used previously. Note, that this doesn't
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_print_first_page_number.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.set_header("&CPage &P of &N");
+    ///     worksheet.set_print_first_page_number(2);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn set_print_first_page_number(&mut self, page_number: u16) -> &mut Worksheet {
+        self.first_page_number = page_number;
+        self.page_setup_changed = true;
+        self
+    }
+
+    /// Set the page setup option to set the print scale.
+    ///
+    /// Set the scale factor of the printed page, in the range 10 <= scale <=
+    /// 400.
+    ///
+    /// The default scale factor is 100. The `set_print_scale()` method
+    /// does not affect the scale of the visible page in Excel. For that you
+    /// should use [`Worksheet::set_zoom()`].
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Page](../worksheet/index.html#page-setup---page).
+    ///
+    /// # Parameters
+    ///
+    /// - `scale`: The print scale factor.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the scale of the worksheet page
+    /// when printed.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_print_scale.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Scale the printed worksheet to 50%.
+    ///     worksheet.set_print_scale(50);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_print_scale(&mut self, scale: u16) -> &mut Worksheet {
+        if !(10..=400).contains(&scale) {
+            eprintln!("Scale factor {scale} outside Excel range: 10 <= zoom <= 400.");
+            return self;
+        }
+
+        // This property is mutually exclusive with fit to page.
+        self.fit_to_page = false;
+
+        self.print_scale = scale;
+        self.page_setup_changed = true;
+        self
+    }
+
+    /// Fit the printed area to a specific number of pages both vertically and
+    /// horizontally.
+    ///
+    /// The `set_print_fit_to_pages()` method is used to fit the printed area to
+    /// a specific number of pages both vertically and horizontally. If the
+    /// printed area exceeds the specified number of pages it will be scaled
+    /// down to fit. This ensures that the printed area will always appear on
+    /// the specified number of pages even if the page size or margins change:
+    ///
+    /// ```text
+    ///     worksheet1.set_print_fit_to_pages(1, 1); // Fit to 1x1 pages.
+    ///     worksheet2.set_print_fit_to_pages(2, 1); // Fit to 2x1 pages.
+    ///     worksheet3.set_print_fit_to_pages(1, 2); // Fit to 1x2 pages.
+    /// ```
+    ///
+    /// The print area can be defined using the `set_print_area()` method.
+    ///
+    /// A common requirement is to fit the printed output to `n` pages wide but
+    /// have the height be as long as necessary. To achieve this set the
+    /// `height` to 0, see the example below.
+    ///
+    /// **Notes**:
+    ///
+    /// - The `set_print_fit_to_pages()` will override any manual page breaks
+    ///   that are defined in the worksheet.
+    ///
+    /// - When using `set_print_fit_to_pages()` it may also be required to set
+    ///   the printer paper size using [`Worksheet::set_paper_size()`] or else
+    ///   Excel will default to "US Letter".
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Page](../worksheet/index.html#page-setup---page).
+    ///
+    /// # Parameters
+    ///
+    /// - `width`: Number of pages horizontally.
+    /// - `height`: Number of pages vertically.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the scale of the worksheet to
+    /// fit a defined number of pages vertically and horizontally. This example
+    /// shows a common use case which is to fit the printed output to 1 page
+    /// wide but have the height be as long as necessary.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_print_fit_to_pages.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Set the printed output to fit 1 page wide and as long as necessary.
+    ///     worksheet.set_print_fit_to_pages(1, 0);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_print_fit_to_pages.png">
+    ///
+    pub fn set_print_fit_to_pages(&mut self, width: u16, height: u16) -> &mut Worksheet {
+        self.fit_width = width;
+        self.fit_height = height;
+
+        // This property is mutually exclusive with print scale.
+        self.print_scale = 100;
+
+        self.fit_to_page = true;
+        self.page_setup_changed = true;
+        self
+    }
+
+    /// Center the printed page horizontally.
+    ///
+    /// Center the worksheet data horizontally between the margins on the
+    /// printed page
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Margins](../worksheet/index.html#page-setup---margins).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_print_center_horizontally(&mut self, enable: bool) -> &mut Worksheet {
+        self.center_horizontally = enable;
+
+        if enable {
+            self.print_options_changed = true;
+            self.page_setup_changed = true;
+        }
+        self
+    }
+
+    /// Center the printed page vertically.
+    ///
+    /// Center the worksheet data vertically between the margins on the printed
+    /// page
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Margins](../worksheet/index.html#page-setup---margins).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_print_center_vertically(&mut self, enable: bool) -> &mut Worksheet {
+        self.center_vertically = enable;
+
+        if enable {
+            self.print_options_changed = true;
+            self.page_setup_changed = true;
+        }
+        self
+    }
+
+    /// Set the option to turn on/off the screen gridlines.
+    ///
+    /// The `set_screen_gridlines()` method is use to turn on/off gridlines on
+    /// displayed worksheet. It is on by default.
+    ///
+    /// To turn on/off the printed gridlines see the
+    /// [`Worksheet::set_print_gridlines()`] method below.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates turn off the worksheet worksheet screen
+    /// gridlines.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_screen_gridlines.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.write(0, 0, "Hello")?;
+    ///
+    ///     // Turn off the screen gridlines.
+    ///     worksheet.set_screen_gridlines(false);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/worksheet_set_screen_gridlines.png">
+    ///
+    pub fn set_screen_gridlines(&mut self, enable: bool) -> &mut Worksheet {
+        self.screen_gridlines = enable;
+
+        self
+    }
+
+    /// Set the page setup option to turn on printed gridlines.
+    ///
+    /// The `set_print_gridlines()` method is use to turn on/off gridlines on
+    /// the printed pages. It is off by default.
+    ///
+    /// To turn on/off the screen gridlines see the
+    /// [`Worksheet::set_screen_gridlines()`] method above.
+    ///
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_print_gridlines(&mut self, enable: bool) -> &mut Worksheet {
+        self.print_gridlines = enable;
+
+        if enable {
+            self.print_options_changed = true;
+            self.page_setup_changed = true;
+        }
+        self
+    }
+
+    /// Set the page setup option to print in black and white.
+    ///
+    /// This `set_print_black_and_white()` method can be used to force printing
+    /// in black and white only. It is off by default.
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_print_black_and_white(&mut self, enable: bool) -> &mut Worksheet {
+        self.print_black_and_white = enable;
+
+        if enable {
+            self.page_setup_changed = true;
+        }
+        self
+    }
+
+    /// Set the page setup option to print in draft quality.
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_print_draft(&mut self, enable: bool) -> &mut Worksheet {
+        self.print_draft = enable;
+
+        if enable {
+            self.page_setup_changed = true;
+        }
+        self
+    }
+
+    /// Set the page setup option to print the row and column headers on the
+    /// printed page.
+    ///
+    /// The `set_print_headings()` method turns on the row and column headers
+    /// when printing a worksheet. This option is off by default.
+    ///
+    /// See also the documentation on [Worksheet Page Setup -
+    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_print_headings(&mut self, enable: bool) -> &mut Worksheet {
+        self.print_headings = enable;
+
+        if enable {
+            self.print_options_changed = true;
+            self.page_setup_changed = true;
+        }
+        self
+    }
+
+    /// Set the print area for the worksheet.
+    ///
+    /// This method is used to specify the area of the worksheet that will be
+    /// printed.
+    ///
+    /// In order to specify an entire row or column range such as `1:20` or
+    /// `A:H` you must specify the corresponding maximum column or row range.
+    /// For example:
+    ///
+    /// - `(0, 0, 31, 16_383) == 1:32`.
+    /// - `(0, 0, 1_048_575, 12) == A:M`.
+    ///
+    /// In these examples 16,383 is the maximum column and 1,048,575 is the
+    /// maximum row (zero indexed).
+    ///
+    /// See also the example below and the documentation on
+    /// [Worksheet Page Setup - Sheet](../worksheet/index.html#page-setup---sheet).
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the print area for several
+    /// worksheets.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_print_area.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     let worksheet1 = workbook.add_worksheet();
+    ///     // Set the print area to "A1:M32"
+    ///     worksheet1.set_print_area(0, 0, 31, 12)?;
+    ///
+    ///     let worksheet2 = workbook.add_worksheet();
+    ///     // Set the print area to "1:32"
+    ///     worksheet2.set_print_area(0, 0, 31, 16_383)?;
+    ///
+    ///     let worksheet3 = workbook.add_worksheet();
+    ///     // Set the print area to "A:M"
+    ///     worksheet3.set_print_area(0, 0, 1_048_575, 12)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file, page setup dialog for worksheet1:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_print_area.png">
+    ///
+    pub fn set_print_area(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // The print range is the entire worksheet, therefore it is the same
+        // as the default, so we can ignore it.
+        if first_row == 0 && first_col == 0 && last_row == ROW_MAX - 1 && last_col == COL_MAX - 1 {
+            return Ok(self);
+        }
+
+        // Store the defined name information.
+        self.print_area_defined_name.in_use = true;
+        self.print_area_defined_name.name_type = DefinedNameType::PrintArea;
+        self.print_area_defined_name.first_row = first_row;
+        self.print_area_defined_name.first_col = first_col;
+        self.print_area_defined_name.last_row = last_row;
+        self.print_area_defined_name.last_col = last_col;
+
+        self.page_setup_changed = true;
+        Ok(self)
+    }
+
+    /// Set the number of rows to repeat at the top of each printed page.
+    ///
+    /// For large Excel documents it is often desirable to have the first row or
+    /// rows of the worksheet print out at the top of each page.
+    ///
+    /// See the example below and the documentation on [Worksheet Page Setup -
+    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. (Zero indexed.)
+    /// - `last_row`: The last row of the range.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the rows to repeat on each
+    /// printed page.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_repeat_rows.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     let worksheet1 = workbook.add_worksheet();
+    ///     // Repeat the first row in the printed output.
+    ///     worksheet1.set_repeat_rows(0, 0)?;
+    ///
+    ///     let worksheet2 = workbook.add_worksheet();
+    ///     // Repeat the first 2 rows in the printed output.
+    ///     worksheet2.set_repeat_rows(0, 1)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file, page setup dialog for worksheet2:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_repeat_rows.png">
+    ///
+    pub fn set_repeat_rows(
+        &mut self,
+        first_row: RowNum,
+        last_row: RowNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
+        // Check rows are in the allowed range.
+        if !self.check_dimensions_only(first_row, 0) || !self.check_dimensions_only(last_row, 0) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // Store the range data.
+        self.repeat_row_cols_defined_name.in_use = true;
+        self.repeat_row_cols_defined_name.name_type = DefinedNameType::PrintTitles;
+        self.repeat_row_cols_defined_name.first_row = first_row;
+        self.repeat_row_cols_defined_name.last_row = last_row;
+
+        self.page_setup_changed = true;
+        Ok(self)
+    }
+
+    /// Set the columns to repeat at the left hand side of each printed page.
+    ///
+    /// For large Excel documents it is often desirable to have the first column
+    /// or columns of the worksheet print out at the left hand side of each
+    /// page.
+    ///
+    /// See the example below and the documentation on [Worksheet Page Setup -
+    /// Sheet](../worksheet/index.html#page-setup---sheet).
+    ///
+    /// # Parameters
+    ///
+    /// - `first_col`: The first column of the range. (Zero indexed.)
+    /// - `last_col`: The last column of the range.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the columns to repeat on each
+    /// printed page.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_repeat_columns.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     let worksheet1 = workbook.add_worksheet();
+    ///     // Repeat the first column in the printed output.
+    ///     worksheet1.set_repeat_columns(0, 0)?;
+    ///
+    ///     let worksheet2 = workbook.add_worksheet();
+    ///     // Repeat the first 2 columns in the printed output.
+    ///     worksheet2.set_repeat_columns(0, 1)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file, page setup dialog for worksheet2:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_repeat_columns.png">
+    ///
+    pub fn set_repeat_columns(
+        &mut self,
+        first_col: ColNum,
+        last_col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
+        // Check columns are in the allowed range.
+        if !self.check_dimensions_only(0, first_col) || !self.check_dimensions_only(0, last_col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        // Store the defined name information.
+        self.repeat_row_cols_defined_name.in_use = true;
+        self.repeat_row_cols_defined_name.name_type = DefinedNameType::PrintTitles;
+        self.repeat_row_cols_defined_name.first_col = first_col;
+        self.repeat_row_cols_defined_name.last_col = last_col;
+
+        self.page_setup_changed = true;
+        Ok(self)
+    }
+
+    /// Autofit the worksheet column widths to the widest data in the column,
+    /// approximately.
+    ///
+    /// Excel autofits columns at runtime when it has access to all of the
+    /// required worksheet information as well as the Windows functions for
+    /// calculating display areas based on fonts and formatting.
+    ///
+    /// The `rust_xlsxwriter` library doesn't have access to these Windows
+    /// functions so it simulates autofit by calculating string widths based on
+    /// the character widths of the font, font size and bold property of the
+    /// cell format.
+    ///
+    /// This isn't perfect but for most cases it should be sufficient and
+    /// indistinguishable from the output of Excel. However there are some
+    /// limitations to be aware of when using this method:
+    ///
+    /// - Character widths are built-in for Calibri, the default Excel font,
+    ///   and for Arial, Times New Roman and Courier New and their metric
+    ///   compatible equivalents. Other fonts use the Calibri widths scaled to
+    ///   the font size. You can add the metrics of other fonts from a font file
+    ///   using [`Worksheet::add_autofit_font()`].
+    /// - CJK and emoji characters are measured as full width characters.
+    /// - Rich strings are measured in the font of the cell format.
+    /// - Numbers and dates with a user defined number format, set with
+    ///   [`Format::set_num_format()`], are measured as they are displayed by
+    ///   [`NumberFormat::format_number()`]. Dates with a built-in format are
+    ///   displayed in the locale of the user so they use the width of the
+    ///   `mm/dd/yyyy` format.
+    /// - Autofit is a relatively expensive operation since it performs a
+    ///   calculation for all the populated cells in a worksheet. See the note
+    ///   on performance below.
+    ///
+    /// For cases that don't match your desired output you can set explicit
+    /// column widths via [`Worksheet::set_column_width()`] or
+    /// [`Worksheet::set_column_width_pixels()`]. The `autofit()` method ignores
+    /// columns that have already been explicitly set if the width is greater
+    /// than the calculated autofit width. Alternatively, setting the column
+    /// width explicitly after calling `autofit()` will override the autofit
+    /// value. See also [`Worksheet::autofit_to_max_width()`] below.
+    ///
+    /// The `autofit()` method only adjusts the column widths by default. To
+    /// also adjust the row heights for wrapped, rotated or large text see
+    /// [`Worksheet::autofit_rows()`] and
+    /// [`Worksheet::set_autofit_row_heights()`].
+    ///
+    /// **Performance**: By default `autofit()` performs a length calculation
+    /// for each populated cell in a worksheet. For very large worksheets this
+    /// could be slow. However, it is possible to mitigate this by calling
+    /// `autofit()` after writing the first 100 or 200 rows. This will produce a
+    /// reasonably accurate autofit for the first visible page of data without
+    /// incurring the performance penalty of autofitting thousands of
+    /// non-visible rows.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates auto-fitting the worksheet column
+    /// widths based on the data in the columns. See all the [Autofitting
+    /// Columns] example in the user guide/examples directory.
+    ///
+    /// [Autofitting Columns]:
+    ///     https://rustxlsxwriter.github.io/examples/autofit.html
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_autofit.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Add some data to the worksheet.
+    ///     worksheet.write_string(0, 0, "Hello")?;
+    ///     worksheet.write_string(0, 1, "Hello")?;
+    ///     worksheet.write_string(1, 1, "Hello World")?;
+    ///     worksheet.write_number(0, 2, 123)?;
+    ///     worksheet.write_number(0, 3, 123456)?;
+    ///
+    ///     // Autofit the columns.
+    ///     worksheet.autofit();
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_autofit.png">
+    ///
+    ///
+    ///
+    pub fn autofit(&mut self) -> &mut Worksheet {
+        self.autofit_worksheet(MAX_AUTOFIT_WIDTH_PIXELS);
+
+        if self.autofit_row_heights {
+            self.autofit_rows();
+        }
+
+        self
+    }
+
+    /// Autofit the worksheet columns up to a maximum width.
+    ///
+    /// The [`Worksheet::autofit()`] method above simulates Excel's column
+    /// autofit. On undesirable side-effect of this is that Excel autofits very
+    /// long strings up to limit of 255 characters/1790 pixels. This is often
+    /// too wide to display on a single screen at normal zoom. As such the
+    /// `autofit_to_max_width()` method is provided to enable a smaller upper
+    /// limit for autofitting long strings. A value of 300 pixels is recommended
+    /// as a good compromise between column width and readability.
+    ///
+    /// # Parameters
+    ///
+    /// - `max_autofit_width`: The maximum column width, in pixels, to use for
+    ///   autofitting.
+    ///
+    ///
+    pub fn autofit_to_max_width(&mut self, max_autofit_width: u16) -> &mut Worksheet {
+        let max_autofit_width = std::cmp::min(max_autofit_width, MAX_AUTOFIT_WIDTH_PIXELS);
+        self.autofit_worksheet(max_autofit_width);
+
+        if self.autofit_row_heights {
+            self.autofit_rows();
+        }
+
+        self
+    }
+
+    /// Autofit the worksheet row heights to the tallest data in the row,
+    /// approximately.
+    ///
+    /// Excel adjusts the height of rows at runtime when the data in a cell is
+    /// edited, for example when text wraps onto several lines. However, it
+    /// doesn't recalculate the row heights when it opens a file so cells with
+    /// wrapped text only show the first line of text.
+    ///
+    /// The `autofit_rows()` method simulates Excel's row autofit by calculating
+    /// the height of the cell data in each row based on:
+    ///
+    /// - The font and font size of the cell format.
+    /// - The number of lines of text in cells with a text wrap format, see
+    ///   [`Format::set_text_wrap()`]. Text is split at newlines and wrapped at
+    ///   the width of the column, so any column widths, or calls to
+    ///   [`Worksheet::autofit()`], should be set before calling this method.
+    /// - The angle of rotated text, see [`Format::set_rotation()`].
+    ///
+    /// Rows are only made taller than the default row height. The height of a
+    /// row that was set with [`Worksheet::set_row_height()`] isn't changed.
+    /// Cells in merged ranges are ignored, as they are in Excel.
+    ///
+    /// The autofit heights aren't marked as custom heights in the output file
+    /// so Excel will recalculate them if the cell data is edited.
+    ///
+    /// See also [`Worksheet::set_autofit_row_heights()`] to autofit the row
+    /// heights as part of [`Worksheet::autofit()`].
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates auto-fitting the worksheet row
+    /// heights for cells with wrapped text.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_autofit_rows.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let wrap = Format::new().set_text_wrap();
+    ///
+    ///     // Add some data to the worksheet.
+    ///     worksheet.set_column_width(1, 30)?;
+    ///     worksheet.write_string(0, 0, "Item")?;
+    ///     worksheet.write_string(0, 1, "Comment")?;
+    ///     worksheet.write_string(1, 0, "Apples")?;
+    ///     worksheet.write_string_with_format(
+    ///         1,
+    ///         1,
+    ///         "The apples were delivered late and some of them were bruised.",
+    ///         &wrap,
+    ///     )?;
+    ///     worksheet.write_string(2, 0, "Pears")?;
+    ///     worksheet.write_string_with_format(2, 1, "Line 1\nLine 2\nLine 3", &wrap)?;
+    ///
+    ///     // Autofit the rows to show all of the wrapped text.
+    ///     worksheet.autofit_rows();
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn autofit_rows(&mut self) -> &mut Worksheet {
+        self.autofit_row_heights();
+        self
+    }
+
+    /// Autofit the row heights as well as the column widths in `autofit()`.
+    ///
+    /// By default [`Worksheet::autofit()`] and
+    /// [`Worksheet::autofit_to_max_width()`] only adjust the column widths.
+    /// This method enables the row height autofit of
+    /// [`Worksheet::autofit_rows()`] in those methods. The row heights are
+    /// calculated after the column widths so that wrapped text is wrapped at
+    /// the autofit column width.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_autofit_row_heights(&mut self, enable: bool) -> &mut Worksheet {
+        self.autofit_row_heights = enable;
+        self
+    }
+
+    /// Add font metrics from a font file for use with autofit.
+    ///
+    /// The [`Worksheet::autofit()`] method has built-in character widths for
+    /// the most common Excel fonts. This method can be used to add the
+    /// character widths of other fonts, or more precise widths for bold or
+    /// italic fonts, from a TrueType or OpenType font file. See
+    /// [`FontMetrics`] for details.
+    ///
+    /// The font metrics are used for cells whose format font name matches the
+    /// [`FontMetrics::font_name()`], ignoring case. If several metrics have the
+    /// same font name then the one that matches the bold and italic properties
+    /// of the format is used.
+    ///
+    /// # Parameters
+    ///
+    /// - `font_metrics`: The [`FontMetrics`] of the font.
+    ///
+    pub fn add_autofit_font(&mut self, font_metrics: &FontMetrics) -> &mut Worksheet {
+        self.autofit_fonts.push(font_metrics.clone());
+        self
+    }
+
+    /// Set the worksheet name used in VBA macros.
+    ///
+    /// This method can be used to set the VBA name for the worksheet. This is
+    /// sometimes required when a VBA macro included via
+    /// [`Workbook::add_vba_project()`](crate::Workbook::add_vba_project())
+    /// makes reference to the worksheet with a name other than the default
+    /// Excel VBA names of `Sheet1`, `Sheet2`, etc.
+    ///
+    /// See also the
+    /// [`Workbook::set_vba_name()`](crate::Workbook::set_vba_name()) method for
+    /// setting the workbook VBA name.
+    ///
+    /// The name must be a valid Excel VBA object name as defined by the
+    /// following rules:
+    ///
+    /// - The name must be less than 32 characters.
+    /// - The name can only contain word characters: letters, numbers and
+    ///   underscores.
+    /// - The name must start with a letter.
+    /// - The name cannot be blank.
+    ///
+    /// The name must be also be unique across the worksheets in the workbook.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The vba name. It must follow the Excel rules, shown above.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::VbaNameError`] - The name doesn't meet one of Excel's
+    ///   criteria, shown above.
+    ///
+    pub fn set_vba_name(&mut self, name: impl Into<String>) -> Result<&mut Worksheet, XlsxError> {
+        let name = name.into();
+        utility::validate_vba_name(&name)?;
+        self.vba_codename = Some(name);
+
+        Ok(self)
+    }
+
+    /// Set the default string used for NaN values.
+    ///
+    /// Excel doesn't support storing `NaN` (Not a Number) values. If a `NAN` is
+    /// generated as the result of a calculation Excel stores and displays the
+    /// error `#NUM!`. However, this error isn't usually used outside of a
+    /// formula result and it isn't stored as a number.
+    ///
+    /// In order to deal with [`f64::NAN`] in a reasonable way `rust_xlsxwriter`
+    /// writes it as the string "NAN". The `set_nan_value()` method allows you
+    /// to override this default value.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The string to use for NaN values.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates handling NaN and Infinity values and
+    /// also setting custom string representations.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_nan_string.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Write NaN and Infinity default values.
+    ///     worksheet.write(0, 0, "Default:")?;
+    ///     worksheet.write(0, 1, f64::NAN)?;
+    ///     worksheet.write(1, 1, f64::INFINITY)?;
+    ///     worksheet.write(2, 1, f64::NEG_INFINITY)?;
+    ///
+    ///     // Overwrite the default values.
+    ///     worksheet.set_nan_value("Nan");
+    ///     worksheet.set_infinity_value("Infinity");
+    ///     worksheet.set_neg_infinity_value("NegInfinity");
+    ///
+    ///     // Write NaN and Infinity custom values.
+    ///     worksheet.write(4, 0, "Custom:")?;
+    ///     worksheet.write(4, 1, f64::NAN)?;
+    ///     worksheet.write(5, 1, f64::INFINITY)?;
+    ///     worksheet.write(6, 1, f64::NEG_INFINITY)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_nan_string.png">
+    ///
+    pub fn set_nan_value(&mut self, value: impl Into<String>) -> &mut Worksheet {
+        self.nan = value.into();
+        self
+    }
+
+    /// Set the default string used for Infinite values.
+    ///
+    /// Excel doesn't support storing `Inf` (infinity) values. If an `Inf` is
+    /// generated as the result of a calculation Excel stores and displays the
+    /// error `#DIV/0`. However, this error isn't usually used outside of a
+    /// formula result and it isn't stored as a number.
+    ///
+    /// In order to deal with [`f64::INFINITY`] in a reasonable way
+    /// `rust_xlsxwriter` writes it as the string "INF". The
+    /// `set_infinite_value()` method allows you to override this default value.
+    ///
+    /// See the example for [`Worksheet::set_nan_value()`] above.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The string to use for `Inf` values.
+    ///
+    pub fn set_infinity_value(&mut self, value: impl Into<String>) -> &mut Worksheet {
+        self.infinity = value.into();
+        self
+    }
+
+    /// Set the default string used for negative Infinite values.
+    ///
+    /// Excel doesn't support storing `-Inf` (negative infinity) values. If a
+    /// `-Inf` is generated as the result of a calculation Excel stores and
+    /// displays the error `#DIV/0`. However, this error isn't usually used
+    /// outside of a formula result and it isn't stored as a number.
+    ///
+    ///
+    /// In order to deal with [`f64::NEG_INFINITY`] in a reasonable way
+    /// `rust_xlsxwriter` writes it as the string "-INF". The
+    /// `set_infinite_value()` method method allows you to override this default
+    /// value.
+    ///
+    /// See the example for [`Worksheet::set_nan_value()`] above.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The string to use for `-Inf` values.
+    ///
+    pub fn set_neg_infinity_value(&mut self, value: impl Into<String>) -> &mut Worksheet {
+        self.neg_infinity = value.into();
+        self
+    }
+
+    /// Ignore an Excel error or warning in a worksheet cell.
+    ///
+    /// Excel flags a number of data errors and inconsistencies with a a small
+    /// green triangle in the top left hand corner of the cell. For example the
+    /// following causes a warning of "Number Stored as Text":
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_ignore_error1.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Write a string that looks like a number.
+    ///     worksheet.write_string(1, 2, "123")?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_ignore_error1.png">
+    ///
+    /// These warnings can be useful indicators that there is an issue in the
+    /// spreadsheet but sometimes it is preferable to turn them off. At the file
+    /// level these errors can be ignored for a cell or cell range using
+    /// `Worksheet::ignore_error()` and [`Worksheet::ignore_error_range()`] (see
+    /// below).
+    ///
+    /// The errors and warnings that can be turned off at the file level are
+    /// represented by the [`IgnoreError`] enum values. These equate, with some
+    /// minor exceptions, to the error categories shown in the Excel Error
+    /// Checking dialog:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/ignore_errors_dialog.png">
+    ///
+    /// (Note: some of the items shown in the above dialog such as "Misleading
+    /// Number Formats" aren't saved in the output file by Excel and can't be
+    /// turned off permanently.)
+    ///
+    /// <br>
+    ///
+    /// The `Worksheet::ignore_error()` method can be called repeatedly to
+    /// ignore errors in different cells but **Excel only allows one ignored
+    /// error per cell**.
+    ///
+    /// An error can be turned off for a range of cells using the
+    /// [`Worksheet::ignore_error_range()`] method (see below).
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `error_type`: An [`IgnoreError`] enum value.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - Parameter error if more than one rule
+    ///   is added to the same cell.
+    ///
+    /// # Examples
+    ///
+    /// An example of turning off worksheet cells errors/warnings using using
+    /// the `rust_xlsxwriter` library.
+    ///
+    /// ```
+    /// # // This code is available in examples/app_ignore_errors.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, IgnoreError, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Create a format to use in descriptions.
+    /// #     let bold = Format::new().set_bold();
+    /// #
+    /// #     // Make the column wider for clarity.
+    /// #     worksheet.set_column_width(1, 16)?;
+    /// #
+    /// #     // Write some descriptions for the cells.
+    /// #     worksheet.write_with_format(1, 1, "Warning:", &bold)?;
+    /// #     worksheet.write_with_format(2, 1, "Warning turned off:", &bold)?;
+    /// #     worksheet.write_with_format(4, 1, "Warning:", &bold)?;
+    /// #     worksheet.write_with_format(5, 1, "Warning turned off:", &bold)?;
+    /// #
+    ///     // Write strings that looks like numbers. This will cause an Excel warning.
+    ///     worksheet.write_string(1, 2, "123")?;
+    ///     worksheet.write_string(2, 2, "123")?;
+    ///
+    ///     // Write a divide by zero formula. This will also cause an Excel warning.
+    ///     worksheet.write_formula(4, 2, "=1/0")?;
+    ///     worksheet.write_formula(5, 2, "=1/0")?;
+    ///
+    ///     // Turn off some of the warnings:
+    ///     worksheet.ignore_error(2, 2, IgnoreError::NumberStoredAsText)?;
+    ///     worksheet.ignore_error(5, 2, IgnoreError::FormulaError)?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("ignore_errors.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/app_ignore_errors.png">
+    ///
+    pub fn ignore_error(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        error_type: IgnoreError,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.ignore_error_range(row, col, row, col, error_type)
+    }
+
+    /// Ignore an Excel error or warning in a range of worksheet cells.
+    ///
+    /// See [`Worksheet::ignore_error()`] above for a detailed explanation of
+    /// Excel worksheet errors.
+    ///
+    /// The `Worksheet::ignore_error_range()` method can be used to ignore an
+    /// error in a range, a row, a column, or the entire worksheet and it can be
+    /// called repeatedly to ignore errors in different cells ranges. It should
+    /// be noted however that **Excel only allows one ignored error per cell**.
+    /// The `rust_xlsxwriter` library verifies that multiple rules aren't added
+    /// to the same cell or cell range and will raise an error if this occurs.
+    /// However it doesn't currently verify whether cells within ranges overlap.
+    /// It is up to the user to ensure that this doesn't happen when using
+    /// ranges.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    /// - `error_type`: An [`IgnoreError`] enum value.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    /// - [`XlsxError::ParameterError`] - Parameter error if more than one rule
+    ///   is added to the same range.
+    ///
+    pub fn ignore_error_range(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        error_type: IgnoreError,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(XlsxError::RowColumnOrderError);
+        }
+
+        let range = utility::cell_range(first_row, first_col, last_row, last_col);
+
+        // Check if the range was used previously. Note, that this doesn't
         // current check for range overlaps. One of the rules in an overlap will
         // be ignored since Excel only allows one rule per cell.
         if !self
@@ -14684,13 +17076,329 @@ impl Worksheet {
             )));
         }
 
-        // Store the range or append it to an existing range.
-        self.ignored_errors
-            .entry(error_type)
-            .and_modify(|sqref| *sqref = format!("{sqref} {range}"))
-            .or_insert(range);
+        // Store the range or append it to an existing range.
+        self.ignored_errors
+            .entry(error_type)
+            .and_modify(|sqref| *sqref = format!("{sqref} {range}"))
+            .or_insert(range);
+
+        Ok(self)
+    }
+
+    // -----------------------------------------------------------------------
+    // Worksheet render methods.
+    // -----------------------------------------------------------------------
+
+    /// Save the printed pages of a worksheet as a PDF file.
+    ///
+    /// The `save_to_pdf()` method renders the pages that Excel would print for
+    /// the worksheet to a PDF document. This can be used to create previews of
+    /// a worksheet without Excel or a conversion application such as
+    /// LibreOffice.
+    ///
+    /// The rendered pages contain the print area of the worksheet, or the cells
+    /// with data if a print area isn't set. The cell values are displayed using
+    /// their number formats along with the font, alignment, fill and border
+    /// formatting, merged ranges, column widths and row heights. The following
+    /// page setup properties are also supported:
+    ///
+    /// - [`Worksheet::set_paper_size()`].
+    /// - [`Worksheet::set_landscape()`] and [`Worksheet::set_portrait()`].
+    /// - [`Worksheet::set_margins()`].
+    /// - [`Worksheet::set_header()`] and [`Worksheet::set_footer()`].
+    /// - [`Worksheet::set_print_area()`].
+    /// - [`Worksheet::set_print_scale()`] and
+    ///   [`Worksheet::set_print_fit_to_pages()`].
+    /// - [`Worksheet::set_print_center_horizontally()`] and
+    ///   [`Worksheet::set_print_center_vertically()`].
+    /// - [`Worksheet::set_print_gridlines()`].
+    /// - [`Worksheet::set_page_order()`].
+    /// - [`Worksheet::set_page_breaks()`] and
+    ///   [`Worksheet::set_vertical_page_breaks()`].
+    /// - [`Worksheet::set_print_first_page_number()`].
+    ///
+    /// The output is an approximation of Excel's printed output and there are
+    /// some limitations:
+    ///
+    /// - Text is rendered with the standard PDF fonts so that font files don't
+    ///   need to be embedded. The cell fonts are mapped to the closest of
+    ///   Helvetica, Times or Courier. These fonts only support the Windows
+    ///   Latin 1 (WinAnsi) character set and text with other characters, such
+    ///   as CJK or Cyrillic text, returns an [`XlsxError::RenderError`] error.
+    /// - Formulas are displayed using their stored result, see
+    ///   [`Formula::set_result()`].
+    /// - Images, charts, shapes, notes, conditional formats and sparklines
+    ///   aren't rendered.
+    /// - Rendering to image formats such as PNG isn't supported.
+    ///
+    /// This method requires the `render` feature.
+    ///
+    /// # Parameters
+    ///
+    /// - `path`: The path of the new PDF file to create.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The worksheet uses constant memory
+    ///   mode so the cell data isn't available to render.
+    /// - [`XlsxError::RenderError`] - The text in the worksheet contains
+    ///   characters that aren't supported by the standard PDF fonts.
+    /// - [`XlsxError::IoError`] - A wrapper for various IO errors when creating
+    ///   the PDF file.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates saving a preview of a worksheet as a
+    /// PDF file.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_save_to_pdf.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, FormatBorder, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Add some formats.
+    ///     let header_format = Format::new()
+    ///         .set_bold()
+    ///         .set_border_bottom(FormatBorder::Thin);
+    ///     let currency_format = Format::new().set_num_format("$#,##0.00");
+    ///
+    ///     // Add some data to the worksheet.
+    ///     worksheet.write_with_format(0, 0, "Region", &header_format)?;
+    ///     worksheet.write_with_format(0, 1, "Sales", &header_format)?;
+    ///     worksheet.write(1, 0, "North")?;
+    ///     worksheet.write_with_format(1, 1, 12345.6, &currency_format)?;
+    ///     worksheet.write(2, 0, "South")?;
+    ///     worksheet.write_with_format(2, 1, 2345.75, &currency_format)?;
+    ///
+    ///     // Widen the columns to fit the data.
+    ///     worksheet.set_column_range_width(0, 1, 12)?;
+    ///
+    ///     // Set up the printed page.
+    ///     worksheet.set_paper_size(9);
+    ///     worksheet.set_header("&CSales report");
+    ///
+    ///     // Save a preview of the worksheet.
+    ///     worksheet.save_to_pdf("worksheet.pdf")?;
+    ///
+    ///     // Save the workbook.
+    ///     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "render")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "render")))]
+    pub fn save_to_pdf<P: AsRef<Path>>(&self, path: P) -> Result<(), XlsxError> {
+        let buffer = self.save_to_pdf_buffer()?;
+        std::fs::write(path, buffer)?;
+
+        Ok(())
+    }
+
+    /// Save the printed pages of a worksheet as a PDF document in a byte
+    /// vector.
+    ///
+    /// The `save_to_pdf_buffer()` method is similar to the
+    /// [`Worksheet::save_to_pdf()`] method except that it returns the PDF
+    /// document as a `Vec<u8>` buffer, for example to attach to an email.
+    ///
+    /// This method requires the `render` feature.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The worksheet uses constant memory
+    ///   mode so the cell data isn't available to render.
+    /// - [`XlsxError::RenderError`] - The text in the worksheet contains
+    ///   characters that aren't supported by the standard PDF fonts.
+    ///
+    #[cfg(feature = "render")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "render")))]
+    pub fn save_to_pdf_buffer(&self) -> Result<Vec<u8>, XlsxError> {
+        let render_sheet = self.render_sheet()?;
+
+        render::render_to_pdf(&[render_sheet])
+    }
+
+    // Collect the cell data, formats and page setup properties required to
+    // render the printed pages of the worksheet.
+    #[cfg(feature = "render")]
+    pub(crate) fn render_sheet(&self) -> Result<RenderSheet, XlsxError> {
+        if self.use_constant_memory {
+            return Err(XlsxError::ParameterError(
+                "Worksheets in constant memory mode can't be rendered.".to_string(),
+            ));
+        }
+
+        let mut render_sheet = RenderSheet {
+            name: self.name.clone(),
+            page_setup: RenderPageSetup {
+                paper_size: self.paper_size,
+                is_portrait: self.is_portrait,
+                margin_left: self.margin_left,
+                margin_right: self.margin_right,
+                margin_top: self.margin_top,
+                margin_bottom: self.margin_bottom,
+                margin_header: self.margin_header,
+                margin_footer: self.margin_footer,
+                header: self.header.clone(),
+                footer: self.footer.clone(),
+                print_scale: self.print_scale,
+                fit_to_page: self.fit_to_page,
+                fit_width: self.fit_width,
+                fit_height: self.fit_height,
+                center_horizontally: self.center_horizontally,
+                center_vertically: self.center_vertically,
+                print_gridlines: self.print_gridlines,
+                down_then_over: self.default_page_order,
+                horizontal_breaks: self.horizontal_breaks.clone(),
+                vertical_breaks: self.vertical_breaks.clone(),
+                first_page_number: self.first_page_number,
+            },
+            ..RenderSheet::default()
+        };
+
+        // Get the print area or, if it isn't set, the range from A1 to the last
+        // cell with data. Print areas of complete rows or columns are limited
+        // to the cells with data.
+        let range = if self.print_area_defined_name.in_use {
+            let print_area = &self.print_area_defined_name;
+            let mut last_row = print_area.last_row;
+            let mut last_col = print_area.last_col;
+
+            if self.dimensions.first_row != ROW_MAX {
+                if last_row == ROW_MAX - 1 {
+                    last_row = self.dimensions.last_row.max(print_area.first_row);
+                }
+                if last_col == COL_MAX - 1 {
+                    last_col = self.dimensions.last_col.max(print_area.first_col);
+                }
+            }
+
+            Some((
+                print_area.first_row,
+                print_area.first_col,
+                last_row,
+                last_col,
+            ))
+        } else if self.dimensions.first_row == ROW_MAX {
+            None
+        } else {
+            Some((0, 0, self.dimensions.last_row, self.dimensions.last_col))
+        };
+
+        let Some((first_row, first_col, last_row, last_col)) = range else {
+            return Ok(render_sheet);
+        };
+
+        // Get the formats indexed by the cell format indices.
+        let mut formats = self.cell_formats();
+        if formats.is_empty() {
+            formats.push(Format::default());
+        }
+
+        for (row, columns) in self.data_table.range(first_row..=last_row) {
+            for (col, cell) in columns.range(first_col..=last_col) {
+                let (value, xf_index) = match cell {
+                    CellType::Number { number, xf_index }
+                    | CellType::DateTime { number, xf_index } => {
+                        (Some(RenderValue::Number(*number)), xf_index)
+                    }
+                    CellType::String {
+                        string, xf_index, ..
+                    }
+                    | CellType::RichString {
+                        raw_string: string,
+                        xf_index,
+                        ..
+                    }
+                    | CellType::InlineString { string, xf_index } => {
+                        (Some(RenderValue::String(string.to_string())), xf_index)
+                    }
+                    CellType::Boolean { boolean, xf_index } => {
+                        (Some(RenderValue::Boolean(*boolean)), xf_index)
+                    }
+                    CellType::Formula {
+                        result, xf_index, ..
+                    }
+                    | CellType::ArrayFormula {
+                        result, xf_index, ..
+                    } => (Self::render_formula_result(result), xf_index),
+                    CellType::Blank { xf_index } | CellType::Error { xf_index, .. } => {
+                        (None, xf_index)
+                    }
+                };
+
+                let format_index = if (*xf_index as usize) < formats.len() {
+                    *xf_index as usize
+                } else {
+                    0
+                };
+
+                render_sheet.cells.insert(
+                    (*row, *col),
+                    RenderCell {
+                        value,
+                        format_index,
+                    },
+                );
+            }
+        }
+
+        render_sheet.formats = formats;
+        render_sheet.range = Some((first_row, first_col, last_row, last_col));
 
-        Ok(self)
+        render_sheet.col_widths = (first_col..=last_col)
+            .map(|col| {
+                f64::from(self.column_pixel_width(col, ObjectMovement::MoveAndSizeWithCells)) * 0.75
+            })
+            .collect();
+
+        render_sheet.row_heights = (first_row..=last_row)
+            .map(|row| match self.changed_rows.get(&row) {
+                Some(row_options) if row_options.hidden => 0.0,
+                Some(row_options) => row_options.height,
+                None => self.user_default_row_height,
+            })
+            .collect();
+
+        render_sheet.merged_ranges = self
+            .merged_ranges
+            .iter()
+            .map(|range| {
+                (
+                    range.first_row,
+                    range.first_col,
+                    range.last_row,
+                    range.last_col,
+                )
+            })
+            .collect();
+
+        Ok(render_sheet)
+    }
+
+    // Convert a stored formula result to a value to render. Numeric and
+    // boolean results are converted from their string representation.
+    #[cfg(feature = "render")]
+    fn render_formula_result(result: &str) -> Option<RenderValue> {
+        if result.is_empty() {
+            return None;
+        }
+
+        if let Ok(number) = result.parse::<f64>() {
+            return Some(RenderValue::Number(number));
+        }
+
+        match result {
+            "TRUE" => Some(RenderValue::Boolean(true)),
+            "FALSE" => Some(RenderValue::Boolean(false)),
+            _ => Some(RenderValue::String(result.to_string())),
+        }
     }
 
     // -----------------------------------------------------------------------
@@ -14997,6 +17705,8 @@ impl Worksheet {
         format: Option<&Format>,
         is_datetime: bool,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check row and col are in the allowed range.
         if !self.check_dimensions(row, col) {
             return Err(XlsxError::RowColumnLimitError);
@@ -15042,6 +17752,8 @@ impl Worksheet {
         string: String,
         format: Option<&Format>,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Empty strings are ignored by Excel unless they have a format in which
         // case they are treated as a blank cell.
         if string.is_empty() {
@@ -15107,6 +17819,8 @@ impl Worksheet {
         raw_string: &str,
         format: Option<&Format>,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Empty strings are ignored by Excel unless they have a format in which
         // case they are treated as a blank cell.
         if string.is_empty() {
@@ -15166,6 +17880,8 @@ impl Worksheet {
         formula: Formula,
         format: Option<&Format>,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Transfer to dynamic formula handling function.
         if formula.has_dynamic_function {
             return self.store_array_formula(row, col, row, col, formula, None, true);
@@ -15213,6 +17929,8 @@ impl Worksheet {
         format: Option<&Format>,
         is_dynamic: bool,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check rows and cols are in the allowed range.
         if !self.check_dimensions(first_row, first_col)
             || !self.check_dimensions(last_row, last_col)
@@ -15292,6 +18010,8 @@ impl Worksheet {
         col: ColNum,
         format: &Format,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check row and col are in the allowed range.
         if !self.check_dimensions(row, col) {
             return Err(XlsxError::RowColumnLimitError);
@@ -15322,6 +18042,8 @@ impl Worksheet {
         boolean: bool,
         format: Option<&Format>,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check row and col are in the allowed range.
         if !self.check_dimensions(row, col) {
             return Err(XlsxError::RowColumnLimitError);
@@ -15352,6 +18074,8 @@ impl Worksheet {
         url: &Url,
         format: Option<&Format>,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         let mut hyperlink = url.clone();
         hyperlink.initialize()?;
 
@@ -15376,6 +18100,8 @@ impl Worksheet {
         image: &Image,
         format: Option<&Format>,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check row and columns are in the allowed range.
         if !self.check_dimensions(row, col) {
             return Err(XlsxError::RowColumnLimitError);
@@ -15633,6 +18359,193 @@ impl Worksheet {
         }
     }
 
+    // Get the index of a worksheet table from its name.
+    fn table_index_from_name(&self, name: &str) -> Result<usize, XlsxError> {
+        self.tables
+            .iter()
+            .position(|table| table.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| {
+                XlsxError::TableError(format!("Table name '{name}' not found in worksheet"))
+            })
+    }
+
+    // Change the last row of a worksheet table, moving the total row and
+    // extending any column formulas and formats to new data rows.
+    fn resize_table_rows(&mut self, index: usize, last_row: RowNum) -> Result<(), XlsxError> {
+        let mut table = self.tables[index].clone();
+        let first_col = table.cell_range.first_col;
+        let last_col = table.cell_range.last_col;
+        let old_last_row = table.cell_range.last_row;
+        let old_last_data_row = table.last_data_row();
+
+        if last_row == old_last_row {
+            return Ok(());
+        }
+
+        if !self.check_dimensions_only(last_row, last_col) {
+            return Err(XlsxError::RowColumnLimitError);
+        }
+
+        // Check that the table has at least one row after the header.
+        if last_row < table.first_data_row() {
+            return Err(XlsxError::TableError(
+                "Table must have at least one row".to_string(),
+            ));
+        }
+
+        // Check if the new rows overlap any other table range.
+        let mut cell_range = table.cell_range.clone();
+        cell_range.last_row = last_row;
+        for row in old_last_row + 1..=last_row {
+            for col in first_col..=last_col {
+                if let Some(other_index) = self.table_cells.get(&(row, col)) {
+                    let previous_cell_range = &self.table_ranges[*other_index];
+                    return Err(XlsxError::TableRangeOverlaps(
+                        cell_range.to_error_string(),
+                        previous_cell_range.to_error_string(),
+                    ));
+                }
+            }
+        }
+
+        // Clear the previous total row.
+        if table.show_total_row {
+            for (offset, column) in table.columns.iter().enumerate() {
+                if !column.total_label.is_empty() || column.total_function != TableFunction::None {
+                    self.clear_table_cell(old_last_row, first_col + offset as ColNum);
+                }
+            }
+        }
+
+        // Clear the column formulas and formats from any data rows that are
+        // no longer part of the table, including the new total row.
+        let first_removed_row = if table.show_total_row {
+            last_row
+        } else {
+            last_row + 1
+        };
+
+        for row in first_removed_row..=old_last_data_row {
+            for (offset, column) in table.columns.iter().enumerate() {
+                let col = first_col + offset as ColNum;
+
+                if column.formula.is_some() {
+                    self.clear_table_cell(row, col);
+                } else if column.format.is_some() {
+                    self.clear_cell_format_internal(row, col);
+                }
+            }
+        }
+
+        // Update the cells owned by the table.
+        for row in old_last_row + 1..=last_row {
+            for col in first_col..=last_col {
+                self.table_cells.insert((row, col), index);
+            }
+        }
+        for row in last_row + 1..=old_last_row {
+            for col in first_col..=last_col {
+                self.table_cells.remove(&(row, col));
+            }
+        }
+
+        table.cell_range = cell_range.clone();
+        self.write_table_column_data(&table, old_last_data_row + 1, table.last_data_row())?;
+
+        self.table_ranges[index] = cell_range;
+        self.tables[index] = table;
+
+        Ok(())
+    }
+
+    // Write the total row of a table and the column formulas and formats for a
+    // range of its data rows.
+    fn write_table_column_data(
+        &mut self,
+        table: &Table,
+        first_data_row: RowNum,
+        last_data_row: RowNum,
+    ) -> Result<(), XlsxError> {
+        let first_col = table.cell_range.first_col;
+        let last_row = table.cell_range.last_row;
+
+        // In constant memory mode add any formulas or totals to the
+        // write-ahead buffer.
+        self.set_writing_ahead(true);
+
+        for (offset, column) in table.columns.iter().enumerate() {
+            let col = first_col + offset as u16;
+
+            // Get a copy of the column format or use the default format. This
+            // is mainly to work around constant memory cases which can't use the
+            // update_cell_format() approach below.
+            let col_format = match &column.format {
+                Some(format) => format.clone(),
+                None => Format::default(),
+            };
+
+            // Write the total row strings or formulas.
+            if table.show_total_row {
+                if !column.total_label.is_empty() {
+                    self.write_string_with_format(last_row, col, &column.total_label, &col_format)?;
+                } else if column.total_function != TableFunction::None {
+                    let formula = column.total_function();
+                    self.write_formula_with_format(last_row, col, formula, &col_format)?;
+                }
+            }
+
+            // Write the column formula as worksheet formulas.
+            if let Some(formula) = &column.formula {
+                for row in first_data_row..=last_data_row {
+                    self.write_formula_with_format(row, col, formula, &col_format)?;
+                }
+            }
+
+            // Set the column format for any existing cells.
+            if let Some(format) = &column.format {
+                let format_index = self.format_xf_index(format);
+                for row in first_data_row..=last_data_row {
+                    self.update_cell_format(row, col, format_index);
+                }
+
+                if table.show_total_row && column.total_function != TableFunction::None {
+                    self.update_cell_format(last_row, col, format_index);
+                }
+            }
+        }
+
+        // Stop writing ahead in constant memory mode.
+        self.set_writing_ahead(false);
+
+        Ok(())
+    }
+
+    // Clear a table cell that may also be in the constant memory write-ahead
+    // buffer, such as a total row cell.
+    fn clear_table_cell(&mut self, row: RowNum, col: ColNum) {
+        let cell = self
+            .data_table
+            .get_mut(&row)
+            .and_then(|columns| columns.remove(&col));
+
+        #[cfg(feature = "constant_memory")]
+        let cell = cell.or_else(|| {
+            self.write_ahead
+                .get_mut(&row)
+                .and_then(|columns| columns.remove(&col))
+        });
+
+        // Strings that have already been added to the workbook shared string
+        // table need to be removed from the total string count.
+        if let Some(CellType::String {
+            string_id: Some(_), ..
+        }) = cell
+        {
+            let mut string_table = self.string_table.lock().unwrap();
+            string_table.count -= 1;
+        }
+    }
+
     // Clear the data and formatting from a worksheet cell. Ignores non-existing
     // cells.
     fn clear_cell_internal(&mut self, row: RowNum, col: ColNum) {
@@ -15876,6 +18789,8 @@ impl Worksheet {
         width: impl Into<f64>,
         autofit: bool,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         let width = width.into();
 
         // If the width is 0 then the Excel treats the column as hidden with
@@ -15935,6 +18850,19 @@ impl Worksheet {
         }
     }
 
+    // Check that a cell or row/column level method isn't being called on a
+    // chartsheet, since chartsheets don't have a cell grid.
+    fn check_chartsheet(&self) -> Result<(), XlsxError> {
+        if self.is_chartsheet {
+            return Err(XlsxError::ChartsheetError(format!(
+                "Chartsheet '{}' doesn't support cell, row or column data or formatting",
+                self.name
+            )));
+        }
+
+        Ok(())
+    }
+
     // Check that row and col are within the allowed Excel range and store max
     // and min values for use in other methods/elements.
     fn check_dimensions(&mut self, row: RowNum, col: ColNum) -> bool {
@@ -16117,6 +19045,39 @@ impl Worksheet {
         self.global_dxf_indices = workbook_dxf_indices.to_vec();
     }
 
+    // Replace the format of Excel 2010 conditional formats, which write the
+    // format inline in the rule, with the prepared workbook dxf format. The
+    // local dxf index of the format is retained.
+    pub(crate) fn set_global_dxf_formats(&mut self, workbook_dxf_formats: &[Format]) {
+        if !self.has_x14_conditional_formats {
+            return;
+        }
+
+        for conditional_formats in self.conditional_formats.values_mut() {
+            for conditional_format in conditional_formats
+                .iter_mut()
+                .filter(|conditional_format| conditional_format.has_x14_only())
+            {
+                let Some(local_index) = conditional_format.format_index() else {
+                    continue;
+                };
+
+                let Some(dxf_format) = self
+                    .global_dxf_indices
+                    .get(local_index as usize)
+                    .and_then(|global_index| workbook_dxf_formats.get(*global_index as usize))
+                else {
+                    continue;
+                };
+
+                if let Some(format) = conditional_format.format_as_mut() {
+                    *format = dxf_format.clone();
+                    format.dxf_index = local_index;
+                }
+            }
+        }
+    }
+
     // Translate the cell xf_index into a global/workbook format index. We also
     // need to make sure that an unformatted cell (xf_index == 0) takes the row
     // format (if it exists) or, failing that, the column format (if that
@@ -16166,84 +19127,248 @@ impl Worksheet {
             let col = cell.1;
             let mut drawing_hyperlink = None;
 
-            let image_id = match image_ids.get(&image.hash) {
-                Some(image_id) => *image_id,
-                None => {
-                    *image_id += 1;
-                    image_ids.insert(image.hash.clone(), *image_id);
-                    *image_id
+            // Handle optional hyperlink in the image.
+            if let Some(hyperlink) = &image.url {
+                drawing_hyperlink = Some(self.prepare_drawing_hyperlink(hyperlink));
+            }
+
+            // Store the image references.
+            let rel_id = self.prepare_drawing_image(image, image_ids, image_id);
+
+            // Convert the image dimensions to drawing dimensions and store the
+            // drawing object.
+            let mut drawing_info = self.position_object_emus(row, col, image);
+            drawing_info.rel_id = rel_id;
+            drawing_info.url.clone_from(&drawing_hyperlink);
+            self.drawing.drawings.push(drawing_info);
+        }
+
+        // Store the linkage to the worksheets rels file.
+        let drawing_name = format!("../drawings/drawing{drawing_id}.xml");
+        self.drawing_object_relationships.push((
+            "drawing".to_string(),
+            drawing_name,
+            String::new(),
+        ));
+
+        self.has_drawing_object_linkage = true;
+    }
+
+    // Set the drawing rel linkage for a hyperlink in an image or shape.
+    fn prepare_drawing_hyperlink(&mut self, hyperlink: &Url) -> Url {
+        let mut hyperlink = hyperlink.clone();
+
+        let target = hyperlink.target();
+        let target_mode = hyperlink.target_mode();
+
+        let rel_id = match self.drawing_rel_ids.get(&hyperlink.link) {
+            Some(rel_id) => *rel_id,
+            None => {
+                let rel_id = 1 + self.drawing_relationships.len() as u32;
+                self.drawing_rel_ids.insert(hyperlink.link.clone(), rel_id);
+
+                // Store the linkage to the drawings rels file.
+                self.drawing_relationships
+                    .push(("hyperlink".to_string(), target, target_mode));
+
+                rel_id
+            }
+        };
+
+        hyperlink.rel_id = rel_id;
+        hyperlink
+    }
+
+    // Set the drawing rel linkage for an image and return the rel id. Images
+    // are only stored once per workbook, and per drawing, based on their hash.
+    fn prepare_drawing_image(
+        &mut self,
+        image: &Image,
+        image_ids: &mut HashMap<String, u32>,
+        image_id: &mut u32,
+    ) -> u32 {
+        let image_id = match image_ids.get(&image.hash) {
+            Some(image_id) => *image_id,
+            None => {
+                *image_id += 1;
+                image_ids.insert(image.hash.clone(), *image_id);
+                *image_id
+            }
+        };
+
+        // Store the used image type for the Content Type file.
+        self.image_types[image.image_type.clone() as usize] = true;
+
+        match self.drawing_rel_ids.get(&image.hash) {
+            Some(rel_id) => *rel_id,
+            None => {
+                let rel_id = 1 + self.drawing_relationships.len() as u32;
+                self.drawing_rel_ids.insert(image.hash.clone(), rel_id);
+
+                // Store the linkage to the drawings rels file.
+                let image_name =
+                    format!("../media/image{image_id}.{}", image.image_type.extension());
+                self.drawing_relationships
+                    .push(("image".to_string(), image_name, String::new()));
+
+                rel_id
+            }
+        }
+    }
+
+    // Convert the drawing groups and their objects into drawing dimensions and
+    // add them to the Drawing object. Also set the rel linkages between the
+    // files.
+    pub(crate) fn prepare_worksheet_drawing_groups(
+        &mut self,
+        image_ids: &mut HashMap<String, u32>,
+        image_id: &mut u32,
+        shape_id: &mut u32,
+        drawing_id: u32,
+    ) {
+        for (cell, group) in &self.drawing_groups.clone() {
+            let mut group_info = self.position_object_emus(cell.0, cell.1, group);
+            group_info.rel_id = *shape_id;
+            *shape_id += 1;
+
+            let mut objects = vec![];
+            for object in &group.objects {
+                // Grouped objects are positioned absolutely, relative to the
+                // top left of the group.
+                let mut drawing_info = match object {
+                    DrawingGroupObject::Image(image) => {
+                        let mut drawing_info = Self::group_object_info(image, &group_info);
+                        drawing_info.rel_id =
+                            self.prepare_drawing_image(image, image_ids, image_id);
+                        drawing_info.url = image.url.clone();
+                        drawing_info
+                    }
+                    DrawingGroupObject::Shape(shape) => {
+                        let mut drawing_info = Self::group_object_info(shape, &group_info);
+                        drawing_info.rel_id = *shape_id;
+                        drawing_info.url = shape.url.clone();
+
+                        let shape = self.prepare_shape_picture_fill(shape, image_ids, image_id);
+                        self.drawing.shapes.push(shape);
+                        *shape_id += 1;
+                        drawing_info
+                    }
+                    DrawingGroupObject::Chart(chart) => {
+                        // Store the linkage to the charts rels file.
+                        let chart_name = format!("../charts/chart{}.xml", chart.id);
+                        self.drawing_relationships.push((
+                            "chart".to_string(),
+                            chart_name,
+                            String::new(),
+                        ));
+
+                        let mut drawing_info = Self::group_object_info(chart, &group_info);
+                        drawing_info.rel_id = self.drawing_relationships.len() as u32;
+                        drawing_info
+                    }
+                };
+
+                if let Some(hyperlink) = &drawing_info.url {
+                    drawing_info.url = Some(self.prepare_drawing_hyperlink(hyperlink));
                 }
-            };
 
-            // Handle optional hyperlink in the image.
-            if let Some(hyperlink) = &image.url {
-                let mut hyperlink = hyperlink.clone();
+                objects.push(drawing_info);
+            }
+
+            self.drawing.drawings.push(group_info);
+            self.drawing.groups.push(objects);
+        }
+
+        // Store the linkage to the worksheets rels file.
+        if self.drawing_object_relationships.is_empty() {
+            let drawing_name = format!("../drawings/drawing{drawing_id}.xml");
+            self.drawing_object_relationships.push((
+                "drawing".to_string(),
+                drawing_name,
+                String::new(),
+            ));
+
+            self.has_drawing_object_linkage = true;
+        }
+    }
 
-                let target = hyperlink.target();
-                let target_mode = hyperlink.target_mode();
+    // Set the rel linkage for the image in a shape picture fill, if there is
+    // one, and return the shape that will be written to the drawing.
+    fn prepare_shape_picture_fill(
+        &mut self,
+        shape: &Shape,
+        image_ids: &mut HashMap<String, u32>,
+        image_id: &mut u32,
+    ) -> Shape {
+        let mut shape = shape.clone();
 
-                let rel_id = match self.drawing_rel_ids.get(&hyperlink.link) {
-                    Some(rel_id) => *rel_id,
-                    None => {
-                        let rel_id = 1 + self.drawing_rel_ids.len() as u32;
-                        self.drawing_rel_ids.insert(hyperlink.link.clone(), rel_id);
+        if let Some(fill) = &mut shape.format.picture_fill {
+            fill.rel_id = self.prepare_drawing_image(&fill.image, image_ids, image_id);
+        }
 
-                        // Store the linkage to the drawings rels file.
-                        self.drawing_relationships.push((
-                            "hyperlink".to_string(),
-                            target,
-                            target_mode,
-                        ));
+        shape
+    }
 
-                        rel_id
-                    }
-                };
+    // Get the worksheet charts, including charts in drawing groups, in the
+    // order that their chart ids are assigned.
+    pub(crate) fn all_charts(&self) -> impl Iterator<Item = &Chart> {
+        self.charts
+            .values()
+            .chain(self.drawing_groups.values().flat_map(DrawingGroup::charts))
+    }
 
-                hyperlink.rel_id = rel_id;
+    // Get mutable references to the worksheet charts, including charts in
+    // drawing groups, in the order that their chart ids are assigned.
+    pub(crate) fn all_charts_mut(&mut self) -> impl Iterator<Item = &mut Chart> {
+        self.charts.values_mut().chain(
+            self.drawing_groups
+                .values_mut()
+                .flat_map(DrawingGroup::charts_mut),
+        )
+    }
 
-                drawing_hyperlink = Some(hyperlink);
-            }
+    // Get the images used in the worksheet drawing objects in the order that
+    // their image ids are assigned when the drawings are prepared.
+    pub(crate) fn drawing_images(&self) -> Vec<&Image> {
+        let mut images: Vec<&Image> = self.images.values().collect();
 
-            // Store the image references.
-            let rel_id = match self.drawing_rel_ids.get(&image.hash) {
-                Some(rel_id) => *rel_id,
-                None => {
-                    let rel_id = 1 + self.drawing_rel_ids.len() as u32;
-                    self.drawing_rel_ids.insert(image.hash.clone(), rel_id);
+        for chart in self.all_charts() {
+            images.extend(chart.picture_fill_images());
+        }
 
-                    // Store the linkage to the drawings rels file.
-                    let image_name =
-                        format!("../media/image{image_id}.{}", image.image_type.extension());
-                    self.drawing_relationships.push((
-                        "image".to_string(),
-                        image_name,
-                        String::new(),
-                    ));
+        images.extend(self.shapes.values().filter_map(Shape::picture_fill_image));
 
-                    rel_id
+        for group in self.drawing_groups.values() {
+            for object in &group.objects {
+                match object {
+                    DrawingGroupObject::Image(image) => images.push(image),
+                    DrawingGroupObject::Shape(shape) => images.extend(shape.picture_fill_image()),
+                    DrawingGroupObject::Chart(_) => {}
                 }
-            };
-
-            // Convert the image dimensions to drawing dimensions and store the
-            // drawing object.
-            let mut drawing_info = self.position_object_emus(row, col, image);
-            drawing_info.rel_id = rel_id;
-            drawing_info.url.clone_from(&drawing_hyperlink);
-            self.drawing.drawings.push(drawing_info);
-
-            // Store the used image type for the Content Type file.
-            self.image_types[image.image_type.clone() as usize] = true;
+            }
         }
 
-        // Store the linkage to the worksheets rels file.
-        let drawing_name = format!("../drawings/drawing{drawing_id}.xml");
-        self.drawing_object_relationships.push((
-            "drawing".to_string(),
-            drawing_name,
-            String::new(),
-        ));
+        images
+    }
 
-        self.has_drawing_object_linkage = true;
+    // Get the drawing information for an object in a group, in EMUs, from its
+    // pixel offset relative to the group.
+    fn group_object_info<T>(object: &T, group_info: &DrawingInfo) -> DrawingInfo
+    where
+        T: DrawingObject,
+    {
+        DrawingInfo {
+            col_absolute: group_info.col_absolute + u64::from(object.x_offset()) * 9525,
+            row_absolute: group_info.row_absolute + u64::from(object.y_offset()) * 9525,
+            width: round_to_emus(object.width_scaled()),
+            height: round_to_emus(object.height_scaled()),
+            name: object.name(),
+            description: object.alt_text(),
+            decorative: object.decorative(),
+            object_movement: group_info.object_movement,
+            drawing_type: object.drawing_type(),
+            ..DrawingInfo::default()
+        }
     }
 
     // Set the relationship for the background image.
@@ -16258,9 +19383,18 @@ impl Worksheet {
 
     // Convert the shape dimensions into drawing dimensions and add them to
     // the Drawing object. Also set the rel linkages between the files.
-    pub(crate) fn prepare_worksheet_shapes(&mut self, shape_id: u32, drawing_id: u32) {
+    pub(crate) fn prepare_worksheet_shapes(
+        &mut self,
+        image_ids: &mut HashMap<String, u32>,
+        image_id: &mut u32,
+        shape_id: u32,
+        drawing_id: u32,
+    ) {
         let mut shape_id = shape_id;
 
+        // Store the drawing <xdr:cNvPr> id of each shape for use by connectors.
+        let mut drawing_ids = HashMap::new();
+
         for (cell, shape) in &self.shapes.clone() {
             let row = cell.0;
             let col = cell.1;
@@ -16268,40 +19402,49 @@ impl Worksheet {
 
             // Handle optional hyperlink in the shape.
             if let Some(hyperlink) = &shape.url {
-                let mut hyperlink = hyperlink.clone();
+                drawing_hyperlink = Some(self.prepare_drawing_hyperlink(hyperlink));
+            }
 
-                let target = hyperlink.target();
-                let target_mode = hyperlink.target_mode();
+            // Convert the shape dimensions to drawing dimensions and store
+            // the drawing object.
+            let mut drawing_info = self.position_object_emus(row, col, shape);
+            drawing_info.rel_id = shape_id;
+            drawing_info.url.clone_from(&drawing_hyperlink);
 
-                let rel_id = match self.drawing_rel_ids.get(&hyperlink.link) {
-                    Some(rel_id) => *rel_id,
-                    None => {
-                        let rel_id = 1 + self.drawing_rel_ids.len() as u32;
-                        self.drawing_rel_ids.insert(hyperlink.link.clone(), rel_id);
+            // The drawing id is the 1-based index of the drawing object plus 1.
+            drawing_ids.insert(*cell, self.drawing.drawings.len() as u32 + 2);
 
-                        // Store the linkage to the drawings rels file.
-                        self.drawing_relationships.push((
-                            "hyperlink".to_string(),
-                            target,
-                            target_mode,
-                        ));
+            let shape = self.prepare_shape_picture_fill(shape, image_ids, image_id);
 
-                        rel_id
-                    }
-                };
+            self.drawing.drawings.push(drawing_info);
+            self.drawing.shapes.push(shape);
 
-                hyperlink.rel_id = rel_id;
+            shape_id += 1;
+        }
 
-                drawing_hyperlink = Some(hyperlink);
-            }
+        // Position the connectors between the shapes that they join.
+        for (start_row, start_col, end_row, end_col, connector) in &self.shape_connectors.clone() {
+            let (Some(start_key), Some(end_key)) = (
+                self.shape_key_at_cell(*start_row, *start_col),
+                self.shape_key_at_cell(*end_row, *end_col),
+            ) else {
+                continue;
+            };
 
-            // Convert the shape dimensions to drawing dimensions and store
-            // the drawing object.
-            let mut drawing_info = self.position_object_emus(row, col, shape);
+            let connector = self.prepare_connector(
+                connector,
+                &self.shapes[&start_key].clone(),
+                &self.shapes[&end_key].clone(),
+                (start_key.0, start_key.1),
+                (end_key.0, end_key.1),
+                drawing_ids[&start_key],
+                drawing_ids[&end_key],
+            );
+
+            let mut drawing_info = self.position_object_emus(0, 0, &connector);
             drawing_info.rel_id = shape_id;
-            drawing_info.url.clone_from(&drawing_hyperlink);
             self.drawing.drawings.push(drawing_info);
-            self.drawing.shapes.push(shape.clone());
+            self.drawing.shapes.push(connector);
 
             shape_id += 1;
         }
@@ -16476,12 +19619,62 @@ impl Worksheet {
 
     // Convert the chart dimensions into drawing dimensions and add them to the
     // Drawing object. Also set the rel linkages between the files.
-    pub(crate) fn prepare_worksheet_charts(&mut self, chart_id: u32, drawing_id: u32) {
+    pub(crate) fn prepare_worksheet_charts(
+        &mut self,
+        image_ids: &mut HashMap<String, u32>,
+        image_id: &mut u32,
+        chart_id: u32,
+        drawing_id: u32,
+    ) {
+        // Charts in drawing groups are numbered after the worksheet charts.
+        // Note, the charts are borrowed by field since the loop also updates
+        // the worksheet image types.
+        let charts = self.charts.values_mut().chain(
+            self.drawing_groups
+                .values_mut()
+                .flat_map(DrawingGroup::charts_mut),
+        );
+
         let mut chart_id = chart_id;
-        for chart in self.charts.values_mut() {
+        for chart in charts {
             chart.id = chart_id;
             chart.add_axis_ids(chart_id);
             chart_id += 1;
+
+            // Set the chart rel linkages for any picture fill images. These
+            // follow the chart shapes drawing linkage, if there is one.
+            chart.drawing_rel_ids.clear();
+            chart.drawing_relationships.clear();
+            let mut rel_id = u32::from(!chart.shapes.is_empty());
+
+            let images: Vec<Image> = chart.picture_fill_images().into_iter().cloned().collect();
+
+            for image in &images {
+                if chart.drawing_rel_ids.contains_key(&image.hash) {
+                    continue;
+                }
+
+                let image_id = match image_ids.get(&image.hash) {
+                    Some(image_id) => *image_id,
+                    None => {
+                        *image_id += 1;
+                        image_ids.insert(image.hash.clone(), *image_id);
+                        *image_id
+                    }
+                };
+
+                // Store the used image type for the Content Type file.
+                self.image_types[image.image_type.clone() as usize] = true;
+
+                rel_id += 1;
+                chart.drawing_rel_ids.insert(image.hash.clone(), rel_id);
+
+                let image_name =
+                    format!("../media/image{image_id}.{}", image.image_type.extension());
+                chart
+                    .drawing_relationships
+                    .push(("image".to_string(), image_name, String::new()));
+            }
         }
 
         let mut rel_id = self.drawing_relationships.len() as u32;
@@ -16559,6 +19752,127 @@ impl Worksheet {
         table_id
     }
 
+    // Get the key of the first shape inserted in a cell, if any.
+    fn shape_key_at_cell(&self, row: RowNum, col: ColNum) -> Option<(RowNum, ColNum, u32, u32)> {
+        self.shapes
+            .range((row, col, 0, 0)..=(row, col, u32::MAX, u32::MAX))
+            .next()
+            .map(|(key, _)| *key)
+    }
+
+    // Calculate the position and orientation of a connector that joins two
+    // shapes. The connector is returned as a copy of the user shape, with an
+    // absolute pixel offset from cell A1, that spans the two connection sites.
+    #[allow(clippy::too_many_arguments)]
+    fn prepare_connector(
+        &mut self,
+        connector: &Shape,
+        start_shape: &Shape,
+        end_shape: &Shape,
+        start_cell: (RowNum, ColNum),
+        end_cell: (RowNum, ColNum),
+        start_id: u32,
+        end_id: u32,
+    ) -> Shape {
+        let start = self.position_object_pixels(start_cell.0, start_cell.1, start_shape);
+        let end = self.position_object_pixels(end_cell.0, end_cell.1, end_shape);
+
+        // Get the center of each shape.
+        let start_center = (
+            start.col_absolute as f64 + start.width / 2.0,
+            start.row_absolute as f64 + start.height / 2.0,
+        );
+        let end_center = (
+            end.col_absolute as f64 + end.width / 2.0,
+            end.row_absolute as f64 + end.height / 2.0,
+        );
+
+        // Choose the connection sites automatically, if required, based on
+        // the larger of the horizontal or vertical distance between shapes.
+        let dx = end_center.0 - start_center.0;
+        let dy = end_center.1 - start_center.1;
+        let automatic_sites = if dx.abs() >= dy.abs() {
+            if dx >= 0.0 {
+                (ShapeConnectionSite::Right, ShapeConnectionSite::Left)
+            } else {
+                (ShapeConnectionSite::Left, ShapeConnectionSite::Right)
+            }
+        } else if dy >= 0.0 {
+            (ShapeConnectionSite::Bottom, ShapeConnectionSite::Top)
+        } else {
+            (ShapeConnectionSite::Top, ShapeConnectionSite::Bottom)
+        };
+
+        let start_site = match connector.start_connection_site {
+            ShapeConnectionSite::Automatic => automatic_sites.0,
+            site => site,
+        };
+        let end_site = match connector.end_connection_site {
+            ShapeConnectionSite::Automatic => automatic_sites.1,
+            site => site,
+        };
+
+        let (start_x, start_y) = Self::connection_site_point(&start, start_site);
+        let (end_x, end_y) = Self::connection_site_point(&end, end_site);
+
+        let is_vertical_site =
+            |site| matches!(site, ShapeConnectionSite::Top | ShapeConnectionSite::Bottom);
+
+        // Elbow and curved connectors that start from the top or bottom of a
+        // shape are rotated 90 degrees so that the first segment is vertical.
+        let rotated =
+            connector.shape_type != ShapeType::StraightConnector && is_vertical_site(start_site);
+
+        let (flip_h, flip_v) = if rotated {
+            (end_y < start_y, end_x > start_x)
+        } else {
+            (end_x < start_x, end_y < start_y)
+        };
+
+        // Connectors between a horizontal and a vertical site only have one
+        // bend so they use the 2 segment version of the preset geometry.
+        let single_bend = is_vertical_site(start_site) != is_vertical_site(end_site);
+        let preset = match connector.shape_type {
+            ShapeType::ElbowConnector if single_bend => "bentConnector2",
+            ShapeType::CurvedConnector if single_bend => "curvedConnector2",
+            ShapeType::ElbowConnector => "bentConnector3",
+            ShapeType::CurvedConnector => "curvedConnector3",
+            _ => "straightConnector1",
+        };
+
+        let mut connector = connector.clone();
+        connector.x_offset = start_x.min(end_x).round() as u32;
+        connector.y_offset = start_y.min(end_y).round() as u32;
+        connector.width = (end_x - start_x).abs();
+        connector.height = (end_y - start_y).abs();
+        connector.url = None;
+        connector.connection = Some(ShapeConnection {
+            start_id,
+            start_index: start_shape.shape_type.connection_site_index(start_site),
+            end_id,
+            end_index: end_shape.shape_type.connection_site_index(end_site),
+            flip_h,
+            flip_v,
+            rotated,
+            preset,
+        });
+
+        connector
+    }
+
+    // Get the pixel position of a connection site on a shape.
+    fn connection_site_point(shape: &DrawingInfo, site: ShapeConnectionSite) -> (f64, f64) {
+        let x = shape.col_absolute as f64;
+        let y = shape.row_absolute as f64;
+
+        match site {
+            ShapeConnectionSite::Top | ShapeConnectionSite::Automatic => (x + shape.width / 2.0, y),
+            ShapeConnectionSite::Bottom => (x + shape.width / 2.0, y + shape.height),
+            ShapeConnectionSite::Left => (x, y + shape.height / 2.0),
+            ShapeConnectionSite::Right => (x + shape.width, y + shape.height / 2.0),
+        }
+    }
+
     // Calculate the vertices that define the position of a graphical object
     // within the worksheet in EMUs. The vertices are expressed as English
     // Metric Units (EMUs). There are 12,700 EMUs per point. Therefore, 12,700 *
@@ -16740,7 +20054,7 @@ impl Worksheet {
 
     // Convert the width of a cell from character units to pixels. Excel rounds
     // the column width to the nearest pixel.
-    fn column_pixel_width(&mut self, col: ColNum, position: ObjectMovement) -> u32 {
+    fn column_pixel_width(&self, col: ColNum, position: ObjectMovement) -> u32 {
         let max_digit_width = 7.0_f64;
         let padding = 5.0_f64;
 
@@ -16786,9 +20100,8 @@ impl Worksheet {
     // Reset an worksheet global data or structures between saves.
     pub(crate) fn reset(&mut self) {
         xmlwriter::reset(&mut self.writer);
-        xmlwriter::reset(&mut self.drawing.writer);
 
-        for chart in self.charts.values_mut() {
+        for chart in self.all_charts_mut() {
             xmlwriter::reset(&mut chart.writer);
         }
 
@@ -16801,7 +20114,7 @@ impl Worksheet {
         self.drawing_object_relationships.clear();
         self.drawing_rel_ids.clear();
         self.drawing_relationships.clear();
-        self.drawing.drawings.clear();
+        self.drawing = Drawing::new();
         self.header_footer_vml_info.clear();
         self.hyperlink_relationships.clear();
         self.table_relationships.clear();
@@ -16909,12 +20222,22 @@ impl Worksheet {
         let mut data = vec![];
 
         for row_num in first_row..=last_row {
-            match self.data_table.get(&row_num) {
+            let row_data = self.data_table.get(&row_num);
+
+            // In constant memory mode most rows will have been flushed to disk
+            // so we also check the write ahead and retained chart data.
+            #[cfg(feature = "constant_memory")]
+            let row_data = row_data
+                .or_else(|| self.write_ahead.get(&row_num))
+                .or_else(|| self.chart_data.get(&row_num));
+
+            match row_data {
                 Some(columns) => {
                     for col_num in first_col..=last_col {
                         match columns.get(&col_num) {
                             Some(cell) => match cell {
-                                CellType::String { string, .. } => {
+                                CellType::String { string, .. }
+                                | CellType::InlineString { string, .. } => {
                                     data.push(string.to_string());
                                     cache.cache_type = ChartRangeCacheDataType::String;
                                 }
@@ -16979,6 +20302,21 @@ impl Worksheet {
         headers
     }
 
+    // Get the formats used in the worksheet indexed by the cell format
+    // indices.
+    fn cell_formats(&self) -> Vec<Format> {
+        if self.has_workbook_global_xfs {
+            let xf_indices = self.workbook_xf_indices.read().expect("RwLock poisoned");
+            let mut formats = vec![Format::default(); xf_indices.len()];
+            for (format, xf_index) in xf_indices.iter() {
+                formats[*xf_index as usize] = format.clone();
+            }
+            formats
+        } else {
+            self.xf_formats.clone()
+        }
+    }
+
     // Autofit the worksheet column widths, approximately.
     //
     // Auto-fitting of columns is something that Excel does at runtime when it
@@ -16987,12 +20325,23 @@ impl Worksheet {
     //
     // The `rust_xlsxwriter` library doesn't have access to the Windows
     // functions that Excel has so it simulates autofit by calculating string
-    // widths using metrics taken from Excel.
+    // widths using the character widths of the cell fonts.
     //
     // This internal function supports autofitting to Excel's maximum cell width
     // or to a user defined value.
     fn autofit_worksheet(&mut self, max_autofit_width: u16) -> &mut Worksheet {
         let mut max_widths: HashMap<ColNum, u16> = HashMap::new();
+        let cell_formats = self.cell_formats();
+
+        // Get the fonts used to measure the cell data, indexed by the cell
+        // format indices.
+        let cell_fonts: Vec<CellFont> = cell_formats
+            .iter()
+            .map(|format| CellFont::new(&format.font, &self.autofit_fonts))
+            .collect();
+        let default_font = CellFont::default();
+
+        let number_formats = Self::autofit_number_formats(&cell_formats);
 
         let (first_row, last_row) = if self.use_constant_memory {
             (self.current_row, self.current_row)
@@ -17006,9 +20355,22 @@ impl Worksheet {
             if let Some(columns) = self.data_table.get(&row_num) {
                 for col_num in self.dimensions.first_col..=self.dimensions.last_col {
                     if let Some(cell) = columns.get(&col_num) {
+                        let (CellType::Blank { xf_index, .. }
+                        | CellType::Error { xf_index, .. }
+                        | CellType::String { xf_index, .. }
+                        | CellType::Number { xf_index, .. }
+                        | CellType::Boolean { xf_index, .. }
+                        | CellType::Formula { xf_index, .. }
+                        | CellType::DateTime { xf_index, .. }
+                        | CellType::RichString { xf_index, .. }
+                        | CellType::InlineString { xf_index, .. }
+                        | CellType::ArrayFormula { xf_index, .. }) = cell;
+
+                        let font = cell_fonts.get(*xf_index as usize).unwrap_or(&default_font);
+
                         let mut pixel_width = match cell {
-                            // For strings we do a calculation based on
-                            // character widths taken from Excel. For rich
+                            // For strings we do a calculation based on the
+                            // character widths of the cell font. For rich
                             // strings we use the unformatted string. We also
                             // split multi-line strings and handle each part
                             // separately.
@@ -17019,26 +20381,38 @@ impl Worksheet {
                             } => {
                                 let mut max = 0;
                                 for segment in string.lines() {
-                                    let length = utility::pixel_width(segment);
+                                    let length = font.text_width(segment);
                                     max = cmp::max(max, length);
                                 }
                                 max
                             }
 
-                            // For numbers we use a workaround/optimization
-                            // since digits all have a pixel width of 7. This
-                            // gives a slightly greater width for the decimal
-                            // place and minus sign but only by a few pixels and
+                            // For numbers with a user defined number format
+                            // we calculate the width of the formatted number.
+                            CellType::Number { number, xf_index }
+                            | CellType::DateTime { number, xf_index }
+                                if number_formats.contains_key(xf_index) =>
+                            {
+                                let number_format = &number_formats[xf_index];
+                                font.text_width(&number_format.format_number(*number))
+                            }
+
+                            // For other numbers we use a workaround/optimization
+                            // since digits all have the same width. This gives
+                            // a slightly greater width for the decimal place
+                            // and minus sign but only by a few pixels and
                             // over-estimation is okay.
-                            CellType::Number { number, .. } => 7 * number.to_string().len() as u16,
+                            CellType::Number { number, .. } => {
+                                font.text_width("0") * number.to_string().len() as u16
+                            }
 
-                            // For Boolean types we use the Excel standard
-                            // widths for TRUE and FALSE.
+                            // For Boolean types we use the widths of TRUE and
+                            // FALSE.
                             CellType::Boolean { boolean, .. } => {
                                 if *boolean {
-                                    31
+                                    font.text_width("TRUE")
                                 } else {
-                                    36
+                                    font.text_width("FALSE")
                                 }
                             }
 
@@ -17049,16 +20423,15 @@ impl Worksheet {
                                 if result.as_ref() == "0" || result.is_empty() {
                                     0
                                 } else {
-                                    utility::pixel_width(result)
+                                    font.text_width(result)
                                 }
                             }
 
-                            // Datetimes are just numbers but they also have an
-                            // Excel format. It isn't feasible to parse the
-                            // number format to get the actual string width for
-                            // all format types so we use a width based on the
-                            // Excel's default format: mm/dd/yyyy.
-                            CellType::DateTime { .. } => 68,
+                            // Datetimes with a built-in date format are
+                            // displayed in the locale of the user so we use a
+                            // width based on the Excel's default format:
+                            // mm/dd/yyyy.
+                            CellType::DateTime { .. } => font.text_width("00/00/0000"),
 
                             // Ignore the following types which don't add to the width.
                             CellType::Blank { .. } | CellType::Error { .. } => 0,
@@ -17116,6 +20489,154 @@ impl Worksheet {
         self
     }
 
+    // Get the user defined number formats, indexed by the cell format indices,
+    // that are used to display numbers and dates.
+    fn autofit_number_formats(cell_formats: &[Format]) -> HashMap<u32, NumberFormat> {
+        cell_formats
+            .iter()
+            .enumerate()
+            .filter(|(_, format)| format.num_format_index == 0 && !format.num_format.is_empty())
+            .map(|(xf_index, format)| (xf_index as u32, NumberFormat::new(&format.num_format)))
+            .filter(|(_, number_format)| !number_format.is_general())
+            .collect()
+    }
+
+    // Autofit the worksheet row heights, approximately. The height of each
+    // cell is calculated from the font, wrapped lines and rotation of the cell
+    // data and the row is set to the maximum height if it is greater than the
+    // default height.
+    fn autofit_row_heights(&mut self) {
+        let mut max_heights: BTreeMap<RowNum, u16> = BTreeMap::new();
+        let cell_formats = self.cell_formats();
+        let default_format = Format::default();
+
+        let cell_fonts: Vec<CellFont> = cell_formats
+            .iter()
+            .map(|format| CellFont::new(&format.font, &self.autofit_fonts))
+            .collect();
+        let default_font = CellFont::default();
+
+        let number_formats = Self::autofit_number_formats(&cell_formats);
+
+        let (first_row, last_row) = if self.use_constant_memory {
+            (self.current_row, self.current_row)
+        } else {
+            (self.dimensions.first_row, self.dimensions.last_row)
+        };
+
+        for row_num in first_row..=last_row {
+            let Some(columns) = self.data_table.get(&row_num) else {
+                continue;
+            };
+
+            for (col_num, cell) in columns {
+                // Excel ignores merged cells when autofitting rows.
+                if self.merged_cells.contains_key(&(row_num, *col_num)) {
+                    continue;
+                }
+
+                let (CellType::Blank { xf_index, .. }
+                | CellType::Error { xf_index, .. }
+                | CellType::String { xf_index, .. }
+                | CellType::Number { xf_index, .. }
+                | CellType::Boolean { xf_index, .. }
+                | CellType::Formula { xf_index, .. }
+                | CellType::DateTime { xf_index, .. }
+                | CellType::RichString { xf_index, .. }
+                | CellType::InlineString { xf_index, .. }
+                | CellType::ArrayFormula { xf_index, .. }) = cell;
+
+                let format = cell_formats
+                    .get(*xf_index as usize)
+                    .unwrap_or(&default_format);
+                let font = cell_fonts.get(*xf_index as usize).unwrap_or(&default_font);
+
+                // Get the displayed text of the cell and whether it can be
+                // wrapped. Only strings and formula results are wrapped.
+                let (text, can_wrap) = match cell {
+                    CellType::String { string, .. }
+                    | CellType::InlineString { string, .. }
+                    | CellType::RichString {
+                        raw_string: string, ..
+                    } => (Cow::Borrowed(string.as_ref()), true),
+
+                    CellType::Formula { result, .. } | CellType::ArrayFormula { result, .. } => {
+                        (Cow::Borrowed(result.as_ref()), true)
+                    }
+
+                    CellType::Number { number, xf_index }
+                    | CellType::DateTime { number, xf_index }
+                        if number_formats.contains_key(xf_index) =>
+                    {
+                        let number_format = &number_formats[xf_index];
+                        (Cow::Owned(number_format.format_number(*number)), false)
+                    }
+
+                    CellType::Number { number, .. } => (Cow::Owned(number.to_string()), false),
+                    CellType::DateTime { .. } => (Cow::Borrowed("00/00/0000"), false),
+                    CellType::Boolean { boolean, .. } => (
+                        Cow::Borrowed(if *boolean { "TRUE" } else { "FALSE" }),
+                        false,
+                    ),
+                    CellType::Blank { .. } | CellType::Error { .. } => (Cow::Borrowed(""), false),
+                };
+
+                // Wrapped text is wrapped at the column width less the 7
+                // pixel cell padding used for autofit column widths.
+                let wrap_width = if can_wrap && format.alignment.text_wrap {
+                    let column_width =
+                        self.column_pixel_width(*col_num, ObjectMovement::MoveAndSizeWithCells);
+                    Some(column_width.saturating_sub(7).min(u32::from(u16::MAX)) as u16)
+                } else {
+                    None
+                };
+
+                let height = font.text_height(&text, wrap_width, format.alignment.rotation);
+
+                let max_height = max_heights.entry(row_num).or_insert(0);
+                *max_height = cmp::max(*max_height, height);
+            }
+        }
+
+        // Set the height of the rows that are taller than the default height,
+        // or that were previously autofit. Rows with a user defined height are
+        // left unchanged. Excel limits row heights to 409 points.
+        let default_height = self.user_default_row_height;
+
+        for (row_num, pixels) in max_heights {
+            let height = f64::from(pixels) * 0.75;
+            let height = height.clamp(DEFAULT_ROW_HEIGHT, 409.0);
+
+            match self.changed_rows.get_mut(&row_num) {
+                Some(row_options) => {
+                    // Note, rows that only have other properties, such as a
+                    // format, still have the default height and can be
+                    // autofit.
+                    if row_options.autofit
+                        || (row_options.height == default_height && height > default_height)
+                    {
+                        row_options.height = height;
+                        row_options.autofit = true;
+                    }
+                }
+                None => {
+                    if height > default_height {
+                        let row_options = RowOptions {
+                            height,
+                            xf_index: 0,
+                            hidden: false,
+                            level: 0,
+                            collapsed: false,
+                            autofit: true,
+                            format: None,
+                        };
+                        self.changed_rows.insert(row_num, row_options);
+                    }
+                }
+            }
+        }
+    }
+
     /// Set the row properties (outline level and hidden) for a range of grouped
     /// rows in an outline.
     fn set_grouped_rows(
@@ -17124,6 +20645,8 @@ impl Worksheet {
         last_row: RowNum,
         collapsed: bool,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Get a suitable minimum value for the dimension check/set.
         let min_col = self.get_min_col();
 
@@ -17178,6 +20701,7 @@ impl Worksheet {
                     hidden: collapsed,
                     level: 1,
                     collapsed: false,
+                    autofit: false,
                     format: None,
                 };
                 self.changed_rows.insert(row, row_options);
@@ -17189,6 +20713,8 @@ impl Worksheet {
 
     // Set the collapsed property to the row after the collapse group.
     fn set_collapsed_row(&mut self, row: RowNum) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check rows are in the allowed range.
         let min_col = self.get_min_col();
         if !self.check_dimensions(row, min_col) {
@@ -17205,6 +20731,7 @@ impl Worksheet {
                     hidden: false,
                     level: 0,
                     collapsed: true,
+                    autofit: false,
                     format: None,
                 };
                 self.changed_rows.insert(row, row_options);
@@ -17222,6 +20749,8 @@ impl Worksheet {
         last_col: ColNum,
         collapsed: bool,
     ) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Get a suitable minimum value for the dimension check/set.
         let min_row = self.get_min_row();
 
@@ -17288,6 +20817,8 @@ impl Worksheet {
 
     // Set the collapsed property to the column after the collapse group.
     fn set_collapsed_column(&mut self, col: ColNum) -> Result<&mut Worksheet, XlsxError> {
+        self.check_chartsheet()?;
+
         // Check columns are in the allowed range.
         let min_row = self.get_min_row();
         if !self.check_dimensions(min_row, col) {
@@ -17499,16 +21030,6 @@ impl Worksheet {
             self.write_sheet_protection();
         }
 
-        // Write the protectedRange element.
-        if !self.unprotected_ranges.is_empty() {
-            self.write_protected_ranges();
-        }
-
-        // Write the printOptions element.
-        if self.print_options_changed {
-            self.write_print_options();
-        }
-
         // Write the pageMargins element.
         self.write_page_margins();
 
@@ -17601,6 +21122,19 @@ impl Worksheet {
             attributes.push(("filterMode", "1".to_string()));
         }
 
+        // Chartsheets only support the tabColor child element.
+        if self.is_chartsheet {
+            if self.tab_color != Color::Default && self.tab_color != Color::Automatic {
+                xml_start_tag(&mut self.writer, "sheetPr", &attributes);
+                self.write_tab_color();
+                xml_end_tag(&mut self.writer, "sheetPr");
+            } else {
+                xml_empty_tag(&mut self.writer, "sheetPr", &attributes);
+            }
+
+            return;
+        }
+
         if self.fit_to_page
             || self.outline_symbols_above
             || self.outline_symbols_left
@@ -17916,10 +21450,11 @@ impl Worksheet {
     // Write the <conditionalFormatting> element.
     fn write_conditional_formats(&mut self) {
         let mut guid_index = 1;
-        let mut priority = 1;
+        let priorities = self.conditional_format_priorities();
 
         for cell_range in &self.conditional_format_order {
             let conditionals_for_range = self.conditional_formats.get(cell_range).unwrap();
+            let priorities_for_range = &priorities[cell_range];
 
             let has_x14_only = conditionals_for_range
                 .iter()
@@ -17931,7 +21466,10 @@ impl Worksheet {
                 xml_start_tag(&mut self.writer, "conditionalFormatting", &attributes);
             }
 
-            for conditional_format in conditionals_for_range {
+            for (conditional_format, priority) in conditionals_for_range
+                .iter()
+                .zip(priorities_for_range.iter().copied())
+            {
                 // Create a pseudo GUID for each unique Excel 2010 data bar.
                 let mut guid = String::new();
                 if conditional_format.has_x14_extensions() {
@@ -17953,14 +21491,47 @@ impl Worksheet {
                     let rule = conditional_format.rule(dxf_index, priority, cell_range, &guid);
                     xml_raw_string(&mut self.writer, &rule);
                 }
+            }
+
+            if !has_x14_only {
+                xml_end_tag(&mut self.writer, "conditionalFormatting");
+            }
+        }
+    }
+
+    // Get the priorities of the conditional format rules for each range. User
+    // defined priorities are retained and the remaining rules are numbered, in
+    // the order they were added, around them.
+    fn conditional_format_priorities(&self) -> HashMap<String, Vec<u32>> {
+        let user_priorities: HashSet<u32> = self
+            .conditional_formats
+            .values()
+            .flatten()
+            .filter_map(|rule| rule.priority())
+            .collect();
+
+        let mut priorities = HashMap::new();
+        let mut priority = 1;
 
-                priority += 1;
-            }
+        for cell_range in &self.conditional_format_order {
+            let mut priorities_for_range = vec![];
 
-            if !has_x14_only {
-                xml_end_tag(&mut self.writer, "conditionalFormatting");
+            for conditional_format in &self.conditional_formats[cell_range] {
+                if let Some(user_priority) = conditional_format.priority() {
+                    priorities_for_range.push(user_priority);
+                } else {
+                    while user_priorities.contains(&priority) {
+                        priority += 1;
+                    }
+                    priorities_for_range.push(priority);
+                    priority += 1;
+                }
             }
+
+            priorities.insert(cell_range.clone(), priorities_for_range);
         }
+
+        priorities
     }
 
     // Write the <x14:conditionalFormattings> element. This is used for new
@@ -17969,7 +21540,7 @@ impl Worksheet {
         xml_start_tag_only(&mut self.writer, "x14:conditionalFormattings");
 
         let mut guid_index = 1;
-        let mut priority = 1;
+        let priorities = self.conditional_format_priorities();
 
         for (cell_range, conditionals_for_range) in &self.conditional_formats {
             // Only create an entry if range contains a x14 style conditional.
@@ -17987,7 +21558,10 @@ impl Worksheet {
 
             xml_start_tag(&mut self.writer, "x14:conditionalFormatting", &attributes);
 
-            for conditional_format in conditionals_for_range {
+            for (conditional_format, priority) in conditionals_for_range
+                .iter()
+                .zip(priorities[cell_range].iter().copied())
+            {
                 if conditional_format.has_x14_extensions() {
                     // Create a pseudo GUID for each unique Excel 2010 data bar.
                     let guid = format!(
@@ -18000,7 +21574,6 @@ impl Worksheet {
                     let rule = conditional_format.x14_rule(priority, &guid);
                     xml_raw_string(&mut self.writer, &rule);
                 }
-                priority += 1;
             }
 
             xml_data_element_only(&mut self.writer, "xm:sqref", cell_range);
@@ -18253,21 +21826,24 @@ impl Worksheet {
             attributes.push(("paperSize", self.paper_size.to_string()));
         }
 
-        if self.print_scale != 100 {
-            attributes.push(("scale", self.print_scale.to_string()));
-        }
-
-        if self.fit_to_page {
-            if self.fit_width != 1 {
-                attributes.push(("fitToWidth", self.fit_width.to_string()));
+        // Chartsheets don't support the scaling or page order options.
+        if !self.is_chartsheet {
+            if self.print_scale != 100 {
+                attributes.push(("scale", self.print_scale.to_string()));
             }
-            if self.fit_height != 1 {
-                attributes.push(("fitToHeight", self.fit_height.to_string()));
+
+            if self.fit_to_page {
+                if self.fit_width != 1 {
+                    attributes.push(("fitToWidth", self.fit_width.to_string()));
+                }
+                if self.fit_height != 1 {
+                    attributes.push(("fitToHeight", self.fit_height.to_string()));
+                }
             }
-        }
 
-        if !self.default_page_order {
-            attributes.push(("pageOrder", "overThenDown".to_string()));
+            if !self.default_page_order {
+                attributes.push(("pageOrder", "overThenDown".to_string()));
+            }
         }
 
         if self.is_portrait {
@@ -18646,6 +22222,41 @@ impl Worksheet {
         self.flush_data_row(next_row);
     }
 
+    // Store a copy of the string and number cells in a row that are referred
+    // to by chart data ranges, before the row is flushed to disk. These are
+    // used to populate the chart caches when the file is saved.
+    #[cfg(feature = "constant_memory")]
+    fn retain_chart_data_cells(&mut self, row: RowNum, columns: &BTreeMap<ColNum, CellType>) {
+        if self.chart_data_ranges.is_empty() {
+            return;
+        }
+
+        for (&col, cell) in columns {
+            let is_chart_data =
+                self.chart_data_ranges
+                    .iter()
+                    .any(|&(first_row, first_col, last_row, last_col)| {
+                        (first_row..=last_row).contains(&row)
+                            && (first_col..=last_col).contains(&col)
+                    });
+
+            if is_chart_data
+                && matches!(
+                    cell,
+                    CellType::String { .. }
+                        | CellType::InlineString { .. }
+                        | CellType::Number { .. }
+                        | CellType::DateTime { .. }
+                )
+            {
+                self.chart_data
+                    .entry(row)
+                    .or_default()
+                    .insert(col, cell.clone());
+            }
+        }
+    }
+
     // Write out all the row and cell data in the constant memory data table.
     #[allow(clippy::too_many_lines)]
     #[cfg(feature = "constant_memory")]
@@ -18679,6 +22290,9 @@ impl Worksheet {
             return;
         };
 
+        // Keep a copy of any cells that are referred to by charts.
+        self.retain_chart_data_cells(current_row, columns);
+
         // The row has data. Write it out cell by cell.
         self.write_constant_table_row(current_row, row_options, true);
         for (&col_num, cell) in columns {
@@ -18917,9 +22531,11 @@ impl Worksheet {
             }
 
             // Only add customHeight parameter if the height is non-default.
-            if row_options.height != DEFAULT_ROW_HEIGHT
-                || (row_options.height == DEFAULT_ROW_HEIGHT
-                    && self.user_default_row_height != DEFAULT_ROW_HEIGHT)
+            // Autofit heights aren't custom so that Excel can adjust them.
+            if !row_options.autofit
+                && (row_options.height != DEFAULT_ROW_HEIGHT
+                    || (row_options.height == DEFAULT_ROW_HEIGHT
+                        && self.user_default_row_height != DEFAULT_ROW_HEIGHT))
             {
                 attributes.push(("customHeight", "1".to_string()));
             }
@@ -18979,9 +22595,11 @@ impl Worksheet {
             }
 
             // Only add customHeight parameter if the height is non-default.
-            if row_options.height != DEFAULT_ROW_HEIGHT
-                || (row_options.height == DEFAULT_ROW_HEIGHT
-                    && self.user_default_row_height != DEFAULT_ROW_HEIGHT)
+            // Autofit heights aren't custom so that Excel can adjust them.
+            if !row_options.autofit
+                && (row_options.height != DEFAULT_ROW_HEIGHT
+                    || (row_options.height == DEFAULT_ROW_HEIGHT
+                        && self.user_default_row_height != DEFAULT_ROW_HEIGHT))
             {
                 attributes.push(("customHeight", "1".to_string()));
             }
@@ -19728,379 +23346,674 @@ impl Worksheet {
             attributes.push(("manualMax", max.to_string()));
         }
 
-        if let Some(min) = sparkline.custom_min {
-            attributes.push(("manualMin", min.to_string()));
-        }
+        if let Some(min) = sparkline.custom_min {
+            attributes.push(("manualMin", min.to_string()));
+        }
+
+        match sparkline.sparkline_type {
+            SparklineType::Column => {
+                attributes.push(("type", "column".to_string()));
+            }
+            SparklineType::WinLose => {
+                attributes.push(("type", "stacked".to_string()));
+            }
+            SparklineType::Line => {}
+        }
+
+        if let Some(weight) = sparkline.line_weight {
+            attributes.push(("lineWeight", weight.to_string()));
+        }
+
+        if sparkline.date_range.has_data() {
+            attributes.push(("dateAxis", "1".to_string()));
+        }
+
+        match sparkline.show_empty_cells_as {
+            ChartEmptyCells::Gaps | ChartEmptyCells::Connected => {
+                attributes.push((
+                    "displayEmptyCellsAs",
+                    sparkline.show_empty_cells_as.to_string(),
+                ));
+            }
+            ChartEmptyCells::Zero => {}
+        }
+
+        if sparkline.show_markers {
+            attributes.push(("markers", "1".to_string()));
+        }
+
+        if sparkline.show_high_point {
+            attributes.push(("high", "1".to_string()));
+        }
+
+        if sparkline.show_low_point {
+            attributes.push(("low", "1".to_string()));
+        }
+
+        if sparkline.show_first_point {
+            attributes.push(("first", "1".to_string()));
+        }
+
+        if sparkline.show_last_point {
+            attributes.push(("last", "1".to_string()));
+        }
+
+        if sparkline.show_negative_points {
+            attributes.push(("negative", "1".to_string()));
+        }
+
+        if sparkline.show_axis {
+            attributes.push(("displayXAxis", "1".to_string()));
+        }
+
+        if sparkline.show_hidden_data {
+            attributes.push(("displayHidden", "1".to_string()));
+        }
+
+        if sparkline.custom_min.is_some() {
+            attributes.push(("minAxisType", "custom".to_string()));
+        } else if sparkline.group_min {
+            attributes.push(("minAxisType", "group".to_string()));
+        }
+
+        if sparkline.custom_max.is_some() {
+            attributes.push(("maxAxisType", "custom".to_string()));
+        } else if sparkline.group_max {
+            attributes.push(("maxAxisType", "group".to_string()));
+        }
+
+        if sparkline.show_right_to_left {
+            attributes.push(("rightToLeft", "1".to_string()));
+        }
+
+        xml_start_tag(&mut self.writer, "x14:sparklineGroup", &attributes);
+
+        // Write the sparkline color elements.
+        self.write_sparkline_color("x14:colorSeries", sparkline.series_color);
+        self.write_sparkline_color("x14:colorNegative", sparkline.negative_points_color);
+        self.write_sparkline_color("x14:colorAxis", sparkline.axis_color);
+        self.write_sparkline_color("x14:colorMarkers", sparkline.markers_color);
+        self.write_sparkline_color("x14:colorFirst", sparkline.first_point_color);
+        self.write_sparkline_color("x14:colorLast", sparkline.last_point_color);
+        self.write_sparkline_color("x14:colorHigh", sparkline.high_point_color);
+        self.write_sparkline_color("x14:colorLow", sparkline.low_point_color);
+
+        if sparkline.date_range.has_data() {
+            xml_data_element_only(&mut self.writer, "xm:f", &sparkline.date_range.formula());
+        }
+
+        // Write the x14:sparklines element.
+        self.write_sparklines(sparkline);
+
+        xml_end_tag(&mut self.writer, "x14:sparklineGroup");
+    }
+
+    // Write a sparkline <x14:color*> element.
+    fn write_sparkline_color(&mut self, name: &str, color: Color) {
+        xml_empty_tag(&mut self.writer, name, &color.attributes());
+    }
+
+    // Write the <x14:sparklines> element.
+    fn write_sparklines(&mut self, sparkline: &Sparkline) {
+        xml_start_tag_only(&mut self.writer, "x14:sparklines");
+
+        for range in &sparkline.ranges {
+            xml_start_tag_only(&mut self.writer, "x14:sparkline");
+
+            xml_data_element_only(&mut self.writer, "xm:f", &range.1);
+            xml_data_element_only(&mut self.writer, "xm:sqref", &range.0);
+
+            xml_end_tag(&mut self.writer, "x14:sparkline");
+        }
+        xml_end_tag(&mut self.writer, "x14:sparklines");
+    }
+
+    // Write the <ignoredErrors> element.
+    fn write_ignored_errors(&mut self) {
+        xml_start_tag_only(&mut self.writer, "ignoredErrors");
+
+        for error_type in IgnoreError::iterator() {
+            let error_name = error_type.to_string();
+
+            if let Some(error_range) = self.ignored_errors.get(&error_type) {
+                let attributes = [
+                    ("sqref", error_range.clone()),
+                    (&error_name, "1".to_string()),
+                ];
+                xml_empty_tag(&mut self.writer, "ignoredError", &attributes);
+            }
+        }
+
+        xml_end_tag(&mut self.writer, "ignoredErrors");
+    }
+}
+
+// -----------------------------------------------------------------------
+// Traits
+// -----------------------------------------------------------------------
+
+/// Trait to map user defined types to one of the supported Excel native types.
+///
+/// This trait allows you to map user defined types into a type that Excel
+/// supports and to write it via [`Worksheet::write()`] and
+/// [`Worksheet::write_with_format()`]. Both of these methods need to be
+/// implemented for the trait. See the example below.
+///
+/// # Examples
+///
+/// Example of how to extend the the `rust_xlsxwriter` `write()` method using
+/// the `IntoExcelData` trait to handle arbitrary user data that can be mapped
+/// to one of the main Excel data types.
+///
+/// ```
+/// # // This code is available in examples/app_write_generic_data.rs
+/// #
+/// use rust_xlsxwriter::*;
+///
+/// fn main() -> Result<(), XlsxError> {
+///     // Create a new Excel file object.
+///     let mut workbook = Workbook::new();
+///
+///     // Add a worksheet to the workbook.
+///     let worksheet = workbook.add_worksheet();
+///
+///     // Add a format for the dates.
+///     let format = Format::new().set_num_format("yyyy-mm-dd");
+///
+///     // Make the first column wider for clarity.
+///     worksheet.set_column_width(0, 12)?;
+///
+///     // Write user defined type instances that implement the IntoExcelData trait.
+///     worksheet.write_with_format(0, 0, UnixTime::new(0), &format)?;
+///     worksheet.write_with_format(1, 0, UnixTime::new(946598400), &format)?;
+///     worksheet.write_with_format(2, 0, UnixTime::new(1672531200), &format)?;
+///
+///     // Save the file to disk.
+///     workbook.save("write_generic.xlsx")?;
+///
+///     Ok(())
+/// }
+///
+/// // For this example we create a simple struct type to represent a Unix time.
+/// // This is the number of elapsed seconds since the epoch of January 1970 (UTC).
+/// // See https://en.wikipedia.org/wiki/Unix_time. Note, this is for demonstration
+/// // purposes only. The `ExcelDateTime` struct in `rust_xlsxwriter` can handle
+/// // Unix timestamps.
+/// pub struct UnixTime {
+///     seconds: u64,
+/// }
+///
+/// impl UnixTime {
+///     pub fn new(seconds: u64) -> UnixTime {
+///         UnixTime { seconds }
+///     }
+/// }
+///
+/// // Implement the IntoExcelData trait to map our new UnixTime struct into an
+/// // Excel type.
+/// //
+/// // The relevant Excel type is f64 which is used to store dates and times (along
+/// // with a number format). The Unix 1970 epoch equates to a date/number of
+/// // 25569.0. For Unix times beyond that we divide by the number of seconds in the
+/// // day (24 * 60 * 60) to get the Excel serial date.
+/// //
+/// // We need to implement two methods for the trait in order to write data with
+/// // and without a format.
+/// //
+/// impl IntoExcelData for UnixTime {
+///     fn write(
+///         self,
+///         worksheet: &mut Worksheet,
+///         row: RowNum,
+///         col: ColNum,
+///     ) -> Result<&mut Worksheet, XlsxError> {
+///         // Convert the Unix time to an Excel datetime.
+///         let datetime = 25569.0 + (self.seconds as f64 / (24.0 * 60.0 * 60.0));
+///
+///         // Write the date as a number with a format.
+///         worksheet.write_number(row, col, datetime)
+///     }
+///
+///     fn write_with_format<'a>(
+///         self,
+///         worksheet: &'a mut Worksheet,
+///         row: RowNum,
+///         col: ColNum,
+///         format: &Format,
+///     ) -> Result<&'a mut Worksheet, XlsxError> {
+///         // Convert the Unix time to an Excel datetime.
+///         let datetime = 25569.0 + (self.seconds as f64 / (24.0 * 60.0 * 60.0));
+///
+///         // Write the date with the user supplied format.
+///         worksheet.write_number_with_format(row, col, datetime, format)
+///     }
+/// }
+/// ```
+///
+/// Output file:
+///
+/// <img src="https://rustxlsxwriter.github.io/images/write_generic.png">
+///
+pub trait IntoExcelData {
+    /// Trait method to handle writing an unformatted type to Excel.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError>;
+
+    /// Trait method to handle writing a formatted type to Excel.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError>;
+}
 
-        match sparkline.sparkline_type {
-            SparklineType::Column => {
-                attributes.push(("type", "column".to_string()));
-            }
-            SparklineType::WinLose => {
-                attributes.push(("type", "stacked".to_string()));
+macro_rules! write_string_trait_impl {
+    ($($t:ty)*) => ($(
+        impl IntoExcelData for $t {
+            fn write(
+                self,
+                worksheet: &mut Worksheet,
+                row: RowNum,
+                col: ColNum,
+            ) -> Result<&mut Worksheet, XlsxError> {
+                worksheet.store_string(row, col, self.into(), None)
             }
-            SparklineType::Line => {}
-        }
-
-        if let Some(weight) = sparkline.line_weight {
-            attributes.push(("lineWeight", weight.to_string()));
-        }
-
-        if sparkline.date_range.has_data() {
-            attributes.push(("dateAxis", "1".to_string()));
-        }
 
-        match sparkline.show_empty_cells_as {
-            ChartEmptyCells::Gaps | ChartEmptyCells::Connected => {
-                attributes.push((
-                    "displayEmptyCellsAs",
-                    sparkline.show_empty_cells_as.to_string(),
-                ));
+            fn write_with_format<'a>(
+                self,
+                worksheet: &'a mut Worksheet,
+                row: RowNum,
+                col: ColNum,
+                format: &Format,
+            ) -> Result<&'a mut Worksheet, XlsxError> {
+                worksheet.store_string(row, col, self.into(), Some(format))
             }
-            ChartEmptyCells::Zero => {}
-        }
-
-        if sparkline.show_markers {
-            attributes.push(("markers", "1".to_string()));
-        }
-
-        if sparkline.show_high_point {
-            attributes.push(("high", "1".to_string()));
-        }
-
-        if sparkline.show_low_point {
-            attributes.push(("low", "1".to_string()));
-        }
-
-        if sparkline.show_first_point {
-            attributes.push(("first", "1".to_string()));
-        }
-
-        if sparkline.show_last_point {
-            attributes.push(("last", "1".to_string()));
-        }
-
-        if sparkline.show_negative_points {
-            attributes.push(("negative", "1".to_string()));
-        }
-
-        if sparkline.show_axis {
-            attributes.push(("displayXAxis", "1".to_string()));
         }
+    )*)
+}
+write_string_trait_impl!(&str &String String Cow<'_, str>);
 
-        if sparkline.show_hidden_data {
-            attributes.push(("displayHidden", "1".to_string()));
-        }
+macro_rules! write_number_trait_impl {
+    ($($t:ty)*) => ($(
+        impl IntoExcelData for $t {
+            fn write(
+                self,
+                worksheet: &mut Worksheet,
+                row: RowNum,
+                col: ColNum,
+            ) -> Result<&mut Worksheet, XlsxError> {
+                worksheet.store_number(row, col, self, None)
+            }
 
-        if sparkline.custom_min.is_some() {
-            attributes.push(("minAxisType", "custom".to_string()));
-        } else if sparkline.group_min {
-            attributes.push(("minAxisType", "group".to_string()));
+            fn write_with_format<'a>(
+                self,
+                worksheet: &'a mut Worksheet,
+                row: RowNum,
+                col: ColNum,
+                format: &Format,
+            ) -> Result<&'a mut Worksheet, XlsxError> {
+                worksheet.store_number(row, col, self, Some(format))
+            }
         }
+    )*)
+}
+write_number_trait_impl!(u8 i8 u16 i16 u32 i32 f32 f64);
 
-        if sparkline.custom_max.is_some() {
-            attributes.push(("maxAxisType", "custom".to_string()));
-        } else if sparkline.group_max {
-            attributes.push(("maxAxisType", "group".to_string()));
-        }
+// Note: Excel doesn't support saving the full range of i64/u64 in f64 so this
+// is documented as a loss of precision.
+macro_rules! write_number_trait_impl {
+    ($($t:ty)*) => ($(
+        impl IntoExcelData for $t {
+            fn write(
+                self,
+                worksheet: &mut Worksheet,
+                row: RowNum,
+                col: ColNum,
+            ) -> Result<&mut Worksheet, XlsxError> {
+                #[allow(clippy::cast_precision_loss)]
+                worksheet.store_number(row, col, self as f64, None)
+            }
 
-        if sparkline.show_right_to_left {
-            attributes.push(("rightToLeft", "1".to_string()));
+            fn write_with_format<'a>(
+                self,
+                worksheet: &'a mut Worksheet,
+                row: RowNum,
+                col: ColNum,
+                format: &Format,
+            ) -> Result<&'a mut Worksheet, XlsxError> {
+                #[allow(clippy::cast_precision_loss)]
+                worksheet.store_number(row, col, self as f64, Some(format))
+            }
         }
+    )*)
+}
+write_number_trait_impl!(u64 i64);
 
-        xml_start_tag(&mut self.writer, "x14:sparklineGroup", &attributes);
+impl IntoExcelData for bool {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        worksheet.store_boolean(row, col, self, None)
+    }
 
-        // Write the sparkline color elements.
-        self.write_sparkline_color("x14:colorSeries", sparkline.series_color);
-        self.write_sparkline_color("x14:colorNegative", sparkline.negative_points_color);
-        self.write_sparkline_color("x14:colorAxis", sparkline.axis_color);
-        self.write_sparkline_color("x14:colorMarkers", sparkline.markers_color);
-        self.write_sparkline_color("x14:colorFirst", sparkline.first_point_color);
-        self.write_sparkline_color("x14:colorLast", sparkline.last_point_color);
-        self.write_sparkline_color("x14:colorHigh", sparkline.high_point_color);
-        self.write_sparkline_color("x14:colorLow", sparkline.low_point_color);
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        worksheet.store_boolean(row, col, self, Some(format))
+    }
+}
 
-        if sparkline.date_range.has_data() {
-            xml_data_element_only(&mut self.writer, "xm:f", &sparkline.date_range.formula());
-        }
+impl IntoExcelData for &ExcelDateTime {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = self.to_excel();
+        worksheet.store_datetime(row, col, number, None)
+    }
 
-        // Write the x14:sparklines element.
-        self.write_sparklines(sparkline);
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = self.to_excel();
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
+}
 
-        xml_end_tag(&mut self.writer, "x14:sparklineGroup");
+impl IntoExcelData for ExcelDateTime {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = self.to_excel_serial_date();
+        worksheet.store_datetime(row, col, number, None)
     }
 
-    // Write a sparkline <x14:color*> element.
-    fn write_sparkline_color(&mut self, name: &str, color: Color) {
-        xml_empty_tag(&mut self.writer, name, &color.attributes());
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = self.to_excel_serial_date();
+        worksheet.store_datetime(row, col, number, Some(format))
     }
+}
 
-    // Write the <x14:sparklines> element.
-    fn write_sparklines(&mut self, sparkline: &Sparkline) {
-        xml_start_tag_only(&mut self.writer, "x14:sparklines");
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl IntoExcelData for &NaiveDateTime {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::chrono_datetime_to_excel(self);
+        worksheet.store_datetime(row, col, number, None)
+    }
 
-        for range in &sparkline.ranges {
-            xml_start_tag_only(&mut self.writer, "x14:sparkline");
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::chrono_datetime_to_excel(self);
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
+}
 
-            xml_data_element_only(&mut self.writer, "xm:f", &range.1);
-            xml_data_element_only(&mut self.writer, "xm:sqref", &range.0);
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl IntoExcelData for &NaiveDate {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::chrono_date_to_excel(self);
+        worksheet.store_datetime(row, col, number, None)
+    }
 
-            xml_end_tag(&mut self.writer, "x14:sparkline");
-        }
-        xml_end_tag(&mut self.writer, "x14:sparklines");
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::chrono_date_to_excel(self);
+        worksheet.store_datetime(row, col, number, Some(format))
     }
+}
 
-    // Write the <ignoredErrors> element.
-    fn write_ignored_errors(&mut self) {
-        xml_start_tag_only(&mut self.writer, "ignoredErrors");
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl IntoExcelData for &NaiveTime {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::chrono_time_to_excel(self);
+        worksheet.store_datetime(row, col, number, None)
+    }
 
-        for error_type in IgnoreError::iterator() {
-            let error_name = error_type.to_string();
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::chrono_time_to_excel(self);
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
+}
 
-            if let Some(error_range) = self.ignored_errors.get(&error_type) {
-                let attributes = [
-                    ("sqref", error_range.clone()),
-                    (&error_name, "1".to_string()),
-                ];
-                xml_empty_tag(&mut self.writer, "ignoredError", &attributes);
-            }
-        }
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelData for PrimitiveDateTime {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_datetime_to_excel(&self);
+        worksheet.store_datetime(row, col, number, None)
+    }
 
-        xml_end_tag(&mut self.writer, "ignoredErrors");
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_datetime_to_excel(&self);
+        worksheet.store_datetime(row, col, number, Some(format))
     }
 }
 
-// -----------------------------------------------------------------------
-// Traits
-// -----------------------------------------------------------------------
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelData for &PrimitiveDateTime {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_datetime_to_excel(self);
+        worksheet.store_datetime(row, col, number, None)
+    }
 
-/// Trait to map user defined types to one of the supported Excel native types.
-///
-/// This trait allows you to map user defined types into a type that Excel
-/// supports and to write it via [`Worksheet::write()`] and
-/// [`Worksheet::write_with_format()`]. Both of these methods need to be
-/// implemented for the trait. See the example below.
-///
-/// # Examples
-///
-/// Example of how to extend the the `rust_xlsxwriter` `write()` method using
-/// the `IntoExcelData` trait to handle arbitrary user data that can be mapped
-/// to one of the main Excel data types.
-///
-/// ```
-/// # // This code is available in examples/app_write_generic_data.rs
-/// #
-/// use rust_xlsxwriter::*;
-///
-/// fn main() -> Result<(), XlsxError> {
-///     // Create a new Excel file object.
-///     let mut workbook = Workbook::new();
-///
-///     // Add a worksheet to the workbook.
-///     let worksheet = workbook.add_worksheet();
-///
-///     // Add a format for the dates.
-///     let format = Format::new().set_num_format("yyyy-mm-dd");
-///
-///     // Make the first column wider for clarity.
-///     worksheet.set_column_width(0, 12)?;
-///
-///     // Write user defined type instances that implement the IntoExcelData trait.
-///     worksheet.write_with_format(0, 0, UnixTime::new(0), &format)?;
-///     worksheet.write_with_format(1, 0, UnixTime::new(946598400), &format)?;
-///     worksheet.write_with_format(2, 0, UnixTime::new(1672531200), &format)?;
-///
-///     // Save the file to disk.
-///     workbook.save("write_generic.xlsx")?;
-///
-///     Ok(())
-/// }
-///
-/// // For this example we create a simple struct type to represent a Unix time.
-/// // This is the number of elapsed seconds since the epoch of January 1970 (UTC).
-/// // See https://en.wikipedia.org/wiki/Unix_time. Note, this is for demonstration
-/// // purposes only. The `ExcelDateTime` struct in `rust_xlsxwriter` can handle
-/// // Unix timestamps.
-/// pub struct UnixTime {
-///     seconds: u64,
-/// }
-///
-/// impl UnixTime {
-///     pub fn new(seconds: u64) -> UnixTime {
-///         UnixTime { seconds }
-///     }
-/// }
-///
-/// // Implement the IntoExcelData trait to map our new UnixTime struct into an
-/// // Excel type.
-/// //
-/// // The relevant Excel type is f64 which is used to store dates and times (along
-/// // with a number format). The Unix 1970 epoch equates to a date/number of
-/// // 25569.0. For Unix times beyond that we divide by the number of seconds in the
-/// // day (24 * 60 * 60) to get the Excel serial date.
-/// //
-/// // We need to implement two methods for the trait in order to write data with
-/// // and without a format.
-/// //
-/// impl IntoExcelData for UnixTime {
-///     fn write(
-///         self,
-///         worksheet: &mut Worksheet,
-///         row: RowNum,
-///         col: ColNum,
-///     ) -> Result<&mut Worksheet, XlsxError> {
-///         // Convert the Unix time to an Excel datetime.
-///         let datetime = 25569.0 + (self.seconds as f64 / (24.0 * 60.0 * 60.0));
-///
-///         // Write the date as a number with a format.
-///         worksheet.write_number(row, col, datetime)
-///     }
-///
-///     fn write_with_format<'a>(
-///         self,
-///         worksheet: &'a mut Worksheet,
-///         row: RowNum,
-///         col: ColNum,
-///         format: &Format,
-///     ) -> Result<&'a mut Worksheet, XlsxError> {
-///         // Convert the Unix time to an Excel datetime.
-///         let datetime = 25569.0 + (self.seconds as f64 / (24.0 * 60.0 * 60.0));
-///
-///         // Write the date with the user supplied format.
-///         worksheet.write_number_with_format(row, col, datetime, format)
-///     }
-/// }
-/// ```
-///
-/// Output file:
-///
-/// <img src="https://rustxlsxwriter.github.io/images/write_generic.png">
-///
-pub trait IntoExcelData {
-    /// Trait method to handle writing an unformatted type to Excel.
-    ///
-    /// # Errors
-    ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
-    ///   of 32,767 characters.
-    ///
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_datetime_to_excel(self);
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelData for OffsetDateTime {
     fn write(
         self,
         worksheet: &mut Worksheet,
         row: RowNum,
         col: ColNum,
-    ) -> Result<&mut Worksheet, XlsxError>;
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_offset_datetime_to_excel(&self);
+        worksheet.store_datetime(row, col, number, None)
+    }
 
-    /// Trait method to handle writing a formatted type to Excel.
-    ///
-    /// # Errors
-    ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
-    ///   of 32,767 characters.
-    ///
     fn write_with_format<'a>(
         self,
         worksheet: &'a mut Worksheet,
         row: RowNum,
         col: ColNum,
         format: &Format,
-    ) -> Result<&'a mut Worksheet, XlsxError>;
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_offset_datetime_to_excel(&self);
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
 }
 
-macro_rules! write_string_trait_impl {
-    ($($t:ty)*) => ($(
-        impl IntoExcelData for $t {
-            fn write(
-                self,
-                worksheet: &mut Worksheet,
-                row: RowNum,
-                col: ColNum,
-            ) -> Result<&mut Worksheet, XlsxError> {
-                worksheet.store_string(row, col, self.into(), None)
-            }
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelData for &OffsetDateTime {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_offset_datetime_to_excel(self);
+        worksheet.store_datetime(row, col, number, None)
+    }
 
-            fn write_with_format<'a>(
-                self,
-                worksheet: &'a mut Worksheet,
-                row: RowNum,
-                col: ColNum,
-                format: &Format,
-            ) -> Result<&'a mut Worksheet, XlsxError> {
-                worksheet.store_string(row, col, self.into(), Some(format))
-            }
-        }
-    )*)
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_offset_datetime_to_excel(self);
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
 }
-write_string_trait_impl!(&str &String String Cow<'_, str>);
 
-macro_rules! write_number_trait_impl {
-    ($($t:ty)*) => ($(
-        impl IntoExcelData for $t {
-            fn write(
-                self,
-                worksheet: &mut Worksheet,
-                row: RowNum,
-                col: ColNum,
-            ) -> Result<&mut Worksheet, XlsxError> {
-                worksheet.store_number(row, col, self, None)
-            }
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelData for Date {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_date_to_excel(&self);
+        worksheet.store_datetime(row, col, number, None)
+    }
 
-            fn write_with_format<'a>(
-                self,
-                worksheet: &'a mut Worksheet,
-                row: RowNum,
-                col: ColNum,
-                format: &Format,
-            ) -> Result<&'a mut Worksheet, XlsxError> {
-                worksheet.store_number(row, col, self, Some(format))
-            }
-        }
-    )*)
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_date_to_excel(&self);
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
 }
-write_number_trait_impl!(u8 i8 u16 i16 u32 i32 f32 f64);
 
-// Note: Excel doesn't support saving the full range of i64/u64 in f64 so this
-// is documented as a loss of precision.
-macro_rules! write_number_trait_impl {
-    ($($t:ty)*) => ($(
-        impl IntoExcelData for $t {
-            fn write(
-                self,
-                worksheet: &mut Worksheet,
-                row: RowNum,
-                col: ColNum,
-            ) -> Result<&mut Worksheet, XlsxError> {
-                #[allow(clippy::cast_precision_loss)]
-                worksheet.store_number(row, col, self as f64, None)
-            }
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelData for &Date {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_date_to_excel(self);
+        worksheet.store_datetime(row, col, number, None)
+    }
 
-            fn write_with_format<'a>(
-                self,
-                worksheet: &'a mut Worksheet,
-                row: RowNum,
-                col: ColNum,
-                format: &Format,
-            ) -> Result<&'a mut Worksheet, XlsxError> {
-                #[allow(clippy::cast_precision_loss)]
-                worksheet.store_number(row, col, self as f64, Some(format))
-            }
-        }
-    )*)
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_date_to_excel(self);
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
 }
-write_number_trait_impl!(u64 i64);
 
-impl IntoExcelData for bool {
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelData for Time {
     fn write(
         self,
         worksheet: &mut Worksheet,
         row: RowNum,
         col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
-        worksheet.store_boolean(row, col, self, None)
+        let number = ExcelDateTime::time_time_to_excel(&self);
+        worksheet.store_datetime(row, col, number, None)
     }
 
     fn write_with_format<'a>(
@@ -20110,18 +24023,21 @@ impl IntoExcelData for bool {
         col: ColNum,
         format: &Format,
     ) -> Result<&'a mut Worksheet, XlsxError> {
-        worksheet.store_boolean(row, col, self, Some(format))
+        let number = ExcelDateTime::time_time_to_excel(&self);
+        worksheet.store_datetime(row, col, number, Some(format))
     }
 }
 
-impl IntoExcelData for &ExcelDateTime {
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelData for &Time {
     fn write(
         self,
         worksheet: &mut Worksheet,
         row: RowNum,
         col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
-        let number = self.to_excel();
+        let number = ExcelDateTime::time_time_to_excel(self);
         worksheet.store_datetime(row, col, number, None)
     }
 
@@ -20132,19 +24048,21 @@ impl IntoExcelData for &ExcelDateTime {
         col: ColNum,
         format: &Format,
     ) -> Result<&'a mut Worksheet, XlsxError> {
-        let number = self.to_excel();
+        let number = ExcelDateTime::time_time_to_excel(self);
         worksheet.store_datetime(row, col, number, Some(format))
     }
 }
 
-impl IntoExcelData for ExcelDateTime {
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelData for civil::DateTime {
     fn write(
         self,
         worksheet: &mut Worksheet,
         row: RowNum,
         col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
-        let number = self.to_excel_serial_date();
+        let number = ExcelDateTime::jiff_datetime_to_excel(&self);
         worksheet.store_datetime(row, col, number, None)
     }
 
@@ -20155,21 +24073,21 @@ impl IntoExcelData for ExcelDateTime {
         col: ColNum,
         format: &Format,
     ) -> Result<&'a mut Worksheet, XlsxError> {
-        let number = self.to_excel_serial_date();
+        let number = ExcelDateTime::jiff_datetime_to_excel(&self);
         worksheet.store_datetime(row, col, number, Some(format))
     }
 }
 
-#[cfg(feature = "chrono")]
-#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
-impl IntoExcelData for &NaiveDateTime {
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelData for &civil::DateTime {
     fn write(
         self,
         worksheet: &mut Worksheet,
         row: RowNum,
         col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
-        let number = ExcelDateTime::chrono_datetime_to_excel(self);
+        let number = ExcelDateTime::jiff_datetime_to_excel(self);
         worksheet.store_datetime(row, col, number, None)
     }
 
@@ -20180,21 +24098,21 @@ impl IntoExcelData for &NaiveDateTime {
         col: ColNum,
         format: &Format,
     ) -> Result<&'a mut Worksheet, XlsxError> {
-        let number = ExcelDateTime::chrono_datetime_to_excel(self);
+        let number = ExcelDateTime::jiff_datetime_to_excel(self);
         worksheet.store_datetime(row, col, number, Some(format))
     }
 }
 
-#[cfg(feature = "chrono")]
-#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
-impl IntoExcelData for &NaiveDate {
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelData for Zoned {
     fn write(
         self,
         worksheet: &mut Worksheet,
         row: RowNum,
         col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
-        let number = ExcelDateTime::chrono_date_to_excel(self);
+        let number = ExcelDateTime::jiff_zoned_to_excel(&self);
         worksheet.store_datetime(row, col, number, None)
     }
 
@@ -20205,21 +24123,21 @@ impl IntoExcelData for &NaiveDate {
         col: ColNum,
         format: &Format,
     ) -> Result<&'a mut Worksheet, XlsxError> {
-        let number = ExcelDateTime::chrono_date_to_excel(self);
+        let number = ExcelDateTime::jiff_zoned_to_excel(&self);
         worksheet.store_datetime(row, col, number, Some(format))
     }
 }
 
-#[cfg(feature = "chrono")]
-#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
-impl IntoExcelData for &NaiveTime {
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelData for &Zoned {
     fn write(
         self,
         worksheet: &mut Worksheet,
         row: RowNum,
         col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
-        let number = ExcelDateTime::chrono_time_to_excel(self);
+        let number = ExcelDateTime::jiff_zoned_to_excel(self);
         worksheet.store_datetime(row, col, number, None)
     }
 
@@ -20230,7 +24148,107 @@ impl IntoExcelData for &NaiveTime {
         col: ColNum,
         format: &Format,
     ) -> Result<&'a mut Worksheet, XlsxError> {
-        let number = ExcelDateTime::chrono_time_to_excel(self);
+        let number = ExcelDateTime::jiff_zoned_to_excel(self);
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
+}
+
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelData for civil::Date {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::jiff_date_to_excel(&self);
+        worksheet.store_datetime(row, col, number, None)
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::jiff_date_to_excel(&self);
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
+}
+
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelData for &civil::Date {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::jiff_date_to_excel(self);
+        worksheet.store_datetime(row, col, number, None)
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::jiff_date_to_excel(self);
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
+}
+
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelData for civil::Time {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::jiff_time_to_excel(&self);
+        worksheet.store_datetime(row, col, number, None)
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::jiff_time_to_excel(&self);
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
+}
+
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+impl IntoExcelData for &civil::Time {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::jiff_time_to_excel(self);
+        worksheet.store_datetime(row, col, number, None)
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::jiff_time_to_excel(self);
         worksheet.store_datetime(row, col, number, Some(format))
     }
 }
@@ -20474,6 +24492,7 @@ struct RowOptions {
     level: u8,
     hidden: bool,
     collapsed: bool,
+    autofit: bool,
     format: Option<Format>,
 }
 
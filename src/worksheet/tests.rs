@@ -9,7 +9,7 @@ mod worksheet_tests {
 
     use crate::test_functions::xml_to_vec;
     use crate::worksheet::*;
-    use crate::XlsxError;
+    use crate::{ChartType, XlsxError};
     use pretty_assertions::assert_eq;
     use std::collections::HashMap;
 
@@ -715,6 +715,387 @@ mod worksheet_tests {
         assert!(matches!(result, Err(XlsxError::ParameterError(_))));
     }
 
+    #[test]
+    fn chartsheet_cell_methods() {
+        let mut chartsheet = Worksheet::new_chartsheet();
+        let format = Format::new();
+
+        let result = chartsheet.write_number(0, 0, 1);
+        assert!(matches!(result, Err(XlsxError::ChartsheetError(_))));
+
+        let result = chartsheet.write_string(0, 0, "");
+        assert!(matches!(result, Err(XlsxError::ChartsheetError(_))));
+
+        let result = chartsheet.merge_range(0, 0, 1, 1, "", &format);
+        assert!(matches!(result, Err(XlsxError::ChartsheetError(_))));
+
+        let result = chartsheet.set_row_height(0, 20);
+        assert!(matches!(result, Err(XlsxError::ChartsheetError(_))));
+
+        let result = chartsheet.set_column_width(0, 20);
+        assert!(matches!(result, Err(XlsxError::ChartsheetError(_))));
+
+        let result = chartsheet.set_freeze_panes(1, 0);
+        assert!(matches!(result, Err(XlsxError::ChartsheetError(_))));
+
+        let result = chartsheet.set_print_area(0, 0, 9, 9);
+        assert!(matches!(result, Err(XlsxError::ChartsheetError(_))));
+
+        let result = chartsheet.autofilter(0, 0, 9, 9);
+        assert!(matches!(result, Err(XlsxError::ChartsheetError(_))));
+    }
+
+    #[test]
+    fn chartsheet_page_setup() {
+        let mut chartsheet = Worksheet::new_chartsheet();
+
+        chartsheet
+            .set_paper_size(9)
+            .set_landscape()
+            .set_print_scale(50)
+            .set_print_fit_to_pages(1, 0)
+            .set_print_gridlines(true)
+            .set_footer("&LConfidential&RPage &P")
+            .set_tab_color("#FF0000")
+            .set_zoom(75)
+            .protect();
+
+        chartsheet.assemble_chartsheet();
+
+        let got = xmlwriter::cursor_to_str(&chartsheet.writer);
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <chartsheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <sheetPr>
+                <tabColor rgb="FFFF0000"/>
+              </sheetPr>
+              <sheetViews>
+                <sheetView zoomScale="75" workbookViewId="0"/>
+              </sheetViews>
+              <sheetProtection content="1" objects="1"/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+              <pageSetup paperSize="9" orientation="landscape" horizontalDpi="200" verticalDpi="200"/>
+              <headerFooter>
+                <oddFooter>&amp;LConfidential&amp;RPage &amp;P</oddFooter>
+              </headerFooter>
+            </chartsheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn shape_connectors() {
+        let mut worksheet = Worksheet::new();
+
+        let rectangle = Shape::new(ShapeType::Rectangle);
+        let ellipse = Shape::new(ShapeType::Ellipse);
+        let elbow = Shape::new(ShapeType::ElbowConnector);
+        let straight = Shape::new(ShapeType::StraightConnector);
+
+        worksheet.insert_shape(1, 1, &rectangle).unwrap();
+        worksheet.insert_shape(10, 1, &rectangle).unwrap();
+        worksheet.insert_shape(1, 6, &ellipse).unwrap();
+
+        // Test the connector errors.
+        let result = worksheet.insert_connector(1, 1, 10, 1, &rectangle);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = worksheet.insert_connector(1, 1, 20, 1, &elbow);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        // Connect vertically and horizontally using automatic sites.
+        worksheet.insert_connector(1, 1, 10, 1, &elbow).unwrap();
+        worksheet.insert_connector(1, 1, 1, 6, &straight).unwrap();
+
+        let mut image_ids = HashMap::new();
+        let mut image_id = 0;
+        worksheet.prepare_worksheet_shapes(&mut image_ids, &mut image_id, 1, 1);
+
+        assert_eq!(worksheet.drawing.drawings.len(), 5);
+
+        // The elbow connector joins the bottom of the first shape to the top
+        // of the second shape and is rotated to start vertically.
+        let drawing_info = &worksheet.drawing.drawings[3];
+        assert_eq!(drawing_info.col_absolute, 160 * 9525);
+        assert_eq!(drawing_info.row_absolute, 140 * 9525);
+        assert_eq!(drawing_info.width, 0.0);
+        assert_eq!(drawing_info.height, 60.0 * 9525.0);
+
+        let connection = worksheet.drawing.shapes[3].connection.clone().unwrap();
+        assert_eq!(connection.start_id, 2);
+        assert_eq!(connection.start_index, Some(2));
+        assert_eq!(connection.end_id, 4);
+        assert_eq!(connection.end_index, Some(0));
+        assert!(connection.rotated);
+        assert!(!connection.flip_h);
+        assert!(!connection.flip_v);
+        assert_eq!(connection.preset, "bentConnector3");
+
+        // The straight connector joins the right of the first shape to the
+        // left of the ellipse.
+        let drawing_info = &worksheet.drawing.drawings[4];
+        assert_eq!(drawing_info.col_absolute, 256 * 9525);
+        assert_eq!(drawing_info.row_absolute, 80 * 9525);
+        assert_eq!(drawing_info.width, 128.0 * 9525.0);
+        assert_eq!(drawing_info.height, 0.0);
+
+        let connection = worksheet.drawing.shapes[4].connection.clone().unwrap();
+        assert_eq!(connection.start_id, 2);
+        assert_eq!(connection.start_index, Some(3));
+        assert_eq!(connection.end_id, 3);
+        assert_eq!(connection.end_index, Some(2));
+        assert!(!connection.rotated);
+        assert_eq!(connection.preset, "straightConnector1");
+    }
+
+    #[test]
+    fn drawing_groups() {
+        let mut worksheet = Worksheet::new();
+
+        // Test an empty group.
+        let result = worksheet.insert_drawing_group(1, 1, &DrawingGroup::new());
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let group = DrawingGroup::new()
+            .add_shape(&Shape::new(ShapeType::Rectangle).set_width(100), 0, 0)
+            .add_shape(&Shape::textbox(), 150, 10)
+            .set_object_movement(ObjectMovement::DontMoveOrSizeWithCells);
+
+        worksheet
+            .insert_drawing_group_with_offset(1, 1, &group, 10, 5)
+            .unwrap();
+
+        let mut image_ids = HashMap::new();
+        let mut image_id = 0;
+        let mut shape_id = 1;
+        worksheet.prepare_worksheet_drawing_groups(&mut image_ids, &mut image_id, &mut shape_id, 1);
+
+        assert_eq!(shape_id, 4);
+        assert_eq!(worksheet.drawing.drawings.len(), 1);
+        assert_eq!(worksheet.drawing.shapes.len(), 2);
+
+        // The group extends to the bottom right of the furthest object.
+        let group_info = &worksheet.drawing.drawings[0];
+        assert_eq!(group_info.col_absolute, 74 * 9525);
+        assert_eq!(group_info.row_absolute, 25 * 9525);
+        assert_eq!(group_info.width, 342.0 * 9525.0);
+        assert_eq!(group_info.height, 130.0 * 9525.0);
+        assert!(group_info.object_movement == ObjectMovement::DontMoveOrSizeWithCells);
+
+        // The grouped objects are offset from the group.
+        let object_info = &worksheet.drawing.groups[0][1];
+        assert_eq!(object_info.col_absolute, 224 * 9525);
+        assert_eq!(object_info.row_absolute, 35 * 9525);
+        assert_eq!(object_info.width, 192.0 * 9525.0);
+        assert_eq!(object_info.height, 120.0 * 9525.0);
+    }
+
+    #[test]
+    fn drawing_group_charts() {
+        let mut worksheet = Worksheet::new();
+
+        // Charts in a group are validated when the group is inserted.
+        let group = DrawingGroup::new().add_chart(&Chart::new(ChartType::Column), 0, 0);
+        let result = worksheet.insert_drawing_group(1, 1, &group);
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$5");
+
+        let group =
+            DrawingGroup::new()
+                .add_chart(&chart, 0, 0)
+                .add_shape(&Shape::textbox(), 480, 0);
+
+        worksheet.insert_chart(10, 1, &chart).unwrap();
+        worksheet.insert_drawing_group(1, 1, &group).unwrap();
+
+        assert_eq!(worksheet.all_charts().count(), 2);
+
+        let mut image_ids = HashMap::new();
+        let mut image_id = 0;
+        let mut shape_id = 1;
+        worksheet.prepare_worksheet_charts(&mut image_ids, &mut image_id, 1, 1);
+        worksheet.prepare_worksheet_drawing_groups(&mut image_ids, &mut image_id, &mut shape_id, 1);
+
+        // The grouped chart is numbered after the worksheet chart and linked
+        // after it in the drawing rels file.
+        let chart_ids: Vec<u32> = worksheet.all_charts().map(|chart| chart.id).collect();
+        assert_eq!(chart_ids, vec![1, 2]);
+        assert_eq!(worksheet.drawing_relationships[1].1, "../charts/chart2.xml");
+
+        let object_info = &worksheet.drawing.groups[0][0];
+        assert!(object_info.drawing_type == DrawingType::Chart);
+        assert_eq!(object_info.rel_id, 2);
+        assert_eq!(object_info.width, 480.0 * 9525.0);
+        assert_eq!(object_info.height, 288.0 * 9525.0);
+    }
+
+    #[test]
+    fn test_autofit_number_formats() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        let currency_format = Format::new().set_num_format("$#,##0.00");
+        let date_format = Format::new().set_num_format("dddd, mmmm d, yyyy");
+        let builtin_format = Format::new().set_num_format_index(14);
+        let date = ExcelDateTime::from_ymd(2025, 9, 10)?;
+
+        worksheet.write_number_with_format(0, 0, 1234567, &currency_format)?;
+        worksheet.write_number(0, 1, 1234567)?;
+        worksheet.write_datetime_with_format(0, 2, &date, &date_format)?;
+        worksheet.write_datetime_with_format(0, 3, &date, &builtin_format)?;
+
+        worksheet.autofit();
+
+        let widths: Vec<u32> = (0..4)
+            .map(|col| worksheet.column_pixel_width(col, ObjectMovement::MoveAndSizeWithCells))
+            .collect();
+
+        // The formatted number "$1,234,567.00" and the formatted date
+        // "Wednesday, September 10, 2025" are measured. Numbers in the General
+        // format and dates with a built-in format use the default widths.
+        let date_width = u32::from(utility::pixel_width("Wednesday, September 10, 2025"));
+        assert_eq!(vec![89, 56, date_width + 7, 75], widths);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_autofit_fonts() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        let bold = Format::new().set_bold();
+        let arial = Format::new().set_font_name("Arial").set_font_size(10);
+        let courier = Format::new().set_font_name("Courier New");
+        let large = Format::new().set_font_size(22);
+
+        worksheet.write_string_with_format(0, 0, "Hello", &bold)?;
+        worksheet.write_string_with_format(0, 1, "Hello", &arial)?;
+        worksheet.write_number_with_format(0, 2, 12345, &arial)?;
+        worksheet.write_string(0, 3, "日本語")?;
+        worksheet.write_boolean_with_format(0, 4, true, &courier)?;
+        worksheet.write_string_with_format(0, 5, "Hello", &large)?;
+
+        worksheet.autofit();
+
+        let widths: Vec<u32> = (0..6)
+            .map(|col| worksheet.column_pixel_width(col, ObjectMovement::MoveAndSizeWithCells))
+            .collect();
+
+        assert_eq!(vec![43, 37, 42, 52, 43, 73], widths);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_autofit_rows() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        let wrap = Format::new().set_text_wrap();
+        let large = Format::new().set_font_size(22);
+        let rotated = Format::new().set_rotation(90);
+
+        worksheet.write_string(0, 0, "Hello")?;
+        worksheet.write_string_with_format(1, 0, "Line 1\nLine 2\nLine 3", &wrap)?;
+        worksheet.write_string_with_format(2, 0, "aaaa aaaa aaaa", &wrap)?;
+        worksheet.write_string(3, 0, "Line 1\nLine 2")?;
+        worksheet.write_string_with_format(4, 0, "Hello", &large)?;
+        worksheet.write_string_with_format(5, 0, "Hello", &rotated)?;
+        worksheet.write_number_with_format(6, 0, 1234567890, &wrap)?;
+
+        // User defined heights aren't changed.
+        worksheet.write_string_with_format(7, 0, "Line 1\nLine 2\nLine 3", &wrap)?;
+        worksheet.set_row_height(7, 30)?;
+        worksheet.write_string_with_format(8, 0, "Line 1\nLine 2\nLine 3", &wrap)?;
+        worksheet.set_row_height(8, 60)?;
+
+        // Merged cells are ignored.
+        worksheet.merge_range(9, 0, 9, 1, "Line 1\nLine 2\nLine 3", &wrap)?;
+
+        worksheet.autofit_rows();
+
+        let heights: Vec<Option<(f64, bool)>> = (0..10)
+            .map(|row| {
+                worksheet
+                    .changed_rows
+                    .get(&row)
+                    .map(|row_options| (row_options.height, row_options.autofit))
+            })
+            .collect();
+
+        let expected = vec![
+            None,
+            Some((42.0, true)),
+            Some((42.0, true)),
+            None,
+            Some((28.5, true)),
+            Some((26.25, true)),
+            None,
+            Some((30.0, false)),
+            Some((60.0, false)),
+            None,
+        ];
+
+        assert_eq!(expected, heights);
+
+        // Autofit heights aren't written as custom heights.
+        worksheet.set_global_xf_indices(&[0, 1, 2, 3]);
+        worksheet.assemble_xml_file();
+        let got = xmlwriter::cursor_to_str(&worksheet.writer);
+
+        assert!(got.contains(r#"<row r="2" spans="1:2" ht="42">"#));
+        assert!(got.contains(r#"<row r="8" spans="1:2" ht="30" customHeight="1">"#));
+        assert!(got.contains(r#"<row r="9" spans="1:2" ht="60" customHeight="1">"#));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_autofit_row_heights() -> Result<(), XlsxError> {
+        let mut worksheet = Worksheet::new();
+        let wrap = Format::new().set_text_wrap();
+
+        worksheet.write_string_with_format(0, 0, "aaaa aaaa aaaa", &wrap)?;
+
+        // Row heights aren't autofit by default.
+        worksheet.autofit();
+        assert!(!worksheet.changed_rows.contains_key(&0));
+
+        // The text is wrapped at the autofit column width.
+        worksheet.set_autofit_row_heights(true);
+        worksheet.autofit();
+        assert!(!worksheet.changed_rows.contains_key(&0));
+
+        worksheet.set_column_width_pixels(0, 64)?;
+        worksheet.autofit_rows();
+        assert_eq!(42.0, worksheet.changed_rows[&0].height);
+
+        // Previously autofit rows are updated if the data changes.
+        worksheet.write_string_with_format(0, 0, "aaaa aaaa", &wrap)?;
+        worksheet.autofit_rows();
+        assert_eq!(28.5, worksheet.changed_rows[&0].height);
+
+        // Row heights are limited to Excel's maximum of 409 points.
+        worksheet.write_string_with_format(0, 0, "a\n".repeat(100), &wrap)?;
+        worksheet.autofit_rows();
+        assert_eq!(409.0, worksheet.changed_rows[&0].height);
+
+        // A user defined height replaces a previous autofit height.
+        worksheet.set_row_height(0, 20)?;
+        worksheet.autofit_rows();
+        assert_eq!(20.0, worksheet.changed_rows[&0].height);
+
+        // Rows with a format but no user defined height are autofit.
+        worksheet.set_row_format(1, &wrap)?;
+        worksheet.write_string_with_format(1, 0, "aaaa aaaa", &wrap)?;
+        worksheet.autofit_rows();
+        assert_eq!(28.5, worksheet.changed_rows[&1].height);
+
+        Ok(())
+    }
+
     #[test]
     fn assert_sync() {
         const _: () = {
@@ -8,6 +8,12 @@
 #[cfg(feature = "chrono")]
 use chrono::NaiveDate;
 
+#[cfg(feature = "time")]
+use time::{Date, Month, Time};
+
+#[cfg(feature = "jiff")]
+use jiff::civil;
+
 use crate::common;
 use rust_xlsxwriter::{ExcelDateTime, Format, Workbook, XlsxError};
 
@@ -153,6 +159,134 @@ fn create_new_xlsx_file_4(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Basic test case. With the time crate.
+#[cfg(feature = "time")]
+fn create_new_xlsx_file_5(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let format1 = Format::new().set_num_format("dd/mm/yyyy;@");
+    let format2 = Format::new().set_num_format("mm/dd/yyyy;@");
+    let format3 = Format::new().set_num_format("yyyy/mm/dd;@");
+    let format4 = Format::new().set_num_format("dddd\\ dd\\ mmmm\\ yyyy;@");
+    let format5 = Format::new().set_num_format("[$-F800]dddd\\,\\ mmmm\\ dd\\,\\ yyyy");
+    let format6 = Format::new().set_num_format("[$-F400]h:mm:ss\\ AM/PM");
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(0, 30)?;
+
+    let date = Date::from_calendar_date(2023, Month::January, 25).unwrap();
+    let datetime = date.midnight();
+    let time = Time::from_hms(18, 0, 0).unwrap();
+    let datetime2 = date.with_time(time);
+
+    worksheet.write_datetime_with_format(0, 0, datetime, &format1)?;
+    worksheet.write_datetime_with_format(1, 0, datetime, &format2)?;
+    worksheet.write_date_with_format(2, 0, date, &format3)?;
+    worksheet.write_date_with_format(3, 0, date, &format4)?;
+    worksheet.write_datetime_with_format(4, 0, datetime2, &format5)?;
+    worksheet.write_time_with_format(5, 0, time, &format6)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Version using generic write_with_format(). With the time crate.
+#[cfg(feature = "time")]
+fn create_new_xlsx_file_6(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let format1 = Format::new().set_num_format("dd/mm/yyyy;@");
+    let format2 = Format::new().set_num_format("mm/dd/yyyy;@");
+    let format3 = Format::new().set_num_format("yyyy/mm/dd;@");
+    let format4 = Format::new().set_num_format("dddd\\ dd\\ mmmm\\ yyyy;@");
+    let format5 = Format::new().set_num_format("[$-F800]dddd\\,\\ mmmm\\ dd\\,\\ yyyy");
+    let format6 = Format::new().set_num_format("[$-F400]h:mm:ss\\ AM/PM");
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(0, 30)?;
+
+    let date = Date::from_calendar_date(2023, Month::January, 25).unwrap();
+    let datetime = date.midnight();
+    let time = Time::from_hms(18, 0, 0).unwrap();
+    let datetime2 = date.with_time(time);
+
+    worksheet.write_with_format(0, 0, datetime, &format1)?;
+    worksheet.write_with_format(1, 0, &datetime, &format2)?;
+    worksheet.write_with_format(2, 0, date, &format3)?;
+    worksheet.write_with_format(3, 0, &date, &format4)?;
+    worksheet.write_with_format(4, 0, datetime2, &format5)?;
+    worksheet.write_with_format(5, 0, time, &format6)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Basic test case. With Jiff.
+#[cfg(feature = "jiff")]
+fn create_new_xlsx_file_7(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let format1 = Format::new().set_num_format("dd/mm/yyyy;@");
+    let format2 = Format::new().set_num_format("mm/dd/yyyy;@");
+    let format3 = Format::new().set_num_format("yyyy/mm/dd;@");
+    let format4 = Format::new().set_num_format("dddd\\ dd\\ mmmm\\ yyyy;@");
+    let format5 = Format::new().set_num_format("[$-F800]dddd\\,\\ mmmm\\ dd\\,\\ yyyy");
+    let format6 = Format::new().set_num_format("[$-F400]h:mm:ss\\ AM/PM");
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(0, 30)?;
+
+    let date = civil::date(2023, 1, 25);
+    let datetime = date.to_datetime(civil::Time::midnight());
+    let time = civil::time(18, 0, 0, 0);
+    let datetime2 = date.to_datetime(time);
+
+    worksheet.write_datetime_with_format(0, 0, datetime, &format1)?;
+    worksheet.write_datetime_with_format(1, 0, datetime, &format2)?;
+    worksheet.write_date_with_format(2, 0, date, &format3)?;
+    worksheet.write_date_with_format(3, 0, date, &format4)?;
+    worksheet.write_datetime_with_format(4, 0, datetime2, &format5)?;
+    worksheet.write_time_with_format(5, 0, time, &format6)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Version using generic write_with_format(). With Jiff.
+#[cfg(feature = "jiff")]
+fn create_new_xlsx_file_8(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let format1 = Format::new().set_num_format("dd/mm/yyyy;@");
+    let format2 = Format::new().set_num_format("mm/dd/yyyy;@");
+    let format3 = Format::new().set_num_format("yyyy/mm/dd;@");
+    let format4 = Format::new().set_num_format("dddd\\ dd\\ mmmm\\ yyyy;@");
+    let format5 = Format::new().set_num_format("[$-F800]dddd\\,\\ mmmm\\ dd\\,\\ yyyy");
+    let format6 = Format::new().set_num_format("[$-F400]h:mm:ss\\ AM/PM");
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(0, 30)?;
+
+    let date = civil::date(2023, 1, 25);
+    let datetime = date.to_datetime(civil::Time::midnight());
+    let time = civil::time(18, 0, 0, 0);
+    let datetime2 = date.to_datetime(time);
+
+    worksheet.write_with_format(0, 0, datetime, &format1)?;
+    worksheet.write_with_format(1, 0, &datetime, &format2)?;
+    worksheet.write_with_format(2, 0, date, &format3)?;
+    worksheet.write_with_format(3, 0, &date, &format4)?;
+    worksheet.write_with_format(4, 0, datetime2, &format5)?;
+    worksheet.write_with_format(5, 0, time, &format6)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn bootstrap36_date_time_1() {
     let test_runner = common::TestRunner::new()
@@ -202,3 +336,55 @@ fn bootstrap36_date_time_4() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[cfg(feature = "time")]
+#[test]
+fn bootstrap36_date_time_5() {
+    let test_runner = common::TestRunner::new()
+        .set_name("bootstrap36")
+        .set_function(create_new_xlsx_file_5)
+        .unique("5")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn bootstrap36_date_time_6() {
+    let test_runner = common::TestRunner::new()
+        .set_name("bootstrap36")
+        .set_function(create_new_xlsx_file_6)
+        .unique("6")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn bootstrap36_date_time_7() {
+    let test_runner = common::TestRunner::new()
+        .set_name("bootstrap36")
+        .set_function(create_new_xlsx_file_7)
+        .unique("7")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[cfg(feature = "jiff")]
+#[test]
+fn bootstrap36_date_time_8() {
+    let test_runner = common::TestRunner::new()
+        .set_name("bootstrap36")
+        .set_function(create_new_xlsx_file_8)
+        .unique("8")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -0,0 +1,157 @@
+// Test case that compares a file generated by rust_xlsxwriter with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+use rust_xlsxwriter::{CsvImportOptions, Format, Table, Workbook, XlsxError};
+
+// Test case for importing CSV data with numbers and strings.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let data = "col1,col2\n1,aaa\n2,bbb\n3,ccc\n";
+
+    worksheet.import_csv(data.as_bytes(), &CsvImportOptions::new())?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for importing TSV data with quoted string fields.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let data = "col1\t\"col2\"\r\n1\taaa\r\n2\t\"bbb\"\r\n3\tccc";
+
+    let options = CsvImportOptions::new().set_delimiter('\t');
+
+    worksheet.import_csv(data.as_bytes(), &options)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for importing CSV data without a header row.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let data = "col1,col2\n1,aaa\n2,bbb\n3,ccc\n";
+
+    let options = CsvImportOptions::new().set_header_row(false);
+
+    worksheet.import_csv(data.as_bytes(), &options)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for importing CSV data with dates.
+fn create_new_xlsx_file_4(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(1, 11)?;
+
+    let format = Format::new().set_num_format_index(14);
+
+    let data = "col1,col2\naaa,2024-01-01\nbbb,2024-01-02\nccc,2024-01-03\n";
+
+    let options = CsvImportOptions::new().set_date_format(format);
+
+    worksheet.import_csv(data.as_bytes(), &options)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for importing CSV data as a worksheet table.
+fn create_new_xlsx_file_5(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.set_column_width(2, 10.288)?;
+    worksheet.set_column_width(3, 10.288)?;
+    worksheet.set_column_width(4, 10.288)?;
+    worksheet.set_column_width(5, 10.288)?;
+
+    let data = format!("Column1,Column2,Column3,Column4\n{}", ",,,\n".repeat(10));
+
+    let options = CsvImportOptions::new()
+        .set_first_cell(2, 2)
+        .set_table(Table::new());
+
+    worksheet.import_csv(data.as_bytes(), &options)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_csv_import01_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde07")
+        .set_function(create_new_xlsx_file_1)
+        .unique("csv_import01_1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_csv_import01_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde07")
+        .set_function(create_new_xlsx_file_2)
+        .unique("csv_import01_2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_csv_import01_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde07")
+        .set_function(create_new_xlsx_file_3)
+        .unique("csv_import01_3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_csv_import01_4() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde10")
+        .set_function(create_new_xlsx_file_4)
+        .unique("csv_import01_4")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_csv_import01_5() {
+    let test_runner = common::TestRunner::new()
+        .set_name("table01")
+        .set_function(create_new_xlsx_file_5)
+        .unique("csv_import01_5")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
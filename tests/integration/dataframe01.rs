@@ -0,0 +1,198 @@
+// Test case that compares a file generated by rust_xlsxwriter with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use crate::common;
+use polars::prelude::*;
+use rust_xlsxwriter::{DataFrameOptions, Format, Table, Workbook, XlsxError};
+
+// Test case for writing a Polars dataframe with numbers and strings.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let df: DataFrame = df!(
+        "col1" => &[1, 2, 3],
+        "col2" => &["aaa", "bbb", "ccc"],
+    )?;
+
+    worksheet.write_dataframe(&df, 0, 0)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for writing a Polars dataframe with dates.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(1, 11)?;
+
+    let format = Format::new().set_num_format_index(14);
+
+    // Days since the Unix epoch for 2024-01-01 to 2024-01-03.
+    let dates = Series::new("col2".into(), &[19_723, 19_724, 19_725]).cast(&DataType::Date)?;
+
+    let df: DataFrame = df!(
+        "col1" => &["aaa", "bbb", "ccc"],
+        "col2" => dates,
+    )?;
+
+    let options = DataFrameOptions::new().set_dtype_format(DataType::Date, format);
+
+    worksheet.write_dataframe_with_options(&df, 0, 0, &options)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for writing a Polars dataframe with datetimes, in milliseconds.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(1, 11)?;
+
+    let format = Format::new().set_num_format_index(14);
+
+    // Milliseconds since the Unix epoch for 2024-01-01 to 2024-01-03.
+    let datetimes = Series::new(
+        "col2".into(),
+        &[1_704_067_200_000_i64, 1_704_153_600_000, 1_704_240_000_000],
+    )
+    .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+
+    let df: DataFrame = df!(
+        "col1" => &["aaa", "bbb", "ccc"],
+        "col2" => datetimes,
+    )?;
+
+    let options = DataFrameOptions::new().set_column_format("col2", format);
+
+    worksheet.write_dataframe_with_options(&df, 0, 0, &options)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for writing a Polars dataframe with time zone aware datetimes.
+fn create_new_xlsx_file_5(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(1, 11)?;
+
+    let format = Format::new().set_num_format_index(14);
+
+    // Milliseconds since the Unix epoch for 2024-01-01 to 2024-01-03 at
+    // midnight in a UTC+05:00 time zone.
+    let datetimes = Int64Chunked::new(
+        "col2".into(),
+        &[1_704_049_200_000, 1_704_135_600_000, 1_704_222_000_000],
+    )
+    .into_datetime(TimeUnit::Milliseconds, Some("Asia/Karachi".into()))
+    .into_series();
+
+    let df: DataFrame = df!(
+        "col1" => &["aaa", "bbb", "ccc"],
+        "col2" => datetimes,
+    )?;
+
+    let options = DataFrameOptions::new().set_column_format("col2", format);
+
+    worksheet.write_dataframe_with_options(&df, 0, 0, &options)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for writing a Polars dataframe as a worksheet table.
+fn create_new_xlsx_file_4(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.set_column_width(2, 10.288)?;
+    worksheet.set_column_width(3, 10.288)?;
+    worksheet.set_column_width(4, 10.288)?;
+    worksheet.set_column_width(5, 10.288)?;
+
+    let df = DataFrame::new(vec![
+        Series::full_null("Column1".into(), 10, &DataType::Float64).into(),
+        Series::full_null("Column2".into(), 10, &DataType::Float64).into(),
+        Series::full_null("Column3".into(), 10, &DataType::Float64).into(),
+        Series::full_null("Column4".into(), 10, &DataType::Float64).into(),
+    ])?;
+
+    let options = DataFrameOptions::new().set_table(Table::new());
+
+    worksheet.write_dataframe_with_options(&df, 2, 2, &options)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_dataframe01_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde07")
+        .set_function(create_new_xlsx_file_1)
+        .unique("dataframe01_1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_dataframe01_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde10")
+        .set_function(create_new_xlsx_file_2)
+        .unique("dataframe01_2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_dataframe01_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde10")
+        .set_function(create_new_xlsx_file_3)
+        .unique("dataframe01_3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_dataframe01_4() {
+    let test_runner = common::TestRunner::new()
+        .set_name("table01")
+        .set_function(create_new_xlsx_file_4)
+        .unique("dataframe01_4")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_dataframe01_5() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde10")
+        .set_function(create_new_xlsx_file_5)
+        .unique("dataframe01_5")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -550,9 +550,12 @@ mod cond_format19;
 mod cond_format20;
 mod cond_format21;
 mod cond_format22;
+mod csv_import01;
 mod data_validation01;
 mod data_validation02;
 mod data_validation03;
+#[cfg(feature = "polars")]
+mod dataframe01;
 #[cfg(feature = "rust_decimal")]
 mod decimal01;
 mod default_row01;
@@ -812,6 +815,10 @@ mod optimize_autofit04;
 #[cfg(feature = "constant_memory")]
 mod optimize_autofit08;
 #[cfg(feature = "constant_memory")]
+mod optimize_chart01;
+#[cfg(feature = "constant_memory")]
+mod optimize_chart02;
+#[cfg(feature = "constant_memory")]
 mod optimize_embed_image01;
 #[cfg(feature = "constant_memory")]
 mod optimize_embed_image02;
@@ -953,6 +960,8 @@ mod optimize_table34;
 mod optimize_table35;
 #[cfg(feature = "constant_memory")]
 mod optimize_table36;
+#[cfg(feature = "constant_memory")]
+mod optimize_table_resize01;
 mod outline01;
 mod outline02;
 mod outline03;
@@ -1015,6 +1024,8 @@ mod quote_name08;
 mod quote_name09;
 mod quote_name10;
 mod quote_name11;
+#[cfg(feature = "arrow")]
+mod record_batch01;
 mod repeat01;
 mod repeat02;
 mod repeat03;
@@ -1143,6 +1154,7 @@ mod table33;
 mod table34;
 mod table35;
 mod table36;
+mod table_resize01;
 mod textbox01;
 mod textbox02;
 mod textbox03;
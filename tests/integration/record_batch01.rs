@@ -0,0 +1,192 @@
+// Test case that compares a file generated by rust_xlsxwriter with a file
+// created by Excel.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2025, John McNamara, jmcnamara@cpan.org
+
+use std::sync::Arc;
+
+use crate::common;
+use arrow_array::types::Int32Type;
+use arrow_array::{
+    ArrayRef, Date32Array, DictionaryArray, Float64Array, Int32Array, RecordBatch, StringArray,
+    TimestampSecondArray,
+};
+use arrow_schema::DataType;
+use rust_xlsxwriter::{Format, RecordBatchOptions, Table, Workbook, XlsxError};
+
+// Test case for writing an Arrow record batch with numbers and strings.
+fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let col1: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+    let col2: ArrayRef = Arc::new(StringArray::from(vec!["aaa", "bbb", "ccc"]));
+    let batch = RecordBatch::try_from_iter(vec![("col1", col1), ("col2", col2)])?;
+
+    worksheet.write_record_batch(&batch, 0, 0)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for writing several Arrow record batches.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let mut batches = vec![];
+    for (col1, col2) in [(vec![1, 2], vec!["aaa", "bbb"]), (vec![3], vec!["ccc"])] {
+        let col1: ArrayRef = Arc::new(Int32Array::from(col1));
+        let col2: ArrayRef = Arc::new(StringArray::from(col2));
+        batches.push(RecordBatch::try_from_iter(vec![
+            ("col1", col1),
+            ("col2", col2),
+        ])?);
+    }
+
+    worksheet.write_record_batches(&batches, 0, 0)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for writing an Arrow record batch with dates.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(1, 11)?;
+
+    let format = Format::new().set_num_format_index(14);
+
+    // Days since the Unix epoch for 2024-01-01 to 2024-01-03.
+    let col1: ArrayRef = Arc::new(StringArray::from(vec!["aaa", "bbb", "ccc"]));
+    let col2: ArrayRef = Arc::new(Date32Array::from(vec![19_723, 19_724, 19_725]));
+    let batch = RecordBatch::try_from_iter(vec![("col1", col1), ("col2", col2)])?;
+
+    let options = RecordBatchOptions::new().set_dtype_format(DataType::Date32, format);
+
+    worksheet.write_record_batch_with_options(&batch, 0, 0, &options)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for writing an Arrow record batch with dictionary strings and time
+// zone aware timestamps.
+fn create_new_xlsx_file_4(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(1, 11)?;
+
+    let format = Format::new().set_num_format_index(14);
+
+    // Seconds since the Unix epoch for 2024-01-01 to 2024-01-03 at midnight in
+    // a UTC+05:00 time zone.
+    let col1: DictionaryArray<Int32Type> = vec!["aaa", "bbb", "ccc"].into_iter().collect();
+    let col2 = TimestampSecondArray::from(vec![1_704_049_200, 1_704_135_600, 1_704_222_000])
+        .with_timezone("+05:00");
+
+    let col1: ArrayRef = Arc::new(col1);
+    let col2: ArrayRef = Arc::new(col2);
+    let batch = RecordBatch::try_from_iter(vec![("col1", col1), ("col2", col2)])?;
+
+    let options = RecordBatchOptions::new().set_column_format("col2", format);
+
+    worksheet.write_record_batch_with_options(&batch, 0, 0, &options)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for writing an Arrow record batch as a worksheet table.
+fn create_new_xlsx_file_5(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.set_column_width(2, 10.288)?;
+    worksheet.set_column_width(3, 10.288)?;
+    worksheet.set_column_width(4, 10.288)?;
+    worksheet.set_column_width(5, 10.288)?;
+
+    let mut columns = vec![];
+    for name in ["Column1", "Column2", "Column3", "Column4"] {
+        let array: ArrayRef = Arc::new(Float64Array::from(vec![None; 10]));
+        columns.push((name, array));
+    }
+
+    let batch = RecordBatch::try_from_iter(columns)?;
+
+    let options = RecordBatchOptions::new().set_table(Table::new());
+
+    worksheet.write_record_batch_with_options(&batch, 2, 2, &options)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_record_batch01_1() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde07")
+        .set_function(create_new_xlsx_file_1)
+        .unique("record_batch01_1")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_record_batch01_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde07")
+        .set_function(create_new_xlsx_file_2)
+        .unique("record_batch01_2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_record_batch01_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde10")
+        .set_function(create_new_xlsx_file_3)
+        .unique("record_batch01_3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_record_batch01_4() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde10")
+        .set_function(create_new_xlsx_file_4)
+        .unique("record_batch01_4")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_record_batch01_5() {
+    let test_runner = common::TestRunner::new()
+        .set_name("table01")
+        .set_function(create_new_xlsx_file_5)
+        .unique("record_batch01_5")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}